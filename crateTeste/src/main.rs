@@ -44,8 +44,12 @@ impl TestApp {
     fn new() -> (Self, Task<Message>) {
         println!("🚀 Initialisation de l'application de test du crate CandleChart");
         
-        // Créer l'état du graphique
-        let mut chart_state = ChartState::new(1200.0, 800.0);
+        // Configurer le graphique : les 150 dernières bougies sont affichées à l'ajout d'une série
+        let builder = ChartState::builder(1200.0, 800.0)
+            .visible_candles(150)
+            .auto_scroll(true);
+        let chart_style = builder.chart_style();
+        let mut chart_state = builder.build();
         
         // Charger toutes les séries depuis le dossier data du projet parent
         match load_all_from_directory("../data") {
@@ -74,7 +78,6 @@ impl TestApp {
         // Créer les états
         let tools_state = ToolsState::default();
         let settings_state = SettingsState::default();
-        let chart_style = ChartStyle::default();
         
         // Ouvrir la fenêtre principale
         let (main_id, open_task) = window::open(window::Settings {
@@ -158,7 +161,7 @@ impl TestApp {
             ChartMessage::UpdatePan { position } => {
                 self.chart_state.update_pan(position);
             }
-            ChartMessage::EndPan => {
+            ChartMessage::EndPan | ChartMessage::CandleClicked { .. } => {
                 self.chart_state.end_pan();
             }
             ChartMessage::ZoomHorizontal { factor } => {
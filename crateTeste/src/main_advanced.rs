@@ -115,7 +115,7 @@ impl TestApp {
         match msg {
             ChartMessage::StartPan { position } => self.chart_state.start_pan(position),
             ChartMessage::UpdatePan { position } => self.chart_state.update_pan(position),
-            ChartMessage::EndPan => self.chart_state.end_pan(),
+            ChartMessage::EndPan | ChartMessage::CandleClicked { .. } => self.chart_state.end_pan(),
             ChartMessage::ZoomHorizontal { factor } => self.chart_state.zoom(factor),
             ChartMessage::ZoomVertical { factor } => self.chart_state.zoom_vertical(factor),
             ChartMessage::ZoomBoth { factor } => self.chart_state.zoom_both(factor),
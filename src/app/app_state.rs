@@ -8,12 +8,12 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use crate::finance_chart::{
     ChartState, ToolsState, SettingsState, ChartStyle,
-    BinanceProvider, ProviderConfigManager, ProviderType,
-    core::{SeriesId, Candle},
-    SeriesPanelMessage,
+    BinanceProvider, BinanceWsProvider, SharedDataProvider, create_data_provider, WsConnectionState, ProviderConfigManager, ProviderType,
+    core::{SeriesId, Candle, cache::{CandleCache, DEFAULT_CANDLE_CACHE_CAPACITY}},
+    SeriesPanelMessage, ChartMessage, ToolsPanelMessage,
 };
 use crate::app::{
-    utils::constants::{MAIN_WINDOW_WIDTH, MAIN_WINDOW_HEIGHT, REALTIME_UPDATE_INTERVAL_SECS},
+    utils::constants::{MAIN_WINDOW_WIDTH, MAIN_WINDOW_HEIGHT, INITIAL_VISIBLE_CANDLES, PRICE_MARGIN_RATIO, REALTIME_UPDATE_INTERVAL_SECS, CANDLE_CACHE_DIR},
     window_manager::{WindowManager, WindowType},
     messages::Message,
     data::data_loading,
@@ -46,7 +46,15 @@ pub struct ChartApp {
     
     // Mode temps réel - Arc pour partage efficace sans clonage coûteux
     pub binance_provider: Arc<BinanceProvider>,
+    // Provider de données (bougies) sélectionné selon le provider actif
+    pub data_provider: SharedDataProvider,
+    // Cache disque des bougies déjà téléchargées (consulté avant les appels API)
+    pub candle_cache: Arc<CandleCache>,
     pub realtime_enabled: bool,
+    // État du WebSocket temps réel (None = pas de flux ouvert)
+    pub ws_connection: Option<WsConnectionState>,
+    // Dernières bougies reçues par le WebSocket (cache partagé avec la subscription)
+    pub ws_provider: BinanceWsProvider,
     
     // Configuration des providers
     pub provider_config: ProviderConfigManager,
@@ -120,12 +128,15 @@ pub struct DownloadProgress {
 
 impl ChartApp {
     pub fn new() -> (Self, Task<Message>) {
-        // Créer l'état initial vide - les données seront chargées de manière asynchrone
-        let chart_state = ChartState::new(MAIN_WINDOW_WIDTH, MAIN_WINDOW_HEIGHT);
-        
         // Charger les états depuis les fichiers
         let tools_state = load_tools_state();
-        let chart_style = load_chart_style();
+        let builder = ChartState::builder(MAIN_WINDOW_WIDTH, MAIN_WINDOW_HEIGHT)
+            .style(load_chart_style())
+            .visible_candles(INITIAL_VISIBLE_CANDLES)
+            .price_margin(PRICE_MARGIN_RATIO);
+        let chart_style = builder.chart_style();
+        // Créer l'état initial vide - les données seront chargées de manière asynchrone
+        let chart_state = builder.build();
         let provider_config = load_provider_config();
 
         // Créer le provider Binance avec le token et la clé secrète configurés (Arc pour partage efficace)
@@ -144,6 +155,9 @@ impl ChartApp {
             ..Default::default()
         });
 
+        // Provider de données générique (temps réel, gaps, historique)
+        let data_provider = create_data_provider(&provider_config);
+
         // Créer une Task pour charger les séries de manière asynchrone
         let load_series_task = data_loading::create_load_series_task(binance_provider.clone());
 
@@ -151,7 +165,12 @@ impl ChartApp {
             Self { 
                 chart_state, 
                 tools_state, 
-                settings_state: SettingsState::default(),
+                settings_state: SettingsState {
+                    show_hud: true,
+                    show_trade_pnl: true,
+                    max_concurrent_requests: SettingsState::DEFAULT_MAX_CONCURRENT_REQUESTS,
+                    ..SettingsState::default()
+                },
                 chart_style,
                 provider_config,
                 editing_provider_token: HashMap::new(),
@@ -160,7 +179,11 @@ impl ChartApp {
                 editing_style: None,
                 editing_color_index: None,
                 binance_provider,
+                data_provider,
+                candle_cache: Arc::new(CandleCache::new(CANDLE_CACHE_DIR, DEFAULT_CANDLE_CACHE_CAPACITY)),
                 realtime_enabled: true, // Activer le mode temps réel par défaut
+                ws_connection: None,
+                ws_provider: BinanceWsProvider::default(),
                 render_version: 0,
                 ui: UiState {
                     panels: load_panels_state(),
@@ -169,6 +192,7 @@ impl ChartApp {
                     chart_context_menu: None,
                     indicators_panel_open: false,
                     backtest_state: crate::app::state::backtest::BacktestState::new(),
+                    replay: crate::app::state::replay::ReplayState::default(),
                     error_messages: Vec::new(),
                     notifications: crate::app::state::notifications::NotificationManager::new(),
                 },
@@ -243,11 +267,21 @@ impl ChartApp {
         let mut subscriptions = vec![window::close_events().map(Message::WindowClosed)];
         
         if self.realtime_enabled {
-            // Subscription pour les mises à jour en temps réel
-            subscriptions.push(
-                iced::time::every(Duration::from_secs_f64(REALTIME_UPDATE_INTERVAL_SECS))
-                    .map(|_| Message::RealtimeUpdate)
-            );
+            // Subscription WebSocket pour les séries actives (non figées) au format Binance
+            let ws_provider = self.realtime_ws_provider();
+            if ws_provider.has_streams() && self.provider_config.active_provider == ProviderType::Binance {
+                subscriptions.push(ws_provider.subscription().map(Message::BinanceWs));
+            }
+            
+            // Polling REST (autres providers, ou en secours tant que le WebSocket n'est pas connecté)
+            let ws_live = self.provider_config.active_provider == ProviderType::Binance
+                && self.ws_connection.is_some_and(|state| state.is_live());
+            if !ws_live {
+                subscriptions.push(
+                    iced::time::every(Duration::from_secs_f64(REALTIME_UPDATE_INTERVAL_SECS))
+                        .map(|_| Message::RealtimeUpdate)
+                );
+            }
         }
         
         // Subscription pour les ticks du backtest si en cours de lecture
@@ -259,6 +293,14 @@ impl ChartApp {
             );
         }
         
+        // Subscription pour les ticks du replay si en lecture automatique
+        if self.ui.replay.is_playing && self.chart_state.replay_cursor.is_some() {
+            subscriptions.push(
+                iced::time::every(Duration::from_millis(self.ui.replay.speed_ms))
+                    .map(|_| Message::ReplayStep)
+            );
+        }
+        
         // Subscription pour mettre à jour les notifications (auto-dismiss)
         subscriptions.push(
             iced::time::every(Duration::from_millis(100))
@@ -272,19 +314,60 @@ impl ChartApp {
     pub fn update(&mut self, message: Message) -> Task<Message> {
         use crate::app::handlers::*;
         
+        // Tout message peut modifier ce que dessine le graphique : invalider son cache de rendu,
+        // sauf pour les messages fréquents qui n'affectent que le crosshair ou d'autres vues
+        if !matches!(
+            message,
+            Message::Chart(ChartMessage::MouseMoved { .. })
+                | Message::Chart(ChartMessage::IndicatorHovered { .. })
+                | Message::UpdateNotifications
+        ) {
+            self.render_version = self.render_version.wrapping_add(1);
+        }
+        
         match message {
             // === Gestion des messages du graphique ===
+            Message::Chart(ChartMessage::SwitchInterval(interval)) => handle_switch_interval(self, interval),
+            Message::Chart(ChartMessage::SelectTool { tool }) => {
+                handle_tools_panel_message(self, ToolsPanelMessage::SelectTool { tool })
+            }
             Message::Chart(chart_msg) => {
+                let opens_text_input = matches!(chart_msg, ChartMessage::StartEditingText { .. });
+                let opens_price_input = matches!(chart_msg, ChartMessage::StartEditingHLinePrice { .. });
+                let opens_label_input = matches!(chart_msg, ChartMessage::StartEditingRectangleLabel { .. });
+                #[cfg(feature = "sqlite")]
+                let ends_pan = matches!(chart_msg, ChartMessage::EndPan | ChartMessage::CandleClicked { .. });
                 handle_chart_message(self, chart_msg);
+                // Donner le focus au champ de saisie d'une annotation texte
+                if opens_text_input {
+                    return iced::widget::operation::focus(crate::app::views::TEXT_ANNOTATION_INPUT_ID);
+                }
+                // Idem pour le prix d'une ligne horizontale
+                if opens_price_input {
+                    return iced::widget::operation::focus(crate::app::views::HLINE_PRICE_INPUT_ID);
+                }
+                // Idem pour le texte d'un rectangle
+                if opens_label_input {
+                    return iced::widget::operation::focus(crate::app::views::RECTANGLE_LABEL_INPUT_ID);
+                }
                 // Si un clic droit a été détecté, ouvrir le menu contextuel
                 if let Some(position) = self.ui.chart_context_menu.take() {
                     return handle_open_chart_context_menu(self, position);
                 }
+                // Vue ramenée au début de l'historique tronqué : recharger la page précédente
+                #[cfg(feature = "sqlite")]
+                if ends_pan {
+                    return crate::app::data::sqlite_store::load_previous_page(self);
+                }
                 Task::none()
             }
             Message::ResetView => handle_reset_view(self),
+            Message::GoToLatest => handle_go_to_latest(self),
             Message::OpenChartContextMenu(position) => handle_open_chart_context_menu(self, position),
             Message::CloseChartContextMenu => handle_close_chart_context_menu(self),
+            Message::ExportChartPng => handle_export_chart_png(self),
+            Message::ExportVisibleCsv => handle_export_visible_csv(self),
+            Message::ToggleComparisonMode => handle_toggle_comparison_mode(self),
             
             // === Gestion des messages des axes ===
             Message::YAxis(msg) => handle_yaxis_message(self, msg),
@@ -297,6 +380,15 @@ impl ChartApp {
             Message::SeriesPanel(SeriesPanelMessage::SelectSeriesByName { series_name }) => {
                 handle_select_series_by_name(self, series_name)
             }
+            Message::SeriesPanel(SeriesPanelMessage::ToggleSeriesVisibility { series_id }) => {
+                handle_toggle_series_visibility(self, series_id)
+            }
+            Message::SeriesPanel(SeriesPanelMessage::ToggleRealtime { series_id }) => {
+                handle_toggle_series_realtime(self, series_id)
+            }
+            Message::SeriesPanel(SeriesPanelMessage::SetSeriesAxis { series_id, axis }) => {
+                handle_set_series_axis(self, series_id, axis)
+            }
             
             // === Gestion des fenêtres ===
             Message::MainWindowOpened(_id) => Task::none(),
@@ -306,6 +398,10 @@ impl ChartApp {
             Message::LoadSeriesFromDirectoryComplete(result) => {
                 handle_load_series_complete(self, result)
             }
+            #[cfg(feature = "sqlite")]
+            Message::SqlitePageLoaded(series_id, result) => {
+                crate::app::data::sqlite_store::handle_page_loaded(self, series_id, result)
+            }
             
             Message::OpenSettings => handle_open_settings(self),
             Message::SettingsWindowOpened(_id) => Task::none(),
@@ -340,10 +436,37 @@ impl ChartApp {
             Message::SelectColor(field_index, color) => {
                 handle_select_color(self, field_index, color)
             }
+            Message::ApplyThemePreset(preset) => handle_apply_theme_preset(self, preset),
             Message::ApplySettings => handle_apply_settings(self),
             Message::CancelSettings => handle_cancel_settings(self),
             Message::ToggleColorPicker(index) => handle_toggle_color_picker(self, index),
             Message::ToggleAutoScroll => handle_toggle_auto_scroll(self),
+            Message::SetRenderMode(mode) => handle_set_render_mode(self, mode),
+            Message::SetCandleWidthRatio(ratio) => handle_set_candle_width_ratio(self, ratio),
+            Message::SetWickWidth(width) => handle_set_wick_width(self, width),
+            Message::SetCandleBodyStyle(body_style) => handle_set_candle_body_style(self, body_style),
+            Message::SetGridDensity(density) => handle_set_grid_density(self, density),
+            Message::SetPriceDecimals(decimals) => handle_set_price_decimals(self, decimals),
+            Message::SetRightPadding(candles) => handle_set_right_padding(self, candles),
+            Message::ToggleGridDashed => handle_toggle_grid_dashed(self),
+            Message::ToggleGridMinorLines => handle_toggle_grid_minor_lines(self),
+            Message::SetCrosshairWidth(width) => handle_set_crosshair_width(self, width),
+            Message::SetCrosshairDash(dash) => handle_set_crosshair_dash(self, dash),
+            Message::ToggleCrosshairLabels => handle_toggle_crosshair_labels(self),
+            Message::ToggleHeikinAshi => handle_toggle_heikin_ashi(self),
+            Message::ToggleCrosshairMagnet => handle_toggle_crosshair_magnet(self),
+            Message::ToggleShowGaps => handle_toggle_show_gaps(self),
+            Message::ToggleVolumeHeatmap => handle_toggle_volume_heatmap(self),
+            Message::ToggleShowDaySeparators => handle_toggle_show_day_separators(self),
+            Message::ToggleSnapPanToCandle => handle_toggle_snap_pan_to_candle(self),
+            Message::SetZoomAnchor(anchor) => handle_set_zoom_anchor(self, anchor),
+            Message::SetMaxConcurrentRequests(limit) => handle_set_max_concurrent_requests(self, limit),
+            Message::SetMaxCandlesPerSeries(limit) => handle_set_max_candles_per_series(self, limit),
+            Message::ToggleShowHud => handle_toggle_show_hud(self),
+            Message::ToggleShowTradePnl => handle_toggle_show_trade_pnl(self),
+            Message::ToggleTooltipField(field) => handle_toggle_tooltip_field(self, field),
+            Message::MoveTooltipFieldUp(field) => handle_move_tooltip_field(self, field, true),
+            Message::MoveTooltipFieldDown(field) => handle_move_tooltip_field(self, field, false),
             
             // === Messages temps réel ===
             Message::CompleteMissingData => {
@@ -391,13 +514,29 @@ impl ChartApp {
             Message::RealtimeUpdateComplete(results) => {
                 handle_realtime_update_complete(self, results)
             }
+            Message::AlertTriggered(alert_id) => handle_alert_triggered(self, alert_id),
+            Message::BinanceWs(event) => {
+                handle_ws_event(self, event)
+            }
             
             // === Gestion des panneaux latéraux ===
             Message::ToggleVolumePanel => handle_toggle_volume_panel(self),
             Message::ToggleRSIPanel => handle_toggle_rsi_panel(self),
             Message::ToggleMACDPanel => handle_toggle_macd_panel(self),
+            Message::ToggleATRPanel => handle_toggle_atr_panel(self),
+            Message::ToggleOBVPanel => handle_toggle_obv_panel(self),
+            Message::ToggleCCIPanel => handle_toggle_cci_panel(self),
+            Message::ToggleWilliamsRPanel => handle_toggle_williams_r_panel(self),
             Message::ToggleBollingerBands => handle_toggle_bollinger_bands(self),
             Message::ToggleMovingAverage => handle_toggle_moving_average(self),
+            Message::ToggleVWAP => handle_toggle_vwap(self),
+            Message::ToggleParabolicSAR => handle_toggle_parabolic_sar(self),
+            Message::ToggleDonchian => handle_toggle_donchian(self),
+            Message::ToggleKeltner => handle_toggle_keltner(self),
+            Message::ToggleVolumeProfile => handle_toggle_volume_profile(self),
+            Message::TogglePivots => handle_toggle_pivots(self),
+            Message::ToggleCrossoverMarkers => handle_toggle_crossover_markers(self),
+            Message::UpdateVolumeMaPeriod(period) => handle_update_volume_ma_period(self, period),
             Message::UpdateRSIPeriod(period) => handle_update_rsi_period(self, period),
             Message::UpdateRSIMethod(method) => handle_update_rsi_method(self, method),
             Message::UpdateMACDFastPeriod(period) => handle_update_macd_fast_period(self, period),
@@ -405,7 +544,23 @@ impl ChartApp {
             Message::UpdateMACDSignalPeriod(period) => handle_update_macd_signal_period(self, period),
             Message::UpdateBollingerPeriod(period) => handle_update_bollinger_period(self, period),
             Message::UpdateBollingerStdDev(std_dev) => handle_update_bollinger_std_dev(self, std_dev),
-            Message::UpdateMAPeriod(period) => handle_update_ma_period(self, period),
+            Message::AddMovingAverage => handle_add_moving_average(self),
+            Message::RemoveMovingAverage(index) => handle_remove_moving_average(self, index),
+            Message::UpdateMAPeriod(index, period) => handle_update_ma_period(self, index, period),
+            Message::UpdateMAType(index, ma_type) => handle_update_ma_type(self, index, ma_type),
+            Message::CycleMAColor(index) => handle_cycle_ma_color(self, index),
+            Message::UpdateATRPeriod(period) => handle_update_atr_period(self, period),
+            Message::UpdatePSARStep(step) => handle_update_psar_step(self, step),
+            Message::UpdatePSARMaxStep(max_step) => handle_update_psar_max_step(self, max_step),
+            Message::UpdateKeltnerEmaPeriod(period) => handle_update_keltner_ema_period(self, period),
+            Message::UpdateKeltnerAtrPeriod(period) => handle_update_keltner_atr_period(self, period),
+            Message::UpdateKeltnerMultiplier(multiplier) => handle_update_keltner_multiplier(self, multiplier),
+            Message::UpdateCCIPeriod(period) => handle_update_cci_period(self, period),
+            Message::UpdateWilliamsRPeriod(period) => handle_update_williams_r_period(self, period),
+            Message::UpdatePivotMethod(method) => handle_update_pivot_method(self, method),
+            Message::UpdateCrossoverFastPeriod(period) => handle_update_crossover_fast_period(self, period),
+            Message::UpdateCrossoverSlowPeriod(period) => handle_update_crossover_slow_period(self, period),
+            Message::UpdateCrossoverMaType(ma_type) => handle_update_crossover_ma_type(self, ma_type),
             Message::StartResizeRightPanel(pos) => handle_start_resize_right_panel(self, pos),
             Message::StartResizeBottomPanel(pos) => handle_start_resize_bottom_panel(self, pos),
             Message::UpdateResizeRightPanel(pos) => handle_update_resize_right_panel(self, pos),
@@ -421,6 +576,18 @@ impl ChartApp {
             Message::UpdateResizeMACDPanel(pos) => handle_update_resize_macd_panel(self, pos),
             Message::EndResizeRSIPanel => handle_end_resize_rsi_panel(self),
             Message::EndResizeMACDPanel => handle_end_resize_macd_panel(self),
+            Message::StartResizeATRPanel(pos) => handle_start_resize_atr_panel(self, pos),
+            Message::UpdateResizeATRPanel(pos) => handle_update_resize_atr_panel(self, pos),
+            Message::EndResizeATRPanel => handle_end_resize_atr_panel(self),
+            Message::StartResizeOBVPanel(pos) => handle_start_resize_obv_panel(self, pos),
+            Message::UpdateResizeOBVPanel(pos) => handle_update_resize_obv_panel(self, pos),
+            Message::EndResizeOBVPanel => handle_end_resize_obv_panel(self),
+            Message::StartResizeCCIPanel(pos) => handle_start_resize_cci_panel(self, pos),
+            Message::UpdateResizeCCIPanel(pos) => handle_update_resize_cci_panel(self, pos),
+            Message::EndResizeCCIPanel => handle_end_resize_cci_panel(self),
+            Message::StartResizeWilliamsRPanel(pos) => handle_start_resize_williams_r_panel(self, pos),
+            Message::UpdateResizeWilliamsRPanel(pos) => handle_update_resize_williams_r_panel(self, pos),
+            Message::EndResizeWilliamsRPanel => handle_end_resize_williams_r_panel(self),
             Message::SelectBottomSection(section) => handle_select_bottom_section(self, section),
             Message::SelectRightSection(section) => handle_select_right_section(self, section),
             Message::OpenSectionContextMenu(section, position) => {
@@ -439,8 +606,12 @@ impl ChartApp {
             Message::UpdateTakeProfit(tp) => handle_update_take_profit(self, tp),
             Message::UpdateStopLoss(sl) => handle_update_stop_loss(self, sl),
             Message::ToggleTPSLEnabled => handle_toggle_tp_sl_enabled(self),
-            Message::PlaceBuyOrder => handle_place_buy_order(self),
-            Message::PlaceSellOrder => handle_place_sell_order(self),
+            Message::PlaceBuyOrder => with_trading_undo(self, handle_place_buy_order),
+            Message::PlaceSellOrder => with_trading_undo(self, handle_place_sell_order),
+            Message::PlaceOcoOrder => with_trading_undo(self, handle_place_oco_order),
+            Message::UpdateTrailingOffset(offset) => handle_update_trailing_offset(self, offset),
+            Message::ToggleTrailingPercent => handle_toggle_trailing_percent(self),
+            Message::UpdateRiskPercent(risk) => handle_update_risk_percent(self, risk),
             Message::BuyOrderPlaced(result) => handle_buy_order_placed(self, result),
             Message::SellOrderPlaced(result) => handle_sell_order_placed(self, result),
             Message::SetRightPanelFocus(focused) => handle_set_right_panel_focus(self, focused),
@@ -448,6 +619,10 @@ impl ChartApp {
             Message::SetVolumePanelFocus(focused) => handle_set_volume_panel_focus(self, focused),
             Message::SetRSIPanelFocus(focused) => handle_set_rsi_panel_focus(self, focused),
             Message::SetMACDPanelFocus(focused) => handle_set_macd_panel_focus(self, focused),
+            Message::SetATRPanelFocus(focused) => handle_set_atr_panel_focus(self, focused),
+            Message::SetOBVPanelFocus(focused) => handle_set_obv_panel_focus(self, focused),
+            Message::SetCCIPanelFocus(focused) => handle_set_cci_panel_focus(self, focused),
+            Message::SetWilliamsRPanelFocus(focused) => handle_set_williams_r_panel_focus(self, focused),
             Message::ClearPanelFocus => handle_clear_panel_focus(self),
             Message::ToggleAccountType => handle_toggle_account_type(self),
             Message::TestProviderConnection => handle_test_provider_connection(self),
@@ -460,6 +635,8 @@ impl ChartApp {
             // === Gestion des stratégies de trading automatisées ===
             Message::RegisterRSIStrategy => handle_register_rsi_strategy(self),
             Message::RegisterMACrossoverStrategy => handle_register_ma_crossover_strategy(self),
+            Message::RegisterBollingerStrategy => handle_register_bollinger_strategy(self),
+            Message::RegisterMacdStrategy => handle_register_macd_strategy(self),
             Message::EnableStrategy(id) => handle_enable_strategy(self, id),
             Message::DisableStrategy(id) => handle_disable_strategy(self, id),
             Message::RemoveStrategy(id) => handle_remove_strategy(self, id),
@@ -481,6 +658,9 @@ impl ChartApp {
             Message::CancelStrategyConfig(strategy_id) => {
                 handle_cancel_strategy_config(self, strategy_id)
             }
+            Message::ResetStrategyParameters(strategy_id) => {
+                handle_reset_strategy_parameters(self, strategy_id)
+            }
             
             // === Gestion du backtest ===
             Message::ToggleBacktestEnabled => handle_toggle_backtest_enabled(self),
@@ -494,6 +674,28 @@ impl ChartApp {
             Message::PauseBacktest => handle_pause_backtest(self),
             Message::StopBacktest => handle_stop_backtest(self),
             Message::BacktestTick => handle_backtest_tick(self),
+            Message::RunBacktest(strategy_id) => handle_run_backtest(self, strategy_id),
+            Message::BacktestComplete(result) => handle_backtest_complete(self, result),
+            Message::PreviewStrategySignals(strategy_id) => handle_preview_strategy_signals(self, strategy_id),
+            Message::StrategySignalsPreviewed(result) => handle_strategy_signals_previewed(self, result),
+            
+            // === Mode replay ===
+            Message::ReplayStart => handle_replay_start(self),
+            Message::ReplayStep => handle_replay_step(self),
+            Message::ReplayPlay => handle_replay_play(self),
+            Message::ReplaySetSpeed(speed_ms) => handle_replay_set_speed(self, speed_ms),
+            Message::ReplayStop => handle_replay_stop(self),
+            
+            // === Gestion de la liste des dessins ===
+            Message::ToggleDrawingLocked { kind, index } => handle_toggle_drawing_locked(self, kind, index),
+            Message::ToggleDrawingHidden { kind, index } => handle_toggle_drawing_hidden(self, kind, index),
+            Message::DeleteDrawing { kind, index } => handle_delete_drawing(self, kind, index),
+            Message::SelectDrawing { kind, index } => handle_select_drawing(self, kind, index),
+            Message::StartRenameDrawing { kind, index } => handle_start_rename_drawing(self, kind, index),
+            Message::UpdateDrawingNameDraft(content) => handle_update_drawing_name_draft(self, content),
+            Message::CommitDrawingName => handle_commit_drawing_name(self),
+            Message::CancelDrawingRename => handle_cancel_drawing_rename(self),
+            Message::SetDrawingColor { kind, index, color } => handle_set_drawing_color(self, kind, index, color),
             
             // === Gestion des erreurs ===
             Message::ShowError(error) => handle_show_error(self, error),
@@ -541,7 +743,7 @@ impl ChartApp {
     }
     
     /// Applique les résultats de la complétion des gaps
-    pub fn apply_complete_gaps_results(&mut self, results: Vec<(SeriesId, String, (i64, i64), Result<Vec<Candle>, String>)>) -> Task<Message> {
+    pub fn apply_complete_gaps_results(&mut self, results: Vec<crate::app::realtime::GapFillResult>) -> Task<Message> {
         crate::app::realtime::apply_complete_gaps_results(self, results)
     }
     
@@ -551,10 +753,30 @@ impl ChartApp {
     }
     
     /// Applique les résultats des mises à jour en temps réel
-    pub fn apply_realtime_updates(&mut self, results: Vec<(SeriesId, String, Result<Option<Candle>, String>)>) {
+    ///
+    /// Retourne les identifiants des alertes de prix déclenchées.
+    pub fn apply_realtime_updates(&mut self, results: Vec<(SeriesId, String, Result<Option<Candle>, String>)>) -> Vec<u64> {
         crate::app::realtime::apply_realtime_updates(self, results)
     }
     
+    /// Applique la limite d'historique des paramètres à toutes les séries chargées
+    ///
+    /// # Retourne
+    /// `true` si au moins une série a été tronquée
+    pub fn apply_history_cap(&mut self) -> bool {
+        let Some(max_candles) = self.settings_state.max_candles_per_series else {
+            return false;
+        };
+        self.chart_state.apply_history_cap(max_candles)
+    }
+    
+    /// Provider WebSocket abonné aux séries temps réel, partageant le cache `ws_provider`
+    pub fn realtime_ws_provider(&self) -> BinanceWsProvider {
+        self.ws_provider.subscribed_to(
+            self.chart_state.series_manager.realtime_series().map(|s| s.id.clone())
+        )
+    }
+    
     /// Met à jour les informations du compte basées sur les trades
     pub fn update_account_info(&mut self) {
         let symbol = self.chart_state.series_manager
@@ -6,7 +6,7 @@
 use iced::Task;
 use crate::finance_chart::{
     load_all_from_directory, load_from_json, is_directory_empty, save_to_json,
-    BinanceProvider, core::{SeriesId, TimeSeries, SeriesData}
+    BinanceProvider, core::{SeriesId, TimeSeries, SeriesData}, data_loader::LoadError,
 };
 use crate::app::{utils::constants::DATA_FILE, messages::Message};
 use std::sync::Arc;
@@ -242,6 +242,16 @@ fn check_1month_series_exists(symbol: &str) -> bool {
     file_path_new.exists() || file_path_old.exists()
 }
 
+/// Charge les séries du dossier data
+///
+/// Avec la feature `sqlite`, chaque série est complétée par les bougies de la base.
+fn load_data_directory() -> Result<Vec<SeriesData>, LoadError> {
+    let series_list = load_all_from_directory("data")?;
+    #[cfg(feature = "sqlite")]
+    let series_list = series_list.into_iter().map(super::sqlite_store::sync_loaded_series).collect();
+    Ok(series_list)
+}
+
 /// Crée une Task pour charger les séries de manière asynchrone
 /// Si le dossier data est vide, télécharge automatiquement les séries BTCUSDT
 /// Sinon, vérifie et télécharge les séries 1M si elles n'existent pas
@@ -268,7 +278,7 @@ pub fn create_load_series_task(provider: Arc<BinanceProvider>) -> Task<Message>
                         println!("✅ {} série(s) téléchargée(s) et sauvegardée(s)", series_list.len());
                         // Recharger depuis le dossier maintenant qu'il contient des fichiers
                         tokio::task::spawn_blocking(move || {
-                            match load_all_from_directory("data") {
+                            match load_data_directory() {
                                 Ok(series_list) => {
                                     println!("✅ {} série(s) chargée(s) depuis le dossier data", series_list.len());
                                     Ok(series_list)
@@ -290,7 +300,7 @@ pub fn create_load_series_task(provider: Arc<BinanceProvider>) -> Task<Message>
             } else {
                 // Le dossier n'est pas vide, charger normalement
                 let series_list_result: Result<Vec<SeriesData>, String> = tokio::task::spawn_blocking(move || {
-                    load_all_from_directory("data")
+                    load_data_directory()
                         .map_err(|e| format!("Erreur: {}", e))
                 })
                 .await
@@ -5,9 +5,11 @@
 pub mod data_loading;
 mod download_manager;
 mod trade_history;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_store;
 
 pub use download_manager::DownloadManager;
-pub use trade_history::{TradeHistory, Trade, TradeType, Position, OrderType, PendingOrder};
+pub use trade_history::{TradeHistory, Trade, TradeType, ClosedTrade, Position, OrderType, PendingOrder};
 
 
 
@@ -0,0 +1,100 @@
+//! Stockage des séries dans la base SQLite (feature `sqlite`)
+//!
+//! La base remplace les fichiers JSON pour les sauvegardes : les bougies sont
+//! insérées par upsert, l'historique tronqué en mémoire reste donc en base.
+//! Au chargement, les séries JSON sont complétées par les bougies stockées, et
+//! le début de l'historique tronqué est rechargé par pages quand la vue l'atteint.
+
+use iced::Task;
+use crate::finance_chart::{
+    core::{Candle, SeriesData, SeriesId},
+    core::resample::parse_interval,
+    data_loader::sqlite::{load_from_sqlite, load_range_from_sqlite, save_to_sqlite, sqlite_time_bounds},
+    data_loader::SaveError,
+};
+use crate::app::{
+    app_state::ChartApp,
+    messages::Message,
+    utils::constants::SQLITE_DB_FILE,
+};
+
+/// Nombre de bougies chargées par page quand la vue remonte dans l'historique
+const PAGE_CANDLES: i64 = 500;
+
+/// Complète une série chargée depuis JSON avec les bougies de la base
+///
+/// Une série absente de la base y est importée, sinon les bougies stockées
+/// (dont celles sauvegardées en temps réel) sont fusionnées dans la série.
+pub fn sync_loaded_series(mut series: SeriesData) -> SeriesData {
+    match sqlite_time_bounds(SQLITE_DB_FILE, &series.symbol, &series.interval) {
+        Ok(Some(_)) => match load_from_sqlite(SQLITE_DB_FILE, &series.symbol, &series.interval) {
+            Ok(stored) => {
+                series.data.merge_candles(stored.data.all_candles().to_vec());
+            }
+            Err(e) => eprintln!("⚠️ Lecture SQLite de {} impossible: {}", series.full_name(), e),
+        },
+        Ok(None) => {
+            if let Err(e) = save_to_sqlite(&series, SQLITE_DB_FILE) {
+                eprintln!("⚠️ Import SQLite de {} impossible: {}", series.full_name(), e);
+            }
+        }
+        Err(e) => eprintln!("⚠️ Base SQLite indisponible: {}", e),
+    }
+    series
+}
+
+/// Sauvegarde une série dans la base
+pub fn save_series(series: &SeriesData) -> Result<(), SaveError> {
+    save_to_sqlite(series, SQLITE_DB_FILE)
+}
+
+/// Charge la page de bougies précédant l'historique en mémoire de la série active
+///
+/// Sans effet tant que la vue n'a pas atteint le début de l'historique tronqué.
+/// La limite d'historique s'applique de nouveau à l'arrivée des bougies suivantes.
+pub fn load_previous_page(app: &ChartApp) -> Task<Message> {
+    let Some(series) = app.chart_state.series_manager.active_series().next() else {
+        return Task::none();
+    };
+    let Some(first) = series.data.min_timestamp().filter(|_| series.history_trimmed) else {
+        return Task::none();
+    };
+    let (view_start, _) = app.chart_state.viewport.time_scale().time_range();
+    if view_start > first {
+        return Task::none();
+    }
+    let Some(interval) = parse_interval(&series.interval).map(|span| span.approx_seconds()) else {
+        return Task::none();
+    };
+
+    let series_id = series.id.clone();
+    let symbol = series.symbol.clone();
+    let interval_name = series.interval.clone();
+    let time_range = (first - PAGE_CANDLES * interval)..first;
+    Task::perform(
+        async move {
+            tokio::task::spawn_blocking(move || {
+                load_range_from_sqlite(SQLITE_DB_FILE, &symbol, &interval_name, time_range)
+                    .map(|page| page.data.all_candles().to_vec())
+                    .map_err(|e| e.to_string())
+            })
+            .await
+            .unwrap_or_else(|e| Err(format!("Erreur de thread: {}", e)))
+        },
+        move |result| Message::SqlitePageLoaded(series_id, result),
+    )
+}
+
+/// Fusionne une page d'historique chargée depuis la base
+pub fn handle_page_loaded(app: &mut ChartApp, series_id: SeriesId, result: Result<Vec<Candle>, String>) -> Task<Message> {
+    match result {
+        Ok(candles) if !candles.is_empty() => {
+            let count = candles.len();
+            app.chart_state.merge_candles(&series_id, candles);
+            println!("📜 {}: {} bougies plus anciennes rechargées depuis SQLite", series_id.name, count);
+        }
+        Ok(_) => {}
+        Err(e) => eprintln!("⚠️ Chargement de l'historique SQLite impossible: {}", e),
+    }
+    Task::none()
+}
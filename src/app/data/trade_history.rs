@@ -1,6 +1,7 @@
 //! Historique des trades et gestion des positions
 
 use serde::{Deserialize, Serialize};
+use crate::finance_chart::core::Candle;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Type de trade
@@ -38,6 +39,25 @@ pub struct PendingOrder {
     pub stop_loss: Option<f64>,
     /// Timestamp de création
     pub created_timestamp: i64,
+    /// Groupe OCO : l'exécution d'un ordre annule les autres ordres du même groupe
+    #[serde(default)]
+    pub oco_group: Option<u64>,
+    /// Ordre stop : déclenché quand le prix franchit `limit_price` dans le sens
+    /// inverse d'un ordre limit (achat si prix >= niveau, vente si prix <= niveau)
+    #[serde(default)]
+    pub is_stop: bool,
+}
+
+impl PendingOrder {
+    /// Indique si l'ordre doit être exécuté au prix donné
+    pub fn is_triggered(&self, current_price: f64) -> bool {
+        match (self.trade_type, self.is_stop) {
+            (TradeType::Buy, false) => current_price <= self.limit_price,
+            (TradeType::Sell, false) => current_price >= self.limit_price,
+            (TradeType::Buy, true) => current_price >= self.limit_price,
+            (TradeType::Sell, true) => current_price <= self.limit_price,
+        }
+    }
 }
 
 /// Position ouverte
@@ -57,6 +77,12 @@ pub struct Position {
     pub take_profit: Option<f64>,
     /// Stop Loss (optionnel)
     pub stop_loss: Option<f64>,
+    /// Écart du trailing stop par rapport au prix (None si pas de trailing stop)
+    #[serde(default)]
+    pub trailing_offset: Option<f64>,
+    /// L'écart du trailing stop est un pourcentage du prix plutôt qu'un montant fixe
+    #[serde(default)]
+    pub trailing_percent: bool,
 }
 
 impl Position {
@@ -72,6 +98,35 @@ impl Position {
     pub fn margin_used(&self) -> f64 {
         self.entry_price * self.quantity
     }
+    
+    /// Fait suivre le stop loss au prix si un trailing stop est actif
+    ///
+    /// Le stop ne se déplace que dans le sens favorable (vers le haut pour un
+    /// long, vers le bas pour un short) et n'est jamais desserré.
+    /// Retourne true si le stop a été déplacé.
+    pub fn update_trailing_stop(&mut self, current_price: f64) -> bool {
+        let Some(offset) = self.trailing_offset else {
+            return false;
+        };
+        let distance = if self.trailing_percent {
+            current_price * offset / 100.0
+        } else {
+            offset
+        };
+        let candidate = match self.trade_type {
+            TradeType::Buy => current_price - distance,
+            TradeType::Sell => current_price + distance,
+        };
+        let tighter = match (self.trade_type, self.stop_loss) {
+            (_, None) => true,
+            (TradeType::Buy, Some(stop)) => candidate > stop,
+            (TradeType::Sell, Some(stop)) => candidate < stop,
+        };
+        if tighter {
+            self.stop_loss = Some(candidate);
+        }
+        tighter
+    }
 }
 
 /// Trade exécuté (historique)
@@ -101,6 +156,23 @@ pub struct Trade {
     pub strategy_name: Option<String>,
 }
 
+/// Position clôturée : entrée et sortie reconstituées depuis l'historique des trades
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClosedTrade {
+    /// Sens de la position (Buy = long, Sell = short)
+    pub trade_type: TradeType,
+    /// Quantité clôturée
+    pub quantity: f64,
+    /// Timestamp et prix d'entrée
+    pub entry_timestamp: i64,
+    pub entry_price: f64,
+    /// Timestamp et prix de sortie
+    pub exit_timestamp: i64,
+    pub exit_price: f64,
+    /// P&L réalisé sur la quantité clôturée
+    pub pnl: f64,
+}
+
 /// Gestionnaire de l'historique des trades et des positions
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradeHistory {
@@ -177,6 +249,8 @@ impl TradeHistory {
             trade_type: TradeType::Buy,
             take_profit,
             stop_loss,
+            trailing_offset: None,
+            trailing_percent: false,
         };
         
         self.open_positions.push(position);
@@ -289,6 +363,8 @@ impl TradeHistory {
             trade_type: TradeType::Sell,
             take_profit,
             stop_loss,
+            trailing_offset: None,
+            trailing_percent: false,
         };
         
         self.open_positions.push(position);
@@ -405,6 +481,117 @@ impl TradeHistory {
         self.trades.iter().map(|t| t.realized_pnl).sum()
     }
     
+    /// Calcule la courbe d'equity sur les bougies données
+    ///
+    /// Les trades sont rejoués dans l'ordre chronologique : un trade ferme la
+    /// position opposée du même symbole si elle existe, sinon il ouvre une
+    /// position. Pour chaque bougie, l'equity vaut le capital de départ, plus le
+    /// P&L réalisé des trades exécutés jusqu'à cette bougie, plus le P&L latent
+    /// des positions encore ouvertes valorisées à la clôture de la bougie.
+    /// Seuls les trades de `symbol` sont rejoués : `candles` doit être sa série.
+    pub fn equity_curve(&self, symbol: &str, candles: &[Candle], starting_balance: f64) -> Vec<(i64, f64)> {
+        let mut trades: Vec<&Trade> = self.trades.iter().filter(|t| t.symbol == symbol).collect();
+        trades.sort_by_key(|t| (t.timestamp, t.id));
+
+        let mut positions: Vec<Position> = Vec::new();
+        let mut realized = 0.0;
+        let mut next_trade = 0;
+        let mut curve = Vec::with_capacity(candles.len());
+
+        for candle in candles {
+            while let Some(trade) = trades.get(next_trade).filter(|t| t.timestamp <= candle.timestamp) {
+                realized += trade.realized_pnl;
+                let opposite = positions.iter()
+                    .position(|p| p.symbol == trade.symbol && p.trade_type != trade.trade_type);
+                match opposite {
+                    Some(index) if trade.quantity >= positions[index].quantity => {
+                        positions.remove(index);
+                    }
+                    Some(index) => positions[index].quantity -= trade.quantity,
+                    None => positions.push(Position {
+                        symbol: trade.symbol.clone(),
+                        quantity: trade.quantity,
+                        entry_price: trade.price,
+                        open_timestamp: trade.timestamp,
+                        trade_type: trade.trade_type,
+                        take_profit: None,
+                        stop_loss: None,
+                        trailing_offset: None,
+                        trailing_percent: false,
+                    }),
+                }
+                next_trade += 1;
+            }
+
+            let unrealized: f64 = positions.iter().map(|p| p.unrealized_pnl(candle.close)).sum();
+            curve.push((candle.timestamp, starting_balance + realized + unrealized));
+        }
+
+        curve
+    }
+    
+    /// Positions clôturées d'un symbole, dans l'ordre de leur sortie
+    ///
+    /// Les trades sont rejoués comme pour `equity_curve` : un trade ferme la
+    /// position opposée la plus ancienne si elle existe, sinon il en ouvre une.
+    /// Une clôture partielle donne une position clôturée de la quantité sortie.
+    pub fn closed_trades(&self, symbol: &str) -> Vec<ClosedTrade> {
+        let mut trades: Vec<&Trade> = self.trades.iter().filter(|t| t.symbol == symbol).collect();
+        trades.sort_by_key(|t| (t.timestamp, t.id));
+
+        // Entrées encore ouvertes : (trade d'entrée, quantité restante)
+        let mut open: Vec<(&Trade, f64)> = Vec::new();
+        let mut closed = Vec::new();
+        for trade in trades {
+            let Some(index) = open.iter().position(|(entry, _)| entry.trade_type != trade.trade_type) else {
+                open.push((trade, trade.quantity));
+                continue;
+            };
+            let (entry, remaining) = open[index];
+            let quantity = trade.quantity.min(remaining);
+            let direction = match entry.trade_type {
+                TradeType::Buy => 1.0,
+                TradeType::Sell => -1.0,
+            };
+            closed.push(ClosedTrade {
+                trade_type: entry.trade_type,
+                quantity,
+                entry_timestamp: entry.timestamp,
+                entry_price: entry.price,
+                exit_timestamp: trade.timestamp,
+                exit_price: trade.price,
+                pnl: (trade.price - entry.price) * quantity * direction,
+            });
+            if trade.quantity >= remaining {
+                open.remove(index);
+            } else {
+                open[index].1 -= trade.quantity;
+            }
+        }
+        closed
+    }
+    
+    /// Active un trailing stop sur la dernière position ouverte du symbole
+    ///
+    /// Le stop initial est placé immédiatement à partir de `current_price`.
+    /// Retourne false si aucune position n'est ouverte sur ce symbole.
+    pub fn set_trailing_stop(&mut self, symbol: &str, offset: f64, percent: bool, current_price: f64) -> bool {
+        let Some(position) = self.open_positions.iter_mut().rev().find(|p| p.symbol == symbol) else {
+            return false;
+        };
+        position.trailing_offset = Some(offset);
+        position.trailing_percent = percent;
+        position.update_trailing_stop(current_price);
+        true
+    }
+    
+    /// Met à jour les trailing stops des positions d'un symbole avec le prix actuel
+    pub fn update_trailing_stops(&mut self, symbol: &str, current_price: f64) {
+        for position in self.open_positions.iter_mut().filter(|p| p.symbol == symbol) {
+            position.update_trailing_stop(current_price);
+        }
+    }
+    
     /// Crée un ordre limit en attente
     pub fn create_pending_order(
         &mut self,
@@ -432,6 +619,8 @@ impl TradeHistory {
             take_profit,
             stop_loss,
             created_timestamp: timestamp,
+            oco_group: None,
+            is_stop: false,
         };
         
         self.next_order_id += 1;
@@ -440,30 +629,88 @@ impl TradeHistory {
         order
     }
     
+    /// Crée un ordre OCO : un ordre limit de take profit et un ordre stop liés
+    ///
+    /// `trade_type` est le sens des deux ordres (Sell pour sortir d'un long,
+    /// Buy pour sortir d'un short). L'exécution de l'un annule l'autre.
+    /// Retourne (take profit, stop).
+    pub fn create_oco_order(
+        &mut self,
+        symbol: String,
+        trade_type: TradeType,
+        quantity: f64,
+        take_profit_price: f64,
+        stop_price: f64,
+    ) -> Result<(PendingOrder, PendingOrder), String> {
+        if quantity <= 0.0 {
+            return Err("La quantité doit être positive".to_string());
+        }
+        let valid_levels = match trade_type {
+            TradeType::Sell => take_profit_price > stop_price,
+            TradeType::Buy => take_profit_price < stop_price,
+        };
+        if !valid_levels {
+            return Err(format!(
+                "Niveaux OCO incohérents: TP {:.2} / stop {:.2}",
+                take_profit_price, stop_price
+            ));
+        }
+        
+        let group = self.next_order_id;
+        let mut create_leg = |price: f64, is_stop: bool| {
+            self.create_pending_order(symbol.clone(), trade_type, quantity, price, None, None);
+            let order = self.pending_orders.last_mut().expect("ordre tout juste ajouté");
+            order.oco_group = Some(group);
+            order.is_stop = is_stop;
+            order.clone()
+        };
+        let take_profit = create_leg(take_profit_price, false);
+        let stop = create_leg(stop_price, true);
+        
+        Ok((take_profit, stop))
+    }
+    
     /// Vérifie et exécute les ordres limit si le prix correspond
     pub fn check_and_execute_pending_orders(&mut self, symbol: &str, current_price: f64, timestamp: Option<i64>) {
+        // Faire suivre les trailing stops avant la vérification des TP/SL
+        self.update_trailing_stops(symbol, current_price);
+        
         let mut orders_to_execute = Vec::new();
+        let mut filled_groups = Vec::new();
         
-        // Trouver les ordres qui peuvent être exécutés
-        for (index, order) in self.pending_orders.iter().enumerate() {
-            if order.symbol != symbol {
+        // Trouver les ordres qui peuvent être exécutés (un seul par groupe OCO)
+        for order in self.pending_orders.iter() {
+            if order.symbol != symbol || !order.is_triggered(current_price) {
                 continue;
             }
-            
-            let should_execute = match order.trade_type {
-                TradeType::Buy => current_price <= order.limit_price, // Achat: prix actuel <= prix limit
-                TradeType::Sell => current_price >= order.limit_price, // Vente: prix actuel >= prix limit
-            };
-            
-            if should_execute {
-                orders_to_execute.push((index, order.clone()));
+            if let Some(group) = order.oco_group {
+                if filled_groups.contains(&group) {
+                    continue;
+                }
+                filled_groups.push(group);
             }
+            orders_to_execute.push(order.clone());
         }
         
-        // Exécuter les ordres (en ordre inverse pour éviter les problèmes d'index)
-        for (index, order) in orders_to_execute.iter().rev() {
-            // Retirer l'ordre de la liste
-            self.pending_orders.remove(*index);
+        // Retirer les ordres exécutés et annuler les ordres liés de leur groupe OCO
+        self.pending_orders.retain(|o| {
+            !orders_to_execute.iter().any(|e| e.id == o.id)
+                && !o.oco_group.is_some_and(|g| filled_groups.contains(&g))
+        });
+        
+        for order in orders_to_execute.iter() {
+            // Les ordres OCO sont des ordres de sortie : ils ne font que fermer une position
+            if order.oco_group.is_some() {
+                match order.trade_type {
+                    TradeType::Sell => self.close_position_with_strategy(
+                        &order.symbol, order.quantity, order.limit_price, None, None, timestamp
+                    ),
+                    TradeType::Buy => self.close_short_position_with_strategy(
+                        &order.symbol, order.quantity, order.limit_price, None, None, timestamp
+                    ),
+                };
+                continue;
+            }
             
             // Exécuter l'ordre
             match order.trade_type {
@@ -559,3 +806,135 @@ impl TradeHistory {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candles(closes: &[f64]) -> Vec<Candle> {
+        closes.iter().enumerate()
+            .map(|(i, &c)| Candle::new(i as i64 * 60, c, c, c, c, 1.0))
+            .collect()
+    }
+
+    #[test]
+    fn test_equity_curve_marks_open_position() {
+        let mut history = TradeHistory::new();
+        history.open_buy_position_with_tp_sl_and_strategy(
+            "TEST".to_string(), 2.0, 100.0, None, None, None, None, Some(60),
+        );
+        history.close_position_with_strategy("TEST", 2.0, 110.0, None, None, Some(180));
+        // Un autre symbole n'entre pas dans la courbe
+        history.open_buy_position_with_tp_sl_and_strategy(
+            "OTHER".to_string(), 1.0, 10.0, None, None, None, None, Some(0),
+        );
+
+        let curve = history.equity_curve("TEST", &candles(&[95.0, 100.0, 105.0, 110.0, 120.0]), 1000.0);
+        let equity: Vec<f64> = curve.iter().map(|&(_, e)| e).collect();
+        // Avant l'entrée, latent à 105, clôture à 110, puis plus d'exposition
+        assert_eq!(equity, vec![1000.0, 1000.0, 1010.0, 1020.0, 1020.0]);
+        assert_eq!(curve[4].0, 240);
+    }
+
+    #[test]
+    fn test_closed_trades_pair_entries_and_exits() {
+        let mut history = TradeHistory::new();
+        history.open_buy_position_with_tp_sl_and_strategy(
+            "TEST".to_string(), 2.0, 100.0, None, None, None, None, Some(60),
+        );
+        history.close_position_with_strategy("TEST", 1.0, 110.0, None, None, Some(120));
+        history.close_position_with_strategy("TEST", 1.0, 90.0, None, None, Some(180));
+        history.open_sell_position_with_tp_sl_and_strategy(
+            "TEST".to_string(), 1.0, 95.0, None, None, None, None, Some(240),
+        );
+        history.open_buy_position_with_tp_sl_and_strategy(
+            "OTHER".to_string(), 1.0, 10.0, None, None, None, None, Some(60),
+        );
+
+        let closed = history.closed_trades("TEST");
+        // Deux sorties partielles du long ; le short est toujours ouvert
+        assert_eq!(closed.len(), 2);
+        assert_eq!((closed[0].entry_timestamp, closed[0].exit_timestamp), (60, 120));
+        assert_eq!(closed[0].pnl, 10.0);
+        assert_eq!((closed[1].exit_price, closed[1].pnl), (90.0, -10.0));
+        assert!(history.closed_trades("OTHER").is_empty());
+    }
+
+    #[test]
+    fn test_oco_fill_cancels_sibling() {
+        let mut history = TradeHistory::new();
+        history.open_buy_position_with_tp_sl_and_strategy(
+            "TEST".to_string(), 1.0, 100.0, None, None, None, None, Some(0),
+        );
+        let (tp, stop) = history.create_oco_order("TEST".to_string(), TradeType::Sell, 1.0, 110.0, 95.0).unwrap();
+        assert_eq!(tp.oco_group, stop.oco_group);
+        assert!(stop.is_stop && !tp.is_stop);
+
+        // Ni le TP ni le stop ne sont atteints
+        history.check_and_execute_pending_orders("TEST", 100.0, Some(60));
+        assert_eq!(history.pending_orders.len(), 2);
+
+        // Le stop est touché : la position est fermée et le TP annulé
+        history.check_and_execute_pending_orders("TEST", 94.0, Some(120));
+        assert!(history.pending_orders.is_empty());
+        assert!(history.open_positions.is_empty());
+        assert_eq!(history.trades.last().unwrap().price, 95.0);
+        assert_eq!(history.total_realized_pnl(), -5.0);
+    }
+
+    #[test]
+    fn test_oco_rejects_inconsistent_levels() {
+        let mut history = TradeHistory::new();
+        assert!(history.create_oco_order("TEST".to_string(), TradeType::Sell, 1.0, 90.0, 95.0).is_err());
+        assert!(history.create_oco_order("TEST".to_string(), TradeType::Buy, 1.0, 110.0, 105.0).is_err());
+        assert!(history.pending_orders.is_empty());
+    }
+
+    #[test]
+    fn test_trailing_stop_only_tightens() {
+        let mut history = TradeHistory::new();
+        history.open_buy_position_with_tp_sl_and_strategy(
+            "TEST".to_string(), 1.0, 100.0, None, Some(90.0), None, None, Some(0),
+        );
+        assert!(history.set_trailing_stop("TEST", 5.0, false, 100.0));
+        assert_eq!(history.open_positions[0].stop_loss, Some(95.0));
+
+        // Nouveau plus haut : le stop monte
+        history.update_trailing_stops("TEST", 110.0);
+        assert_eq!(history.open_positions[0].stop_loss, Some(105.0));
+
+        // Recul du prix : le stop ne redescend pas
+        history.update_trailing_stops("TEST", 107.0);
+        assert_eq!(history.open_positions[0].stop_loss, Some(105.0));
+
+        // Le stop suivi est déclenché par la vérification des TP/SL
+        history.check_take_profit_stop_loss("TEST", 104.0, Some(60));
+        assert!(history.open_positions.is_empty());
+        assert_eq!(history.total_realized_pnl(), 4.0);
+    }
+
+    #[test]
+    fn test_trailing_stop_percent_short() {
+        let mut history = TradeHistory::new();
+        history.open_sell_position_with_tp_sl_and_strategy(
+            "TEST".to_string(), 1.0, 200.0, None, None, None, None, Some(0),
+        );
+        history.set_trailing_stop("TEST", 10.0, true, 200.0);
+        assert_eq!(history.open_positions[0].stop_loss, Some(220.0));
+
+        history.update_trailing_stops("TEST", 150.0);
+        assert_eq!(history.open_positions[0].stop_loss, Some(165.0));
+        history.update_trailing_stops("TEST", 160.0);
+        assert_eq!(history.open_positions[0].stop_loss, Some(165.0));
+    }
+
+    #[test]
+    fn test_equity_curve_short_opened_before_window() {
+        let mut history = TradeHistory::new();
+        history.open_sell_position_with_tp_sl_and_strategy(
+            "TEST".to_string(), 1.0, 100.0, None, None, None, None, Some(-60),
+        );
+
+        let curve = history.equity_curve("TEST", &candles(&[90.0, 105.0]), 1000.0);
+        assert_eq!(curve, vec![(0, 1010.0), (60, 995.0)]);
+    }
+}
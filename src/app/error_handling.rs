@@ -292,6 +292,22 @@ fn should_not_retry(error: &AppError) -> bool {
     }
 }
 
+/// Conversion utilisée par `retry_with_backoff` : les erreurs client 4xx (hors
+/// limite de débit) sont classées en validation pour ne pas être retentées
+impl From<ProviderError> for AppError {
+    fn from(err: ProviderError) -> Self {
+        let client_error = matches!(
+            err,
+            ProviderError::Api { status: Some(status), .. } if (400..500).contains(&status)
+        ) && !err.is_rate_limited();
+        let mut app_error = AppError::from_provider_error(err, "la requête");
+        if client_error {
+            app_error.error_type = ErrorType::Validation;
+        }
+        app_error
+    }
+}
+
 /// Helper pour convertir une String en AppError
 impl From<String> for AppError {
     fn from(msg: String) -> Self {
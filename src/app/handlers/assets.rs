@@ -112,6 +112,7 @@ pub fn handle_asset_series_created(
             
             // Ajouter la série à l'application
             app.chart_state.add_series(series.clone());
+            app.apply_history_cap();
             
             // Mémoriser le symbole sélectionné depuis le pick_list
             app.selected_asset_symbol = Some(symbol.clone());
@@ -29,6 +29,116 @@ fn save_backtest_trades(app: &ChartApp, strategy_id: &str) {
     }
 }
 
+/// Lance un backtest complet de la stratégie sur la série active
+///
+/// Le rejeu tourne dans un thread dédié pour ne pas bloquer l'interface ;
+/// le rapport arrive par `Message::BacktestComplete`.
+pub fn handle_run_backtest(app: &mut ChartApp, strategy_id: String) -> Task<Message> {
+    use crate::app::strategies::backtest::run_backtest;
+
+    let Some(reg) = app.strategy_manager.get_strategy(&strategy_id) else {
+        app.ui.notifications.add_warning(format!("Stratégie {} introuvable", strategy_id));
+        return Task::none();
+    };
+    let Some(series) = app.chart_state.series_manager.active_series().next() else {
+        app.ui.notifications.add_warning("Aucune série active pour le backtest".to_string());
+        return Task::none();
+    };
+
+    let strategy = reg.strategy.clone();
+    let series = series.clone();
+    let initial_capital = app.ui.backtest_state.initial_capital;
+    Task::perform(
+        async move {
+            tokio::task::spawn_blocking(move || run_backtest(strategy.as_ref(), &series, initial_capital))
+                .await
+                .map_err(|e| format!("Erreur de thread: {}", e))
+        },
+        Message::BacktestComplete,
+    )
+}
+
+/// Affiche le rapport d'un backtest terminé
+pub fn handle_backtest_complete(
+    app: &mut ChartApp,
+    result: Result<crate::app::strategies::backtest::BacktestReport, String>,
+) -> Task<Message> {
+    match result {
+        Ok(report) => {
+            println!(
+                "📊 Backtest {} sur {} ({}): {:.2}% ({} trades)",
+                report.strategy_name, report.symbol, report.interval, report.total_return_pct, report.trades.len()
+            );
+            app.ui.backtest_state.last_report = Some(report);
+        }
+        Err(e) => app.ui.notifications.add_warning(format!("Backtest interrompu: {}", e)),
+    }
+    Task::none()
+}
+
+/// Affiche les signaux d'une stratégie sur l'historique de la série active, sans exécuter d'ordre
+///
+/// Un second appel pour la même stratégie masque l'aperçu.
+pub fn handle_preview_strategy_signals(app: &mut ChartApp, strategy_id: String) -> Task<Message> {
+    use crate::app::data::TradeType;
+    use crate::app::state::backtest::SignalPreview;
+    use crate::app::strategies::strategy::TradingSignal;
+
+    if app.ui.backtest_state.signal_preview.as_ref().is_some_and(|p| p.strategy_id == strategy_id) {
+        app.ui.backtest_state.signal_preview = None;
+        return Task::none();
+    }
+
+    let Some(reg) = app.strategy_manager.get_strategy(&strategy_id) else {
+        app.ui.notifications.add_warning(format!("Stratégie {} introuvable", strategy_id));
+        return Task::none();
+    };
+    let Some(series) = app.chart_state.series_manager.active_series().next() else {
+        app.ui.notifications.add_warning("Aucune série active pour l'aperçu des signaux".to_string());
+        return Task::none();
+    };
+
+    // Évaluer la stratégie sur tout l'historique hors du thread de l'interface
+    let strategy = reg.strategy.clone();
+    let series = series.clone();
+    Task::perform(
+        async move {
+            tokio::task::spawn_blocking(move || {
+                let signals: Vec<(i64, TradeType)> = strategy
+                    .signals_over(&series)
+                    .into_iter()
+                    .filter_map(|(timestamp, signal)| match signal {
+                        TradingSignal::Buy { .. } => Some((timestamp, TradeType::Buy)),
+                        TradingSignal::Sell { .. } => Some((timestamp, TradeType::Sell)),
+                        TradingSignal::Hold => None,
+                    })
+                    .collect();
+                println!("🔎 Aperçu {} sur {}: {} signaux", strategy.name(), series.symbol, signals.len());
+                SignalPreview {
+                    strategy_id,
+                    series_id: series.id,
+                    signals,
+                }
+            })
+            .await
+            .map_err(|e| format!("Erreur de thread: {}", e))
+        },
+        Message::StrategySignalsPreviewed,
+    )
+}
+
+/// Affiche l'aperçu des signaux calculé par `PreviewStrategySignals`
+pub fn handle_strategy_signals_previewed(
+    app: &mut ChartApp,
+    result: Result<crate::app::state::backtest::SignalPreview, String>,
+) -> Task<Message> {
+    match result {
+        Ok(preview) => app.ui.backtest_state.signal_preview = Some(preview),
+        Err(e) => app.ui.notifications.add_warning(format!("Aperçu des signaux impossible: {}", e)),
+    }
+    Task::none()
+}
+
 /// Active ou désactive le mode backtest
 pub fn handle_toggle_backtest_enabled(app: &mut ChartApp) -> Task<Message> {
     let new_state = !app.ui.backtest_state.enabled;
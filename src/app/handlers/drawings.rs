@@ -0,0 +1,82 @@
+//! Handlers pour la liste des dessins (sélection, renommage, couleur, verrouillage, visibilité, suppression)
+
+use iced::{Color, Task};
+use crate::app::app_state::ChartApp;
+use crate::app::messages::Message;
+use crate::finance_chart::tools::{DrawingKind, DrawingRenameState};
+
+/// Verrouille ou déverrouille un dessin
+pub fn handle_toggle_drawing_locked(app: &mut ChartApp, kind: DrawingKind, index: usize) -> Task<Message> {
+    if app.tools_state.toggle_drawing_locked(kind, index).is_none() {
+        println!("⚠️ {} #{} introuvable", kind.label(), index);
+    }
+    Task::none()
+}
+
+/// Masque ou réaffiche un dessin
+pub fn handle_toggle_drawing_hidden(app: &mut ChartApp, kind: DrawingKind, index: usize) -> Task<Message> {
+    if app.tools_state.toggle_drawing_hidden(kind, index).is_none() {
+        println!("⚠️ {} #{} introuvable", kind.label(), index);
+    }
+    Task::none()
+}
+
+/// Supprime un dessin depuis la liste (annulable avec Ctrl+Z)
+pub fn handle_delete_drawing(app: &mut ChartApp, kind: DrawingKind, index: usize) -> Task<Message> {
+    if !app.tools_state.delete_drawing(kind, index) {
+        println!("⚠️ {} #{} introuvable", kind.label(), index);
+    }
+    Task::none()
+}
+
+/// Sélectionne un dessin depuis la liste (même sélection qu'un clic sur le graphique)
+pub fn handle_select_drawing(app: &mut ChartApp, kind: DrawingKind, index: usize) -> Task<Message> {
+    if !app.tools_state.select_drawing(kind, index) {
+        println!("⚠️ {} #{} non sélectionnable", kind.label(), index);
+    }
+    Task::none()
+}
+
+/// Ouvre le champ de renommage d'un dessin, pré-rempli avec son nom actuel
+pub fn handle_start_rename_drawing(app: &mut ChartApp, kind: DrawingKind, index: usize) -> Task<Message> {
+    let Some(name) = app.tools_state.drawing_name(kind, index) else {
+        println!("⚠️ {} #{} introuvable", kind.label(), index);
+        return Task::none();
+    };
+    app.tools_state.drawing_rename = Some(DrawingRenameState {
+        kind,
+        index,
+        content: name.unwrap_or_default().to_string(),
+    });
+    iced::widget::operation::focus(crate::app::views::DRAWING_NAME_INPUT_ID)
+}
+
+/// Met à jour le nom en cours de saisie
+pub fn handle_update_drawing_name_draft(app: &mut ChartApp, content: String) -> Task<Message> {
+    if let Some(rename) = app.tools_state.drawing_rename.as_mut() {
+        rename.content = content;
+    }
+    Task::none()
+}
+
+/// Valide le renommage (annulable avec Ctrl+Z)
+pub fn handle_commit_drawing_name(app: &mut ChartApp) -> Task<Message> {
+    if let Some(rename) = app.tools_state.drawing_rename.take() {
+        app.tools_state.rename_drawing(rename.kind, rename.index, &rename.content);
+    }
+    Task::none()
+}
+
+/// Abandonne le renommage en cours
+pub fn handle_cancel_drawing_rename(app: &mut ChartApp) -> Task<Message> {
+    app.tools_state.drawing_rename = None;
+    Task::none()
+}
+
+/// Change la couleur d'un dessin (annulable avec Ctrl+Z)
+pub fn handle_set_drawing_color(app: &mut ChartApp, kind: DrawingKind, index: usize, color: Color) -> Task<Message> {
+    if !app.tools_state.set_drawing_color(kind, index, color) {
+        println!("⚠️ {} #{} introuvable", kind.label(), index);
+    }
+    Task::none()
+}
@@ -5,7 +5,7 @@
 
 use crate::finance_chart::{
     ChartMessage,
-    tools::Action as HistoryAction,
+    tools::{Action as HistoryAction, DrawnText, TextEditState, HLinePriceEditState, RectangleLabelEditState},
 };
 use crate::app::app_state::ChartApp;
 
@@ -36,19 +36,78 @@ pub fn handle_chart_message(app: &mut ChartApp, msg: ChartMessage) {
         ChartMessage::EndPan => {
             // Ne pas terminer le pan si on est en train de drag la tête de lecture
             if !app.ui.backtest_state.dragging_playhead {
-                app.chart_state.end_pan();
+                app.chart_state.end_pan(app.settings_state.snap_pan_to_candle);
             }
         }
-        ChartMessage::ZoomHorizontal { factor } => {
-            app.chart_state.zoom(factor);
+        // Un clic sur une bougie termine le pan démarré par l'appui et la sélectionne
+        // (un second clic sur la bougie sélectionnée la désélectionne)
+        ChartMessage::CandleClicked { timestamp, .. } => {
+            if !app.ui.backtest_state.dragging_playhead {
+                app.chart_state.end_pan(app.settings_state.snap_pan_to_candle);
+            }
+            let selection = if app.chart_state.selected_candle == Some(timestamp) {
+                ChartMessage::DeselectCandle
+            } else {
+                ChartMessage::SelectCandle { timestamp }
+            };
+            handle_chart_message(app, selection);
+        }
+        ChartMessage::SelectCandle { timestamp } => {
+            app.chart_state.selected_candle = Some(timestamp);
+        }
+        ChartMessage::DeselectCandle => {
+            app.chart_state.selected_candle = None;
+        }
+        ChartMessage::ZoomHorizontal { factor, cursor } => {
+            let anchor = zoom_anchor(app, cursor);
+            app.chart_state.zoom(factor, anchor);
         }
-        ChartMessage::ZoomVertical { factor } => {
-            app.chart_state.zoom_vertical(factor);
+        ChartMessage::ZoomVertical { factor, cursor } => {
+            let anchor = zoom_anchor(app, cursor);
+            app.chart_state.zoom_vertical(factor, anchor);
         }
-        ChartMessage::ZoomBoth { factor } => {
-            app.chart_state.zoom_both(factor);
+        ChartMessage::ZoomBoth { factor, cursor } => {
+            let anchor = zoom_anchor(app, cursor);
+            app.chart_state.zoom_both(factor, anchor);
+        }
+        ChartMessage::FitToData => {
+            if app.chart_state.fit_to_data() {
+                app.render_version = app.render_version.wrapping_add(1);
+            }
+        }
+        ChartMessage::ResetView => {
+            // Un dessin démarré par le premier clic ne doit pas survivre au double-clic
+            if app.tools_state.drawing.is_drawing {
+                app.tools_state.drawing.cancel();
+            }
+            if app.chart_state.fit_to_data() {
+                app.render_version = app.render_version.wrapping_add(1);
+            }
+        }
+        ChartMessage::ToggleCrosshairMagnet => {
+            app.settings_state.crosshair_magnet = !app.settings_state.crosshair_magnet;
+        }
+        ChartMessage::StartZoomSelection { screen_x, screen_y, time, price } => {
+            app.tools_state.drawing.start_zoom_selection(screen_x, screen_y, time, price);
+        }
+        ChartMessage::ZoomToSelection { start, end } => {
+            app.tools_state.drawing.cancel();
+            if app.chart_state.zoom_to_rect((start.0, end.0), (start.1, end.1)) {
+                app.render_version = app.render_version.wrapping_add(1);
+            }
         }
         
+        // === Intervalle d'affichage ===
+        ChartMessage::SetDisplayInterval { interval } => {
+            if let Err(e) = app.chart_state.set_display_interval(interval) {
+                eprintln!("⚠️ Impossible de changer l'intervalle d'affichage: {}", e);
+            }
+        }
+        // Traité par `handle_switch_interval` (peut lancer un téléchargement)
+        ChartMessage::SwitchInterval(_) => {}
+        // Relayé au panel d'outils (`ToolsPanelMessage::SelectTool`)
+        ChartMessage::SelectTool { .. } => {}
+        
         // === Dessin de rectangles ===
         ChartMessage::StartDrawingRectangle { screen_x, screen_y, time, price } => {
             app.tools_state.drawing.start(screen_x, screen_y, time, price);
@@ -56,7 +115,13 @@ pub fn handle_chart_message(app: &mut ChartApp, msg: ChartMessage) {
         ChartMessage::UpdateDrawing { screen_x, screen_y } => {
             app.tools_state.drawing.update(screen_x, screen_y);
         }
-        ChartMessage::FinishDrawingRectangle { end_time, end_price } => {
+        ChartMessage::FinishDrawingRectangle { end_time, end_price, snap } => {
+            let (end_time, end_price) = if snap {
+                snap_drawing_start(app);
+                snap_point(app, end_time, end_price)
+            } else {
+                (end_time, end_price)
+            };
             if let Some(rect) = app.tools_state.drawing.finish(end_time, end_price) {
                 app.tools_state.history.record(HistoryAction::CreateRectangle { rect: rect.clone() });
                 let new_index = app.tools_state.rectangles.len();
@@ -70,7 +135,11 @@ pub fn handle_chart_message(app: &mut ChartApp, msg: ChartMessage) {
         ChartMessage::StartDrawingHLine { screen_y, price } => {
             app.tools_state.drawing.start(0.0, screen_y, 0, price);
         }
-        ChartMessage::FinishDrawingHLine => {
+        ChartMessage::FinishDrawingHLine { snap } => {
+            if snap {
+                app.tools_state.drawing.start_price = app.tools_state.drawing.start_price
+                    .map(|price| snap_price(app, price));
+            }
             if let Some(line) = app.tools_state.drawing.finish_hline() {
                 app.tools_state.history.record(HistoryAction::CreateHLine { line: line.clone() });
                 let new_index = app.tools_state.horizontal_lines.len();
@@ -79,6 +148,80 @@ pub fn handle_chart_message(app: &mut ChartApp, msg: ChartMessage) {
                 app.tools_state.selected_tool = None;
             }
         }
+        
+        // === Dessin de lignes verticales ===
+        ChartMessage::StartDrawingVLine { screen_x, time } => {
+            app.tools_state.drawing.start(screen_x, 0.0, time, 0.0);
+        }
+        ChartMessage::FinishDrawingVLine => {
+            if let Some(line) = app.tools_state.drawing.finish_vline() {
+                app.tools_state.history.record(HistoryAction::CreateVLine { line: line.clone() });
+                let new_index = app.tools_state.vertical_lines.len();
+                app.tools_state.vertical_lines.push(line);
+                app.tools_state.vline_editing.selected_index = Some(new_index);
+                app.tools_state.selected_tool = None;
+            }
+        }
+        
+        // === Mesure ===
+        ChartMessage::StartMeasure { screen_x, screen_y, time, price } => {
+            app.tools_state.drawing.start(screen_x, screen_y, time, price);
+        }
+        
+        // === Annotations texte ===
+        ChartMessage::StartEditingText { position, time, price, index } => {
+            let existing = index.and_then(|i| app.tools_state.texts.get(i));
+            let edit = match existing {
+                Some(text) => TextEditState {
+                    index,
+                    time: text.time,
+                    price: text.price,
+                    position: (position.x, position.y),
+                    content: text.content.clone(),
+                },
+                None => TextEditState {
+                    index: None,
+                    time,
+                    price,
+                    position: (position.x, position.y),
+                    content: String::new(),
+                },
+            };
+            app.tools_state.text_editing = Some(edit);
+        }
+        ChartMessage::UpdateTextDraft { content } => {
+            if let Some(edit) = app.tools_state.text_editing.as_mut() {
+                edit.content = content;
+            }
+        }
+        ChartMessage::CommitText { content } => {
+            commit_text(app, content);
+        }
+        ChartMessage::CancelText => {
+            app.tools_state.text_editing = None;
+        }
+        ChartMessage::SelectText { index } => {
+            if index < app.tools_state.texts.len() {
+                app.tools_state.selected_text = Some(index);
+            }
+        }
+        ChartMessage::DeselectText => {
+            app.tools_state.selected_text = None;
+        }
+        
+        // === Dessin de demi-droites ===
+        ChartMessage::StartDrawingRay { screen_x, screen_y, time, price } => {
+            app.tools_state.drawing.start(screen_x, screen_y, time, price);
+        }
+        ChartMessage::FinishDrawingRay { end_time, end_price } => {
+            if let Some(line) = app.tools_state.drawing.finish_trend_line(end_time, end_price, true) {
+                app.tools_state.history.record(HistoryAction::CreateTrendLine { line: line.clone() });
+                let new_index = app.tools_state.trend_lines.len();
+                app.tools_state.trend_lines.push(line);
+                app.tools_state.selected_trend_line = Some(new_index);
+                app.tools_state.selected_tool = None;
+            }
+        }
         ChartMessage::CancelDrawing => {
             app.tools_state.drawing.cancel();
         }
@@ -90,14 +233,26 @@ pub fn handle_chart_message(app: &mut ChartApp, msg: ChartMessage) {
                 app.tools_state.editing.start(index, mode, time, price, rect_clone);
             }
         }
-        ChartMessage::UpdateRectangleEdit { time, price } => {
+        ChartMessage::UpdateRectangleEdit { time, price, snap } => {
             // Note: Le clone est nécessaire pour éviter un conflit d'emprunt
             // (on emprunte editing en lecture et rectangles en écriture simultanément)
             if let Some(index) = app.tools_state.editing.selected_index {
                 if index < app.tools_state.rectangles.len() {
-                    use crate::finance_chart::interaction::apply_edit_update;
+                    use crate::finance_chart::interaction::{apply_edit_update, snap_rectangle_edit};
                     let edit_state = app.tools_state.editing.clone();
                     apply_edit_update(&mut app.tools_state.rectangles[index], &edit_state, time, price);
+                    if let (true, Some(mode)) = (snap, edit_state.edit_mode) {
+                        if let Some((_, candles)) = app.chart_state.visible_candles().first() {
+                            snap_rectangle_edit(
+                                &mut app.tools_state.rectangles[index],
+                                mode,
+                                time,
+                                price,
+                                candles,
+                                &app.chart_state.viewport,
+                            );
+                        }
+                    }
                 }
             }
         }
@@ -106,6 +261,36 @@ pub fn handle_chart_message(app: &mut ChartApp, msg: ChartMessage) {
         }
         ChartMessage::DeselectRectangle => {
             app.tools_state.editing.deselect();
+            app.tools_state.rectangle_label_editing = None;
+        }
+        ChartMessage::SetRectangleColor { index, color } => {
+            modify_rectangle(app, index, |rect| rect.color = iced::Color { a: rect.color.a, ..color });
+        }
+        ChartMessage::SetRectangleOpacity { index, opacity } => {
+            let opacity = opacity.clamp(0.0, crate::finance_chart::tools::DrawnRectangle::MAX_FILL_OPACITY);
+            modify_rectangle(app, index, |rect| rect.color.a = opacity);
+        }
+        ChartMessage::StartEditingRectangleLabel { index } => {
+            if let Some(rect) = app.tools_state.rectangles.get(index) {
+                app.tools_state.rectangle_label_editing = Some(RectangleLabelEditState {
+                    index,
+                    content: rect.label.clone().unwrap_or_default(),
+                });
+            }
+        }
+        ChartMessage::UpdateRectangleLabelDraft { content } => {
+            if let Some(edit) = app.tools_state.rectangle_label_editing.as_mut() {
+                edit.content = content;
+            }
+        }
+        ChartMessage::CommitRectangleLabel => {
+            if let Some(edit) = app.tools_state.rectangle_label_editing.take() {
+                let label = Some(edit.content.trim().to_string()).filter(|label| !label.is_empty());
+                modify_rectangle(app, edit.index, |rect| rect.label = label);
+            }
+        }
+        ChartMessage::CancelRectangleLabelEdit => {
+            app.tools_state.rectangle_label_editing = None;
         }
         
         // === Édition de lignes horizontales ===
@@ -115,13 +300,15 @@ pub fn handle_chart_message(app: &mut ChartApp, msg: ChartMessage) {
                 app.tools_state.hline_editing.start(index, price, line_clone);
             }
         }
-        ChartMessage::UpdateHLineEdit { price } => {
+        ChartMessage::UpdateHLineEdit { price, snap } => {
             if let Some(index) = app.tools_state.hline_editing.selected_index {
                 if index < app.tools_state.horizontal_lines.len() {
                     if let Some(ref original) = app.tools_state.hline_editing.original_line {
                         if let Some(start_price) = app.tools_state.hline_editing.start_price {
                             let delta = price - start_price;
-                            app.tools_state.horizontal_lines[index].price = original.price + delta;
+                            let new_price = original.price + delta;
+                            app.tools_state.horizontal_lines[index].price =
+                                if snap { snap_price(app, new_price) } else { new_price };
                         }
                     }
                 }
@@ -133,6 +320,67 @@ pub fn handle_chart_message(app: &mut ChartApp, msg: ChartMessage) {
         ChartMessage::DeselectHLine => {
             app.tools_state.hline_editing.deselect();
         }
+        ChartMessage::StartEditingHLinePrice { index, position } => {
+            if let Some(line) = app.tools_state.horizontal_lines.get(index) {
+                app.tools_state.hline_price_editing = Some(HLinePriceEditState {
+                    index,
+                    position: (position.x, position.y),
+                    content: crate::finance_chart::settings::format_price(line.price, &app.chart_style),
+                });
+                app.tools_state.hline_editing.selected_index = Some(index);
+            }
+        }
+        ChartMessage::UpdateHLinePriceDraft { content } => {
+            if let Some(edit) = app.tools_state.hline_price_editing.as_mut() {
+                edit.content = content;
+            }
+        }
+        ChartMessage::SetHLinePrice { index, price } => {
+            app.tools_state.hline_price_editing = None;
+            modify_hline(app, index, |line| line.price = price);
+        }
+        ChartMessage::CancelHLinePriceEdit => {
+            app.tools_state.hline_price_editing = None;
+        }
+        ChartMessage::SetHLineColor { index, color } => {
+            modify_hline(app, index, |line| line.color = color);
+        }
+        
+        // === Édition de lignes verticales ===
+        ChartMessage::StartVLineEdit { index, time } => {
+            if index < app.tools_state.vertical_lines.len() {
+                let line_clone = app.tools_state.vertical_lines[index].clone();
+                app.tools_state.vline_editing.start(index, time, line_clone);
+            }
+        }
+        ChartMessage::UpdateVLineEdit { time } => {
+            if let Some(index) = app.tools_state.vline_editing.selected_index {
+                if index < app.tools_state.vertical_lines.len() {
+                    if let Some(ref original) = app.tools_state.vline_editing.original_line {
+                        if let Some(start_time) = app.tools_state.vline_editing.start_time {
+                            let delta = time - start_time;
+                            app.tools_state.vertical_lines[index].time = original.time + delta;
+                        }
+                    }
+                }
+            }
+        }
+        ChartMessage::FinishVLineEdit => {
+            finish_vline_edit(app);
+        }
+        ChartMessage::DeselectVLine => {
+            app.tools_state.vline_editing.deselect();
+        }
+        
+        // === Sélection de lignes de tendance ===
+        ChartMessage::SelectTrendLine { index } => {
+            if index < app.tools_state.trend_lines.len() {
+                app.tools_state.selected_trend_line = Some(index);
+            }
+        }
+        ChartMessage::DeselectTrendLine => {
+            app.tools_state.selected_trend_line = None;
+        }
         
         // === Suppression ===
         ChartMessage::DeleteSelected => {
@@ -140,20 +388,38 @@ pub fn handle_chart_message(app: &mut ChartApp, msg: ChartMessage) {
         }
         
         // === Historique ===
+        ChartMessage::Undo if super::is_order_panel_focused(app) => {
+            super::handle_undo_trading_action(app, false);
+        }
+        ChartMessage::Redo if super::is_order_panel_focused(app) => {
+            super::handle_undo_trading_action(app, true);
+        }
         ChartMessage::Undo => {
             app.tools_state.editing.deselect();
             app.tools_state.hline_editing.deselect();
+            app.tools_state.vline_editing.deselect();
+            app.tools_state.selected_text = None;
+            app.tools_state.selected_trend_line = None;
             app.tools_state.history.undo(
                 &mut app.tools_state.rectangles,
                 &mut app.tools_state.horizontal_lines,
+                &mut app.tools_state.vertical_lines,
+                &mut app.tools_state.texts,
+                &mut app.tools_state.trend_lines,
             );
         }
         ChartMessage::Redo => {
             app.tools_state.editing.deselect();
             app.tools_state.hline_editing.deselect();
+            app.tools_state.vline_editing.deselect();
+            app.tools_state.selected_text = None;
+            app.tools_state.selected_trend_line = None;
             app.tools_state.history.redo(
                 &mut app.tools_state.rectangles,
                 &mut app.tools_state.horizontal_lines,
+                &mut app.tools_state.vertical_lines,
+                &mut app.tools_state.texts,
+                &mut app.tools_state.trend_lines,
             );
         }
         
@@ -176,6 +442,13 @@ pub fn handle_chart_message(app: &mut ChartApp, msg: ChartMessage) {
         // === Position souris ===
         ChartMessage::MouseMoved { position } => {
             app.chart_state.interaction.mouse_position = Some(position);
+            app.chart_state.hover_timestamp = Some(app.chart_state.viewport.time_scale().x_to_time(position.x));
+            let magnet = app.settings_state.crosshair_magnet
+                && app.chart_style.render_mode != crate::finance_chart::settings::ChartRenderMode::Renko;
+            app.chart_state.update_crosshair(position, magnet);
+        }
+        ChartMessage::IndicatorHovered { timestamp } => {
+            app.chart_state.hover_timestamp = Some(timestamp);
         }
         
         // === Clic droit ===
@@ -191,6 +464,15 @@ pub fn handle_chart_message(app: &mut ChartApp, msg: ChartMessage) {
             app.chart_state.interaction.set_main_chart_bounds(x, y, width, height);
         }
         
+        // === Renko ===
+        ChartMessage::SetRenkoBrickSize(size) => {
+            app.chart_state.renko_brick_size = if size > 0.0 && size.is_finite() {
+                Some(size)
+            } else {
+                None
+            };
+        }
+        
         // === Backtest ===
         // Note: Ce message n'est plus utilisé pour définir automatiquement la tête de lecture.
         // La tête de lecture est maintenant définie uniquement via le menu contextuel (bouton "Définir lecture").
@@ -202,6 +484,36 @@ pub fn handle_chart_message(app: &mut ChartApp, msg: ChartMessage) {
     }
 }
 
+/// Helper pour aimanter un point (temps, prix) sur les bougies visibles
+fn snap_point(app: &ChartApp, time: i64, price: f64) -> (i64, f64) {
+    use crate::finance_chart::interaction::snap_to_candle;
+    match app.chart_state.visible_candles().first() {
+        Some((_, candles)) => snap_to_candle(time, price, candles, &app.chart_state.viewport),
+        None => (time, price),
+    }
+}
+
+/// Helper pour résoudre le point fixe du zoom selon le réglage d'ancrage
+fn zoom_anchor(app: &ChartApp, cursor: Option<iced::Point>) -> iced::Point {
+    let viewport = &app.chart_state.viewport;
+    app.settings_state.zoom_anchor.resolve(cursor, viewport.width(), viewport.height())
+}
+
+/// Helper pour arrondir un prix au niveau "rond" le plus proche de la plage visible
+fn snap_price(app: &ChartApp, price: f64) -> f64 {
+    let (min_price, max_price) = app.chart_state.viewport.price_scale().price_range();
+    crate::finance_chart::scale::round_to_nice(price, max_price - min_price)
+}
+
+/// Helper pour aimanter le point de départ du dessin en cours
+fn snap_drawing_start(app: &mut ChartApp) {
+    if let (Some(time), Some(price)) = (app.tools_state.drawing.start_time, app.tools_state.drawing.start_price) {
+        let (time, price) = snap_point(app, time, price);
+        app.tools_state.drawing.start_time = Some(time);
+        app.tools_state.drawing.start_price = Some(price);
+    }
+}
+
 /// Helper pour finaliser l'édition d'un rectangle avec historique
 pub fn finish_rectangle_edit(app: &mut ChartApp) {
     if let (Some(idx), Some(old_rect)) = (
@@ -245,6 +557,89 @@ pub fn finish_hline_edit(app: &mut ChartApp) {
     app.tools_state.hline_editing.finish();
 }
 
+/// Helper pour modifier le style d'un rectangle avec historique
+fn modify_rectangle(app: &mut ChartApp, index: usize, change: impl FnOnce(&mut crate::finance_chart::tools::DrawnRectangle)) {
+    let Some(rect) = app.tools_state.rectangles.get_mut(index) else {
+        return;
+    };
+    let old_rect = rect.clone();
+    change(rect);
+    if *rect != old_rect {
+        let new_rect = rect.clone();
+        app.tools_state.history.record(HistoryAction::ModifyRectangle { index, old_rect, new_rect });
+    }
+}
+
+/// Helper pour modifier une ligne horizontale avec historique
+fn modify_hline(app: &mut ChartApp, index: usize, change: impl FnOnce(&mut crate::finance_chart::tools::DrawnHorizontalLine)) {
+    let Some(line) = app.tools_state.horizontal_lines.get_mut(index) else {
+        return;
+    };
+    let old_line = line.clone();
+    change(line);
+    if *line != old_line {
+        let new_line = line.clone();
+        app.tools_state.history.record(HistoryAction::ModifyHLine { index, old_line, new_line });
+    }
+}
+
+/// Helper pour finaliser l'édition d'une ligne verticale avec historique
+pub fn finish_vline_edit(app: &mut ChartApp) {
+    if let (Some(idx), Some(old_line)) = (
+        app.tools_state.vline_editing.selected_index,
+        app.tools_state.vline_editing.original_line.clone(),
+    ) {
+        if idx < app.tools_state.vertical_lines.len() {
+            let new_line = app.tools_state.vertical_lines[idx].clone();
+            if old_line.time != new_line.time {
+                app.tools_state.history.record(HistoryAction::ModifyVLine {
+                    index: idx,
+                    old_line,
+                    new_line,
+                });
+            }
+        }
+    }
+    app.tools_state.vline_editing.finish();
+}
+
+/// Helper pour valider la saisie d'une annotation texte avec historique
+///
+/// Un texte vide n'est pas enregistré (l'annotation existante reste inchangée).
+pub fn commit_text(app: &mut ChartApp, content: String) {
+    let Some(edit) = app.tools_state.text_editing.take() else {
+        return;
+    };
+    let content = content.trim().to_string();
+    if content.is_empty() {
+        return;
+    }
+    
+    match edit.index {
+        Some(idx) if idx < app.tools_state.texts.len() => {
+            let old_text = app.tools_state.texts[idx].clone();
+            if old_text.content != content {
+                app.tools_state.texts[idx].content = content;
+                let new_text = app.tools_state.texts[idx].clone();
+                app.tools_state.history.record(HistoryAction::ModifyText {
+                    index: idx,
+                    old_text,
+                    new_text,
+                });
+            }
+            app.tools_state.selected_text = Some(idx);
+        }
+        _ => {
+            let text = DrawnText::new(edit.time, edit.price, content);
+            app.tools_state.history.record(HistoryAction::CreateText { text: text.clone() });
+            let new_index = app.tools_state.texts.len();
+            app.tools_state.texts.push(text);
+            app.tools_state.selected_text = Some(new_index);
+            app.tools_state.selected_tool = None;
+        }
+    }
+}
+
 /// Helper pour supprimer un élément sélectionné avec historique
 pub fn delete_selected(app: &mut ChartApp) {
     // Supprimer rectangle sélectionné
@@ -271,8 +666,50 @@ pub fn delete_selected(app: &mut ChartApp) {
             });
             app.tools_state.horizontal_lines.remove(index);
             app.tools_state.hline_editing.deselect();
+            return;
         }
     }
+    
+    // Supprimer ligne verticale sélectionnée
+    if let Some(index) = app.tools_state.vline_editing.selected_index {
+        if index < app.tools_state.vertical_lines.len() {
+            let deleted_line = app.tools_state.vertical_lines[index].clone();
+            app.tools_state.history.record(HistoryAction::DeleteVLine { 
+                index, 
+                line: deleted_line 
+            });
+            app.tools_state.vertical_lines.remove(index);
+            app.tools_state.vline_editing.deselect();
+            return;
+        }
+    }
+    
+    // Supprimer annotation texte sélectionnée
+    if let Some(index) = app.tools_state.selected_text {
+        if index < app.tools_state.texts.len() {
+            let deleted_text = app.tools_state.texts[index].clone();
+            app.tools_state.history.record(HistoryAction::DeleteText { 
+                index, 
+                text: deleted_text 
+            });
+            app.tools_state.texts.remove(index);
+            app.tools_state.selected_text = None;
+            return;
+        }
+    }
+    
+    // Supprimer ligne de tendance sélectionnée
+    if let Some(index) = app.tools_state.selected_trend_line {
+        if index < app.tools_state.trend_lines.len() {
+            let deleted_line = app.tools_state.trend_lines[index].clone();
+            app.tools_state.history.record(HistoryAction::DeleteTrendLine { 
+                index, 
+                line: deleted_line 
+            });
+            app.tools_state.trend_lines.remove(index);
+        }
+        app.tools_state.selected_trend_line = None;
+    }
 }
 
 /// Réinitialise le zoom de la vue du graphique
@@ -281,12 +718,103 @@ pub fn handle_reset_view(app: &mut ChartApp) -> iced::Task<crate::app::messages:
     iced::Task::none()
 }
 
+/// Active ou désactive le mode comparaison (séries rebasées en pourcentage)
+pub fn handle_toggle_comparison_mode(app: &mut ChartApp) -> iced::Task<crate::app::messages::Message> {
+    app.ui.chart_context_menu = None;
+    app.chart_state.toggle_comparison_mode();
+    iced::Task::none()
+}
+
+/// Ramène la vue sur la dernière bougie
+pub fn handle_go_to_latest(app: &mut ChartApp) -> iced::Task<crate::app::messages::Message> {
+    app.chart_state.scroll_to_latest();
+    iced::Task::none()
+}
+
 /// Ouvre le menu contextuel du graphique
 pub fn handle_open_chart_context_menu(app: &mut ChartApp, position: iced::Point) -> iced::Task<crate::app::messages::Message> {
     app.ui.chart_context_menu = Some(position);
     iced::Task::none()
 }
 
+/// Exporte le graphique affiché en PNG dans le dossier exports/
+pub fn handle_export_chart_png(app: &mut ChartApp) -> iced::Task<crate::app::messages::Message> {
+    use crate::finance_chart::export::{render_to_png, export_file_name};
+
+    app.ui.chart_context_menu = None;
+
+    let viewport = &app.chart_state.viewport;
+    let size = iced::Size::new(viewport.width().round() as u32, viewport.height().round() as u32);
+    let symbol = app.chart_state.series_manager.active_series().next()
+        .map(|series| series.symbol.clone())
+        .unwrap_or_else(|| "chart".to_string());
+    let path = std::path::Path::new("exports")
+        .join(export_file_name(&symbol, chrono::Utc::now().timestamp()));
+
+    let result = render_to_png(&app.chart_state, &app.tools_state, &app.settings_state, &app.chart_style, size)
+        .and_then(|bytes| {
+            std::fs::create_dir_all("exports").map_err(|e| e.to_string())?;
+            std::fs::write(&path, bytes).map_err(|e| e.to_string())
+        });
+
+    match result {
+        Ok(()) => app.ui.notifications.add_success(format!("Graphique exporté: {}", path.display())),
+        Err(e) => {
+            use crate::app::error_handling::{AppError, ErrorType};
+            let error = AppError::new(
+                "Impossible d'exporter le graphique".to_string(),
+                e,
+                ErrorType::Unknown,
+            )
+            .with_source("Export PNG".to_string());
+            error.log();
+            app.ui.notifications.add_error(error);
+        }
+    }
+    iced::Task::none()
+}
+
+/// Exporte en CSV les bougies visibles de la série affichée dans le dossier exports/
+pub fn handle_export_visible_csv(app: &mut ChartApp) -> iced::Task<crate::app::messages::Message> {
+    use crate::finance_chart::data_loader::export_visible_to_csv;
+
+    app.ui.chart_context_menu = None;
+
+    let Some(series) = app.chart_state.displayed_series() else {
+        app.ui.notifications.add_warning("Aucune série à exporter".to_string());
+        return iced::Task::none();
+    };
+    let time_range = app.chart_state.viewport.time_scale().time_range();
+    let date = chrono::Utc::now().format("%Y%m%d_%H%M%S");
+    let name: String = format!("data_{}_{}_{}.csv", series.symbol, series.interval, date)
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '_' { c } else { '_' })
+        .collect();
+    let path = std::path::Path::new("exports").join(name);
+
+    let result = std::fs::create_dir_all("exports")
+        .map_err(|e| e.to_string())
+        .and_then(|_| export_visible_to_csv(series, time_range, &path).map_err(|e| e.to_string()));
+
+    match result {
+        Ok(count) => app.ui.notifications.add_success(format!(
+            "{} bougies exportées: {}", count, path.display()
+        )),
+        Err(e) => {
+            use crate::app::error_handling::{AppError, ErrorType};
+            let error = AppError::new(
+                "Impossible d'exporter les données".to_string(),
+                e,
+                ErrorType::Unknown,
+            )
+            .with_source("Export CSV".to_string());
+            error.log();
+            app.ui.notifications.add_error(error);
+        }
+    }
+    iced::Task::none()
+}
+
 /// Ferme le menu contextuel du graphique
 pub fn handle_close_chart_context_menu(app: &mut ChartApp) -> iced::Task<crate::app::messages::Message> {
     app.ui.chart_context_menu = None;
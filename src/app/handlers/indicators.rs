@@ -22,6 +22,30 @@ pub fn handle_toggle_macd_panel(app: &mut ChartApp) -> Task<crate::app::messages
     Task::none()
 }
 
+pub fn handle_toggle_atr_panel(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.ui.panels.atr.toggle_visibility();
+    app.save_panel_state();
+    Task::none()
+}
+
+pub fn handle_toggle_obv_panel(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.ui.panels.obv.toggle_visibility();
+    app.save_panel_state();
+    Task::none()
+}
+
+pub fn handle_toggle_cci_panel(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.ui.panels.cci.toggle_visibility();
+    app.save_panel_state();
+    Task::none()
+}
+
+pub fn handle_toggle_williams_r_panel(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.ui.panels.williams_r.toggle_visibility();
+    app.save_panel_state();
+    Task::none()
+}
+
 /// Gère le toggle des indicateurs
 pub fn handle_toggle_bollinger_bands(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
     app.indicators.bollinger_bands_enabled = !app.indicators.bollinger_bands_enabled;
@@ -30,10 +54,54 @@ pub fn handle_toggle_bollinger_bands(app: &mut ChartApp) -> Task<crate::app::mes
 
 pub fn handle_toggle_moving_average(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
     app.indicators.moving_average_enabled = !app.indicators.moving_average_enabled;
+    // Réactivation après suppression de toutes les lignes : repartir de la SMA 20
+    if app.indicators.moving_average_enabled && app.indicators.params.moving_averages.is_empty() {
+        app.indicators.params.moving_averages.push(crate::app::state::MaConfig::preset(0));
+    }
+    Task::none()
+}
+
+pub fn handle_toggle_vwap(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.indicators.vwap_enabled = !app.indicators.vwap_enabled;
+    Task::none()
+}
+
+pub fn handle_toggle_parabolic_sar(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.indicators.psar_enabled = !app.indicators.psar_enabled;
+    Task::none()
+}
+
+pub fn handle_toggle_donchian(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.indicators.donchian_enabled = !app.indicators.donchian_enabled;
+    Task::none()
+}
+
+pub fn handle_toggle_keltner(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.indicators.keltner_enabled = !app.indicators.keltner_enabled;
+    Task::none()
+}
+
+pub fn handle_toggle_volume_profile(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.indicators.volume_profile_enabled = !app.indicators.volume_profile_enabled;
+    Task::none()
+}
+
+pub fn handle_toggle_pivots(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.indicators.pivots_enabled = !app.indicators.pivots_enabled;
+    Task::none()
+}
+
+pub fn handle_toggle_crossover_markers(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.indicators.crossover_markers_enabled = !app.indicators.crossover_markers_enabled;
     Task::none()
 }
 
 /// Gère la mise à jour des paramètres des indicateurs
+pub fn handle_update_volume_ma_period(app: &mut ChartApp, period: Option<usize>) -> Task<crate::app::messages::Message> {
+    app.indicators.params.volume_ma_period = period;
+    Task::none()
+}
+
 pub fn handle_update_rsi_period(app: &mut ChartApp, period: usize) -> Task<crate::app::messages::Message> {
     app.indicators.params.rsi_period = period;
     Task::none()
@@ -69,8 +137,106 @@ pub fn handle_update_bollinger_std_dev(app: &mut ChartApp, std_dev: f64) -> Task
     Task::none()
 }
 
-pub fn handle_update_ma_period(app: &mut ChartApp, period: usize) -> Task<crate::app::messages::Message> {
-    app.indicators.params.ma_period = period;
+pub fn handle_add_moving_average(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    use crate::app::state::{MaConfig, MAX_MOVING_AVERAGES};
+    let moving_averages = &mut app.indicators.params.moving_averages;
+    if moving_averages.len() < MAX_MOVING_AVERAGES {
+        moving_averages.push(MaConfig::preset(moving_averages.len()));
+        app.indicators.moving_average_enabled = true;
+    }
+    Task::none()
+}
+
+pub fn handle_remove_moving_average(app: &mut ChartApp, index: usize) -> Task<crate::app::messages::Message> {
+    let moving_averages = &mut app.indicators.params.moving_averages;
+    if index < moving_averages.len() {
+        moving_averages.remove(index);
+    }
+    // Plus aucune moyenne mobile : désactiver l'overlay
+    if moving_averages.is_empty() {
+        app.indicators.moving_average_enabled = false;
+    }
+    Task::none()
+}
+
+pub fn handle_update_ma_period(app: &mut ChartApp, index: usize, period: usize) -> Task<crate::app::messages::Message> {
+    if let Some(config) = app.indicators.params.moving_averages.get_mut(index) {
+        config.period = period;
+    }
+    Task::none()
+}
+
+pub fn handle_update_ma_type(app: &mut ChartApp, index: usize, ma_type: crate::app::state::MaType) -> Task<crate::app::messages::Message> {
+    if let Some(config) = app.indicators.params.moving_averages.get_mut(index) {
+        config.ma_type = ma_type;
+    }
+    Task::none()
+}
+
+pub fn handle_cycle_ma_color(app: &mut ChartApp, index: usize) -> Task<crate::app::messages::Message> {
+    if let Some(config) = app.indicators.params.moving_averages.get_mut(index) {
+        config.cycle_color();
+    }
+    Task::none()
+}
+
+pub fn handle_update_atr_period(app: &mut ChartApp, period: usize) -> Task<crate::app::messages::Message> {
+    app.indicators.params.atr_period = period;
+    Task::none()
+}
+
+pub fn handle_update_psar_step(app: &mut ChartApp, step: f64) -> Task<crate::app::messages::Message> {
+    app.indicators.params.psar_step = step;
+    Task::none()
+}
+
+pub fn handle_update_psar_max_step(app: &mut ChartApp, max_step: f64) -> Task<crate::app::messages::Message> {
+    app.indicators.params.psar_max_step = max_step;
+    Task::none()
+}
+
+pub fn handle_update_keltner_ema_period(app: &mut ChartApp, period: usize) -> Task<crate::app::messages::Message> {
+    app.indicators.params.keltner_ema_period = period;
+    Task::none()
+}
+
+pub fn handle_update_keltner_atr_period(app: &mut ChartApp, period: usize) -> Task<crate::app::messages::Message> {
+    app.indicators.params.keltner_atr_period = period;
+    Task::none()
+}
+
+pub fn handle_update_keltner_multiplier(app: &mut ChartApp, multiplier: f64) -> Task<crate::app::messages::Message> {
+    app.indicators.params.keltner_multiplier = multiplier;
+    Task::none()
+}
+
+pub fn handle_update_cci_period(app: &mut ChartApp, period: usize) -> Task<crate::app::messages::Message> {
+    app.indicators.params.cci_period = period;
+    Task::none()
+}
+
+pub fn handle_update_williams_r_period(app: &mut ChartApp, period: usize) -> Task<crate::app::messages::Message> {
+    app.indicators.params.williams_r_period = period;
+    Task::none()
+}
+
+pub fn handle_update_pivot_method(app: &mut ChartApp, method: crate::app::state::PivotMethod) -> Task<crate::app::messages::Message> {
+    app.indicators.params.pivot_method = method;
+    Task::none()
+}
+
+pub fn handle_update_crossover_fast_period(app: &mut ChartApp, period: usize) -> Task<crate::app::messages::Message> {
+    app.indicators.params.crossover_fast_period = period;
+    Task::none()
+}
+
+pub fn handle_update_crossover_slow_period(app: &mut ChartApp, period: usize) -> Task<crate::app::messages::Message> {
+    app.indicators.params.crossover_slow_period = period;
+    Task::none()
+}
+
+pub fn handle_update_crossover_ma_type(app: &mut ChartApp, ma_type: crate::app::state::MaType) -> Task<crate::app::messages::Message> {
+    app.indicators.params.crossover_ma_type = ma_type;
     Task::none()
 }
 
@@ -155,6 +321,70 @@ pub fn handle_end_resize_macd_panel(app: &mut ChartApp) -> Task<crate::app::mess
     Task::none()
 }
 
+pub fn handle_start_resize_atr_panel(app: &mut ChartApp, pos: f32) -> Task<crate::app::messages::Message> {
+    app.ui.panels.atr.start_resize(pos);
+    Task::none()
+}
+
+pub fn handle_update_resize_atr_panel(app: &mut ChartApp, pos: f32) -> Task<crate::app::messages::Message> {
+    app.ui.panels.atr.update_resize(pos, false);
+    Task::none()
+}
+
+pub fn handle_end_resize_atr_panel(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.ui.panels.atr.end_resize();
+    app.save_panel_state();
+    Task::none()
+}
+
+pub fn handle_start_resize_obv_panel(app: &mut ChartApp, pos: f32) -> Task<crate::app::messages::Message> {
+    app.ui.panels.obv.start_resize(pos);
+    Task::none()
+}
+
+pub fn handle_update_resize_obv_panel(app: &mut ChartApp, pos: f32) -> Task<crate::app::messages::Message> {
+    app.ui.panels.obv.update_resize(pos, false);
+    Task::none()
+}
+
+pub fn handle_end_resize_obv_panel(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.ui.panels.obv.end_resize();
+    app.save_panel_state();
+    Task::none()
+}
+
+pub fn handle_start_resize_cci_panel(app: &mut ChartApp, pos: f32) -> Task<crate::app::messages::Message> {
+    app.ui.panels.cci.start_resize(pos);
+    Task::none()
+}
+
+pub fn handle_update_resize_cci_panel(app: &mut ChartApp, pos: f32) -> Task<crate::app::messages::Message> {
+    app.ui.panels.cci.update_resize(pos, false);
+    Task::none()
+}
+
+pub fn handle_end_resize_cci_panel(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.ui.panels.cci.end_resize();
+    app.save_panel_state();
+    Task::none()
+}
+
+pub fn handle_start_resize_williams_r_panel(app: &mut ChartApp, pos: f32) -> Task<crate::app::messages::Message> {
+    app.ui.panels.williams_r.start_resize(pos);
+    Task::none()
+}
+
+pub fn handle_update_resize_williams_r_panel(app: &mut ChartApp, pos: f32) -> Task<crate::app::messages::Message> {
+    app.ui.panels.williams_r.update_resize(pos, false);
+    Task::none()
+}
+
+pub fn handle_end_resize_williams_r_panel(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.ui.panels.williams_r.end_resize();
+    app.save_panel_state();
+    Task::none()
+}
+
 /// Gère la sélection et le déplacement des sections
 pub fn handle_select_bottom_section(
     app: &mut ChartApp,
@@ -234,11 +464,35 @@ pub fn handle_set_macd_panel_focus(app: &mut ChartApp, focused: bool) -> Task<cr
     Task::none()
 }
 
+pub fn handle_set_atr_panel_focus(app: &mut ChartApp, focused: bool) -> Task<crate::app::messages::Message> {
+    app.ui.panels.atr.set_focused(focused);
+    Task::none()
+}
+
+pub fn handle_set_obv_panel_focus(app: &mut ChartApp, focused: bool) -> Task<crate::app::messages::Message> {
+    app.ui.panels.obv.set_focused(focused);
+    Task::none()
+}
+
+pub fn handle_set_cci_panel_focus(app: &mut ChartApp, focused: bool) -> Task<crate::app::messages::Message> {
+    app.ui.panels.cci.set_focused(focused);
+    Task::none()
+}
+
+pub fn handle_set_williams_r_panel_focus(app: &mut ChartApp, focused: bool) -> Task<crate::app::messages::Message> {
+    app.ui.panels.williams_r.set_focused(focused);
+    Task::none()
+}
+
 pub fn handle_clear_panel_focus(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
     app.ui.panels.right.set_focused(false);
     app.ui.panels.bottom.set_focused(false);
     app.ui.panels.volume.set_focused(false);
     app.ui.panels.rsi.set_focused(false);
+    app.ui.panels.atr.set_focused(false);
+    app.ui.panels.obv.set_focused(false);
+    app.ui.panels.cci.set_focused(false);
+    app.ui.panels.williams_r.set_focused(false);
     Task::none()
 }
 
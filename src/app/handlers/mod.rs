@@ -13,11 +13,13 @@ mod realtime;
 mod panels;
 pub mod strategies;
 mod backtest;
+mod replay;
+mod drawings;
 mod assets;
 mod errors;
 mod notifications;
 
-pub use handlers::{handle_chart_message, handle_reset_view, handle_open_chart_context_menu, handle_close_chart_context_menu};
+pub use handlers::{handle_chart_message, handle_reset_view, handle_go_to_latest, handle_toggle_comparison_mode, handle_open_chart_context_menu, handle_close_chart_context_menu, handle_export_chart_png, handle_export_visible_csv};
 pub use windows::{
     handle_open_settings,
     handle_open_downloads,
@@ -26,6 +28,10 @@ pub use windows::{
 };
 pub use series::{
     handle_select_series_by_name,
+    handle_switch_interval,
+    handle_toggle_series_visibility,
+    handle_toggle_series_realtime,
+    handle_set_series_axis,
     handle_load_series_complete,
 };
 pub use indicators::*;
@@ -36,6 +42,8 @@ pub use realtime::*;
 pub use panels::*;
 pub use strategies::*;
 pub use backtest::*;
+pub use replay::*;
+pub use drawings::*;
 pub use assets::{handle_toggle_asset_selection, handle_select_asset_from_header, handle_asset_series_created};
 pub use errors::*;
 pub use notifications::*;
@@ -7,8 +7,25 @@ use crate::finance_chart::{YAxisMessage, XAxisMessage, ToolsPanelMessage};
 /// Gère les messages des axes
 pub fn handle_yaxis_message(app: &mut ChartApp, msg: YAxisMessage) -> Task<crate::app::messages::Message> {
     match msg {
-        YAxisMessage::ZoomVertical { factor } => {
-            app.chart_state.zoom_vertical(factor);
+        YAxisMessage::DragScale { delta } => {
+            app.chart_state.drag_scale_price(delta);
+        }
+        YAxisMessage::ToggleLogScale => {
+            app.chart_state.toggle_log_scale();
+        }
+        YAxisMessage::ToggleInverted => {
+            app.chart_state.toggle_inverted_scale();
+        }
+        YAxisMessage::ToggleAutoScale => {
+            app.chart_state.toggle_auto_scale();
+        }
+        YAxisMessage::CreateAlert { price } => {
+            // Le sens de l'alerte dépend du dernier prix connu
+            let reference_price = app.chart_state.last_candle().map(|c| c.close).unwrap_or(price);
+            app.chart_state.alerts.add(price, reference_price);
+        }
+        YAxisMessage::RemoveAlert { id } => {
+            app.chart_state.alerts.remove(id);
         }
     }
     Task::none()
@@ -16,8 +33,8 @@ pub fn handle_yaxis_message(app: &mut ChartApp, msg: YAxisMessage) -> Task<crate
 
 pub fn handle_xaxis_message(app: &mut ChartApp, msg: XAxisMessage) -> Task<crate::app::messages::Message> {
     match msg {
-        XAxisMessage::ZoomHorizontal { factor } => {
-            app.chart_state.zoom(factor);
+        XAxisMessage::DragScale { delta } => {
+            app.chart_state.drag_scale_time(delta);
         }
     }
     Task::none()
@@ -36,6 +53,13 @@ pub fn handle_tools_panel_message(app: &mut ChartApp, msg: ToolsPanelMessage) ->
         ToolsPanelMessage::ToggleIndicatorsPanel => {
             app.ui.indicators_panel_open = !app.ui.indicators_panel_open;
         }
+        ToolsPanelMessage::SelectTool { tool } => {
+            // Un dessin en cours avec l'ancien outil est abandonné
+            if app.tools_state.selected_tool != tool && app.tools_state.drawing.is_drawing {
+                app.tools_state.drawing.cancel();
+            }
+            app.tools_state.selected_tool = tool;
+        }
     }
     Task::none()
 }
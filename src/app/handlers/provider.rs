@@ -3,7 +3,7 @@
 use iced::{Task, Size, window};
 use crate::app::app_state::ChartApp;
 use crate::app::window_manager::WindowType;
-use crate::finance_chart::{ProviderType, BinanceProvider};
+use crate::finance_chart::{ProviderType, BinanceProvider, create_data_provider};
 use std::sync::Arc;
 
 /// Gère l'ouverture de la fenêtre de configuration des providers
@@ -99,6 +99,7 @@ pub fn handle_apply_provider_config(app: &mut ChartApp) -> Task<crate::app::mess
         ));
         println!("✅ Provider recréé avec la nouvelle configuration");
     }
+    app.data_provider = create_data_provider(&app.provider_config);
     
     // Fermer la fenêtre
     if let Some(id) = app.windows.get_id(WindowType::ProviderConfig) {
@@ -122,6 +123,7 @@ pub fn handle_select_provider(app: &mut ChartApp, provider_type: ProviderType) -
         ));
         println!("✅ Provider changé et recréé");
     }
+    app.data_provider = create_data_provider(&app.provider_config);
     
     Task::none()
 }
@@ -3,6 +3,8 @@
 use iced::Task;
 use crate::app::app_state::ChartApp;
 use crate::finance_chart::core::{SeriesId, Candle};
+use crate::finance_chart::{WsConnectionState, WsEvent};
+use crate::finance_chart::providers::binance_ws::{dedup_kline, KlineAction};
 use std::collections::HashSet;
 
 /// Gère le chargement de l'historique complet
@@ -19,6 +21,7 @@ pub fn handle_load_full_history_complete(
             match app.chart_state.merge_candles(&series_id, candles) {
                 crate::finance_chart::UpdateResult::MultipleCandlesAdded(count) => {
                     println!("  ✅ {} nouvelles bougies ajoutées", count);
+                    app.apply_history_cap();
                     // Mettre à jour le viewport pour afficher toutes les données
                     app.chart_state.update_viewport_from_series();
                     // Sauvegarder la série mise à jour de manière asynchrone
@@ -96,6 +99,7 @@ pub fn handle_batch_download_result(
         match app.chart_state.merge_candles(&series_id, candles) {
             crate::finance_chart::UpdateResult::MultipleCandlesAdded(added) => {
                 println!("  📊 +{} bougies fusionnées (total téléchargé: {})", added, count);
+                app.apply_history_cap();
                 // Sauvegarder seulement tous les 10 batches pour éviter les freezes
                 // ou si c'est le dernier batch
                 if let Some(ref progress) = app.download_manager.get_progress(&series_id) {
@@ -236,7 +240,65 @@ pub fn handle_realtime_update_complete(
     results: Vec<(SeriesId, String, Result<Option<Candle>, String>)>
 ) -> Task<crate::app::messages::Message> {
     println!("📥 RealtimeUpdateComplete: {} résultats reçus", results.len());
-    app.apply_realtime_updates(results);
+    let triggered_alerts = app.apply_realtime_updates(results);
+    alerts_triggered_task(triggered_alerts)
+}
+
+/// Émet un `Message::AlertTriggered` par alerte de prix déclenchée
+fn alerts_triggered_task(alert_ids: Vec<u64>) -> Task<crate::app::messages::Message> {
+    Task::batch(
+        alert_ids
+            .into_iter()
+            .map(|id| Task::done(crate::app::messages::Message::AlertTriggered(id))),
+    )
+}
+
+/// Gère le déclenchement d'une alerte de prix
+pub fn handle_alert_triggered(app: &mut ChartApp, alert_id: u64) -> Task<crate::app::messages::Message> {
+    if let Some(alert) = app.chart_state.alerts.get(alert_id) {
+        let symbol = app.chart_state.series_manager
+            .active_series()
+            .next()
+            .map(|s| s.symbol.clone())
+            .unwrap_or_default();
+        println!("🔔 Alerte {} déclenchée: {} à {:.2}", alert_id, symbol, alert.price);
+        app.ui.notifications.add_info(format!("🔔 Alerte: {} a atteint {:.2}", symbol, alert.price));
+    }
+    Task::none()
+}
+
+/// Gère les événements du flux WebSocket Binance
+pub fn handle_ws_event(app: &mut ChartApp, event: WsEvent) -> Task<crate::app::messages::Message> {
+    match event {
+        WsEvent::Status(state) => {
+            if state.is_live() {
+                println!("🟢 Flux WebSocket actif: {}", app.realtime_ws_provider().stream_url());
+            } else if let WsConnectionState::Reconnecting { attempt } = state {
+                println!("🟠 WebSocket déconnecté, reconnexion (tentative {})", attempt);
+            }
+            app.ws_connection = Some(state);
+        }
+        WsEvent::Kline { series_id, candle } => {
+            // Ignorer les bougies d'une série figée (reçues avant la fermeture du flux)
+            let series = app.chart_state.series_manager.get_series(&series_id);
+            if series.is_some_and(|s| !s.realtime_enabled) {
+                return Task::none();
+            }
+            // Ignorer la bougie si elle est identique (ou antérieure) à la dernière bougie REST
+            let last = series.and_then(|s| s.data.last_candle());
+            if dedup_kline(&candle, last) == KlineAction::Skip {
+                return Task::none();
+            }
+            // Conserver la bougie pour les lectures via `RealtimeDataProvider`
+            app.ws_provider.record(&series_id, candle);
+            let series_name = app.chart_state.series_manager
+                .get_series(&series_id)
+                .map(|s| s.full_name())
+                .unwrap_or_else(|| series_id.name.clone());
+            let triggered_alerts = app.apply_realtime_updates(vec![(series_id, series_name, Ok(Some(candle)))]);
+            return alerts_triggered_task(triggered_alerts);
+        }
+    }
     Task::none()
 }
 
@@ -252,7 +314,7 @@ pub fn handle_complete_missing_data_complete(
 /// Gère la complétion des gaps
 pub fn handle_complete_gaps_complete(
     app: &mut ChartApp,
-    results: Vec<(SeriesId, String, (i64, i64), Result<Vec<Candle>, String>)>
+    results: Vec<crate::app::realtime::GapFillResult>
 ) -> Task<crate::app::messages::Message> {
     println!("📥 CompleteGapsComplete: {} résultats reçus", results.len());
     app.apply_complete_gaps_results(results)
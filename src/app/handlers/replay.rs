@@ -0,0 +1,59 @@
+//! Handlers pour le mode replay
+
+use iced::Task;
+use crate::app::app_state::ChartApp;
+use crate::app::messages::Message;
+
+/// Démarre le replay à la bougie située sous le clic droit
+pub fn handle_replay_start(app: &mut ChartApp) -> Task<Message> {
+    if let Some(absolute_position) = app.ui.chart_context_menu {
+        // Convertir la position absolue en timestamp
+        let relative_position = app.chart_state.interaction.absolute_to_relative(absolute_position);
+        let timestamp = app.chart_state.viewport.time_scale().x_to_time(relative_position.x);
+
+        if app.chart_state.start_replay(timestamp) {
+            app.ui.replay.is_playing = false;
+            let _ = app.chart_state.compute_and_store_macd();
+        }
+    }
+
+    // Fermer le menu contextuel
+    app.ui.chart_context_menu = None;
+
+    Task::none()
+}
+
+/// Révèle la bougie suivante (bouton ou tick de lecture)
+pub fn handle_replay_step(app: &mut ChartApp) -> Task<Message> {
+    if app.chart_state.step_replay() {
+        // Le MACD est prolongé d'une bougie par step_replay : ceci ne recalcule
+        // tout que si le cache a dû être invalidé
+        let _ = app.chart_state.compute_and_store_macd();
+    } else {
+        // Fin de la série : arrêter la lecture automatique
+        app.ui.replay.is_playing = false;
+    }
+    Task::none()
+}
+
+/// Lance ou met en pause la lecture automatique
+pub fn handle_replay_play(app: &mut ChartApp) -> Task<Message> {
+    if app.chart_state.replay_cursor.is_some() {
+        app.ui.replay.is_playing = !app.ui.replay.is_playing;
+    }
+    Task::none()
+}
+
+/// Change la vitesse de lecture du replay
+pub fn handle_replay_set_speed(app: &mut ChartApp, speed_ms: u64) -> Task<Message> {
+    app.ui.replay.speed_ms = speed_ms.max(1);
+    Task::none()
+}
+
+/// Quitte le replay et révèle toutes les bougies
+pub fn handle_replay_stop(app: &mut ChartApp) -> Task<Message> {
+    app.chart_state.stop_replay();
+    let _ = app.chart_state.compute_and_store_macd();
+    app.ui.replay.is_playing = false;
+    Task::none()
+}
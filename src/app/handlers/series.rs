@@ -3,6 +3,7 @@
 use iced::Task;
 use crate::app::app_state::ChartApp;
 use crate::app::persistence::TimeframePersistenceState;
+use crate::finance_chart::core::{PriceAxis, SeriesId};
 
 /// Gère la sélection d'une série par nom
 pub fn handle_select_series_by_name(app: &mut ChartApp, series_name: String) -> Task<crate::app::messages::Message> {
@@ -130,6 +131,57 @@ pub fn handle_select_series_by_name(app: &mut ChartApp, series_name: String) ->
     Task::none()
 }
 
+/// Gère le changement d'intervalle de l'actif courant (barre d'intervalles)
+///
+/// Un intervalle déjà chargé est activé directement ; sinon la série est
+/// téléchargée comme depuis la liste des séries.
+pub fn handle_switch_interval(app: &mut ChartApp, interval: String) -> Task<crate::app::messages::Message> {
+    let symbol = app.selected_asset_symbol.clone().or_else(|| {
+        app.chart_state.series_manager
+            .active_series()
+            .next()
+            .map(|series| series.symbol.clone())
+    });
+    let Some(symbol) = symbol else {
+        return Task::none();
+    };
+    let series_name = app.chart_state.series_manager
+        .series_for_interval(&symbol, &interval)
+        .map(|series| series.id.name.clone())
+        .unwrap_or_else(|| format!("{}_{}", symbol, interval));
+    handle_select_series_by_name(app, series_name)
+}
+
+/// Gère l'affichage/masquage d'une série superposée
+pub fn handle_toggle_series_visibility(app: &mut ChartApp, series_id: SeriesId) -> Task<crate::app::messages::Message> {
+    if !app.chart_state.toggle_series_visibility(&series_id) {
+        println!("⚠️ Impossible de changer la visibilité de {} (dernière série affichée ?)", series_id.name);
+    }
+    Task::none()
+}
+
+/// Gère la suspension/reprise du temps réel d'une série
+pub fn handle_toggle_series_realtime(app: &mut ChartApp, series_id: SeriesId) -> Task<crate::app::messages::Message> {
+    match app.chart_state.series_manager.toggle_realtime(&series_id) {
+        Some(true) => println!("▶️ Temps réel repris pour {}", series_id.name),
+        Some(false) => println!("⏸️ Temps réel suspendu pour {}", series_id.name),
+        None => println!("⚠️ Série {} introuvable", series_id.name),
+    }
+    Task::none()
+}
+
+/// Gère l'assignation d'une série superposée à l'axe de prix gauche ou droit
+pub fn handle_set_series_axis(app: &mut ChartApp, series_id: SeriesId, axis: PriceAxis) -> Task<crate::app::messages::Message> {
+    if app.chart_state.series_manager.set_series_axis(&series_id, axis) {
+        let side = match axis {
+            PriceAxis::Left => "gauche",
+            PriceAxis::Right => "droite",
+        };
+        println!("📏 {} tracée sur l'axe de {}", series_id.name, side);
+    }
+    Task::none()
+}
+
 /// Gère le chargement des séries depuis le répertoire
 pub fn handle_load_series_complete(
     app: &mut ChartApp,
@@ -149,6 +201,7 @@ pub fn handle_load_series_complete(
                 );
                 app.chart_state.add_series(series);
             }
+            app.apply_history_cap();
             // Calculer et stocker le MACD pré-calculé une fois après le chargement initial
             let _ = app.chart_state.compute_and_store_macd();
             if app.chart_state.series_manager.total_count() == 0 {
@@ -3,7 +3,7 @@
 use iced::Task;
 use crate::app::app_state::ChartApp;
 use crate::app::window_manager::WindowType;
-use crate::finance_chart::settings::color_fields;
+use crate::finance_chart::settings::{color_fields, CandleBodyStyle, ChartRenderMode, CrosshairDash, ThemePreset, TooltipField, ZoomAnchor, MAX_PRICE_DECIMALS};
 
 /// Gère la sélection d'une couleur dans les settings
 pub fn handle_select_color(
@@ -21,11 +21,23 @@ pub fn handle_select_color(
     Task::none()
 }
 
+/// Gère l'application d'un thème prédéfini (toutes les couleurs à la fois)
+pub fn handle_apply_theme_preset(app: &mut ChartApp, preset: ThemePreset) -> Task<crate::app::messages::Message> {
+    if let Some(ref mut style) = app.editing_style {
+        style.apply_preset(preset);
+    }
+    app.editing_color_index = None;
+    Task::none()
+}
+
 /// Gère l'application des settings
 pub fn handle_apply_settings(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
     use iced::window;
     
     if let Some(new_style) = app.editing_style.take() {
+        if new_style.right_padding_candles != app.chart_style.right_padding_candles {
+            app.chart_state.set_right_padding_candles(new_style.right_padding_candles);
+        }
         app.chart_style = new_style.clone();
         if let Err(e) = new_style.save_to_file("chart_style.json") {
             eprintln!("⚠️ Erreur sauvegarde style: {}", e);
@@ -72,3 +84,179 @@ pub fn handle_toggle_auto_scroll(app: &mut ChartApp) -> Task<crate::app::message
     Task::none()
 }
 
+/// Gère le changement de mode de rendu des séries
+pub fn handle_set_render_mode(app: &mut ChartApp, mode: ChartRenderMode) -> Task<crate::app::messages::Message> {
+    if let Some(ref mut style) = app.editing_style {
+        style.render_mode = mode;
+    }
+    Task::none()
+}
+
+/// Gère le changement de largeur du corps des bougies
+pub fn handle_set_candle_width_ratio(app: &mut ChartApp, ratio: Option<f32>) -> Task<crate::app::messages::Message> {
+    if let Some(ref mut style) = app.editing_style {
+        style.candle_width_ratio = ratio.map(|r| r.clamp(0.0, 1.0));
+    }
+    Task::none()
+}
+
+/// Gère le changement d'épaisseur des mèches
+pub fn handle_set_wick_width(app: &mut ChartApp, width: f32) -> Task<crate::app::messages::Message> {
+    if let Some(ref mut style) = app.editing_style {
+        style.wick_width = width;
+    }
+    Task::none()
+}
+
+/// Gère le changement de style du corps des bougies
+pub fn handle_set_candle_body_style(app: &mut ChartApp, body_style: CandleBodyStyle) -> Task<crate::app::messages::Message> {
+    if let Some(ref mut style) = app.editing_style {
+        style.candle_body_style = body_style;
+    }
+    Task::none()
+}
+
+/// Gère le changement de densité de la grille
+pub fn handle_set_grid_density(app: &mut ChartApp, density: f32) -> Task<crate::app::messages::Message> {
+    if let Some(ref mut style) = app.editing_style {
+        style.grid.density = density;
+    }
+    Task::none()
+}
+
+/// Gère le changement de précision des prix affichés
+pub fn handle_set_price_decimals(app: &mut ChartApp, decimals: Option<usize>) -> Task<crate::app::messages::Message> {
+    if let Some(ref mut style) = app.editing_style {
+        style.price_decimals = decimals.map(|d| d.min(MAX_PRICE_DECIMALS));
+    }
+    Task::none()
+}
+
+/// Gère le changement de l'espace vide à droite de la dernière bougie
+pub fn handle_set_right_padding(app: &mut ChartApp, candles: f32) -> Task<crate::app::messages::Message> {
+    if let Some(ref mut style) = app.editing_style {
+        style.right_padding_candles = candles.max(0.0);
+    }
+    Task::none()
+}
+
+/// Gère le toggle des lignes de grille pointillées
+pub fn handle_toggle_grid_dashed(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    if let Some(ref mut style) = app.editing_style {
+        style.grid.dashed = !style.grid.dashed;
+    }
+    Task::none()
+}
+
+/// Gère le toggle des lignes de grille secondaires
+pub fn handle_toggle_grid_minor_lines(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    if let Some(ref mut style) = app.editing_style {
+        style.grid.minor_lines = !style.grid.minor_lines;
+    }
+    Task::none()
+}
+
+/// Gère le changement d'épaisseur des lignes du crosshair
+pub fn handle_set_crosshair_width(app: &mut ChartApp, width: f32) -> Task<crate::app::messages::Message> {
+    if let Some(ref mut style) = app.editing_style {
+        style.crosshair.width = width;
+    }
+    Task::none()
+}
+
+/// Gère le changement de motif de trait du crosshair
+pub fn handle_set_crosshair_dash(app: &mut ChartApp, dash: CrosshairDash) -> Task<crate::app::messages::Message> {
+    if let Some(ref mut style) = app.editing_style {
+        style.crosshair.dash = dash;
+    }
+    Task::none()
+}
+
+/// Gère le toggle des étiquettes du crosshair sur les axes
+pub fn handle_toggle_crosshair_labels(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    if let Some(ref mut style) = app.editing_style {
+        style.crosshair.show_labels = !style.crosshair.show_labels;
+    }
+    Task::none()
+}
+
+/// Gère le toggle de l'affichage Heikin-Ashi (appliqué immédiatement)
+pub fn handle_toggle_heikin_ashi(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.settings_state.heikin_ashi_enabled = !app.settings_state.heikin_ashi_enabled;
+    Task::none()
+}
+
+/// Gère le toggle du mode aimant du crosshair (appliqué immédiatement)
+pub fn handle_toggle_crosshair_magnet(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.settings_state.crosshair_magnet = !app.settings_state.crosshair_magnet;
+    Task::none()
+}
+
+/// Gère le toggle de l'affichage des trous de données (appliqué immédiatement)
+pub fn handle_toggle_show_gaps(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.settings_state.show_gaps = !app.settings_state.show_gaps;
+    Task::none()
+}
+
+/// Gère le toggle de la heatmap de volume sur les bougies (appliqué immédiatement)
+pub fn handle_toggle_volume_heatmap(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.settings_state.volume_heatmap = !app.settings_state.volume_heatmap;
+    Task::none()
+}
+
+/// Gère le toggle des séparateurs de journée (appliqué immédiatement)
+pub fn handle_toggle_show_day_separators(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.settings_state.show_day_separators = !app.settings_state.show_day_separators;
+    Task::none()
+}
+
+/// Gère le toggle de l'alignement sur des bougies entières après un pan (appliqué immédiatement)
+pub fn handle_toggle_snap_pan_to_candle(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.settings_state.snap_pan_to_candle = !app.settings_state.snap_pan_to_candle;
+    Task::none()
+}
+
+/// Gère le changement du point fixe du zoom à la molette (appliqué immédiatement)
+pub fn handle_set_zoom_anchor(app: &mut ChartApp, anchor: ZoomAnchor) -> Task<crate::app::messages::Message> {
+    app.settings_state.zoom_anchor = anchor;
+    Task::none()
+}
+
+/// Gère le nombre maximal de requêtes simultanées (appliqué aux prochaines complétions)
+pub fn handle_set_max_concurrent_requests(app: &mut ChartApp, limit: usize) -> Task<crate::app::messages::Message> {
+    app.settings_state.max_concurrent_requests = limit.max(1);
+    Task::none()
+}
+
+/// Gère la limite de bougies gardées en mémoire par série (appliquée immédiatement)
+pub fn handle_set_max_candles_per_series(app: &mut ChartApp, limit: Option<usize>) -> Task<crate::app::messages::Message> {
+    app.settings_state.max_candles_per_series = limit;
+    if app.apply_history_cap() {
+        let _ = app.chart_state.compute_and_store_macd();
+    }
+    Task::none()
+}
+
+/// Gère le toggle du bandeau d'informations (appliqué immédiatement)
+pub fn handle_toggle_show_hud(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.settings_state.show_hud = !app.settings_state.show_hud;
+    Task::none()
+}
+
+/// Gère le toggle des liaisons entrée/sortie des trades clôturés (appliqué immédiatement)
+pub fn handle_toggle_show_trade_pnl(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.settings_state.show_trade_pnl = !app.settings_state.show_trade_pnl;
+    Task::none()
+}
+
+/// Gère l'affichage d'un champ du tooltip (appliqué immédiatement)
+pub fn handle_toggle_tooltip_field(app: &mut ChartApp, field: TooltipField) -> Task<crate::app::messages::Message> {
+    app.settings_state.tooltip.toggle(field);
+    Task::none()
+}
+
+/// Gère le déplacement d'un champ du tooltip dans l'ordre d'affichage
+pub fn handle_move_tooltip_field(app: &mut ChartApp, field: TooltipField, up: bool) -> Task<crate::app::messages::Message> {
+    app.settings_state.tooltip.move_field(field, up);
+    Task::none()
+}
@@ -184,6 +184,26 @@ pub fn handle_register_ma_crossover_strategy(app: &mut ChartApp) -> Task<crate::
     Task::none()
 }
 
+/// Enregistre une stratégie de cassure des bandes de Bollinger
+pub fn handle_register_bollinger_strategy(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    use crate::app::strategies::examples::BollingerBreakoutStrategy;
+    let strategy = Box::new(BollingerBreakoutStrategy::new());
+    let id = app.strategy_manager.register_strategy(strategy);
+    println!("✅ Stratégie Bollinger Breakout enregistrée: {}", id);
+    save_strategies(app);
+    Task::none()
+}
+
+/// Enregistre une stratégie de croisement MACD
+pub fn handle_register_macd_strategy(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    use crate::app::strategies::examples::MacdCrossStrategy;
+    let strategy = Box::new(MacdCrossStrategy::new());
+    let id = app.strategy_manager.register_strategy(strategy);
+    println!("✅ Stratégie MACD Cross enregistrée: {}", id);
+    save_strategies(app);
+    Task::none()
+}
+
 /// Active une stratégie
 pub fn handle_enable_strategy(app: &mut ChartApp, id: String) -> Task<crate::app::messages::Message> {
     if let Err(e) = app.strategy_manager.enable_strategy(&id) {
@@ -232,8 +252,10 @@ pub fn handle_toggle_strategy_config(app: &mut ChartApp, strategy_id: String) ->
             .unwrap_or_default();
         
         if let Some(reg) = reg {
-            for param in reg.strategy.parameters() {
-                param_values.insert(param.name, format!("{:.2}", param.value));
+            for spec in reg.strategy.parameter_specs() {
+                if let Some(value) = reg.strategy.parameter_value(&spec.name) {
+                    param_values.insert(spec.name.clone(), spec.format(value));
+                }
             }
         }
         
@@ -324,25 +346,21 @@ pub fn handle_apply_strategy_config(app: &mut ChartApp, strategy_id: String) ->
         return Task::none();
     };
     
-    // Appliquer les paramètres
-    for param in reg.strategy.parameters() {
-        if let Some(value_str) = editing_state.param_values.get(&param.name) {
-            if let Ok(value) = value_str.parse::<f64>() {
-                if value >= param.min && value <= param.max {
-                    if let Err(e) = reg.strategy.update_parameter(&param.name, value) {
-                        eprintln!("⚠️ Erreur mise à jour paramètre {}: {}", param.name, e);
-                    } else {
-                        println!("✅ Paramètre {} mis à jour: {:.2}", param.name, value);
-                    }
-                } else {
-                    eprintln!("⚠️ Valeur {} hors limites pour {} (min: {}, max: {})", 
-                        value, param.name, param.min, param.max);
-                }
-            } else {
-                eprintln!("⚠️ Valeur invalide pour {}: {}", param.name, value_str);
+    // Appliquer les paramètres (les valeurs hors bornes sont rejetées par `set_parameter`)
+    let mut rejected = Vec::new();
+    let mut values = Vec::new();
+    for spec in reg.strategy.parameter_specs() {
+        if let Some(value_str) = editing_state.param_values.get(&spec.name) {
+            match value_str.trim().parse::<f64>() {
+                Ok(value) => values.push((spec.name, value)),
+                Err(_) => rejected.push(format!("Valeur invalide pour {}: {}", spec.name, value_str)),
             }
         }
     }
+    rejected.extend(reg.strategy.set_parameters(&values));
+    if rejected.is_empty() {
+        println!("✅ Paramètres de {} mis à jour", reg.strategy.name());
+    }
     
     // Appliquer les timeframes
     let timeframes = if editing_state.selected_timeframes.is_empty() {
@@ -376,9 +394,16 @@ pub fn handle_apply_strategy_config(app: &mut ChartApp, strategy_id: String) ->
         println!("✅ Mode de trading mis à jour: {}", mode_text);
     }
     
-    // Fermer le panneau de configuration
-    if let Some(editing) = app.editing_strategies.get_mut(&strategy_id) {
-        editing.expanded = false;
+    // Fermer le panneau de configuration, sauf si une valeur doit être corrigée
+    if rejected.is_empty() {
+        if let Some(editing) = app.editing_strategies.get_mut(&strategy_id) {
+            editing.expanded = false;
+        }
+    } else {
+        for e in rejected {
+            eprintln!("⚠️ {}", e);
+            app.ui.notifications.add_warning(e);
+        }
     }
     
     // Sauvegarder les modifications
@@ -395,8 +420,10 @@ pub fn handle_cancel_strategy_config(app: &mut ChartApp, strategy_id: String) ->
         // Réinitialiser les valeurs avec les valeurs actuelles
         if let Some(reg) = app.strategy_manager.get_strategy(&strategy_id) {
             editing.param_values.clear();
-            for param in reg.strategy.parameters() {
-                editing.param_values.insert(param.name, format!("{:.2}", param.value));
+            for spec in reg.strategy.parameter_specs() {
+                if let Some(value) = reg.strategy.parameter_value(&spec.name) {
+                    editing.param_values.insert(spec.name.clone(), spec.format(value));
+                }
             }
             editing.selected_timeframes = reg.allowed_timeframes.clone().unwrap_or_default();
             editing.trading_mode = reg.trading_mode;
@@ -406,6 +433,21 @@ pub fn handle_cancel_strategy_config(app: &mut ChartApp, strategy_id: String) ->
     Task::none()
 }
 
+/// Remplace les valeurs en cours d'édition par les valeurs par défaut de la stratégie
+///
+/// Les valeurs ne sont appliquées qu'avec `ApplyStrategyConfig`.
+pub fn handle_reset_strategy_parameters(app: &mut ChartApp, strategy_id: String) -> Task<crate::app::messages::Message> {
+    let Some(reg) = app.strategy_manager.get_strategy(&strategy_id) else {
+        return Task::none();
+    };
+    if let Some(editing) = app.editing_strategies.get_mut(&strategy_id) {
+        for spec in reg.strategy.parameter_specs() {
+            editing.param_values.insert(spec.name.clone(), spec.format(spec.default));
+        }
+    }
+    Task::none()
+}
+
 /// Met à jour le mode de trading temporairement dans l'état d'édition
 pub fn handle_update_strategy_trading_mode(
     app: &mut ChartApp,
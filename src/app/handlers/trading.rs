@@ -5,6 +5,7 @@ use std::sync::Arc;
 use crate::app::app_state::ChartApp;
 use crate::app::data::OrderType;
 use crate::app::trading::api::{validate_order, place_market_buy_order, place_market_sell_order, place_limit_buy_order, place_limit_sell_order};
+use crate::app::trading::risk::position_size;
 
 /// Gère la mise à jour de la quantité d'ordre
 pub fn handle_update_order_quantity(app: &mut ChartApp, quantity: String) -> Task<crate::app::messages::Message> {
@@ -47,15 +48,125 @@ pub fn handle_update_take_profit(app: &mut ChartApp, tp: String) -> Task<crate::
 /// Gère la mise à jour du stop loss
 pub fn handle_update_stop_loss(app: &mut ChartApp, sl: String) -> Task<crate::app::messages::Message> {
     app.trading_state.stop_loss = sl;
+    suggest_quantity_from_risk(app);
     Task::none()
 }
 
+/// Gère la mise à jour du pourcentage de risque
+pub fn handle_update_risk_percent(app: &mut ChartApp, risk: String) -> Task<crate::app::messages::Message> {
+    app.trading_state.risk_percent = risk;
+    suggest_quantity_from_risk(app);
+    Task::none()
+}
+
+/// Propose une quantité à partir du stop loss et du pourcentage de risque
+///
+/// L'entrée est le prix limite pour un ordre limit, sinon le dernier prix.
+/// La quantité saisie n'est pas modifiée si le calcul est impossible.
+fn suggest_quantity_from_risk(app: &mut ChartApp) {
+    let (Some(stop), Some(risk)) = (
+        app.trading_state.parse_stop_loss(),
+        app.trading_state.parse_risk_percent(),
+    ) else {
+        return;
+    };
+    let entry = match app.trading_state.order_type {
+        OrderType::Limit => app.trading_state.parse_limit_price(),
+        OrderType::Market => app.chart_state.last_candle().map(|c| c.close),
+    };
+    let Some(entry) = entry else {
+        return;
+    };
+    let quantity = position_size(app.account_info.total_balance, risk, entry, stop);
+    if quantity > 0.0 {
+        app.trading_state.order_quantity = format!("{:.6}", quantity);
+    }
+}
+
+/// Clôture et timestamp de la dernière bougie révélée en mode replay
+///
+/// Les ordres papier passés pendant un replay s'exécutent à ce prix, pas au dernier prix de la série.
+fn replay_quote(app: &ChartApp) -> Option<(f64, i64)> {
+    app.chart_state.replay_candle().map(|candle| (candle.close, candle.timestamp))
+}
+
 /// Gère le toggle de TP/SL
 pub fn handle_toggle_tp_sl_enabled(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
     app.trading_state.tp_sl_enabled = !app.trading_state.tp_sl_enabled;
     Task::none()
 }
 
+/// Gère la mise à jour de l'écart du trailing stop
+pub fn handle_update_trailing_offset(app: &mut ChartApp, offset: String) -> Task<crate::app::messages::Message> {
+    app.trading_state.trailing_offset = offset;
+    Task::none()
+}
+
+/// Gère le toggle de l'écart du trailing stop en pourcentage
+pub fn handle_toggle_trailing_percent(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    app.trading_state.trailing_percent = !app.trading_state.trailing_percent;
+    Task::none()
+}
+
+/// Active le trailing stop saisi sur la position qui vient d'être ouverte
+fn apply_trailing_stop(app: &mut ChartApp, symbol: &str, price: f64) {
+    if let Some(offset) = app.trading_state.parse_trailing_offset() {
+        let percent = app.trading_state.trailing_percent;
+        if app.trading_state.trade_history.set_trailing_stop(symbol, offset, percent, price) {
+            println!("  📊 Trailing stop: {}{}", offset, if percent { " %" } else { "" });
+        }
+    }
+}
+
+/// Exécute une action de trading en mémorisant l'historique précédent pour Ctrl+Z
+///
+/// Seules les actions qui modifient l'historique local (mode démo) sont enregistrées.
+pub fn with_trading_undo(
+    app: &mut ChartApp,
+    handler: fn(&mut ChartApp) -> Task<crate::app::messages::Message>,
+) -> Task<crate::app::messages::Message> {
+    let before = app.trading_state.trade_history.clone();
+    let task = handler(app);
+    let trading_state = &mut app.trading_state;
+    trading_state.undo_stack.record(before, &trading_state.trade_history);
+    task
+}
+
+/// Indique si le panneau affichant la section des ordres a le focus
+pub fn is_order_panel_focused(app: &ChartApp) -> bool {
+    use crate::app::state::BottomPanelSection;
+
+    let sections = &app.ui.bottom_panel_sections;
+    if sections.is_section_in_right_panel(BottomPanelSection::Orders) {
+        app.ui.panels.right.focused && sections.active_right_section == Some(BottomPanelSection::Orders)
+    } else {
+        app.ui.panels.bottom.focused && sections.active_bottom_section == BottomPanelSection::Orders
+    }
+}
+
+/// Annule (ou rétablit avec `redo`) la dernière action de trading
+pub fn handle_undo_trading_action(app: &mut ChartApp, redo: bool) {
+    let trading_state = &mut app.trading_state;
+    let applied = if redo {
+        trading_state.undo_stack.redo(&mut trading_state.trade_history)
+    } else {
+        trading_state.undo_stack.undo(&mut trading_state.trade_history)
+    };
+    if !applied {
+        return;
+    }
+
+    if let Err(e) = app.trading_state.trade_history.save_to_file("paper_trading.json") {
+        eprintln!("⚠️ Erreur sauvegarde historique trading: {}", e);
+    }
+    app.update_account_info();
+    app.ui.notifications.add_info(if redo {
+        "Action de trading rétablie".to_string()
+    } else {
+        "Action de trading annulée".to_string()
+    });
+}
+
 /// Gère le placement d'un ordre d'achat
 pub fn handle_place_buy_order(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
     if let Some(quantity) = app.trading_state.parse_quantity() {
@@ -103,6 +214,8 @@ pub fn handle_place_buy_order(app: &mut ChartApp) -> Task<crate::app::messages::
                         .unwrap_or(0.0)
                 }
             };
+            // En replay, le prix courant est la clôture de la dernière bougie révélée
+            let current_price = replay_quote(app).map_or(current_price, |(close, _)| close);
             
             let (price, total_amount) = match app.trading_state.order_type {
                 OrderType::Market => {
@@ -147,10 +260,12 @@ pub fn handle_place_buy_order(app: &mut ChartApp) -> Task<crate::app::messages::
                         // En mode démo, simuler l'ordre
                         if app.account_type.is_demo() {
                             // Récupérer le timestamp de la dernière bougie
-                            let timestamp = app.chart_state.series_manager
-                                .active_series()
-                                .next()
-                                .and_then(|s| s.data.last_candle().map(|c| c.timestamp));
+                            let timestamp = replay_quote(app).map(|(_, timestamp)| timestamp).or_else(|| {
+                                app.chart_state.series_manager
+                                    .active_series()
+                                    .next()
+                                    .and_then(|s| s.data.last_candle().map(|c| c.timestamp))
+                            });
                             
                             // Ouvrir une position d'achat avec TP/SL
                             let position = app.trading_state.trade_history.open_buy_position_with_tp_sl_and_strategy(
@@ -168,6 +283,7 @@ pub fn handle_place_buy_order(app: &mut ChartApp) -> Task<crate::app::messages::
                             if take_profit.is_some() || stop_loss.is_some() {
                                 println!("  📊 TP: {:?}, SL: {:?}", take_profit, stop_loss);
                             }
+                            apply_trailing_stop(app, &symbol, price);
                             
                             // Sauvegarder l'historique
                             if let Err(e) = app.trading_state.trade_history.save_to_file("paper_trading.json") {
@@ -222,10 +338,12 @@ pub fn handle_place_buy_order(app: &mut ChartApp) -> Task<crate::app::messages::
                                 order.id, price);
                             
                             // Vérifier immédiatement si l'ordre peut être exécuté
-                            let timestamp = app.chart_state.series_manager
-                                .active_series()
-                                .next()
-                                .and_then(|s| s.data.last_candle().map(|c| c.timestamp));
+                            let timestamp = replay_quote(app).map(|(_, timestamp)| timestamp).or_else(|| {
+                                app.chart_state.series_manager
+                                    .active_series()
+                                    .next()
+                                    .and_then(|s| s.data.last_candle().map(|c| c.timestamp))
+                            });
                             app.trading_state.trade_history.check_and_execute_pending_orders(&symbol, current_price, timestamp);
                             
                             // Sauvegarder l'historique
@@ -325,6 +443,8 @@ pub fn handle_place_sell_order(app: &mut ChartApp) -> Task<crate::app::messages:
                         .unwrap_or(0.0)
                 }
             };
+            // En replay, le prix courant est la clôture de la dernière bougie révélée
+            let current_price = replay_quote(app).map_or(current_price, |(close, _)| close);
             
             let (price, total_amount) = match app.trading_state.order_type {
                 OrderType::Market => {
@@ -367,10 +487,12 @@ pub fn handle_place_sell_order(app: &mut ChartApp) -> Task<crate::app::messages:
                     // En mode démo, simuler l'ordre
                     if app.account_type.is_demo() {
                         // Récupérer le timestamp de la dernière bougie
-                        let timestamp = app.chart_state.series_manager
-                            .active_series()
-                            .next()
-                            .and_then(|s| s.data.last_candle().map(|c| c.timestamp));
+                        let timestamp = replay_quote(app).map(|(_, timestamp)| timestamp).or_else(|| {
+                            app.chart_state.series_manager
+                                .active_series()
+                                .next()
+                                .and_then(|s| s.data.last_candle().map(|c| c.timestamp))
+                        });
                         
                         // Essayer de fermer une position existante
                         if let Some(trade) = app.trading_state.trade_history.close_position_with_strategy(
@@ -394,6 +516,7 @@ pub fn handle_place_sell_order(app: &mut ChartApp) -> Task<crate::app::messages:
                             if take_profit.is_some() || stop_loss.is_some() {
                                 println!("  📊 TP: {:?}, SL: {:?}", take_profit, stop_loss);
                             }
+                            apply_trailing_stop(app, &symbol, price);
                         }
                         
                         // Sauvegarder l'historique
@@ -443,10 +566,12 @@ pub fn handle_place_sell_order(app: &mut ChartApp) -> Task<crate::app::messages:
                             order.id, price);
                         
                         // Vérifier immédiatement si l'ordre peut être exécuté
-                        let timestamp = app.chart_state.series_manager
-                            .active_series()
-                            .next()
-                            .and_then(|s| s.data.last_candle().map(|c| c.timestamp));
+                        let timestamp = replay_quote(app).map(|(_, timestamp)| timestamp).or_else(|| {
+                            app.chart_state.series_manager
+                                .active_series()
+                                .next()
+                                .and_then(|s| s.data.last_candle().map(|c| c.timestamp))
+                        });
                         app.trading_state.trade_history.check_and_execute_pending_orders(&symbol, current_price, timestamp);
                         
                         // Sauvegarder l'historique
@@ -493,6 +618,71 @@ pub fn handle_place_sell_order(app: &mut ChartApp) -> Task<crate::app::messages:
     Task::none()
 }
 
+/// Gère le placement d'un ordre OCO (take profit + stop liés) sur la position ouverte
+///
+/// Le sens de l'OCO est déduit de la position du symbole actif : vente pour
+/// sortir d'un long, achat pour sortir d'un short. Disponible en mode démo uniquement.
+pub fn handle_place_oco_order(app: &mut ChartApp) -> Task<crate::app::messages::Message> {
+    use crate::app::data::TradeType;
+
+    if !app.account_type.is_demo() {
+        app.ui.notifications.add_warning("Les ordres OCO ne sont disponibles qu'en mode démo".to_string());
+        return Task::none();
+    }
+
+    let Some(series) = app.chart_state.series_manager.active_series().next() else {
+        app.ui.notifications.add_warning("Aucune série active pour l'ordre OCO".to_string());
+        return Task::none();
+    };
+    let symbol = series.symbol.clone();
+    let last_candle = series.data.last_candle().copied();
+
+    let (Some(take_profit), Some(stop)) = (
+        app.trading_state.parse_take_profit(),
+        app.trading_state.parse_stop_loss(),
+    ) else {
+        app.ui.notifications.add_warning("Renseignez le Take Profit et le Stop Loss de l'ordre OCO".to_string());
+        return Task::none();
+    };
+
+    let Some(position) = app.trading_state.trade_history.open_positions
+        .iter()
+        .find(|p| p.symbol == symbol)
+    else {
+        app.ui.notifications.add_warning(format!("Aucune position ouverte sur {}", symbol));
+        return Task::none();
+    };
+    let side = match position.trade_type {
+        TradeType::Buy => TradeType::Sell,
+        TradeType::Sell => TradeType::Buy,
+    };
+    let quantity = app.trading_state.parse_quantity()
+        .filter(|&q| q > 0.0)
+        .map_or(position.quantity, |q| q.min(position.quantity));
+
+    let history = &mut app.trading_state.trade_history;
+    match history.create_oco_order(symbol.clone(), side, quantity, take_profit, stop) {
+        Ok((tp_order, stop_order)) => {
+            println!("  ✅ Ordre OCO créé: #{} (TP {:.2}) / #{} (stop {:.2})",
+                tp_order.id, take_profit, stop_order.id, stop);
+
+            // Vérifier immédiatement si l'un des niveaux est déjà atteint
+            if let Some(candle) = last_candle {
+                history.check_and_execute_pending_orders(&symbol, candle.close, Some(candle.timestamp));
+            }
+
+            if let Err(e) = history.save_to_file("paper_trading.json") {
+                eprintln!("⚠️ Erreur sauvegarde historique trading: {}", e);
+            }
+            app.update_account_info();
+            app.ui.notifications.add_success(format!("Ordre OCO placé sur {}", symbol));
+        }
+        Err(e) => app.ui.notifications.add_warning(e),
+    }
+
+    Task::none()
+}
+
 /// Gère le résultat du placement d'un ordre d'achat
 pub fn handle_buy_order_placed(
     app: &mut ChartApp,
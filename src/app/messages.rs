@@ -5,9 +5,10 @@
 use iced::window;
 use crate::finance_chart::{
     ChartMessage, YAxisMessage, XAxisMessage, ToolsPanelMessage, SeriesPanelMessage,
-    settings::SerializableColor,
+    settings::{SerializableColor, ChartRenderMode, CandleBodyStyle, CrosshairDash, ThemePreset, TooltipField, ZoomAnchor},
     core::{SeriesId, Candle, SeriesData},
-    ProviderType,
+    tools::DrawingKind,
+    ProviderType, WsEvent,
 };
 
 /// Messages de l'application
@@ -17,12 +18,20 @@ pub enum Message {
     Chart(ChartMessage),
     /// Réinitialiser le zoom de la vue du graphique
     ResetView,
+    /// Ramener la vue sur la dernière bougie (bouton flottant du graphique)
+    GoToLatest,
     /// Ouvrir le menu contextuel du graphique (position du curseur)
     /// Note: Ce message est utilisé indirectement via le clic droit sur le graphique
     #[allow(dead_code)]
     OpenChartContextMenu(iced::Point),
     /// Fermer le menu contextuel du graphique
     CloseChartContextMenu,
+    /// Exporter le graphique en PNG (fichier horodaté dans exports/)
+    ExportChartPng,
+    /// Exporter en CSV les bougies visibles (fichier horodaté dans exports/)
+    ExportVisibleCsv,
+    /// Activer/désactiver le mode comparaison (séries rebasées en %)
+    ToggleComparisonMode,
     /// Activer le mode "définir la tête de lecture" (attendre un clic sur le graphique)
     SetPlayheadMode,
     /// Démarrer le drag de la tête de lecture
@@ -52,14 +61,72 @@ pub enum Message {
     
     // === Messages des settings ===
     SelectColor(usize, SerializableColor),
+    /// Appliquer un thème de couleurs prédéfini (remplace toutes les couleurs)
+    ApplyThemePreset(ThemePreset),
     ApplySettings,
     CancelSettings,
     ToggleColorPicker(usize),
     ToggleAutoScroll,
+    /// Changer le mode de rendu des séries (bougies, ligne, aire)
+    SetRenderMode(ChartRenderMode),
+    /// Changer la largeur du corps des bougies (part de l'espace, None = adaptative)
+    SetCandleWidthRatio(Option<f32>),
+    /// Changer l'épaisseur des mèches (pixels)
+    SetWickWidth(f32),
+    /// Changer le style du corps des bougies (plein ou creux)
+    SetCandleBodyStyle(CandleBodyStyle),
+    /// Changer la densité de la grille
+    SetGridDensity(f32),
+    /// Activer/désactiver les lignes de grille pointillées
+    ToggleGridDashed,
+    /// Activer/désactiver les lignes de grille secondaires
+    ToggleGridMinorLines,
+    /// Changer l'épaisseur des lignes du crosshair (pixels)
+    SetCrosshairWidth(f32),
+    /// Changer le motif de trait du crosshair
+    SetCrosshairDash(CrosshairDash),
+    /// Afficher/masquer les étiquettes du crosshair sur les axes
+    ToggleCrosshairLabels,
+    /// Changer le nombre de décimales des prix (None = automatique)
+    SetPriceDecimals(Option<usize>),
+    /// Changer l'espace vide à droite de la dernière bougie (en bougies)
+    SetRightPadding(f32),
+    /// Activer/désactiver l'affichage Heikin-Ashi
+    ToggleHeikinAshi,
+    /// Activer/désactiver le mode aimant du crosshair
+    ToggleCrosshairMagnet,
+    /// Activer/désactiver l'affichage des trous de données
+    ToggleShowGaps,
+    /// Activer/désactiver la coloration des bougies par volume
+    ToggleVolumeHeatmap,
+    /// Activer/désactiver les séparateurs de journée
+    ToggleShowDaySeparators,
+    /// Activer/désactiver l'alignement de la vue sur des bougies entières après un pan
+    ToggleSnapPanToCandle,
+    /// Changer le point fixe du zoom à la molette
+    SetZoomAnchor(ZoomAnchor),
+    /// Définir le nombre maximal de requêtes simultanées vers le provider
+    SetMaxConcurrentRequests(usize),
+    /// Définir le nombre maximal de bougies gardées en mémoire par série (None = illimité)
+    SetMaxCandlesPerSeries(Option<usize>),
+    /// Activer/désactiver le bandeau d'informations du graphique
+    ToggleShowHud,
+    /// Activer/désactiver les liaisons entrée/sortie des trades clôturés
+    ToggleShowTradePnl,
+    /// Afficher/masquer un champ du tooltip OHLC
+    ToggleTooltipField(TooltipField),
+    /// Remonter un champ du tooltip d'un rang
+    MoveTooltipFieldUp(TooltipField),
+    /// Descendre un champ du tooltip d'un rang
+    MoveTooltipFieldDown(TooltipField),
     
     // === Messages temps réel ===
     RealtimeUpdate,
     RealtimeUpdateComplete(Vec<(SeriesId, String, Result<Option<Candle>, String>)>),
+    /// Une alerte de prix a été franchie (identifiant de l'alerte)
+    AlertTriggered(u64),
+    /// Événement du flux WebSocket Binance (état de connexion ou bougie)
+    BinanceWs(WsEvent),
     #[allow(dead_code)] // Utilisé dans main.rs mais jamais construit directement
     CompleteMissingData,
     CompleteMissingDataComplete(Vec<(SeriesId, String, Result<Vec<Candle>, String>)>),
@@ -80,11 +147,14 @@ pub enum Message {
     StopDownload(SeriesId),
     #[allow(dead_code)] // Utilisé dans le match de main.rs (ligne 308)
     CompleteGaps,
-    CompleteGapsComplete(Vec<(SeriesId, String, (i64, i64), Result<Vec<Candle>, String>)>),
+    CompleteGapsComplete(Vec<crate::app::realtime::GapFillResult>),
     SaveSeriesComplete(Vec<(String, Result<(), String>)>),
     #[allow(dead_code)] // Utilisé dans le match de main.rs (ligne 77)
     LoadSeriesFromDirectory,
     LoadSeriesFromDirectoryComplete(Result<Vec<SeriesData>, String>),
+    /// Page d'historique chargée depuis la base SQLite
+    #[cfg(feature = "sqlite")]
+    SqlitePageLoaded(SeriesId, Result<Vec<Candle>, String>),
     
     // === Messages de configuration des providers ===
     OpenProviderConfig,
@@ -109,9 +179,21 @@ pub enum Message {
     ToggleVolumePanel,
     ToggleRSIPanel,
     ToggleMACDPanel,
+    ToggleATRPanel,
+    ToggleOBVPanel,
+    ToggleCCIPanel,
+    ToggleWilliamsRPanel,
     ToggleBollingerBands,
     ToggleMovingAverage,
+    ToggleVWAP,
+    ToggleParabolicSAR,
+    ToggleDonchian,
+    ToggleKeltner,
+    ToggleVolumeProfile,
+    TogglePivots,
+    ToggleCrossoverMarkers,
     // Messages pour modifier les paramètres des indicateurs
+    UpdateVolumeMaPeriod(Option<usize>),
     UpdateRSIPeriod(usize),
     UpdateRSIMethod(crate::app::state::RSIMethod),
     UpdateMACDFastPeriod(usize),
@@ -119,22 +201,50 @@ pub enum Message {
     UpdateMACDSignalPeriod(usize),
     UpdateBollingerPeriod(usize),
     UpdateBollingerStdDev(f64),
-    UpdateMAPeriod(usize),
+    AddMovingAverage,
+    RemoveMovingAverage(usize),
+    UpdateMAPeriod(usize, usize), // (index, période)
+    UpdateMAType(usize, crate::app::state::MaType),
+    CycleMAColor(usize),
+    UpdateATRPeriod(usize),
+    UpdatePSARStep(f64),
+    UpdatePSARMaxStep(f64),
+    UpdateKeltnerEmaPeriod(usize),
+    UpdateKeltnerAtrPeriod(usize),
+    UpdateKeltnerMultiplier(f64),
+    UpdateCCIPeriod(usize),
+    UpdateWilliamsRPeriod(usize),
+    UpdatePivotMethod(crate::app::state::PivotMethod),
+    UpdateCrossoverFastPeriod(usize),
+    UpdateCrossoverSlowPeriod(usize),
+    UpdateCrossoverMaType(crate::app::state::MaType),
     StartResizeRightPanel(f32),
     StartResizeBottomPanel(f32),
     StartResizeVolumePanel(f32),
     StartResizeRSIPanel(f32),
     StartResizeMACDPanel(f32),
+    StartResizeATRPanel(f32),
+    StartResizeOBVPanel(f32),
+    StartResizeCCIPanel(f32),
+    StartResizeWilliamsRPanel(f32),
     UpdateResizeRightPanel(f32),
     UpdateResizeBottomPanel(f32),
     UpdateResizeVolumePanel(f32),
     UpdateResizeRSIPanel(f32),
     UpdateResizeMACDPanel(f32),
+    UpdateResizeATRPanel(f32),
+    UpdateResizeOBVPanel(f32),
+    UpdateResizeCCIPanel(f32),
+    UpdateResizeWilliamsRPanel(f32),
     EndResizeRightPanel,
     EndResizeBottomPanel,
     EndResizeVolumePanel,
     EndResizeRSIPanel,
     EndResizeMACDPanel,
+    EndResizeATRPanel,
+    EndResizeOBVPanel,
+    EndResizeCCIPanel,
+    EndResizeWilliamsRPanel,
     
     // === Messages de sélection de sections ===
     SelectBottomSection(crate::app::state::BottomPanelSection),
@@ -162,6 +272,10 @@ pub enum Message {
     SetVolumePanelFocus(bool),
     SetRSIPanelFocus(bool),
     SetMACDPanelFocus(bool),
+    SetATRPanelFocus(bool),
+    SetOBVPanelFocus(bool),
+    SetCCIPanelFocus(bool),
+    SetWilliamsRPanelFocus(bool),
     ClearPanelFocus,
     
     // === Messages de trading ===
@@ -171,8 +285,13 @@ pub enum Message {
     UpdateTakeProfit(String),
     UpdateStopLoss(String),
     ToggleTPSLEnabled,
+    UpdateTrailingOffset(String),
+    ToggleTrailingPercent,
+    UpdateRiskPercent(String),
     PlaceBuyOrder,
     PlaceSellOrder,
+    /// Place un ordre OCO (TP + stop liés) sur la position ouverte du symbole actif
+    PlaceOcoOrder,
     /// Résultat du placement d'un ordre d'achat
     BuyOrderPlaced(Result<crate::app::trading::api::OrderResponse, String>),
     /// Résultat du placement d'un ordre de vente
@@ -181,6 +300,8 @@ pub enum Message {
     // === Messages des stratégies de trading automatisées ===
     RegisterRSIStrategy,
     RegisterMACrossoverStrategy,
+    RegisterBollingerStrategy,
+    RegisterMacdStrategy,
     EnableStrategy(String),
     DisableStrategy(String),
     RemoveStrategy(String),
@@ -191,6 +312,7 @@ pub enum Message {
     UpdateStrategyTradingMode { strategy_id: String, trading_mode: crate::app::strategies::strategy::TradingMode }, // Met à jour le mode de trading temporairement
     ApplyStrategyConfig(String), // Applique les modifications d'une stratégie
     CancelStrategyConfig(String), // Annule les modifications d'une stratégie
+    ResetStrategyParameters(String), // Remet les paramètres en cours d'édition à leur valeur par défaut
     
     // === Messages de gestion d'erreurs ===
     /// Afficher un message d'erreur à l'utilisateur
@@ -238,5 +360,45 @@ pub enum Message {
     StopBacktest,
     /// Mise à jour de la lecture du backtest (appelé périodiquement)
     BacktestTick,
+    /// Rejouer tout l'historique de la série active avec une stratégie (ID) et afficher le rapport
+    RunBacktest(String),
+    /// Rapport du backtest lancé par `RunBacktest`
+    BacktestComplete(Result<crate::app::strategies::backtest::BacktestReport, String>),
+    /// Afficher (ou masquer) les signaux qu'une stratégie (ID) aurait émis sur la série active
+    PreviewStrategySignals(String),
+    /// Signaux calculés par `PreviewStrategySignals`
+    StrategySignalsPreviewed(Result<crate::app::state::backtest::SignalPreview, String>),
+    
+    // === Messages du mode replay ===
+    /// Démarrer le replay à la position du menu contextuel du graphique
+    ReplayStart,
+    /// Révéler la bougie suivante
+    ReplayStep,
+    /// Lancer ou mettre en pause la lecture automatique
+    ReplayPlay,
+    /// Changer la vitesse de lecture (millisecondes entre chaque bougie)
+    ReplaySetSpeed(u64),
+    /// Quitter le replay et révéler toutes les bougies
+    ReplayStop,
+    
+    // === Messages de la liste des dessins ===
+    /// Verrouiller/déverrouiller un dessin
+    ToggleDrawingLocked { kind: DrawingKind, index: usize },
+    /// Masquer/réafficher un dessin
+    ToggleDrawingHidden { kind: DrawingKind, index: usize },
+    /// Supprimer un dessin (annulable)
+    DeleteDrawing { kind: DrawingKind, index: usize },
+    /// Sélectionner un dessin depuis la liste (comme un clic sur le graphique)
+    SelectDrawing { kind: DrawingKind, index: usize },
+    /// Ouvrir le champ de renommage d'un dessin
+    StartRenameDrawing { kind: DrawingKind, index: usize },
+    /// Modifier le nom en cours de saisie
+    UpdateDrawingNameDraft(String),
+    /// Valider le renommage (annulable)
+    CommitDrawingName,
+    /// Annuler le renommage
+    CancelDrawingRename,
+    /// Changer la couleur d'un dessin (annulable)
+    SetDrawingColor { kind: DrawingKind, index: usize, color: iced::Color },
 }
 
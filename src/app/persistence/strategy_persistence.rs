@@ -13,6 +13,8 @@ use crate::app::strategies::strategy::{TradingStrategy, TradingMode};
 pub enum StrategyType {
     RSI,
     MovingAverageCrossover,
+    BollingerBreakout,
+    MacdCross,
 }
 
 /// État sérialisable d'une stratégie
@@ -85,6 +87,10 @@ pub fn strategy_to_persistence(
         StrategyType::RSI
     } else if reg.strategy.name().contains("MA Crossover") || reg.strategy.name().contains("Moving Average") {
         StrategyType::MovingAverageCrossover
+    } else if reg.strategy.name().contains("Bollinger") {
+        StrategyType::BollingerBreakout
+    } else if reg.strategy.name().contains("MACD") {
+        StrategyType::MacdCross
     } else {
         return Err(format!("Type de stratégie inconnu: {}", reg.strategy.name()));
     };
@@ -111,32 +117,24 @@ pub fn strategy_to_persistence(
 pub fn persistence_to_strategy(
     state: &StrategyPersistenceState,
 ) -> Result<RegisteredStrategy, String> {
-    use crate::app::strategies::examples::{RSIStrategy, MovingAverageCrossoverStrategy};
+    use crate::app::strategies::examples::{
+        BollingerBreakoutStrategy, MacdCrossStrategy, MovingAverageCrossoverStrategy, RSIStrategy,
+    };
     
     // Créer la stratégie selon son type
-    let strategy: Box<dyn TradingStrategy> = match state.strategy_type {
-        StrategyType::RSI => {
-            let mut s = RSIStrategy::new();
-            // Appliquer les paramètres sauvegardés
-            for (name, value) in &state.parameters {
-                if let Err(e) = s.update_parameter(name, *value) {
-                    eprintln!("⚠️ Erreur lors de la restauration du paramètre {}: {}", name, e);
-                }
-            }
-            Box::new(s)
-        }
-        StrategyType::MovingAverageCrossover => {
-            let mut s = MovingAverageCrossoverStrategy::new();
-            // Appliquer les paramètres sauvegardés
-            for (name, value) in &state.parameters {
-                if let Err(e) = s.update_parameter(name, *value) {
-                    eprintln!("⚠️ Erreur lors de la restauration du paramètre {}: {}", name, e);
-                }
-            }
-            Box::new(s)
-        }
+    let mut strategy: Box<dyn TradingStrategy> = match state.strategy_type {
+        StrategyType::RSI => Box::new(RSIStrategy::new()),
+        StrategyType::MovingAverageCrossover => Box::new(MovingAverageCrossoverStrategy::new()),
+        StrategyType::BollingerBreakout => Box::new(BollingerBreakoutStrategy::new()),
+        StrategyType::MacdCross => Box::new(MacdCrossStrategy::new()),
     };
     
+    // Appliquer les paramètres sauvegardés
+    let saved: Vec<(String, f64)> = state.parameters.iter().map(|(name, value)| (name.clone(), *value)).collect();
+    for e in strategy.set_parameters(&saved) {
+        eprintln!("⚠️ Erreur lors de la restauration d'un paramètre: {}", e);
+    }
+    
     // Mettre à jour le nom si nécessaire
     if strategy.name() != state.name {
         // Note: On ne peut pas changer le nom directement, mais on peut le vérifier
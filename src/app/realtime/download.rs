@@ -29,7 +29,7 @@ pub fn load_full_history(app: &mut ChartApp, series_id: SeriesId) -> Task<Messag
     println!("🔄 Chargement de l'historique complet pour {}...", series_name);
     
     // Arc::clone est très efficace (juste un compteur atomique)
-    let provider = Arc::clone(&app.binance_provider);
+    let provider = Arc::clone(&app.data_provider);
     
     // Créer une Task async pour télécharger l'historique complet
     Task::perform(
@@ -59,7 +59,12 @@ pub fn download_batch(app: &mut ChartApp, series_id: &SeriesId) -> Task<Message>
         }
     };
     
-    let provider = Arc::clone(&app.binance_provider);
+    let provider = Arc::clone(&app.data_provider);
+    let cache = Arc::clone(&app.candle_cache);
+    let provider_type = app.provider_config.active_provider;
+    let cache_key = app.chart_state.series_manager
+        .get_series(series_id)
+        .map(|series| (series.symbol.clone(), series.interval.clone()));
     let series_id_clone = progress.series_id.clone();
     let gap_start = progress.current_start;  // timestamp le plus ancien du gap (objectif)
     let current_end = progress.target_end;     // timestamp actuel (on descend vers gap_start)
@@ -70,6 +75,18 @@ pub fn download_batch(app: &mut ChartApp, series_id: &SeriesId) -> Task<Message>
     
     Task::perform(
         async move {
+            // Plage déjà téléchargée : la servir depuis le cache disque, sans appel API
+            if let Some((symbol, interval)) = &cache_key {
+                let cached = Arc::clone(&cache)
+                    .get_range_async(provider_type, symbol.clone(), interval.clone(), gap_start, current_end)
+                    .await;
+                if let Some(cached) = cached {
+                    println!("    💾 Cache: {} bougies de {} à {}", cached.len(), gap_start, current_end);
+                    let new_count = current_count + cached.len();
+                    return (series_id_clone.clone(), cached, new_count, estimated_total, gap_start);
+                }
+            }
+            
             // Petite pause pour éviter de surcharger l'API
             tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
             
@@ -116,8 +133,25 @@ pub fn download_batch(app: &mut ChartApp, series_id: &SeriesId) -> Task<Message>
                         oldest_in_batch - 1
                     };
                     
+                    // Mémoriser la plage couverte par ce batch dans le cache disque
+                    if let Some((symbol, interval)) = &cache_key {
+                        let covered_start = if next_end == gap_start { gap_start } else { oldest_in_batch };
+                        let inserted = Arc::clone(&cache)
+                            .insert_range_async(provider_type, symbol.clone(), interval.clone(), covered_start, current_end, filtered_candles.clone())
+                            .await;
+                        if let Err(e) = inserted {
+                            eprintln!("    ⚠️ Cache de bougies non mis à jour: {}", e);
+                        }
+                    }
+                    
                     (series_id_clone.clone(), filtered_candles, new_count, estimated_total, next_end)
                 }
+                Err(e) if e.is_rate_limited() => {
+                    // Limite de débit toujours active après les tentatives du provider :
+                    // reprendre la même fenêtre au prochain batch au lieu d'abandonner le gap
+                    eprintln!("  ⏳ Limite de requêtes atteinte, le batch sera repris: {}", e);
+                    (series_id_clone.clone(), Vec::new(), current_count, estimated_total, current_end)
+                }
                 Err(e) => {
                     eprintln!("  ❌ Erreur téléchargement: {}", e);
                     (series_id_clone.clone(), Vec::new(), current_count, estimated_total, gap_start)
@@ -19,9 +19,15 @@ use crate::app::{
     },
 };
 
+/// Requête de complétion d'un gap : série, nom, (symbole, intervalle) pour le cache, (début, fin)
+type GapRequest = (SeriesId, String, (String, String), (i64, i64));
+
+/// Résultat de la complétion d'un gap : série, nom, (début, fin) et bougies récupérées
+pub type GapFillResult = (SeriesId, String, (i64, i64), Result<Vec<Candle>, String>);
+
 /// Complète les données manquantes pour toutes les séries
 pub fn complete_missing_data(app: &mut ChartApp) -> Task<Message> {
-    println!("🔄 Complétion des données manquantes depuis Binance...");
+    println!("🔄 Complétion des données manquantes depuis {}...", app.provider_config.active_provider.display_name());
     
     // Collecter toutes les informations nécessaires d'abord
     let mut updates: Vec<(SeriesId, String, Option<i64>)> = Vec::new();
@@ -47,16 +53,17 @@ pub fn complete_missing_data(app: &mut ChartApp) -> Task<Message> {
     }
     
     // Arc::clone est très efficace (juste un compteur atomique)
-    let provider = Arc::clone(&app.binance_provider);
+    let provider = Arc::clone(&app.data_provider);
     
     // Calculer le timestamp actuel une seule fois
     let now = current_timestamp();
+    let max_concurrent = app.settings_state.concurrency_limit();
     
-    // Créer une Task async qui fait toutes les requêtes en parallèle
-    println!("🚀 Démarrage des requêtes async pour {} série(s)", updates.len());
+    // Créer une Task async qui fait les requêtes en parallèle (au plus `max_concurrent` à la fois)
+    println!("🚀 Démarrage des requêtes async pour {} série(s) ({} simultanée(s) max)", updates.len(), max_concurrent);
     Task::perform(
         async move {
-            use futures::future::join_all;
+            use futures::stream::{self, StreamExt};
             
             // Créer un vecteur de futures pour toutes les requêtes
             let futures: Vec<_> = updates
@@ -95,8 +102,11 @@ pub fn complete_missing_data(app: &mut ChartApp) -> Task<Message> {
                 })
                 .collect();
             
-            // Exécuter toutes les requêtes en parallèle
-            let results = join_all(futures).await;
+            // Exécuter les requêtes en parallèle, en limitant le nombre simultané
+            let results: Vec<_> = stream::iter(futures)
+                .buffer_unordered(max_concurrent)
+                .collect()
+                .await;
             println!("✅ Toutes les requêtes async terminées");
             results
         },
@@ -134,6 +144,7 @@ pub fn apply_complete_missing_data_results(app: &mut ChartApp, results: Vec<(Ser
     
     // Après avoir complété les données manquantes, détecter et compléter les gaps internes
     if has_updates {
+        app.apply_history_cap();
         println!("🔍 Vérification des gaps dans les données...");
         return complete_gaps(app);
     }
@@ -147,7 +158,7 @@ pub fn apply_complete_missing_data_results(app: &mut ChartApp, results: Vec<(Ser
 /// Détecte et complète les gaps dans toutes les séries de manière asynchrone
 pub fn complete_gaps(app: &mut ChartApp) -> Task<Message> {
     // Collecter toutes les informations nécessaires
-    let mut gap_requests: Vec<(SeriesId, String, (i64, i64))> = Vec::new();
+    let mut gap_requests: Vec<GapRequest> = Vec::new();
     
     for series in app.chart_state.series_manager.all_series() {
         let series_id = series.id.clone();
@@ -169,7 +180,7 @@ pub fn complete_gaps(app: &mut ChartApp) -> Task<Message> {
             println!("  🔍 {}: {} gap(s) détecté(s)", series_name, gaps.len());
             // Ajouter chaque gap comme une requête séparée
             for gap in gaps {
-                gap_requests.push((series_id.clone(), series_name.clone(), gap));
+                gap_requests.push((series_id.clone(), series_name.clone(), (series.symbol.clone(), series.interval.clone()), gap));
             }
         }
     }
@@ -180,36 +191,59 @@ pub fn complete_gaps(app: &mut ChartApp) -> Task<Message> {
     }
     
     // Arc::clone est très efficace (juste un compteur atomique)
-    let provider = Arc::clone(&app.binance_provider);
+    let provider = Arc::clone(&app.data_provider);
+    let cache = Arc::clone(&app.candle_cache);
+    let provider_type = app.provider_config.active_provider;
+    let max_concurrent = app.settings_state.concurrency_limit();
     
-    // Créer une Task async qui fait toutes les requêtes en parallèle
-    println!("🚀 Démarrage de la complétion des gaps pour {} gap(s)", gap_requests.len());
+    // Créer une Task async qui fait les requêtes en parallèle (au plus `max_concurrent` à la fois)
+    println!("🚀 Démarrage de la complétion des gaps pour {} gap(s) ({} simultanée(s) max)", gap_requests.len(), max_concurrent);
     Task::perform(
         async move {
-            use futures::future::join_all;
+            use futures::stream::{self, StreamExt};
             
             // Créer un vecteur de futures pour toutes les requêtes
             let futures: Vec<_> = gap_requests
                 .into_iter()
-                .map(|(series_id, series_name, (gap_start, gap_end))| {
+                .map(|(series_id, series_name, (symbol, interval), (gap_start, gap_end))| {
                     let provider = Arc::clone(&provider);
+                    let cache = Arc::clone(&cache);
                     let series_id_clone = series_id.clone();
                     let series_name_clone = series_name.clone();
                     
                     async move {
+                        // Gap déjà téléchargé : le servir depuis le cache disque, sans appel API
+                        let cached = Arc::clone(&cache)
+                            .get_range_async(provider_type, symbol.clone(), interval.clone(), gap_start, gap_end)
+                            .await;
+                        if let Some(cached) = cached {
+                            println!("  💾 {}: {} bougies du cache pour le gap de {} à {}", series_name_clone, cached.len(), gap_start, gap_end);
+                            return (series_id, series_name_clone, (gap_start, gap_end), Ok(cached));
+                        }
                         let gap_days = (gap_end - gap_start) / 86400;
                         println!("  📥 {}: Complétion du gap de {} jours ({} à {})", series_name_clone, gap_days, gap_start, gap_end);
                         // Utiliser la version avec pagination pour les gros gaps
                         let result = provider.fetch_all_candles_in_range_async(&series_id_clone, gap_start, gap_end)
                             .await
                             .map_err(|e| e.to_string());
+                        if let Ok(candles) = &result {
+                            let inserted = cache
+                                .insert_range_async(provider_type, symbol, interval, gap_start, gap_end, candles.clone())
+                                .await;
+                            if let Err(e) = inserted {
+                                eprintln!("  ⚠️ Cache de bougies non mis à jour: {}", e);
+                            }
+                        }
                         (series_id, series_name_clone, (gap_start, gap_end), result)
                     }
                 })
                 .collect();
             
-            // Exécuter toutes les requêtes en parallèle
-            let results = join_all(futures).await;
+            // Exécuter les requêtes en parallèle, en limitant le nombre simultané
+            let results: Vec<_> = stream::iter(futures)
+                .buffer_unordered(max_concurrent)
+                .collect()
+                .await;
             println!("✅ Toutes les requêtes de complétion des gaps terminées");
             results
         },
@@ -218,7 +252,7 @@ pub fn complete_gaps(app: &mut ChartApp) -> Task<Message> {
 }
 
 /// Applique les résultats de la complétion des gaps
-pub fn apply_complete_gaps_results(app: &mut ChartApp, results: Vec<(SeriesId, String, (i64, i64), Result<Vec<Candle>, String>)>) -> Task<Message> {
+pub fn apply_complete_gaps_results(app: &mut ChartApp, results: Vec<GapFillResult>) -> Task<Message> {
     let mut has_updates = false;
     let mut updated_series: std::collections::HashSet<SeriesId> = std::collections::HashSet::new();
     
@@ -247,6 +281,10 @@ pub fn apply_complete_gaps_results(app: &mut ChartApp, results: Vec<(SeriesId, S
         }
     }
     
+    if has_updates {
+        app.apply_history_cap();
+    }
+    
     // Lancer la sauvegarde de manière asynchrone pour ne pas bloquer l'UI
     if !updated_series.is_empty() {
         // Après les merges, recalculer et stocker le MACD avant d'éventuellement sauvegarder
@@ -304,6 +342,7 @@ pub fn has_gaps_to_fill(app: &ChartApp, series_id: &SeriesId) -> bool {
 
 /// Complète automatiquement une série avec toutes les données manquantes
 /// Télécharge par batch de 1000 et met à jour le graphique progressivement
+/// Chaque batch consulte d'abord le cache disque (`CandleCache`) avant d'interroger l'API
 pub fn auto_complete_series(app: &mut ChartApp, series_id: SeriesId) -> Task<Message> {
     
     // Vérifier si le format est compatible avec Binance et extraire toutes les infos nécessaires
@@ -337,7 +376,7 @@ pub fn auto_complete_series(app: &mut ChartApp, series_id: SeriesId) -> Task<Mes
     
     println!("🔄 Auto-complétion pour {}...", series_name);
     
-    let provider = Arc::clone(&app.binance_provider);
+    let provider = Arc::clone(&app.data_provider);
     let series_id_clone = series_id.clone();
     let internal_gaps_clone = internal_gaps.clone();
     
@@ -21,11 +21,12 @@ mod realtime_utils;
 pub use updates::{update_realtime, apply_realtime_updates};
 pub use gaps::{
     has_gaps_to_fill, auto_complete_series, complete_missing_data,
-    apply_complete_missing_data_results, complete_gaps, apply_complete_gaps_results,
+    apply_complete_missing_data_results, complete_gaps, apply_complete_gaps_results, GapFillResult,
 };
 pub use download::{load_full_history, download_batch};
 pub use save::save_series_async;
 pub use connection::{test_provider_connection, fetch_account_info, load_assets};
+pub use realtime_utils::{is_binance_format, current_timestamp};
 
 
 
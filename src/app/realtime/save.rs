@@ -16,7 +16,7 @@ pub fn save_series_async(app: &mut ChartApp, updated_series: HashSet<SeriesId>)
     println!("💾 Lancement de la sauvegarde asynchrone des séries mises à jour...");
     
     // Collecter les données à sauvegarder (cloner ce qui est nécessaire)
-    let save_requests: Vec<(String, String, String, Vec<crate::finance_chart::core::Candle>, std::path::PathBuf)> = updated_series
+    let save_requests: Vec<(String, String, String, Vec<crate::finance_chart::core::Candle>, std::path::PathBuf, bool)> = updated_series
         .iter()
         .filter_map(|series_id| {
             app.chart_state.series_manager.get_series(series_id)
@@ -36,7 +36,7 @@ pub fn save_series_async(app: &mut ChartApp, updated_series: HashSet<SeriesId>)
                     let interval = series.interval.clone();
                     // Cloner toutes les bougies
                     let candles: Vec<crate::finance_chart::core::Candle> = series.data.all_candles().to_vec();
-                    (file_path_str, symbol, interval, candles, file_path)
+                    (file_path_str, symbol, interval, candles, file_path, series.history_trimmed)
                 })
         })
         .collect();
@@ -52,7 +52,7 @@ pub fn save_series_async(app: &mut ChartApp, updated_series: HashSet<SeriesId>)
             
             // Créer les dossiers si nécessaire
             use std::fs;
-            for (_, _, _, _, ref file_path) in &save_requests {
+            for (_, _, _, _, ref file_path, _) in &save_requests {
                 if let Some(parent) = file_path.parent() {
                     let _ = fs::create_dir_all(parent);
                 }
@@ -60,7 +60,7 @@ pub fn save_series_async(app: &mut ChartApp, updated_series: HashSet<SeriesId>)
             
             let futures: Vec<_> = save_requests
                 .into_iter()
-                .map(|(file_path, symbol, interval, candles, _file_path_buf)| {
+                .map(|(file_path, symbol, interval, candles, _file_path_buf, history_trimmed)| {
                     let file_path_clone = file_path.clone();
                     async move {
                         // Extraire le nom de la série depuis le chemin du fichier
@@ -79,8 +79,8 @@ pub fn save_series_async(app: &mut ChartApp, updated_series: HashSet<SeriesId>)
                         let series_name_for_result = series_name.clone();
                         
                         let result = tokio::task::spawn_blocking(move || {
-                            // Utiliser save_to_json en créant une SeriesData temporaire
-                            use crate::finance_chart::{core::{SeriesData, SeriesId, TimeSeries}, data_loader::save_to_json};
+                            // Sauvegarder via une SeriesData temporaire
+                            use crate::finance_chart::core::{SeriesData, SeriesId, TimeSeries};
                             
                             // Utiliser le nom de la série (pas le chemin complet)
                             let series_id = SeriesId::new(series_name);
@@ -100,10 +100,28 @@ pub fn save_series_async(app: &mut ChartApp, updated_series: HashSet<SeriesId>)
                                 }
                                 ts
                             };
-                            let series_data = SeriesData::new(series_id, symbol, interval, timeseries);
+                            let mut series_data = SeriesData::new(series_id, symbol, interval, timeseries);
+                            series_data.history_trimmed = history_trimmed;
                             
-                            save_to_json(&series_data, &file_path_clone)
-                                .map_err(|e| e.to_string())
+                            #[cfg(feature = "sqlite")]
+                            let saved = crate::app::data::sqlite_store::save_series(&series_data);
+                            // Série tronquée en mémoire : seule la fin du fichier est réécrite, ce qui
+                            // conserve l'historique plus ancien sans le relire
+                            #[cfg(not(feature = "sqlite"))]
+                            let saved = {
+                                use crate::finance_chart::data_loader::{append_to_json, save_to_json};
+                                if series_data.history_trimmed {
+                                    match append_to_json(&series_data, &file_path_clone) {
+                                        Ok(true) => Ok(()),
+                                        // Fichier absent ou illisible : réécriture complète
+                                        Ok(false) => save_to_json(&series_data, &file_path_clone),
+                                        Err(e) => Err(e),
+                                    }
+                                } else {
+                                    save_to_json(&series_data, &file_path_clone)
+                                }
+                            };
+                            saved.map_err(|e| e.to_string())
                         }).await;
                         
                         match result {
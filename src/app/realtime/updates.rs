@@ -21,9 +21,9 @@ pub fn update_realtime(app: &mut ChartApp) -> Task<Message> {
         return Task::none();
     }
     
-    // Collecter les IDs des séries actives d'abord
+    // Collecter les IDs des séries actives d'abord (les séries figées sont ignorées)
     let active_series: Vec<(SeriesId, String)> = app.chart_state.series_manager
-        .active_series()
+        .realtime_series()
         .filter_map(|s| {
             let name = s.full_name();
             // Vérifier si le format est compatible avec Binance
@@ -40,7 +40,7 @@ pub fn update_realtime(app: &mut ChartApp) -> Task<Message> {
     }
     
     // Arc::clone est très efficace (juste un compteur atomique)
-    let provider = Arc::clone(&app.binance_provider);
+    let provider = Arc::clone(&app.data_provider);
     
     // Créer une Task async qui fait toutes les requêtes en parallèle
     println!("🚀 Démarrage des requêtes async pour {} série(s)", active_series.len());
@@ -75,9 +75,18 @@ pub fn update_realtime(app: &mut ChartApp) -> Task<Message> {
 }
 
 /// Applique les résultats des mises à jour en temps réel
-pub fn apply_realtime_updates(app: &mut ChartApp, results: Vec<(SeriesId, String, Result<Option<Candle>, String>)>) {
+///
+/// Retourne les identifiants des alertes de prix déclenchées par les nouvelles bougies.
+pub fn apply_realtime_updates(app: &mut ChartApp, results: Vec<(SeriesId, String, Result<Option<Candle>, String>)>) -> Vec<u64> {
     let mut has_updates = false;
     let mut has_new_candles = false;
+    let mut triggered_alerts = Vec::new();
+    
+    // Les alertes portent sur la série principale
+    let primary_series_id = app.chart_state.series_manager
+        .active_series()
+        .next()
+        .map(|s| s.id.clone());
     
     // Collecter les symboles, prix et timestamps avant de traiter les résultats
     let mut symbol_prices = Vec::new();
@@ -90,6 +99,10 @@ pub fn apply_realtime_updates(app: &mut ChartApp, results: Vec<(SeriesId, String
                     symbol_prices.push((series.symbol.clone(), candle.close, candle.timestamp));
                 }
                 
+                if primary_series_id.as_ref() == Some(series_id) {
+                    triggered_alerts.extend(app.chart_state.alerts.check(candle));
+                }
+                
                 match app.chart_state.update_candle(series_id, candle.clone()) {
                     UpdateResult::NewCandle => {
                         println!("🔄 {}: Nouvelle bougie ajoutée", series_name);
@@ -115,6 +128,11 @@ pub fn apply_realtime_updates(app: &mut ChartApp, results: Vec<(SeriesId, String
         }
     }
     
+    // Les nouvelles bougies peuvent faire dépasser la limite d'historique
+    if has_new_candles {
+        app.apply_history_cap();
+    }
+    
     // Ajuster le viewport si nécessaire (si auto-scroll activé et nouvelles bougies)
     if has_new_candles && app.chart_style.auto_scroll_enabled {
         app.chart_state.auto_scroll_to_latest();
@@ -161,5 +179,7 @@ pub fn apply_realtime_updates(app: &mut ChartApp, results: Vec<(SeriesId, String
             app.update_account_info();
         }
     }
+    
+    triggered_alerts
 }
 
@@ -2,7 +2,18 @@
 //!
 //! Ce module gère l'état du backtest : date de départ, état de lecture, etc.
 
-use crate::app::data::TradeHistory;
+use crate::app::data::{TradeHistory, TradeType};
+use crate::app::strategies::backtest::BacktestReport;
+use crate::finance_chart::core::SeriesId;
+
+/// Signaux qu'une stratégie aurait émis sur l'historique d'une série (aucun ordre exécuté)
+#[derive(Debug, Clone)]
+pub struct SignalPreview {
+    pub strategy_id: String,
+    pub series_id: SeriesId,
+    /// Timestamp de la bougie et sens du signal
+    pub signals: Vec<(i64, TradeType)>,
+}
 
 /// État du backtest
 #[derive(Debug, Clone)]
@@ -25,6 +36,10 @@ pub struct BacktestState {
     pub initial_capital: f64,
     /// Indique si on est en train de déplacer la tête de lecture par drag
     pub dragging_playhead: bool,
+    /// Dernier rapport de backtest complet (`Message::RunBacktest`)
+    pub last_report: Option<BacktestReport>,
+    /// Aperçu des signaux affiché sur le graphique (`Message::PreviewStrategySignals`)
+    pub signal_preview: Option<SignalPreview>,
 }
 
 impl Default for BacktestState {
@@ -39,6 +54,8 @@ impl Default for BacktestState {
             backtest_trade_history: TradeHistory::new(),
             initial_capital: 10000.0, // Capital par défaut pour le backtest
             dragging_playhead: false,
+            last_report: None,
+            signal_preview: None,
         }
     }
 }
@@ -24,6 +24,10 @@ pub enum BottomPanelSection {
     Strategies,
     /// Section pour le backtest
     Backtest,
+    /// Section pour la courbe d'equity
+    Equity,
+    /// Section listant les dessins (verrouillage, visibilité, suppression)
+    Drawings,
 }
 
 impl BottomPanelSection {
@@ -38,6 +42,8 @@ impl BottomPanelSection {
             Self::TradeHistory,
             Self::Strategies,
             Self::Backtest,
+            Self::Equity,
+            Self::Drawings,
         ]
     }
     
@@ -52,6 +58,8 @@ impl BottomPanelSection {
             Self::TradeHistory => "Historique",
             Self::Strategies => "Stratégies",
             Self::Backtest => "Backtest",
+            Self::Equity => "Equity",
+            Self::Drawings => "Dessins",
         }
     }
 }
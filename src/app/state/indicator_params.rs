@@ -1,6 +1,7 @@
 //! Paramètres configurables des indicateurs techniques
 
 use serde::{Deserialize, Serialize};
+use crate::finance_chart::settings::SerializableColor;
 
 /// Méthode de calcul du RSI
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -34,9 +35,122 @@ impl Default for RSIMethod {
     }
 }
 
+/// Formule des points pivots
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PivotMethod {
+    /// Pivots classiques (floor pivots)
+    #[default]
+    Classic,
+    /// Pivots de Fibonacci (ratios 0.382 / 0.618 / 1.0)
+    Fibonacci,
+}
+
+impl PivotMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PivotMethod::Classic => "Classic",
+            PivotMethod::Fibonacci => "Fibonacci",
+        }
+    }
+    
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "Classic" => Some(PivotMethod::Classic),
+            "Fibonacci" => Some(PivotMethod::Fibonacci),
+            _ => None,
+        }
+    }
+}
+
+/// Type de moyenne mobile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum MaType {
+    /// Moyenne mobile simple
+    #[default]
+    Sma,
+    /// Moyenne mobile exponentielle
+    Ema,
+    /// Moyenne mobile pondérée linéairement
+    Wma,
+}
+
+impl MaType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MaType::Sma => "SMA",
+            MaType::Ema => "EMA",
+            MaType::Wma => "WMA",
+        }
+    }
+    
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "SMA" => Some(MaType::Sma),
+            "EMA" => Some(MaType::Ema),
+            "WMA" => Some(MaType::Wma),
+            _ => None,
+        }
+    }
+}
+
+/// Nombre maximum de moyennes mobiles affichées simultanément
+pub const MAX_MOVING_AVERAGES: usize = 8;
+
+/// Palette de couleurs proposée pour les moyennes mobiles (RGB)
+const MA_PALETTE: [(f32, f32, f32); 6] = [
+    (1.0, 0.5, 0.0),  // Orange
+    (0.2, 0.7, 1.0),  // Bleu
+    (0.9, 0.3, 0.9),  // Magenta
+    (1.0, 0.9, 0.2),  // Jaune
+    (0.3, 0.9, 0.4),  // Vert
+    (0.9, 0.9, 0.9),  // Blanc
+];
+
+/// Configurations proposées lors de l'ajout d'une moyenne mobile
+const MA_PRESETS: [(usize, MaType); 5] = [
+    (20, MaType::Sma),
+    (50, MaType::Sma),
+    (200, MaType::Sma),
+    (9, MaType::Ema),
+    (21, MaType::Ema),
+];
+
+/// Configuration d'une moyenne mobile affichée sur le graphique
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MaConfig {
+    pub period: usize,
+    pub ma_type: MaType,
+    pub color: SerializableColor,
+}
+
+impl MaConfig {
+    /// Configuration proposée pour la n-ième moyenne mobile (SMA 20, SMA 50, SMA 200, EMA 9...)
+    pub fn preset(index: usize) -> Self {
+        let (period, ma_type) = MA_PRESETS[index % MA_PRESETS.len()];
+        let (r, g, b) = MA_PALETTE[index % MA_PALETTE.len()];
+        Self {
+            period,
+            ma_type,
+            color: SerializableColor::from_rgba(r, g, b, 0.9),
+        }
+    }
+    
+    /// Passe à la couleur suivante de la palette
+    pub fn cycle_color(&mut self) {
+        let current = MA_PALETTE
+            .iter()
+            .position(|&(r, g, b)| r == self.color.r && g == self.color.g && b == self.color.b);
+        let (r, g, b) = MA_PALETTE[current.map_or(0, |i| (i + 1) % MA_PALETTE.len())];
+        self.color = SerializableColor::from_rgba(r, g, b, self.color.a);
+    }
+}
+
 /// Paramètres pour tous les indicateurs
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IndicatorParams {
+    // Volume : période de la moyenne du volume (None = désactivée)
+    pub volume_ma_period: Option<usize>,
+    
     // RSI
     pub rsi_period: usize,
     pub rsi_method: RSIMethod,
@@ -50,13 +164,43 @@ pub struct IndicatorParams {
     pub bollinger_period: usize,
     pub bollinger_std_dev: f64,
     
-    // Moving Average
-    pub ma_period: usize,
+    // Moyennes mobiles (une ligne par configuration)
+    pub moving_averages: Vec<MaConfig>,
+    
+    // ATR
+    pub atr_period: usize,
+    
+    // Parabolic SAR
+    pub psar_step: f64,
+    pub psar_max_step: f64,
+    
+    // Canaux de Donchian
+    pub donchian_period: usize,
+    
+    // Canaux de Keltner
+    pub keltner_ema_period: usize,
+    pub keltner_atr_period: usize,
+    pub keltner_multiplier: f64,
+    
+    // CCI
+    pub cci_period: usize,
+    
+    // Williams %R
+    pub williams_r_period: usize,
+    
+    // Points pivots
+    pub pivot_method: PivotMethod,
+    
+    // Marqueurs de croisement (moyenne rapide / moyenne lente)
+    pub crossover_fast_period: usize,
+    pub crossover_slow_period: usize,
+    pub crossover_ma_type: MaType,
 }
 
 impl Default for IndicatorParams {
     fn default() -> Self {
         Self {
+            volume_ma_period: None,
             rsi_period: 14,
             rsi_method: RSIMethod::Wilder,
             macd_fast_period: 12,
@@ -64,7 +208,20 @@ impl Default for IndicatorParams {
             macd_signal_period: 9,
             bollinger_period: 20,
             bollinger_std_dev: 2.0,
-            ma_period: 20,
+            moving_averages: vec![MaConfig::preset(0)],
+            atr_period: 14,
+            psar_step: 0.02,
+            psar_max_step: 0.2,
+            donchian_period: 20,
+            keltner_ema_period: 20,
+            keltner_atr_period: 10,
+            keltner_multiplier: 2.0,
+            cci_period: 20,
+            williams_r_period: 14,
+            pivot_method: PivotMethod::Classic,
+            crossover_fast_period: 50,
+            crossover_slow_period: 200,
+            crossover_ma_type: MaType::Sma,
         }
     }
 }
@@ -14,6 +14,27 @@ pub struct IndicatorState {
     /// Indique si la moyenne mobile est activée
     pub moving_average_enabled: bool,
     
+    /// Indique si le VWAP est activé
+    pub vwap_enabled: bool,
+    
+    /// Indique si le Parabolic SAR est activé
+    pub psar_enabled: bool,
+    
+    /// Indique si les canaux de Donchian sont activés
+    pub donchian_enabled: bool,
+    
+    /// Indique si les canaux de Keltner sont activés
+    pub keltner_enabled: bool,
+    
+    /// Indique si le Volume Profile (volume par niveau de prix) est activé
+    pub volume_profile_enabled: bool,
+    
+    /// Indique si les points pivots sont activés
+    pub pivots_enabled: bool,
+    
+    /// Indique si les marqueurs de croisement de moyennes mobiles sont activés
+    pub crossover_markers_enabled: bool,
+    
     /// Paramètres configurables des indicateurs
     pub params: IndicatorParams,
 }
@@ -23,6 +44,13 @@ impl Default for IndicatorState {
         Self {
             bollinger_bands_enabled: false,
             moving_average_enabled: false,
+            vwap_enabled: false,
+            psar_enabled: false,
+            donchian_enabled: false,
+            keltner_enabled: false,
+            volume_profile_enabled: false,
+            pivots_enabled: false,
+            crossover_markers_enabled: false,
             params: IndicatorParams::new(),
         }
     }
@@ -57,6 +57,34 @@ pub fn load_panels_state() -> PanelsState {
                 panels.macd.visible = false;
             }
             
+            // Restaurer les valeurs pour le panneau ATR (masqué par défaut via serde)
+            panels.atr.min_size = MIN_PANEL_SIZE;
+            panels.atr.max_size = 400.0;
+            panels.atr.is_resizing = false;
+            panels.atr.resize_start = None;
+            panels.atr.focused = false;
+            
+            // Restaurer les valeurs pour le panneau OBV (masqué par défaut via serde)
+            panels.obv.min_size = MIN_PANEL_SIZE;
+            panels.obv.max_size = 400.0;
+            panels.obv.is_resizing = false;
+            panels.obv.resize_start = None;
+            panels.obv.focused = false;
+
+            // Restaurer les valeurs pour le panneau CCI (masqué par défaut via serde)
+            panels.cci.min_size = MIN_PANEL_SIZE;
+            panels.cci.max_size = 400.0;
+            panels.cci.is_resizing = false;
+            panels.cci.resize_start = None;
+            panels.cci.focused = false;
+
+            // Restaurer les valeurs pour le panneau Williams %R (masqué par défaut via serde)
+            panels.williams_r.min_size = MIN_PANEL_SIZE;
+            panels.williams_r.max_size = 400.0;
+            panels.williams_r.is_resizing = false;
+            panels.williams_r.resize_start = None;
+            panels.williams_r.focused = false;
+            
             panels
         }
         Err(_) => {
@@ -78,7 +106,12 @@ pub fn load_trading_state() -> TradingState {
                 take_profit: String::new(),
                 stop_loss: String::new(),
                 tp_sl_enabled: false,  // Désactivé par défaut pour les ordres Market
+                trailing_offset: String::new(),
+                trailing_percent: false,
+                risk_percent: String::from("1"),
+                starting_balance: TradingState::DEFAULT_STARTING_BALANCE,
                 trade_history,
+                undo_stack: Default::default(),
             }
         }
         Err(e) => {
@@ -131,9 +164,12 @@ pub fn load_tools_state() -> crate::finance_chart::ToolsState {
     match tools_state.load_from_file("drawings.json") {
         Ok(()) => {
             println!(
-                "✅ Dessins chargés: {} rectangles, {} lignes horizontales",
+                "✅ Dessins chargés: {} rectangles, {} lignes horizontales, {} lignes verticales, {} lignes de tendance, {} textes",
                 tools_state.rectangles.len(),
-                tools_state.horizontal_lines.len()
+                tools_state.horizontal_lines.len(),
+                tools_state.vertical_lines.len(),
+                tools_state.trend_lines.len(),
+                tools_state.texts.len()
             );
         }
         Err(e) => {
@@ -12,6 +12,7 @@ pub mod loaders;
 mod ui_state;
 mod indicator_state;
 pub mod backtest;
+pub mod replay;
 pub mod notifications;
 
 pub use panel_state::{PanelsState, MIN_PANEL_SIZE};
@@ -19,7 +20,7 @@ pub use account_info::AccountInfo;
 pub use account_type::{AccountType, AccountTypeState};
 pub use bottom_panel_sections::{BottomPanelSection, BottomPanelSectionsState};
 pub use trading_state::TradingState;
-pub use indicator_params::{IndicatorParams, RSIMethod};
+pub use indicator_params::{IndicatorParams, RSIMethod, MaType, MaConfig, MAX_MOVING_AVERAGES, PivotMethod};
 pub use ui_state::UiState;
 pub use indicator_state::IndicatorState;
 
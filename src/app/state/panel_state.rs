@@ -122,6 +122,18 @@ pub struct PanelsState {
     /// Panneau du MACD chart (redimensionnable en hauteur)
     #[serde(default = "default_macd_panel")]
     pub macd: PanelState,
+    /// Panneau de l'ATR chart (redimensionnable en hauteur)
+    #[serde(default = "default_atr_panel")]
+    pub atr: PanelState,
+    /// Panneau de l'OBV chart (redimensionnable en hauteur)
+    #[serde(default = "default_obv_panel")]
+    pub obv: PanelState,
+    /// Panneau du CCI chart (redimensionnable en hauteur)
+    #[serde(default = "default_cci_panel")]
+    pub cci: PanelState,
+    /// Panneau du Williams %R chart (redimensionnable en hauteur)
+    #[serde(default = "default_williams_r_panel")]
+    pub williams_r: PanelState,
 }
 
 /// Fonction helper pour créer un volume panel par défaut lors de la désérialisation
@@ -146,13 +158,53 @@ fn default_macd_panel() -> PanelState {
     panel
 }
 
+/// Fonction helper pour créer un ATR panel par défaut lors de la désérialisation
+fn default_atr_panel() -> PanelState {
+    use crate::app::utils::constants::ATR_CHART_HEIGHT;
+    let mut panel = PanelState::new(ATR_CHART_HEIGHT, MIN_PANEL_SIZE, 400.0);
+    panel.visible = false; // L'ATR panel est masqué par défaut
+    panel
+}
+
+/// Fonction helper pour créer un OBV panel par défaut lors de la désérialisation
+fn default_obv_panel() -> PanelState {
+    use crate::app::utils::constants::OBV_CHART_HEIGHT;
+    let mut panel = PanelState::new(OBV_CHART_HEIGHT, MIN_PANEL_SIZE, 400.0);
+    panel.visible = false; // L'OBV panel est masqué par défaut
+    panel
+}
+
+/// Fonction helper pour créer un CCI panel par défaut lors de la désérialisation
+fn default_cci_panel() -> PanelState {
+    use crate::app::utils::constants::CCI_CHART_HEIGHT;
+    let mut panel = PanelState::new(CCI_CHART_HEIGHT, MIN_PANEL_SIZE, 400.0);
+    panel.visible = false; // Le CCI panel est masqué par défaut
+    panel
+}
+
+/// Fonction helper pour créer un Williams %R panel par défaut lors de la désérialisation
+fn default_williams_r_panel() -> PanelState {
+    use crate::app::utils::constants::WILLIAMS_R_CHART_HEIGHT;
+    let mut panel = PanelState::new(WILLIAMS_R_CHART_HEIGHT, MIN_PANEL_SIZE, 400.0);
+    panel.visible = false; // Le Williams %R panel est masqué par défaut
+    panel
+}
+
 impl PanelsState {
     pub fn new() -> Self {
-        use crate::app::utils::constants::{RIGHT_PANEL_WIDTH, BOTTOM_PANEL_HEIGHT, VOLUME_CHART_HEIGHT, RSI_CHART_HEIGHT, MACD_CHART_HEIGHT};
+        use crate::app::utils::constants::{RIGHT_PANEL_WIDTH, BOTTOM_PANEL_HEIGHT, VOLUME_CHART_HEIGHT, RSI_CHART_HEIGHT, MACD_CHART_HEIGHT, ATR_CHART_HEIGHT, OBV_CHART_HEIGHT, CCI_CHART_HEIGHT, WILLIAMS_R_CHART_HEIGHT};
         let mut rsi_panel = PanelState::new(RSI_CHART_HEIGHT, MIN_PANEL_SIZE, 400.0);
         rsi_panel.visible = false; // Le RSI panel est masqué par défaut
         let mut macd_panel = PanelState::new(MACD_CHART_HEIGHT, MIN_PANEL_SIZE, 400.0);
         macd_panel.visible = false; // Le MACD panel est masqué par défaut
+        let mut atr_panel = PanelState::new(ATR_CHART_HEIGHT, MIN_PANEL_SIZE, 400.0);
+        atr_panel.visible = false; // L'ATR panel est masqué par défaut
+        let mut obv_panel = PanelState::new(OBV_CHART_HEIGHT, MIN_PANEL_SIZE, 400.0);
+        obv_panel.visible = false; // L'OBV panel est masqué par défaut
+        let mut cci_panel = PanelState::new(CCI_CHART_HEIGHT, MIN_PANEL_SIZE, 400.0);
+        cci_panel.visible = false; // Le CCI panel est masqué par défaut
+        let mut williams_r_panel = PanelState::new(WILLIAMS_R_CHART_HEIGHT, MIN_PANEL_SIZE, 400.0);
+        williams_r_panel.visible = false; // Le Williams %R panel est masqué par défaut
         Self {
             // Taille minimale = juste la poignée (MIN_PANEL_SIZE)
             right: PanelState::new(RIGHT_PANEL_WIDTH, MIN_PANEL_SIZE, 500.0),
@@ -160,13 +212,17 @@ impl PanelsState {
             volume: PanelState::new(VOLUME_CHART_HEIGHT, MIN_PANEL_SIZE, 400.0), // Peut être snappé à MIN_PANEL_SIZE
             rsi: rsi_panel, // Peut être snappé à MIN_PANEL_SIZE
             macd: macd_panel, // Peut être snappé à MIN_PANEL_SIZE
+            atr: atr_panel, // Peut être snappé à MIN_PANEL_SIZE
+            obv: obv_panel, // Peut être snappé à MIN_PANEL_SIZE
+            cci: cci_panel, // Peut être snappé à MIN_PANEL_SIZE
+            williams_r: williams_r_panel, // Peut être snappé à MIN_PANEL_SIZE
         }
     }
     
     
     /// Retourne true si un panneau a le focus
     pub fn has_focused_panel(&self) -> bool {
-        self.right.focused || self.bottom.focused || self.volume.focused || self.rsi.focused || self.macd.focused
+        self.right.focused || self.bottom.focused || self.volume.focused || self.rsi.focused || self.macd.focused || self.atr.focused || self.obv.focused || self.cci.focused || self.williams_r.focused
     }
 }
 
@@ -0,0 +1,25 @@
+//! État du mode replay
+//!
+//! Ce module gère la lecture bougie par bougie du graphique (lecture, vitesse).
+//! La position du replay elle-même est portée par `ChartState::replay_cursor`.
+
+/// Vitesses de lecture proposées (en millisecondes entre chaque bougie)
+pub const REPLAY_SPEEDS_MS: [u64; 4] = [1000, 500, 200, 50];
+
+/// État du mode replay
+#[derive(Debug, Clone)]
+pub struct ReplayState {
+    /// Indique si la lecture automatique est en cours
+    pub is_playing: bool,
+    /// Vitesse de lecture (en millisecondes entre chaque bougie)
+    pub speed_ms: u64,
+}
+
+impl Default for ReplayState {
+    fn default() -> Self {
+        Self {
+            is_playing: false,
+            speed_ms: 500,
+        }
+    }
+}
@@ -1,7 +1,64 @@
 //! État de trading pour les ordres d'achat/vente
 
+use std::collections::VecDeque;
+
 use crate::app::data::{TradeHistory, OrderType};
 
+/// Nombre maximal d'actions de trading annulables
+const MAX_TRADING_UNDO: usize = 50;
+
+/// Pile annuler/rétablir des actions de trading (historique de session, non sauvegardé)
+///
+/// Chaque entrée est une copie complète du `TradeHistory` : annuler restaure
+/// l'historique tel qu'il était juste avant l'action. Annuler une fermeture rouvre
+/// donc la position avec son prix et sa date d'entrée d'origine et retire le trade
+/// de clôture. Les exécutions automatiques survenues depuis (TP/SL, ordres limit,
+/// OCO) sont annulées avec l'action ; rétablir l'action les restaure.
+#[derive(Debug, Clone, Default)]
+pub struct TradingUndoStack {
+    undo: VecDeque<TradeHistory>,
+    redo: Vec<TradeHistory>,
+}
+
+impl TradingUndoStack {
+    /// Enregistre l'historique précédant une action si celle-ci l'a modifié
+    ///
+    /// Toute action de trading crée un trade ou un ordre (compteurs d'IDs) ou
+    /// retire un ordre en attente : ces compteurs suffisent à détecter un changement.
+    pub fn record(&mut self, before: TradeHistory, after: &TradeHistory) {
+        let unchanged = before.next_trade_id == after.next_trade_id
+            && before.next_order_id == after.next_order_id
+            && before.pending_orders.len() == after.pending_orders.len()
+            && before.open_positions.len() == after.open_positions.len();
+        if unchanged {
+            return;
+        }
+        if self.undo.len() == MAX_TRADING_UNDO {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(before);
+        self.redo.clear();
+    }
+
+    /// Annule la dernière action ; retourne false s'il n'y a rien à annuler
+    pub fn undo(&mut self, current: &mut TradeHistory) -> bool {
+        let Some(previous) = self.undo.pop_back() else {
+            return false;
+        };
+        self.redo.push(std::mem::replace(current, previous));
+        true
+    }
+
+    /// Rétablit la dernière action annulée ; retourne false s'il n'y a rien à rétablir
+    pub fn redo(&mut self, current: &mut TradeHistory) -> bool {
+        let Some(next) = self.redo.pop() else {
+            return false;
+        };
+        self.undo.push_back(std::mem::replace(current, next));
+        true
+    }
+}
+
 /// État de trading pour gérer les ordres
 #[derive(Debug, Clone)]
 pub struct TradingState {
@@ -17,8 +74,18 @@ pub struct TradingState {
     pub stop_loss: String,
     /// TP/SL activé (pour les ordres Market)
     pub tp_sl_enabled: bool,
+    /// Écart du trailing stop (en string pour l'input, vide = désactivé)
+    pub trailing_offset: String,
+    /// Écart du trailing stop exprimé en pourcentage
+    pub trailing_percent: bool,
+    /// Pourcentage du solde risqué par trade (en string pour l'input)
+    pub risk_percent: String,
+    /// Solde de départ du compte de paper trading (base de la courbe d'equity)
+    pub starting_balance: f64,
     /// Historique des trades et positions
     pub trade_history: TradeHistory,
+    /// Annuler/rétablir des actions de trading (Ctrl+Z / Ctrl+Y sur le panneau d'ordres)
+    pub undo_stack: TradingUndoStack,
 }
 
 impl Default for TradingState {
@@ -30,12 +97,20 @@ impl Default for TradingState {
             take_profit: String::new(),
             stop_loss: String::new(),
             tp_sl_enabled: false,  // Désactivé par défaut pour les ordres Market
+            trailing_offset: String::new(),
+            trailing_percent: false,
+            risk_percent: String::from("1"),
+            starting_balance: Self::DEFAULT_STARTING_BALANCE,
             trade_history: TradeHistory::new(),
+            undo_stack: TradingUndoStack::default(),
         }
     }
 }
 
 impl TradingState {
+    /// Solde de départ par défaut du compte de paper trading
+    pub const DEFAULT_STARTING_BALANCE: f64 = 10000.0;
+
     pub fn new() -> Self {
         Self::default()
     }
@@ -68,6 +143,16 @@ impl TradingState {
         }
     }
     
+    /// Parse l'écart du trailing stop, retourne None si vide, invalide ou non positif
+    pub fn parse_trailing_offset(&self) -> Option<f64> {
+        self.trailing_offset.parse::<f64>().ok().filter(|&offset| offset > 0.0)
+    }
+    
+    /// Parse le pourcentage de risque, retourne None si vide, invalide ou non positif
+    pub fn parse_risk_percent(&self) -> Option<f64> {
+        self.risk_percent.parse::<f64>().ok().filter(|&risk| risk > 0.0)
+    }
+    
     /// Met à jour TP et SL avec 15% d'écart par rapport au prix actuel
     /// Ne met à jour que si les champs sont vides (pour ne pas écraser les valeurs saisies manuellement)
     pub fn update_tp_sl_from_price(&mut self, current_price: f64) {
@@ -86,3 +171,103 @@ impl TradingState {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::data::TradeType;
+
+    fn buy(history: &mut TradeHistory, quantity: f64, timestamp: i64) {
+        history.open_buy_position_with_tp_sl_and_strategy(
+            "TEST".to_string(), quantity, 100.0, None, None, None, None, Some(timestamp),
+        );
+    }
+
+    /// Exécute une action sur `history` en l'enregistrant dans la pile
+    fn act(stack: &mut TradingUndoStack, history: &mut TradeHistory, action: impl FnOnce(&mut TradeHistory)) {
+        let before = history.clone();
+        action(history);
+        stack.record(before, history);
+    }
+
+    #[test]
+    fn test_undo_redo_restores_history() {
+        let mut stack = TradingUndoStack::default();
+        let mut history = TradeHistory::new();
+        act(&mut stack, &mut history, |h| buy(h, 1.0, 0));
+        act(&mut stack, &mut history, |h| {
+            h.close_position_with_strategy("TEST", 1.0, 110.0, None, None, Some(60));
+        });
+        assert!(history.open_positions.is_empty());
+
+        // Annuler la fermeture rouvre la position d'origine
+        assert!(stack.undo(&mut history));
+        assert_eq!(history.open_positions.len(), 1);
+        assert_eq!(history.open_positions[0].open_timestamp, 0);
+        assert_eq!(history.trades.len(), 1);
+
+        assert!(stack.redo(&mut history));
+        assert!(history.open_positions.is_empty());
+        assert_eq!(history.trades.len(), 2);
+        assert!(!stack.redo(&mut history));
+
+        // Une nouvelle action vide la pile de rétablissement
+        assert!(stack.undo(&mut history));
+        act(&mut stack, &mut history, |h| buy(h, 2.0, 120));
+        assert!(!stack.redo(&mut history));
+    }
+
+    #[test]
+    fn test_undo_is_bounded() {
+        let mut stack = TradingUndoStack::default();
+        let mut history = TradeHistory::new();
+        for i in 0..MAX_TRADING_UNDO + 5 {
+            act(&mut stack, &mut history, |h| buy(h, 1.0, i as i64));
+        }
+        let mut undone = 0;
+        while stack.undo(&mut history) {
+            undone += 1;
+        }
+        // Les actions les plus anciennes sont oubliées
+        assert_eq!(undone, MAX_TRADING_UNDO);
+        assert_eq!(history.trades.len(), 5);
+    }
+
+    #[test]
+    fn test_record_skips_unchanged_history() {
+        let mut stack = TradingUndoStack::default();
+        let mut history = TradeHistory::new();
+        // Action sans effet (ex: fermeture sans position)
+        act(&mut stack, &mut history, |h| {
+            h.close_position_with_strategy("TEST", 1.0, 110.0, None, None, Some(0));
+        });
+        assert!(!stack.undo(&mut history));
+
+        // L'annulation d'un ordre en attente ne crée aucun ID mais est détectée
+        history.create_pending_order("TEST".to_string(), TradeType::Buy, 1.0, 90.0, None, None);
+        act(&mut stack, &mut history, |h| h.pending_orders.clear());
+        assert!(stack.undo(&mut history));
+        assert_eq!(history.pending_orders.len(), 1);
+    }
+
+    #[test]
+    fn test_undo_discards_automatic_fills() {
+        let mut stack = TradingUndoStack::default();
+        let mut history = TradeHistory::new();
+        act(&mut stack, &mut history, |h| {
+            h.create_pending_order("TEST".to_string(), TradeType::Buy, 1.0, 90.0, None, None);
+        });
+        // L'ordre limit est exécuté automatiquement après l'action
+        history.check_and_execute_pending_orders("TEST", 89.0, Some(60));
+        assert_eq!(history.open_positions.len(), 1);
+
+        // Annuler la création de l'ordre retire aussi son exécution
+        assert!(stack.undo(&mut history));
+        assert!(history.open_positions.is_empty() && history.pending_orders.is_empty());
+        assert!(history.trades.is_empty());
+
+        // Rétablir restaure l'état exécuté
+        assert!(stack.redo(&mut history));
+        assert_eq!(history.open_positions.len(), 1);
+    }
+}
@@ -3,7 +3,7 @@
 //! Ce module regroupe tous les champs liés à l'interface utilisateur
 //! (panneaux, sections, menus contextuels).
 
-use crate::app::state::{PanelsState, BottomPanelSectionsState, BottomPanelSection, backtest::BacktestState};
+use crate::app::state::{PanelsState, BottomPanelSectionsState, BottomPanelSection, backtest::BacktestState, replay::ReplayState};
 use crate::app::error_handling::AppError;
 use super::notifications::NotificationManager;
 
@@ -28,6 +28,9 @@ pub struct UiState {
     /// État du backtest
     pub backtest_state: BacktestState,
     
+    /// État du mode replay
+    pub replay: ReplayState,
+    
     /// Messages d'erreur à afficher à l'utilisateur (déprécié, utiliser notifications)
     #[deprecated(note = "Utiliser notifications à la place")]
     pub error_messages: Vec<AppError>,
@@ -45,6 +48,7 @@ impl Default for UiState {
             chart_context_menu: None,
             indicators_panel_open: false,
             backtest_state: BacktestState::new(),
+            replay: ReplayState::default(),
             error_messages: Vec::new(),
             notifications: NotificationManager::new(),
         }
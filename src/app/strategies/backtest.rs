@@ -0,0 +1,328 @@
+//! Backtest rapide d'une stratégie sur l'historique d'une série
+//!
+//! Contrairement à la lecture pas à pas (`BacktestState`), toutes les bougies
+//! sont rejouées d'un coup : chaque bougie est fournie à la stratégie dans
+//! l'ordre chronologique et les ordres sont exécutés au prix de clôture.
+//! Une seule position (long ou short) est ouverte à la fois.
+
+use crate::app::strategies::strategy::{MarketContext, TradingSignal, TradingStrategy};
+use crate::finance_chart::core::SeriesData;
+
+/// Sens d'une position simulée
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BacktestSide {
+    Long,
+    Short,
+}
+
+/// Raison de la clôture d'une position simulée
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitReason {
+    /// Signal inverse de la stratégie
+    Signal,
+    TakeProfit,
+    StopLoss,
+    /// Position encore ouverte à la dernière bougie
+    EndOfData,
+}
+
+/// Trade fermé pendant le backtest
+#[derive(Debug, Clone)]
+pub struct BacktestTrade {
+    pub side: BacktestSide,
+    pub quantity: f64,
+    pub entry_time: i64,
+    pub entry_price: f64,
+    pub exit_time: i64,
+    pub exit_price: f64,
+    pub pnl: f64,
+    pub exit_reason: ExitReason,
+}
+
+/// Rapport de performance d'un backtest
+#[derive(Debug, Clone)]
+pub struct BacktestReport {
+    pub strategy_name: String,
+    pub symbol: String,
+    pub interval: String,
+    pub initial_balance: f64,
+    pub final_balance: f64,
+    /// Rendement total en pourcentage du capital initial
+    pub total_return_pct: f64,
+    /// Baisse maximale de l'equity depuis un sommet, en pourcentage
+    pub max_drawdown_pct: f64,
+    /// Pourcentage de trades gagnants
+    pub win_rate_pct: f64,
+    /// Gains bruts / pertes brutes (None si aucune perte)
+    pub profit_factor: Option<f64>,
+    pub candles_processed: usize,
+    pub trades: Vec<BacktestTrade>,
+}
+
+/// Position ouverte pendant la simulation
+struct OpenPosition {
+    side: BacktestSide,
+    quantity: f64,
+    entry_time: i64,
+    entry_price: f64,
+    take_profit: Option<f64>,
+    stop_loss: Option<f64>,
+}
+
+impl OpenPosition {
+    fn pnl_at(&self, price: f64) -> f64 {
+        match self.side {
+            BacktestSide::Long => (price - self.entry_price) * self.quantity,
+            BacktestSide::Short => (self.entry_price - price) * self.quantity,
+        }
+    }
+
+    /// Vérifie si le TP ou le SL est atteint au prix donné
+    fn exit_trigger(&self, price: f64) -> Option<ExitReason> {
+        let (tp_hit, sl_hit) = match self.side {
+            BacktestSide::Long => (
+                self.take_profit.is_some_and(|tp| price >= tp),
+                self.stop_loss.is_some_and(|sl| price <= sl),
+            ),
+            BacktestSide::Short => (
+                self.take_profit.is_some_and(|tp| price <= tp),
+                self.stop_loss.is_some_and(|sl| price >= sl),
+            ),
+        };
+        if sl_hit {
+            Some(ExitReason::StopLoss)
+        } else if tp_hit {
+            Some(ExitReason::TakeProfit)
+        } else {
+            None
+        }
+    }
+
+    fn close(self, time: i64, price: f64, exit_reason: ExitReason) -> BacktestTrade {
+        BacktestTrade {
+            side: self.side,
+            quantity: self.quantity,
+            entry_time: self.entry_time,
+            entry_price: self.entry_price,
+            exit_time: time,
+            exit_price: price,
+            pnl: self.pnl_at(price),
+            exit_reason,
+        }
+    }
+}
+
+/// Rejoue toutes les bougies de `series` avec la stratégie et calcule les performances
+///
+/// Un signal d'achat ferme un short ou ouvre un long, un signal de vente ferme
+/// un long ou ouvre un short. Le TP/SL du signal d'ouverture est vérifié sur les
+/// clôtures suivantes. La position restante est fermée à la dernière bougie.
+pub fn run_backtest(
+    strategy: &dyn TradingStrategy,
+    series: &SeriesData,
+    initial_balance: f64,
+) -> BacktestReport {
+    let candles = series.data.all_candles();
+    let mut balance = initial_balance;
+    let mut position: Option<OpenPosition> = None;
+    let mut trades = Vec::new();
+    let mut peak_equity = initial_balance;
+    let mut max_drawdown_pct: f64 = 0.0;
+    let mut context: Option<MarketContext> = None;
+
+    for (index, candle) in candles.iter().enumerate() {
+        let price = candle.close;
+
+        // TP/SL avant d'évaluer un nouveau signal
+        if let Some(reason) = position.as_ref().and_then(|p| p.exit_trigger(price)) {
+            if let Some(open) = position.take() {
+                let trade = open.close(candle.timestamp, price, reason);
+                balance += trade.pnl;
+                trades.push(trade);
+            }
+        }
+
+        let context = context.get_or_insert_with(|| MarketContext::at_candle(series, index));
+        context.move_to(series, index);
+
+        let (side, quantity, take_profit, stop_loss) = match strategy.evaluate(context).signal {
+            TradingSignal::Buy { quantity, take_profit, stop_loss, .. } => {
+                (BacktestSide::Long, quantity, take_profit, stop_loss)
+            }
+            TradingSignal::Sell { quantity, take_profit, stop_loss, .. } => {
+                (BacktestSide::Short, quantity, take_profit, stop_loss)
+            }
+            TradingSignal::Hold => (BacktestSide::Long, 0.0, None, None),
+        };
+
+        if quantity > 0.0 {
+            match position.as_ref().map(|p| p.side) {
+                // Signal inverse : fermer la position
+                Some(current) if current != side => {
+                    if let Some(open) = position.take() {
+                        let trade = open.close(candle.timestamp, price, ExitReason::Signal);
+                        balance += trade.pnl;
+                        trades.push(trade);
+                    }
+                }
+                // Déjà positionné dans le même sens : ignorer
+                Some(_) => {}
+                None => {
+                    position = Some(OpenPosition {
+                        side,
+                        quantity,
+                        entry_time: candle.timestamp,
+                        entry_price: price,
+                        take_profit,
+                        stop_loss,
+                    });
+                }
+            }
+        }
+
+        // Drawdown sur l'equity (capital + P&L latent)
+        let equity = balance + position.as_ref().map_or(0.0, |p| p.pnl_at(price));
+        peak_equity = peak_equity.max(equity);
+        if peak_equity > 0.0 {
+            max_drawdown_pct = max_drawdown_pct.max((peak_equity - equity) / peak_equity * 100.0);
+        }
+    }
+
+    if let (Some(open), Some(last)) = (position.take(), candles.last()) {
+        let trade = open.close(last.timestamp, last.close, ExitReason::EndOfData);
+        balance += trade.pnl;
+        trades.push(trade);
+    }
+
+    let gross_profit: f64 = trades.iter().filter(|t| t.pnl > 0.0).map(|t| t.pnl).sum();
+    let gross_loss: f64 = trades.iter().filter(|t| t.pnl < 0.0).map(|t| -t.pnl).sum();
+    let winners = trades.iter().filter(|t| t.pnl > 0.0).count();
+
+    BacktestReport {
+        strategy_name: strategy.name().to_string(),
+        symbol: series.symbol.clone(),
+        interval: series.interval.clone(),
+        initial_balance,
+        final_balance: balance,
+        total_return_pct: if initial_balance > 0.0 {
+            (balance - initial_balance) / initial_balance * 100.0
+        } else {
+            0.0
+        },
+        max_drawdown_pct,
+        win_rate_pct: if trades.is_empty() {
+            0.0
+        } else {
+            winners as f64 / trades.len() as f64 * 100.0
+        },
+        profit_factor: (gross_loss > 0.0).then(|| gross_profit / gross_loss),
+        candles_processed: candles.len(),
+        trades,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::data::OrderType;
+    use crate::app::strategies::strategy::{ParamSpec, StrategyResult};
+    use crate::finance_chart::core::{Candle, SeriesId, TimeSeries};
+
+    /// Achète sous `buy_below`, vend au-dessus de `sell_above`
+    #[derive(Clone)]
+    struct ThresholdStrategy {
+        buy_below: f64,
+        sell_above: f64,
+    }
+
+    impl TradingStrategy for ThresholdStrategy {
+        fn name(&self) -> &str {
+            "Seuils"
+        }
+
+        fn description(&self) -> &str {
+            "Stratégie de test"
+        }
+
+        fn evaluate(&self, context: &MarketContext) -> StrategyResult {
+            let order_type = OrderType::Market;
+            let signal = if context.current_price < self.buy_below {
+                TradingSignal::Buy { quantity: 1.0, order_type, limit_price: None, take_profit: None, stop_loss: None }
+            } else if context.current_price > self.sell_above {
+                TradingSignal::Sell { quantity: 1.0, order_type, limit_price: None, take_profit: None, stop_loss: None }
+            } else {
+                TradingSignal::Hold
+            };
+            StrategyResult { signal, reason: String::new(), confidence: 1.0 }
+        }
+
+        fn parameter_specs(&self) -> Vec<ParamSpec> {
+            Vec::new()
+        }
+
+        fn parameter_value(&self, _name: &str) -> Option<f64> {
+            None
+        }
+
+        fn update_parameter(&mut self, _name: &str, _value: f64) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn clone_box(&self) -> Box<dyn TradingStrategy> {
+            Box::new(self.clone())
+        }
+    }
+
+    fn series(closes: &[f64]) -> SeriesData {
+        let mut data = TimeSeries::new();
+        for (i, &close) in closes.iter().enumerate() {
+            data.push(Candle::new(i as i64 * 60, close, close, close, close, 1.0)).unwrap();
+        }
+        SeriesData::new(SeriesId::new("TEST_1m"), "TEST".to_string(), "1m".to_string(), data)
+    }
+
+    #[test]
+    fn test_backtest_metrics() {
+        let strategy = ThresholdStrategy { buy_below: 95.0, sell_above: 105.0 };
+        // Long 90 → 110 (+20, fermé par signal), short 110 → 120 (-10, fin des données)
+        let report = run_backtest(&strategy, &series(&[100.0, 90.0, 100.0, 110.0, 110.0, 120.0]), 1000.0);
+
+        assert_eq!(report.trades.len(), 2);
+        assert_eq!(report.trades[0].side, BacktestSide::Long);
+        assert_eq!(report.trades[0].pnl, 20.0);
+        assert_eq!(report.trades[0].exit_reason, ExitReason::Signal);
+        assert_eq!(report.trades[1].side, BacktestSide::Short);
+        assert_eq!(report.trades[1].pnl, -10.0);
+        assert_eq!(report.trades[1].exit_reason, ExitReason::EndOfData);
+
+        assert_eq!(report.final_balance, 1010.0);
+        assert!((report.total_return_pct - 1.0).abs() < 1e-9);
+        assert_eq!(report.win_rate_pct, 50.0);
+        assert_eq!(report.profit_factor, Some(2.0));
+        // Sommet à 1020 (après le long), creux à 1010 à la dernière bougie
+        assert!((report.max_drawdown_pct - 10.0 / 1020.0 * 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_backtest_without_signals() {
+        let strategy = ThresholdStrategy { buy_below: 0.0, sell_above: 1_000.0 };
+        let report = run_backtest(&strategy, &series(&[100.0, 101.0]), 1000.0);
+        assert!(report.trades.is_empty());
+        assert_eq!(report.final_balance, 1000.0);
+        assert_eq!(report.win_rate_pct, 0.0);
+        assert_eq!(report.profit_factor, None);
+        assert_eq!(report.candles_processed, 2);
+    }
+
+    #[test]
+    fn test_signals_over_history() {
+        let strategy = ThresholdStrategy { buy_below: 95.0, sell_above: 105.0 };
+        // Contrairement au backtest, chaque signal est rapporté, même en position
+        let signals = strategy.signals_over(&series(&[100.0, 90.0, 90.0, 100.0, 110.0]));
+        let summary: Vec<(i64, bool)> = signals
+            .iter()
+            .map(|(timestamp, signal)| (*timestamp, matches!(signal, TradingSignal::Buy { .. })))
+            .collect();
+        assert_eq!(summary, vec![(60, true), (120, true), (240, false)]);
+    }
+}
@@ -3,6 +3,8 @@
 use crate::app::strategies::strategy::*;
 use crate::app::data::OrderType;
 use crate::finance_chart::core::Candle;
+use crate::finance_chart::indicators::bollinger::calc::{calculate_bollinger_bands, BOLLINGER_PERIOD, BOLLINGER_STD_DEV};
+use crate::finance_chart::indicators::macd::calc::{calculate_macd, MACD_FAST_PERIOD, MACD_SIGNAL_PERIOD, MACD_SLOW_PERIOD};
 
 /// Stratégie basée sur RSI (Relative Strength Index)
 pub struct RSIStrategy {
@@ -110,75 +112,63 @@ impl TradingStrategy for RSIStrategy {
         }
     }
     
-    fn parameters(&self) -> Vec<StrategyParameter> {
+    fn parameter_specs(&self) -> Vec<ParamSpec> {
         vec![
-            StrategyParameter {
+            ParamSpec {
                 name: "rsi_period".to_string(),
-                value: self.rsi_period,
+                description: "Période pour le calcul du RSI".to_string(),
                 min: 5.0,
                 max: 50.0,
-                description: "Période pour le calcul du RSI".to_string(),
+                step: 1.0,
+                default: 14.0,
             },
-            StrategyParameter {
+            ParamSpec {
                 name: "oversold_threshold".to_string(),
-                value: self.oversold_threshold,
+                description: "Seuil de survente (signal d'achat)".to_string(),
                 min: 10.0,
                 max: 40.0,
-                description: "Seuil de survente (signal d'achat)".to_string(),
+                step: 1.0,
+                default: 30.0,
             },
-            StrategyParameter {
+            ParamSpec {
                 name: "overbought_threshold".to_string(),
-                value: self.overbought_threshold,
+                description: "Seuil de surachat (signal de vente)".to_string(),
                 min: 60.0,
                 max: 90.0,
-                description: "Seuil de surachat (signal de vente)".to_string(),
+                step: 1.0,
+                default: 70.0,
             },
-            StrategyParameter {
+            ParamSpec {
                 name: "quantity".to_string(),
-                value: self.quantity,
+                description: "Quantité à trader".to_string(),
                 min: 0.0001,
                 max: 1.0,
-                description: "Quantité à trader".to_string(),
+                step: 0.0001,
+                default: 0.001,
             },
         ]
     }
     
+    fn parameter_value(&self, name: &str) -> Option<f64> {
+        match name {
+            "rsi_period" => Some(self.rsi_period),
+            "oversold_threshold" => Some(self.oversold_threshold),
+            "overbought_threshold" => Some(self.overbought_threshold),
+            "quantity" => Some(self.quantity),
+            _ => None,
+        }
+    }
+    
     fn update_parameter(&mut self, name: &str, value: f64) -> Result<(), String> {
+        // Bornes vérifiées par `set_parameter` (voir `parameter_specs`)
         match name {
-            "rsi_period" => {
-                if value >= 5.0 && value <= 50.0 {
-                    self.rsi_period = value;
-                    Ok(())
-                } else {
-                    Err("Période RSI doit être entre 5 et 50".to_string())
-                }
-            }
-            "oversold_threshold" => {
-                if value >= 10.0 && value <= 40.0 {
-                    self.oversold_threshold = value;
-                    Ok(())
-                } else {
-                    Err("Seuil de survente doit être entre 10 et 40".to_string())
-                }
-            }
-            "overbought_threshold" => {
-                if value >= 60.0 && value <= 90.0 {
-                    self.overbought_threshold = value;
-                    Ok(())
-                } else {
-                    Err("Seuil de surachat doit être entre 60 et 90".to_string())
-                }
-            }
-            "quantity" => {
-                if value > 0.0 && value <= 1.0 {
-                    self.quantity = value;
-                    Ok(())
-                } else {
-                    Err("Quantité doit être positive et <= 1.0".to_string())
-                }
-            }
-            _ => Err(format!("Paramètre inconnu: {}", name)),
+            "rsi_period" => self.rsi_period = value,
+            "oversold_threshold" => self.oversold_threshold = value,
+            "overbought_threshold" => self.overbought_threshold = value,
+            "quantity" => self.quantity = value,
+            _ => return Err(format!("Paramètre inconnu: {}", name)),
         }
+        Ok(())
     }
     
     fn clone_box(&self) -> Box<dyn TradingStrategy> {
@@ -305,70 +295,459 @@ impl TradingStrategy for MovingAverageCrossoverStrategy {
         }
     }
     
-    fn parameters(&self) -> Vec<StrategyParameter> {
+    fn parameter_specs(&self) -> Vec<ParamSpec> {
         vec![
-            StrategyParameter {
+            ParamSpec {
                 name: "fast_period".to_string(),
-                value: self.fast_period,
+                description: "Période de la moyenne mobile rapide".to_string(),
                 min: 5.0,
                 max: 50.0,
-                description: "Période de la moyenne mobile rapide".to_string(),
+                step: 1.0,
+                default: 10.0,
             },
-            StrategyParameter {
+            ParamSpec {
                 name: "slow_period".to_string(),
-                value: self.slow_period,
+                description: "Période de la moyenne mobile lente".to_string(),
                 min: 10.0,
                 max: 200.0,
-                description: "Période de la moyenne mobile lente".to_string(),
+                step: 1.0,
+                default: 30.0,
             },
-            StrategyParameter {
+            ParamSpec {
                 name: "quantity".to_string(),
-                value: self.quantity,
+                description: "Quantité à trader".to_string(),
                 min: 0.0001,
                 max: 1.0,
+                step: 0.0001,
+                default: 0.001,
+            },
+        ]
+    }
+    
+    fn parameter_value(&self, name: &str) -> Option<f64> {
+        match name {
+            "fast_period" => Some(self.fast_period),
+            "slow_period" => Some(self.slow_period),
+            "quantity" => Some(self.quantity),
+            _ => None,
+        }
+    }
+    
+    fn update_parameter(&mut self, name: &str, value: f64) -> Result<(), String> {
+        // Bornes vérifiées par `set_parameter` (voir `parameter_specs`)
+        match name {
+            "fast_period" if value >= self.slow_period => {
+                return Err("Période rapide doit être < période lente".to_string());
+            }
+            "slow_period" if value <= self.fast_period => {
+                return Err("Période lente doit être > période rapide".to_string());
+            }
+            "fast_period" => self.fast_period = value,
+            "slow_period" => self.slow_period = value,
+            "quantity" => self.quantity = value,
+            _ => return Err(format!("Paramètre inconnu: {}", name)),
+        }
+        Ok(())
+    }
+    
+    fn clone_box(&self) -> Box<dyn TradingStrategy> {
+        Box::new(Self {
+            name: self.name.clone(),
+            fast_period: self.fast_period,
+            slow_period: self.slow_period,
+            quantity: self.quantity,
+        })
+    }
+}
+
+/// Stratégie de cassure des bandes de Bollinger
+pub struct BollingerBreakoutStrategy {
+    name: String,
+    period: f64,
+    std_dev: f64,
+    quantity: f64,
+}
+
+impl BollingerBreakoutStrategy {
+    pub fn new() -> Self {
+        Self {
+            name: "Bollinger Breakout Strategy".to_string(),
+            period: BOLLINGER_PERIOD as f64,
+            std_dev: BOLLINGER_STD_DEV,
+            quantity: 0.001,
+        }
+    }
+}
+
+impl TradingStrategy for BollingerBreakoutStrategy {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn description(&self) -> &str {
+        "Stratégie de cassure des bandes de Bollinger. Achete quand la clôture franchit la bande supérieure, vends sous la bande inférieure"
+    }
+    
+    fn evaluate(&self, context: &MarketContext) -> StrategyResult {
+        let period = self.period as usize;
+        
+        // Seules les bandes des deux dernières bougies sont utiles
+        let Some(start) = context.candles.len().checked_sub(period + 1) else {
+            return StrategyResult {
+                signal: TradingSignal::Hold,
+                reason: "Pas assez de données pour calculer les bandes de Bollinger".to_string(),
+                confidence: 0.0,
+            };
+        };
+        let candles = &context.candles[start..];
+        let bands = calculate_bollinger_bands(candles, period, self.std_dev);
+        let (Some(Some(prev_band)), Some(Some(band))) = (bands.get(bands.len() - 2), bands.last()) else {
+            return StrategyResult {
+                signal: TradingSignal::Hold,
+                reason: "Bandes de Bollinger indisponibles".to_string(),
+                confidence: 0.0,
+            };
+        };
+        let prev_close = candles[candles.len() - 2].close;
+        let close = context.current_price;
+        // Largeur d'une demi-bande, pour mesurer l'ampleur de la cassure
+        let half_width = (band.upper - band.middle).max(f64::EPSILON);
+        
+        // Cassure haussière: la clôture passe au-dessus de la bande supérieure
+        if prev_close <= prev_band.upper && close > band.upper {
+            StrategyResult {
+                signal: TradingSignal::Buy {
+                    quantity: self.quantity,
+                    order_type: OrderType::Market,
+                    limit_price: None,
+                    take_profit: Some(close * 1.05),
+                    stop_loss: Some(band.middle), // Retour sous la moyenne = cassure invalidée
+                },
+                reason: format!("Cassure haussière: clôture ({:.2}) > bande supérieure ({:.2})", close, band.upper),
+                confidence: (0.5 + (close - band.upper) / half_width).min(1.0),
+            }
+        }
+        // Cassure baissière: la clôture passe sous la bande inférieure
+        else if prev_close >= prev_band.lower && close < band.lower {
+            StrategyResult {
+                signal: TradingSignal::Sell {
+                    quantity: self.quantity,
+                    order_type: OrderType::Market,
+                    limit_price: None,
+                    take_profit: Some(close * 0.95),
+                    stop_loss: Some(band.middle),
+                },
+                reason: format!("Cassure baissière: clôture ({:.2}) < bande inférieure ({:.2})", close, band.lower),
+                confidence: (0.5 + (band.lower - close) / half_width).min(1.0),
+            }
+        } else {
+            StrategyResult {
+                signal: TradingSignal::Hold,
+                reason: format!("Clôture ({:.2}) dans les bandes [{:.2}, {:.2}]", close, band.lower, band.upper),
+                confidence: 0.0,
+            }
+        }
+    }
+    
+    fn parameter_specs(&self) -> Vec<ParamSpec> {
+        vec![
+            ParamSpec {
+                name: "period".to_string(),
+                description: "Période de la moyenne et de l'écart-type".to_string(),
+                min: 5.0,
+                max: 100.0,
+                step: 1.0,
+                default: BOLLINGER_PERIOD as f64,
+            },
+            ParamSpec {
+                name: "std_dev".to_string(),
+                description: "Nombre d'écarts-types des bandes".to_string(),
+                min: 1.0,
+                max: 4.0,
+                step: 0.1,
+                default: BOLLINGER_STD_DEV,
+            },
+            ParamSpec {
+                name: "quantity".to_string(),
                 description: "Quantité à trader".to_string(),
+                min: 0.0001,
+                max: 1.0,
+                step: 0.0001,
+                default: 0.001,
             },
         ]
     }
     
+    fn parameter_value(&self, name: &str) -> Option<f64> {
+        match name {
+            "period" => Some(self.period),
+            "std_dev" => Some(self.std_dev),
+            "quantity" => Some(self.quantity),
+            _ => None,
+        }
+    }
+    
     fn update_parameter(&mut self, name: &str, value: f64) -> Result<(), String> {
+        // Bornes vérifiées par `set_parameter` (voir `parameter_specs`)
         match name {
-            "fast_period" => {
-                if value >= 5.0 && value <= 50.0 && value < self.slow_period {
-                    self.fast_period = value;
-                    Ok(())
-                } else {
-                    Err("Période rapide doit être entre 5 et 50, et < période lente".to_string())
-                }
+            "period" => self.period = value,
+            "std_dev" => self.std_dev = value,
+            "quantity" => self.quantity = value,
+            _ => return Err(format!("Paramètre inconnu: {}", name)),
+        }
+        Ok(())
+    }
+    
+    fn clone_box(&self) -> Box<dyn TradingStrategy> {
+        Box::new(Self {
+            name: self.name.clone(),
+            period: self.period,
+            std_dev: self.std_dev,
+            quantity: self.quantity,
+        })
+    }
+}
+
+/// Stratégie de croisement MACD / ligne de signal
+pub struct MacdCrossStrategy {
+    name: String,
+    fast_period: f64,
+    slow_period: f64,
+    signal_period: f64,
+    quantity: f64,
+}
+
+impl MacdCrossStrategy {
+    pub fn new() -> Self {
+        Self {
+            name: "MACD Signal Cross Strategy".to_string(),
+            fast_period: MACD_FAST_PERIOD as f64,
+            slow_period: MACD_SLOW_PERIOD as f64,
+            signal_period: MACD_SIGNAL_PERIOD as f64,
+            quantity: 0.001,
+        }
+    }
+}
+
+impl TradingStrategy for MacdCrossStrategy {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn description(&self) -> &str {
+        "Stratégie de croisement MACD. Achete quand la ligne MACD croise au-dessus de sa ligne de signal, vends au croisement inverse"
+    }
+    
+    fn evaluate(&self, context: &MarketContext) -> StrategyResult {
+        // Tout l'historique est utilisé : les EMA ont besoin de données pour se stabiliser
+        let values = calculate_macd(
+            &context.candles,
+            self.fast_period as usize,
+            self.slow_period as usize,
+            self.signal_period as usize,
+        );
+        let (Some(Some(prev)), Some(Some(current))) = (values.len().checked_sub(2).and_then(|i| values.get(i)), values.last()) else {
+            return StrategyResult {
+                signal: TradingSignal::Hold,
+                reason: "Pas assez de données pour détecter un croisement MACD".to_string(),
+                confidence: 0.0,
+            };
+        };
+        
+        // Croisement haussier: l'histogramme passe au-dessus de zéro
+        if prev.histogram <= 0.0 && current.histogram > 0.0 {
+            StrategyResult {
+                signal: TradingSignal::Buy {
+                    quantity: self.quantity,
+                    order_type: OrderType::Market,
+                    limit_price: None,
+                    take_profit: Some(context.current_price * 1.10),
+                    stop_loss: Some(context.current_price * 0.95),
+                },
+                reason: format!("Croisement haussier: MACD ({:.4}) > signal ({:.4})",
+                    current.macd_line, current.signal_line),
+                // Un croisement sous la ligne zéro annonce un retournement plus marqué
+                confidence: if current.macd_line < 0.0 { 0.7 } else { 0.5 },
             }
-            "slow_period" => {
-                if value >= 10.0 && value <= 200.0 && value > self.fast_period {
-                    self.slow_period = value;
-                    Ok(())
-                } else {
-                    Err("Période lente doit être entre 10 et 200, et > période rapide".to_string())
-                }
+        }
+        // Croisement baissier: l'histogramme passe sous zéro
+        else if prev.histogram >= 0.0 && current.histogram < 0.0 {
+            StrategyResult {
+                signal: TradingSignal::Sell {
+                    quantity: self.quantity,
+                    order_type: OrderType::Market,
+                    limit_price: None,
+                    take_profit: Some(context.current_price * 0.90),
+                    stop_loss: Some(context.current_price * 1.05),
+                },
+                reason: format!("Croisement baissier: MACD ({:.4}) < signal ({:.4})",
+                    current.macd_line, current.signal_line),
+                confidence: if current.macd_line > 0.0 { 0.7 } else { 0.5 },
             }
-            "quantity" => {
-                if value > 0.0 && value <= 1.0 {
-                    self.quantity = value;
-                    Ok(())
-                } else {
-                    Err("Quantité doit être positive et <= 1.0".to_string())
-                }
+        } else {
+            StrategyResult {
+                signal: TradingSignal::Hold,
+                reason: format!("Pas de croisement: MACD={:.4}, signal={:.4}",
+                    current.macd_line, current.signal_line),
+                confidence: 0.0,
             }
-            _ => Err(format!("Paramètre inconnu: {}", name)),
         }
     }
     
+    fn parameter_specs(&self) -> Vec<ParamSpec> {
+        vec![
+            ParamSpec {
+                name: "fast_period".to_string(),
+                description: "Période de l'EMA rapide".to_string(),
+                min: 2.0,
+                max: 50.0,
+                step: 1.0,
+                default: MACD_FAST_PERIOD as f64,
+            },
+            ParamSpec {
+                name: "slow_period".to_string(),
+                description: "Période de l'EMA lente".to_string(),
+                min: 5.0,
+                max: 100.0,
+                step: 1.0,
+                default: MACD_SLOW_PERIOD as f64,
+            },
+            ParamSpec {
+                name: "signal_period".to_string(),
+                description: "Période de la ligne de signal".to_string(),
+                min: 2.0,
+                max: 50.0,
+                step: 1.0,
+                default: MACD_SIGNAL_PERIOD as f64,
+            },
+            ParamSpec {
+                name: "quantity".to_string(),
+                description: "Quantité à trader".to_string(),
+                min: 0.0001,
+                max: 1.0,
+                step: 0.0001,
+                default: 0.001,
+            },
+        ]
+    }
+    
+    fn parameter_value(&self, name: &str) -> Option<f64> {
+        match name {
+            "fast_period" => Some(self.fast_period),
+            "slow_period" => Some(self.slow_period),
+            "signal_period" => Some(self.signal_period),
+            "quantity" => Some(self.quantity),
+            _ => None,
+        }
+    }
+    
+    fn update_parameter(&mut self, name: &str, value: f64) -> Result<(), String> {
+        // Bornes vérifiées par `set_parameter` (voir `parameter_specs`)
+        match name {
+            "fast_period" if value >= self.slow_period => {
+                return Err("Période rapide doit être < période lente".to_string());
+            }
+            "slow_period" if value <= self.fast_period => {
+                return Err("Période lente doit être > période rapide".to_string());
+            }
+            "fast_period" => self.fast_period = value,
+            "slow_period" => self.slow_period = value,
+            "signal_period" => self.signal_period = value,
+            "quantity" => self.quantity = value,
+            _ => return Err(format!("Paramètre inconnu: {}", name)),
+        }
+        Ok(())
+    }
+    
     fn clone_box(&self) -> Box<dyn TradingStrategy> {
         Box::new(Self {
             name: self.name.clone(),
             fast_period: self.fast_period,
             slow_period: self.slow_period,
+            signal_period: self.signal_period,
             quantity: self.quantity,
         })
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::finance_chart::core::{SeriesData, SeriesId, TimeSeries};
+
+    fn series(closes: &[f64]) -> SeriesData {
+        let mut data = TimeSeries::new();
+        for (i, &close) in closes.iter().enumerate() {
+            data.push(Candle::new(i as i64 * 60, close, close, close, close, 1.0)).unwrap();
+        }
+        SeriesData::new(SeriesId::new("TEST_1m"), "TEST".to_string(), "1m".to_string(), data)
+    }
+
+    fn is_buy(signal: &TradingSignal) -> bool {
+        matches!(signal, TradingSignal::Buy { .. })
+    }
+
+    #[test]
+    fn test_bollinger_breakout_signals() {
+        // Marché plat puis saut au-dessus de la bande supérieure, puis chute sous la bande inférieure
+        let mut closes = vec![100.0; 30];
+        closes.push(120.0);
+        closes.extend([120.0; 5]);
+        closes.push(80.0);
+        let signals = BollingerBreakoutStrategy::new().signals_over(&series(&closes));
+        let summary: Vec<(i64, bool)> = signals.iter().map(|(t, s)| (*t, is_buy(s))).collect();
+        assert_eq!(summary, vec![(30 * 60, true), (36 * 60, false)]);
+    }
+
+    #[test]
+    fn test_macd_cross_signals() {
+        // Baisse régulière puis hausse : un seul croisement haussier après le creux
+        let closes: Vec<f64> = (0..60).map(|i| 200.0 - i as f64)
+            .chain((1..=40).map(|i| 141.0 + i as f64))
+            .collect();
+        let signals = MacdCrossStrategy::new().signals_over(&series(&closes));
+        assert_eq!(signals.len(), 1);
+        let (timestamp, signal) = &signals[0];
+        assert!(is_buy(signal));
+        assert!(*timestamp > 59 * 60);
+    }
+
+    #[test]
+    fn test_spec_defaults_match_new_strategies() {
+        let strategies: Vec<Box<dyn TradingStrategy>> = vec![
+            Box::new(RSIStrategy::new()),
+            Box::new(MovingAverageCrossoverStrategy::new()),
+            Box::new(BollingerBreakoutStrategy::new()),
+            Box::new(MacdCrossStrategy::new()),
+        ];
+        for strategy in &strategies {
+            for spec in strategy.parameter_specs() {
+                assert_eq!(strategy.parameter_value(&spec.name), Some(spec.default), "{} / {}", strategy.name(), spec.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_set_parameter_rejects_out_of_range() {
+        let mut strategy = RSIStrategy::new();
+        assert!(strategy.set_parameter("rsi_period", -5.0).is_err());
+        assert!(strategy.set_parameter("inconnu", 1.0).is_err());
+        assert_eq!(strategy.parameter_value("rsi_period"), Some(14.0));
+        assert!(strategy.set_parameter("rsi_period", 21.0).is_ok());
+        assert_eq!(strategy.parameter_value("rsi_period"), Some(21.0));
+    }
 
+    #[test]
+    fn test_macd_periods_stay_ordered() {
+        let mut strategy = MacdCrossStrategy::new();
+        assert!(strategy.update_parameter("fast_period", 30.0).is_err());
+        assert!(strategy.update_parameter("slow_period", 40.0).is_ok());
+        assert!(strategy.update_parameter("fast_period", 30.0).is_ok());
+
+        // Appliquées ensemble, les deux périodes passent quel que soit l'ordre
+        let mut strategy = MacdCrossStrategy::new();
+        let errors = strategy.set_parameters(&[("fast_period".to_string(), 30.0), ("slow_period".to_string(), 40.0)]);
+        assert!(errors.is_empty());
+        assert_eq!(strategy.parameter_value("fast_period"), Some(30.0));
+    }
+}
@@ -6,5 +6,6 @@
 pub mod strategy;
 pub mod manager;
 pub mod examples;
+pub mod backtest;
 
 
@@ -1,6 +1,6 @@
 //! Trait de base pour les stratégies de trading
 
-use crate::finance_chart::core::{Candle, SeriesId};
+use crate::finance_chart::core::{Candle, SeriesData, SeriesId};
 use crate::app::data::OrderType;
 
 /// Contexte de marché fourni à une stratégie
@@ -20,6 +20,43 @@ pub struct MarketContext {
     pub current_volume: f64,
 }
 
+/// Nombre maximum de bougies d'historique fournies à la stratégie à chaque pas
+pub const HISTORY_WINDOW: usize = 500;
+
+impl MarketContext {
+    /// Contexte vu par la stratégie à la clôture de la bougie `index` de `series`
+    ///
+    /// L'historique se limite aux `HISTORY_WINDOW` bougies se terminant à `index`.
+    pub fn at_candle(series: &SeriesData, index: usize) -> Self {
+        let candle = series.data.all_candles()[index];
+        let mut context = Self {
+            symbol: series.symbol.clone(),
+            series_id: series.id.clone(),
+            current_candle: candle,
+            candles: Vec::with_capacity(HISTORY_WINDOW),
+            current_price: candle.close,
+            current_volume: candle.volume,
+        };
+        context.move_to(series, index);
+        context
+    }
+
+    /// Replace le contexte sur la bougie `index` de `series`
+    ///
+    /// Réutilise le tampon d'historique : à préférer à `at_candle` pour parcourir
+    /// une série bougie par bougie.
+    pub fn move_to(&mut self, series: &SeriesData, index: usize) {
+        let candles = series.data.all_candles();
+        let candle = candles[index];
+        let window_start = (index + 1).saturating_sub(HISTORY_WINDOW);
+        self.candles.clear();
+        self.candles.extend_from_slice(&candles[window_start..=index]);
+        self.current_candle = candle;
+        self.current_price = candle.close;
+        self.current_volume = candle.volume;
+    }
+}
+
 /// Mode de trading pour une stratégie
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum TradingMode {
@@ -81,6 +118,57 @@ pub struct StrategyParameter {
     pub description: String,
 }
 
+/// Description d'un paramètre réglable : bornes, pas et valeur par défaut
+///
+/// Permet à l'interface de générer les champs de configuration (curseurs bornés)
+/// et de rejeter les valeurs hors limites avant qu'elles n'atteignent la stratégie.
+#[derive(Debug, Clone)]
+pub struct ParamSpec {
+    pub name: String,
+    pub description: String,
+    pub min: f64,
+    pub max: f64,
+    /// Pas entre deux valeurs (1.0 pour une période)
+    pub step: f64,
+    /// Valeur à la création de la stratégie
+    pub default: f64,
+}
+
+impl ParamSpec {
+    /// Ramène `value` dans les bornes
+    pub fn clamp(&self, value: f64) -> f64 {
+        value.clamp(self.min, self.max)
+    }
+
+    /// Vérifie que `value` est dans les bornes et l'arrondit au pas le plus proche
+    pub fn validate(&self, value: f64) -> Result<f64, String> {
+        if !value.is_finite() {
+            return Err(format!("Valeur invalide pour {}", self.name));
+        }
+        if value < self.min || value > self.max {
+            return Err(format!(
+                "{} doit être entre {} et {} (reçu {})",
+                self.name, self.format(self.min), self.format(self.max), value
+            ));
+        }
+        if self.step <= 0.0 {
+            return Ok(value);
+        }
+        let snapped = self.min + ((value - self.min) / self.step).round() * self.step;
+        Ok(self.clamp(snapped))
+    }
+
+    /// Formate `value` avec autant de décimales que le pas
+    pub fn format(&self, value: f64) -> String {
+        let decimals = if self.step > 0.0 {
+            (-self.step.log10() - 1e-9).ceil().max(0.0) as usize
+        } else {
+            2
+        };
+        format!("{:.*}", decimals, value)
+    }
+}
+
 /// Trait que toutes les stratégies doivent implémenter
 pub trait TradingStrategy: Send + Sync {
     /// Nom de la stratégie
@@ -92,14 +180,95 @@ pub trait TradingStrategy: Send + Sync {
     /// Évalue le marché et génère un signal
     fn evaluate(&self, context: &MarketContext) -> StrategyResult;
     
-    /// Retourne les paramètres configurables de la stratégie
-    fn parameters(&self) -> Vec<StrategyParameter>;
+    /// Décrit les paramètres configurables de la stratégie (bornes, pas, défauts)
+    fn parameter_specs(&self) -> Vec<ParamSpec>;
+    
+    /// Valeur actuelle d'un paramètre (None si inconnu)
+    fn parameter_value(&self, name: &str) -> Option<f64>;
     
     /// Met à jour un paramètre
+    ///
+    /// Ne vérifie que les contraintes propres à la stratégie (période rapide <
+    /// période lente…) : passer par `set_parameter` pour valider les bornes.
     fn update_parameter(&mut self, name: &str, value: f64) -> Result<(), String>;
     
     /// Clone la stratégie (pour permettre le stockage dans un Vec)
     fn clone_box(&self) -> Box<dyn TradingStrategy>;
+
+    /// Retourne les paramètres configurables de la stratégie avec leur valeur actuelle
+    fn parameters(&self) -> Vec<StrategyParameter> {
+        self.parameter_specs()
+            .into_iter()
+            .filter_map(|spec| {
+                let value = self.parameter_value(&spec.name)?;
+                Some(StrategyParameter {
+                    name: spec.name,
+                    value,
+                    min: spec.min,
+                    max: spec.max,
+                    description: spec.description,
+                })
+            })
+            .collect()
+    }
+
+    /// Valide `value` selon la description du paramètre puis l'applique
+    ///
+    /// Les valeurs hors bornes (ou non finies) sont rejetées sans modifier la stratégie.
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), String> {
+        let spec = self
+            .parameter_specs()
+            .into_iter()
+            .find(|spec| spec.name == name)
+            .ok_or_else(|| format!("Paramètre inconnu: {}", name))?;
+        let value = spec.validate(value)?;
+        self.update_parameter(name, value)
+    }
+
+    /// Applique plusieurs paramètres avec `set_parameter` et retourne les erreurs
+    ///
+    /// Les valeurs rejetées sont retentées une fois après les autres : une
+    /// contrainte entre paramètres (période rapide < période lente) peut dépendre
+    /// d'une valeur appliquée plus loin dans la liste.
+    fn set_parameters(&mut self, values: &[(String, f64)]) -> Vec<String> {
+        let mut pending: Vec<&(String, f64)> = values.iter().collect();
+        let mut errors = Vec::new();
+        for _ in 0..2 {
+            errors.clear();
+            pending.retain(|(name, value)| match self.set_parameter(name, *value) {
+                Ok(()) => false,
+                Err(e) => {
+                    errors.push(e);
+                    true
+                }
+            });
+        }
+        errors
+    }
+
+    /// Évalue la stratégie sur chaque bougie de `series`, sans exécuter d'ordre
+    ///
+    /// Retourne les signaux d'achat et de vente avec le timestamp de leur bougie
+    /// (les `Hold` sont omis). Sert à visualiser la logique d'une stratégie sur
+    /// l'historique, indépendamment du moteur de backtest.
+    fn signals_over(&self, series: &SeriesData) -> Vec<(i64, TradingSignal)> {
+        let candles = series.data.all_candles();
+        if candles.is_empty() {
+            return Vec::new();
+        }
+        let mut context = MarketContext::at_candle(series, 0);
+        candles
+            .iter()
+            .enumerate()
+            .filter_map(|(index, candle)| {
+                context.move_to(series, index);
+                match self.evaluate(&context).signal {
+                    TradingSignal::Hold => None,
+                    signal => Some((candle.timestamp, signal)),
+                }
+            })
+            .collect()
+    }
 }
 
 // Implémentation de Clone pour Box<dyn TradingStrategy>
@@ -109,4 +278,39 @@ impl Clone for Box<dyn TradingStrategy> {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn period_spec() -> ParamSpec {
+        ParamSpec {
+            name: "period".to_string(),
+            description: "Période".to_string(),
+            min: 5.0,
+            max: 50.0,
+            step: 1.0,
+            default: 14.0,
+        }
+    }
+
+    #[test]
+    fn test_param_spec_validation() {
+        let spec = period_spec();
+        assert_eq!(spec.validate(20.0), Ok(20.0));
+        // Arrondi au pas : une période reste entière
+        assert_eq!(spec.validate(20.4), Ok(20.0));
+        assert!(spec.validate(-3.0).is_err());
+        assert!(spec.validate(51.0).is_err());
+        assert!(spec.validate(f64::NAN).is_err());
+        assert_eq!(spec.clamp(-3.0), 5.0);
+    }
 
+    #[test]
+    fn test_param_spec_format_follows_step() {
+        assert_eq!(period_spec().format(14.0), "14");
+        let quantity = ParamSpec { step: 0.0001, ..period_spec() };
+        assert_eq!(quantity.format(0.001), "0.0010");
+        let std_dev = ParamSpec { step: 0.1, ..period_spec() };
+        assert_eq!(std_dev.format(2.0), "2.0");
+    }
+}
@@ -1,3 +1,4 @@
 //! Module de trading pour gérer les ordres réels
 
 pub mod api;
+pub mod risk;
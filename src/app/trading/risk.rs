@@ -0,0 +1,45 @@
+//! Calcul de la taille de position selon le risque
+
+/// Calcule la quantité à trader pour que l'atteinte du stop fasse perdre
+/// exactement `risk_percent` % du solde
+///
+/// Retourne 0 si l'entrée et le stop sont identiques ou si les paramètres
+/// ne sont pas positifs.
+pub fn position_size(account_balance: f64, risk_percent: f64, entry: f64, stop: f64) -> f64 {
+    let risk_per_unit = (entry - stop).abs();
+    if risk_per_unit <= f64::EPSILON || account_balance <= 0.0 || risk_percent <= 0.0 {
+        return 0.0;
+    }
+    account_balance * risk_percent / 100.0 / risk_per_unit
+}
+
+/// Ratio gain/risque d'un trade : distance au take profit divisée par la distance au stop
+///
+/// Retourne None si le stop est au prix d'entrée.
+pub fn risk_reward_ratio(entry: f64, stop: f64, take_profit: f64) -> Option<f64> {
+    let risk = (entry - stop).abs();
+    (risk > f64::EPSILON).then(|| (take_profit - entry).abs() / risk)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_size_risks_exact_percent() {
+        // 1% de 10 000 = 100 de risque, 5 de risque par unité
+        let quantity = position_size(10_000.0, 1.0, 100.0, 95.0);
+        assert!((quantity - 20.0).abs() < 1e-9);
+        // Short : stop au-dessus de l'entrée
+        assert!((position_size(10_000.0, 1.0, 100.0, 105.0) - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_position_size_degenerate_inputs() {
+        assert_eq!(position_size(10_000.0, 1.0, 100.0, 100.0), 0.0);
+        assert_eq!(position_size(0.0, 1.0, 100.0, 95.0), 0.0);
+        assert_eq!(position_size(10_000.0, 0.0, 100.0, 95.0), 0.0);
+        assert_eq!(risk_reward_ratio(100.0, 100.0, 110.0), None);
+        assert_eq!(risk_reward_ratio(100.0, 95.0, 110.0), Some(2.0));
+    }
+}
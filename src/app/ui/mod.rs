@@ -10,6 +10,10 @@ pub use resize_handle::{
     volume_resize_handle,
     rsi_panel_resize_handle,
     macd_panel_resize_handle,
+    atr_panel_resize_handle,
+    obv_panel_resize_handle,
+    cci_panel_resize_handle,
+    williams_r_panel_resize_handle,
 };
 
 
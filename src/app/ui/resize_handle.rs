@@ -562,3 +562,438 @@ pub fn macd_panel_resize_handle(height: f32, is_resizing: bool) -> Element<'stat
         .into()
 }
 
+/// Handle de redimensionnement vertical pour le panneau ATR
+pub struct ATRPanelResizeHandle {
+    height: f32,
+    is_resizing: bool,
+}
+
+impl ATRPanelResizeHandle {
+    pub fn new(height: f32, is_resizing: bool) -> Self {
+        Self { height, is_resizing }
+    }
+}
+
+impl<Message> Program<Message> for ATRPanelResizeHandle
+where
+    Message: Clone + From<crate::app::messages::Message>,
+{
+    type State = ResizeHandleState;
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        
+        let handle_color = if self.is_resizing {
+            Color::from_rgb(0.4, 0.6, 0.9)
+        } else {
+            Color::from_rgb(0.3, 0.3, 0.35)
+        };
+        
+        let handle = Path::rectangle(Point::new(0.0, 0.0), Size::new(bounds.width, self.height));
+        frame.fill(&handle, handle_color);
+        
+        let border_color = if self.is_resizing {
+            Color::from_rgb(0.5, 0.7, 1.0)
+        } else {
+            Color::from_rgb(0.4, 0.4, 0.45)
+        };
+        
+        // Bordure basse
+        let border = Path::line(
+            Point::new(0.0, self.height - 0.5),
+            Point::new(bounds.width, self.height - 0.5)
+        );
+        frame.stroke(&border, Stroke::default().with_color(border_color).with_width(1.0));
+        
+        vec![frame.into_geometry()]
+    }
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Option<iced::widget::canvas::Action<Message>> {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) if cursor.is_over(bounds) => {
+                state.is_dragging = true;
+                if let Some(global_pos) = cursor.position() {
+                    state.drag_start = Some(global_pos.y);
+                    return Some(iced::widget::canvas::Action::publish(
+                        Message::from(crate::app::messages::Message::StartResizeATRPanel(global_pos.y))
+                    ));
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                state.is_dragging = false;
+                state.drag_start = None;
+                return Some(iced::widget::canvas::Action::publish(
+                    Message::from(crate::app::messages::Message::EndResizeATRPanel)
+                ));
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) if state.is_dragging => {
+                return Some(iced::widget::canvas::Action::publish(
+                    Message::from(crate::app::messages::Message::UpdateResizeATRPanel(position.y))
+                ));
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Self::State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        if cursor.is_over(bounds) {
+            mouse::Interaction::ResizingVertically
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+/// Crée un handle de redimensionnement vertical pour l'ATR chart
+pub fn atr_panel_resize_handle(height: f32, is_resizing: bool) -> Element<'static, crate::app::messages::Message> {
+    Canvas::new(ATRPanelResizeHandle::new(height, is_resizing))
+        .width(Length::Fill)
+        .height(Length::Fixed(height))
+        .into()
+}
+
+/// Handle de redimensionnement vertical pour le panneau OBV
+pub struct OBVPanelResizeHandle {
+    height: f32,
+    is_resizing: bool,
+}
+
+impl OBVPanelResizeHandle {
+    pub fn new(height: f32, is_resizing: bool) -> Self {
+        Self { height, is_resizing }
+    }
+}
+
+impl<Message> Program<Message> for OBVPanelResizeHandle
+where
+    Message: Clone + From<crate::app::messages::Message>,
+{
+    type State = ResizeHandleState;
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        
+        let handle_color = if self.is_resizing {
+            Color::from_rgb(0.4, 0.6, 0.9)
+        } else {
+            Color::from_rgb(0.3, 0.3, 0.35)
+        };
+        
+        let handle = Path::rectangle(Point::new(0.0, 0.0), Size::new(bounds.width, self.height));
+        frame.fill(&handle, handle_color);
+        
+        let border_color = if self.is_resizing {
+            Color::from_rgb(0.5, 0.7, 1.0)
+        } else {
+            Color::from_rgb(0.4, 0.4, 0.45)
+        };
+        
+        // Bordure basse
+        let border = Path::line(
+            Point::new(0.0, self.height - 0.5),
+            Point::new(bounds.width, self.height - 0.5)
+        );
+        frame.stroke(&border, Stroke::default().with_color(border_color).with_width(1.0));
+        
+        vec![frame.into_geometry()]
+    }
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Option<iced::widget::canvas::Action<Message>> {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) if cursor.is_over(bounds) => {
+                state.is_dragging = true;
+                if let Some(global_pos) = cursor.position() {
+                    state.drag_start = Some(global_pos.y);
+                    return Some(iced::widget::canvas::Action::publish(
+                        Message::from(crate::app::messages::Message::StartResizeOBVPanel(global_pos.y))
+                    ));
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                state.is_dragging = false;
+                state.drag_start = None;
+                return Some(iced::widget::canvas::Action::publish(
+                    Message::from(crate::app::messages::Message::EndResizeOBVPanel)
+                ));
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) if state.is_dragging => {
+                return Some(iced::widget::canvas::Action::publish(
+                    Message::from(crate::app::messages::Message::UpdateResizeOBVPanel(position.y))
+                ));
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Self::State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        if cursor.is_over(bounds) {
+            mouse::Interaction::ResizingVertically
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+/// Crée un handle de redimensionnement vertical pour l'OBV chart
+pub fn obv_panel_resize_handle(height: f32, is_resizing: bool) -> Element<'static, crate::app::messages::Message> {
+    Canvas::new(OBVPanelResizeHandle::new(height, is_resizing))
+        .width(Length::Fill)
+        .height(Length::Fixed(height))
+        .into()
+}
+
+/// Handle de redimensionnement vertical pour le panneau CCI
+pub struct CCIPanelResizeHandle {
+    height: f32,
+    is_resizing: bool,
+}
+
+impl CCIPanelResizeHandle {
+    pub fn new(height: f32, is_resizing: bool) -> Self {
+        Self { height, is_resizing }
+    }
+}
+
+impl<Message> Program<Message> for CCIPanelResizeHandle
+where
+    Message: Clone + From<crate::app::messages::Message>,
+{
+    type State = ResizeHandleState;
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        
+        let handle_color = if self.is_resizing {
+            Color::from_rgb(0.4, 0.6, 0.9)
+        } else {
+            Color::from_rgb(0.3, 0.3, 0.35)
+        };
+        
+        let handle = Path::rectangle(Point::new(0.0, 0.0), Size::new(bounds.width, self.height));
+        frame.fill(&handle, handle_color);
+        
+        let border_color = if self.is_resizing {
+            Color::from_rgb(0.5, 0.7, 1.0)
+        } else {
+            Color::from_rgb(0.4, 0.4, 0.45)
+        };
+        
+        // Bordure basse
+        let border = Path::line(
+            Point::new(0.0, self.height - 0.5),
+            Point::new(bounds.width, self.height - 0.5)
+        );
+        frame.stroke(&border, Stroke::default().with_color(border_color).with_width(1.0));
+        
+        vec![frame.into_geometry()]
+    }
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Option<iced::widget::canvas::Action<Message>> {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) if cursor.is_over(bounds) => {
+                state.is_dragging = true;
+                if let Some(global_pos) = cursor.position() {
+                    state.drag_start = Some(global_pos.y);
+                    return Some(iced::widget::canvas::Action::publish(
+                        Message::from(crate::app::messages::Message::StartResizeCCIPanel(global_pos.y))
+                    ));
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                state.is_dragging = false;
+                state.drag_start = None;
+                return Some(iced::widget::canvas::Action::publish(
+                    Message::from(crate::app::messages::Message::EndResizeCCIPanel)
+                ));
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) if state.is_dragging => {
+                return Some(iced::widget::canvas::Action::publish(
+                    Message::from(crate::app::messages::Message::UpdateResizeCCIPanel(position.y))
+                ));
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Self::State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        if cursor.is_over(bounds) {
+            mouse::Interaction::ResizingVertically
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+/// Crée un handle de redimensionnement vertical pour le CCI chart
+pub fn cci_panel_resize_handle(height: f32, is_resizing: bool) -> Element<'static, crate::app::messages::Message> {
+    Canvas::new(CCIPanelResizeHandle::new(height, is_resizing))
+        .width(Length::Fill)
+        .height(Length::Fixed(height))
+        .into()
+}
+
+/// Handle de redimensionnement vertical pour le panneau Williams %R
+pub struct WilliamsRPanelResizeHandle {
+    height: f32,
+    is_resizing: bool,
+}
+
+impl WilliamsRPanelResizeHandle {
+    pub fn new(height: f32, is_resizing: bool) -> Self {
+        Self { height, is_resizing }
+    }
+}
+
+impl<Message> Program<Message> for WilliamsRPanelResizeHandle
+where
+    Message: Clone + From<crate::app::messages::Message>,
+{
+    type State = ResizeHandleState;
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        
+        let handle_color = if self.is_resizing {
+            Color::from_rgb(0.4, 0.6, 0.9)
+        } else {
+            Color::from_rgb(0.3, 0.3, 0.35)
+        };
+        
+        let handle = Path::rectangle(Point::new(0.0, 0.0), Size::new(bounds.width, self.height));
+        frame.fill(&handle, handle_color);
+        
+        let border_color = if self.is_resizing {
+            Color::from_rgb(0.5, 0.7, 1.0)
+        } else {
+            Color::from_rgb(0.4, 0.4, 0.45)
+        };
+        
+        // Bordure basse
+        let border = Path::line(
+            Point::new(0.0, self.height - 0.5),
+            Point::new(bounds.width, self.height - 0.5)
+        );
+        frame.stroke(&border, Stroke::default().with_color(border_color).with_width(1.0));
+        
+        vec![frame.into_geometry()]
+    }
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Option<iced::widget::canvas::Action<Message>> {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) if cursor.is_over(bounds) => {
+                state.is_dragging = true;
+                if let Some(global_pos) = cursor.position() {
+                    state.drag_start = Some(global_pos.y);
+                    return Some(iced::widget::canvas::Action::publish(
+                        Message::from(crate::app::messages::Message::StartResizeWilliamsRPanel(global_pos.y))
+                    ));
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                state.is_dragging = false;
+                state.drag_start = None;
+                return Some(iced::widget::canvas::Action::publish(
+                    Message::from(crate::app::messages::Message::EndResizeWilliamsRPanel)
+                ));
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position }) if state.is_dragging => {
+                return Some(iced::widget::canvas::Action::publish(
+                    Message::from(crate::app::messages::Message::UpdateResizeWilliamsRPanel(position.y))
+                ));
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Self::State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> mouse::Interaction {
+        if cursor.is_over(bounds) {
+            mouse::Interaction::ResizingVertically
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+/// Crée un handle de redimensionnement vertical pour le Williams %R chart
+pub fn williams_r_panel_resize_handle(height: f32, is_resizing: bool) -> Element<'static, crate::app::messages::Message> {
+    Canvas::new(WilliamsRPanelResizeHandle::new(height, is_resizing))
+        .width(Length::Fill)
+        .height(Length::Fixed(height))
+        .into()
+}
@@ -7,6 +7,12 @@ pub const DATA_FILE: &str = "data/BTCUSDT_1h.json";
 pub const MAIN_WINDOW_WIDTH: f32 = 1200.0;
 pub const MAIN_WINDOW_HEIGHT: f32 = 800.0;
 
+/// Nombre de bougies affichées à l'ouverture d'une série
+pub const INITIAL_VISIBLE_CANDLES: usize = 150;
+
+/// Marge verticale de l'échelle de prix (10% en haut et en bas)
+pub const PRICE_MARGIN_RATIO: f32 = 0.1;
+
 /// Dimensions de la fenêtre de settings
 pub const SETTINGS_WINDOW_WIDTH: f32 = 500.0;
 pub const SETTINGS_WINDOW_HEIGHT: f32 = 450.0;
@@ -14,6 +20,13 @@ pub const SETTINGS_WINDOW_HEIGHT: f32 = 450.0;
 /// Intervalle de mise à jour en temps réel (en secondes)
 pub const REALTIME_UPDATE_INTERVAL_SECS: f64 = 0.9;
 
+/// Dossier du cache disque des bougies téléchargées
+pub const CANDLE_CACHE_DIR: &str = "cache/candles";
+
+/// Base SQLite des séries (feature `sqlite`)
+#[cfg(feature = "sqlite")]
+pub const SQLITE_DB_FILE: &str = "data/candles.db";
+
 /// Largeur de la section à droite du graphique
 pub const RIGHT_PANEL_WIDTH: f32 = 200.0;
 
@@ -29,6 +42,18 @@ pub const RSI_CHART_HEIGHT: f32 = 120.0;
 /// Hauteur du graphique MACD (sous le graphique principal)
 pub const MACD_CHART_HEIGHT: f32 = 120.0;
 
+/// Hauteur du graphique ATR (sous le graphique principal)
+pub const ATR_CHART_HEIGHT: f32 = 120.0;
+
+/// Hauteur du graphique OBV (sous le graphique principal)
+pub const OBV_CHART_HEIGHT: f32 = 120.0;
+
+/// Hauteur du graphique CCI (sous le graphique principal)
+pub const CCI_CHART_HEIGHT: f32 = 120.0;
+
+/// Hauteur du graphique Williams %R (sous le graphique principal)
+pub const WILLIAMS_R_CHART_HEIGHT: f32 = 120.0;
+
 /// Largeur de l'onglet d'indicateurs
 pub const INDICATORS_PANEL_WIDTH: f32 = 250.0;
 
@@ -262,7 +262,11 @@ pub fn view_bottom_panel_account(app: &ChartApp) -> Element<'_, Message> {
     
     // Déterminer le statut de connexion pour la pastille
     let is_connected = if is_real_mode {
-        app.provider_connection_status.unwrap_or(false)
+        // Si un flux WebSocket est ouvert, la pastille reflète l'état du socket
+        match app.ws_connection.filter(|_| app.realtime_enabled) {
+            Some(state) => state.is_live(),
+            None => app.provider_connection_status.unwrap_or(false),
+        }
     } else {
         true // En mode paper, considéré comme "connecté"
     };
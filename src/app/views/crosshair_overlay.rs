@@ -3,22 +3,23 @@
 //! Ce composant dessine une ligne verticale qui traverse tous les graphiques
 //! (principal, volume, RSI, MACD) de manière synchronisée.
 
-use iced::widget::canvas::{Canvas, Frame, Geometry, Program, Path, Stroke};
+use iced::widget::canvas::{Canvas, Frame, Geometry, Program};
 use iced::{Element, Event, Length, Point, Rectangle, mouse};
 use iced::mouse::Cursor;
 use crate::finance_chart::state::ChartState;
-use crate::finance_chart::render::crosshair::{CrosshairStyle, draw_time_label};
-use crate::finance_chart::render::grid::format_time;
+use crate::finance_chart::settings::ChartStyle;
+use crate::finance_chart::render::crosshair::CrosshairStyle;
 use crate::finance_chart::X_AXIS_HEIGHT;
 
 /// Programme canvas pour le crosshair vertical synchronisé
 pub struct CrosshairOverlayProgram<'a> {
     chart_state: &'a ChartState,
+    chart_style: &'a ChartStyle,
 }
 
 impl<'a> CrosshairOverlayProgram<'a> {
-    pub fn new(chart_state: &'a ChartState) -> Self {
-        Self { chart_state }
+    pub fn new(chart_state: &'a ChartState, chart_style: &'a ChartStyle) -> Self {
+        Self { chart_state, chart_style }
     }
 }
 
@@ -54,15 +55,8 @@ impl<'a, Message> Program<Message> for CrosshairOverlayProgram<'a> {
             None
         };
         
-        // Si on n'a pas de timestamp depuis le curseur, utiliser la position du graphique principal
-        let timestamp = timestamp.or_else(|| {
-            if let Some(main_pos) = self.chart_state.interaction.mouse_position {
-                let viewport = &self.chart_state.viewport;
-                Some(viewport.time_scale().x_to_time(main_pos.x))
-            } else {
-                None
-            }
-        });
+        // Si on n'a pas de timestamp depuis le curseur, utiliser le timestamp survolé partagé
+        let timestamp = timestamp.or(self.chart_state.hover_timestamp);
         
         // Dessiner la ligne verticale si on a un timestamp
         if let Some(timestamp) = timestamp {
@@ -73,26 +67,14 @@ impl<'a, Message> Program<Message> for CrosshairOverlayProgram<'a> {
                 let x = viewport.time_scale().time_to_x(timestamp);
                 
                 if x >= 0.0 && x <= chart_width {
-                    let style = CrosshairStyle::default();
+                    let style = CrosshairStyle::from_chart_style(self.chart_style);
                     
                     // Dessiner la ligne verticale sur toute la hauteur (sauf l'axe X en bas)
                     let chart_height = bounds.height - X_AXIS_HEIGHT;
                     
                     if chart_height > 0.0 {
-                        let vertical_line = Path::new(|builder| {
-                            builder.move_to(Point::new(x, 0.0));
-                            builder.line_to(Point::new(x, chart_height));
-                        });
-                        
-                        let stroke = Stroke::default()
-                            .with_color(style.line_color)
-                            .with_width(style.line_width);
-                        
-                        frame.stroke(&vertical_line, stroke);
-                        
-                        // Dessiner le label du temps sur le bord bas (dans l'axe X)
-                        let time_label = format_time(timestamp, 3600);
-                        draw_time_label(&mut frame, &style, x, bounds.height, &time_label);
+                        style.stroke_line(&mut frame, Point::new(x, 0.0), Point::new(x, chart_height));
+                        // Note: Le label du temps est dessiné par l'axe X
                     }
                 }
             }
@@ -131,8 +113,9 @@ impl<'a, Message> Program<Message> for CrosshairOverlayProgram<'a> {
 /// Crée un canvas overlay pour le crosshair vertical synchronisé
 pub fn crosshair_overlay<'a>(
     chart_state: &'a ChartState,
+    chart_style: &'a ChartStyle,
 ) -> Element<'a, crate::app::messages::Message> {
-    Canvas::new(CrosshairOverlayProgram::new(chart_state))
+    Canvas::new(CrosshairOverlayProgram::new(chart_state, chart_style))
         .width(Length::Fill)
         .height(Length::Fill)
         .into()
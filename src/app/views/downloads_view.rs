@@ -9,6 +9,7 @@ use crate::app::view_styles;
 /// Vue de la fenêtre de téléchargements
 pub fn view_downloads(app: &ChartApp) -> Element<'_, Message> {
     let downloads = app.download_manager.all_downloads();
+    let rate_limited = app.data_provider.is_rate_limited();
     
     if downloads.is_empty() {
         container(
@@ -72,6 +73,10 @@ pub fn view_downloads(app: &ChartApp) -> Element<'_, Message> {
                                 text("⏸️ En pause")
                                     .size(12)
                                     .color(Color::from_rgb(1.0, 0.7, 0.0))
+                            } else if rate_limited {
+                                text("⏳ Limite de requêtes atteinte, en attente")
+                                    .size(12)
+                                    .color(Color::from_rgb(1.0, 0.7, 0.0))
                             } else {
                                 text(gaps_text_clone.clone())
                                     .size(12)
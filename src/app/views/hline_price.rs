@@ -0,0 +1,101 @@
+//! Champ de saisie du prix d'une ligne horizontale
+//!
+//! Affiché par-dessus l'étiquette de prix de la ligne après un clic, avec
+//! une palette pour changer la couleur de la ligne.
+
+use iced::widget::{button, container, row, stack, text, text_input};
+use iced::{Color, Element, Length};
+use crate::finance_chart::ChartMessage;
+use crate::app::{app_state::ChartApp, messages::Message};
+
+/// Identifiant du champ de saisie (utilisé pour lui donner le focus)
+pub const HLINE_PRICE_INPUT_ID: &str = "hline-price-input";
+
+/// Largeur approximative de l'éditeur (champ + palette)
+const EDITOR_WIDTH: f32 = 190.0;
+
+/// Largeur de l'étiquette de prix sur laquelle l'éditeur s'aligne à droite
+const LABEL_WIDTH: f32 = 60.0;
+
+/// Couleurs proposées pour les lignes horizontales
+pub const LINE_COLORS: [Color; 6] = [
+    Color { r: 1.0, g: 0.8, b: 0.0, a: 0.8 },
+    Color { r: 0.2, g: 0.8, b: 0.4, a: 0.8 },
+    Color { r: 1.0, g: 0.3, b: 0.3, a: 0.8 },
+    Color { r: 0.3, g: 0.6, b: 1.0, a: 0.8 },
+    Color { r: 0.7, g: 0.5, b: 1.0, a: 0.8 },
+    Color { r: 0.9, g: 0.9, b: 0.9, a: 0.8 },
+];
+
+/// Overlay contenant le champ de saisie du prix de la ligne en cours d'édition
+pub fn hline_price_overlay(app: &ChartApp) -> Element<'_, Message> {
+    let Some(edit) = &app.tools_state.hline_price_editing else {
+        return stack![].width(Length::Fill).height(Length::Fill).into();
+    };
+    let (x, y) = edit.position;
+    let index = edit.index;
+    let current_color = app.tools_state.horizontal_lines.get(index).map(|line| line.color);
+
+    let submit = match edit.parsed_price() {
+        Some(price) => ChartMessage::SetHLinePrice { index, price },
+        None => ChartMessage::CancelHLinePriceEdit,
+    };
+    let input = text_input("Prix", &edit.content)
+        .id(HLINE_PRICE_INPUT_ID)
+        .on_input(|content| Message::Chart(ChartMessage::UpdateHLinePriceDraft { content }))
+        .on_submit(Message::Chart(submit))
+        .padding(2)
+        .size(11)
+        .width(Length::Fixed(80.0));
+
+    let mut editor = row![input].spacing(3).align_y(iced::Alignment::Center);
+    for color in LINE_COLORS {
+        let is_current = current_color == Some(color);
+        let swatch = container(text(""))
+            .width(Length::Fixed(14.0))
+            .height(Length::Fixed(14.0))
+            .style(move |_theme| container::Style {
+                background: Some(iced::Background::Color(color)),
+                border: iced::Border {
+                    color: if is_current { Color::WHITE } else { Color::from_rgb(0.3, 0.3, 0.35) },
+                    width: 1.0,
+                    radius: 2.0.into(),
+                },
+                ..Default::default()
+            });
+        editor = editor.push(
+            button(swatch)
+                .on_press(Message::Chart(ChartMessage::SetHLineColor { index, color }))
+                .padding(0)
+                .style(|_theme, _status| button::Style {
+                    background: None,
+                    ..Default::default()
+                }),
+        );
+    }
+
+    container(
+        container(editor)
+            .padding(2)
+            .style(|_theme| container::Style {
+                background: Some(iced::Background::Color(Color::from_rgb(0.12, 0.12, 0.15))),
+                border: iced::Border {
+                    color: Color::from_rgb(0.3, 0.3, 0.35),
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            })
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .align_x(iced::alignment::Horizontal::Left)
+    .align_y(iced::alignment::Vertical::Top)
+    .padding(iced::Padding {
+        left: (x + LABEL_WIDTH - EDITOR_WIDTH).max(0.0),
+        top: y,
+        right: 0.0,
+        bottom: 0.0,
+    })
+    .into()
+}
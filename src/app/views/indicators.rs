@@ -22,7 +22,7 @@ pub fn indicators_panel(app: &ChartApp) -> Element<'_, Message> {
     // Liste des indicateurs disponibles avec leur état
     let indicators = vec![
         Indicator {
-            name: "Volume Profile",
+            name: "Volume",
             is_active: app.ui.panels.volume.visible,
             on_toggle: |_| Message::ToggleVolumePanel,
         },
@@ -36,6 +36,26 @@ pub fn indicators_panel(app: &ChartApp) -> Element<'_, Message> {
             is_active: app.ui.panels.macd.visible,
             on_toggle: |_| Message::ToggleMACDPanel,
         },
+        Indicator {
+            name: "ATR",
+            is_active: app.ui.panels.atr.visible,
+            on_toggle: |_| Message::ToggleATRPanel,
+        },
+        Indicator {
+            name: "OBV",
+            is_active: app.ui.panels.obv.visible,
+            on_toggle: |_| Message::ToggleOBVPanel,
+        },
+        Indicator {
+            name: "CCI",
+            is_active: app.ui.panels.cci.visible,
+            on_toggle: |_| Message::ToggleCCIPanel,
+        },
+        Indicator {
+            name: "Williams %R",
+            is_active: app.ui.panels.williams_r.visible,
+            on_toggle: |_| Message::ToggleWilliamsRPanel,
+        },
         Indicator {
             name: "Bollinger Bands",
             is_active: app.indicators.bollinger_bands_enabled,
@@ -46,6 +66,41 @@ pub fn indicators_panel(app: &ChartApp) -> Element<'_, Message> {
             is_active: app.indicators.moving_average_enabled,
             on_toggle: |_| Message::ToggleMovingAverage,
         },
+        Indicator {
+            name: "VWAP",
+            is_active: app.indicators.vwap_enabled,
+            on_toggle: |_| Message::ToggleVWAP,
+        },
+        Indicator {
+            name: "Parabolic SAR",
+            is_active: app.indicators.psar_enabled,
+            on_toggle: |_| Message::ToggleParabolicSAR,
+        },
+        Indicator {
+            name: "Donchian Channels",
+            is_active: app.indicators.donchian_enabled,
+            on_toggle: |_| Message::ToggleDonchian,
+        },
+        Indicator {
+            name: "Keltner Channels",
+            is_active: app.indicators.keltner_enabled,
+            on_toggle: |_| Message::ToggleKeltner,
+        },
+        Indicator {
+            name: "Volume Profile",
+            is_active: app.indicators.volume_profile_enabled,
+            on_toggle: |_| Message::ToggleVolumeProfile,
+        },
+        Indicator {
+            name: "Pivot Points",
+            is_active: app.indicators.pivots_enabled,
+            on_toggle: |_| Message::TogglePivots,
+        },
+        Indicator {
+            name: "MA Crossovers",
+            is_active: app.indicators.crossover_markers_enabled,
+            on_toggle: |_| Message::ToggleCrossoverMarkers,
+        },
         Indicator {
             name: "Stochastic",
             is_active: false,
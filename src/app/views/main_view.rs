@@ -3,10 +3,12 @@
 use iced::widget::{button, column, container, mouse_area, row, stack, text, Space};
 use iced::{Element, Length};
 use crate::finance_chart::{
-    chart, chart_with_trading, chart_with_trades_and_trading,
-    x_axis, y_axis, tools_panel, series_select_box,
+    chart, chart_with_trading, chart_with_trades_and_trading, ChartMessage, ChartOptions,
+    x_axis, y_axis, y_axis_left, tools_panel, series_select_box, display_interval_select, interval_toolbar,
     X_AXIS_HEIGHT, TOOLS_PANEL_WIDTH,
 };
+use crate::finance_chart::state::{ReadoutEntry, ReadoutIndicators};
+use crate::app::state::replay::REPLAY_SPEEDS_MS;
 use crate::app::{
     app_state::ChartApp,
     messages::Message,
@@ -18,6 +20,9 @@ use super::panels::{view_right_panel, view_bottom_panel, build_indicator_panels,
 use super::crosshair_overlay::crosshair_overlay;
 use super::error_messages::error_messages_overlay;
 use super::notifications::notifications_overlay;
+use super::text_annotation::text_annotation_overlay;
+use super::hline_price::hline_price_overlay;
+use super::rectangle_style::rectangle_style_overlay;
 
 /// Composant qui regroupe toutes les sections du graphique
 fn view_chart_component(app: &ChartApp) -> Element<'_, Message> {
@@ -35,9 +40,24 @@ fn view_chart_component(app: &ChartApp) -> Element<'_, Message> {
 
             let trades = &app.trading_state.trade_history.trades;
 
+            let options = ChartOptions {
+                panel_focused,
+                bollinger_enabled: app.indicators.bollinger_bands_enabled,
+                ma_enabled: app.indicators.moving_average_enabled,
+                vwap_enabled: app.indicators.vwap_enabled,
+                psar_enabled: app.indicators.psar_enabled,
+                donchian_enabled: app.indicators.donchian_enabled,
+                keltner_enabled: app.indicators.keltner_enabled,
+                volume_profile_enabled: app.indicators.volume_profile_enabled,
+                pivots_enabled: app.indicators.pivots_enabled,
+                crossover_markers_enabled: app.indicators.crossover_markers_enabled,
+                indicator_params: Some(&app.indicators.params),
+                backtest_state: Some(&app.ui.backtest_state),
+                render_version: app.render_version,
+            };
+
             // Utiliser chart_with_trades_and_trading si on est en mode paper et qu'il y a des trades
             // Sinon utiliser chart_with_trading pour afficher les ordres limit même sans trades
-            let backtest_state = Some(&app.ui.backtest_state);
             if app.account_type.is_demo() && !current_symbol.is_empty() {
                 if !trades.is_empty() {
                     chart_with_trades_and_trading(
@@ -45,14 +65,10 @@ fn view_chart_component(app: &ChartApp) -> Element<'_, Message> {
                         &app.tools_state,
                         &app.settings_state,
                         &app.chart_style,
-                        panel_focused,
                         trades,
                         current_symbol,
                         &app.trading_state,
-                        app.indicators.bollinger_bands_enabled,
-                        app.indicators.moving_average_enabled,
-                        Some(&app.indicators.params),
-                        backtest_state,
+                        &options,
                     )
                     .map(Message::Chart)
                 } else {
@@ -61,18 +77,14 @@ fn view_chart_component(app: &ChartApp) -> Element<'_, Message> {
                         &app.tools_state,
                         &app.settings_state,
                         &app.chart_style,
-                        panel_focused,
                         &app.trading_state,
                         current_symbol,
-                        app.indicators.bollinger_bands_enabled,
-                        app.indicators.moving_average_enabled,
-                        Some(&app.indicators.params),
-                        backtest_state,
+                        &options,
                     )
                     .map(Message::Chart)
                 }
             } else {
-                chart(&app.chart_state, &app.tools_state, &app.settings_state, &app.chart_style, panel_focused, app.indicators.bollinger_bands_enabled, app.indicators.moving_average_enabled, Some(&app.indicators.params), backtest_state)
+                chart(&app.chart_state, &app.tools_state, &app.settings_state, &app.chart_style, &options)
                     .map(Message::Chart)
             }
         })
@@ -81,8 +93,60 @@ fn view_chart_component(app: &ChartApp) -> Element<'_, Message> {
     )
     .on_enter(Message::ClearPanelFocus);
 
+    // Bouton flottant "aller à la dernière bougie", seulement si elle est hors de la vue
+    let (_, view_max_time) = app.chart_state.viewport.time_scale().time_range();
+    let scrolled_away = app.chart_state.last_candle()
+        .is_some_and(|candle| candle.timestamp > view_max_time);
+    let main_chart: Element<'_, Message> = if scrolled_away {
+        stack![
+            main_chart,
+            container(
+                button(text("»").size(16))
+                    .on_press(Message::GoToLatest)
+                    .padding([2, 10])
+                    .style(view_styles::icon_button_style)
+            )
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .align_x(iced::alignment::Horizontal::Right)
+            .align_y(iced::alignment::Vertical::Bottom)
+            .padding(10)
+        ]
+        .into()
+    } else {
+        main_chart.into()
+    };
+
+    // Barre de contrôle du replay, seulement pendant un replay
+    let main_chart: Element<'_, Message> = if app.chart_state.replay_cursor.is_some() {
+        stack![
+            main_chart,
+            container(replay_controls(app))
+                .width(Length::Fill)
+                .align_x(iced::alignment::Horizontal::Center)
+                .padding(8)
+        ]
+        .into()
+    } else {
+        main_chart
+    };
+
+    // Axe Y de gauche, superposé au bord du graphique (après la barre d'outils)
+    // pour garder l'alignement temporel avec l'axe X et les panneaux d'indicateurs
+    let main_chart: Element<'_, Message> = if app.chart_state.left_axis_viewport().is_some() {
+        stack![
+            main_chart,
+            container(y_axis_left(&app.chart_state, &app.chart_style).map(Message::YAxis))
+                .height(Length::Fill)
+                .padding(iced::Padding { left: TOOLS_PANEL_WIDTH, ..iced::Padding::ZERO })
+        ]
+        .into()
+    } else {
+        main_chart
+    };
+
     // Axe Y à droite
-    let y_axis_element = y_axis(&app.chart_state).map(Message::YAxis);
+    let y_axis_element = y_axis(&app.chart_state, &app.chart_style).map(Message::YAxis);
 
     // Ligne principale du graphique : Chart (gauche) + Axe Y (droite)
     let chart_area = row![
@@ -93,7 +157,7 @@ fn view_chart_component(app: &ChartApp) -> Element<'_, Message> {
     .height(Length::Fill);
 
     // Axe X en bas
-    let x_axis_element = x_axis(&app.chart_state).map(Message::XAxis);
+    let x_axis_element = x_axis(&app.chart_state, &app.chart_style).map(Message::XAxis);
 
     // Ligne du bas : Axe X + bouton settings (coin)
     let bottom_row = row![
@@ -103,8 +167,11 @@ fn view_chart_component(app: &ChartApp) -> Element<'_, Message> {
     .width(Length::Fill)
     .height(Length::Fixed(X_AXIS_HEIGHT));
 
-    // Construire le layout vertical avec les indicateurs
-    let mut layout_items: Vec<Element<'_, Message>> = vec![chart_area.into()];
+    // Construire le layout vertical : bandeau de lecture, graphique, indicateurs
+    let mut layout_items: Vec<Element<'_, Message>> = vec![
+        indicator_readout_strip(app),
+        chart_area.into(),
+    ];
 
     // Ajouter les panneaux d'indicateurs (Volume, RSI, MACD)
     build_indicator_panels(app, &mut layout_items);
@@ -138,7 +205,7 @@ fn view_chart_component(app: &ChartApp) -> Element<'_, Message> {
             .align_x(iced::alignment::Horizontal::Left)
             .align_y(iced::alignment::Vertical::Top),
         // Overlay pour la barre verticale synchronisée du crosshair
-        container(crosshair_overlay(&app.chart_state))
+        container(crosshair_overlay(&app.chart_state, &app.chart_style))
             .width(Length::Fill)
             .height(Length::Fill)
             .style(|_theme| container::Style {
@@ -159,6 +226,99 @@ fn view_chart_component(app: &ChartApp) -> Element<'_, Message> {
     chart_with_indicators_overlay(layout_stack.into(), app)
 }
 
+/// Hauteur du bandeau de lecture des indicateurs
+const READOUT_STRIP_HEIGHT: f32 = 18.0;
+
+/// Bandeau d'une ligne affichant les valeurs des indicateurs activés à la bougie survolée
+///
+/// Le détail de la bougie sélectionnée reste affiché à droite jusqu'à sa désélection.
+fn indicator_readout_strip(app: &ChartApp) -> Element<'_, Message> {
+    let indicators = ReadoutIndicators {
+        volume: app.ui.panels.volume.visible,
+        rsi: app.ui.panels.rsi.visible,
+        macd: app.ui.panels.macd.visible,
+        bollinger: app.indicators.bollinger_bands_enabled,
+        moving_averages: app.indicators.moving_average_enabled,
+    };
+    let entries = app.chart_state.hover_timestamp
+        .map(|time| app.chart_state.indicator_readout_at(time, indicators, &app.indicators.params, &app.chart_style))
+        .unwrap_or_default();
+
+    let readout_entry = |entry: ReadoutEntry| {
+        row![
+            text(entry.label).size(11).color(colors::TEXT_SECONDARY),
+            text(entry.value).size(11).color(colors::TEXT_PRIMARY),
+        ]
+        .spacing(4)
+    };
+
+    let mut strip = row![].spacing(12).align_y(iced::Alignment::Center);
+    for entry in entries {
+        strip = strip.push(readout_entry(entry));
+    }
+
+    let selected_entries = app.chart_state.selected_candle_readout(&app.chart_style);
+    if !selected_entries.is_empty() {
+        strip = strip.push(Space::new().width(Length::Fill));
+        for entry in selected_entries {
+            strip = strip.push(readout_entry(entry));
+        }
+        strip = strip.push(
+            button(text("✕").size(10))
+                .on_press(Message::Chart(ChartMessage::DeselectCandle))
+                .padding([0, 4])
+                .style(view_styles::icon_button_style)
+        );
+    }
+
+    container(strip)
+        .width(Length::Fill)
+        .height(Length::Fixed(READOUT_STRIP_HEIGHT))
+        .padding(iced::Padding { left: TOOLS_PANEL_WIDTH + 8.0, right: 8.0, top: 2.0, bottom: 2.0 })
+        .style(view_styles::dark_background_style)
+        .into()
+}
+
+/// Barre de contrôle du replay (pas à pas, lecture, vitesse, arrêt)
+fn replay_controls(app: &ChartApp) -> Element<'_, Message> {
+    let play_label = if app.ui.replay.is_playing { "⏸" } else { "▶" };
+    let mut controls = row![
+        text("Replay").size(12).color(colors::TEXT_SECONDARY),
+        button(text("⏭").size(12))
+            .on_press(Message::ReplayStep)
+            .padding([2, 8])
+            .style(view_styles::icon_button_style),
+        button(text(play_label).size(12))
+            .on_press(Message::ReplayPlay)
+            .padding([2, 8])
+            .style(view_styles::icon_button_style),
+    ]
+    .spacing(4)
+    .align_y(iced::Alignment::Center);
+
+    for speed_ms in REPLAY_SPEEDS_MS {
+        let label = format!("{:.1}/s", 1000.0 / speed_ms as f64);
+        let speed_button = button(text(label).size(11)).on_press(Message::ReplaySetSpeed(speed_ms)).padding([2, 6]);
+        controls = controls.push(if app.ui.replay.speed_ms == speed_ms {
+            speed_button.style(view_styles::success_button_style)
+        } else {
+            speed_button.style(view_styles::icon_button_style)
+        });
+    }
+
+    controls = controls.push(
+        button(text("⏹").size(12))
+            .on_press(Message::ReplayStop)
+            .padding([2, 8])
+            .style(view_styles::icon_button_style)
+    );
+
+    container(controls)
+        .padding([4, 8])
+        .style(view_styles::panel_container_style)
+        .into()
+}
+
 /// Vue principale de l'application
 pub fn view_main(app: &ChartApp) -> Element<'_, Message> {
     // Récupérer le symbole de la série active pour le titre
@@ -280,6 +440,8 @@ pub fn view_main(app: &ChartApp) -> Element<'_, Message> {
         action_buttons,
         Space::new().width(Length::Fixed(10.0)),
         series_select_box(&app.chart_state.series_manager, app.selected_asset_symbol.as_ref()).map(Message::SeriesPanel),
+        interval_toolbar(&app.chart_state.series_manager, app.selected_asset_symbol.as_ref()).map(Message::Chart),
+        display_interval_select(&app.chart_state).map(Message::Chart),
         Space::new().width(Length::Fixed(10.0)),
         status_label
     ]
@@ -425,6 +587,9 @@ pub fn view_main(app: &ChartApp) -> Element<'_, Message> {
         .height(Length::Fill),
         section_context_menu_overlay,
         chart_context_menu_overlay,
+        text_annotation_overlay(app),
+        hline_price_overlay(app),
+        rectangle_style_overlay(app),
         error_overlay,
         notifications_overlay_element
     ]
@@ -444,8 +609,35 @@ fn chart_context_menu(app: &ChartApp) -> Element<'_, Message> {
             .on_press(Message::ResetView)
             .style(view_styles::icon_button_style)
             .width(Length::Fill),
+        button("📷 Exporter PNG")
+            .on_press(Message::ExportChartPng)
+            .style(view_styles::icon_button_style)
+            .width(Length::Fill),
+        button("📄 Exporter CSV (visible)")
+            .on_press(Message::ExportVisibleCsv)
+            .style(view_styles::icon_button_style)
+            .width(Length::Fill),
     ];
     
+    let comparison_label = if app.chart_state.comparison_mode {
+        "💲 Revenir aux prix"
+    } else {
+        "📊 Comparer en %"
+    };
+    menu_items = menu_items.push(
+        button(comparison_label)
+            .on_press(Message::ToggleComparisonMode)
+            .style(view_styles::icon_button_style)
+            .width(Length::Fill)
+    );
+    
+    menu_items = menu_items.push(
+        button("⏪ Replay à partir d'ici")
+            .on_press(Message::ReplayStart)
+            .style(view_styles::icon_button_style)
+            .width(Length::Fill)
+    );
+    
     // Ajouter le bouton "Définir lecture" si le backtest est activé
     if app.ui.backtest_state.enabled {
         menu_items = menu_items.push(
@@ -14,6 +14,9 @@
 //! - `settings.rs` : fenêtre de configuration du style
 //! - `provider.rs` : fenêtre de configuration des providers
 //! - `account.rs` : section compte et trading
+//! - `text_annotation.rs` : champ de saisie des annotations texte
+//! - `hline_price.rs` : champ de saisie du prix des lignes horizontales
+//! - `rectangle_style.rs` : barre de style du rectangle sélectionné
 
 mod main_view;
 mod panels;
@@ -29,6 +32,9 @@ mod crosshair_overlay;
 mod backtest_overlay;
 mod error_messages;
 mod notifications;
+mod text_annotation;
+mod hline_price;
+mod rectangle_style;
 
 // Réexporter les fonctions publiques pour compatibilité
 pub use main_view::view_main;
@@ -36,4 +42,8 @@ pub use settings::view_settings;
 pub use provider::view_provider_config;
 pub use downloads_view::view_downloads;
 pub use assets::view_assets;
+pub use text_annotation::TEXT_ANNOTATION_INPUT_ID;
+pub use hline_price::HLINE_PRICE_INPUT_ID;
+pub use rectangle_style::RECTANGLE_LABEL_INPUT_ID;
+pub use panels::DRAWING_NAME_INPUT_ID;
 
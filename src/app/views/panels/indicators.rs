@@ -1,17 +1,17 @@
-//! Panneaux d'indicateurs (Volume, RSI, MACD)
+//! Panneaux d'indicateurs (Volume, RSI, MACD, ATR, OBV, CCI, Williams %R)
 //!
 //! Ce module gère l'affichage des panneaux d'indicateurs sous le graphique principal.
 
 use iced::{Element, Length};
 use iced::widget::{row, column, mouse_area};
 use crate::app::{app_state::ChartApp, messages::Message};
-use crate::finance_chart::{volume_chart, rsi_chart, macd_chart, volume_y_axis, rsi_y_axis, macd_y_axis, scale::VolumeScale};
-use crate::app::ui::{volume_resize_handle, rsi_panel_resize_handle, macd_panel_resize_handle};
+use crate::finance_chart::{volume_chart, rsi_chart, macd_chart, atr_chart, obv_chart, cci_chart, williams_r_chart, volume_y_axis, rsi_y_axis, macd_y_axis, atr_y_axis, obv_y_axis, cci_y_axis, williams_r_y_axis, scale::VolumeScale};
+use crate::app::ui::{volume_resize_handle, rsi_panel_resize_handle, macd_panel_resize_handle, atr_panel_resize_handle, obv_panel_resize_handle, cci_panel_resize_handle, williams_r_panel_resize_handle};
 
 /// Construit et ajoute les panneaux d'indicateurs visibles au layout
 ///
 /// Cette fonction vérifie quels panneaux d'indicateurs sont visibles
-/// (Volume, RSI, MACD) et les ajoute à la liste des éléments de layout.
+/// (Volume, RSI, MACD, ATR, OBV, CCI, Williams %R) et les ajoute à la liste des éléments de layout.
 pub fn build_indicator_panels<'a>(app: &'a ChartApp, layout_items: &mut Vec<Element<'a, Message>>) {
     // Panneau Volume
     if app.ui.panels.volume.visible {
@@ -38,7 +38,12 @@ pub fn build_indicator_panels<'a>(app: &'a ChartApp, layout_items: &mut Vec<Elem
             }
         };
 
-        let volume_panel = volume_chart(&app.chart_state, volume_scale.clone());
+        let volume_panel = volume_chart(
+            &app.chart_state,
+            volume_scale.clone(),
+            &app.chart_style,
+            app.indicators.params.volume_ma_period,
+        );
         let volume_y_axis_panel = volume_y_axis(volume_scale);
         
         // Créer une row avec le graphique de volume + son axe Y
@@ -125,4 +130,131 @@ pub fn build_indicator_panels<'a>(app: &'a ChartApp, layout_items: &mut Vec<Elem
         
         layout_items.push(macd_panel_with_handle.into());
     }
+
+    // Panneau ATR
+    if app.ui.panels.atr.visible {
+        let handle_height = 6.0;
+        let chart_height = app.ui.panels.atr.size - handle_height;
+        let atr_period = app.indicators.params.atr_period;
+        
+        let atr_panel = atr_chart(&app.chart_state, atr_period);
+        let atr_y_axis_panel = atr_y_axis(&app.chart_state, atr_period, chart_height);
+        
+        // Créer une row avec le graphique ATR + son axe Y
+        let atr_chart_row = row![
+            atr_panel,
+            atr_y_axis_panel
+        ]
+        .width(Length::Fill)
+        .height(Length::Fixed(chart_height));
+        
+        // Ajouter le handle de redimensionnement en haut
+        let atr_panel_with_handle = mouse_area(
+            column![
+                atr_panel_resize_handle(handle_height, app.ui.panels.atr.is_resizing),
+                atr_chart_row
+            ]
+            .width(Length::Fill)
+            .height(Length::Fixed(app.ui.panels.atr.size))
+        )
+        .on_enter(Message::SetATRPanelFocus(true))
+        .on_exit(Message::SetATRPanelFocus(false));
+        
+        layout_items.push(atr_panel_with_handle.into());
+    }
+
+    // Panneau OBV
+    if app.ui.panels.obv.visible {
+        let handle_height = 6.0;
+        let chart_height = app.ui.panels.obv.size - handle_height;
+        
+        let obv_panel = obv_chart(&app.chart_state);
+        let obv_y_axis_panel = obv_y_axis(&app.chart_state, chart_height);
+        
+        // Créer une row avec le graphique OBV + son axe Y
+        let obv_chart_row = row![
+            obv_panel,
+            obv_y_axis_panel
+        ]
+        .width(Length::Fill)
+        .height(Length::Fixed(chart_height));
+        
+        // Ajouter le handle de redimensionnement en haut
+        let obv_panel_with_handle = mouse_area(
+            column![
+                obv_panel_resize_handle(handle_height, app.ui.panels.obv.is_resizing),
+                obv_chart_row
+            ]
+            .width(Length::Fill)
+            .height(Length::Fixed(app.ui.panels.obv.size))
+        )
+        .on_enter(Message::SetOBVPanelFocus(true))
+        .on_exit(Message::SetOBVPanelFocus(false));
+        
+        layout_items.push(obv_panel_with_handle.into());
+    }
+
+    // Panneau CCI
+    if app.ui.panels.cci.visible {
+        let handle_height = 6.0;
+        let chart_height = app.ui.panels.cci.size - handle_height;
+        let cci_period = app.indicators.params.cci_period;
+        
+        let cci_panel = cci_chart(&app.chart_state, cci_period);
+        let cci_y_axis_panel = cci_y_axis(&app.chart_state, cci_period, chart_height);
+        
+        // Créer une row avec le graphique CCI + son axe Y
+        let cci_chart_row = row![
+            cci_panel,
+            cci_y_axis_panel
+        ]
+        .width(Length::Fill)
+        .height(Length::Fixed(chart_height));
+        
+        // Ajouter le handle de redimensionnement en haut
+        let cci_panel_with_handle = mouse_area(
+            column![
+                cci_panel_resize_handle(handle_height, app.ui.panels.cci.is_resizing),
+                cci_chart_row
+            ]
+            .width(Length::Fill)
+            .height(Length::Fixed(app.ui.panels.cci.size))
+        )
+        .on_enter(Message::SetCCIPanelFocus(true))
+        .on_exit(Message::SetCCIPanelFocus(false));
+        
+        layout_items.push(cci_panel_with_handle.into());
+    }
+
+    // Panneau Williams %R
+    if app.ui.panels.williams_r.visible {
+        let handle_height = 6.0;
+        let chart_height = app.ui.panels.williams_r.size - handle_height;
+        let williams_r_period = app.indicators.params.williams_r_period;
+        
+        let williams_r_panel = williams_r_chart(&app.chart_state, williams_r_period);
+        let williams_r_y_axis_panel = williams_r_y_axis(&app.chart_state, chart_height);
+        
+        // Créer une row avec le graphique Williams %R + son axe Y
+        let williams_r_chart_row = row![
+            williams_r_panel,
+            williams_r_y_axis_panel
+        ]
+        .width(Length::Fill)
+        .height(Length::Fixed(chart_height));
+        
+        // Ajouter le handle de redimensionnement en haut
+        let williams_r_panel_with_handle = mouse_area(
+            column![
+                williams_r_panel_resize_handle(handle_height, app.ui.panels.williams_r.is_resizing),
+                williams_r_chart_row
+            ]
+            .width(Length::Fill)
+            .height(Length::Fixed(app.ui.panels.williams_r.size))
+        )
+        .on_enter(Message::SetWilliamsRPanelFocus(true))
+        .on_exit(Message::SetWilliamsRPanelFocus(false));
+        
+        layout_items.push(williams_r_panel_with_handle.into());
+    }
 }
\ No newline at end of file
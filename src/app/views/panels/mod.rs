@@ -9,6 +9,7 @@ mod sections;
 pub use indicators::build_indicator_panels;
 pub use right::{view_right_panel, section_context_menu};
 pub use bottom::view_bottom_panel;
+pub use sections::DRAWING_NAME_INPUT_ID;
 
 
 
@@ -3,6 +3,7 @@
 use iced::widget::{button, checkbox, column, container, pick_list, row, scrollable, text};
 use iced::{Element, Length, Color};
 use crate::app::{app_state::ChartApp, messages::Message, view_styles::colors};
+use crate::app::strategies::backtest::{BacktestReport, BacktestSide, ExitReason};
 
 /// Style pour les boutons primaires
 fn primary_button_style(_theme: &iced::Theme, _status: iced::widget::button::Status) -> button::Style {
@@ -41,6 +42,83 @@ fn format_timestamp(timestamp: i64) -> String {
     datetime.format("%d/%m/%Y %H:%M:%S").to_string()
 }
 
+/// Couleur selon le signe d'une valeur (vert si positive ou nulle)
+fn pnl_color(value: f64) -> Color {
+    if value >= 0.0 {
+        Color::from_rgb(0.2, 0.8, 0.3)
+    } else {
+        Color::from_rgb(0.8, 0.2, 0.2)
+    }
+}
+
+/// Ligne "libellé: valeur" du rapport
+fn report_row<'a>(label: &'a str, value: String, color: Color) -> Element<'a, Message> {
+    row![
+        text(label).size(12).color(colors::TEXT_SECONDARY),
+        text(value).size(12).color(color)
+    ]
+    .spacing(5)
+    .into()
+}
+
+/// Rapport du backtest complet (`Message::RunBacktest`)
+fn view_backtest_report(report: &BacktestReport) -> Element<'_, Message> {
+    let profit_factor = report.profit_factor
+        .map(|pf| format!("{:.2}", pf))
+        .unwrap_or_else(|| "∞".to_string());
+
+    let mut trades_column = column![].spacing(3);
+    for trade in report.trades.iter().rev().take(20) {
+        let (side_text, side_color) = match trade.side {
+            BacktestSide::Long => ("LONG", Color::from_rgb(0.2, 0.8, 0.3)),
+            BacktestSide::Short => ("SHORT", Color::from_rgb(0.8, 0.2, 0.2)),
+        };
+        let exit_text = match trade.exit_reason {
+            ExitReason::Signal => "signal",
+            ExitReason::TakeProfit => "TP",
+            ExitReason::StopLoss => "SL",
+            ExitReason::EndOfData => "fin",
+        };
+        trades_column = trades_column.push(
+            row![
+                text(side_text).size(11).color(side_color),
+                text(format!(
+                    " {:.4} · {} {:.2} → {} {:.2} ({})",
+                    trade.quantity,
+                    format_timestamp(trade.entry_time), trade.entry_price,
+                    format_timestamp(trade.exit_time), trade.exit_price,
+                    exit_text
+                ))
+                .size(11)
+                .color(colors::TEXT_SECONDARY),
+                text(format!(" P&L: {:.2}", trade.pnl)).size(11).color(pnl_color(trade.pnl))
+            ]
+            .spacing(5)
+        );
+    }
+
+    column![
+        text(format!("Rapport: {} sur {} ({})", report.strategy_name, report.symbol, report.interval))
+            .size(14)
+            .color(colors::TEXT_PRIMARY),
+        report_row("Bougies rejouées: ", report.candles_processed.to_string(), colors::TEXT_PRIMARY),
+        report_row(
+            "Capital final: ",
+            format!("{:.2} USDT", report.final_balance),
+            pnl_color(report.final_balance - report.initial_balance),
+        ),
+        report_row("Rendement total: ", format!("{:.2}%", report.total_return_pct), pnl_color(report.total_return_pct)),
+        report_row("Drawdown max: ", format!("{:.2}%", report.max_drawdown_pct), colors::TEXT_PRIMARY),
+        report_row("Taux de réussite: ", format!("{:.1}%", report.win_rate_pct), colors::TEXT_PRIMARY),
+        report_row("Profit factor: ", profit_factor, colors::TEXT_PRIMARY),
+        report_row("Trades: ", report.trades.len().to_string(), colors::TEXT_PRIMARY),
+        trades_column,
+    ]
+    .spacing(8)
+    .padding(10)
+    .into()
+}
+
 /// Vue pour la section "Backtest"
 pub fn view_backtest(app: &ChartApp) -> Element<'_, Message> {
     let backtest_state = &app.ui.backtest_state;
@@ -173,8 +251,30 @@ pub fn view_backtest(app: &ChartApp) -> Element<'_, Message> {
                 .style(secondary_button_style)
         );
         
+        // Backtest complet sur tout l'historique (rapport de performance)
+        if let Some(ref strategy_id) = backtest_state.selected_strategy_id {
+            controls_row = controls_row.push(
+                button("📊 Rapport complet")
+                    .on_press(Message::RunBacktest(strategy_id.clone()))
+                    .style(primary_button_style)
+            );
+            let previewing = backtest_state
+                .signal_preview
+                .as_ref()
+                .is_some_and(|p| &p.strategy_id == strategy_id);
+            controls_row = controls_row.push(
+                button(if previewing { "🔎 Masquer les signaux" } else { "🔎 Aperçu des signaux" })
+                    .on_press(Message::PreviewStrategySignals(strategy_id.clone()))
+                    .style(secondary_button_style)
+            );
+        }
+        
         content = content.push(controls_row);
         
+        if let Some(ref report) = backtest_state.last_report {
+            content = content.push(view_backtest_report(report));
+        }
+        
         // État de la lecture
         if is_playing {
             if let Some(timestamp) = backtest_state.current_candle_timestamp() {
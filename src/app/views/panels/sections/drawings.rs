@@ -0,0 +1,232 @@
+//! Section "Dessins"
+
+use iced::widget::{button, column, container, row, scrollable, text, text_input, Space};
+use iced::{Color, Element, Length};
+use crate::app::{
+    app_state::ChartApp,
+    messages::Message,
+    view_styles::{self, colors},
+};
+use crate::finance_chart::settings::format_price;
+use crate::finance_chart::tools::{DrawingKind, DrawingRenameState};
+use crate::app::views::hline_price::LINE_COLORS;
+
+/// Formate un timestamp pour la liste des dessins
+fn format_date(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| String::from("N/A"))
+}
+
+/// Identifiant du champ de renommage (utilisé pour lui donner le focus)
+pub const DRAWING_NAME_INPUT_ID: &str = "drawing-name-input";
+
+/// Dessin affiché sur une ligne de la liste
+struct DrawingEntry<'a> {
+    kind: DrawingKind,
+    index: usize,
+    name: Option<&'a str>,
+    description: String,
+    color: Color,
+    locked: bool,
+    hidden: bool,
+}
+
+/// Carré de couleur (indicateur ou bouton de la palette)
+fn swatch<'a>(color: Color, highlighted: bool) -> Element<'a, Message> {
+    container(text(""))
+        .width(Length::Fixed(12.0))
+        .height(Length::Fixed(12.0))
+        .style(move |_theme| container::Style {
+            background: Some(iced::Background::Color(Color { a: 1.0, ..color })),
+            border: iced::Border {
+                color: if highlighted { Color::WHITE } else { colors::SEPARATOR },
+                width: 1.0,
+                radius: 2.0.into(),
+            },
+            ..Default::default()
+        })
+        .into()
+}
+
+/// Ligne d'un dessin : type, nom, couleur, renommage, verrouillage, visibilité, suppression
+///
+/// Un clic sur le nom sélectionne le dessin sur le graphique ; la ligne du dessin
+/// sélectionné (depuis la liste ou le graphique) est surlignée et propose la palette.
+fn drawing_row<'a>(entry: DrawingEntry<'a>, selected: bool, rename: Option<&'a DrawingRenameState>) -> Element<'a, Message> {
+    let DrawingEntry { kind, index, name, description, color, locked, hidden } = entry;
+    let text_color = if hidden { colors::TEXT_SECONDARY } else { colors::TEXT_PRIMARY };
+
+    let title: Element<'a, Message> = match rename.filter(|rename| rename.kind == kind && rename.index == index) {
+        Some(rename) => text_input("Nom du dessin", &rename.content)
+            .id(DRAWING_NAME_INPUT_ID)
+            .on_input(Message::UpdateDrawingNameDraft)
+            .on_submit(Message::CommitDrawingName)
+            .size(12)
+            .padding(2)
+            .width(Length::Fixed(220.0))
+            .into(),
+        None => {
+            let label = match name {
+                Some(name) => format!("{} — {}", name, description),
+                None => description,
+            };
+            button(text(label).size(12).color(text_color))
+                .on_press(Message::SelectDrawing { kind, index })
+                .padding(0)
+                .style(view_styles::transparent_button_style)
+                .into()
+        }
+    };
+
+    let mut line = row![
+        text(kind.label()).size(12).color(colors::TEXT_SECONDARY).width(Length::Fixed(120.0)),
+        swatch(color, false),
+        title,
+        Space::new().width(Length::Fill),
+    ]
+    .spacing(6)
+    .align_y(iced::Alignment::Center);
+
+    // Palette de couleurs pour le dessin sélectionné
+    if selected {
+        for preset in LINE_COLORS {
+            let is_current = (color.r, color.g, color.b) == (preset.r, preset.g, preset.b);
+            line = line.push(
+                button(swatch(preset, is_current))
+                    .on_press(Message::SetDrawingColor { kind, index, color: preset })
+                    .padding(0)
+                    .style(view_styles::transparent_button_style),
+            );
+        }
+    }
+
+    let rename_message = if rename.is_some_and(|rename| rename.kind == kind && rename.index == index) {
+        Message::CancelDrawingRename
+    } else {
+        Message::StartRenameDrawing { kind, index }
+    };
+    line = line
+        .push(
+            button(text("✏").size(12))
+                .on_press(rename_message)
+                .style(view_styles::icon_button_style),
+        )
+        .push(
+            button(text(if locked { "🔒" } else { "🔓" }).size(12))
+                .on_press(Message::ToggleDrawingLocked { kind, index })
+                .style(view_styles::icon_button_style),
+        )
+        .push(
+            button(text(if hidden { "🚫" } else { "👁" }).size(12))
+                .on_press(Message::ToggleDrawingHidden { kind, index })
+                .style(view_styles::icon_button_style),
+        )
+        .push(
+            button(text("🗑").size(12))
+                .on_press(Message::DeleteDrawing { kind, index })
+                .style(view_styles::icon_button_style),
+        );
+
+    container(line)
+        .padding([2, 4])
+        .style(move |_theme| container::Style {
+            background: selected.then_some(iced::Background::Color(colors::ACCENT_HOVER)),
+            border: iced::Border {
+                color: if selected { colors::BORDER_ACTIVE } else { Color::TRANSPARENT },
+                width: 1.0,
+                radius: 3.0.into(),
+            },
+            ..Default::default()
+        })
+        .into()
+}
+
+/// Vue pour la section "Dessins"
+pub fn view_drawings(app: &ChartApp) -> Element<'_, Message> {
+    let tools = &app.tools_state;
+    let style = &app.chart_style;
+
+    let selected = tools.selected_drawing();
+    let rename = tools.drawing_rename.as_ref();
+
+    let mut entries = Vec::new();
+    for (index, rect) in tools.rectangles.iter().enumerate() {
+        let description = format!(
+            "{} → {} ({} – {})",
+            format_date(rect.start_time.min(rect.end_time)),
+            format_date(rect.start_time.max(rect.end_time)),
+            format_price(rect.start_price.min(rect.end_price), style),
+            format_price(rect.start_price.max(rect.end_price), style),
+        );
+        entries.push(DrawingEntry {
+            kind: DrawingKind::Rectangle, index, name: rect.name.as_deref(), description,
+            color: rect.color, locked: rect.locked, hidden: rect.hidden,
+        });
+    }
+    for (index, line) in tools.horizontal_lines.iter().enumerate() {
+        entries.push(DrawingEntry {
+            kind: DrawingKind::HorizontalLine, index, name: line.name.as_deref(),
+            description: format_price(line.price, style),
+            color: line.color, locked: line.locked, hidden: line.hidden,
+        });
+    }
+    for (index, line) in tools.vertical_lines.iter().enumerate() {
+        let description = match &line.label {
+            Some(label) => format!("{} « {} »", format_date(line.time), label),
+            None => format_date(line.time),
+        };
+        entries.push(DrawingEntry {
+            kind: DrawingKind::VerticalLine, index, name: line.name.as_deref(), description,
+            color: line.color, locked: line.locked, hidden: line.hidden,
+        });
+    }
+    for (index, line) in tools.trend_lines.iter().enumerate() {
+        let description = format!(
+            "{} → {}",
+            format_price(line.start_price, style),
+            format_price(line.end_price, style),
+        );
+        entries.push(DrawingEntry {
+            kind: DrawingKind::TrendLine, index, name: line.name.as_deref(), description,
+            color: line.color, locked: line.locked, hidden: line.hidden,
+        });
+    }
+    for (index, annotation) in tools.texts.iter().enumerate() {
+        entries.push(DrawingEntry {
+            kind: DrawingKind::Text, index, name: annotation.name.as_deref(),
+            description: format!("« {} »", annotation.content),
+            color: annotation.color, locked: annotation.locked, hidden: annotation.hidden,
+        });
+    }
+
+    let count = entries.len();
+    let mut rows = column![].spacing(4);
+    for entry in entries {
+        let is_selected = selected == Some((entry.kind, entry.index));
+        rows = rows.push(drawing_row(entry, is_selected, rename));
+    }
+
+    let content: Element<'_, Message> = if count == 0 {
+        text("Aucun dessin sur le graphique").size(13).color(colors::TEXT_SECONDARY).into()
+    } else {
+        scrollable(rows).height(Length::Fill).into()
+    };
+
+    container(
+        column![
+            row![
+                text("Dessins").size(20).color(colors::TEXT_PRIMARY),
+                Space::new().width(Length::Fill),
+                text(count.to_string()).size(14).color(colors::TEXT_SECONDARY),
+            ]
+            .spacing(10),
+            content,
+        ]
+        .spacing(10)
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .padding(15)
+    .into()
+}
@@ -0,0 +1,138 @@
+//! Section "Equity"
+
+use iced::widget::canvas::{Canvas, Frame, Geometry, Path, Program, Stroke, Text};
+use iced::widget::{column, container, row, text, Space};
+use iced::{Color, Element, Length, Point, Rectangle};
+use iced::mouse::Cursor;
+use crate::app::{app_state::ChartApp, messages::Message, view_styles::colors};
+
+/// Marge intérieure du graphique en pixels
+const PADDING: f32 = 8.0;
+
+/// Programme canvas dessinant la courbe d'equity en ligne
+struct EquityCurveProgram {
+    points: Vec<(i64, f64)>,
+    /// Solde de départ, dessiné comme ligne de référence
+    starting_balance: f64,
+}
+
+impl<Message> Program<Message> for EquityCurveProgram {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let width = bounds.width - PADDING * 2.0;
+        let height = bounds.height - PADDING * 2.0;
+
+        let (Some(first), Some(last)) = (self.points.first(), self.points.last()) else {
+            return vec![frame.into_geometry()];
+        };
+        if width <= 0.0 || height <= 0.0 {
+            return vec![frame.into_geometry()];
+        }
+
+        let (min, max) = self.points.iter().fold((self.starting_balance, self.starting_balance), |(lo, hi), &(_, e)| {
+            (lo.min(e), hi.max(e))
+        });
+        let time_span = (last.0 - first.0).max(1) as f32;
+        let value_span = (max - min).max(f64::EPSILON);
+
+        let to_point = |time: i64, equity: f64| Point::new(
+            PADDING + (time - first.0) as f32 / time_span * width,
+            PADDING + ((max - equity) / value_span) as f32 * height,
+        );
+
+        // Ligne de référence au solde initial
+        let base_y = to_point(first.0, self.starting_balance).y;
+        let baseline = Path::line(Point::new(PADDING, base_y), Point::new(PADDING + width, base_y));
+        frame.stroke(&baseline, Stroke::default()
+            .with_color(Color::from_rgba(0.6, 0.6, 0.6, 0.5))
+            .with_width(1.0));
+
+        let curve = Path::new(|builder| {
+            builder.move_to(to_point(first.0, first.1));
+            for &(time, equity) in &self.points[1..] {
+                builder.line_to(to_point(time, equity));
+            }
+        });
+        let color = if last.1 >= self.starting_balance {
+            Color::from_rgb(0.2, 0.8, 0.3)
+        } else {
+            Color::from_rgb(0.8, 0.2, 0.2)
+        };
+        frame.stroke(&curve, Stroke::default().with_color(color).with_width(1.5));
+
+        for (value, y) in [(max, PADDING), (min, PADDING + height - 12.0)] {
+            frame.fill_text(Text {
+                content: format!("{:.2}", value),
+                position: Point::new(PADDING + 2.0, y),
+                color: colors::TEXT_SECONDARY,
+                size: iced::Pixels(11.0),
+                ..Text::default()
+            });
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+/// Vue pour la section "Equity"
+pub fn view_equity(app: &ChartApp) -> Element<'_, Message> {
+    let starting_balance = app.trading_state.starting_balance;
+    let points = app.chart_state.series_manager
+        .active_series()
+        .next()
+        .map(|s| app.trading_state.trade_history.equity_curve(&s.symbol, s.data.all_candles(), starting_balance))
+        .unwrap_or_default();
+
+    let summary = match points.last() {
+        Some(&(_, equity)) => {
+            let pnl = equity - starting_balance;
+            let color = if pnl >= 0.0 {
+                Color::from_rgb(0.2, 0.8, 0.3)
+            } else {
+                Color::from_rgb(0.8, 0.2, 0.2)
+            };
+            text(format!("{:.2} USDT ({:+.2})", equity, pnl)).size(14).color(color)
+        }
+        None => text("Aucune donnée").size(14).color(colors::TEXT_SECONDARY),
+    };
+
+    let header = row![
+        text("Courbe d'equity").size(20).color(colors::TEXT_PRIMARY),
+        Space::new().width(Length::Fill),
+        summary,
+    ]
+    .spacing(10);
+
+    let chart = container(
+        Canvas::new(EquityCurveProgram { points, starting_balance })
+            .width(Length::Fill)
+            .height(Length::Fill)
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .style(|_theme: &iced::Theme| container::Style {
+        background: Some(iced::Background::Color(Color::from_rgb(0.12, 0.12, 0.15))),
+        border: iced::Border {
+            color: Color::from_rgb(0.2, 0.2, 0.25),
+            width: 1.0,
+            radius: 6.0.into(),
+        },
+        ..Default::default()
+    });
+
+    column![header, chart]
+        .spacing(10)
+        .padding(10)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+}
@@ -18,8 +18,19 @@ pub fn view_indicators(app: &ChartApp) -> Element<'_, Message> {
     let has_active_indicators = app.ui.panels.volume.visible
         || app.ui.panels.rsi.visible
         || app.ui.panels.macd.visible
+        || app.ui.panels.atr.visible
+        || app.ui.panels.obv.visible
+        || app.ui.panels.cci.visible
+        || app.ui.panels.williams_r.visible
         || app.indicators.bollinger_bands_enabled
-        || app.indicators.moving_average_enabled;
+        || app.indicators.moving_average_enabled
+        || app.indicators.vwap_enabled
+        || app.indicators.psar_enabled
+        || app.indicators.donchian_enabled
+        || app.indicators.keltner_enabled
+        || app.indicators.volume_profile_enabled
+        || app.indicators.pivots_enabled
+        || app.indicators.crossover_markers_enabled;
     
     if has_active_indicators {
         // Titre de la section
@@ -29,11 +40,15 @@ pub fn view_indicators(app: &ChartApp) -> Element<'_, Message> {
                 .color(colors::TEXT_SECONDARY)
         );
         
-        // Volume Profile
+        // Volume
         if app.ui.panels.volume.visible {
-            let indicator_row = container(
+            let volume_ma_str = app.indicators.params.volume_ma_period
+                .map(|p| p.to_string())
+                .unwrap_or_default();
+            let indicator_content = column![
+                // Header avec nom et bouton supprimer
                 row![
-                    text("Volume Profile")
+                    text("Volume")
                         .size(12)
                         .color(colors::TEXT_PRIMARY),
                     Space::new().width(Length::Fill),
@@ -63,20 +78,50 @@ pub fn view_indicators(app: &ChartApp) -> Element<'_, Message> {
                     .on_press(Message::ToggleVolumePanel)
                 ]
                 .spacing(8)
-                .align_y(iced::Alignment::Center)
+                .align_y(iced::Alignment::Center),
+                // Paramètres
+                column![
+                    row![
+                        text("Moyenne (période):")
+                            .size(11)
+                            .color(colors::TEXT_SECONDARY),
+                        Space::new().width(Length::Fill),
+                        text_input("Aucune", &volume_ma_str)
+                            .on_input(|s| {
+                                if s.trim().is_empty() {
+                                    return Message::UpdateVolumeMaPeriod(None);
+                                }
+                                s.parse::<usize>()
+                                    .ok()
+                                    .filter(|&v| v > 0 && v <= 200)
+                                    .map(|v| Message::UpdateVolumeMaPeriod(Some(v)))
+                                    .unwrap_or(Message::ClearPanelFocus)
+                            })
+                            .padding(4)
+                            .width(Length::Fixed(60.0))
+                            .size(11)
+                    ]
+                    .spacing(8)
+                    .align_y(iced::Alignment::Center)
+                ]
+                .spacing(4)
+                .padding([8.0, 10.0])
+            ]
+            .spacing(4);
+            
+            let indicator_row = container(indicator_content)
                 .padding([6, 10])
-            )
-            .style(|_theme| {
-                container::Style {
-                    background: Some(iced::Background::Color(colors::BACKGROUND_MEDIUM)),
-                    border: iced::Border {
-                        color: colors::BORDER_STANDARD,
-                        width: 1.0,
-                        radius: 4.0.into(),
-                    },
-                    ..Default::default()
-                }
-            });
+                .style(|_theme| {
+                    container::Style {
+                        background: Some(iced::Background::Color(colors::BACKGROUND_MEDIUM)),
+                        border: iced::Border {
+                            color: colors::BORDER_STANDARD,
+                            width: 1.0,
+                            radius: 4.0.into(),
+                        },
+                        ..Default::default()
+                    }
+                });
             
             active_indicators = active_indicators.push(indicator_row);
         }
@@ -304,14 +349,13 @@ pub fn view_indicators(app: &ChartApp) -> Element<'_, Message> {
             active_indicators = active_indicators.push(indicator_row);
         }
         
-        // Bollinger Bands
-        if app.indicators.bollinger_bands_enabled {
-            let bb_period_str = app.indicators.params.bollinger_period.to_string();
-            let bb_std_dev_str = format!("{:.1}", app.indicators.params.bollinger_std_dev);
+        // ATR
+        if app.ui.panels.atr.visible {
+            let atr_period_str = app.indicators.params.atr_period.to_string();
             let indicator_content = column![
                 // Header avec nom et bouton supprimer
                 row![
-                    text("Bollinger Bands")
+                    text("ATR")
                         .size(12)
                         .color(colors::TEXT_PRIMARY),
                     Space::new().width(Length::Fill),
@@ -338,7 +382,7 @@ pub fn view_indicators(app: &ChartApp) -> Element<'_, Message> {
                             ..Default::default()
                         }
                     })
-                    .on_press(Message::ToggleBollingerBands)
+                    .on_press(Message::ToggleATRPanel)
                 ]
                 .spacing(8)
                 .align_y(iced::Alignment::Center),
@@ -349,12 +393,12 @@ pub fn view_indicators(app: &ChartApp) -> Element<'_, Message> {
                             .size(11)
                             .color(colors::TEXT_SECONDARY),
                         Space::new().width(Length::Fill),
-                        text_input("20", &bb_period_str)
+                        text_input("14", &atr_period_str)
                             .on_input(|s| {
                                 s.parse::<usize>()
                                     .ok()
                                     .filter(|&v| v > 0 && v <= 200)
-                                    .map(Message::UpdateBollingerPeriod)
+                                    .map(Message::UpdateATRPeriod)
                                     .unwrap_or(Message::ClearPanelFocus)
                             })
                             .padding(4)
@@ -362,18 +406,132 @@ pub fn view_indicators(app: &ChartApp) -> Element<'_, Message> {
                             .size(11)
                     ]
                     .spacing(8)
-                    .align_y(iced::Alignment::Center),
+                    .align_y(iced::Alignment::Center)
+                ]
+                .spacing(4)
+                .padding([8.0, 10.0])
+            ]
+            .spacing(4);
+            
+            let indicator_row = container(indicator_content)
+                .padding([6, 10])
+                .style(|_theme| {
+                    container::Style {
+                        background: Some(iced::Background::Color(colors::BACKGROUND_MEDIUM)),
+                        border: iced::Border {
+                            color: colors::BORDER_STANDARD,
+                            width: 1.0,
+                            radius: 4.0.into(),
+                        },
+                        ..Default::default()
+                    }
+                });
+            
+            active_indicators = active_indicators.push(indicator_row);
+        }
+        
+        // OBV
+        if app.ui.panels.obv.visible {
+            let indicator_row = container(
+                row![
+                    text("OBV")
+                        .size(12)
+                        .color(colors::TEXT_PRIMARY),
+                    Space::new().width(Length::Fill),
+                    button(
+                        text("×")
+                            .size(16)
+                            .color(colors::TEXT_PRIMARY)
+                    )
+                    .padding([4, 8])
+                    .style(|_theme, status| {
+                        let background = match status {
+                            iced::widget::button::Status::Pressed => colors::DANGER,
+                            iced::widget::button::Status::Hovered => Color::from_rgb(0.6, 0.2, 0.2),
+                            _ => Color::from_rgb(0.4, 0.15, 0.15),
+                        };
+                        button::Style {
+                            background: Some(iced::Background::Color(background)),
+                            border: iced::Border {
+                                color: colors::BORDER_STANDARD,
+                                width: 1.0,
+                                radius: 2.0.into(),
+                            },
+                            text_color: colors::TEXT_PRIMARY,
+                            ..Default::default()
+                        }
+                    })
+                    .on_press(Message::ToggleOBVPanel)
+                ]
+                .spacing(8)
+                .align_y(iced::Alignment::Center)
+                .padding([6, 10])
+            )
+            .style(|_theme| {
+                container::Style {
+                    background: Some(iced::Background::Color(colors::BACKGROUND_MEDIUM)),
+                    border: iced::Border {
+                        color: colors::BORDER_STANDARD,
+                        width: 1.0,
+                        radius: 4.0.into(),
+                    },
+                    ..Default::default()
+                }
+            });
+            
+            active_indicators = active_indicators.push(indicator_row);
+        }
+        
+        // CCI
+        if app.ui.panels.cci.visible {
+            let cci_period_str = app.indicators.params.cci_period.to_string();
+            let indicator_content = column![
+                // Header avec nom et bouton supprimer
+                row![
+                    text("CCI")
+                        .size(12)
+                        .color(colors::TEXT_PRIMARY),
+                    Space::new().width(Length::Fill),
+                    button(
+                        text("×")
+                            .size(16)
+                            .color(colors::TEXT_PRIMARY)
+                    )
+                    .padding([4, 8])
+                    .style(|_theme, status| {
+                        let background = match status {
+                            iced::widget::button::Status::Pressed => colors::DANGER,
+                            iced::widget::button::Status::Hovered => Color::from_rgb(0.6, 0.2, 0.2),
+                            _ => Color::from_rgb(0.4, 0.15, 0.15),
+                        };
+                        button::Style {
+                            background: Some(iced::Background::Color(background)),
+                            border: iced::Border {
+                                color: colors::BORDER_STANDARD,
+                                width: 1.0,
+                                radius: 2.0.into(),
+                            },
+                            text_color: colors::TEXT_PRIMARY,
+                            ..Default::default()
+                        }
+                    })
+                    .on_press(Message::ToggleCCIPanel)
+                ]
+                .spacing(8)
+                .align_y(iced::Alignment::Center),
+                // Paramètres
+                column![
                     row![
-                        text("Écart-type:")
+                        text("Période:")
                             .size(11)
                             .color(colors::TEXT_SECONDARY),
                         Space::new().width(Length::Fill),
-                        text_input("2.0", &bb_std_dev_str)
+                        text_input("20", &cci_period_str)
                             .on_input(|s| {
-                                s.parse::<f64>()
+                                s.parse::<usize>()
                                     .ok()
-                                    .filter(|&v| v > 0.0 && v <= 10.0)
-                                    .map(Message::UpdateBollingerStdDev)
+                                    .filter(|&v| v > 0 && v <= 200)
+                                    .map(Message::UpdateCCIPeriod)
                                     .unwrap_or(Message::ClearPanelFocus)
                             })
                             .padding(4)
@@ -405,13 +563,13 @@ pub fn view_indicators(app: &ChartApp) -> Element<'_, Message> {
             active_indicators = active_indicators.push(indicator_row);
         }
         
-        // Moving Average
-        if app.indicators.moving_average_enabled {
-            let ma_period_str = app.indicators.params.ma_period.to_string();
+        // Williams %R
+        if app.ui.panels.williams_r.visible {
+            let williams_r_period_str = app.indicators.params.williams_r_period.to_string();
             let indicator_content = column![
                 // Header avec nom et bouton supprimer
                 row![
-                    text("Moving Average")
+                    text("Williams %R")
                         .size(12)
                         .color(colors::TEXT_PRIMARY),
                     Space::new().width(Length::Fill),
@@ -438,7 +596,7 @@ pub fn view_indicators(app: &ChartApp) -> Element<'_, Message> {
                             ..Default::default()
                         }
                     })
-                    .on_press(Message::ToggleMovingAverage)
+                    .on_press(Message::ToggleWilliamsRPanel)
                 ]
                 .spacing(8)
                 .align_y(iced::Alignment::Center),
@@ -449,12 +607,811 @@ pub fn view_indicators(app: &ChartApp) -> Element<'_, Message> {
                             .size(11)
                             .color(colors::TEXT_SECONDARY),
                         Space::new().width(Length::Fill),
-                        text_input("20", &ma_period_str)
+                        text_input("14", &williams_r_period_str)
                             .on_input(|s| {
                                 s.parse::<usize>()
                                     .ok()
                                     .filter(|&v| v > 0 && v <= 200)
-                                    .map(Message::UpdateMAPeriod)
+                                    .map(Message::UpdateWilliamsRPeriod)
+                                    .unwrap_or(Message::ClearPanelFocus)
+                            })
+                            .padding(4)
+                            .width(Length::Fixed(60.0))
+                            .size(11)
+                    ]
+                    .spacing(8)
+                    .align_y(iced::Alignment::Center)
+                ]
+                .spacing(4)
+                .padding([8.0, 10.0])
+            ]
+            .spacing(4);
+            
+            let indicator_row = container(indicator_content)
+                .padding([6, 10])
+                .style(|_theme| {
+                    container::Style {
+                        background: Some(iced::Background::Color(colors::BACKGROUND_MEDIUM)),
+                        border: iced::Border {
+                            color: colors::BORDER_STANDARD,
+                            width: 1.0,
+                            radius: 4.0.into(),
+                        },
+                        ..Default::default()
+                    }
+                });
+            
+            active_indicators = active_indicators.push(indicator_row);
+        }
+        
+        // Bollinger Bands
+        if app.indicators.bollinger_bands_enabled {
+            let bb_period_str = app.indicators.params.bollinger_period.to_string();
+            let bb_std_dev_str = format!("{:.1}", app.indicators.params.bollinger_std_dev);
+            let indicator_content = column![
+                // Header avec nom et bouton supprimer
+                row![
+                    text("Bollinger Bands")
+                        .size(12)
+                        .color(colors::TEXT_PRIMARY),
+                    Space::new().width(Length::Fill),
+                    button(
+                        text("×")
+                            .size(16)
+                            .color(colors::TEXT_PRIMARY)
+                    )
+                    .padding([4, 8])
+                    .style(|_theme, status| {
+                        let background = match status {
+                            iced::widget::button::Status::Pressed => colors::DANGER,
+                            iced::widget::button::Status::Hovered => Color::from_rgb(0.6, 0.2, 0.2),
+                            _ => Color::from_rgb(0.4, 0.15, 0.15),
+                        };
+                        button::Style {
+                            background: Some(iced::Background::Color(background)),
+                            border: iced::Border {
+                                color: colors::BORDER_STANDARD,
+                                width: 1.0,
+                                radius: 2.0.into(),
+                            },
+                            text_color: colors::TEXT_PRIMARY,
+                            ..Default::default()
+                        }
+                    })
+                    .on_press(Message::ToggleBollingerBands)
+                ]
+                .spacing(8)
+                .align_y(iced::Alignment::Center),
+                // Paramètres
+                column![
+                    row![
+                        text("Période:")
+                            .size(11)
+                            .color(colors::TEXT_SECONDARY),
+                        Space::new().width(Length::Fill),
+                        text_input("20", &bb_period_str)
+                            .on_input(|s| {
+                                s.parse::<usize>()
+                                    .ok()
+                                    .filter(|&v| v > 0 && v <= 200)
+                                    .map(Message::UpdateBollingerPeriod)
+                                    .unwrap_or(Message::ClearPanelFocus)
+                            })
+                            .padding(4)
+                            .width(Length::Fixed(60.0))
+                            .size(11)
+                    ]
+                    .spacing(8)
+                    .align_y(iced::Alignment::Center),
+                    row![
+                        text("Écart-type:")
+                            .size(11)
+                            .color(colors::TEXT_SECONDARY),
+                        Space::new().width(Length::Fill),
+                        text_input("2.0", &bb_std_dev_str)
+                            .on_input(|s| {
+                                s.parse::<f64>()
+                                    .ok()
+                                    .filter(|&v| v > 0.0 && v <= 10.0)
+                                    .map(Message::UpdateBollingerStdDev)
+                                    .unwrap_or(Message::ClearPanelFocus)
+                            })
+                            .padding(4)
+                            .width(Length::Fixed(60.0))
+                            .size(11)
+                    ]
+                    .spacing(8)
+                    .align_y(iced::Alignment::Center)
+                ]
+                .spacing(4)
+                .padding([8.0, 10.0])
+            ]
+            .spacing(4);
+            
+            let indicator_row = container(indicator_content)
+                .padding([6, 10])
+                .style(|_theme| {
+                    container::Style {
+                        background: Some(iced::Background::Color(colors::BACKGROUND_MEDIUM)),
+                        border: iced::Border {
+                            color: colors::BORDER_STANDARD,
+                            width: 1.0,
+                            radius: 4.0.into(),
+                        },
+                        ..Default::default()
+                    }
+                });
+            
+            active_indicators = active_indicators.push(indicator_row);
+        }
+        
+        // Moving Average
+        if app.indicators.moving_average_enabled {
+            let mut ma_rows = column![].spacing(4).padding([8.0, 10.0]);
+            for (index, config) in app.indicators.params.moving_averages.iter().enumerate() {
+                let swatch_color = config.color.to_iced();
+                let ma_row = row![
+                    // Pastille de couleur : un clic passe à la couleur suivante
+                    button(Space::new().width(Length::Fixed(12.0)).height(Length::Fixed(12.0)))
+                        .padding(0)
+                        .style(move |_theme, _status| button::Style {
+                            background: Some(iced::Background::Color(swatch_color)),
+                            border: iced::Border {
+                                color: colors::BORDER_STANDARD,
+                                width: 1.0,
+                                radius: 2.0.into(),
+                            },
+                            ..Default::default()
+                        })
+                        .on_press(Message::CycleMAColor(index)),
+                    pick_list(
+                        vec!["SMA".to_string(), "EMA".to_string(), "WMA".to_string()],
+                        Some(config.ma_type.as_str().to_string()),
+                        move |selected: String| {
+                            if let Some(ma_type) = crate::app::state::MaType::from_str(&selected) {
+                                Message::UpdateMAType(index, ma_type)
+                            } else {
+                                Message::ClearPanelFocus
+                            }
+                        }
+                    )
+                    .width(Length::Fixed(70.0))
+                    .text_size(11.0),
+                    Space::new().width(Length::Fill),
+                    text_input("20", &config.period.to_string())
+                        .on_input(move |s| {
+                            s.parse::<usize>()
+                                .ok()
+                                .filter(|&v| v > 0 && v <= 500)
+                                .map(|period| Message::UpdateMAPeriod(index, period))
+                                .unwrap_or(Message::ClearPanelFocus)
+                        })
+                        .padding(4)
+                        .width(Length::Fixed(60.0))
+                        .size(11),
+                    button(text("−").size(12).color(colors::TEXT_PRIMARY))
+                        .padding([2, 6])
+                        .on_press(Message::RemoveMovingAverage(index))
+                ]
+                .spacing(6)
+                .align_y(iced::Alignment::Center);
+                ma_rows = ma_rows.push(ma_row);
+            }
+            if app.indicators.params.moving_averages.len() < crate::app::state::MAX_MOVING_AVERAGES {
+                ma_rows = ma_rows.push(
+                    button(text("+ Ajouter une moyenne").size(11))
+                        .padding([4, 8])
+                        .on_press(Message::AddMovingAverage)
+                );
+            }
+            
+            let indicator_content = column![
+                // Header avec nom et bouton supprimer
+                row![
+                    text("Moving Average")
+                        .size(12)
+                        .color(colors::TEXT_PRIMARY),
+                    Space::new().width(Length::Fill),
+                    button(
+                        text("×")
+                            .size(16)
+                            .color(colors::TEXT_PRIMARY)
+                    )
+                    .padding([4, 8])
+                    .style(|_theme, status| {
+                        let background = match status {
+                            iced::widget::button::Status::Pressed => colors::DANGER,
+                            iced::widget::button::Status::Hovered => Color::from_rgb(0.6, 0.2, 0.2),
+                            _ => Color::from_rgb(0.4, 0.15, 0.15),
+                        };
+                        button::Style {
+                            background: Some(iced::Background::Color(background)),
+                            border: iced::Border {
+                                color: colors::BORDER_STANDARD,
+                                width: 1.0,
+                                radius: 2.0.into(),
+                            },
+                            text_color: colors::TEXT_PRIMARY,
+                            ..Default::default()
+                        }
+                    })
+                    .on_press(Message::ToggleMovingAverage)
+                ]
+                .spacing(8)
+                .align_y(iced::Alignment::Center),
+                // Paramètres : une ligne par moyenne mobile
+                ma_rows
+            ]
+            .spacing(4);
+            
+            let indicator_row = container(indicator_content)
+                .padding([6, 10])
+                .style(|_theme| {
+                    container::Style {
+                        background: Some(iced::Background::Color(colors::BACKGROUND_MEDIUM)),
+                        border: iced::Border {
+                            color: colors::BORDER_STANDARD,
+                            width: 1.0,
+                            radius: 4.0.into(),
+                        },
+                        ..Default::default()
+                    }
+                });
+            
+            active_indicators = active_indicators.push(indicator_row);
+        }
+        
+        // VWAP
+        if app.indicators.vwap_enabled {
+            let indicator_row = container(
+                row![
+                    text("VWAP")
+                        .size(12)
+                        .color(colors::TEXT_PRIMARY),
+                    Space::new().width(Length::Fill),
+                    button(
+                        text("×")
+                            .size(16)
+                            .color(colors::TEXT_PRIMARY)
+                    )
+                    .padding([4, 8])
+                    .style(|_theme, status| {
+                        let background = match status {
+                            iced::widget::button::Status::Pressed => colors::DANGER,
+                            iced::widget::button::Status::Hovered => Color::from_rgb(0.6, 0.2, 0.2),
+                            _ => Color::from_rgb(0.4, 0.15, 0.15),
+                        };
+                        button::Style {
+                            background: Some(iced::Background::Color(background)),
+                            border: iced::Border {
+                                color: colors::BORDER_STANDARD,
+                                width: 1.0,
+                                radius: 2.0.into(),
+                            },
+                            text_color: colors::TEXT_PRIMARY,
+                            ..Default::default()
+                        }
+                    })
+                    .on_press(Message::ToggleVWAP)
+                ]
+                .spacing(8)
+                .align_y(iced::Alignment::Center)
+                .padding([6, 10])
+            )
+            .style(|_theme| {
+                container::Style {
+                    background: Some(iced::Background::Color(colors::BACKGROUND_MEDIUM)),
+                    border: iced::Border {
+                        color: colors::BORDER_STANDARD,
+                        width: 1.0,
+                        radius: 4.0.into(),
+                    },
+                    ..Default::default()
+                }
+            });
+            
+            active_indicators = active_indicators.push(indicator_row);
+        }
+        
+        // Parabolic SAR
+        if app.indicators.psar_enabled {
+            let psar_step_str = format!("{:.2}", app.indicators.params.psar_step);
+            let psar_max_step_str = format!("{:.2}", app.indicators.params.psar_max_step);
+            let indicator_content = column![
+                // Header avec nom et bouton supprimer
+                row![
+                    text("Parabolic SAR")
+                        .size(12)
+                        .color(colors::TEXT_PRIMARY),
+                    Space::new().width(Length::Fill),
+                    button(
+                        text("×")
+                            .size(16)
+                            .color(colors::TEXT_PRIMARY)
+                    )
+                    .padding([4, 8])
+                    .style(|_theme, status| {
+                        let background = match status {
+                            iced::widget::button::Status::Pressed => colors::DANGER,
+                            iced::widget::button::Status::Hovered => Color::from_rgb(0.6, 0.2, 0.2),
+                            _ => Color::from_rgb(0.4, 0.15, 0.15),
+                        };
+                        button::Style {
+                            background: Some(iced::Background::Color(background)),
+                            border: iced::Border {
+                                color: colors::BORDER_STANDARD,
+                                width: 1.0,
+                                radius: 2.0.into(),
+                            },
+                            text_color: colors::TEXT_PRIMARY,
+                            ..Default::default()
+                        }
+                    })
+                    .on_press(Message::ToggleParabolicSAR)
+                ]
+                .spacing(8)
+                .align_y(iced::Alignment::Center),
+                // Paramètres
+                column![
+                    row![
+                        text("Pas:")
+                            .size(11)
+                            .color(colors::TEXT_SECONDARY),
+                        Space::new().width(Length::Fill),
+                        text_input("0.02", &psar_step_str)
+                            .on_input(|s| {
+                                s.parse::<f64>()
+                                    .ok()
+                                    .filter(|&v| v > 0.0 && v <= 1.0)
+                                    .map(Message::UpdatePSARStep)
+                                    .unwrap_or(Message::ClearPanelFocus)
+                            })
+                            .padding(4)
+                            .width(Length::Fixed(60.0))
+                            .size(11)
+                    ]
+                    .spacing(8)
+                    .align_y(iced::Alignment::Center),
+                    row![
+                        text("Pas max:")
+                            .size(11)
+                            .color(colors::TEXT_SECONDARY),
+                        Space::new().width(Length::Fill),
+                        text_input("0.2", &psar_max_step_str)
+                            .on_input(|s| {
+                                s.parse::<f64>()
+                                    .ok()
+                                    .filter(|&v| v > 0.0 && v <= 1.0)
+                                    .map(Message::UpdatePSARMaxStep)
+                                    .unwrap_or(Message::ClearPanelFocus)
+                            })
+                            .padding(4)
+                            .width(Length::Fixed(60.0))
+                            .size(11)
+                    ]
+                    .spacing(8)
+                    .align_y(iced::Alignment::Center)
+                ]
+                .spacing(4)
+                .padding([8.0, 10.0])
+            ]
+            .spacing(4);
+            
+            let indicator_row = container(indicator_content)
+                .padding([6, 10])
+                .style(|_theme| {
+                    container::Style {
+                        background: Some(iced::Background::Color(colors::BACKGROUND_MEDIUM)),
+                        border: iced::Border {
+                            color: colors::BORDER_STANDARD,
+                            width: 1.0,
+                            radius: 4.0.into(),
+                        },
+                        ..Default::default()
+                    }
+                });
+            
+            active_indicators = active_indicators.push(indicator_row);
+        }
+        
+        // Canaux de Donchian
+        if app.indicators.donchian_enabled {
+            let indicator_row = container(
+                row![
+                    text(format!("Donchian ({})", app.indicators.params.donchian_period))
+                        .size(12)
+                        .color(colors::TEXT_PRIMARY),
+                    Space::new().width(Length::Fill),
+                    button(
+                        text("×")
+                            .size(16)
+                            .color(colors::TEXT_PRIMARY)
+                    )
+                    .padding([4, 8])
+                    .style(|_theme, status| {
+                        let background = match status {
+                            iced::widget::button::Status::Pressed => colors::DANGER,
+                            iced::widget::button::Status::Hovered => Color::from_rgb(0.6, 0.2, 0.2),
+                            _ => Color::from_rgb(0.4, 0.15, 0.15),
+                        };
+                        button::Style {
+                            background: Some(iced::Background::Color(background)),
+                            border: iced::Border {
+                                color: colors::BORDER_STANDARD,
+                                width: 1.0,
+                                radius: 2.0.into(),
+                            },
+                            text_color: colors::TEXT_PRIMARY,
+                            ..Default::default()
+                        }
+                    })
+                    .on_press(Message::ToggleDonchian)
+                ]
+                .spacing(8)
+                .align_y(iced::Alignment::Center)
+                .padding([6, 10])
+            )
+            .style(|_theme| {
+                container::Style {
+                    background: Some(iced::Background::Color(colors::BACKGROUND_MEDIUM)),
+                    border: iced::Border {
+                        color: colors::BORDER_STANDARD,
+                        width: 1.0,
+                        radius: 4.0.into(),
+                    },
+                    ..Default::default()
+                }
+            });
+            
+            active_indicators = active_indicators.push(indicator_row);
+        }
+        
+        // Canaux de Keltner
+        if app.indicators.keltner_enabled {
+            let keltner_ema_str = app.indicators.params.keltner_ema_period.to_string();
+            let keltner_atr_str = app.indicators.params.keltner_atr_period.to_string();
+            let keltner_multiplier_str = format!("{:.1}", app.indicators.params.keltner_multiplier);
+            let indicator_content = column![
+                // Header avec nom et bouton supprimer
+                row![
+                    text("Keltner Channels")
+                        .size(12)
+                        .color(colors::TEXT_PRIMARY),
+                    Space::new().width(Length::Fill),
+                    button(
+                        text("×")
+                            .size(16)
+                            .color(colors::TEXT_PRIMARY)
+                    )
+                    .padding([4, 8])
+                    .style(|_theme, status| {
+                        let background = match status {
+                            iced::widget::button::Status::Pressed => colors::DANGER,
+                            iced::widget::button::Status::Hovered => Color::from_rgb(0.6, 0.2, 0.2),
+                            _ => Color::from_rgb(0.4, 0.15, 0.15),
+                        };
+                        button::Style {
+                            background: Some(iced::Background::Color(background)),
+                            border: iced::Border {
+                                color: colors::BORDER_STANDARD,
+                                width: 1.0,
+                                radius: 2.0.into(),
+                            },
+                            text_color: colors::TEXT_PRIMARY,
+                            ..Default::default()
+                        }
+                    })
+                    .on_press(Message::ToggleKeltner)
+                ]
+                .spacing(8)
+                .align_y(iced::Alignment::Center),
+                // Paramètres
+                column![
+                    row![
+                        text("Période EMA:")
+                            .size(11)
+                            .color(colors::TEXT_SECONDARY),
+                        Space::new().width(Length::Fill),
+                        text_input("20", &keltner_ema_str)
+                            .on_input(|s| {
+                                s.parse::<usize>()
+                                    .ok()
+                                    .filter(|&v| v > 0 && v <= 500)
+                                    .map(Message::UpdateKeltnerEmaPeriod)
+                                    .unwrap_or(Message::ClearPanelFocus)
+                            })
+                            .padding(4)
+                            .width(Length::Fixed(60.0))
+                            .size(11)
+                    ]
+                    .spacing(8)
+                    .align_y(iced::Alignment::Center),
+                    row![
+                        text("Période ATR:")
+                            .size(11)
+                            .color(colors::TEXT_SECONDARY),
+                        Space::new().width(Length::Fill),
+                        text_input("10", &keltner_atr_str)
+                            .on_input(|s| {
+                                s.parse::<usize>()
+                                    .ok()
+                                    .filter(|&v| v > 0 && v <= 500)
+                                    .map(Message::UpdateKeltnerAtrPeriod)
+                                    .unwrap_or(Message::ClearPanelFocus)
+                            })
+                            .padding(4)
+                            .width(Length::Fixed(60.0))
+                            .size(11)
+                    ]
+                    .spacing(8)
+                    .align_y(iced::Alignment::Center),
+                    row![
+                        text("Multiplicateur:")
+                            .size(11)
+                            .color(colors::TEXT_SECONDARY),
+                        Space::new().width(Length::Fill),
+                        text_input("2.0", &keltner_multiplier_str)
+                            .on_input(|s| {
+                                s.parse::<f64>()
+                                    .ok()
+                                    .filter(|&v| v > 0.0 && v <= 10.0)
+                                    .map(Message::UpdateKeltnerMultiplier)
+                                    .unwrap_or(Message::ClearPanelFocus)
+                            })
+                            .padding(4)
+                            .width(Length::Fixed(60.0))
+                            .size(11)
+                    ]
+                    .spacing(8)
+                    .align_y(iced::Alignment::Center)
+                ]
+                .spacing(4)
+                .padding([8.0, 10.0])
+            ]
+            .spacing(4);
+            
+            let indicator_row = container(indicator_content)
+                .padding([6, 10])
+                .style(|_theme| {
+                    container::Style {
+                        background: Some(iced::Background::Color(colors::BACKGROUND_MEDIUM)),
+                        border: iced::Border {
+                            color: colors::BORDER_STANDARD,
+                            width: 1.0,
+                            radius: 4.0.into(),
+                        },
+                        ..Default::default()
+                    }
+                });
+            
+            active_indicators = active_indicators.push(indicator_row);
+        }
+        
+        // Volume Profile
+        if app.indicators.volume_profile_enabled {
+            let indicator_row = container(
+                row![
+                    text("Volume Profile")
+                        .size(12)
+                        .color(colors::TEXT_PRIMARY),
+                    Space::new().width(Length::Fill),
+                    button(
+                        text("×")
+                            .size(16)
+                            .color(colors::TEXT_PRIMARY)
+                    )
+                    .padding([4, 8])
+                    .style(|_theme, status| {
+                        let background = match status {
+                            iced::widget::button::Status::Pressed => colors::DANGER,
+                            iced::widget::button::Status::Hovered => Color::from_rgb(0.6, 0.2, 0.2),
+                            _ => Color::from_rgb(0.4, 0.15, 0.15),
+                        };
+                        button::Style {
+                            background: Some(iced::Background::Color(background)),
+                            border: iced::Border {
+                                color: colors::BORDER_STANDARD,
+                                width: 1.0,
+                                radius: 2.0.into(),
+                            },
+                            text_color: colors::TEXT_PRIMARY,
+                            ..Default::default()
+                        }
+                    })
+                    .on_press(Message::ToggleVolumeProfile)
+                ]
+                .spacing(8)
+                .align_y(iced::Alignment::Center)
+                .padding([6, 10])
+            )
+            .style(|_theme| {
+                container::Style {
+                    background: Some(iced::Background::Color(colors::BACKGROUND_MEDIUM)),
+                    border: iced::Border {
+                        color: colors::BORDER_STANDARD,
+                        width: 1.0,
+                        radius: 4.0.into(),
+                    },
+                    ..Default::default()
+                }
+            });
+            
+            active_indicators = active_indicators.push(indicator_row);
+        }
+        
+        // Points pivots
+        if app.indicators.pivots_enabled {
+            let indicator_content = column![
+                // Header avec nom et bouton supprimer
+                row![
+                    text("Pivot Points")
+                        .size(12)
+                        .color(colors::TEXT_PRIMARY),
+                    Space::new().width(Length::Fill),
+                    button(
+                        text("×")
+                            .size(16)
+                            .color(colors::TEXT_PRIMARY)
+                    )
+                    .padding([4, 8])
+                    .style(|_theme, status| {
+                        let background = match status {
+                            iced::widget::button::Status::Pressed => colors::DANGER,
+                            iced::widget::button::Status::Hovered => Color::from_rgb(0.6, 0.2, 0.2),
+                            _ => Color::from_rgb(0.4, 0.15, 0.15),
+                        };
+                        button::Style {
+                            background: Some(iced::Background::Color(background)),
+                            border: iced::Border {
+                                color: colors::BORDER_STANDARD,
+                                width: 1.0,
+                                radius: 2.0.into(),
+                            },
+                            text_color: colors::TEXT_PRIMARY,
+                            ..Default::default()
+                        }
+                    })
+                    .on_press(Message::TogglePivots)
+                ]
+                .spacing(8)
+                .align_y(iced::Alignment::Center),
+                // Paramètres
+                column![
+                    row![
+                        text("Formule:")
+                            .size(11)
+                            .color(colors::TEXT_SECONDARY),
+                        Space::new().width(Length::Fill),
+                        pick_list(
+                            vec!["Classic".to_string(), "Fibonacci".to_string()],
+                            Some(app.indicators.params.pivot_method.as_str().to_string()),
+                            move |selected: String| {
+                                if let Some(method) = crate::app::state::PivotMethod::from_str(&selected) {
+                                    Message::UpdatePivotMethod(method)
+                                } else {
+                                    Message::ClearPanelFocus
+                                }
+                            }
+                        )
+                        .width(Length::Fixed(100.0))
+                        .text_size(11.0)
+                    ]
+                    .spacing(8)
+                    .align_y(iced::Alignment::Center)
+                ]
+                .spacing(4)
+                .padding([8.0, 10.0])
+            ]
+            .spacing(4);
+            
+            let indicator_row = container(indicator_content)
+                .padding([6, 10])
+                .style(|_theme| {
+                    container::Style {
+                        background: Some(iced::Background::Color(colors::BACKGROUND_MEDIUM)),
+                        border: iced::Border {
+                            color: colors::BORDER_STANDARD,
+                            width: 1.0,
+                            radius: 4.0.into(),
+                        },
+                        ..Default::default()
+                    }
+                });
+            
+            active_indicators = active_indicators.push(indicator_row);
+        }
+        
+        // Marqueurs de croisement de moyennes mobiles
+        if app.indicators.crossover_markers_enabled {
+            let fast_str = app.indicators.params.crossover_fast_period.to_string();
+            let slow_str = app.indicators.params.crossover_slow_period.to_string();
+            let indicator_content = column![
+                // Header avec nom et bouton supprimer
+                row![
+                    text("MA Crossovers")
+                        .size(12)
+                        .color(colors::TEXT_PRIMARY),
+                    Space::new().width(Length::Fill),
+                    button(
+                        text("×")
+                            .size(16)
+                            .color(colors::TEXT_PRIMARY)
+                    )
+                    .padding([4, 8])
+                    .style(|_theme, status| {
+                        let background = match status {
+                            iced::widget::button::Status::Pressed => colors::DANGER,
+                            iced::widget::button::Status::Hovered => Color::from_rgb(0.6, 0.2, 0.2),
+                            _ => Color::from_rgb(0.4, 0.15, 0.15),
+                        };
+                        button::Style {
+                            background: Some(iced::Background::Color(background)),
+                            border: iced::Border {
+                                color: colors::BORDER_STANDARD,
+                                width: 1.0,
+                                radius: 2.0.into(),
+                            },
+                            text_color: colors::TEXT_PRIMARY,
+                            ..Default::default()
+                        }
+                    })
+                    .on_press(Message::ToggleCrossoverMarkers)
+                ]
+                .spacing(8)
+                .align_y(iced::Alignment::Center),
+                // Paramètres
+                column![
+                    row![
+                        text("Type:")
+                            .size(11)
+                            .color(colors::TEXT_SECONDARY),
+                        Space::new().width(Length::Fill),
+                        pick_list(
+                            vec!["SMA".to_string(), "EMA".to_string(), "WMA".to_string()],
+                            Some(app.indicators.params.crossover_ma_type.as_str().to_string()),
+                            move |selected: String| {
+                                if let Some(ma_type) = crate::app::state::MaType::from_str(&selected) {
+                                    Message::UpdateCrossoverMaType(ma_type)
+                                } else {
+                                    Message::ClearPanelFocus
+                                }
+                            }
+                        )
+                        .width(Length::Fixed(70.0))
+                        .text_size(11.0)
+                    ]
+                    .spacing(8)
+                    .align_y(iced::Alignment::Center),
+                    row![
+                        text("Période rapide:")
+                            .size(11)
+                            .color(colors::TEXT_SECONDARY),
+                        Space::new().width(Length::Fill),
+                        text_input("50", &fast_str)
+                            .on_input(|s| {
+                                s.parse::<usize>()
+                                    .ok()
+                                    .filter(|&v| v > 0 && v <= 500)
+                                    .map(Message::UpdateCrossoverFastPeriod)
+                                    .unwrap_or(Message::ClearPanelFocus)
+                            })
+                            .padding(4)
+                            .width(Length::Fixed(60.0))
+                            .size(11)
+                    ]
+                    .spacing(8)
+                    .align_y(iced::Alignment::Center),
+                    row![
+                        text("Période lente:")
+                            .size(11)
+                            .color(colors::TEXT_SECONDARY),
+                        Space::new().width(Length::Fill),
+                        text_input("200", &slow_str)
+                            .on_input(|s| {
+                                s.parse::<usize>()
+                                    .ok()
+                                    .filter(|&v| v > 0 && v <= 500)
+                                    .map(Message::UpdateCrossoverSlowPeriod)
                                     .unwrap_or(Message::ClearPanelFocus)
                             })
                             .padding(4)
@@ -8,6 +8,10 @@ mod trade_history;
 mod account;
 mod strategies;
 mod backtest;
+mod equity;
+mod drawings;
+
+pub use drawings::DRAWING_NAME_INPUT_ID;
 
 use iced::Element;
 use crate::app::{
@@ -27,6 +31,8 @@ pub fn view_section_content(app: &ChartApp, section: BottomPanelSection) -> Elem
         BottomPanelSection::TradeHistory => trade_history::view_trade_history(app),
         BottomPanelSection::Strategies => strategies::view_strategies(app),
         BottomPanelSection::Backtest => backtest::view_backtest(app),
+        BottomPanelSection::Equity => equity::view_equity(app),
+        BottomPanelSection::Drawings => drawings::view_drawings(app),
     }
 }
 
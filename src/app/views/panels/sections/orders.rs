@@ -9,6 +9,7 @@ use crate::app::{
     data::OrderType,
 };
 use crate::finance_chart::core::{SeriesData, Candle};
+use crate::app::trading::risk::risk_reward_ratio;
 
 /// Formate un prix pour l'affichage avec une précision adaptative
 /// - >= 10000 : 0 décimales (ex: 90000)
@@ -129,6 +130,18 @@ pub fn view_orders(app: &ChartApp) -> Element<'_, Message> {
     };
     let total_amount = quantity_value * price_for_total;
     
+    // Risque du trade au stop et ratio gain/risque si un TP est saisi
+    let stop_value = app.trading_state.parse_stop_loss();
+    let risk_summary = stop_value.map(|stop| {
+        let risk_amount = (price_for_total - stop).abs() * quantity_value;
+        let ratio = app.trading_state.parse_take_profit()
+            .and_then(|tp| risk_reward_ratio(price_for_total, stop, tp));
+        match ratio {
+            Some(ratio) => format!("Risque: {:.2} {} | R:R 1:{:.2}", risk_amount, quote_currency, ratio),
+            None => format!("Risque: {:.2} {}", risk_amount, quote_currency),
+        }
+    });
+    
     // Style pour les boutons
     let buy_button_style = move |_theme: &iced::Theme, _status: iced::widget::button::Status| {
         button::Style {
@@ -385,6 +398,48 @@ pub fn view_orders(app: &ChartApp) -> Element<'_, Message> {
                         .width(Length::Fill),
                     ]
                     .width(Length::Fill),
+                    Space::new().height(Length::Fixed(8.0)),
+                    // Taille de position selon le risque : saisir un stop propose une quantité
+                    row![
+                        text("Risque par trade (%)")
+                            .size(12)
+                            .color(colors::TEXT_SECONDARY),
+                        Space::new().width(Length::Fill),
+                        text_input("1", &app.trading_state.risk_percent)
+                            .on_input(Message::UpdateRiskPercent)
+                            .padding(6)
+                            .size(12)
+                            .width(Length::Fixed(70.0)),
+                    ]
+                    .align_y(iced::Alignment::Center)
+                    .width(Length::Fill),
+                    text(risk_summary.unwrap_or_default())
+                        .size(12)
+                        .color(colors::TEXT_SECONDARY),
+                    Space::new().height(Length::Fixed(8.0)),
+                    // Trailing stop (appliqué à l'ouverture d'une position au marché)
+                    row![
+                        text_input("Trailing stop (optionnel)", &app.trading_state.trailing_offset)
+                            .on_input(Message::UpdateTrailingOffset)
+                            .padding(8)
+                            .size(14)
+                            .width(Length::Fill),
+                        Space::new().width(Length::Fixed(8.0)),
+                        checkbox(app.trading_state.trailing_percent)
+                            .on_toggle(|_| Message::ToggleTrailingPercent),
+                        text("%")
+                            .size(12)
+                            .color(colors::TEXT_SECONDARY),
+                    ]
+                    .spacing(4)
+                    .align_y(iced::Alignment::Center)
+                    .width(Length::Fill),
+                    Space::new().height(Length::Fixed(8.0)),
+                    // Ordre OCO : TP et stop liés sur la position ouverte
+                    button(text("Placer en OCO sur la position").size(12))
+                        .on_press(Message::PlaceOcoOrder)
+                        .padding([6, 12])
+                        .width(Length::Fill),
                 ]
                 .spacing(4)
             )
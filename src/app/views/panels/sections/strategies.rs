@@ -1,6 +1,6 @@
 //! Section "Stratégies de Trading"
 
-use iced::widget::{button, column, container, row, scrollable, text, text_input, Space, checkbox};
+use iced::widget::{button, column, container, row, scrollable, slider, text, text_input, Space, checkbox};
 use iced::{Element, Length, Color};
 use crate::app::{app_state::ChartApp, messages::Message, view_styles::colors};
 use std::collections::HashSet;
@@ -113,8 +113,8 @@ pub fn view_strategies(app: &ChartApp) -> Element<'_, Message> {
             let editing_state = app.editing_strategies.get(&id);
             let is_expanded = editing_state.map(|s| s.expanded).unwrap_or(false);
             
-            // Récupérer les paramètres de la stratégie (cloner pour éviter les problèmes de borrowing)
-            let parameters: Vec<_> = reg.strategy.parameters().into_iter().collect();
+            // Description des paramètres (bornes, pas) pour générer les champs
+            let specs = reg.strategy.parameter_specs();
             
             // Récupérer les timeframes actuels
             let current_timeframes = reg.allowed_timeframes.clone().unwrap_or_default();
@@ -171,43 +171,56 @@ pub fn view_strategies(app: &ChartApp) -> Element<'_, Message> {
                         .color(colors::TEXT_PRIMARY)
                 );
                 
-                for param in &parameters {
-                    let param_name = param.name.clone();
+                for spec in specs {
+                    let param_name = spec.name.clone();
                     let param_id = id.clone();
-                    let param_description = param.description.clone();
-                    let param_min = param.min;
-                    let param_max = param.max;
+                    let slider_name = spec.name.clone();
+                    let slider_id = id.clone();
+                    let slider_spec = spec.clone();
+                    let strategy_value = reg.strategy.parameter_value(&spec.name).unwrap_or(spec.default);
                     
                     // Récupérer la valeur actuelle (depuis l'état d'édition ou la valeur réelle)
-                    let current_value = if let Some(editing) = editing_state {
-                        editing.param_values.get(&param_name)
-                            .cloned()
-                            .unwrap_or_else(|| format!("{:.2}", param.value))
-                    } else {
-                        format!("{:.2}", param.value)
-                    };
+                    let current_value = editing_state
+                        .and_then(|editing| editing.param_values.get(&spec.name).cloned())
+                        .unwrap_or_else(|| spec.format(strategy_value));
+                    // Le curseur reste dans les bornes, même si la saisie en sort
+                    let slider_value = spec.clamp(current_value.trim().parse().unwrap_or(strategy_value));
+                    let bounds = text(format!("Min: {}, Max: {}", spec.format(spec.min), spec.format(spec.max)))
+                        .size(10)
+                        .color(colors::TEXT_SECONDARY);
                     
                     config_panel = config_panel.push(
                         column![
                             row![
-                                text(param_description)
+                                text(spec.description.clone())
                                     .size(12)
                                     .color(colors::TEXT_SECONDARY),
                                 Space::new().width(Length::Fill),
-                                text(format!("Min: {:.2}, Max: {:.2}", param_min, param_max))
-                                    .size(10)
-                                    .color(colors::TEXT_SECONDARY)
+                                bounds
                             ],
-                            text_input("", &current_value)
-                                .on_input(move |value| {
+                            row![
+                                slider(spec.min..=spec.max, slider_value, move |value| {
                                     Message::UpdateStrategyParamInput {
-                                        strategy_id: param_id.clone(),
-                                        param_name: param_name.clone(),
-                                        value,
+                                        strategy_id: slider_id.clone(),
+                                        param_name: slider_name.clone(),
+                                        value: slider_spec.format(value),
                                     }
                                 })
-                                .padding(6)
-                                .size(12)
+                                .step(spec.step),
+                                text_input("", &current_value)
+                                    .on_input(move |value| {
+                                        Message::UpdateStrategyParamInput {
+                                            strategy_id: param_id.clone(),
+                                            param_name: param_name.clone(),
+                                            value,
+                                        }
+                                    })
+                                    .padding(6)
+                                    .size(12)
+                                    .width(Length::Fixed(90.0))
+                            ]
+                            .spacing(8)
+                            .align_y(iced::Alignment::Center)
                         ]
                         .spacing(4)
                     );
@@ -348,6 +361,7 @@ pub fn view_strategies(app: &ChartApp) -> Element<'_, Message> {
                 
                 let apply_id = id.clone();
                 let cancel_id = id.clone();
+                let reset_id = id.clone();
                 
                 config_panel = config_panel.push(
                     row![
@@ -356,6 +370,9 @@ pub fn view_strategies(app: &ChartApp) -> Element<'_, Message> {
                             .style(primary_button_style),
                         button("Annuler")
                             .on_press(Message::CancelStrategyConfig(cancel_id))
+                            .style(secondary_button_style),
+                        button("Valeurs par défaut")
+                            .on_press(Message::ResetStrategyParameters(reset_id))
                             .style(secondary_button_style)
                     ]
                     .spacing(10)
@@ -408,6 +425,18 @@ pub fn view_strategies(app: &ChartApp) -> Element<'_, Message> {
         .spacing(10)
     );
     
+    content = content.push(
+        row![
+            button("Stratégie Bollinger")
+                .on_press(Message::RegisterBollingerStrategy)
+                .style(primary_button_style),
+            button("Stratégie MACD")
+                .on_press(Message::RegisterMacdStrategy)
+                .style(primary_button_style)
+        ]
+        .spacing(10)
+    );
+    
     scrollable(content)
         .width(Length::Fill)
         .height(Length::Fill)
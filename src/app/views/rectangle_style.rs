@@ -0,0 +1,135 @@
+//! Barre de style du rectangle sélectionné
+//!
+//! Affichée en haut à gauche du graphique tant qu'un rectangle est sélectionné :
+//! couleur et opacité du fond, texte centré dans la zone.
+
+use iced::widget::{button, container, row, stack, text, text_input, Space};
+use iced::{Color, Element, Length};
+use crate::finance_chart::ChartMessage;
+use crate::finance_chart::tools::DrawnRectangle;
+use crate::app::{app_state::ChartApp, messages::Message, view_styles};
+
+/// Identifiant du champ de saisie (utilisé pour lui donner le focus)
+pub const RECTANGLE_LABEL_INPUT_ID: &str = "rectangle-label-input";
+
+/// Couleurs de fond proposées (zones de demande, d'offre, neutres…)
+const FILL_COLORS: [Color; 6] = [
+    Color { r: 0.2, g: 0.8, b: 0.4, a: 1.0 },
+    Color { r: 1.0, g: 0.3, b: 0.3, a: 1.0 },
+    Color { r: 0.2, g: 0.6, b: 1.0, a: 1.0 },
+    Color { r: 1.0, g: 0.6, b: 0.1, a: 1.0 },
+    Color { r: 0.7, g: 0.5, b: 1.0, a: 1.0 },
+    Color { r: 0.6, g: 0.6, b: 0.6, a: 1.0 },
+];
+
+/// Overlay contenant la barre de style du rectangle sélectionné
+pub fn rectangle_style_overlay(app: &ChartApp) -> Element<'_, Message> {
+    let editing = &app.tools_state.editing;
+    let selected = editing.selected_index
+        .filter(|_| !editing.is_editing)
+        .and_then(|index| app.tools_state.rectangles.get(index).map(|rect| (index, rect)));
+    let Some((index, rect)) = selected else {
+        return stack![].width(Length::Fill).height(Length::Fill).into();
+    };
+
+    let mut bar = row![].spacing(4).align_y(iced::Alignment::Center);
+
+    // Couleurs du fond
+    for color in FILL_COLORS {
+        let is_current = (rect.color.r, rect.color.g, rect.color.b) == (color.r, color.g, color.b);
+        let swatch = container(text(""))
+            .width(Length::Fixed(14.0))
+            .height(Length::Fixed(14.0))
+            .style(move |_theme| container::Style {
+                background: Some(iced::Background::Color(color)),
+                border: iced::Border {
+                    color: if is_current { Color::WHITE } else { Color::from_rgb(0.3, 0.3, 0.35) },
+                    width: 1.0,
+                    radius: 2.0.into(),
+                },
+                ..Default::default()
+            });
+        bar = bar.push(
+            button(swatch)
+                .on_press(Message::Chart(ChartMessage::SetRectangleColor { index, color }))
+                .padding(0)
+                .style(|_theme, _status| button::Style {
+                    background: None,
+                    ..Default::default()
+                }),
+        );
+    }
+    bar = bar.push(Space::new().width(Length::Fixed(6.0)));
+
+    // Opacité du fond
+    let current_opacity = rect.fill_color().a;
+    for opacity in DrawnRectangle::FILL_OPACITIES {
+        let is_selected = (current_opacity - opacity).abs() < 0.01;
+        bar = bar.push(
+            button(text(format!("{:.0}%", opacity * 100.0)).size(11))
+                .on_press(Message::Chart(ChartMessage::SetRectangleOpacity { index, opacity }))
+                .padding([2, 5])
+                .style(move |theme, status| {
+                    if is_selected {
+                        view_styles::success_button_style(theme, status)
+                    } else {
+                        view_styles::icon_button_style(theme, status)
+                    }
+                }),
+        );
+    }
+    bar = bar.push(Space::new().width(Length::Fixed(6.0)));
+
+    // Texte du rectangle : saisie en cours ou bouton d'édition
+    match app.tools_state.rectangle_label_editing.as_ref().filter(|edit| edit.index == index) {
+        Some(edit) => {
+            bar = bar.push(
+                text_input("Texte…", &edit.content)
+                    .id(RECTANGLE_LABEL_INPUT_ID)
+                    .on_input(|content| Message::Chart(ChartMessage::UpdateRectangleLabelDraft { content }))
+                    .on_submit(Message::Chart(ChartMessage::CommitRectangleLabel))
+                    .padding(2)
+                    .size(11)
+                    .width(Length::Fixed(120.0)),
+            );
+        }
+        None => {
+            let label = rect.label.as_deref().unwrap_or("Texte…");
+            bar = bar.push(
+                button(text(label.to_string()).size(11))
+                    .on_press(Message::Chart(ChartMessage::StartEditingRectangleLabel { index }))
+                    .padding([2, 6])
+                    .style(view_styles::icon_button_style),
+            );
+        }
+    }
+
+    let (left, top) = app.chart_state.interaction.main_chart_bounds
+        .map(|(x, y, _, _)| (x + 8.0, y + 8.0))
+        .unwrap_or((8.0, 8.0));
+
+    container(
+        container(bar)
+            .padding(4)
+            .style(|_theme| container::Style {
+                background: Some(iced::Background::Color(Color::from_rgb(0.12, 0.12, 0.15))),
+                border: iced::Border {
+                    color: Color::from_rgb(0.3, 0.3, 0.35),
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                ..Default::default()
+            })
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .align_x(iced::alignment::Horizontal::Left)
+    .align_y(iced::alignment::Vertical::Top)
+    .padding(iced::Padding {
+        left,
+        top,
+        right: 0.0,
+        bottom: 0.0,
+    })
+    .into()
+}
@@ -1,8 +1,8 @@
 //! Vue des settings (style du graphique)
 
-use iced::widget::{button, checkbox, column, container, row, scrollable, text, Space};
+use iced::widget::{button, checkbox, column, container, row, scrollable, text, text_input, Space};
 use iced::{Element, Length, Color};
-use crate::finance_chart::settings::{color_fields, preset_colors, SerializableColor};
+use crate::finance_chart::settings::{color_fields, preset_colors, SerializableColor, CandleBodyStyle, ChartRenderMode, CrosshairConfig, CrosshairDash, GridConfig, SettingsState, ThemePreset, TooltipField, ZoomAnchor, PRICE_DECIMALS_CHOICES, RIGHT_PADDING_CHOICES, format_abbreviated};
 use crate::app::{
     app_state::ChartApp,
     messages::Message,
@@ -22,6 +22,24 @@ pub fn view_settings(app: &ChartApp) -> Element<'_, Message> {
         .size(20)
         .color(Color::WHITE);
 
+    // Sélecteur de thème (remplace toutes les couleurs, retouchables ensuite)
+    let mut theme_row = row![
+        text("Thème")
+            .size(14)
+            .color(colors::TEXT_TERTIARY),
+        Space::new().width(Length::Fill),
+    ]
+    .spacing(5)
+    .align_y(iced::Alignment::Center);
+
+    for preset in ThemePreset::ALL {
+        let theme_btn = button(text(preset.label()).size(13))
+            .on_press(Message::ApplyThemePreset(preset))
+            .padding([4, 10])
+            .style(view_styles::icon_button_style);
+        theme_row = theme_row.push(theme_btn);
+    }
+
     // Liste des champs de couleur
     let mut color_rows = column![].spacing(10);
     
@@ -153,18 +171,502 @@ pub fn view_settings(app: &ChartApp) -> Element<'_, Message> {
     .spacing(10)
     .align_y(iced::Alignment::Center);
 
+    // Toggle Heikin-Ashi (appliqué immédiatement, non sauvegardé)
+    let heikin_ashi_toggle = row![
+        checkbox(app.settings_state.heikin_ashi_enabled)
+            .on_toggle(|_| Message::ToggleHeikinAshi),
+        text("Bougies Heikin-Ashi")
+            .size(14)
+            .color(colors::TEXT_TERTIARY)
+    ]
+    .spacing(10)
+    .align_y(iced::Alignment::Center);
+
+    // Toggle du mode aimant du crosshair (appliqué immédiatement, non sauvegardé)
+    let magnet_toggle = row![
+        checkbox(app.settings_state.crosshair_magnet)
+            .on_toggle(|_| Message::ToggleCrosshairMagnet),
+        text("Crosshair aimanté sur les prix OHLC (touche M)")
+            .size(14)
+            .color(colors::TEXT_TERTIARY)
+    ]
+    .spacing(10)
+    .align_y(iced::Alignment::Center);
+
+    // Toggle des trous de données (appliqué immédiatement, non sauvegardé)
+    let gaps_toggle = row![
+        checkbox(app.settings_state.show_gaps)
+            .on_toggle(|_| Message::ToggleShowGaps),
+        text("Hachurer les zones sans données")
+            .size(14)
+            .color(colors::TEXT_TERTIARY)
+    ]
+    .spacing(10)
+    .align_y(iced::Alignment::Center);
+
+    // Toggle de la heatmap de volume (appliqué immédiatement, non sauvegardé)
+    let volume_heatmap_toggle = row![
+        checkbox(app.settings_state.volume_heatmap)
+            .on_toggle(|_| Message::ToggleVolumeHeatmap),
+        text("Colorer les bougies selon leur volume")
+            .size(14)
+            .color(colors::TEXT_TERTIARY)
+    ]
+    .spacing(10)
+    .align_y(iced::Alignment::Center);
+
+    // Toggle de l'alignement sur des bougies entières (appliqué immédiatement, non sauvegardé)
+    let snap_pan_toggle = row![
+        checkbox(app.settings_state.snap_pan_to_candle)
+            .on_toggle(|_| Message::ToggleSnapPanToCandle),
+        text("Aligner la vue sur des bougies entières après un déplacement")
+            .size(14)
+            .color(colors::TEXT_TERTIARY)
+    ]
+    .spacing(10)
+    .align_y(iced::Alignment::Center);
+
+    // Point fixe du zoom à la molette (appliqué immédiatement, non sauvegardé)
+    let mut zoom_anchor_row = row![
+        text("Zoom à la molette autour du")
+            .size(14)
+            .color(colors::TEXT_TERTIARY),
+        Space::new().width(Length::Fill),
+    ]
+    .spacing(5)
+    .align_y(iced::Alignment::Center);
+
+    for anchor in ZoomAnchor::ALL {
+        let is_selected = anchor == app.settings_state.zoom_anchor;
+        let anchor_btn = button(text(anchor.label()).size(13))
+            .on_press(Message::SetZoomAnchor(anchor))
+            .padding([4, 10])
+            .style(move |theme, status| {
+                if is_selected {
+                    view_styles::success_button_style(theme, status)
+                } else {
+                    view_styles::icon_button_style(theme, status)
+                }
+            });
+        zoom_anchor_row = zoom_anchor_row.push(anchor_btn);
+    }
+
+    // Toggle des séparateurs de journée (appliqué immédiatement, non sauvegardé)
+    let day_separators_toggle = row![
+        checkbox(app.settings_state.show_day_separators)
+            .on_toggle(|_| Message::ToggleShowDaySeparators),
+        text("Séparateurs de journée (graphiques intraday)")
+            .size(14)
+            .color(colors::TEXT_TERTIARY)
+    ]
+    .spacing(10)
+    .align_y(iced::Alignment::Center);
+
+    // Toggle des liaisons entrée/sortie des trades (appliqué immédiatement, non sauvegardé)
+    let trade_pnl_toggle = row![
+        checkbox(app.settings_state.show_trade_pnl)
+            .on_toggle(|_| Message::ToggleShowTradePnl),
+        text("Relier l'entrée et la sortie des trades clôturés (P&L)")
+            .size(14)
+            .color(colors::TEXT_TERTIARY)
+    ]
+    .spacing(10)
+    .align_y(iced::Alignment::Center);
+
+    // Toggle du bandeau d'informations (appliqué immédiatement, non sauvegardé)
+    let hud_toggle = row![
+        checkbox(app.settings_state.show_hud)
+            .on_toggle(|_| Message::ToggleShowHud),
+        text("Bandeau d'informations (bougies visibles, intervalle, période)")
+            .size(14)
+            .color(colors::TEXT_TERTIARY)
+    ]
+    .spacing(10)
+    .align_y(iced::Alignment::Center);
+
+    // Champs du tooltip OHLC : les champs affichés d'abord, dans leur ordre, puis les autres
+    let tooltip_config = &app.settings_state.tooltip;
+    let mut tooltip_rows = column![
+        text("Tooltip (Maj + survol)")
+            .size(14)
+            .color(colors::TEXT_TERTIARY)
+    ]
+    .spacing(4);
+    let hidden_fields = TooltipField::ALL
+        .into_iter()
+        .filter(|field| !tooltip_config.is_enabled(*field));
+    for field in tooltip_config.fields.iter().copied().chain(hidden_fields) {
+        let enabled = tooltip_config.is_enabled(field);
+        let mut field_row = row![
+            checkbox(enabled)
+                .on_toggle(move |_| Message::ToggleTooltipField(field)),
+            text(field.label())
+                .size(13)
+                .color(colors::TEXT_TERTIARY),
+            Space::new().width(Length::Fill),
+        ]
+        .spacing(10)
+        .align_y(iced::Alignment::Center);
+        if enabled {
+            field_row = field_row
+                .push(
+                    button(text("▲").size(11))
+                        .on_press(Message::MoveTooltipFieldUp(field))
+                        .padding([2, 6])
+                        .style(view_styles::icon_button_style),
+                )
+                .push(
+                    button(text("▼").size(11))
+                        .on_press(Message::MoveTooltipFieldDown(field))
+                        .padding([2, 6])
+                        .style(view_styles::icon_button_style),
+                );
+        }
+        tooltip_rows = tooltip_rows.push(field_row);
+    }
+
+    // Sélecteur du mode de rendu
+    let current_mode = editing_style
+        .map(|s| s.render_mode)
+        .unwrap_or_default();
+
+    let mut render_mode_row = row![
+        text("Mode de rendu")
+            .size(14)
+            .color(colors::TEXT_TERTIARY),
+        Space::new().width(Length::Fill),
+    ]
+    .spacing(5)
+    .align_y(iced::Alignment::Center);
+
+    for mode in ChartRenderMode::ALL {
+        let is_selected = mode == current_mode;
+        let mode_btn = button(text(mode.label()).size(13))
+            .on_press(Message::SetRenderMode(mode))
+            .padding([4, 10])
+            .style(move |theme, status| {
+                if is_selected {
+                    view_styles::success_button_style(theme, status)
+                } else {
+                    view_styles::icon_button_style(theme, status)
+                }
+            });
+        render_mode_row = render_mode_row.push(mode_btn);
+    }
+
+    // Dimensions des bougies : largeur du corps en % de l'espace (vide = adaptative) et mèches
+    let candle_width_str = editing_style
+        .and_then(|s| s.candle_width_ratio)
+        .map(|ratio| format!("{:.0}", ratio * 100.0))
+        .unwrap_or_default();
+    let wick_width_str = editing_style
+        .map(|s| s.wick_width.to_string())
+        .unwrap_or_else(|| "1".to_string());
+
+    let candle_size_row = row![
+        text("Largeur des bougies (%)")
+            .size(14)
+            .color(colors::TEXT_TERTIARY),
+        text_input("auto", &candle_width_str)
+            .on_input(|s| {
+                if s.trim().is_empty() {
+                    return Message::SetCandleWidthRatio(None);
+                }
+                s.parse::<u32>()
+                    .ok()
+                    .filter(|&v| v > 0 && v <= 100)
+                    .map(|v| Message::SetCandleWidthRatio(Some(v as f32 / 100.0)))
+                    .unwrap_or(Message::ClearPanelFocus)
+            })
+            .padding(4)
+            .width(Length::Fixed(60.0))
+            .size(13),
+        Space::new().width(Length::Fixed(20.0)),
+        text("Épaisseur des mèches (px)")
+            .size(14)
+            .color(colors::TEXT_TERTIARY),
+        text_input("1", &wick_width_str)
+            .on_input(|s| {
+                s.parse::<f32>()
+                    .ok()
+                    .filter(|v| (0.5..=5.0).contains(v))
+                    .map(Message::SetWickWidth)
+                    .unwrap_or(Message::ClearPanelFocus)
+            })
+            .padding(4)
+            .width(Length::Fixed(60.0))
+            .size(13),
+    ]
+    .spacing(10)
+    .align_y(iced::Alignment::Center);
+
+    // Style du corps des bougies : pleines ou creuses (haussières en contour seul)
+    let current_body_style = editing_style
+        .map(|s| s.candle_body_style)
+        .unwrap_or_default();
+    let mut body_style_row = row![
+        text("Corps des bougies")
+            .size(14)
+            .color(colors::TEXT_TERTIARY),
+        Space::new().width(Length::Fill),
+    ]
+    .spacing(5)
+    .align_y(iced::Alignment::Center);
+
+    for body_style in CandleBodyStyle::ALL {
+        let is_selected = body_style == current_body_style;
+        let body_style_btn = button(text(body_style.label()).size(13))
+            .on_press(Message::SetCandleBodyStyle(body_style))
+            .padding([4, 10])
+            .style(move |theme, status| {
+                if is_selected {
+                    view_styles::success_button_style(theme, status)
+                } else {
+                    view_styles::icon_button_style(theme, status)
+                }
+            });
+        body_style_row = body_style_row.push(body_style_btn);
+    }
+
+    // Apparence de la grille : densité, pointillés et lignes secondaires
+    let grid = editing_style.map(|s| s.grid).unwrap_or_default();
+    let mut grid_row = row![
+        text("Grille")
+            .size(14)
+            .color(colors::TEXT_TERTIARY),
+        Space::new().width(Length::Fill),
+    ]
+    .spacing(5)
+    .align_y(iced::Alignment::Center);
+
+    for (density, label) in GridConfig::DENSITIES {
+        let is_selected = grid.density == density;
+        let density_btn = button(text(label).size(13))
+            .on_press(Message::SetGridDensity(density))
+            .padding([4, 10])
+            .style(move |theme, status| {
+                if is_selected {
+                    view_styles::success_button_style(theme, status)
+                } else {
+                    view_styles::icon_button_style(theme, status)
+                }
+            });
+        grid_row = grid_row.push(density_btn);
+    }
+    let grid_row = grid_row
+        .push(Space::new().width(Length::Fixed(20.0)))
+        .push(checkbox(grid.dashed).on_toggle(|_| Message::ToggleGridDashed))
+        .push(text("Pointillés").size(14).color(colors::TEXT_TERTIARY))
+        .push(Space::new().width(Length::Fixed(10.0)))
+        .push(checkbox(grid.minor_lines).on_toggle(|_| Message::ToggleGridMinorLines))
+        .push(text("Lignes secondaires").size(14).color(colors::TEXT_TERTIARY));
+
+    // Apparence du crosshair : épaisseur, motif de trait et étiquettes des axes
+    let crosshair = editing_style.map(|s| s.crosshair).unwrap_or_default();
+    let mut crosshair_row = row![
+        text("Crosshair")
+            .size(14)
+            .color(colors::TEXT_TERTIARY),
+        Space::new().width(Length::Fill),
+    ]
+    .spacing(5)
+    .align_y(iced::Alignment::Center);
+
+    for width in CrosshairConfig::WIDTHS {
+        let is_selected = crosshair.width == width;
+        let width_btn = button(text(format!("{}px", width)).size(13))
+            .on_press(Message::SetCrosshairWidth(width))
+            .padding([4, 10])
+            .style(move |theme, status| {
+                if is_selected {
+                    view_styles::success_button_style(theme, status)
+                } else {
+                    view_styles::icon_button_style(theme, status)
+                }
+            });
+        crosshair_row = crosshair_row.push(width_btn);
+    }
+    crosshair_row = crosshair_row.push(Space::new().width(Length::Fixed(20.0)));
+    for dash in CrosshairDash::ALL {
+        let is_selected = crosshair.dash == dash;
+        let dash_btn = button(text(dash.label()).size(13))
+            .on_press(Message::SetCrosshairDash(dash))
+            .padding([4, 10])
+            .style(move |theme, status| {
+                if is_selected {
+                    view_styles::success_button_style(theme, status)
+                } else {
+                    view_styles::icon_button_style(theme, status)
+                }
+            });
+        crosshair_row = crosshair_row.push(dash_btn);
+    }
+    let crosshair_row = crosshair_row
+        .push(Space::new().width(Length::Fixed(20.0)))
+        .push(checkbox(crosshair.show_labels).on_toggle(|_| Message::ToggleCrosshairLabels))
+        .push(text("Étiquettes").size(14).color(colors::TEXT_TERTIARY));
+
+    // Précision des prix : automatique (selon l'ordre de grandeur) ou nombre de décimales fixe
+    let current_decimals = editing_style.and_then(|s| s.price_decimals);
+    let mut price_decimals_row = row![
+        text("Décimales des prix")
+            .size(14)
+            .color(colors::TEXT_TERTIARY),
+        Space::new().width(Length::Fill),
+    ]
+    .spacing(5)
+    .align_y(iced::Alignment::Center);
+
+    let choices = std::iter::once(None).chain(PRICE_DECIMALS_CHOICES.into_iter().map(Some));
+    for decimals in choices {
+        let is_selected = decimals == current_decimals;
+        let label = decimals.map(|d| d.to_string()).unwrap_or_else(|| "Auto".to_string());
+        let decimals_btn = button(text(label).size(13))
+            .on_press(Message::SetPriceDecimals(decimals))
+            .padding([4, 10])
+            .style(move |theme, status| {
+                if is_selected {
+                    view_styles::success_button_style(theme, status)
+                } else {
+                    view_styles::icon_button_style(theme, status)
+                }
+            });
+        price_decimals_row = price_decimals_row.push(decimals_btn);
+    }
+
+    // Espace vide à droite de la dernière bougie (projections des tracés)
+    let current_padding = editing_style.map(|s| s.right_padding_candles).unwrap_or(0.0);
+    let mut right_padding_row = row![
+        text("Espace à droite (bougies)")
+            .size(14)
+            .color(colors::TEXT_TERTIARY),
+        Space::new().width(Length::Fill),
+    ]
+    .spacing(5)
+    .align_y(iced::Alignment::Center);
+
+    for candles in RIGHT_PADDING_CHOICES {
+        let is_selected = candles == current_padding;
+        let padding_btn = button(text(format!("{}", candles)).size(13))
+            .on_press(Message::SetRightPadding(candles))
+            .padding([4, 10])
+            .style(move |theme, status| {
+                if is_selected {
+                    view_styles::success_button_style(theme, status)
+                } else {
+                    view_styles::icon_button_style(theme, status)
+                }
+            });
+        right_padding_row = right_padding_row.push(padding_btn);
+    }
+
+    // Requêtes simultanées vers le provider (appliqué immédiatement, non sauvegardé)
+    let current_limit = app.settings_state.concurrency_limit();
+    let mut concurrency_row = row![
+        text("Requêtes simultanées (complétion des données)")
+            .size(14)
+            .color(colors::TEXT_TERTIARY),
+        Space::new().width(Length::Fill),
+    ]
+    .spacing(5)
+    .align_y(iced::Alignment::Center);
+
+    for limit in SettingsState::MAX_CONCURRENT_REQUESTS_CHOICES {
+        let is_selected = limit == current_limit;
+        let limit_btn = button(text(limit.to_string()).size(13))
+            .on_press(Message::SetMaxConcurrentRequests(limit))
+            .padding([4, 10])
+            .style(move |theme, status| {
+                if is_selected {
+                    view_styles::success_button_style(theme, status)
+                } else {
+                    view_styles::icon_button_style(theme, status)
+                }
+            });
+        concurrency_row = concurrency_row.push(limit_btn);
+    }
+
+    // Historique gardé en mémoire par série (appliqué immédiatement, non sauvegardé)
+    let mut history_cap_row = row![
+        text("Bougies en mémoire par série")
+            .size(14)
+            .color(colors::TEXT_TERTIARY),
+        Space::new().width(Length::Fill),
+    ]
+    .spacing(5)
+    .align_y(iced::Alignment::Center);
+
+    for limit in SettingsState::MAX_CANDLES_CHOICES {
+        let is_selected = limit == app.settings_state.max_candles_per_series;
+        let label = match limit {
+            Some(max_candles) => format_abbreviated(max_candles as f64),
+            None => "Illimité".to_string(),
+        };
+        let limit_btn = button(text(label).size(13))
+            .on_press(Message::SetMaxCandlesPerSeries(limit))
+            .padding([4, 10])
+            .style(move |theme, status| {
+                if is_selected {
+                    view_styles::success_button_style(theme, status)
+                } else {
+                    view_styles::icon_button_style(theme, status)
+                }
+            });
+        history_cap_row = history_cap_row.push(limit_btn);
+    }
+
     // Layout complet
     let content = column![
         title,
         Space::new().height(10),
         separator(),
         Space::new().height(10),
+        theme_row,
+        Space::new().height(10),
         scrollable(color_rows).height(Length::Fill),
         Space::new().height(10),
         separator(),
         Space::new().height(10),
         auto_scroll_toggle,
         Space::new().height(10),
+        render_mode_row,
+        Space::new().height(10),
+        candle_size_row,
+        Space::new().height(10),
+        body_style_row,
+        Space::new().height(10),
+        grid_row,
+        Space::new().height(10),
+        crosshair_row,
+        Space::new().height(10),
+        price_decimals_row,
+        Space::new().height(10),
+        right_padding_row,
+        Space::new().height(10),
+        heikin_ashi_toggle,
+        Space::new().height(10),
+        magnet_toggle,
+        Space::new().height(10),
+        snap_pan_toggle,
+        Space::new().height(10),
+        zoom_anchor_row,
+        Space::new().height(10),
+        gaps_toggle,
+        Space::new().height(10),
+        volume_heatmap_toggle,
+        Space::new().height(10),
+        day_separators_toggle,
+        Space::new().height(10),
+        hud_toggle,
+        Space::new().height(10),
+        trade_pnl_toggle,
+        Space::new().height(10),
+        concurrency_row,
+        Space::new().height(10),
+        history_cap_row,
+        Space::new().height(10),
+        tooltip_rows,
+        Space::new().height(10),
         separator(),
         Space::new().height(10),
         buttons_row
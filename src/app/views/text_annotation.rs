@@ -0,0 +1,50 @@
+//! Champ de saisie des annotations texte du graphique
+//!
+//! Affiché par-dessus le graphique à la position du clic tant qu'une
+//! annotation est en cours de saisie.
+
+use iced::widget::{container, stack, text_input};
+use iced::{Color, Element, Length};
+use crate::finance_chart::ChartMessage;
+use crate::app::{app_state::ChartApp, messages::Message};
+
+/// Identifiant du champ de saisie (utilisé pour lui donner le focus)
+pub const TEXT_ANNOTATION_INPUT_ID: &str = "text-annotation-input";
+
+/// Overlay contenant le champ de saisie de l'annotation en cours
+pub fn text_annotation_overlay(app: &ChartApp) -> Element<'_, Message> {
+    let Some(edit) = &app.tools_state.text_editing else {
+        return stack![].width(Length::Fill).height(Length::Fill).into();
+    };
+    let (x, y) = edit.position;
+
+    let input = text_input("Texte…", &edit.content)
+        .id(TEXT_ANNOTATION_INPUT_ID)
+        .on_input(|content| Message::Chart(ChartMessage::UpdateTextDraft { content }))
+        .on_submit(Message::Chart(ChartMessage::CommitText { content: edit.content.clone() }))
+        .padding(4)
+        .size(12)
+        .width(Length::Fixed(180.0));
+
+    container(
+        container(input).style(|_theme| container::Style {
+            border: iced::Border {
+                color: Color::from_rgb(0.3, 0.3, 0.35),
+                width: 1.0,
+                radius: 4.0.into(),
+            },
+            ..Default::default()
+        })
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .align_x(iced::alignment::Horizontal::Left)
+    .align_y(iced::alignment::Vertical::Top)
+    .padding(iced::Padding {
+        left: x,
+        top: y,
+        right: 0.0,
+        bottom: 0.0,
+    })
+    .into()
+}
@@ -0,0 +1,140 @@
+//! Alertes de prix
+//!
+//! Une alerte est posée à un niveau de prix et se déclenche une seule fois,
+//! lorsque la bougie la plus récente franchit ce niveau dans la direction attendue.
+
+use super::core::Candle;
+
+/// Sens de franchissement attendu pour déclencher l'alerte
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertDirection {
+    /// Le prix monte jusqu'au niveau (plus haut >= niveau)
+    Above,
+    /// Le prix descend jusqu'au niveau (plus bas <= niveau)
+    Below,
+}
+
+impl AlertDirection {
+    /// Déduit le sens à partir du prix courant au moment de la création
+    pub fn from_reference(price: f64, reference_price: f64) -> Self {
+        if price >= reference_price {
+            AlertDirection::Above
+        } else {
+            AlertDirection::Below
+        }
+    }
+}
+
+/// Alerte de prix
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alert {
+    /// Identifiant unique (stable, utilisé dans `Message::AlertTriggered`)
+    pub id: u64,
+    /// Niveau de prix surveillé
+    pub price: f64,
+    /// Sens de franchissement attendu
+    pub direction: AlertDirection,
+    /// L'alerte s'est déjà déclenchée (elle ne se redéclenche pas)
+    pub triggered: bool,
+}
+
+impl Alert {
+    /// Indique si la bougie franchit le niveau de l'alerte
+    pub fn is_crossed_by(&self, candle: &Candle) -> bool {
+        match self.direction {
+            AlertDirection::Above => candle.high >= self.price,
+            AlertDirection::Below => candle.low <= self.price,
+        }
+    }
+}
+
+/// Ensemble des alertes de prix du graphique
+#[derive(Debug, Clone, Default)]
+pub struct AlertsState {
+    pub alerts: Vec<Alert>,
+    next_id: u64,
+}
+
+impl AlertsState {
+    /// Ajoute une alerte au niveau `price` et retourne son identifiant
+    ///
+    /// Le sens est déduit de `reference_price` (dernier prix connu).
+    pub fn add(&mut self, price: f64, reference_price: f64) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.alerts.push(Alert {
+            id,
+            price,
+            direction: AlertDirection::from_reference(price, reference_price),
+            triggered: false,
+        });
+        id
+    }
+
+    /// Supprime une alerte
+    pub fn remove(&mut self, id: u64) -> bool {
+        let len = self.alerts.len();
+        self.alerts.retain(|alert| alert.id != id);
+        self.alerts.len() != len
+    }
+
+    /// Retourne une alerte par son identifiant
+    pub fn get(&self, id: u64) -> Option<&Alert> {
+        self.alerts.iter().find(|alert| alert.id == id)
+    }
+
+    /// Vérifie les alertes en attente contre une nouvelle bougie
+    ///
+    /// Les alertes franchies sont marquées `triggered` et leurs identifiants retournés.
+    pub fn check(&mut self, candle: &Candle) -> Vec<u64> {
+        let mut triggered = Vec::new();
+        for alert in self.alerts.iter_mut().filter(|alert| !alert.triggered) {
+            if alert.is_crossed_by(candle) {
+                alert.triggered = true;
+                triggered.push(alert.id);
+            }
+        }
+        triggered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candle(low: f64, high: f64) -> Candle {
+        Candle::new(0, low, high, low, high, 1.0)
+    }
+
+    #[test]
+    fn test_direction_from_reference() {
+        let mut alerts = AlertsState::default();
+        let up = alerts.add(110.0, 100.0);
+        let down = alerts.add(90.0, 100.0);
+        assert_eq!(alerts.get(up).unwrap().direction, AlertDirection::Above);
+        assert_eq!(alerts.get(down).unwrap().direction, AlertDirection::Below);
+        assert_ne!(up, down);
+    }
+
+    #[test]
+    fn test_alert_fires_once() {
+        let mut alerts = AlertsState::default();
+        let id = alerts.add(110.0, 100.0);
+
+        assert!(alerts.check(&candle(100.0, 105.0)).is_empty());
+        assert_eq!(alerts.check(&candle(104.0, 111.0)), vec![id]);
+        assert!(alerts.get(id).unwrap().triggered);
+        // Ne se redéclenche pas sur la bougie suivante
+        assert!(alerts.check(&candle(108.0, 115.0)).is_empty());
+    }
+
+    #[test]
+    fn test_below_alert_uses_low() {
+        let mut alerts = AlertsState::default();
+        let id = alerts.add(90.0, 100.0);
+        assert!(alerts.check(&candle(91.0, 120.0)).is_empty());
+        assert_eq!(alerts.check(&candle(89.5, 95.0)), vec![id]);
+        assert!(alerts.remove(id));
+        assert!(alerts.alerts.is_empty());
+    }
+}
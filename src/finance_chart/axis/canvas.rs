@@ -3,12 +3,14 @@
 //! Architecture Elm : émet des messages pour les mutations d'état,
 //! reçoit des références immuables pour le rendu.
 
-use iced::widget::canvas::{Canvas, Frame, Geometry, Program, Text, Action, Path};
+use iced::widget::canvas::{Canvas, Frame, Geometry, Program, Text, Action, Path, Stroke};
 use iced::{Color, Element, Event, Length, Point, Rectangle, Size};
 use iced::mouse;
 
 use crate::finance_chart::state::ChartState;
-use crate::finance_chart::render::{calculate_nice_step, calculate_nice_time_step, format_time};
+use crate::finance_chart::render::{calculate_nice_step, calculate_nice_time_step, format_time, visible_renko_range, price_grid_levels};
+use crate::finance_chart::render::crosshair::{CrosshairStyle, draw_axis_price_label, draw_axis_time_label, format_crosshair_time};
+use crate::finance_chart::settings::{format_price, ChartRenderMode, ChartStyle};
 use crate::finance_chart::messages::{YAxisMessage, XAxisMessage};
 use super::style::AxisStyle;
 
@@ -18,9 +20,18 @@ pub const Y_AXIS_WIDTH: f32 = 43.0;
 /// Hauteur du canvas X (axe du temps)
 pub const X_AXIS_HEIGHT: f32 = 30.0;
 
-/// Convertit un intervalle (ex: "1h", "15m") en secondes
-fn interval_to_seconds(interval: &str) -> i64 {
-    match interval {
+/// Hauteur de la zone cliquable du cadenas (haut de l'axe Y)
+const LOCK_ZONE_HEIGHT: f32 = 18.0;
+
+/// Hauteur de la zone cliquable d'inversion de l'échelle (bas de l'axe Y)
+const INVERT_ZONE_HEIGHT: f32 = 18.0;
+
+/// Hauteur des étiquettes d'alerte sur l'axe Y (zone cliquable pour les supprimer)
+const ALERT_LABEL_HEIGHT: f32 = 14.0;
+
+/// Convertit un intervalle (ex: "1h", "15m") en secondes (`None` si inconnu)
+fn interval_to_seconds(interval: &str) -> Option<i64> {
+    let seconds = match interval {
         "1m" => 60,
         "3m" => 180,
         "5m" => 300,
@@ -36,26 +47,24 @@ fn interval_to_seconds(interval: &str) -> i64 {
         "3d" => 259200,
         "1w" => 604800,
         "1M" => 2592000, // Approximation (30 jours)
-        _ => 3600, // Défaut: 1h
-    }
+        _ => return None,
+    };
+    Some(seconds)
 }
 
-/// Calcule le temps restant avant la clôture de la bougie
-fn calculate_time_remaining(candle_timestamp: i64, interval: &str) -> String {
-    let now = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap()
-        .as_secs() as i64;
-    
-    let interval_seconds = interval_to_seconds(interval);
-    let next_close_time = candle_timestamp + interval_seconds;
-    let remaining_seconds = next_close_time - now;
-    
-    if remaining_seconds <= 0 {
-        return "0s".to_string();
-    }
-    
-    // Formater le temps restant
+/// Secondes restantes avant la clôture de la bougie en cours
+///
+/// `None` si l'intervalle est inconnu ou si la bougie est déjà close : la série
+/// n'est alors pas mise à jour en direct (historique seul) et le compte à rebours
+/// n'a pas de sens.
+fn candle_time_remaining(candle_timestamp: i64, interval: &str, now: i64) -> Option<i64> {
+    let interval_seconds = interval_to_seconds(interval)?;
+    let remaining_seconds = candle_timestamp + interval_seconds - now;
+    (remaining_seconds > 0 && remaining_seconds <= interval_seconds).then_some(remaining_seconds)
+}
+
+/// Formate le temps restant avant la clôture de la bougie
+fn format_time_remaining(remaining_seconds: i64) -> String {
     let hours = remaining_seconds / 3600;
     let minutes = (remaining_seconds % 3600) / 60;
     let seconds = remaining_seconds % 60;
@@ -80,17 +89,52 @@ pub struct YAxisState {
     drag_start_y: Option<f32>,
     /// Est-ce qu'on est en train de drag
     is_dragging: bool,
+    /// Le drag est sorti de l'axe vers le graphique : on place une alerte
+    placing_alert: bool,
 }
 
 /// Program pour l'axe Y (prix)
 /// Reçoit une référence immutable, émet des messages
 pub struct YAxisProgram<'a> {
     chart_state: &'a ChartState,
+    /// Style du graphique (précision des prix)
+    chart_style: &'a ChartStyle,
 }
 
 impl<'a> YAxisProgram<'a> {
-    pub fn new(chart_state: &'a ChartState) -> Self {
-        Self { chart_state }
+    pub fn new(chart_state: &'a ChartState, chart_style: &'a ChartStyle) -> Self {
+        Self { chart_state, chart_style }
+    }
+}
+
+impl<'a> YAxisProgram<'a> {
+    /// Dessine les niveaux en pourcentage du mode comparaison
+    fn draw_comparison_labels(&self, frame: &mut Frame, style: &AxisStyle, bounds: Rectangle) {
+        let series = self.chart_state.comparison_candles();
+        let Some(viewport) = self.chart_state.comparison_viewport(&series) else {
+            return;
+        };
+
+        for percent in price_grid_levels(viewport.price_scale()) {
+            let y = viewport.price_scale().price_to_y(percent);
+            if y >= 0.0 && y <= viewport.height() {
+                frame.fill_text(Text {
+                    content: format!("{:+.1}%", percent),
+                    position: Point::new(5.0, y - 6.0),
+                    color: style.text_color,
+                    size: iced::Pixels(style.text_size),
+                    ..Text::default()
+                });
+            }
+        }
+
+        // Étiquette du crosshair, convertie dans l'échelle en pourcentage
+        let crosshair_style = CrosshairStyle::from_chart_style(self.chart_style);
+        if let Some((_, price)) = self.chart_state.crosshair.filter(|_| crosshair_style.show_labels) {
+            let y = self.chart_state.viewport.price_scale().price_to_y(price);
+            let label = format!("{:+.2}%", viewport.price_scale().y_to_price(y));
+            draw_axis_price_label(frame, &crosshair_style, y, bounds.width, &label);
+        }
     }
 }
 
@@ -115,33 +159,26 @@ impl<'a> Program<YAxisMessage> for YAxisProgram<'a> {
             style.background_color,
         );
 
+        // Mode comparaison : l'axe est gradué en variation (%) depuis le bord gauche
+        if self.chart_state.comparison_mode {
+            self.draw_comparison_labels(&mut frame, &style, bounds);
+            return vec![frame.into_geometry()];
+        }
+
         let viewport = &self.chart_state.viewport;
 
-        // Calculer les niveaux de prix
+        // Calculer les niveaux de prix (mêmes niveaux que la grille)
         let (min_price, max_price) = viewport.price_scale().price_range();
-        let price_range = max_price - min_price;
-        let price_step = calculate_nice_step(price_range);
+        let price_step = calculate_nice_step(max_price - min_price);
+        let is_log = viewport.price_scale().is_log();
 
-        // Trouver le premier niveau rond >= min_price
-        let first_price = (min_price / price_step).ceil() * price_step;
-
-        let mut price = first_price;
-        while price <= max_price {
+        for price in price_grid_levels(viewport.price_scale()) {
             let y = viewport.price_scale().price_to_y(price);
 
             // Ne dessiner que si visible
             if y >= 0.0 && y <= viewport.height() {
-                // Formater le prix
-                let label = if price_step >= 1.0 {
-                    format!("{:.0}", price)
-                } else if price_step >= 0.1 {
-                    format!("{:.1}", price)
-                } else {
-                    format!("{:.2}", price)
-                };
-
                 let text = Text {
-                    content: label,
+                    content: format_axis_price(price, price_step, is_log, self.chart_style),
                     position: Point::new(5.0, y - 6.0),
                     color: style.text_color,
                     size: iced::Pixels(style.text_size),
@@ -149,56 +186,121 @@ impl<'a> Program<YAxisMessage> for YAxisProgram<'a> {
                 };
                 frame.fill_text(text);
             }
+        }
 
-            price += price_step;
+        // Indicateur d'inversion en bas de l'axe : atténué tant que l'échelle est normale
+        let inverted = viewport.price_scale().is_inverted();
+        frame.fill_text(Text {
+            content: "INV".to_string(),
+            position: Point::new(5.0, bounds.height - 16.0),
+            color: if inverted { style.text_color } else { Color { a: 0.35, ..style.text_color } },
+            size: iced::Pixels(style.text_size),
+            ..Text::default()
+        });
+
+        // Indicateur d'échelle logarithmique, au-dessus de l'indicateur d'inversion
+        if is_log {
+            let text = Text {
+                content: "LOG".to_string(),
+                position: Point::new(5.0, bounds.height - 16.0 - INVERT_ZONE_HEIGHT),
+                color: style.text_color,
+                size: iced::Pixels(style.text_size),
+                ..Text::default()
+            };
+            frame.fill_text(text);
         }
 
+        // Étiquettes des alertes de prix (clic pour supprimer)
+        for alert in &self.chart_state.alerts.alerts {
+            let y = viewport.price_scale().price_to_y(alert.price);
+            if y < 0.0 || y > viewport.height() {
+                continue;
+            }
+            let bg_color = if alert.triggered {
+                Color::from_rgba(0.4, 0.4, 0.4, 1.0)
+            } else {
+                Color::from_rgba(0.7, 0.5, 0.0, 1.0)
+            };
+            let rect = Path::rectangle(
+                Point::new(2.0, y - ALERT_LABEL_HEIGHT / 2.0),
+                Size::new(bounds.width - 4.0, ALERT_LABEL_HEIGHT),
+            );
+            frame.fill(&rect, bg_color);
+            frame.fill_text(Text {
+                content: format!("{} ×", format_price(alert.price, self.chart_style)),
+                position: Point::new(5.0, y - 6.0),
+                color: Color::WHITE,
+                size: iced::Pixels(style.text_size),
+                ..Text::default()
+            });
+        }
+
+        // Cadenas en haut de l'axe : fermé quand la plage de prix est verrouillée
+        draw_lock_indicator(&mut frame, !viewport.auto_scale(), &style);
+
         // === Dessiner le temps restant avant clôture de la bougie ===
-        if let Some(last_candle) = self.chart_state.last_candle() {
-            // Obtenir l'intervalle de la série active
-            if let Some(active_series) = self.chart_state.series_manager.active_series().next() {
-                let current_price = last_candle.close;
-                let y = viewport.price_scale().price_to_y(current_price);
+        // Uniquement pour les séries Binance (mises à jour en direct) dont la bougie est en cours
+        let is_live_series = self.chart_state.series_manager
+            .active_series()
+            .next()
+            .is_some_and(|series| crate::app::realtime::is_binance_format(&series.id.name));
+        let countdown = self.chart_state.last_candle()
+            .filter(|_| is_live_series)
+            .zip(self.chart_state.displayed_interval())
+            .and_then(|(last_candle, interval)| {
+                let now = crate::app::realtime::current_timestamp();
+                candle_time_remaining(last_candle.timestamp, interval, now)
+                    .map(|remaining| (last_candle, remaining))
+            });
+        if let Some((last_candle, time_remaining)) = countdown {
+            let current_price = last_candle.close;
+            let y = viewport.price_scale().price_to_y(current_price);
+            
+            // Ne dessiner que si visible
+            if y >= 0.0 && y <= viewport.height() {
+                // Couleur selon si le prix est haussier ou baissier
+                let is_bullish = last_candle.close >= last_candle.open;
+                let bg_color = if is_bullish {
+                    Color::from_rgba(0.0, 0.5, 0.0, 1.0) // Vert foncé opaque
+                } else {
+                    Color::from_rgba(0.5, 0.0, 0.0, 1.0) // Rouge foncé opaque
+                };
                 
-                // Ne dessiner que si visible
-                if y >= 0.0 && y <= viewport.height() {
-                    // Couleur selon si le prix est haussier ou baissier
-                    let is_bullish = last_candle.close >= last_candle.open;
-                    let bg_color = if is_bullish {
-                        Color::from_rgba(0.0, 0.5, 0.0, 1.0) // Vert foncé opaque
-                    } else {
-                        Color::from_rgba(0.5, 0.0, 0.0, 1.0) // Rouge foncé opaque
-                    };
-                    
-                    // Calculer le temps restant avant la clôture
-                    let time_remaining = calculate_time_remaining(last_candle.timestamp, &active_series.interval);
-                    
-                    // Dimensions du rectangle
-                    let rect_height = 16.0;
-                    let rect_width = bounds.width - 4.0;
-                    let rect_x = 2.0;
-                    let rect_y = y - rect_height / 2.0;
-                    
-                    // Dessiner le rectangle de fond
-                    let rect = Path::rectangle(
-                        Point::new(rect_x, rect_y),
-                        Size::new(rect_width, rect_height),
-                    );
-                    frame.fill(&rect, bg_color);
-                    
-                    // Afficher le temps restant
-                    let time_text = Text {
-                        content: time_remaining,
-                        position: Point::new(5.0, y - 5.0),
-                        color: Color::WHITE,
-                        size: iced::Pixels(style.text_size),
-                        ..Text::default()
-                    };
-                    frame.fill_text(time_text);
-                }
+                let time_remaining = format_time_remaining(time_remaining);
+                
+                // Dimensions du rectangle
+                let rect_height = 16.0;
+                let rect_width = bounds.width - 4.0;
+                let rect_x = 2.0;
+                let rect_y = y - rect_height / 2.0;
+                
+                // Dessiner le rectangle de fond
+                let rect = Path::rectangle(
+                    Point::new(rect_x, rect_y),
+                    Size::new(rect_width, rect_height),
+                );
+                frame.fill(&rect, bg_color);
+                
+                // Afficher le temps restant
+                let time_text = Text {
+                    content: time_remaining,
+                    position: Point::new(5.0, y - 5.0),
+                    color: Color::WHITE,
+                    size: iced::Pixels(style.text_size),
+                    ..Text::default()
+                };
+                frame.fill_text(time_text);
             }
         }
 
+        // Étiquette du prix du crosshair (au-dessus des autres étiquettes)
+        let crosshair_style = CrosshairStyle::from_chart_style(self.chart_style);
+        if let Some((_, price)) = self.chart_state.crosshair.filter(|_| crosshair_style.show_labels) {
+            let y = viewport.price_scale().price_to_y(price);
+            let label = format_price(price, self.chart_style);
+            draw_axis_price_label(&mut frame, &crosshair_style, y, bounds.width, &label);
+        }
+
         vec![frame.into_geometry()]
     }
 
@@ -212,37 +314,67 @@ impl<'a> Program<YAxisMessage> for YAxisProgram<'a> {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 if let Some(position) = cursor.position_in(bounds) {
+                    // Clic sur le cadenas : verrouiller/déverrouiller l'ajustement automatique
+                    if position.y <= LOCK_ZONE_HEIGHT {
+                        return Some(Action::publish(YAxisMessage::ToggleAutoScale));
+                    }
+                    // Clic sur l'indicateur du bas : inverser le sens de l'échelle
+                    if position.y >= bounds.height - INVERT_ZONE_HEIGHT {
+                        return Some(Action::publish(YAxisMessage::ToggleInverted));
+                    }
+                    // Clic sur l'étiquette d'une alerte : la supprimer
+                    let price_scale = self.chart_state.viewport.price_scale();
+                    if let Some(alert) = self.chart_state.alerts.alerts.iter().find(|alert| {
+                        (price_scale.price_to_y(alert.price) - position.y).abs() <= ALERT_LABEL_HEIGHT / 2.0
+                    }) {
+                        return Some(Action::publish(YAxisMessage::RemoveAlert { id: alert.id }));
+                    }
                     axis_state.is_dragging = true;
                     axis_state.drag_start_y = Some(position.y);
                     return Some(Action::request_redraw());
                 }
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let placing_alert = axis_state.placing_alert;
                 axis_state.is_dragging = false;
                 axis_state.drag_start_y = None;
+                axis_state.placing_alert = false;
+
+                // Drag depuis l'axe relâché sur le graphique : créer une alerte à ce prix
+                if placing_alert {
+                    if let Some(position) = cursor.position() {
+                        let y = position.y - bounds.y;
+                        if position.x < bounds.x && y >= 0.0 && y <= self.chart_state.viewport.height() {
+                            let price = self.chart_state.viewport.price_scale().y_to_price(y);
+                            return Some(Action::publish(YAxisMessage::CreateAlert { price }));
+                        }
+                    }
+                }
                 return Some(Action::request_redraw());
             }
+            // Clic droit : basculer entre échelle linéaire et logarithmique
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) if cursor.position_in(bounds).is_some() => {
+                return Some(Action::publish(YAxisMessage::ToggleLogScale));
+            }
             Event::Mouse(mouse::Event::CursorMoved { position }) => {
                 if axis_state.is_dragging {
+                    // Sortie de l'axe vers la gauche : le drag place une alerte au lieu d'étirer
+                    if position.x < bounds.x {
+                        axis_state.placing_alert = true;
+                    }
+                    if axis_state.placing_alert {
+                        return Some(Action::request_redraw());
+                    }
+
                     let current_y = position.y - bounds.y;
                     
                     if let Some(start_y) = axis_state.drag_start_y {
                         let delta_y = current_y - start_y;
                         
-                        // Calculer le facteur de zoom basé sur le déplacement
-                        let zoom_factor = if delta_y.abs() > 2.0 {
-                            if delta_y > 0.0 {
-                                1.02 // Zoom out
-                            } else {
-                                0.98 // Zoom in
-                            }
-                        } else {
-                            1.0
-                        };
-                        
-                        if zoom_factor != 1.0 {
+                        // Étirement proportionnel au déplacement (bas = dézoom, haut = zoom)
+                        if delta_y != 0.0 {
                             axis_state.drag_start_y = Some(current_y);
-                            return Some(Action::publish(YAxisMessage::ZoomVertical { factor: zoom_factor }));
+                            return Some(Action::publish(YAxisMessage::DragScale { delta: delta_y }));
                         }
                     }
                     
@@ -269,9 +401,126 @@ impl<'a> Program<YAxisMessage> for YAxisProgram<'a> {
     }
 }
 
+/// Formate un niveau de prix de l'axe : précision fixe si configurée, sinon selon le pas
+/// (en log, la précision dépend de l'ordre de grandeur du niveau)
+fn format_axis_price(price: f64, price_step: f64, is_log: bool, chart_style: &ChartStyle) -> String {
+    let label_step = if is_log { price } else { price_step };
+    if let Some(decimals) = chart_style.price_decimals {
+        format!("{:.*}", decimals, price)
+    } else if label_step >= 1.0 {
+        format!("{:.0}", price)
+    } else if label_step >= 0.1 {
+        format!("{:.1}", price)
+    } else if label_step >= 0.01 || !is_log {
+        format!("{:.2}", price)
+    } else {
+        let decimals = (-price.log10().floor()) as usize;
+        format!("{:.*}", decimals, price)
+    }
+}
+
+/// Dessine un petit cadenas (ouvert = auto, fermé = plage verrouillée)
+fn draw_lock_indicator(frame: &mut Frame, locked: bool, style: &AxisStyle) {
+    let color = if locked {
+        style.text_color
+    } else {
+        Color { a: 0.35, ..style.text_color }
+    };
+    let body_x = 6.0;
+    let body_y = 9.0;
+    let body = Path::rectangle(Point::new(body_x, body_y), Size::new(9.0, 6.0));
+    frame.fill(&body, color);
+
+    // Anse : décalée vers le haut quand le cadenas est ouvert
+    let lift = if locked { 0.0 } else { 2.5 };
+    let shackle = Path::new(|builder| {
+        builder.move_to(Point::new(body_x + 2.0, body_y));
+        builder.line_to(Point::new(body_x + 2.0, body_y - 3.5 - lift));
+        builder.line_to(Point::new(body_x + 7.0, body_y - 3.5 - lift));
+        builder.line_to(Point::new(body_x + 7.0, body_y - lift));
+    });
+    frame.stroke(&shackle, Stroke::default().with_color(color).with_width(1.5));
+}
+
 /// Crée un élément canvas pour l'axe Y
-pub fn y_axis<'a>(chart_state: &'a ChartState) -> Element<'a, YAxisMessage> {
-    Canvas::new(YAxisProgram::new(chart_state))
+pub fn y_axis<'a>(chart_state: &'a ChartState, chart_style: &'a ChartStyle) -> Element<'a, YAxisMessage> {
+    Canvas::new(YAxisProgram::new(chart_state, chart_style))
+        .width(Length::Fixed(Y_AXIS_WIDTH))
+        .height(Length::Fill)
+        .into()
+}
+
+// ============================================================================
+// Canvas Y gauche (Axe des prix des séries superposées)
+// ============================================================================
+
+/// Program pour l'axe Y de gauche
+///
+/// Lecture seule : son échelle est recalculée à chaque rendu à partir des séries
+/// assignées à gauche (voir `ChartState::left_axis_viewport`).
+pub struct YAxisLeftProgram<'a> {
+    chart_state: &'a ChartState,
+    /// Style du graphique (précision des prix)
+    chart_style: &'a ChartStyle,
+}
+
+impl<'a> Program<YAxisMessage> for YAxisLeftProgram<'a> {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: Rectangle,
+        _cursor: iced::mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let Some(viewport) = self.chart_state.left_axis_viewport() else {
+            return vec![];
+        };
+        let mut frame = Frame::new(renderer, bounds.size());
+        let style = AxisStyle::default();
+
+        // Fond semi-transparent : l'axe est superposé au bord gauche du graphique
+        frame.fill_rectangle(
+            Point::ORIGIN,
+            bounds.size(),
+            Color { a: 0.8, ..style.background_color },
+        );
+
+        let (min_price, max_price) = viewport.price_scale().price_range();
+        let price_step = calculate_nice_step(max_price - min_price);
+        let is_log = viewport.price_scale().is_log();
+
+        for price in price_grid_levels(viewport.price_scale()) {
+            let y = viewport.price_scale().price_to_y(price);
+            if y >= 0.0 && y <= viewport.height() {
+                frame.fill_text(Text {
+                    content: format_axis_price(price, price_step, is_log, self.chart_style),
+                    position: Point::new(5.0, y - 6.0),
+                    color: style.text_color,
+                    size: iced::Pixels(style.text_size),
+                    ..Text::default()
+                });
+            }
+        }
+
+        // Étiquette du prix du crosshair, exprimé dans l'échelle de gauche
+        let crosshair_style = CrosshairStyle::from_chart_style(self.chart_style);
+        if let Some((_, price)) = self.chart_state.crosshair.filter(|_| crosshair_style.show_labels) {
+            let y = self.chart_state.viewport.price_scale().price_to_y(price);
+            let left_price = viewport.price_scale().y_to_price(y);
+            let label = format_price(left_price, self.chart_style);
+            draw_axis_price_label(&mut frame, &crosshair_style, y, bounds.width, &label);
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+/// Crée un élément canvas pour l'axe Y de gauche (séries superposées assignées à gauche)
+pub fn y_axis_left<'a>(chart_state: &'a ChartState, chart_style: &'a ChartStyle) -> Element<'a, YAxisMessage> {
+    Canvas::new(YAxisLeftProgram { chart_state, chart_style })
         .width(Length::Fixed(Y_AXIS_WIDTH))
         .height(Length::Fill)
         .into()
@@ -293,11 +542,41 @@ pub struct XAxisState {
 /// Program pour l'axe X (temps)
 pub struct XAxisProgram<'a> {
     chart_state: &'a ChartState,
+    /// Style du graphique (en Renko, l'axe affiche les indices de briques)
+    chart_style: &'a ChartStyle,
 }
 
 impl<'a> XAxisProgram<'a> {
-    pub fn new(chart_state: &'a ChartState) -> Self {
-        Self { chart_state }
+    pub fn new(chart_state: &'a ChartState, chart_style: &'a ChartStyle) -> Self {
+        Self { chart_state, chart_style }
+    }
+
+    /// Dessine les indices des briques Renko visibles
+    fn draw_renko_labels(&self, frame: &mut Frame, style: &AxisStyle) {
+        let viewport = &self.chart_state.viewport;
+        let bricks = self.chart_state.renko_bricks();
+        let visible = visible_renko_range(&bricks, viewport);
+        if visible.is_empty() {
+            return;
+        }
+
+        let slot_width = viewport.width() / visible.len() as f32;
+        let index_step = (calculate_nice_step(visible.len() as f64) as usize).max(1);
+
+        // Premier indice "rond" dans la plage visible
+        let first_index = visible.start.div_ceil(index_step) * index_step;
+
+        for index in (first_index..visible.end).step_by(index_step) {
+            let x = (index - visible.start) as f32 * slot_width + slot_width / 2.0;
+            let text = Text {
+                content: format!("#{}", index),
+                position: Point::new(x - 10.0, 8.0),
+                color: style.text_color,
+                size: iced::Pixels(style.text_size),
+                ..Text::default()
+            };
+            frame.fill_text(text);
+        }
     }
 }
 
@@ -322,6 +601,12 @@ impl<'a> Program<XAxisMessage> for XAxisProgram<'a> {
             style.background_color,
         );
 
+        // En Renko, l'axe n'est pas linéaire en temps : afficher les indices de briques
+        if self.chart_style.render_mode == ChartRenderMode::Renko {
+            self.draw_renko_labels(&mut frame, &style);
+            return vec![frame.into_geometry()];
+        }
+
         let viewport = &self.chart_state.viewport;
 
         // Calculer les timestamps
@@ -353,6 +638,17 @@ impl<'a> Program<XAxisMessage> for XAxisProgram<'a> {
             time += time_step;
         }
 
+        // Étiquette du temps du crosshair
+        let crosshair_style = CrosshairStyle::from_chart_style(self.chart_style);
+        if let Some((time, _)) = self.chart_state.crosshair.filter(|_| crosshair_style.show_labels) {
+            let x = viewport.time_scale().time_to_x(time);
+            let interval_seconds = self.chart_state.displayed_interval()
+                .and_then(interval_to_seconds)
+                .unwrap_or(0);
+            let label = format_crosshair_time(time, interval_seconds);
+            draw_axis_time_label(&mut frame, &crosshair_style, x, bounds.width, &label);
+        }
+
         vec![frame.into_geometry()]
     }
 
@@ -383,19 +679,10 @@ impl<'a> Program<XAxisMessage> for XAxisProgram<'a> {
                     if let Some(start_x) = axis_state.drag_start_x {
                         let delta_x = current_x - start_x;
                         
-                        let zoom_factor = if delta_x.abs() > 2.0 {
-                            if delta_x > 0.0 {
-                                1.02 // Zoom out
-                            } else {
-                                0.98 // Zoom in
-                            }
-                        } else {
-                            1.0
-                        };
-                        
-                        if zoom_factor != 1.0 {
+                        // Étirement proportionnel au déplacement (gauche = plus de bougies)
+                        if delta_x != 0.0 {
                             axis_state.drag_start_x = Some(current_x);
-                            return Some(Action::publish(XAxisMessage::ZoomHorizontal { factor: zoom_factor }));
+                            return Some(Action::publish(XAxisMessage::DragScale { delta: delta_x }));
                         }
                     }
                     
@@ -423,10 +710,25 @@ impl<'a> Program<XAxisMessage> for XAxisProgram<'a> {
 }
 
 /// Crée un élément canvas pour l'axe X
-pub fn x_axis<'a>(chart_state: &'a ChartState) -> Element<'a, XAxisMessage> {
-    Canvas::new(XAxisProgram::new(chart_state))
+pub fn x_axis<'a>(chart_state: &'a ChartState, chart_style: &'a ChartStyle) -> Element<'a, XAxisMessage> {
+    Canvas::new(XAxisProgram::new(chart_state, chart_style))
         .width(Length::Fill)
         .height(Length::Fixed(X_AXIS_HEIGHT))
         .into()
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_candle_time_remaining() {
+        // Bougie 1h ouverte à t=3600, il est t=5400 : 30 minutes restantes
+        assert_eq!(candle_time_remaining(3600, "1h", 5400), Some(1800));
+        assert_eq!(format_time_remaining(1800), "30m 0s");
+        // Bougie déjà close : série historique, pas de compte à rebours
+        assert_eq!(candle_time_remaining(3600, "1h", 7200), None);
+        // Intervalle inconnu
+        assert_eq!(candle_time_remaining(3600, "7x", 5400), None);
+    }
+}
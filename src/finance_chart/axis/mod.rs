@@ -6,6 +6,6 @@ pub mod canvas;
 pub mod style;
 
 // Ré-exports
-pub use canvas::{x_axis, y_axis, X_AXIS_HEIGHT, Y_AXIS_WIDTH};
+pub use canvas::{x_axis, y_axis, y_axis_left, X_AXIS_HEIGHT, Y_AXIS_WIDTH};
 pub use style::AxisStyle;
 
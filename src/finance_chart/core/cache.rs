@@ -1,11 +1,20 @@
-//! Système de cache pour les calculs de plages
+//! Système de cache pour les calculs de plages et les bougies téléchargées
 //!
 //! Évite de recalculer les plages de prix et de temps
-//! qui sont coûteuses avec de grandes séries de données.
+//! qui sont coûteuses avec de grandes séries de données, et de
+//! retélécharger des bougies déjà récupérées auprès du provider.
 
 use std::cell::{Cell, RefCell};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use super::Candle;
+use super::resample::{bucket_start, parse_interval};
+use crate::finance_chart::providers::ProviderType;
 
 /// Cache pour les plages de prix calculées
 /// Utilise Cell/RefCell pour permettre la mutation interne avec des références immuables
@@ -95,3 +104,288 @@ impl TimeRangeCache {
     }
 }
 
+
+/// Nombre de séries gardées en mémoire par défaut par le cache de bougies
+pub const DEFAULT_CANDLE_CACHE_CAPACITY: usize = 8;
+
+/// Clé d'une série dans le cache de bougies : (provider, symbole, intervalle)
+type CandleCacheKey = (ProviderType, String, String);
+
+/// Bougies d'une série connues du cache
+#[derive(Debug, Default)]
+struct CachedCandles {
+    /// Plages (début, fin) déjà récupérées auprès du provider, triées et disjointes
+    ranges: Vec<(i64, i64)>,
+    /// Bougies triées par timestamp
+    candles: Vec<Candle>,
+}
+
+/// Format du fichier de cache d'une série
+#[derive(Serialize, Deserialize)]
+struct CandleCacheFile {
+    ranges: Vec<(i64, i64)>,
+    /// (timestamp, open, high, low, close, volume)
+    candles: Vec<(i64, f64, f64, f64, f64, f64)>,
+}
+
+impl CachedCandles {
+    /// Indique si la plage [start, end] a déjà été récupérée en entier
+    fn covers(&self, start: i64, end: i64) -> bool {
+        self.ranges.iter().any(|&(from, to)| from <= start && end <= to)
+    }
+
+    /// Ajoute une plage récupérée, en fusionnant les plages qui se chevauchent ou se touchent
+    fn add_range(&mut self, start: i64, end: i64) {
+        self.ranges.push((start, end));
+        self.ranges.sort_unstable();
+        let mut merged: Vec<(i64, i64)> = Vec::with_capacity(self.ranges.len());
+        for &(from, to) in &self.ranges {
+            match merged.last_mut() {
+                Some(last) if from <= last.1 => last.1 = last.1.max(to),
+                _ => merged.push((from, to)),
+            }
+        }
+        self.ranges = merged;
+    }
+
+    /// Fusionne des bougies (une bougie existante est remplacée à timestamp égal)
+    fn merge(&mut self, candles: &[Candle]) {
+        let mut by_timestamp: std::collections::BTreeMap<i64, Candle> =
+            self.candles.iter().map(|candle| (candle.timestamp, *candle)).collect();
+        by_timestamp.extend(candles.iter().map(|candle| (candle.timestamp, *candle)));
+        self.candles = by_timestamp.into_values().collect();
+    }
+
+    fn load(path: &Path) -> Option<Self> {
+        let content = std::fs::read_to_string(path).ok()?;
+        let file: CandleCacheFile = serde_json::from_str(&content).ok()?;
+        Some(Self {
+            ranges: file.ranges,
+            candles: file.candles
+                .into_iter()
+                .map(|(timestamp, open, high, low, close, volume)| Candle::new(timestamp, open, high, low, close, volume))
+                .collect(),
+        })
+    }
+
+    fn save(&self, path: &Path) -> std::io::Result<()> {
+        let file = CandleCacheFile {
+            ranges: self.ranges.clone(),
+            candles: self.candles
+                .iter()
+                .map(|c| (c.timestamp, c.open, c.high, c.low, c.close, c.volume))
+                .collect(),
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string(&file).map_err(std::io::Error::other)?;
+        std::fs::write(path, content)
+    }
+}
+
+/// Séries chargées en mémoire, de la moins récemment utilisée à la plus récente
+#[derive(Debug, Default)]
+struct CandleCacheEntries {
+    entries: HashMap<CandleCacheKey, CachedCandles>,
+    order: VecDeque<CandleCacheKey>,
+}
+
+/// Cache disque des bougies déjà récupérées auprès du provider
+///
+/// Chaque série (provider, symbole, intervalle) est stockée dans son propre fichier avec
+/// les plages déjà téléchargées : une plage entièrement couverte est servie depuis le
+/// disque sans appel API. Les `capacity` séries les plus récemment utilisées restent
+/// en mémoire (LRU). Partageable entre tâches asynchrones (`Arc<CandleCache>`) : les
+/// variantes `_async` font les accès disque sur un thread bloquant.
+#[derive(Debug)]
+pub struct CandleCache {
+    /// Dossier des fichiers de cache
+    dir: PathBuf,
+    /// Nombre maximal de séries gardées en mémoire
+    capacity: usize,
+    entries: Mutex<CandleCacheEntries>,
+}
+
+impl CandleCache {
+    /// Crée un cache stocké dans `dir`, gardant au plus `capacity` séries en mémoire
+    pub fn new(dir: impl Into<PathBuf>, capacity: usize) -> Self {
+        Self {
+            dir: dir.into(),
+            capacity: capacity.max(1),
+            entries: Mutex::new(CandleCacheEntries::default()),
+        }
+    }
+
+    /// Bougies de la plage [start, end] si elle a déjà été récupérée en entier
+    ///
+    /// `None` (cache miss) si une partie de la plage n'a jamais été téléchargée.
+    pub fn get_range(&self, provider: ProviderType, symbol: &str, interval: &str, start: i64, end: i64) -> Option<Vec<Candle>> {
+        let mut entries = self.entries.lock().ok()?;
+        let cached = self.entry(&mut entries, provider, symbol, interval);
+        if !cached.covers(start, end) {
+            return None;
+        }
+        let from = cached.candles.partition_point(|c| c.timestamp < start);
+        let to = cached.candles.partition_point(|c| c.timestamp <= end);
+        Some(cached.candles[from..to].to_vec())
+    }
+
+    /// `get_range` exécuté sur un thread bloquant (lecture éventuelle du fichier)
+    pub async fn get_range_async(
+        self: Arc<Self>,
+        provider: ProviderType,
+        symbol: String,
+        interval: String,
+        start: i64,
+        end: i64,
+    ) -> Option<Vec<Candle>> {
+        tokio::task::spawn_blocking(move || self.get_range(provider, &symbol, &interval, start, end))
+            .await
+            .ok()
+            .flatten()
+    }
+
+    /// Enregistre les bougies récupérées pour la plage [start, end] et met à jour le fichier
+    ///
+    /// La plage est marquée comme récupérée même si elle ne contient aucune bougie
+    /// (marché fermé, période antérieure à la cotation). La bougie de l'intervalle en
+    /// cours n'est pas définitive : elle est ignorée et la plage s'arrête avant elle.
+    pub fn insert_range(&self, provider: ProviderType, symbol: &str, interval: &str, start: i64, end: i64, candles: &[Candle]) -> std::io::Result<()> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs() as i64)
+            .unwrap_or(i64::MAX);
+        self.insert_range_at(provider, symbol, interval, start, end, candles, now)
+    }
+
+    /// `insert_range` exécuté sur un thread bloquant (réécriture du fichier)
+    pub async fn insert_range_async(
+        self: Arc<Self>,
+        provider: ProviderType,
+        symbol: String,
+        interval: String,
+        start: i64,
+        end: i64,
+        candles: Vec<Candle>,
+    ) -> std::io::Result<()> {
+        tokio::task::spawn_blocking(move || self.insert_range(provider, &symbol, &interval, start, end, &candles))
+            .await
+            .unwrap_or_else(|e| Err(std::io::Error::other(e)))
+    }
+
+    /// `insert_range` à l'instant `now`
+    #[allow(clippy::too_many_arguments)]
+    fn insert_range_at(
+        &self,
+        provider: ProviderType,
+        symbol: &str,
+        interval: &str,
+        start: i64,
+        end: i64,
+        candles: &[Candle],
+        now: i64,
+    ) -> std::io::Result<()> {
+        // Exclure l'intervalle en cours (début du paquet contenant `now`)
+        let end = match parse_interval(interval) {
+            Some(span) => end.min(bucket_start(now, span) - 1),
+            None => end.min(now),
+        };
+        if end < start {
+            return Ok(());
+        }
+        let complete = &candles[..candles.partition_point(|c| c.timestamp <= end)];
+
+        let mut entries = self.entries
+            .lock()
+            .map_err(|_| std::io::Error::other("cache de bougies verrouillé"))?;
+        let path = self.file_path(provider, symbol, interval);
+        let cached = self.entry(&mut entries, provider, symbol, interval);
+        cached.merge(complete);
+        cached.add_range(start, end);
+        cached.save(&path)
+    }
+
+    /// Série en mémoire (chargée depuis le disque si besoin), marquée comme la plus récente
+    fn entry<'a>(&self, entries: &'a mut CandleCacheEntries, provider: ProviderType, symbol: &str, interval: &str) -> &'a mut CachedCandles {
+        let key = (provider, symbol.to_string(), interval.to_string());
+        entries.order.retain(|existing| *existing != key);
+        entries.order.push_back(key.clone());
+        if !entries.entries.contains_key(&key) {
+            let loaded = CachedCandles::load(&self.file_path(provider, symbol, interval)).unwrap_or_default();
+            entries.entries.insert(key.clone(), loaded);
+        }
+        // Les séries évincées restent sur disque
+        while entries.order.len() > self.capacity {
+            if let Some(evicted) = entries.order.pop_front() {
+                entries.entries.remove(&evicted);
+            }
+        }
+        entries.entries.entry(key).or_default()
+    }
+
+    /// Fichier de cache d'une série : {dir}/{provider}/{symbol}/{intervalle}.json
+    fn file_path(&self, provider: ProviderType, symbol: &str, interval: &str) -> PathBuf {
+        // Même nommage que les fichiers de séries (1m et 1M ne doivent pas entrer en collision)
+        let file_name = crate::finance_chart::data_loader::interval_to_filename(interval);
+        self.dir.join(provider.display_name()).join(symbol).join(file_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candle(timestamp: i64) -> Candle {
+        Candle::new(timestamp, 100.0, 101.0, 99.0, 100.5, 10.0)
+    }
+
+    fn temp_cache_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("candlechart_cache_{}_{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_candle_cache_serves_covered_ranges_only() {
+        let dir = temp_cache_dir("ranges");
+        let cache = CandleCache::new(&dir, DEFAULT_CANDLE_CACHE_CAPACITY);
+        assert!(cache.get_range(ProviderType::Binance, "BTCUSDT", "1h", 0, 7200).is_none());
+
+        cache.insert_range(ProviderType::Binance, "BTCUSDT", "1h", 0, 7200, &[candle(0), candle(3600), candle(7200)]).unwrap();
+        cache.insert_range(ProviderType::Binance, "BTCUSDT", "1h", 7200, 14400, &[candle(10800), candle(14400)]).unwrap();
+        assert_eq!(cache.get_range(ProviderType::Binance, "BTCUSDT", "1h", 3600, 10800).map(|c| c.len()), Some(3));
+        assert!(cache.get_range(ProviderType::Binance, "BTCUSDT", "1h", 3600, 18000).is_none());
+        assert!(cache.get_range(ProviderType::Binance, "BTCUSDT", "1m", 3600, 7200).is_none());
+        assert!(cache.get_range(ProviderType::Kraken, "BTCUSDT", "1h", 3600, 7200).is_none());
+
+        // Relu depuis le disque par un nouveau cache
+        let reloaded = CandleCache::new(&dir, 1);
+        assert_eq!(reloaded.get_range(ProviderType::Binance, "BTCUSDT", "1h", 0, 14400).map(|c| c.len()), Some(5));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_candle_cache_evicts_least_recently_used() {
+        let dir = temp_cache_dir("lru");
+        let cache = CandleCache::new(&dir, 1);
+        cache.insert_range(ProviderType::Binance, "BTCUSDT", "1h", 0, 3600, &[candle(0)]).unwrap();
+        cache.insert_range(ProviderType::Binance, "ETHUSDT", "1h", 0, 3600, &[candle(0)]).unwrap();
+        assert_eq!(cache.entries.lock().unwrap().entries.len(), 1);
+        // La série évincée est relue depuis son fichier
+        assert_eq!(cache.get_range(ProviderType::Binance, "BTCUSDT", "1h", 0, 3600).map(|c| c.len()), Some(1));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_candle_cache_skips_forming_candle() {
+        let dir = temp_cache_dir("forming");
+        let cache = CandleCache::new(&dir, DEFAULT_CANDLE_CACHE_CAPACITY);
+        // À 10h30, la bougie 1h de 10h est encore en formation
+        let now = 10 * 3600 + 1800;
+        let candles: Vec<_> = (8..=10).map(|h| candle(h * 3600)).collect();
+        cache.insert_range_at(ProviderType::Binance, "BTCUSDT", "1h", 8 * 3600, now, &candles, now).unwrap();
+
+        assert_eq!(cache.get_range(ProviderType::Binance, "BTCUSDT", "1h", 8 * 3600, 10 * 3600 - 1).map(|c| c.len()), Some(2));
+        assert!(cache.get_range(ProviderType::Binance, "BTCUSDT", "1h", 8 * 3600, 10 * 3600).is_none());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
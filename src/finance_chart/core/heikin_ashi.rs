@@ -0,0 +1,93 @@
+//! Transformation Heikin-Ashi
+//!
+//! Les bougies Heikin-Ashi lissent l'action du prix :
+//! - HA-close = (open + high + low + close) / 4
+//! - HA-open  = (HA-open précédent + HA-close précédent) / 2
+//! - HA-high  = max(high, HA-open, HA-close)
+//! - HA-low   = min(low, HA-open, HA-close)
+//!
+//! L'ouverture dépend de la bougie HA précédente : la transformation doit donc
+//! partir de la première bougie chargée de la série, et non de la fenêtre visible.
+
+use super::candle::Candle;
+
+/// Transforme une série de bougies en bougies Heikin-Ashi
+///
+/// La première bougie utilise (open + close) / 2 comme ouverture.
+/// Les timestamps et volumes sont conservés tels quels.
+pub fn to_heikin_ashi(candles: &[Candle]) -> Vec<Candle> {
+    let mut result = Vec::with_capacity(candles.len());
+    let mut previous: Option<(f64, f64)> = None; // (HA-open, HA-close)
+
+    for candle in candles {
+        let ha_close = (candle.open + candle.high + candle.low + candle.close) / 4.0;
+        let ha_open = match previous {
+            Some((prev_open, prev_close)) => (prev_open + prev_close) / 2.0,
+            None => (candle.open + candle.close) / 2.0,
+        };
+        let ha_high = candle.high.max(ha_open).max(ha_close);
+        let ha_low = candle.low.min(ha_open).min(ha_close);
+
+        result.push(Candle::new(
+            candle.timestamp,
+            ha_open,
+            ha_high,
+            ha_low,
+            ha_close,
+            candle.volume,
+        ));
+        previous = Some((ha_open, ha_close));
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        assert!(to_heikin_ashi(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_first_candle() {
+        let candles = vec![Candle::new(1000, 100.0, 110.0, 90.0, 104.0, 5.0)];
+        let ha = to_heikin_ashi(&candles);
+        assert_eq!(ha.len(), 1);
+        assert_eq!(ha[0].timestamp, 1000);
+        assert_eq!(ha[0].open, 102.0);
+        assert_eq!(ha[0].close, 101.0);
+        assert_eq!(ha[0].high, 110.0);
+        assert_eq!(ha[0].low, 90.0);
+        assert_eq!(ha[0].volume, 5.0);
+    }
+
+    #[test]
+    fn test_recursive_open() {
+        let candles = vec![
+            Candle::new(1000, 100.0, 110.0, 90.0, 104.0, 1.0),
+            Candle::new(2000, 104.0, 120.0, 100.0, 116.0, 1.0),
+        ];
+        let ha = to_heikin_ashi(&candles);
+        // HA-open = (102 + 101) / 2
+        assert_eq!(ha[1].open, 101.5);
+        assert_eq!(ha[1].close, 110.0);
+        assert_eq!(ha[1].high, 120.0);
+        assert_eq!(ha[1].low, 100.0);
+    }
+
+    #[test]
+    fn test_high_low_envelop_body() {
+        let candles = vec![
+            Candle::new(1000, 100.0, 101.0, 99.0, 100.0, 1.0),
+            Candle::new(2000, 50.0, 51.0, 49.0, 50.0, 1.0),
+        ];
+        let ha = to_heikin_ashi(&candles);
+        for c in &ha {
+            assert!(c.high >= c.open.max(c.close));
+            assert!(c.low <= c.open.min(c.close));
+        }
+    }
+}
@@ -2,9 +2,15 @@ pub mod candle;
 pub mod timeseries;
 pub mod series_data;
 pub mod cache;
+pub mod heikin_ashi;
+pub mod renko;
+pub mod resample;
 
 // Ré-exporter pour faciliter l'utilisation
 pub use candle::Candle;
 pub use timeseries::TimeSeries;
-pub use series_data::{SeriesId, SeriesData, SeriesManager};
+pub use series_data::{PriceAxis, SeriesId, SeriesData, SeriesManager};
+pub use heikin_ashi::to_heikin_ashi;
+pub use renko::{build_renko, RenkoBrick, RenkoDirection};
+pub use resample::resample;
 
@@ -0,0 +1,156 @@
+//! Construction des briques Renko
+//!
+//! Une brique est ajoutée chaque fois que la clôture dépasse la brique précédente
+//! d'au moins une taille de brique. Un retournement nécessite un mouvement de deux
+//! briques (depuis le haut d'une brique haussière ou le bas d'une brique baissière).
+
+use super::candle::Candle;
+
+/// Période utilisée pour la taille de brique automatique (basée sur l'ATR)
+pub const RENKO_ATR_PERIOD: usize = 14;
+
+/// Direction d'une brique Renko
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenkoDirection {
+    Up,
+    Down,
+}
+
+/// Brique Renko
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RenkoBrick {
+    /// Prix bas de la brique
+    pub price_low: f64,
+    /// Prix haut de la brique
+    pub price_high: f64,
+    /// Direction de la brique
+    pub direction: RenkoDirection,
+    /// Timestamp de la bougie qui a formé la brique
+    pub timestamp: i64,
+}
+
+/// Construit les briques Renko à partir des clôtures
+///
+/// La première clôture sert d'ancre. Retourne un vecteur vide si `brick_size`
+/// n'est pas strictement positif.
+pub fn build_renko(candles: &[Candle], brick_size: f64) -> Vec<RenkoBrick> {
+    let mut bricks = Vec::new();
+    if brick_size <= 0.0 || !brick_size.is_finite() {
+        return bricks;
+    }
+    let Some(first) = candles.first() else {
+        return bricks;
+    };
+
+    // Bornes de la dernière brique (confondues tant qu'aucune brique n'existe)
+    let mut top = first.close;
+    let mut bottom = first.close;
+
+    for candle in &candles[1..] {
+        while candle.close >= top + brick_size {
+            bricks.push(RenkoBrick {
+                price_low: top,
+                price_high: top + brick_size,
+                direction: RenkoDirection::Up,
+                timestamp: candle.timestamp,
+            });
+            bottom = top;
+            top += brick_size;
+        }
+        while candle.close <= bottom - brick_size {
+            bricks.push(RenkoBrick {
+                price_low: bottom - brick_size,
+                price_high: bottom,
+                direction: RenkoDirection::Down,
+                timestamp: candle.timestamp,
+            });
+            top = bottom;
+            bottom -= brick_size;
+        }
+    }
+
+    bricks
+}
+
+/// Calcule une taille de brique automatique : moyenne du true range
+/// sur les `period` dernières bougies
+pub fn auto_brick_size(candles: &[Candle], period: usize) -> Option<f64> {
+    if candles.len() < 2 || period == 0 {
+        return None;
+    }
+
+    let start = candles.len().saturating_sub(period).max(1);
+    let ranges: Vec<f64> = (start..candles.len())
+        .map(|i| {
+            let candle = &candles[i];
+            let prev_close = candles[i - 1].close;
+            (candle.high - candle.low)
+                .max((candle.high - prev_close).abs())
+                .max((candle.low - prev_close).abs())
+        })
+        .collect();
+
+    let size = ranges.iter().sum::<f64>() / ranges.len() as f64;
+    if size > 0.0 {
+        Some(size)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn closes(values: &[f64]) -> Vec<Candle> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| Candle::new(i as i64 * 60, c, c, c, c, 1.0))
+            .collect()
+    }
+
+    #[test]
+    fn test_invalid_brick_size() {
+        let candles = closes(&[100.0, 110.0]);
+        assert!(build_renko(&candles, 0.0).is_empty());
+        assert!(build_renko(&candles, -1.0).is_empty());
+        assert!(build_renko(&[], 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_up_bricks() {
+        let candles = closes(&[100.0, 103.5]);
+        let bricks = build_renko(&candles, 1.0);
+        assert_eq!(bricks.len(), 3);
+        assert!(bricks.iter().all(|b| b.direction == RenkoDirection::Up));
+        assert_eq!(bricks[0].price_low, 100.0);
+        assert_eq!(bricks[2].price_high, 103.0);
+        assert_eq!(bricks[2].timestamp, 60);
+    }
+
+    #[test]
+    fn test_reversal_needs_two_bricks() {
+        // Après 100 -> 102, un retour à 101 ne crée pas de brique baissière
+        let candles = closes(&[100.0, 102.0, 101.0]);
+        assert_eq!(build_renko(&candles, 1.0).len(), 2);
+
+        // Un retour à 100 crée une brique baissière [100, 101]
+        let candles = closes(&[100.0, 102.0, 100.0]);
+        let bricks = build_renko(&candles, 1.0);
+        assert_eq!(bricks.len(), 3);
+        assert_eq!(bricks[2].direction, RenkoDirection::Down);
+        assert_eq!(bricks[2].price_low, 100.0);
+        assert_eq!(bricks[2].price_high, 101.0);
+    }
+
+    #[test]
+    fn test_auto_brick_size() {
+        let candles = vec![
+            Candle::new(0, 100.0, 102.0, 98.0, 100.0, 1.0),
+            Candle::new(60, 100.0, 104.0, 100.0, 102.0, 1.0),
+        ];
+        assert_eq!(auto_brick_size(&candles, 14), Some(4.0));
+        assert_eq!(auto_brick_size(&candles[..1], 14), None);
+    }
+}
@@ -0,0 +1,251 @@
+//! Agrégation de bougies vers un intervalle supérieur (ex: 1m → 5m)
+//!
+//! Les bornes des paquets sont alignées sur l'epoch Unix (UTC) : minutes,
+//! heures et jours alignés, semaines commençant le lundi, et mois calendaires
+//! pour `1M`.
+
+use super::{Candle, SeriesData, SeriesId, TimeSeries};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Décalage du premier lundi après l'epoch (le 1er janvier 1970 était un jeudi)
+const FIRST_MONDAY_OFFSET: i64 = 4 * SECONDS_PER_DAY;
+
+/// Durée d'un intervalle : fixe en secondes, ou en mois calendaires
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntervalSpan {
+    Seconds(i64),
+    Months(i64),
+}
+
+impl IntervalSpan {
+    /// Durée approximative en secondes (30 jours par mois), pour comparer deux intervalles
+    pub fn approx_seconds(&self) -> i64 {
+        match *self {
+            IntervalSpan::Seconds(s) => s,
+            IntervalSpan::Months(n) => n * 30 * SECONDS_PER_DAY,
+        }
+    }
+}
+
+/// Parse un intervalle au format Binance (ex: "1m", "15m", "4h", "1d", "1w", "1M")
+pub fn parse_interval(interval: &str) -> Option<IntervalSpan> {
+    let unit = interval.chars().last()?;
+    let count: i64 = interval[..interval.len() - unit.len_utf8()].parse().ok()?;
+    if count <= 0 {
+        return None;
+    }
+    match unit {
+        'm' => Some(IntervalSpan::Seconds(count * 60)),
+        'h' => Some(IntervalSpan::Seconds(count * 3600)),
+        'd' => Some(IntervalSpan::Seconds(count * SECONDS_PER_DAY)),
+        'w' => Some(IntervalSpan::Seconds(count * 7 * SECONDS_PER_DAY)),
+        'M' => Some(IntervalSpan::Months(count)),
+        _ => None,
+    }
+}
+
+/// Convertit un nombre de jours depuis l'epoch en date civile (année, mois 1-12)
+fn civil_from_days(days: i64) -> (i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month)
+}
+
+/// Convertit une date civile (premier jour du mois) en nombre de jours depuis l'epoch
+fn days_from_civil(year: i64, month: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Retourne le début du paquet contenant `timestamp` pour l'intervalle donné
+pub fn bucket_start(timestamp: i64, span: IntervalSpan) -> i64 {
+    match span {
+        IntervalSpan::Seconds(seconds) if seconds % (7 * SECONDS_PER_DAY) == 0 => {
+            // Semaines alignées sur le lundi
+            let shifted = timestamp - FIRST_MONDAY_OFFSET;
+            shifted - shifted.rem_euclid(seconds) + FIRST_MONDAY_OFFSET
+        }
+        IntervalSpan::Seconds(seconds) => timestamp - timestamp.rem_euclid(seconds),
+        IntervalSpan::Months(months) => {
+            let (year, month) = civil_from_days(timestamp.div_euclid(SECONDS_PER_DAY));
+            let index = year * 12 + (month - 1);
+            let bucket_index = index - index.rem_euclid(months);
+            days_from_civil(bucket_index.div_euclid(12), bucket_index.rem_euclid(12) + 1) * SECONDS_PER_DAY
+        }
+    }
+}
+
+/// Agrège les bougies d'une série vers un intervalle supérieur
+///
+/// Chaque paquet prend l'ouverture de la première bougie, le plus haut et le
+/// plus bas du paquet, la clôture de la dernière bougie et la somme des volumes.
+/// La série source n'est pas modifiée.
+pub fn resample(series: &SeriesData, target_interval: &str) -> Result<SeriesData, String> {
+    let target = parse_interval(target_interval)
+        .ok_or_else(|| format!("Intervalle cible invalide: {}", target_interval))?;
+    if let Some(source) = parse_interval(&series.interval) {
+        if target.approx_seconds() < source.approx_seconds() {
+            return Err(format!(
+                "Impossible d'agréger {} vers un intervalle inférieur ({})",
+                series.interval, target_interval
+            ));
+        }
+    }
+
+    let mut timeseries = TimeSeries::new();
+    for bucket in aggregate(series.data.all_candles(), target) {
+        timeseries.push(bucket).map_err(|e| e.to_string())?;
+    }
+
+    let id = SeriesId::new(format!("{}_{}", series.symbol, target_interval));
+    Ok(SeriesData::new(id, series.symbol.clone(), target_interval.to_string(), timeseries))
+}
+
+/// Met à jour la queue d'une série agrégée après une modification de la source à partir de `since`
+///
+/// Seuls les paquets à partir de celui contenant `since` sont recalculés.
+/// Retourne `Ok(false)` sans rien modifier si ce paquet précède le dernier paquet
+/// agrégé : il faut alors tout ré-agréger avec [`resample`].
+pub fn resample_tail(series: &SeriesData, resampled: &mut SeriesData, since: i64) -> Result<bool, String> {
+    let target = parse_interval(&resampled.interval)
+        .ok_or_else(|| format!("Intervalle cible invalide: {}", resampled.interval))?;
+    let start = bucket_start(since, target);
+    if resampled.data.last_candle().is_some_and(|last| start < last.timestamp) {
+        return Ok(false);
+    }
+
+    let candles = series.data.all_candles();
+    let tail = &candles[candles.partition_point(|c| c.timestamp < start)..];
+    for bucket in aggregate(tail, target) {
+        resampled.data.update_or_append_candle(bucket).map_err(|e| e.to_string())?;
+    }
+    Ok(true)
+}
+
+/// Regroupe des bougies triées en paquets alignés sur `target`
+fn aggregate(candles: &[Candle], target: IntervalSpan) -> Vec<Candle> {
+    let mut buckets: Vec<Candle> = Vec::new();
+    for candle in candles {
+        let start = bucket_start(candle.timestamp, target);
+        match buckets.last_mut() {
+            Some(bucket) if bucket.timestamp == start => {
+                bucket.high = bucket.high.max(candle.high);
+                bucket.low = bucket.low.min(candle.low);
+                bucket.close = candle.close;
+                bucket.volume += candle.volume;
+            }
+            _ => buckets.push(Candle { timestamp: start, ..*candle }),
+        }
+    }
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(interval: &str, candles: Vec<Candle>) -> SeriesData {
+        let mut data = TimeSeries::new();
+        for candle in candles {
+            data.push(candle).unwrap();
+        }
+        SeriesData::new(SeriesId::new("TEST"), "BTCUSDT".to_string(), interval.to_string(), data)
+    }
+
+    #[test]
+    fn test_parse_interval() {
+        assert_eq!(parse_interval("1m"), Some(IntervalSpan::Seconds(60)));
+        assert_eq!(parse_interval("4h"), Some(IntervalSpan::Seconds(14_400)));
+        assert_eq!(parse_interval("1w"), Some(IntervalSpan::Seconds(604_800)));
+        assert_eq!(parse_interval("1M"), Some(IntervalSpan::Months(1)));
+        assert_eq!(parse_interval("abc"), None);
+        assert_eq!(parse_interval("0m"), None);
+    }
+
+    #[test]
+    fn test_bucket_start_fixed() {
+        // 2023-11-14 22:13:20 UTC
+        let ts = 1_700_000_000;
+        assert_eq!(bucket_start(ts, IntervalSpan::Seconds(300)), 1_699_999_800);
+        assert_eq!(bucket_start(ts, IntervalSpan::Seconds(3600)), 1_699_999_200);
+        assert_eq!(bucket_start(ts, IntervalSpan::Seconds(SECONDS_PER_DAY)), 1_699_920_000);
+    }
+
+    #[test]
+    fn test_bucket_start_week_starts_monday() {
+        // 2023-11-13 00:00 UTC était un lundi
+        let ts = 1_700_000_000;
+        assert_eq!(bucket_start(ts, IntervalSpan::Seconds(7 * SECONDS_PER_DAY)), 1_699_833_600);
+    }
+
+    #[test]
+    fn test_bucket_start_calendar_month() {
+        // 2023-11-14 → 2023-11-01 00:00 UTC
+        assert_eq!(bucket_start(1_700_000_000, IntervalSpan::Months(1)), 1_698_796_800);
+        // 2024-02-29 12:00 UTC (année bissextile) → 2024-02-01
+        assert_eq!(bucket_start(1_709_208_000, IntervalSpan::Months(1)), 1_706_745_600);
+    }
+
+    #[test]
+    fn test_resample_ohlcv() {
+        let base = 1_700_000_100; // aligné sur 5 minutes
+        let source = series("1m", vec![
+            Candle::new(base, 10.0, 12.0, 9.0, 11.0, 1.0),
+            Candle::new(base + 60, 11.0, 15.0, 10.0, 14.0, 2.0),
+            Candle::new(base + 240, 14.0, 14.5, 8.0, 9.0, 3.0),
+            Candle::new(base + 300, 9.0, 10.0, 8.5, 9.5, 4.0),
+        ]);
+
+        let resampled = resample(&source, "5m").unwrap();
+        let candles = resampled.data.all_candles();
+        assert_eq!(resampled.interval, "5m");
+        assert_eq!(candles.len(), 2);
+        assert_eq!(candles[0].timestamp, base);
+        assert_eq!(candles[0].open, 10.0);
+        assert_eq!(candles[0].high, 15.0);
+        assert_eq!(candles[0].low, 8.0);
+        assert_eq!(candles[0].close, 9.0);
+        assert_eq!(candles[0].volume, 6.0);
+        assert_eq!(candles[1].timestamp, base + 300);
+        // La série source n'est pas modifiée
+        assert_eq!(source.data.all_candles().len(), 4);
+    }
+
+    #[test]
+    fn test_resample_tail_matches_full_resample() {
+        let base = 1_700_000_100; // aligné sur 5 minutes
+        let mut source = series("1m", (0..8)
+            .map(|i| Candle::new(base + i * 60, 10.0, 11.0 + i as f64, 9.0, 10.5, 1.0))
+            .collect());
+        let mut resampled = resample(&source, "5m").unwrap();
+
+        // Mise à jour de la bougie en cours puis ouverture d'un nouveau paquet
+        source.data.update_or_append_candle(Candle::new(base + 420, 10.0, 30.0, 5.0, 12.0, 2.0)).unwrap();
+        assert!(resample_tail(&source, &mut resampled, base + 420).unwrap());
+        source.data.push(Candle::new(base + 600, 12.0, 13.0, 11.0, 12.5, 1.0)).unwrap();
+        assert!(resample_tail(&source, &mut resampled, base + 600).unwrap());
+        assert_eq!(resampled.data.all_candles(), resample(&source, "5m").unwrap().data.all_candles());
+
+        // Une modification antérieure au dernier paquet demande une ré-agrégation complète
+        assert!(!resample_tail(&source, &mut resampled, base).unwrap());
+    }
+
+    #[test]
+    fn test_resample_rejects_lower_interval() {
+        let source = series("1h", vec![Candle::new(1_700_000_000, 1.0, 1.0, 1.0, 1.0, 0.0)]);
+        assert!(resample(&source, "5m").is_err());
+        assert!(resample(&source, "xyz").is_err());
+    }
+}
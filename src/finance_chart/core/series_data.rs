@@ -16,6 +16,19 @@ impl SeriesId {
     }
 }
 
+/// Axe de prix sur lequel une série est tracée
+///
+/// Permet de superposer des séries d'ordres de grandeur très différents :
+/// chaque axe a sa propre échelle de prix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PriceAxis {
+    /// Axe de gauche, ajusté aux séries qui lui sont assignées
+    Left,
+    /// Axe principal de droite (zoom, verrouillage, outils de dessin)
+    #[default]
+    Right,
+}
+
 /// Données d'une série temporelle avec métadonnées
 #[derive(Debug, Clone)]
 pub struct SeriesData {
@@ -29,6 +42,13 @@ pub struct SeriesData {
     pub interval: String,
     /// Couleur personnalisée pour cette série (optionnel)
     pub color: Option<iced::Color>,
+    /// Reçoit les mises à jour temps réel (false = série figée)
+    pub realtime_enabled: bool,
+    /// Les bougies les plus anciennes ont été retirées de la mémoire (voir `trim_to`) ;
+    /// elles restent dans le fichier de la série
+    pub history_trimmed: bool,
+    /// Axe de prix de la série lorsqu'elle est superposée
+    pub axis: PriceAxis,
 }
 
 impl SeriesData {
@@ -40,6 +60,9 @@ impl SeriesData {
             symbol,
             interval,
             color: None,
+            realtime_enabled: true,
+            history_trimmed: false,
+            axis: PriceAxis::Right,
         }
     }
 
@@ -47,6 +70,18 @@ impl SeriesData {
     pub fn full_name(&self) -> String {
         format!("{}_{}", self.symbol, self.interval)
     }
+
+    /// Limite la série à `max_candles` bougies en supprimant les plus anciennes
+    ///
+    /// # Retourne
+    /// Le nombre de bougies supprimées
+    pub fn trim_to(&mut self, max_candles: usize) -> usize {
+        let removed = self.data.trim_to(max_candles);
+        if removed > 0 {
+            self.history_trimmed = true;
+        }
+        removed
+    }
 }
 
 /// Gestionnaire de plusieurs séries temporelles
@@ -104,6 +139,89 @@ impl SeriesManager {
         }
     }
 
+    /// Intervalles chargés pour un symbole, triés par durée croissante
+    ///
+    /// Chaque intervalle est une `TimeSeries` indépendante (téléchargée séparément,
+    /// avec ses propres trous) : ensemble, elles forment une série logique multi-intervalles.
+    /// Les intervalles non reconnus sont placés en dernier.
+    pub fn intervals_for_symbol(&self, symbol: &str) -> Vec<(&str, &SeriesId)> {
+        let mut intervals: Vec<(&str, &SeriesId)> = self.series
+            .values()
+            .filter(|series| series.symbol == symbol)
+            .map(|series| (series.interval.as_str(), &series.id))
+            .collect();
+        intervals.sort_by_key(|(interval, _)| {
+            super::resample::parse_interval(interval)
+                .map(|span| span.approx_seconds())
+                .unwrap_or(i64::MAX)
+        });
+        intervals
+    }
+
+    /// Série d'un symbole à un intervalle donné
+    pub fn series_for_interval(&self, symbol: &str, interval: &str) -> Option<&SeriesData> {
+        self.series
+            .values()
+            .find(|series| series.symbol == symbol && series.interval == interval)
+    }
+
+    /// Indique si une série est affichée
+    pub fn is_series_visible(&self, id: &SeriesId) -> bool {
+        self.active_series.contains(id)
+    }
+
+    /// Affiche ou masque une série sans toucher aux autres (superposition)
+    ///
+    /// La dernière série visible ne peut pas être masquée. La première série
+    /// active reste la série principale (indicateurs, prix courant).
+    ///
+    /// # Retourne
+    /// `true` si la visibilité a changé
+    pub fn toggle_series_visibility(&mut self, id: &SeriesId) -> bool {
+        if !self.series.contains_key(id) {
+            return false;
+        }
+        if let Some(pos) = self.active_series.iter().position(|active| active == id) {
+            if self.active_series.len() == 1 {
+                return false;
+            }
+            self.active_series.remove(pos);
+        } else {
+            self.active_series.push(id.clone());
+        }
+        true
+    }
+
+    /// Séries actives recevant les mises à jour temps réel
+    pub fn realtime_series(&self) -> impl Iterator<Item = &SeriesData> {
+        self.active_series().filter(|series| series.realtime_enabled)
+    }
+
+    /// Active ou suspend les mises à jour temps réel d'une série
+    ///
+    /// # Retourne
+    /// Le nouvel état, ou `None` si la série n'existe pas
+    pub fn toggle_realtime(&mut self, id: &SeriesId) -> Option<bool> {
+        self.series.get_mut(id).map(|series| {
+            series.realtime_enabled = !series.realtime_enabled;
+            series.realtime_enabled
+        })
+    }
+
+    /// Assigne une série à l'axe de prix gauche ou droit
+    ///
+    /// # Retourne
+    /// `true` si l'axe de la série a changé
+    pub fn set_series_axis(&mut self, id: &SeriesId, axis: PriceAxis) -> bool {
+        match self.series.get_mut(id) {
+            Some(series) if series.axis != axis => {
+                series.axis = axis;
+                true
+            }
+            _ => false,
+        }
+    }
+
     /// Retourne toutes les bougies visibles de toutes les séries actives dans une plage temporelle
     pub fn visible_candles(&self, time_range: std::ops::Range<i64>) -> Vec<(SeriesId, &[Candle])> {
         self.active_series()
@@ -114,6 +232,17 @@ impl SeriesManager {
             .collect()
     }
 
+    /// Limite toutes les séries à `max_candles` bougies (les plus anciennes sont supprimées)
+    ///
+    /// # Retourne
+    /// Les identifiants des séries tronquées
+    pub fn trim_all_to(&mut self, max_candles: usize) -> Vec<SeriesId> {
+        self.series
+            .values_mut()
+            .filter_map(|series| (series.trim_to(max_candles) > 0).then(|| series.id.clone()))
+            .collect()
+    }
+
     /// Retourne le nombre total de séries
     pub fn total_count(&self) -> usize {
         self.series.len()
@@ -150,3 +279,99 @@ impl Default for SeriesManager {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(name: &str) -> SeriesData {
+        let (symbol, interval) = name.split_once('_').unwrap();
+        SeriesData::new(SeriesId::new(name), symbol.to_string(), interval.to_string(), TimeSeries::new())
+    }
+
+    #[test]
+    fn test_toggle_visibility_overlays_series() {
+        let mut manager = SeriesManager::new();
+        manager.add_series(series("BTCUSDT_1h"));
+        manager.add_series(series("ETHUSDT_1h"));
+
+        let eth = SeriesId::new("ETHUSDT_1h");
+        assert!(manager.toggle_series_visibility(&eth));
+        let visible: Vec<_> = manager.active_series().map(|s| s.id.name.as_str()).collect();
+        assert_eq!(visible, vec!["BTCUSDT_1h", "ETHUSDT_1h"]);
+
+        assert!(manager.toggle_series_visibility(&eth));
+        assert!(!manager.is_series_visible(&eth));
+    }
+
+    #[test]
+    fn test_intervals_for_symbol_sorted_by_duration() {
+        let mut manager = SeriesManager::new();
+        manager.add_series(series("BTCUSDT_1d"));
+        manager.add_series(series("BTCUSDT_15m"));
+        manager.add_series(series("ETHUSDT_1m"));
+        manager.add_series(series("BTCUSDT_1h"));
+
+        let intervals: Vec<&str> = manager.intervals_for_symbol("BTCUSDT")
+            .into_iter()
+            .map(|(interval, _)| interval)
+            .collect();
+        assert_eq!(intervals, vec!["15m", "1h", "1d"]);
+        assert_eq!(
+            manager.series_for_interval("BTCUSDT", "1h").map(|s| s.id.name.as_str()),
+            Some("BTCUSDT_1h")
+        );
+        assert!(manager.series_for_interval("ETHUSDT", "1h").is_none());
+    }
+
+    #[test]
+    fn test_toggle_realtime_freezes_series() {
+        let mut manager = SeriesManager::new();
+        manager.add_series(series("BTCUSDT_1h"));
+        manager.add_series(series("ETHUSDT_1h"));
+        let btc = SeriesId::new("BTCUSDT_1h");
+        let eth = SeriesId::new("ETHUSDT_1h");
+        manager.toggle_series_visibility(&eth);
+
+        assert_eq!(manager.toggle_realtime(&btc), Some(false));
+        let live: Vec<_> = manager.realtime_series().map(|s| s.id.name.as_str()).collect();
+        assert_eq!(live, vec!["ETHUSDT_1h"]);
+        // Une série figée reste affichée
+        assert!(manager.is_series_visible(&btc));
+
+        assert_eq!(manager.toggle_realtime(&btc), Some(true));
+        assert_eq!(manager.realtime_series().count(), 2);
+        assert_eq!(manager.toggle_realtime(&SeriesId::new("UNKNOWN_1h")), None);
+    }
+
+    #[test]
+    fn test_trim_all_drops_oldest_candles() {
+        let mut manager = SeriesManager::new();
+        let mut btc = series("BTCUSDT_1h");
+        for i in 0..10 {
+            btc.data.push(Candle::new(1000 + i * 3600, 100.0, 101.0, 99.0, 100.5, 10.0)).unwrap();
+        }
+        manager.add_series(btc);
+        manager.add_series(series("ETHUSDT_1h"));
+
+        let trimmed = manager.trim_all_to(4);
+        assert_eq!(trimmed, vec![SeriesId::new("BTCUSDT_1h")]);
+        let btc = manager.get_series(&SeriesId::new("BTCUSDT_1h")).unwrap();
+        assert_eq!(btc.data.len(), 4);
+        assert_eq!(btc.data.min_timestamp(), Some(1000 + 6 * 3600));
+        assert!(btc.history_trimmed);
+        assert!(!manager.get_series(&SeriesId::new("ETHUSDT_1h")).unwrap().history_trimmed);
+        assert!(manager.trim_all_to(4).is_empty());
+    }
+
+    #[test]
+    fn test_last_visible_series_cannot_be_hidden() {
+        let mut manager = SeriesManager::new();
+        manager.add_series(series("BTCUSDT_1h"));
+
+        let btc = SeriesId::new("BTCUSDT_1h");
+        assert!(!manager.toggle_series_visibility(&btc));
+        assert!(manager.is_series_visible(&btc));
+        assert!(!manager.toggle_series_visibility(&SeriesId::new("UNKNOWN_1h")));
+    }
+}
+
@@ -239,6 +239,21 @@ impl TimeSeries {
         self.candles.len()
     }
 
+    /// Ne garde que les `max_candles` bougies les plus récentes
+    /// Invalide automatiquement les caches
+    ///
+    /// # Retourne
+    /// Le nombre de bougies supprimées
+    pub fn trim_to(&mut self, max_candles: usize) -> usize {
+        let excess = self.candles.len().saturating_sub(max_candles);
+        if excess > 0 {
+            self.candles.drain(..excess);
+            self.price_cache.invalidate();
+            self.time_cache.invalidate();
+        }
+        excess
+    }
+
     /// Retourne le timestamp minimum
     pub fn min_timestamp(&self) -> Option<i64> {
         // Vérifier le cache d'abord
@@ -350,34 +365,20 @@ impl TimeSeries {
 
     /// Retourne les bougies visibles dans une plage de timestamps
     /// 
-    /// Utilise une recherche binaire pour efficacité avec grandes séries
-    /// Inclut toutes les bougies dont le timestamp est >= start et < end
+    /// Inclut toutes les bougies dont le timestamp est >= start et <= end
+    /// (la bougie posée exactement sur le bord droit reste visible).
     pub fn visible_candles(&self, time_range: Range<i64>) -> &[Candle] {
-        if self.candles.is_empty() {
-            return &[];
-        }
+        self.candles_in_range(time_range.start, time_range.end)
+    }
 
-        // Recherche binaire pour trouver le début
-        // On veut inclure toutes les bougies avec timestamp >= time_range.start
-        let start_idx = self
-            .candles
-            .binary_search_by_key(&time_range.start, |c| c.timestamp)
-            .unwrap_or_else(|idx| idx);
-
-        // Recherche binaire pour trouver la fin
-        // On veut inclure toutes les bougies avec timestamp < time_range.end
-        // Si time_range.end correspond exactement à un timestamp, on l'inclut aussi
-        let end_idx = self
-            .candles
-            .binary_search_by_key(&time_range.end, |c| c.timestamp)
-            .map(|idx| idx + 1) // Inclure la bougie à time_range.end si elle existe
-            .unwrap_or_else(|idx| idx); // Sinon, inclure toutes jusqu'à idx
-
-        // S'assurer que les indices sont valides
-        let start = start_idx.min(self.candles.len());
-        let end = end_idx.min(self.candles.len()).max(start);
-        
-        &self.candles[start..end]
+    /// Retourne le sous-slice des bougies dont le timestamp est dans `[start, end]`
+    ///
+    /// Les bougies étant triées par timestamp, les bornes sont trouvées par
+    /// recherche binaire (O(log n)) : utilisable à chaque frame sur de grandes séries.
+    pub fn candles_in_range(&self, start: i64, end: i64) -> &[Candle] {
+        let start_idx = self.candles.partition_point(|c| c.timestamp < start);
+        let end_idx = self.candles.partition_point(|c| c.timestamp <= end).max(start_idx);
+        &self.candles[start_idx..end_idx]
     }
 
     /// Détecte les gaps dans les données selon l'intervalle attendu
@@ -417,6 +418,31 @@ impl TimeSeries {
 
         gaps
     }
+
+    /// Retourne les indices des bougies qui ouvrent un nouveau jour calendaire
+    ///
+    /// Le jour est évalué dans le fuseau décalé de `utc_offset_minutes` par rapport
+    /// à UTC (0 = UTC). La première bougie n'est jamais considérée comme une frontière.
+    pub fn day_boundaries(&self, utc_offset_minutes: i32) -> Vec<usize> {
+        let Some(offset) = chrono::FixedOffset::east_opt(utc_offset_minutes * 60) else {
+            return Vec::new();
+        };
+        let day_of = |timestamp: i64| {
+            chrono::DateTime::from_timestamp(timestamp, 0)
+                .map(|dt| dt.with_timezone(&offset).date_naive())
+        };
+
+        let mut boundaries = Vec::new();
+        let mut previous_day = self.candles.first().and_then(|c| day_of(c.timestamp));
+        for (i, candle) in self.candles.iter().enumerate().skip(1) {
+            let day = day_of(candle.timestamp);
+            if day != previous_day {
+                boundaries.push(i);
+                previous_day = day;
+            }
+        }
+        boundaries
+    }
 }
 
 impl Default for TimeSeries {
@@ -464,6 +490,30 @@ mod tests {
         assert_eq!(visible.len(), 3); // indices 2, 3, 4
     }
 
+    #[test]
+    fn test_candles_in_range_matches_linear_filter() {
+        let mut ts = TimeSeries::new();
+        for i in 0..100_000 {
+            ts.push(Candle::new(i * 60, 100.0, 105.0, 99.0, 104.0, 1.0)).unwrap();
+        }
+
+        for &(start, end) in &[
+            (0, 60),
+            (1_234_567, 2_345_678),
+            (3_000_000, 3_000_000),
+            (-500, 30),
+            (5_999_940, 7_000_000),
+            (10, 5),
+        ] {
+            let expected: Vec<Candle> = ts.all_candles()
+                .iter()
+                .filter(|c| c.timestamp >= start && c.timestamp <= end)
+                .copied()
+                .collect();
+            assert_eq!(ts.candles_in_range(start, end), expected.as_slice(), "plage {}..={}", start, end);
+        }
+    }
+
     #[test]
     fn test_price_range_cache() {
         let mut ts = TimeSeries::new();
@@ -572,5 +622,20 @@ mod tests {
         let candles: Vec<i64> = ts.candles.iter().map(|c| c.timestamp).collect();
         assert_eq!(candles, vec![100, 150, 200, 300]);
     }
-}
 
+    #[test]
+    fn test_day_boundaries() {
+        // Bougies de 6h à partir du 2024-01-01 00:00 UTC
+        let mut ts = TimeSeries::new();
+        for i in 0..8 {
+            ts.push(Candle::new(1_704_067_200 + i * 21_600, 1.0, 1.0, 1.0, 1.0, 1.0)).unwrap();
+        }
+
+        // En UTC, un nouveau jour commence toutes les 4 bougies
+        assert_eq!(ts.day_boundaries(0), vec![4]);
+        assert_eq!(ts.day_boundaries(120), vec![4]);
+        // En UTC-6h, la première bougie tombe la veille à 18h locale
+        assert_eq!(ts.day_boundaries(-360), vec![1, 5]);
+        assert!(TimeSeries::new().day_boundaries(0).is_empty());
+    }
+}
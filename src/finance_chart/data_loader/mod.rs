@@ -0,0 +1,1096 @@
+//! Module de chargement et sauvegarde des données financières depuis/vers des fichiers JSON
+//!
+//! Supporte le format Binance klines avec timestamps en millisecondes,
+//! ainsi que le chargement de fichiers CSV (`timestamp,open,high,low,close,volume`).
+//! Un backend SQLite alternatif est disponible avec la feature `sqlite`.
+
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use super::core::{Candle, TimeSeries, SeriesData, SeriesId};
+
+/// Structure JSON pour une bougie Binance
+#[derive(Debug, Deserialize, Serialize)]
+struct JsonKline {
+    /// Timestamp d'ouverture en millisecondes
+    open_time: i64,
+    /// Prix d'ouverture
+    open: f64,
+    /// Prix le plus haut
+    high: f64,
+    /// Prix le plus bas
+    low: f64,
+    /// Prix de clôture
+    close: f64,
+    /// Volume échangé
+    #[serde(rename = "volume")]
+    volume: f64,
+}
+
+/// Structure JSON pour le fichier complet
+#[derive(Debug, Deserialize, Serialize)]
+struct JsonData {
+    /// Symbole de la paire
+    symbol: String,
+    /// Intervalle temporel
+    interval: String,
+    /// Liste des bougies
+    klines: Vec<JsonKline>,
+}
+
+/// Erreur de chargement des données
+#[derive(Debug)]
+pub enum LoadError {
+    /// Erreur d'ouverture du fichier
+    FileOpen(std::io::Error),
+    /// Erreur de parsing JSON
+    JsonParse(serde_json::Error),
+    /// Erreur(s) de parsing CSV (une par ligne invalide)
+    CsvParse(String),
+    /// Erreur de validation des données
+    Validation(String),
+    /// Erreur de la base de données (backend SQLite)
+    #[cfg(feature = "sqlite")]
+    Database(String),
+    /// Fichier trop volumineux
+    FileTooLarge { size: u64, max_size: u64 },
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::FileOpen(e) => write!(f, "Erreur d'ouverture du fichier: {}", e),
+            LoadError::JsonParse(e) => write!(f, "Erreur de parsing JSON: {}", e),
+            LoadError::CsvParse(msg) => write!(f, "Erreur de parsing CSV: {}", msg),
+            LoadError::Validation(msg) => write!(f, "Erreur de validation: {}", msg),
+            #[cfg(feature = "sqlite")]
+            LoadError::Database(msg) => write!(f, "Erreur de base de données: {}", msg),
+            LoadError::FileTooLarge { size, max_size } => {
+                write!(f, "Fichier trop volumineux: {} bytes (max: {} bytes)", size, max_size)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// Taille maximale d'un fichier JSON (100 MB)
+const MAX_FILE_SIZE: u64 = 100 * 1024 * 1024;
+
+/// Nombre maximum de bougies par fichier
+const MAX_CANDLES: usize = 1_000_000;
+
+/// Convertit un intervalle en nom de fichier
+/// 
+/// - "1m" → "1min.json"
+/// - "1M" → "1month.json"
+/// - Les autres intervalles restent inchangés
+pub fn interval_to_filename(interval: &str) -> String {
+    match interval {
+        "1m" => "1min.json".to_string(),
+        "1M" => "1month.json".to_string(),
+        _ => format!("{}.json", interval),
+    }
+}
+
+/// Convertit un nom de fichier en intervalle
+/// 
+/// - "1min.json" → "1m"
+/// - "1month.json" → "1M"
+/// - Les autres restent inchangés (extension `.json` ou `.csv` retirée)
+pub fn filename_to_interval(filename: &str) -> String {
+    let stem = filename
+        .strip_suffix(".json")
+        .or_else(|| filename.strip_suffix(".csv"));
+    match stem {
+        Some("1min") => "1m".to_string(),
+        Some("1month") => "1M".to_string(),
+        Some(stem) => stem.to_string(),
+        None => filename.to_string(),
+    }
+}
+
+/// Valide les données JSON chargées
+fn validate_json_data(data: &JsonData) -> Result<(), LoadError> {
+    // Vérifier que le symbole n'est pas vide
+    if data.symbol.trim().is_empty() {
+        return Err(LoadError::Validation("Le symbole ne peut pas être vide".to_string()));
+    }
+    
+    // Vérifier que l'intervalle n'est pas vide
+    if data.interval.trim().is_empty() {
+        return Err(LoadError::Validation("L'intervalle ne peut pas être vide".to_string()));
+    }
+    
+    // Vérifier le nombre de bougies
+    if data.klines.len() > MAX_CANDLES {
+        return Err(LoadError::Validation(format!(
+            "Trop de bougies: {} (max: {})",
+            data.klines.len(),
+            MAX_CANDLES
+        )));
+    }
+    
+    // Valider chaque bougie
+    for (idx, kline) in data.klines.iter().enumerate() {
+        // Vérifier que les prix sont valides (positifs et finis)
+        if !kline.open.is_finite() || kline.open <= 0.0 {
+            return Err(LoadError::Validation(format!(
+                "Bougie {}: prix d'ouverture invalide: {}",
+                idx, kline.open
+            )));
+        }
+        if !kline.high.is_finite() || kline.high <= 0.0 {
+            return Err(LoadError::Validation(format!(
+                "Bougie {}: prix maximum invalide: {}",
+                idx, kline.high
+            )));
+        }
+        if !kline.low.is_finite() || kline.low <= 0.0 {
+            return Err(LoadError::Validation(format!(
+                "Bougie {}: prix minimum invalide: {}",
+                idx, kline.low
+            )));
+        }
+        if !kline.close.is_finite() || kline.close <= 0.0 {
+            return Err(LoadError::Validation(format!(
+                "Bougie {}: prix de clôture invalide: {}",
+                idx, kline.close
+            )));
+        }
+        
+        // Vérifier la cohérence OHLC (high >= low, high >= open/close, low <= open/close)
+        if kline.high < kline.low {
+            return Err(LoadError::Validation(format!(
+                "Bougie {}: high ({}) < low ({})",
+                idx, kline.high, kline.low
+            )));
+        }
+        if kline.high < kline.open || kline.high < kline.close {
+            return Err(LoadError::Validation(format!(
+                "Bougie {}: high ({}) doit être >= open ({}) et close ({})",
+                idx, kline.high, kline.open, kline.close
+            )));
+        }
+        if kline.low > kline.open || kline.low > kline.close {
+            return Err(LoadError::Validation(format!(
+                "Bougie {}: low ({}) doit être <= open ({}) et close ({})",
+                idx, kline.low, kline.open, kline.close
+            )));
+        }
+        
+        // Vérifier le timestamp
+        if kline.open_time <= 0 {
+            return Err(LoadError::Validation(format!(
+                "Bougie {}: timestamp invalide: {}",
+                idx, kline.open_time
+            )));
+        }
+    }
+    
+    Ok(())
+}
+
+/// Lit et parse un fichier JSON au format Binance (sans valider les bougies)
+fn read_json_data<P: AsRef<Path>>(path: P) -> Result<JsonData, LoadError> {
+    // Vérifier la taille du fichier avant de l'ouvrir
+    let metadata = std::fs::metadata(&path).map_err(LoadError::FileOpen)?;
+    if metadata.len() > MAX_FILE_SIZE {
+        return Err(LoadError::FileTooLarge {
+            size: metadata.len(),
+            max_size: MAX_FILE_SIZE,
+        });
+    }
+    
+    // Ouvrir le fichier
+    let file = File::open(&path).map_err(LoadError::FileOpen)?;
+    let reader = BufReader::new(file);
+
+    // Parser le JSON
+    serde_json::from_reader(reader).map_err(LoadError::JsonParse)
+}
+
+/// Charge les données depuis un fichier JSON au format Binance
+///
+/// # Arguments
+/// * `path` - Chemin vers le fichier JSON
+///
+/// # Returns
+/// * `Ok(SeriesData)` - Série temporelle chargée avec métadonnées
+/// * `Err(LoadError)` - Erreur de chargement
+///
+/// # Example
+/// ```ignore
+/// let series = load_from_json("data/BTCUSDT_1h.json")?;
+/// ```
+pub fn load_from_json<P: AsRef<Path>>(path: P) -> Result<SeriesData, LoadError> {
+    let json_data = read_json_data(&path)?;
+    
+    // Valider les données
+    validate_json_data(&json_data)?;
+
+    // Créer l'ID de la série à partir du symbole et de l'intervalle du JSON
+    // Format: SYMBOL_INTERVAL (ex: BTCUSDT_1h)
+    let series_id_name = format!("{}_{}", json_data.symbol, json_data.interval);
+    let series_id = SeriesId::new(series_id_name);
+
+    // Convertir en TimeSeries
+    let mut timeseries = TimeSeries::new();
+
+    for kline in json_data.klines {
+        // Convertir timestamp millisecondes → secondes
+        let timestamp = kline.open_time / 1000;
+
+        let candle = Candle::new(
+            timestamp,
+            kline.open,
+            kline.high,
+            kline.low,
+            kline.close,
+            kline.volume,
+        );
+
+        // Ignorer les bougies invalides (déjà validées dans validate_json_data)
+        if let Err(e) = timeseries.push(candle) {
+            eprintln!("⚠️ Bougie invalide ignorée lors du chargement: {}", e);
+        }
+    }
+
+    // Créer SeriesData avec les métadonnées
+    let series = SeriesData::new(
+        series_id,
+        json_data.symbol,
+        json_data.interval,
+        timeseries,
+    );
+
+    Ok(series)
+}
+
+/// Bilan d'un chargement tolérant (`load_from_json_lenient`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoadReport {
+    /// Bougies présentes dans la série chargée
+    pub loaded: usize,
+    /// Bougies corrigées (high/low, volume, ordre chronologique)
+    pub repaired: usize,
+    /// Bougies écartées (prix ou timestamp inutilisables, doublons)
+    pub dropped: usize,
+}
+
+impl std::fmt::Display for LoadReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} bougies chargées, {} réparées, {} écartées",
+            self.loaded, self.repaired, self.dropped
+        )
+    }
+}
+
+/// Répare une liste de bougies au lieu de la rejeter
+///
+/// - les bougies dont l'ouverture, la clôture ou le timestamp sont inutilisables sont écartées ;
+/// - high/low sont élargis pour contenir open/close, un volume négatif ou non fini est ramené à 0 ;
+/// - les bougies sont triées par timestamp (celles qui arrivaient dans le désordre comptent comme réparées) ;
+/// - pour un même timestamp, seule la dernière bougie rencontrée est conservée.
+fn repair_candles(candles: Vec<Candle>) -> (Vec<Candle>, LoadReport) {
+    let mut report = LoadReport::default();
+    let mut repaired: Vec<Candle> = Vec::with_capacity(candles.len());
+    let mut latest_timestamp = i64::MIN;
+
+    for mut candle in candles {
+        let usable_price = |price: f64| price.is_finite() && price > 0.0;
+        if candle.timestamp <= 0 || !usable_price(candle.open) || !usable_price(candle.close) {
+            report.dropped += 1;
+            continue;
+        }
+
+        let max_body = candle.open.max(candle.close);
+        let min_body = candle.open.min(candle.close);
+        let high = if candle.high.is_finite() { candle.high.max(max_body) } else { max_body };
+        let low = if usable_price(candle.low) { candle.low.min(min_body) } else { min_body };
+        let volume = if candle.volume.is_finite() && candle.volume >= 0.0 { candle.volume } else { 0.0 };
+        let mut was_repaired = high != candle.high || low != candle.low || volume != candle.volume;
+        candle.high = high;
+        candle.low = low;
+        candle.volume = volume;
+
+        if candle.timestamp < latest_timestamp {
+            was_repaired = true;
+        }
+        latest_timestamp = latest_timestamp.max(candle.timestamp);
+        if was_repaired {
+            report.repaired += 1;
+        }
+        repaired.push(candle);
+    }
+
+    // Tri stable : à timestamp égal, l'ordre d'origine est conservé et on garde la dernière
+    repaired.sort_by_key(|candle| candle.timestamp);
+    let before_dedup = repaired.len();
+    let mut deduped: Vec<Candle> = Vec::with_capacity(before_dedup);
+    for candle in repaired {
+        match deduped.last_mut() {
+            Some(last) if last.timestamp == candle.timestamp => *last = candle,
+            _ => deduped.push(candle),
+        }
+    }
+    report.dropped += before_dedup - deduped.len();
+    report.loaded = deduped.len();
+
+    (deduped, report)
+}
+
+/// Charge un fichier JSON au format Binance en réparant les bougies malformées
+///
+/// Contrairement à `load_from_json`, une bougie incohérente n'invalide pas tout le
+/// fichier : elle est corrigée ou écartée (voir `repair_candles`). Le symbole,
+/// l'intervalle et le nombre maximum de bougies restent vérifiés.
+///
+/// # Returns
+/// * `Ok((SeriesData, LoadReport))` - Série chargée et bilan des réparations
+/// * `Err(LoadError)` - Fichier illisible ou métadonnées invalides
+pub fn load_from_json_lenient<P: AsRef<Path>>(path: P) -> Result<(SeriesData, LoadReport), LoadError> {
+    let json_data = read_json_data(&path)?;
+
+    if json_data.symbol.trim().is_empty() {
+        return Err(LoadError::Validation("Le symbole ne peut pas être vide".to_string()));
+    }
+    if json_data.interval.trim().is_empty() {
+        return Err(LoadError::Validation("L'intervalle ne peut pas être vide".to_string()));
+    }
+    if json_data.klines.len() > MAX_CANDLES {
+        return Err(LoadError::Validation(format!(
+            "Trop de bougies: {} (max: {})",
+            json_data.klines.len(),
+            MAX_CANDLES
+        )));
+    }
+
+    // Convertir timestamp millisecondes → secondes
+    // Bougies brutes (sans `Candle::new`, qui corrigerait déjà high/low et le volume)
+    // pour que `repair_candles` comptabilise ces défauts dans le bilan
+    let candles: Vec<Candle> = json_data.klines
+        .iter()
+        .map(|kline| Candle {
+            timestamp: kline.open_time / 1000,
+            open: kline.open,
+            high: kline.high,
+            low: kline.low,
+            close: kline.close,
+            volume: kline.volume,
+        })
+        .collect();
+    let (candles, mut report) = repair_candles(candles);
+
+    let mut timeseries = TimeSeries::new();
+    for candle in candles {
+        // Reste rejeté par la validation : timestamp trop loin dans le futur
+        if timeseries.push(candle).is_err() {
+            report.dropped += 1;
+            report.loaded -= 1;
+        }
+    }
+
+    let series_id = SeriesId::new(format!("{}_{}", json_data.symbol, json_data.interval));
+    let series = SeriesData::new(series_id, json_data.symbol, json_data.interval, timeseries);
+    Ok((series, report))
+}
+
+/// Seuil au-delà duquel un timestamp est considéré en millisecondes
+///
+/// 10^11 secondes correspond à l'an 5138 : aucun timestamp en secondes réaliste ne le dépasse.
+const MILLIS_THRESHOLD: i64 = 100_000_000_000;
+
+/// Nombre maximum d'erreurs de ligne détaillées dans le message d'erreur CSV
+const MAX_CSV_ERRORS_REPORTED: usize = 10;
+
+/// Index des colonnes d'un fichier CSV, déterminés à partir de l'en-tête
+#[derive(Debug, Clone, Copy)]
+struct CsvColumns {
+    timestamp: usize,
+    open: usize,
+    high: usize,
+    low: usize,
+    close: usize,
+    /// Le volume est optionnel (0 si absent)
+    volume: Option<usize>,
+}
+
+impl CsvColumns {
+    /// Analyse la ligne d'en-tête (colonnes dans n'importe quel ordre, insensible à la casse)
+    fn from_header(header: &str) -> Result<Self, LoadError> {
+        let names: Vec<String> = header
+            .split(',')
+            .map(|name| name.trim().trim_matches('"').to_ascii_lowercase())
+            .collect();
+        let find = |aliases: &[&str]| names.iter().position(|name| aliases.contains(&name.as_str()));
+        let require = |aliases: &[&str]| {
+            find(aliases).ok_or_else(|| {
+                LoadError::CsvParse(format!("colonne '{}' absente de l'en-tête", aliases[0]))
+            })
+        };
+
+        Ok(Self {
+            timestamp: require(&["timestamp", "time", "open_time"])?,
+            open: require(&["open"])?,
+            high: require(&["high"])?,
+            low: require(&["low"])?,
+            close: require(&["close"])?,
+            volume: find(&["volume"]),
+        })
+    }
+}
+
+/// Normalise un timestamp en secondes (détection automatique des millisecondes)
+fn normalize_timestamp(timestamp: i64) -> i64 {
+    if timestamp.abs() >= MILLIS_THRESHOLD {
+        timestamp / 1000
+    } else {
+        timestamp
+    }
+}
+
+/// Parse une ligne de données CSV en bougie
+fn parse_csv_row(fields: &[&str], columns: &CsvColumns) -> Result<Candle, String> {
+    let field = |index: usize, name: &str| -> Result<&str, String> {
+        fields
+            .get(index)
+            .map(|value| value.trim().trim_matches('"'))
+            .ok_or_else(|| format!("colonne '{}' manquante", name))
+    };
+    let number = |index: usize, name: &str| -> Result<f64, String> {
+        let raw = field(index, name)?;
+        raw.parse::<f64>()
+            .map_err(|_| format!("{} invalide: '{}'", name, raw))
+    };
+
+    let raw_timestamp = field(columns.timestamp, "timestamp")?;
+    let timestamp = raw_timestamp
+        .parse::<i64>()
+        .or_else(|_| raw_timestamp.parse::<f64>().map(|v| v as i64))
+        .map_err(|_| format!("timestamp invalide: '{}'", raw_timestamp))?;
+    let volume = match columns.volume {
+        Some(index) => number(index, "volume")?,
+        None => 0.0,
+    };
+
+    Ok(Candle::new(
+        normalize_timestamp(timestamp),
+        number(columns.open, "open")?,
+        number(columns.high, "high")?,
+        number(columns.low, "low")?,
+        number(columns.close, "close")?,
+        volume,
+    ))
+}
+
+/// Parse le contenu d'un fichier CSV en série temporelle
+///
+/// Les erreurs de chaque ligne (parsing ou validation via `TimeSeries::push`)
+/// sont accumulées dans le message d'erreur retourné.
+fn parse_csv(content: &str) -> Result<TimeSeries, LoadError> {
+    let mut lines = content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'));
+    let (_, header) = lines
+        .next()
+        .ok_or_else(|| LoadError::CsvParse("fichier vide".to_string()))?;
+    let columns = CsvColumns::from_header(header)?;
+
+    let mut timeseries = TimeSeries::new();
+    let mut errors = Vec::new();
+
+    for (line_idx, line) in lines {
+        let line_number = line_idx + 1;
+        if timeseries.len() >= MAX_CANDLES {
+            return Err(LoadError::Validation(format!(
+                "Trop de bougies (max: {})",
+                MAX_CANDLES
+            )));
+        }
+        let fields: Vec<&str> = line.split(',').collect();
+        let result = parse_csv_row(&fields, &columns)
+            .and_then(|candle| timeseries.push(candle).map_err(|e| e.to_string()));
+        if let Err(e) = result {
+            errors.push(format!("ligne {}: {}", line_number, e));
+        }
+    }
+
+    if !errors.is_empty() {
+        let total = errors.len();
+        let mut message = errors
+            .into_iter()
+            .take(MAX_CSV_ERRORS_REPORTED)
+            .collect::<Vec<_>>()
+            .join("; ");
+        if total > MAX_CSV_ERRORS_REPORTED {
+            message.push_str(&format!(" (et {} autres erreurs)", total - MAX_CSV_ERRORS_REPORTED));
+        }
+        return Err(LoadError::CsvParse(message));
+    }
+
+    Ok(timeseries)
+}
+
+/// Déduit le symbole et l'intervalle à partir du chemin d'un fichier CSV
+///
+/// - `SYMBOL_INTERVAL.csv` (ex: `BTCUSDT_1h.csv`) → ("BTCUSDT", "1h")
+/// - `{Symbol}/{interval}.csv` (ex: `Binance/BTCUSDT/1min.csv`) → ("BTCUSDT", "1m")
+fn infer_symbol_interval(path: &Path) -> Result<(String, String), LoadError> {
+    let file_name = path
+        .file_name()
+        .and_then(|s| s.to_str())
+        .ok_or_else(|| LoadError::Validation("Nom de fichier invalide".to_string()))?;
+    let interval_or_stem = filename_to_interval(file_name);
+
+    if let Some((symbol, interval)) = interval_or_stem.rsplit_once('_') {
+        if !symbol.is_empty() && !interval.is_empty() {
+            return Ok((symbol.to_string(), interval.to_string()));
+        }
+    }
+
+    let symbol = path
+        .parent()
+        .and_then(|p| p.file_name())
+        .and_then(|s| s.to_str())
+        .filter(|s| !s.trim().is_empty())
+        .ok_or_else(|| LoadError::Validation(format!(
+            "Impossible de déduire le symbole depuis {:?}", path
+        )))?;
+    Ok((symbol.to_string(), interval_or_stem))
+}
+
+/// Charge les données depuis un fichier CSV
+///
+/// Les lignes commençant par `#` sont des commentaires et sont ignorées.
+/// L'en-tête doit contenir les colonnes `timestamp,open,high,low,close,volume`
+/// (dans n'importe quel ordre). Les timestamps peuvent être en secondes ou en
+/// millisecondes (détection automatique). Le symbole et l'intervalle sont
+/// déduits du nom du fichier.
+///
+/// # Example
+/// ```ignore
+/// let series = load_from_csv("data/BTCUSDT_1h.csv")?;
+/// ```
+pub fn load_from_csv<P: AsRef<Path>>(path: P) -> Result<SeriesData, LoadError> {
+    let path = path.as_ref();
+    let metadata = std::fs::metadata(path).map_err(LoadError::FileOpen)?;
+    if metadata.len() > MAX_FILE_SIZE {
+        return Err(LoadError::FileTooLarge {
+            size: metadata.len(),
+            max_size: MAX_FILE_SIZE,
+        });
+    }
+
+    let (symbol, interval) = infer_symbol_interval(path)?;
+    let content = std::fs::read_to_string(path).map_err(LoadError::FileOpen)?;
+    let timeseries = parse_csv(&content)?;
+
+    let series_id = SeriesId::new(format!("{}_{}", symbol, interval));
+    Ok(SeriesData::new(series_id, symbol, interval, timeseries))
+}
+
+/// Charge toutes les séries depuis un dossier (récursif)
+///
+/// # Arguments
+/// * `dir_path` - Chemin vers le dossier contenant les fichiers JSON
+///
+/// # Returns
+/// * `Ok(Vec<SeriesData>)` - Liste de toutes les séries chargées
+/// * `Err(LoadError)` - Erreur de chargement
+///
+/// # Structure supportée
+/// - `data/*.json` (ancien format)
+/// - `data/{Provider}/{Symbol}/*.json` (nouveau format)
+/// - `data/**/*.csv` (symbole et intervalle déduits du nom du fichier)
+pub fn load_all_from_directory<P: AsRef<Path>>(dir_path: P) -> Result<Vec<SeriesData>, LoadError> {
+    use std::fs;
+    
+    let mut series_list = Vec::new();
+    let dir = fs::read_dir(&dir_path).map_err(|e| LoadError::FileOpen(e))?;
+    
+    for entry in dir {
+        let entry = entry.map_err(|e| LoadError::FileOpen(e))?;
+        let path = entry.path();
+        
+        if path.is_dir() {
+            // Si c'est un dossier, chercher récursivement
+            match load_all_from_directory(&path) {
+                Ok(mut sub_series) => series_list.append(&mut sub_series),
+                Err(e) => {
+                    eprintln!("⚠️ Erreur lors du chargement du dossier {:?}: {}", path, e);
+                }
+            }
+        } else if path.extension().and_then(|s| s.to_str()) == Some("json") {
+            // Charger uniquement les fichiers .json ; un fichier rejeté par la
+            // validation stricte est rechargé en réparant les bougies malformées
+            let loaded = match load_from_json(&path) {
+                Err(LoadError::Validation(msg)) => {
+                    eprintln!("⚠️ {:?}: {} — tentative de réparation", path, msg);
+                    load_from_json_lenient(&path).map(|(series, report)| {
+                        println!("  🔧 {:?}: {}", path, report);
+                        series
+                    })
+                }
+                other => other,
+            };
+            match loaded {
+                Ok(mut series) => {
+                    // Si le nom du fichier utilise le nouveau format (1min.json, 1month.json),
+                    // corriger l'intervalle dans les données pour correspondre au nom du fichier
+                    if let Some(file_name) = path.file_name().and_then(|s| s.to_str()) {
+                        let expected_interval = filename_to_interval(file_name);
+                        if expected_interval != series.interval {
+                            // Le fichier utilise le nouveau format ou il y a une incohérence
+                            let old_interval = series.interval.clone();
+                            series.interval = expected_interval.clone();
+                            // Recalculer le SeriesId avec le bon intervalle
+                            let series_id_name = format!("{}_{}", series.symbol, expected_interval);
+                            series.id = SeriesId::new(series_id_name);
+                            
+                            // Afficher un message informatif
+                            if file_name == "1min.json" || file_name == "1month.json" {
+                                println!("  ℹ️  {:?}: Intervalle corrigé de '{}' à '{}' (nouveau format de fichier)", 
+                                    path, old_interval, expected_interval);
+                            } else {
+                                eprintln!("⚠️ Incohérence dans {:?}: le fichier s'appelle '{}' mais contient l'intervalle '{}'. Corrigé en '{}'", 
+                                    path, file_name, old_interval, expected_interval);
+                            }
+                        }
+                    }
+                    series_list.push(series);
+                }
+                Err(e) => {
+                    eprintln!("⚠️ Erreur lors du chargement de {:?}: {}", path, e);
+                }
+            }
+        } else if path.extension().and_then(|s| s.to_str()) == Some("csv") {
+            match load_from_csv(&path) {
+                Ok(series) => series_list.push(series),
+                Err(e) => {
+                    eprintln!("⚠️ Erreur lors du chargement de {:?}: {}", path, e);
+                }
+            }
+        }
+    }
+    
+    Ok(series_list)
+}
+
+/// Vérifie si un dossier est vide (pas de fichiers JSON ni CSV)
+///
+/// # Arguments
+/// * `dir_path` - Chemin vers le dossier à vérifier
+///
+/// # Returns
+/// * `Ok(true)` - Le dossier est vide ou n'existe pas
+/// * `Ok(false)` - Le dossier contient des fichiers JSON
+/// * `Err(LoadError)` - Erreur lors de la vérification
+pub fn is_directory_empty<P: AsRef<Path>>(dir_path: P) -> Result<bool, LoadError> {
+    use std::fs;
+    
+    let dir = match fs::read_dir(&dir_path) {
+        Ok(dir) => dir,
+        Err(_) => return Ok(true), // Dossier n'existe pas = vide
+    };
+    
+    for entry in dir {
+        let entry = entry.map_err(|e| LoadError::FileOpen(e))?;
+        let path = entry.path();
+        
+        if path.is_dir() {
+            // Vérifier récursivement dans les sous-dossiers
+            if !is_directory_empty(&path)? {
+                return Ok(false);
+            }
+        } else if matches!(path.extension().and_then(|s| s.to_str()), Some("json") | Some("csv")) {
+            // Trouvé un fichier de données, le dossier n'est pas vide
+            return Ok(false);
+        }
+    }
+    
+    Ok(true)
+}
+
+/// Erreur de sauvegarde des données
+#[derive(Debug)]
+pub enum SaveError {
+    /// Erreur d'écriture du fichier
+    FileWrite(std::io::Error),
+    /// Erreur de sérialisation JSON
+    JsonSerialize(serde_json::Error),
+    /// Erreur de la base de données (backend SQLite)
+    #[cfg(feature = "sqlite")]
+    Database(String),
+}
+
+impl std::fmt::Display for SaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveError::FileWrite(e) => write!(f, "Erreur d'écriture du fichier: {}", e),
+            SaveError::JsonSerialize(e) => write!(f, "Erreur de sérialisation JSON: {}", e),
+            #[cfg(feature = "sqlite")]
+            SaveError::Database(msg) => write!(f, "Erreur de base de données: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+/// Sauvegarde une série dans un fichier JSON au format Binance
+///
+/// # Arguments
+/// * `series` - La série à sauvegarder
+/// * `path` - Chemin vers le fichier JSON de destination
+///
+/// # Returns
+/// * `Ok(())` - Sauvegarde réussie
+/// * `Err(SaveError)` - Erreur de sauvegarde
+///
+/// # Example
+/// ```ignore
+/// save_to_json(&series, "data/BTCUSDT_1h.json")?;
+/// ```
+pub fn save_to_json<P: AsRef<Path>>(series: &SeriesData, path: P) -> Result<(), SaveError> {
+    // Convertir les bougies en format JSON
+    let klines: Vec<JsonKline> = series.data.all_candles()
+        .iter()
+        .map(|candle| JsonKline {
+            open_time: candle.timestamp * 1000, // Convertir secondes → millisecondes
+            open: candle.open,
+            high: candle.high,
+            low: candle.low,
+            close: candle.close,
+            volume: candle.volume,
+        })
+        .collect();
+
+    // Créer la structure JSON complète
+    let json_data = JsonData {
+        symbol: series.symbol.clone(),
+        interval: series.interval.clone(),
+        klines,
+    };
+
+    // Sérialiser en JSON avec indentation
+    let json = serde_json::to_string_pretty(&json_data)
+        .map_err(SaveError::JsonSerialize)?;
+
+    // Écrire dans le fichier
+    std::fs::write(&path, json)
+        .map_err(SaveError::FileWrite)?;
+
+    Ok(())
+}
+
+/// Ajoute à un fichier JSON existant les bougies postérieures à sa dernière kline
+///
+/// Seule la fin du fichier est lue : la dernière kline est remplacée si la série
+/// contient une bougie au même timestamp (bougie en formation), les bougies plus
+/// récentes sont ajoutées à sa suite. Les klines précédentes restent intactes, même
+/// si elles ne figurent plus dans la série (historique tronqué en mémoire).
+///
+/// # Returns
+/// * `Ok(true)` - Fichier mis à jour
+/// * `Ok(false)` - Fichier absent ou fin de fichier non reconnue (aucune modification)
+/// * `Err(SaveError)` - Erreur d'écriture
+#[cfg_attr(feature = "sqlite", allow(dead_code))] // Sauvegardes en base avec SQLite
+pub fn append_to_json<P: AsRef<Path>>(series: &SeriesData, path: P) -> Result<bool, SaveError> {
+    use std::io::{Read, Seek, SeekFrom, Write};
+
+    /// Taille de la fin de fichier lue (plusieurs klines indentées)
+    const JSON_TAIL_BYTES: u64 = 4096;
+
+    /// Formate une kline comme `save_to_json` (objet indenté dans le tableau `klines`)
+    fn kline_to_pretty_json(candle: &Candle) -> Result<String, SaveError> {
+        let kline = JsonKline {
+            open_time: candle.timestamp * 1000,
+            open: candle.open,
+            high: candle.high,
+            low: candle.low,
+            close: candle.close,
+            volume: candle.volume,
+        };
+        serde_json::to_string_pretty(&kline)
+            .map(|json| json.replace('\n', "\n    "))
+            .map_err(SaveError::JsonSerialize)
+    }
+
+    let mut file = match std::fs::OpenOptions::new().read(true).write(true).open(&path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(SaveError::FileWrite(e)),
+    };
+    let len = file.metadata().map_err(SaveError::FileWrite)?.len();
+    let tail_start = len.saturating_sub(JSON_TAIL_BYTES);
+    let mut tail = Vec::new();
+    file.seek(SeekFrom::Start(tail_start))
+        .and_then(|_| file.read_to_end(&mut tail))
+        .map_err(SaveError::FileWrite)?;
+
+    // Fin attendue : `...{kline}` `]` `}` (espaces ignorés)
+    let last_token = |end: usize, token: u8| {
+        tail[..end]
+            .iter()
+            .rposition(|b| !b.is_ascii_whitespace())
+            .filter(|&i| tail[i] == token)
+    };
+    let Some(kline_end) = last_token(tail.len(), b'}')
+        .and_then(|root_end| last_token(root_end, b']'))
+        .and_then(|array_end| last_token(array_end, b'}'))
+    else {
+        return Ok(false);
+    };
+    let Some(kline_start) = tail[..kline_end].iter().rposition(|&b| b == b'{') else {
+        return Ok(false);
+    };
+    let Ok(last) = serde_json::from_slice::<JsonKline>(&tail[kline_start..=kline_end]) else {
+        return Ok(false);
+    };
+
+    let last_timestamp = last.open_time / 1000;
+    let candles = series.data.all_candles();
+    let new_candles = &candles[candles.partition_point(|c| c.timestamp < last_timestamp)..];
+    if new_candles.is_empty() {
+        return Ok(true);
+    }
+    // La dernière kline est réécrite si la série en contient une version à jour
+    let (cut, mut suffix) = if new_candles[0].timestamp == last_timestamp {
+        (kline_start, String::new())
+    } else {
+        (kline_end + 1, ",\n    ".to_string())
+    };
+    let klines = new_candles
+        .iter()
+        .map(kline_to_pretty_json)
+        .collect::<Result<Vec<_>, _>>()?;
+    suffix.push_str(&klines.join(",\n    "));
+    suffix.push_str("\n  ]\n}");
+
+    file.set_len(tail_start + cut as u64)
+        .and_then(|_| file.seek(SeekFrom::End(0)))
+        .and_then(|_| file.write_all(suffix.as_bytes()))
+        .map_err(SaveError::FileWrite)?;
+    Ok(true)
+}
+
+/// Formate des bougies en CSV (`timestamp,open,high,low,close,volume`)
+///
+/// Une ligne de commentaire en tête rappelle le symbole et l'intervalle.
+/// Les timestamps sont écrits en secondes.
+fn candles_to_csv(symbol: &str, interval: &str, candles: &[Candle]) -> String {
+    let mut csv = format!("# {} {}\ntimestamp,open,high,low,close,volume\n", symbol, interval);
+    for candle in candles {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            candle.timestamp, candle.open, candle.high, candle.low, candle.close, candle.volume
+        ));
+    }
+    csv
+}
+
+/// Exporte en CSV les bougies d'une série comprises dans `time_range` (bornes incluses)
+///
+/// Le fichier reste relisible par `load_from_csv`.
+///
+/// # Returns
+/// * `Ok(n)` - Nombre de bougies écrites
+/// * `Err(SaveError)` - Erreur d'écriture
+///
+/// # Example
+/// ```ignore
+/// export_visible_to_csv(&series, viewport.time_scale().time_range(), "exports/BTCUSDT_1h.csv")?;
+/// ```
+pub fn export_visible_to_csv<P: AsRef<Path>>(
+    series: &SeriesData,
+    time_range: (i64, i64),
+    path: P,
+) -> Result<usize, SaveError> {
+    let candles = series.data.candles_in_range(time_range.0, time_range.1);
+    let csv = candles_to_csv(&series.symbol, &series.interval, candles);
+    std::fs::write(&path, csv).map_err(SaveError::FileWrite)?;
+    Ok(candles.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_nonexistent_file() {
+        let result = load_from_json("nonexistent.json");
+        assert!(result.is_err());
+    }
+
+    fn candle(timestamp: i64, open: f64, high: f64, low: f64, close: f64, volume: f64) -> Candle {
+        Candle::new(timestamp, open, high, low, close, volume)
+    }
+
+    #[test]
+    fn test_repair_clamps_high_low_and_volume() {
+        // high < low et ne contenant pas la clôture, puis volume négatif
+        let json = r#"{"symbol": "TEST", "interval": "1m", "klines": [
+            {"open_time": 100000, "open": 10.0, "high": 9.0, "low": 11.0, "close": 12.0, "volume": 5.0},
+            {"open_time": 200000, "open": 10.0, "high": 12.0, "low": 9.0, "close": 11.0, "volume": -3.0}
+        ]}"#;
+        let path = std::env::temp_dir().join(format!("candlechart_lenient_{}.json", std::process::id()));
+        std::fs::write(&path, json).unwrap();
+        let result = load_from_json_lenient(&path);
+        let _ = std::fs::remove_file(&path);
+
+        let (series, report) = result.unwrap();
+        assert_eq!(report, LoadReport { loaded: 2, repaired: 2, dropped: 0 });
+        let candles = series.data.all_candles();
+        assert_eq!((candles[0].high, candles[0].low), (12.0, 10.0));
+        assert_eq!(candles[1].volume, 0.0);
+    }
+
+    #[test]
+    fn test_repair_keeps_last_duplicate() {
+        let (candles, report) = repair_candles(vec![
+            candle(100, 10.0, 11.0, 9.0, 10.5, 1.0),
+            candle(100, 10.0, 12.0, 9.0, 11.5, 2.0),
+            candle(200, 11.0, 12.0, 10.0, 11.0, 1.0),
+        ]);
+        assert_eq!(report, LoadReport { loaded: 2, repaired: 0, dropped: 1 });
+        assert_eq!(candles[0].close, 11.5);
+    }
+
+    #[test]
+    fn test_repair_sorts_out_of_order() {
+        let (candles, report) = repair_candles(vec![
+            candle(300, 10.0, 11.0, 9.0, 10.0, 1.0),
+            candle(100, 10.0, 11.0, 9.0, 10.0, 1.0),
+            candle(200, 10.0, 11.0, 9.0, 10.0, 1.0),
+        ]);
+        let timestamps: Vec<i64> = candles.iter().map(|c| c.timestamp).collect();
+        assert_eq!(timestamps, vec![100, 200, 300]);
+        assert_eq!(report, LoadReport { loaded: 3, repaired: 2, dropped: 0 });
+    }
+
+    #[test]
+    fn test_repair_drops_unusable_candles() {
+        let (candles, report) = repair_candles(vec![
+            candle(0, 10.0, 11.0, 9.0, 10.0, 1.0),
+            candle(100, f64::NAN, 11.0, 9.0, 10.0, 1.0),
+            candle(200, 10.0, 11.0, 9.0, -1.0, 1.0),
+            candle(300, 10.0, 11.0, 9.0, 10.0, 1.0),
+        ]);
+        assert_eq!(candles.len(), 1);
+        assert_eq!(report, LoadReport { loaded: 1, repaired: 0, dropped: 3 });
+    }
+
+    /// Premier timestamp des séries de test (les timestamps nuls sont rejetés)
+    const T0: i64 = 1_700_000_000;
+
+    fn series_from(candles: &[(i64, f64)]) -> SeriesData {
+        let mut data = TimeSeries::new();
+        for &(timestamp, close) in candles {
+            data.push(Candle::new(T0 + timestamp, close, close + 1.0, close - 1.0, close, 1.0)).unwrap();
+        }
+        SeriesData::new(SeriesId::new("TEST_1h"), "TEST".to_string(), "1h".to_string(), data)
+    }
+
+    #[test]
+    fn test_append_to_json_keeps_file_prefix() {
+        let path = std::env::temp_dir().join(format!("candlechart_append_{}.json", std::process::id()));
+        save_to_json(&series_from(&[(0, 10.0), (3600, 11.0), (7200, 12.0)]), &path).unwrap();
+
+        // Série tronquée : la dernière bougie du fichier a évolué, deux bougies sont nouvelles
+        let trimmed = series_from(&[(3600, 11.0), (7200, 13.0), (10800, 14.0), (14400, 15.0)]);
+        assert!(append_to_json(&trimmed, &path).unwrap());
+
+        let saved = load_from_json(&path).unwrap();
+        let closes: Vec<_> = saved.data.all_candles().iter().map(|c| (c.timestamp - T0, c.close)).collect();
+        assert_eq!(closes, vec![(0, 10.0), (3600, 11.0), (7200, 13.0), (10800, 14.0), (14400, 15.0)]);
+
+        // Aucune bougie plus récente : fichier inchangé
+        assert!(append_to_json(&series_from(&[(3600, 20.0)]), &path).unwrap());
+        assert_eq!(load_from_json(&path).unwrap().data.len(), 5);
+        let _ = std::fs::remove_file(&path);
+
+        assert!(!append_to_json(&trimmed, &path).unwrap());
+    }
+
+    #[test]
+    fn test_filename_to_interval() {
+        assert_eq!(filename_to_interval("1min.json"), "1m");
+        assert_eq!(filename_to_interval("1month.csv"), "1M");
+        assert_eq!(filename_to_interval("4h.csv"), "4h");
+        assert_eq!(filename_to_interval("1h"), "1h");
+    }
+
+    #[test]
+    fn test_parse_csv_any_column_order() {
+        let csv = "close,open,timestamp,low,high,volume\n\
+                   105,100,1700000000,95,110,12.5\n\
+                   108,105,1700003600,104,109,3\n";
+        let series = parse_csv(csv).unwrap();
+        let candles = series.all_candles();
+        assert_eq!(candles.len(), 2);
+        assert_eq!(candles[0].timestamp, 1_700_000_000);
+        assert_eq!(candles[0].open, 100.0);
+        assert_eq!(candles[0].high, 110.0);
+        assert_eq!(candles[0].close, 105.0);
+        assert_eq!(candles[1].volume, 3.0);
+    }
+
+    #[test]
+    fn test_parse_csv_millisecond_timestamps() {
+        let csv = "timestamp,open,high,low,close,volume\n1700000000000,1,2,0.5,1.5,10\n";
+        let series = parse_csv(csv).unwrap();
+        assert_eq!(series.all_candles()[0].timestamp, 1_700_000_000);
+    }
+
+    #[test]
+    fn test_parse_csv_accumulates_row_errors() {
+        let csv = "timestamp,open,high,low,close\n\
+                   1700000000,abc,2,0.5,1.5\n\
+                   1700003600,1,2,0.5,1.5\n\
+                   1700007200,1,2\n";
+        match parse_csv(csv) {
+            Err(LoadError::CsvParse(msg)) => {
+                assert!(msg.contains("ligne 2"), "{}", msg);
+                assert!(msg.contains("ligne 4"), "{}", msg);
+                assert!(!msg.contains("ligne 3"), "{}", msg);
+            }
+            other => panic!("erreur CSV attendue, obtenu {:?}", other.map(|s| s.len())),
+        }
+    }
+
+    #[test]
+    fn test_parse_csv_missing_column() {
+        let csv = "timestamp,open,high,close\n1700000000,1,2,1.5\n";
+        assert!(matches!(parse_csv(csv), Err(LoadError::CsvParse(_))));
+    }
+
+    #[test]
+    fn test_infer_symbol_interval() {
+        let (symbol, interval) = infer_symbol_interval(Path::new("data/BTCUSDT_1h.csv")).unwrap();
+        assert_eq!((symbol.as_str(), interval.as_str()), ("BTCUSDT", "1h"));
+
+        let (symbol, interval) = infer_symbol_interval(Path::new("data/Binance/ETHUSDT/1min.csv")).unwrap();
+        assert_eq!((symbol.as_str(), interval.as_str()), ("ETHUSDT", "1m"));
+    }
+
+    #[test]
+    fn test_csv_export_round_trip() {
+        let candles = vec![
+            Candle::new(1000, 100.0, 105.0, 99.0, 104.0, 10.0),
+            Candle::new(2000, 104.0, 106.5, 103.0, 105.0, 12.5),
+        ];
+        let csv = candles_to_csv("BTCUSDT", "1h", &candles);
+        assert!(csv.starts_with("# BTCUSDT 1h\ntimestamp,open,high,low,close,volume\n"));
+
+        let parsed = parse_csv(&csv).unwrap();
+        assert_eq!(parsed.all_candles(), &candles[..]);
+    }
+}
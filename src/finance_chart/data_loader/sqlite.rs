@@ -0,0 +1,251 @@
+//! Backend de stockage SQLite pour les séries de bougies
+//!
+//! Alternative aux fichiers JSON pour les historiques volumineux : toutes les
+//! bougies sont stockées dans une seule table indexée par
+//! `(symbol, interval, timestamp)`, ce qui rend les sauvegardes idempotentes
+//! (upsert) et permet de ne charger qu'une plage de temps.
+
+use std::ops::Range;
+use std::path::Path;
+
+use rusqlite::{params, Connection};
+
+use super::{LoadError, SaveError};
+use crate::finance_chart::core::{Candle, SeriesData, SeriesId, TimeSeries};
+
+/// Schéma de la table des bougies (timestamps en secondes)
+const SCHEMA: &str = "CREATE TABLE IF NOT EXISTS candles (
+    symbol    TEXT    NOT NULL,
+    interval  TEXT    NOT NULL,
+    timestamp INTEGER NOT NULL,
+    open      REAL    NOT NULL,
+    high      REAL    NOT NULL,
+    low       REAL    NOT NULL,
+    close     REAL    NOT NULL,
+    volume    REAL    NOT NULL,
+    PRIMARY KEY (symbol, interval, timestamp)
+) WITHOUT ROWID";
+
+/// Ouvre (ou crée) la base et s'assure que le schéma existe
+fn open_database(db_path: &Path) -> rusqlite::Result<Connection> {
+    let conn = Connection::open(db_path)?;
+    conn.execute_batch(SCHEMA)?;
+    Ok(conn)
+}
+
+/// Lit les bougies d'un symbole/intervalle, triées par timestamp
+fn query_candles(
+    conn: &Connection,
+    symbol: &str,
+    interval: &str,
+    time_range: Range<i64>,
+) -> rusqlite::Result<Vec<Candle>> {
+    let mut stmt = conn.prepare(
+        "SELECT timestamp, open, high, low, close, volume FROM candles
+         WHERE symbol = ?1 AND interval = ?2 AND timestamp >= ?3 AND timestamp < ?4
+         ORDER BY timestamp",
+    )?;
+    let rows = stmt.query_map(
+        params![symbol, interval, time_range.start, time_range.end],
+        |row| {
+            Ok(Candle::new(
+                row.get(0)?,
+                row.get(1)?,
+                row.get(2)?,
+                row.get(3)?,
+                row.get(4)?,
+                row.get(5)?,
+            ))
+        },
+    )?;
+    rows.collect()
+}
+
+/// Construit une SeriesData à partir des bougies lues en base
+fn build_series(symbol: &str, interval: &str, candles: Vec<Candle>) -> SeriesData {
+    let mut timeseries = TimeSeries::new();
+    for candle in candles {
+        if let Err(e) = timeseries.push(candle) {
+            eprintln!("⚠️ Bougie invalide ignorée lors du chargement SQLite: {}", e);
+        }
+    }
+
+    let series_id = SeriesId::new(format!("{}_{}", symbol, interval));
+    SeriesData::new(series_id, symbol.to_string(), interval.to_string(), timeseries)
+}
+
+/// Charge toutes les bougies d'un symbole/intervalle depuis une base SQLite
+///
+/// # Example
+/// ```ignore
+/// let series = load_from_sqlite("data/candles.db", "BTCUSDT", "1h")?;
+/// ```
+pub fn load_from_sqlite<P: AsRef<Path>>(
+    db_path: P,
+    symbol: &str,
+    interval: &str,
+) -> Result<SeriesData, LoadError> {
+    load_range_from_sqlite(db_path, symbol, interval, i64::MIN..i64::MAX)
+}
+
+/// Charge uniquement les bougies dont le timestamp est dans `time_range`
+///
+/// Permet au viewport de charger les données par pages au lieu de charger
+/// tout l'historique d'un symbole.
+///
+/// # Example
+/// ```ignore
+/// let page = load_range_from_sqlite("data/candles.db", "BTCUSDT", "1h", start..end)?;
+/// ```
+pub fn load_range_from_sqlite<P: AsRef<Path>>(
+    db_path: P,
+    symbol: &str,
+    interval: &str,
+    time_range: Range<i64>,
+) -> Result<SeriesData, LoadError> {
+    let db_path = db_path.as_ref();
+    if !db_path.exists() {
+        return Err(LoadError::FileOpen(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("Base introuvable: {}", db_path.display()),
+        )));
+    }
+
+    let conn = open_database(db_path).map_err(|e| LoadError::Database(e.to_string()))?;
+    let candles = query_candles(&conn, symbol, interval, time_range)
+        .map_err(|e| LoadError::Database(e.to_string()))?;
+
+    Ok(build_series(symbol, interval, candles))
+}
+
+/// Retourne les timestamps min/max stockés pour un symbole/intervalle
+///
+/// Utile pour connaître l'étendue disponible avant de charger une page.
+pub fn sqlite_time_bounds<P: AsRef<Path>>(
+    db_path: P,
+    symbol: &str,
+    interval: &str,
+) -> Result<Option<(i64, i64)>, LoadError> {
+    let conn = open_database(db_path.as_ref()).map_err(|e| LoadError::Database(e.to_string()))?;
+    conn.query_row(
+        "SELECT MIN(timestamp), MAX(timestamp) FROM candles WHERE symbol = ?1 AND interval = ?2",
+        params![symbol, interval],
+        |row| Ok(row.get::<_, Option<i64>>(0)?.zip(row.get::<_, Option<i64>>(1)?)),
+    )
+    .map_err(|e| LoadError::Database(e.to_string()))
+}
+
+/// Sauvegarde une série dans une base SQLite
+///
+/// Les bougies existantes (même symbole, intervalle et timestamp) sont
+/// remplacées : sauvegarder plusieurs fois la même série est sans effet.
+///
+/// # Example
+/// ```ignore
+/// save_to_sqlite(&series, "data/candles.db")?;
+/// ```
+pub fn save_to_sqlite<P: AsRef<Path>>(series: &SeriesData, db_path: P) -> Result<(), SaveError> {
+    let db_err = |e: rusqlite::Error| SaveError::Database(e.to_string());
+
+    let mut conn = open_database(db_path.as_ref()).map_err(db_err)?;
+    let tx = conn.transaction().map_err(db_err)?;
+    {
+        let mut stmt = tx
+            .prepare(
+                "INSERT INTO candles (symbol, interval, timestamp, open, high, low, close, volume)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                 ON CONFLICT (symbol, interval, timestamp) DO UPDATE SET
+                    open = excluded.open,
+                    high = excluded.high,
+                    low = excluded.low,
+                    close = excluded.close,
+                    volume = excluded.volume",
+            )
+            .map_err(db_err)?;
+
+        for candle in series.data.all_candles() {
+            stmt.execute(params![
+                series.symbol,
+                series.interval,
+                candle.timestamp,
+                candle.open,
+                candle.high,
+                candle.low,
+                candle.close,
+                candle.volume,
+            ])
+            .map_err(db_err)?;
+        }
+    }
+    tx.commit().map_err(db_err)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// Chemin de base temporaire unique par test
+    fn temp_db(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("candlechart_{}_{}.db", name, std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn series(candles: &[(i64, f64)]) -> SeriesData {
+        let mut data = TimeSeries::new();
+        for &(timestamp, close) in candles {
+            data.push(Candle::new(timestamp, close, close + 1.0, close - 1.0, close, 1.0)).unwrap();
+        }
+        build_series("BTCUSDT", "1h", data.all_candles().to_vec())
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let db = temp_db("roundtrip");
+        save_to_sqlite(&series(&[(3600, 10.0), (7200, 11.0)]), &db).unwrap();
+
+        let loaded = load_from_sqlite(&db, "BTCUSDT", "1h").unwrap();
+        assert_eq!(loaded.id.name, "BTCUSDT_1h");
+        assert_eq!(loaded.data.len(), 2);
+        assert_eq!(loaded.data.all_candles()[1].close, 11.0);
+
+        // Autre intervalle : aucune bougie
+        assert_eq!(load_from_sqlite(&db, "BTCUSDT", "4h").unwrap().data.len(), 0);
+        let _ = std::fs::remove_file(&db);
+    }
+
+    #[test]
+    fn test_save_is_idempotent_upsert() {
+        let db = temp_db("upsert");
+        save_to_sqlite(&series(&[(3600, 10.0), (7200, 11.0)]), &db).unwrap();
+        save_to_sqlite(&series(&[(7200, 12.0), (10800, 13.0)]), &db).unwrap();
+
+        let loaded = load_from_sqlite(&db, "BTCUSDT", "1h").unwrap();
+        let candles = loaded.data.all_candles();
+        assert_eq!(candles.len(), 3);
+        assert_eq!(candles[1].close, 12.0);
+        let _ = std::fs::remove_file(&db);
+    }
+
+    #[test]
+    fn test_load_time_range() {
+        let db = temp_db("range");
+        save_to_sqlite(&series(&[(3600, 1.0), (7200, 2.0), (10800, 3.0), (14400, 4.0)]), &db).unwrap();
+
+        let page = load_range_from_sqlite(&db, "BTCUSDT", "1h", 7200..14400).unwrap();
+        let timestamps: Vec<i64> = page.data.all_candles().iter().map(|c| c.timestamp).collect();
+        assert_eq!(timestamps, vec![7200, 10800]);
+        assert_eq!(sqlite_time_bounds(&db, "BTCUSDT", "1h").unwrap(), Some((3600, 14400)));
+        assert_eq!(sqlite_time_bounds(&db, "ETHUSDT", "1h").unwrap(), None);
+        let _ = std::fs::remove_file(&db);
+    }
+
+    #[test]
+    fn test_load_missing_database() {
+        let db = temp_db("missing");
+        assert!(matches!(load_from_sqlite(&db, "BTCUSDT", "1h"), Err(LoadError::FileOpen(_))));
+    }
+}
@@ -0,0 +1,104 @@
+//! Export du graphique en image PNG
+//!
+//! Le graphique est redessiné hors écran avec le moteur de rendu logiciel
+//! (tiny-skia), via la même séquence de rendu que le widget (`draw_chart`).
+
+use iced::advanced::graphics::geometry::Renderer as _;
+use iced::advanced::renderer::Headless;
+use iced::widget::canvas::Frame;
+use iced::{Font, Pixels, Size};
+
+use super::settings::{ChartStyle, SettingsState};
+use super::state::ChartState;
+use super::tools::ToolsState;
+use super::widget::{draw_chart, ChartProgram, WidgetState};
+
+/// Dessine le graphique hors écran et retourne l'image encodée en PNG
+///
+/// Le viewport est redimensionné à `size` sur une copie de l'état : la vue
+/// affichée n'est pas modifiée. Le crosshair n'est pas dessiné.
+pub fn render_to_png(
+    chart_state: &ChartState,
+    tools_state: &ToolsState,
+    settings_state: &SettingsState,
+    chart_style: &ChartStyle,
+    size: Size<u32>,
+) -> Result<Vec<u8>, String> {
+    if size.width == 0 || size.height == 0 {
+        return Err("Taille d'export invalide".to_string());
+    }
+
+    let mut renderer = futures::executor::block_on(<iced::Renderer as Headless>::new(
+        Font::default(),
+        Pixels(16.0),
+        Some("tiny-skia"),
+    ))
+    .ok_or_else(|| "Moteur de rendu logiciel indisponible".to_string())?;
+
+    let frame_size = Size::new(size.width as f32, size.height as f32);
+    let mut chart_state = chart_state.clone();
+    chart_state.resize(frame_size.width, frame_size.height);
+
+    let program = ChartProgram::new(&chart_state, tools_state, settings_state, chart_style, false);
+    let mut frame = Frame::new(&renderer, frame_size);
+    draw_chart(&mut frame, &program, &WidgetState::default(), frame_size, None);
+    renderer.draw_geometry(frame.into_geometry());
+
+    let rgba = renderer.screenshot(size, 1.0, chart_style.background_color.to_iced());
+    encode_png(size.width, size.height, &rgba)
+}
+
+/// Encode des pixels RGBA 8 bits en PNG
+fn encode_png(width: u32, height: u32, rgba: &[u8]) -> Result<Vec<u8>, String> {
+    if rgba.len() != (width as usize) * (height as usize) * 4 {
+        return Err(format!(
+            "Taille de l'image incohérente: {} octets pour {}x{}",
+            rgba.len(), width, height
+        ));
+    }
+
+    let mut bytes = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut bytes, width, height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+        writer.write_image_data(rgba).map_err(|e| e.to_string())?;
+    }
+    Ok(bytes)
+}
+
+/// Nom du fichier d'export horodaté (ex: `chart_BTCUSDT_20240101_120000.png`)
+pub fn export_file_name(symbol: &str, timestamp: i64) -> String {
+    let date = chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|dt| dt.format("%Y%m%d_%H%M%S").to_string())
+        .unwrap_or_else(|| timestamp.to_string());
+    let symbol: String = symbol
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("chart_{}_{}.png", symbol, date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_png_signature() {
+        let rgba = vec![255u8; 2 * 3 * 4];
+        let bytes = encode_png(2, 3, &rgba).unwrap();
+        assert_eq!(&bytes[..8], &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]);
+    }
+
+    #[test]
+    fn test_encode_png_rejects_wrong_length() {
+        assert!(encode_png(2, 2, &[0u8; 4]).is_err());
+    }
+
+    #[test]
+    fn test_export_file_name() {
+        assert_eq!(export_file_name("BTCUSDT", 1_704_110_400), "chart_BTCUSDT_20240101_120000.png");
+        assert_eq!(export_file_name("BTC/USD", 0), "chart_BTC_USD_19700101_000000.png");
+    }
+}
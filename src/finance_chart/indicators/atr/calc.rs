@@ -0,0 +1,105 @@
+//! Calculs de l'ATR (Average True Range)
+//!
+//! L'ATR mesure la volatilité à partir du "true range" de chaque bougie :
+//! le plus grand écart entre le plus haut, le plus bas et la clôture précédente.
+
+use crate::finance_chart::core::Candle;
+
+/// Période par défaut pour l'ATR
+pub const ATR_PERIOD: usize = 14;
+
+/// Calcule le true range de chaque bougie
+///
+/// Pour la première bougie (pas de clôture précédente), le true range
+/// vaut simplement `high - low`.
+pub fn calculate_true_range(candles: &[Candle]) -> Vec<f64> {
+    candles
+        .iter()
+        .enumerate()
+        .map(|(i, candle)| {
+            let range = candle.high - candle.low;
+            if i == 0 {
+                return range;
+            }
+            let prev_close = candles[i - 1].close;
+            range
+                .max((candle.high - prev_close).abs())
+                .max((candle.low - prev_close).abs())
+        })
+        .collect()
+}
+
+/// Calcule l'ATR (Average True Range) pour une série de bougies
+///
+/// Utilise le lissage de Wilder : la première valeur est la moyenne simple
+/// des `period` premiers true ranges, puis `ATR = (ATR_prev * (period - 1) + TR) / period`.
+///
+/// # Arguments
+/// * `candles` - Slice de bougies triées par timestamp croissant
+/// * `period` - Période pour le calcul (défaut: 14)
+///
+/// # Retourne
+/// Un vecteur de valeurs ATR correspondant à chaque bougie.
+/// Les `period - 1` premières valeurs sont `None` car il n'y a pas assez de données.
+pub fn calculate_atr(candles: &[Candle], period: usize) -> Vec<Option<f64>> {
+    if period == 0 || candles.len() < period {
+        return vec![None; candles.len()];
+    }
+
+    let true_ranges = calculate_true_range(candles);
+    let mut atr_values = vec![None; period - 1];
+
+    // Première valeur : moyenne simple des true ranges
+    let mut atr = true_ranges[..period].iter().sum::<f64>() / period as f64;
+    atr_values.push(Some(atr));
+
+    // Valeurs suivantes : lissage de Wilder
+    for tr in &true_ranges[period..] {
+        atr = (atr * (period - 1) as f64 + tr) / period as f64;
+        atr_values.push(Some(atr));
+    }
+
+    atr_values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_true_range_uses_previous_close() {
+        let candles = vec![
+            Candle::new(1000, 100.0, 102.0, 98.0, 100.0, 1000.0),
+            // Gap haussier : high - prev_close (110 - 100) > high - low (110 - 107)
+            Candle::new(2000, 108.0, 110.0, 107.0, 109.0, 1000.0),
+            // Gap baissier : |low - prev_close| (|95 - 109|) domine
+            Candle::new(3000, 96.0, 97.0, 95.0, 96.0, 1000.0),
+        ];
+
+        let tr = calculate_true_range(&candles);
+        assert_eq!(tr, vec![4.0, 10.0, 14.0]);
+    }
+
+    #[test]
+    fn test_atr_wilder_smoothing() {
+        let candles = vec![
+            Candle::new(1000, 100.0, 102.0, 98.0, 100.0, 1000.0),
+            Candle::new(2000, 100.0, 102.0, 98.0, 100.0, 1000.0),
+            Candle::new(3000, 100.0, 108.0, 100.0, 100.0, 1000.0),
+        ];
+
+        let atr = calculate_atr(&candles, 2);
+        assert_eq!(atr.len(), candles.len());
+        assert!(atr[0].is_none());
+        assert_eq!(atr[1], Some(4.0));
+        // (4 * 1 + 8) / 2
+        assert_eq!(atr[2], Some(6.0));
+    }
+
+    #[test]
+    fn test_atr_not_enough_data() {
+        let candles = vec![Candle::new(1000, 100.0, 102.0, 98.0, 100.0, 1000.0)];
+        let atr = calculate_atr(&candles, ATR_PERIOD);
+        assert_eq!(atr, vec![None]);
+    }
+}
@@ -0,0 +1,200 @@
+//! Widget Canvas pour afficher l'ATR (Average True Range)
+//!
+//! Affiche l'ATR dans un graphique séparé sous le graphique principal,
+//! avec une échelle ajustée à la plage visible.
+
+use iced::widget::canvas::{Canvas, Frame, Geometry, Program, Path, Stroke, Text};
+use iced::{Color, Element, Length, Point, Rectangle};
+use iced::mouse::Cursor;
+
+use crate::finance_chart::state::ChartState;
+use crate::finance_chart::render::render_atr_crosshair;
+use crate::finance_chart::render::crosshair::CrosshairStyle;
+use super::data::{calculate_all_atr_values, calculate_atr_data, calculate_atr_range, atr_to_y, y_to_atr, format_atr_value};
+
+/// Couleur de la ligne ATR
+const ATR_LINE_COLOR: Color = Color::from_rgb(1.0, 0.6, 0.2);
+
+/// Program Iced pour le rendu de l'ATR
+pub struct ATRProgram<'a> {
+    chart_state: &'a ChartState,
+    period: usize,
+}
+
+impl<'a> ATRProgram<'a> {
+    pub fn new(chart_state: &'a ChartState, period: usize) -> Self {
+        Self { chart_state, period }
+    }
+}
+
+impl<'a> Program<crate::app::messages::Message> for ATRProgram<'a> {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        // Fond sombre
+        let background = Path::rectangle(Point::ORIGIN, bounds.size());
+        frame.fill(&background, Color::from_rgb(0.10, 0.09, 0.07));
+
+        // Calculer toutes les valeurs ATR (sur toutes les bougies, pas seulement visibles)
+        let all_atr_values = match calculate_all_atr_values(self.chart_state, Some(self.period)) {
+            Some(values) => values,
+            None => return vec![frame.into_geometry()],
+        };
+
+        let (visible_atr_values, visible_candles_slice, _visible_start_idx) =
+            match calculate_atr_data(self.chart_state, &all_atr_values) {
+                Some(data) => data,
+                None => return vec![frame.into_geometry()],
+            };
+
+        // Échelle ajustée aux valeurs visibles
+        let range = match calculate_atr_range(visible_atr_values) {
+            Some(range) => range,
+            None => return vec![frame.into_geometry()],
+        };
+
+        let viewport = &self.chart_state.viewport;
+
+        // Créer un TimeScale temporaire pour l'ATR chart qui utilise bounds.width
+        let (min_time, max_time) = viewport.time_scale().time_range();
+        use crate::finance_chart::scale::TimeScale;
+        let atr_time_scale = TimeScale::new(min_time, max_time, bounds.width);
+
+        let height = bounds.height;
+
+        // Dessiner la ligne de l'ATR
+        let atr_path = Path::new(|builder| {
+            let mut first_point = true;
+
+            for (atr_opt, candle) in visible_atr_values.iter().zip(visible_candles_slice.iter()) {
+                if let Some(atr) = atr_opt {
+                    let x = atr_time_scale.time_to_x(candle.timestamp);
+                    let y = atr_to_y(*atr, range, height);
+
+                    if x >= -10.0 && x <= bounds.width + 10.0 {
+                        if first_point {
+                            builder.move_to(Point::new(x, y));
+                            first_point = false;
+                        } else {
+                            builder.line_to(Point::new(x, y));
+                        }
+                    }
+                }
+            }
+        });
+
+        let stroke = Stroke::default()
+            .with_color(ATR_LINE_COLOR)
+            .with_width(2.0);
+        frame.stroke(&atr_path, stroke);
+
+        // Rendre le crosshair synchronisé avec le graphique principal
+        let mouse_position_in_chart = cursor.position_in(bounds);
+        let crosshair_style = CrosshairStyle {
+            line_color: Color::from_rgba(0.6, 0.6, 0.6, 0.8),
+            ..Default::default()
+        };
+        render_atr_crosshair(
+            &mut frame,
+            bounds.width,
+            bounds.height,
+            mouse_position_in_chart.map(|p| p.y),
+            &|y| y_to_atr(y, range, height),
+            Some(crosshair_style),
+        );
+
+        // Dessiner le label ATR dans la zone du chart (à droite)
+        if let Some(current_atr) = all_atr_values.iter().rev().find_map(|opt| *opt) {
+            let label = format!("ATR({}): {}", self.period, format_atr_value(current_atr));
+            let label_width = 6.5 * label.len() as f32 + 8.0;
+            let text_bg = Path::rectangle(
+                Point::new(bounds.width - label_width, 0.0),
+                iced::Size::new(label_width, 18.0),
+            );
+            frame.fill(&text_bg, Color::from_rgba(0.0, 0.0, 0.0, 0.45));
+            frame.fill_text(Text {
+                content: label,
+                position: Point::new(bounds.width - label_width + 4.0, 6.0),
+                color: ATR_LINE_COLOR,
+                size: iced::Pixels(11.0),
+                ..Text::default()
+            });
+        }
+
+        vec![frame.into_geometry()]
+    }
+
+    fn update(
+        &self,
+        _state: &mut Self::State,
+        event: &iced::Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Option<iced::widget::canvas::Action<crate::app::messages::Message>> {
+        match event {
+            // Gestion du pan (drag) avec la position absolue, comme le graphique principal
+            iced::Event::Mouse(iced::mouse::Event::ButtonPressed(iced::mouse::Button::Left)) if cursor.position_in(bounds).is_some() => {
+                if let Some(absolute_position) = cursor.position() {
+                    return Some(iced::widget::canvas::Action::publish(
+                        crate::app::messages::Message::Chart(
+                            crate::finance_chart::messages::ChartMessage::StartPan {
+                                position: absolute_position,
+                                time: None,
+                            }
+                        )
+                    ));
+                }
+            }
+            iced::Event::Mouse(iced::mouse::Event::ButtonReleased(iced::mouse::Button::Left)) => {
+                return Some(iced::widget::canvas::Action::publish(
+                    crate::app::messages::Message::Chart(
+                        crate::finance_chart::messages::ChartMessage::EndPan
+                    )
+                ));
+            }
+            iced::Event::Mouse(iced::mouse::Event::CursorMoved { position: _ }) => {
+                if self.chart_state.interaction.is_panning {
+                    if let Some(absolute_position) = cursor.position() {
+                        return Some(iced::widget::canvas::Action::publish(
+                            crate::app::messages::Message::Chart(
+                                crate::finance_chart::messages::ChartMessage::UpdatePanHorizontal {
+                                    position: absolute_position
+                                }
+                            )
+                        ));
+                    }
+                }
+                // Sinon, demander un redraw pour mettre à jour le crosshair
+                return Some(iced::widget::canvas::Action::request_redraw());
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Self::State,
+        _bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> iced::mouse::Interaction {
+        iced::mouse::Interaction::Crosshair
+    }
+}
+
+/// Crée un widget canvas pour l'ATR
+pub fn atr_chart<'a>(chart_state: &'a ChartState, period: usize) -> Element<'a, crate::app::messages::Message> {
+    Canvas::new(ATRProgram::new(chart_state, period))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+}
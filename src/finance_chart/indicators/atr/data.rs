@@ -0,0 +1,159 @@
+//! Fonctions helper pour le calcul et l'extraction des données ATR
+//!
+//! Ce module contient la logique partagée pour calculer l'ATR avec toutes les bougies
+//! et extraire les valeurs correspondant aux bougies visibles.
+
+use crate::finance_chart::state::ChartState;
+use crate::finance_chart::indicators::VisibleSlice;
+use super::calc::{calculate_atr, ATR_PERIOD};
+
+/// Marge appliquée autour de la plage visible pour que la courbe ne touche pas les bords
+const ATR_RANGE_PADDING: f64 = 0.1;
+
+/// Extrait les valeurs ATR correspondant aux bougies visibles
+///
+/// # Arguments
+/// * `chart_state` - L'état du graphique contenant les bougies
+/// * `all_atr_values` - Toutes les valeurs ATR pré-calculées
+///
+/// # Retourne
+/// Un tuple contenant :
+/// - Les valeurs ATR correspondant aux bougies visibles
+/// - Les bougies visibles
+/// - L'index de début des bougies visibles
+pub fn calculate_atr_data<'a>(
+    chart_state: &'a ChartState,
+    all_atr_values: &'a [Option<f64>],
+) -> Option<VisibleSlice<'a, Option<f64>>> {
+    if all_atr_values.is_empty() {
+        return None;
+    }
+
+    // Récupérer les bougies visibles pour déterminer quelle partie de l'ATR afficher
+    let visible_candles = chart_state.visible_candles();
+    if visible_candles.is_empty() {
+        return None;
+    }
+    let (_, visible_candles_slice) = &visible_candles[0];
+
+    // Récupérer toutes les bougies pour trouver l'index de début
+    let all_candles = chart_state.all_candles()?;
+
+    // Trouver l'index de début des bougies visibles dans toutes les bougies
+    let visible_start_idx = if let Some(first_visible) = visible_candles_slice.first() {
+        all_candles
+            .iter()
+            .position(|c| c.timestamp == first_visible.timestamp)
+            .unwrap_or(0)
+    } else {
+        0
+    };
+
+    // Limiter la tranche pour éviter un out-of-bounds si les vecteurs diffèrent
+    let end = (visible_start_idx + visible_candles_slice.len()).min(all_atr_values.len());
+    let start = visible_start_idx.min(end);
+    let slice = &all_atr_values[start..end];
+
+    Some((slice, visible_candles_slice, visible_start_idx))
+}
+
+/// Calcule toutes les valeurs ATR pour toutes les bougies
+///
+/// # Arguments
+/// * `chart_state` - L'état du graphique contenant les bougies
+/// * `period` - Période de l'ATR (défaut: `ATR_PERIOD`)
+///
+/// # Retourne
+/// Toutes les valeurs ATR calculées, ou `None` si le calcul n'est pas possible
+pub fn calculate_all_atr_values(
+    chart_state: &ChartState,
+    period: Option<usize>,
+) -> Option<Vec<Option<f64>>> {
+    let all_candles = chart_state.all_candles()?;
+
+    if all_candles.is_empty() {
+        return None;
+    }
+
+    let period = period.unwrap_or(ATR_PERIOD);
+    let all_atr_values = calculate_atr(all_candles, period);
+
+    if all_atr_values.is_empty() {
+        None
+    } else {
+        Some(all_atr_values)
+    }
+}
+
+/// Calcule la plage (min, max) des valeurs ATR visibles, avec une marge
+///
+/// # Retourne
+/// `Some((min, max))` si des valeurs valides existent, `None` sinon
+pub fn calculate_atr_range(visible_atr_values: &[Option<f64>]) -> Option<(f64, f64)> {
+    let (min, max) = visible_atr_values
+        .iter()
+        .filter_map(|opt| *opt)
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), atr| {
+            (min.min(atr), max.max(atr))
+        });
+
+    if min.is_infinite() || max.is_infinite() {
+        return None;
+    }
+
+    // Éviter une plage nulle quand l'ATR est constant
+    let span = (max - min).max(max.abs() * 0.01).max(f64::EPSILON);
+    let padding = span * ATR_RANGE_PADDING;
+    Some(((min - padding).max(0.0), max + padding))
+}
+
+/// Convertit une valeur ATR en coordonnée Y pour une plage et une hauteur données
+pub fn atr_to_y(value: f64, range: (f64, f64), height: f32) -> f32 {
+    let (min, max) = range;
+    let normalized = (value - min) / (max - min);
+    height * (1.0 - normalized as f32)
+}
+
+/// Convertit une coordonnée Y en valeur ATR pour une plage et une hauteur données
+pub fn y_to_atr(y: f32, range: (f64, f64), height: f32) -> f64 {
+    let (min, max) = range;
+    let normalized = 1.0 - (y / height) as f64;
+    min + normalized * (max - min)
+}
+
+/// Formate une valeur ATR avec une précision adaptée à son ordre de grandeur
+pub fn format_atr_value(value: f64) -> String {
+    if value.abs() >= 100.0 {
+        format!("{:.1}", value)
+    } else if value.abs() >= 1.0 {
+        format!("{:.2}", value)
+    } else {
+        format!("{:.4}", value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atr_range_adds_padding() {
+        let values = vec![None, Some(2.0), Some(4.0)];
+        let (min, max) = calculate_atr_range(&values).unwrap();
+        assert!((min - 1.8).abs() < 1e-9);
+        assert!((max - 4.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_atr_range_empty() {
+        assert!(calculate_atr_range(&[None, None]).is_none());
+    }
+
+    #[test]
+    fn test_atr_y_roundtrip() {
+        let range = (1.0, 5.0);
+        let y = atr_to_y(3.0, range, 100.0);
+        assert!((y - 50.0).abs() < 1e-4);
+        assert!((y_to_atr(y, range, 100.0) - 3.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,12 @@
+//! Module ATR (Average True Range)
+//!
+//! Contient les calculs, le rendu graphique et l'axe Y de l'ATR.
+
+pub mod calc;
+pub mod chart;
+pub mod axis;
+pub mod data;
+
+// Ré-exports pour faciliter l'accès
+pub use chart::atr_chart;
+pub use axis::atr_y_axis;
@@ -0,0 +1,89 @@
+//! Canvas pour l'axe Y du CCI
+//!
+//! Affiche les valeurs du CCI sur l'axe vertical à droite du graphique CCI.
+//! L'échelle suit la plage des valeurs visibles.
+
+use iced::widget::canvas::{Canvas, Frame, Geometry, Program, Text, Path};
+use iced::{Element, Length, Point, Rectangle};
+use iced::mouse::Cursor;
+use iced::Pixels;
+
+use crate::finance_chart::axis::{Y_AXIS_WIDTH, AxisStyle};
+use crate::finance_chart::render::calculate_nice_step;
+use crate::finance_chart::state::ChartState;
+use super::data::{calculate_all_cci_values, calculate_cci_data, calculate_cci_range, cci_to_y};
+
+/// Program pour l'axe Y du CCI
+pub struct CCIAxisProgram {
+    /// Plage (min, max) des valeurs visibles, `None` si aucune donnée
+    range: Option<(f64, f64)>,
+}
+
+impl CCIAxisProgram {
+    pub fn new(range: Option<(f64, f64)>) -> Self {
+        Self { range }
+    }
+}
+
+impl<Message> Program<Message> for CCIAxisProgram {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let style = AxisStyle::default();
+
+        // Fond
+        let background = Path::rectangle(Point::ORIGIN, bounds.size());
+        frame.fill(&background, style.background_color);
+
+        let Some(range) = self.range else {
+            return vec![frame.into_geometry()];
+        };
+
+        let step = calculate_nice_step(range.1 - range.0);
+        if step <= 0.0 || !step.is_finite() {
+            return vec![frame.into_geometry()];
+        }
+
+        // Dessiner les labels
+        let mut value = (range.0 / step).ceil() * step;
+        while value <= range.1 {
+            let y = cci_to_y(value, range, bounds.height);
+
+            if y >= 0.0 && y <= bounds.height {
+                let text = Text {
+                    content: format!("{:.0}", value),
+                    position: Point::new(5.0, y - 6.0),
+                    color: style.text_color,
+                    size: Pixels(style.text_size),
+                    ..Text::default()
+                };
+                frame.fill_text(text);
+            }
+
+            value += step;
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+/// Crée un widget canvas pour l'axe Y du CCI
+pub fn cci_y_axis<'a>(chart_state: &'a ChartState, period: usize, height: f32) -> Element<'a, crate::app::messages::Message> {
+    let range = calculate_all_cci_values(chart_state, Some(period)).and_then(|all_cci_values| {
+        calculate_cci_data(chart_state, &all_cci_values)
+            .and_then(|(visible, _, _)| calculate_cci_range(visible))
+    });
+
+    Canvas::new(CCIAxisProgram::new(range))
+        .width(Length::Fixed(Y_AXIS_WIDTH))
+        .height(Length::Fixed(height))
+        .into()
+}
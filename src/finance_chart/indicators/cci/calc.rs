@@ -0,0 +1,89 @@
+//! Calculs du CCI (Commodity Channel Index)
+//!
+//! CCI = (TP - SMA(TP)) / (0.015 × déviation moyenne), où TP est le prix typique
+//! `(high + low + close) / 3` et la déviation moyenne est la moyenne des écarts
+//! absolus entre le prix typique et sa SMA sur la période.
+
+use crate::finance_chart::core::Candle;
+
+/// Période par défaut pour le CCI
+pub const CCI_PERIOD: usize = 20;
+
+/// Constante de Lambert : environ 70 à 80 % des valeurs tombent entre -100 et +100
+const CCI_CONSTANT: f64 = 0.015;
+
+/// Niveaux de référence du CCI
+pub const CCI_OVERBOUGHT: f64 = 100.0;
+pub const CCI_OVERSOLD: f64 = -100.0;
+
+/// Calcule le prix typique de chaque bougie
+pub fn calculate_typical_price(candles: &[Candle]) -> Vec<f64> {
+    candles
+        .iter()
+        .map(|c| (c.high + c.low + c.close) / 3.0)
+        .collect()
+}
+
+/// Calcule le CCI pour une série de bougies
+///
+/// # Arguments
+/// * `candles` - Slice de bougies triées par timestamp croissant
+/// * `period` - Période pour le calcul (défaut: 20)
+///
+/// # Retourne
+/// Un vecteur de valeurs CCI correspondant à chaque bougie.
+/// Les `period - 1` premières valeurs sont `None` car il n'y a pas assez de données.
+/// Si la déviation moyenne est nulle (prix typique constant), le CCI vaut 0.
+pub fn calculate_cci(candles: &[Candle], period: usize) -> Vec<Option<f64>> {
+    if period == 0 || candles.len() < period {
+        return vec![None; candles.len()];
+    }
+
+    let typical_prices = calculate_typical_price(candles);
+    let mut cci_values = vec![None; period - 1];
+
+    for window in typical_prices.windows(period) {
+        let sma = window.iter().sum::<f64>() / period as f64;
+        let mean_deviation = window.iter().map(|tp| (tp - sma).abs()).sum::<f64>() / period as f64;
+        let current = window[period - 1];
+
+        let cci = if mean_deviation > f64::EPSILON {
+            (current - sma) / (CCI_CONSTANT * mean_deviation)
+        } else {
+            0.0
+        };
+        cci_values.push(Some(cci));
+    }
+
+    cci_values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cci_known_values() {
+        // Prix typiques : 1, 2, 3 (high = low = close)
+        let candles: Vec<Candle> = [1.0, 2.0, 3.0]
+            .iter()
+            .enumerate()
+            .map(|(i, &p)| Candle::new(i as i64 * 60, p, p, p, p, 1.0))
+            .collect();
+
+        let cci = calculate_cci(&candles, 3);
+        assert_eq!(cci[0], None);
+        assert_eq!(cci[1], None);
+        // SMA = 2, déviation moyenne = 2/3, CCI = 1 / (0.015 × 2/3) = 100
+        assert!((cci[2].unwrap() - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cci_flat_prices_is_zero() {
+        let candles: Vec<Candle> = (0..5)
+            .map(|i| Candle::new(i * 60, 10.0, 11.0, 9.0, 10.0, 1.0))
+            .collect();
+        let cci = calculate_cci(&candles, 3);
+        assert!(cci[2..].iter().all(|v| *v == Some(0.0)));
+    }
+}
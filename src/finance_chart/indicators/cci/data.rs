@@ -0,0 +1,145 @@
+//! Fonctions helper pour le calcul et l'extraction des données CCI
+//!
+//! Ce module contient la logique partagée pour calculer le CCI avec toutes les bougies
+//! et extraire les valeurs correspondant aux bougies visibles.
+
+use crate::finance_chart::state::ChartState;
+use crate::finance_chart::indicators::VisibleSlice;
+use super::calc::{calculate_cci, CCI_PERIOD, CCI_OVERBOUGHT, CCI_OVERSOLD};
+
+/// Marge appliquée autour de la plage visible pour que la courbe ne touche pas les bords
+const CCI_RANGE_PADDING: f64 = 0.1;
+
+/// Extrait les valeurs CCI correspondant aux bougies visibles
+///
+/// # Arguments
+/// * `chart_state` - L'état du graphique contenant les bougies
+/// * `all_cci_values` - Toutes les valeurs CCI pré-calculées
+///
+/// # Retourne
+/// Un tuple contenant :
+/// - Les valeurs CCI correspondant aux bougies visibles
+/// - Les bougies visibles
+/// - L'index de début des bougies visibles
+pub fn calculate_cci_data<'a>(
+    chart_state: &'a ChartState,
+    all_cci_values: &'a [Option<f64>],
+) -> Option<VisibleSlice<'a, Option<f64>>> {
+    if all_cci_values.is_empty() {
+        return None;
+    }
+
+    // Récupérer les bougies visibles pour déterminer quelle partie du CCI afficher
+    let visible_candles = chart_state.visible_candles();
+    if visible_candles.is_empty() {
+        return None;
+    }
+    let (_, visible_candles_slice) = &visible_candles[0];
+
+    // Récupérer toutes les bougies pour trouver l'index de début
+    let all_candles = chart_state.all_candles()?;
+
+    // Trouver l'index de début des bougies visibles dans toutes les bougies
+    let visible_start_idx = if let Some(first_visible) = visible_candles_slice.first() {
+        all_candles
+            .iter()
+            .position(|c| c.timestamp == first_visible.timestamp)
+            .unwrap_or(0)
+    } else {
+        0
+    };
+
+    // Limiter la tranche pour éviter un out-of-bounds si les vecteurs diffèrent
+    let end = (visible_start_idx + visible_candles_slice.len()).min(all_cci_values.len());
+    let start = visible_start_idx.min(end);
+    let slice = &all_cci_values[start..end];
+
+    Some((slice, visible_candles_slice, visible_start_idx))
+}
+
+/// Calcule toutes les valeurs CCI pour toutes les bougies
+///
+/// # Arguments
+/// * `chart_state` - L'état du graphique contenant les bougies
+/// * `period` - Période du CCI (défaut: `CCI_PERIOD`)
+///
+/// # Retourne
+/// Toutes les valeurs CCI calculées, ou `None` si le calcul n'est pas possible
+pub fn calculate_all_cci_values(
+    chart_state: &ChartState,
+    period: Option<usize>,
+) -> Option<Vec<Option<f64>>> {
+    let all_candles = chart_state.all_candles()?;
+
+    if all_candles.is_empty() {
+        return None;
+    }
+
+    let period = period.unwrap_or(CCI_PERIOD);
+    let all_cci_values = calculate_cci(all_candles, period);
+
+    if all_cci_values.is_empty() {
+        None
+    } else {
+        Some(all_cci_values)
+    }
+}
+
+/// Calcule la plage (min, max) des valeurs CCI visibles, avec une marge
+///
+/// La plage inclut toujours les niveaux ±100 pour que les lignes de référence
+/// restent visibles même quand l'oscillateur est calme.
+///
+/// # Retourne
+/// `Some((min, max))` si des valeurs valides existent, `None` sinon
+pub fn calculate_cci_range(visible_cci_values: &[Option<f64>]) -> Option<(f64, f64)> {
+    let (min, max) = visible_cci_values
+        .iter()
+        .filter_map(|opt| *opt)
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), cci| {
+            (min.min(cci), max.max(cci))
+        });
+
+    if min.is_infinite() || max.is_infinite() {
+        return None;
+    }
+
+    let min = min.min(CCI_OVERSOLD);
+    let max = max.max(CCI_OVERBOUGHT);
+    let padding = (max - min) * CCI_RANGE_PADDING;
+    Some((min - padding, max + padding))
+}
+
+/// Convertit une valeur CCI en coordonnée Y pour une plage et une hauteur données
+pub fn cci_to_y(value: f64, range: (f64, f64), height: f32) -> f32 {
+    let (min, max) = range;
+    let normalized = (value - min) / (max - min);
+    height * (1.0 - normalized as f32)
+}
+
+/// Convertit une coordonnée Y en valeur CCI pour une plage et une hauteur données
+pub fn y_to_cci(y: f32, range: (f64, f64), height: f32) -> f64 {
+    let (min, max) = range;
+    let normalized = 1.0 - (y / height) as f64;
+    min + normalized * (max - min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cci_range_includes_reference_levels() {
+        let values = vec![None, Some(-20.0), Some(50.0)];
+        let (min, max) = calculate_cci_range(&values).unwrap();
+        assert!(min < CCI_OVERSOLD);
+        assert!(max > CCI_OVERBOUGHT);
+    }
+
+    #[test]
+    fn test_cci_range_follows_extremes() {
+        let values = vec![Some(-250.0), Some(300.0)];
+        let (min, max) = calculate_cci_range(&values).unwrap();
+        assert!(min < -250.0 && max > 300.0);
+    }
+}
@@ -0,0 +1,12 @@
+//! Module CCI (Commodity Channel Index)
+//!
+//! Contient les calculs, le rendu graphique et l'axe Y du CCI.
+
+pub mod calc;
+pub mod chart;
+pub mod axis;
+pub mod data;
+
+// Ré-exports pour faciliter l'accès
+pub use chart::cci_chart;
+pub use axis::cci_y_axis;
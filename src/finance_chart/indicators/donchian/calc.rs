@@ -0,0 +1,80 @@
+//! Calculs des canaux de Donchian
+//!
+//! Les canaux de Donchian sont composés de :
+//! - Une bande supérieure (plus haut des `period` dernières bougies)
+//! - Une bande inférieure (plus bas des `period` dernières bougies)
+//! - Une ligne médiane (moyenne des deux bandes)
+
+use crate::finance_chart::core::Candle;
+
+/// Période par défaut des canaux de Donchian
+pub const DONCHIAN_PERIOD: usize = 20;
+
+/// Structure pour stocker les valeurs des canaux de Donchian
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DonchianValue {
+    pub upper: f64,   // Plus haut de la période
+    pub lower: f64,   // Plus bas de la période
+    pub middle: f64,  // Ligne médiane
+}
+
+/// Calcule les canaux de Donchian pour une série de bougies
+///
+/// # Arguments
+/// * `candles` - Slice de bougies triées par timestamp croissant
+/// * `period` - Nombre de bougies de la fenêtre glissante (défaut: 20)
+///
+/// # Retourne
+/// Un vecteur de valeurs correspondant à chaque bougie.
+/// Les `period - 1` premières valeurs sont `None` car la fenêtre est incomplète.
+pub fn calculate_donchian_channels(candles: &[Candle], period: usize) -> Vec<Option<DonchianValue>> {
+    if period == 0 || candles.len() < period {
+        return vec![None; candles.len()];
+    }
+
+    let mut result = vec![None; period - 1];
+    result.extend(candles.windows(period).map(|window| {
+        let upper = window.iter().map(|c| c.high).fold(f64::NEG_INFINITY, f64::max);
+        let lower = window.iter().map(|c| c.low).fold(f64::INFINITY, f64::min);
+        Some(DonchianValue {
+            upper,
+            lower,
+            middle: (upper + lower) / 2.0,
+        })
+    }));
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candle(i: i64, high: f64, low: f64) -> Candle {
+        Candle::new(i * 60, low, high, low, high, 1.0)
+    }
+
+    #[test]
+    fn test_donchian_rolling_extremes() {
+        let candles = vec![
+            candle(0, 10.0, 5.0),
+            candle(1, 12.0, 8.0),
+            candle(2, 11.0, 4.0),
+            candle(3, 9.0, 7.0),
+        ];
+        let values = calculate_donchian_channels(&candles, 3);
+
+        assert_eq!(values.len(), 4);
+        assert!(values[0].is_none() && values[1].is_none());
+        assert_eq!(values[2], Some(DonchianValue { upper: 12.0, lower: 4.0, middle: 8.0 }));
+        // Le plus haut à 10 sort de la fenêtre, 12 y reste
+        assert_eq!(values[3], Some(DonchianValue { upper: 12.0, lower: 4.0, middle: 8.0 }));
+    }
+
+    #[test]
+    fn test_donchian_insufficient_data() {
+        let candles = vec![candle(0, 10.0, 5.0)];
+        assert_eq!(calculate_donchian_channels(&candles, 3), vec![None]);
+        assert_eq!(calculate_donchian_channels(&candles, 0), vec![None]);
+    }
+}
@@ -0,0 +1,83 @@
+//! Fonctions helper pour le calcul et l'extraction des données des canaux de Donchian
+//!
+//! Ce module contient la logique partagée pour calculer les canaux de Donchian avec toutes les bougies
+//! et extraire les valeurs correspondant aux bougies visibles.
+
+use crate::finance_chart::state::ChartState;
+use crate::finance_chart::indicators::VisibleSlice;
+use super::calc::{calculate_donchian_channels, DonchianValue, DONCHIAN_PERIOD};
+
+/// Extrait les valeurs des canaux de Donchian correspondant aux bougies visibles
+///
+/// # Arguments
+/// * `chart_state` - L'état du graphique contenant les bougies
+/// * `all_donchian_values` - Toutes les valeurs Donchian pré-calculées
+///
+/// # Retourne
+/// Un tuple contenant :
+/// - Les valeurs Donchian correspondant aux bougies visibles
+/// - Les bougies visibles
+/// - L'index de début des bougies visibles
+pub fn calculate_donchian_data<'a>(
+    chart_state: &'a ChartState,
+    all_donchian_values: &'a [Option<DonchianValue>],
+) -> Option<VisibleSlice<'a, Option<DonchianValue>>> {
+    if all_donchian_values.is_empty() {
+        return None;
+    }
+
+    // Récupérer les bougies visibles pour déterminer quelle partie du canal afficher
+    let visible_candles = chart_state.visible_candles();
+    if visible_candles.is_empty() {
+        return None;
+    }
+    let (_, visible_candles_slice) = &visible_candles[0];
+
+    // Récupérer toutes les bougies pour trouver l'index de début
+    let all_candles = chart_state.all_candles()?;
+
+    // Trouver l'index de début des bougies visibles dans toutes les bougies
+    let visible_start_idx = if let Some(first_visible) = visible_candles_slice.first() {
+        all_candles
+            .iter()
+            .position(|c| c.timestamp == first_visible.timestamp)
+            .unwrap_or(0)
+    } else {
+        0
+    };
+
+    // Limiter la tranche pour éviter un out-of-bounds si les vecteurs diffèrent
+    let end = (visible_start_idx + visible_candles_slice.len()).min(all_donchian_values.len());
+    let start = visible_start_idx.min(end);
+    let slice = &all_donchian_values[start..end];
+
+    Some((slice, visible_candles_slice, visible_start_idx))
+}
+
+/// Calcule toutes les valeurs des canaux de Donchian pour toutes les bougies
+///
+/// # Arguments
+/// * `chart_state` - L'état du graphique contenant les bougies
+/// * `period` - Période de la fenêtre glissante (optionnel, utilise DONCHIAN_PERIOD par défaut)
+///
+/// # Retourne
+/// Toutes les valeurs Donchian calculées, ou `None` si le calcul n'est pas possible
+pub fn calculate_all_donchian_values(
+    chart_state: &ChartState,
+    period: Option<usize>,
+) -> Option<Vec<Option<DonchianValue>>> {
+    let all_candles = chart_state.all_candles()?;
+
+    if all_candles.is_empty() {
+        return None;
+    }
+
+    let period = period.unwrap_or(DONCHIAN_PERIOD);
+    let all_donchian_values = calculate_donchian_channels(all_candles, period);
+
+    if all_donchian_values.is_empty() {
+        None
+    } else {
+        Some(all_donchian_values)
+    }
+}
@@ -0,0 +1,13 @@
+//! Module des canaux de Donchian
+//!
+//! Les canaux de Donchian encadrent le prix entre le plus haut et le plus
+//! bas des N dernières bougies, avec une ligne médiane entre les deux.
+
+pub mod calc;
+pub mod data;
+
+pub use calc::DonchianValue;
+pub use data::{
+    calculate_donchian_data,
+    calculate_all_donchian_values,
+};
@@ -6,7 +6,7 @@
 /// Calculateur EMA stateful
 /// 
 /// Maintient l'état interne pour des calculs incrémentaux efficaces.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct Ema {
     /// Période de l'EMA
     period: usize,
@@ -14,8 +14,10 @@ pub struct Ema {
     alpha: f64,
     /// Valeur EMA précédente
     prev: Option<f64>,
-    /// Historique des valeurs pour le seed SMA
-    values: Vec<f64>,
+    /// Nombre de valeurs reçues
+    count: usize,
+    /// Somme des `period` premières valeurs pour le seed SMA
+    seed_sum: f64,
 }
 
 impl Ema {
@@ -25,7 +27,8 @@ impl Ema {
             period,
             alpha: 2.0 / (period + 1) as f64,
             prev: None,
-            values: Vec::with_capacity(period),
+            count: 0,
+            seed_sum: 0.0,
         }
     }
 
@@ -37,14 +40,17 @@ impl Ema {
     /// # Returns
     /// La valeur EMA calculée (peut être moins précise avant `period` valeurs)
     pub fn feed(&mut self, value: f64) -> f64 {
-        self.values.push(value);
-        let i = self.values.len() - 1;
+        let i = self.count;
+        self.count += 1;
+        if i < self.period {
+            self.seed_sum += value;
+        }
 
         let result = if i == 0 {
             value
         } else if i == self.period - 1 {
             // Seed avec SMA
-            self.values[0..self.period].iter().sum::<f64>() / self.period as f64
+            self.seed_sum / self.period as f64
         } else if i < self.period - 1 {
             // Pas encore initialisé, retourner la valeur brute
             value
@@ -0,0 +1,100 @@
+//! Calculs des canaux de Keltner
+//!
+//! Les canaux de Keltner sont composés de :
+//! - Une ligne médiane (EMA des clôtures)
+//! - Une bande supérieure (EMA + multiplicateur × ATR)
+//! - Une bande inférieure (EMA - multiplicateur × ATR)
+
+use crate::finance_chart::core::Candle;
+use crate::finance_chart::indicators::atr::calc::calculate_atr;
+use crate::finance_chart::indicators::ema::Ema;
+
+/// Paramètres par défaut pour les canaux de Keltner
+pub const KELTNER_EMA_PERIOD: usize = 20;
+pub const KELTNER_ATR_PERIOD: usize = 10;
+pub const KELTNER_MULTIPLIER: f64 = 2.0;
+
+/// Structure pour stocker les valeurs des canaux de Keltner
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeltnerValue {
+    pub middle: f64,  // EMA des clôtures
+    pub upper: f64,   // Bande supérieure
+    pub lower: f64,   // Bande inférieure
+}
+
+/// Calcule les canaux de Keltner pour une série de bougies
+///
+/// # Arguments
+/// * `candles` - Slice de bougies triées par timestamp croissant
+/// * `ema_period` - Période de l'EMA médiane (défaut: 20)
+/// * `atr_period` - Période de l'ATR (défaut: 10)
+/// * `multiplier` - Multiple de l'ATR pour les bandes (défaut: 2.0)
+///
+/// # Retourne
+/// Un vecteur de valeurs correspondant à chaque bougie. Les valeurs sont `None`
+/// tant que l'EMA ou l'ATR n'ont pas assez de données (période de chauffe).
+pub fn calculate_keltner_channels(
+    candles: &[Candle],
+    ema_period: usize,
+    atr_period: usize,
+    multiplier: f64,
+) -> Vec<Option<KeltnerValue>> {
+    if ema_period == 0 || atr_period == 0 || !multiplier.is_finite() || multiplier < 0.0 {
+        return vec![None; candles.len()];
+    }
+
+    let atr_values = calculate_atr(candles, atr_period);
+    let mut ema = Ema::new(ema_period);
+
+    candles
+        .iter()
+        .zip(atr_values)
+        .enumerate()
+        .map(|(i, (candle, atr))| {
+            let middle = ema.feed(candle.close);
+            // L'EMA n'est valide qu'à partir de son seed SMA
+            if i + 1 < ema_period {
+                return None;
+            }
+            atr.map(|atr| KeltnerValue {
+                middle,
+                upper: middle + multiplier * atr,
+                lower: middle - multiplier * atr,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flat_candles(count: i64) -> Vec<Candle> {
+        (0..count)
+            .map(|i| Candle::new(i * 60, 100.0, 101.0, 99.0, 100.0, 1.0))
+            .collect()
+    }
+
+    #[test]
+    fn test_keltner_warm_up_is_none() {
+        let candles = flat_candles(10);
+        let values = calculate_keltner_channels(&candles, 3, 5, 2.0);
+
+        assert_eq!(values.len(), 10);
+        // L'ATR (période 5) est la dernière série à devenir valide
+        assert!(values[..4].iter().all(Option::is_none));
+        assert!(values[4..].iter().all(Option::is_some));
+    }
+
+    #[test]
+    fn test_keltner_bands_around_ema() {
+        let candles = flat_candles(6);
+        let values = calculate_keltner_channels(&candles, 3, 3, 1.5);
+        let last = values.last().unwrap().unwrap();
+
+        // Prix constant : EMA = 100, true range = 2
+        assert!((last.middle - 100.0).abs() < 1e-12);
+        assert!((last.upper - 103.0).abs() < 1e-12);
+        assert!((last.lower - 97.0).abs() < 1e-12);
+    }
+}
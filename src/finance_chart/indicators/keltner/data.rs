@@ -0,0 +1,91 @@
+//! Fonctions helper pour le calcul et l'extraction des données des canaux de Keltner
+//!
+//! Ce module contient la logique partagée pour calculer les canaux de Keltner avec toutes les bougies
+//! et extraire les valeurs correspondant aux bougies visibles.
+
+use crate::finance_chart::state::ChartState;
+use crate::finance_chart::indicators::VisibleSlice;
+use super::calc::{calculate_keltner_channels, KeltnerValue, KELTNER_EMA_PERIOD, KELTNER_ATR_PERIOD, KELTNER_MULTIPLIER};
+
+/// Extrait les valeurs des canaux de Keltner correspondant aux bougies visibles
+///
+/// # Arguments
+/// * `chart_state` - L'état du graphique contenant les bougies
+/// * `all_keltner_values` - Toutes les valeurs Keltner pré-calculées
+///
+/// # Retourne
+/// Un tuple contenant :
+/// - Les valeurs Keltner correspondant aux bougies visibles
+/// - Les bougies visibles
+/// - L'index de début des bougies visibles
+pub fn calculate_keltner_data<'a>(
+    chart_state: &'a ChartState,
+    all_keltner_values: &'a [Option<KeltnerValue>],
+) -> Option<VisibleSlice<'a, Option<KeltnerValue>>> {
+    if all_keltner_values.is_empty() {
+        return None;
+    }
+
+    // Récupérer les bougies visibles pour déterminer quelle partie des canaux afficher
+    let visible_candles = chart_state.visible_candles();
+    if visible_candles.is_empty() {
+        return None;
+    }
+    let (_, visible_candles_slice) = &visible_candles[0];
+
+    // Récupérer toutes les bougies pour trouver l'index de début
+    let all_candles = chart_state.all_candles()?;
+
+    // Trouver l'index de début des bougies visibles dans toutes les bougies
+    let visible_start_idx = if let Some(first_visible) = visible_candles_slice.first() {
+        all_candles
+            .iter()
+            .position(|c| c.timestamp == first_visible.timestamp)
+            .unwrap_or(0)
+    } else {
+        0
+    };
+
+    // Limiter la tranche pour éviter un out-of-bounds si les vecteurs diffèrent
+    let end = (visible_start_idx + visible_candles_slice.len()).min(all_keltner_values.len());
+    let start = visible_start_idx.min(end);
+    let slice = &all_keltner_values[start..end];
+
+    Some((slice, visible_candles_slice, visible_start_idx))
+}
+
+/// Calcule toutes les valeurs des canaux de Keltner pour toutes les bougies
+///
+/// # Arguments
+/// * `chart_state` - L'état du graphique contenant les bougies
+/// * `ema_period` - Période de l'EMA médiane (optionnel, utilise KELTNER_EMA_PERIOD par défaut)
+/// * `atr_period` - Période de l'ATR (optionnel, utilise KELTNER_ATR_PERIOD par défaut)
+/// * `multiplier` - Multiple de l'ATR (optionnel, utilise KELTNER_MULTIPLIER par défaut)
+///
+/// # Retourne
+/// Toutes les valeurs Keltner calculées, ou `None` si le calcul n'est pas possible
+pub fn calculate_all_keltner_values(
+    chart_state: &ChartState,
+    ema_period: Option<usize>,
+    atr_period: Option<usize>,
+    multiplier: Option<f64>,
+) -> Option<Vec<Option<KeltnerValue>>> {
+    let all_candles = chart_state.all_candles()?;
+
+    if all_candles.is_empty() {
+        return None;
+    }
+
+    let all_keltner_values = calculate_keltner_channels(
+        all_candles,
+        ema_period.unwrap_or(KELTNER_EMA_PERIOD),
+        atr_period.unwrap_or(KELTNER_ATR_PERIOD),
+        multiplier.unwrap_or(KELTNER_MULTIPLIER),
+    );
+
+    if all_keltner_values.is_empty() {
+        None
+    } else {
+        Some(all_keltner_values)
+    }
+}
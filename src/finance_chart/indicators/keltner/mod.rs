@@ -0,0 +1,13 @@
+//! Module des canaux de Keltner
+//!
+//! Les canaux de Keltner entourent une EMA des clôtures de bandes placées
+//! à un multiple de l'ATR, ce qui les rend sensibles à la volatilité.
+
+pub mod calc;
+pub mod data;
+
+pub use calc::KeltnerValue;
+pub use data::{
+    calculate_keltner_data,
+    calculate_all_keltner_values,
+};
@@ -12,7 +12,7 @@ use iced::Pixels;
 use crate::finance_chart::axis::{Y_AXIS_WIDTH, AxisStyle};
 use crate::finance_chart::state::ChartState;
 use super::calc::MacdValue;
-use super::data::{calculate_macd_data, calculate_macd_range, get_last_macd_value, calculate_all_macd_values};
+use super::data::{calculate_macd_data, calculate_macd_range, get_last_macd_value, macd_values};
 use super::snapshot::MacdAxisSnapshot;
 use super::scaling::MacdScaling;
 
@@ -147,8 +147,8 @@ impl<Message> Program<Message> for MACDLabelOverlayProgram {
 
 /// Crée un widget canvas pour l'axe Y du MACD avec overlay de la valeur MACD actuelle
 pub fn macd_y_axis<'a>(chart_state: &'a ChartState) -> Element<'a, crate::app::messages::Message> {
-    // Calculer toutes les valeurs MACD une seule fois
-    let all_macd_values = match calculate_all_macd_values(chart_state) {
+    // Récupérer toutes les valeurs MACD (cache du ChartState si disponible)
+    let all_macd_values = match macd_values(chart_state) {
         Some(v) => v,
         None => {
             let empty_snapshot = MacdAxisSnapshot::new(vec![], 0.0, 0.0);
@@ -184,7 +184,7 @@ pub fn macd_y_axis<'a>(chart_state: &'a ChartState) -> Element<'a, crate::app::m
     };
 
     // Récupérer la dernière valeur MACD pour l'overlay
-    let last_macd = get_last_macd_value(chart_state, Some(&all_macd_values));
+    let last_macd = get_last_macd_value(chart_state, Some(&*all_macd_values));
 
     // Créer le snapshot léger avec les données pré-calculées
     let snapshot = MacdAxisSnapshot::new(
@@ -111,6 +111,100 @@ pub fn calculate_macd(
     result
 }
 
+/// État incrémental du MACD
+///
+/// Conserve les EMA courantes pour qu'ajouter ou remplacer la dernière bougie
+/// coûte O(1) au lieu de recalculer toute la série avec `calculate_macd`.
+/// Produit les mêmes valeurs que le calcul complet, bougie par bougie.
+#[derive(Debug, Clone)]
+pub struct MacdState {
+    slow_period: usize,
+    signal_period: usize,
+    fast: Ema,
+    slow: Ema,
+    signal: Ema,
+    /// EMA avant la dernière bougie, pour pouvoir la remplacer
+    before_last: Option<(Ema, Ema, Ema)>,
+    len: usize,
+    last_timestamp: Option<i64>,
+}
+
+impl MacdState {
+    /// Crée un état vide
+    pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Self {
+        Self {
+            slow_period,
+            signal_period,
+            fast: Ema::new(fast_period),
+            slow: Ema::new(slow_period),
+            signal: Ema::new(signal_period),
+            before_last: None,
+            len: 0,
+            last_timestamp: None,
+        }
+    }
+
+    /// Construit l'état à partir d'une série complète
+    pub fn from_candles(
+        candles: &[Candle],
+        fast_period: usize,
+        slow_period: usize,
+        signal_period: usize,
+    ) -> Self {
+        let mut state = Self::new(fast_period, slow_period, signal_period);
+        for candle in candles {
+            state.push(candle);
+        }
+        state
+    }
+
+    /// Nombre de bougies intégrées
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Timestamp de la dernière bougie intégrée
+    pub fn last_timestamp(&self) -> Option<i64> {
+        self.last_timestamp
+    }
+
+    /// Intègre une nouvelle bougie et retourne la valeur MACD correspondante
+    pub fn push(&mut self, candle: &Candle) -> Option<MacdValue> {
+        self.before_last = Some((self.fast, self.slow, self.signal));
+        let i = self.len;
+        self.len += 1;
+        self.last_timestamp = Some(candle.timestamp);
+
+        let macd = self.fast.feed(candle.close) - self.slow.feed(candle.close);
+        if i < self.slow_period - 1 {
+            return None;
+        }
+        let signal = self.signal.feed(macd);
+        if i < (self.slow_period - 1) + (self.signal_period - 1) {
+            return None;
+        }
+        Some(MacdValue {
+            macd_line: macd,
+            signal_line: signal,
+            histogram: macd - signal,
+        })
+    }
+
+    /// Remplace la dernière bougie intégrée (bougie en cours mise à jour)
+    pub fn replace_last(&mut self, candle: &Candle) -> Option<MacdValue> {
+        match self.before_last.take() {
+            Some((fast, slow, signal)) => {
+                self.fast = fast;
+                self.slow = slow;
+                self.signal = signal;
+                self.len -= 1;
+                self.push(candle)
+            }
+            None => self.push(candle),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,5 +243,47 @@ mod tests {
             assert!(last.signal_line.is_finite());
         }
     }
-}
 
+    fn candle(i: i64, close: f64) -> Candle {
+        Candle::new(1000 + i * 1000, close, close + 1.0, close - 1.0, close, 1000.0)
+    }
+
+    #[test]
+    fn test_macd_state_matches_batch() {
+        let candles: Vec<Candle> = (0..80)
+            .map(|i| candle(i, 100.0 + (i as f64 * 0.7).sin() * 5.0))
+            .collect();
+        let batch = calculate_macd(&candles, MACD_FAST_PERIOD, MACD_SLOW_PERIOD, MACD_SIGNAL_PERIOD);
+
+        let mut state = MacdState::new(MACD_FAST_PERIOD, MACD_SLOW_PERIOD, MACD_SIGNAL_PERIOD);
+        for (c, expected) in candles.iter().zip(&batch) {
+            let value = state.push(c);
+            match (value, expected) {
+                (Some(v), Some(e)) => {
+                    assert!((v.macd_line - e.macd_line).abs() < 1e-9);
+                    assert!((v.signal_line - e.signal_line).abs() < 1e-9);
+                }
+                (None, None) => {}
+                (v, e) => panic!("divergence: {:?} vs {:?}", v, e),
+            }
+        }
+        assert_eq!(state.len(), candles.len());
+    }
+
+    #[test]
+    fn test_macd_state_replace_last() {
+        let mut candles: Vec<Candle> = (0..60).map(|i| candle(i, 100.0 + i as f64)).collect();
+        let mut state = MacdState::from_candles(&candles, MACD_FAST_PERIOD, MACD_SLOW_PERIOD, MACD_SIGNAL_PERIOD);
+
+        // La bougie en cours évolue plusieurs fois
+        for close in [150.0, 120.0, 170.0] {
+            candles[59] = candle(59, close);
+            let value = state.replace_last(&candles[59]).unwrap();
+            let batch = calculate_macd(&candles, MACD_FAST_PERIOD, MACD_SLOW_PERIOD, MACD_SIGNAL_PERIOD);
+            let expected = batch[59].as_ref().unwrap();
+            assert!((value.macd_line - expected.macd_line).abs() < 1e-9);
+            assert!((value.histogram - expected.histogram).abs() < 1e-9);
+        }
+        assert_eq!(state.len(), 60);
+    }
+}
@@ -2,91 +2,96 @@
 //!
 //! Affiche le MACD dans un graphique séparé sous le graphique principal.
 
-use iced::widget::canvas::{Canvas, Frame, Geometry, Program, Path, Stroke};
+use iced::widget::canvas::{Cache, Canvas, Frame, Geometry, Program, Path, Stroke};
 use iced::{Color, Element, Length, Point, Rectangle};
 use iced::mouse::Cursor;
-use std::sync::Arc;
+use std::cell::Cell;
+use std::ops::Range;
 
+use crate::finance_chart::core::Candle;
+use crate::finance_chart::scale::TimeScale;
 use crate::finance_chart::state::ChartState;
 use crate::finance_chart::render::{calculate_candle_period, calculate_bar_width};
-use crate::finance_chart::render::render_macd_crosshair;
+use crate::finance_chart::render::{render_macd_crosshair, render_hover_value};
 use crate::finance_chart::render::crosshair::CrosshairStyle;
 use super::calc::MacdValue;
-use super::data::{calculate_macd_data, calculate_macd_range, calculate_all_macd_values};
+use super::data::{calculate_macd_data, calculate_macd_range, macd_values};
+use super::snapshot::MacdSnapshot;
 use super::scaling::MacdScaling;
 
 /// Program Iced pour le rendu du MACD
 pub struct MACDProgram<'a> {
     chart_state: &'a ChartState,
-    /// Valeurs MACD pré-calculées (optionnel, pour éviter les recalculs)
-    precomputed_macd_values: Option<Arc<Vec<Option<MacdValue>>>>,
 }
 
 impl<'a> MACDProgram<'a> {
     pub fn new(chart_state: &'a ChartState) -> Self {
-        Self { 
-            chart_state,
-            precomputed_macd_values: None,
-        }
+        Self { chart_state }
     }
-    
-    /// Crée un nouveau MACDProgram avec des valeurs MACD pré-calculées
-    #[allow(dead_code)] // Utilisé pour optimisation future
-    pub fn with_precomputed_values(
-        chart_state: &'a ChartState, 
-        macd_values: Arc<Vec<Option<MacdValue>>>
-    ) -> Self {
-        Self {
-            chart_state,
-            precomputed_macd_values: Some(macd_values),
-        }
+}
+
+/// État local du canvas MACD : géométrie des courbes en cache
+#[derive(Default)]
+pub struct MacdChartState {
+    /// Géométrie des courbes (fond, niveaux, lignes, histogramme)
+    curves_cache: Cache,
+    /// Clé de la géométrie en cache (None = jamais dessinée ou valeurs non versionnées)
+    curves_key: Cell<Option<MacdLayerKey>>,
+}
+
+/// Ce dont dépendent les courbes : version des valeurs MACD et vue
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MacdLayerKey {
+    version: u64,
+    time_range: (i64, i64),
+    size: (f32, f32),
+}
+
+impl MacdLayerKey {
+    /// La géométrie dessinée pour `self` reste valable pour `next` si la vue est
+    /// identique et qu'aucune valeur visible n'a changé entre les deux versions
+    fn still_valid(&self, next: &MacdLayerKey, snapshot: &MacdSnapshot, visible: &Range<usize>) -> bool {
+        self.time_range == next.time_range
+            && self.size == next.size
+            && snapshot.unchanged_since(self.version, visible)
     }
 }
 
 impl<'a> Program<crate::app::messages::Message> for MACDProgram<'a> {
-    type State = ();
+    type State = MacdChartState;
 
     fn draw(
         &self,
-        _state: &Self::State,
+        state: &Self::State,
         renderer: &iced::Renderer,
         _theme: &iced::Theme,
         bounds: Rectangle,
         cursor: Cursor,
     ) -> Vec<Geometry> {
-        let mut frame = Frame::new(renderer, bounds.size());
+        let empty = || {
+            let mut frame = Frame::new(renderer, bounds.size());
+            frame.fill(&Path::rectangle(Point::ORIGIN, bounds.size()), MACD_BACKGROUND);
+            vec![frame.into_geometry()]
+        };
 
-        // Fond sombre
-        let background = Path::rectangle(Point::ORIGIN, bounds.size());
-        frame.fill(&background, Color::from_rgb(0.08, 0.10, 0.08));
-
-        // Utiliser les valeurs pré-calculées si disponibles, sinon les calculer
-        let mut _owned_macd: Option<Vec<Option<MacdValue>>> = None;
-        let all_macd_slice: &[Option<MacdValue>] = if let Some(ref precomputed) =
-            self.precomputed_macd_values
-        {
-            &precomputed[..]
-        } else {
-            let values = match calculate_all_macd_values(self.chart_state) {
-                Some(v) => v,
-                None => return vec![frame.into_geometry()],
-            };
-            _owned_macd = Some(values);
-            _owned_macd.as_ref().map(|v| &v[..]).unwrap()
+        // Valeurs du cache du ChartState si disponibles, sinon calculées
+        let all_macd_values = match macd_values(self.chart_state) {
+            Some(values) => values,
+            None => return empty(),
         };
+        let all_macd_slice: &[Option<MacdValue>] = &all_macd_values;
 
         // Extraire les valeurs visibles
-        let (visible_macd_values, visible_candles_slice, _visible_start_idx) =
+        let (visible_macd_values, visible_candles_slice, visible_start_idx) =
             match calculate_macd_data(self.chart_state, all_macd_slice) {
                 Some(data) => data,
-                None => return vec![frame.into_geometry()],
+                None => return empty(),
             };
 
         let viewport = &self.chart_state.viewport;
         
         // Créer un TimeScale temporaire pour le MACD chart
         let (min_time, max_time) = viewport.time_scale().time_range();
-        use crate::finance_chart::scale::TimeScale;
         let macd_time_scale = TimeScale::new(min_time, max_time, bounds.width);
 
         let height = bounds.height;
@@ -94,168 +99,40 @@ impl<'a> Program<crate::app::messages::Message> for MACDProgram<'a> {
         // Calculer la plage de valeurs MACD pour le scaling
         let (min_macd, max_macd) = match calculate_macd_range(visible_macd_values) {
             Some(range) => range,
-            None => return vec![frame.into_geometry()],
+            None => return empty(),
         };
 
         // Créer le scaling MACD
         let scaling = MacdScaling::new(min_macd, max_macd, height);
-        let zero_y = scaling.zero_y();
-        
-        // Calculer les niveaux MACD pour dessiner les lignes de référence
-        let macd_step = scaling.calculate_step();
-        let first_macd = scaling.first_level();
-        
-        // Dessiner les lignes de référence horizontales
-        let mut macd_value = first_macd;
-        while macd_value <= scaling.symmetric_max {
-            let y = scaling.macd_to_y(macd_value);
-            
-            if y >= 0.0 && y <= height {
-                let ref_line = Path::new(|builder| {
-                    builder.move_to(Point::new(0.0, y));
-                    builder.line_to(Point::new(bounds.width, y));
-                });
-                
-                let line_color = if macd_value == 0.0 {
-                    Color::from_rgba(0.5, 0.5, 0.5, 0.5)
-                } else {
-                    Color::from_rgba(0.3, 0.3, 0.3, 0.3)
-                };
-                
-                frame.stroke(
-                    &ref_line,
-                    Stroke::default()
-                        .with_color(line_color)
-                        .with_width(1.0),
-                );
-            }
-            
-            macd_value += macd_step;
-        }
 
-        // Dessiner la ligne MACD
-        let macd_path = Path::new(|builder| {
-            let mut first_point = true;
-            
-            for (i, macd_opt) in visible_macd_values.iter().enumerate() {
-                if i >= visible_candles_slice.len() {
-                    break;
-                }
-                
-                if let Some(macd) = macd_opt {
-                    let x = macd_time_scale.time_to_x(visible_candles_slice[i].timestamp);
-                    let y = scaling.macd_to_y(macd.macd_line);
-                    
-                    if x >= -10.0 && x <= bounds.width + 10.0 {
-                        if first_point {
-                            builder.move_to(Point::new(x, y));
-                            first_point = false;
-                        } else {
-                            builder.line_to(Point::new(x, y));
-                        }
-                    }
-                }
-            }
+        // Courbes : redessinées seulement si la vue ou des valeurs visibles ont changé
+        let snapshot = self.chart_state.macd_cache.as_ref();
+        let key = snapshot.map(|snapshot| MacdLayerKey {
+            version: snapshot.version,
+            time_range: (min_time, max_time),
+            size: (bounds.width, bounds.height),
         });
-
-        frame.stroke(
-            &macd_path,
-            Stroke::default()
-                .with_color(Color::from_rgb(0.0, 0.8, 1.0)) // Cyan
-                .with_width(1.5),
-        );
-
-        // Dessiner la ligne de signal
-        let signal_path = Path::new(|builder| {
-            let mut first_point = true;
-            
-            for (i, macd_opt) in visible_macd_values.iter().enumerate() {
-                if i >= visible_candles_slice.len() {
-                    break;
-                }
-                
-                if let Some(macd) = macd_opt {
-                    let x = macd_time_scale.time_to_x(visible_candles_slice[i].timestamp);
-                    let y = scaling.macd_to_y(macd.signal_line);
-                    
-                    if x >= -10.0 && x <= bounds.width + 10.0 {
-                        if first_point {
-                            builder.move_to(Point::new(x, y));
-                            first_point = false;
-                        } else {
-                            builder.line_to(Point::new(x, y));
-                        }
-                    }
-                }
-            }
+        let visible_range = visible_start_idx..visible_start_idx + visible_macd_values.len();
+        let reusable = match (state.curves_key.get(), key, snapshot) {
+            (Some(cached), Some(key), Some(snapshot)) => cached.still_valid(&key, snapshot, &visible_range),
+            _ => false,
+        };
+        if !reusable {
+            state.curves_cache.clear();
+        }
+        state.curves_key.set(key);
+        let curves = state.curves_cache.draw(renderer, bounds.size(), |frame| {
+            draw_macd_curves(
+                frame,
+                visible_macd_values,
+                visible_candles_slice,
+                &macd_time_scale,
+                &scaling,
+                max_time - min_time,
+            );
         });
 
-        frame.stroke(
-            &signal_path,
-            Stroke::default()
-                .with_color(Color::from_rgb(1.0, 0.5, 0.0)) // Orange
-                .with_width(1.5),
-        );
-
-        // Dessiner l'histogramme
-        for (i, macd_opt) in visible_macd_values.iter().enumerate() {
-            if i >= visible_candles_slice.len() {
-                break;
-            }
-            
-            if let Some(macd) = macd_opt {
-                let x = macd_time_scale.time_to_x(visible_candles_slice[i].timestamp);
-                
-                if x >= -10.0 && x <= bounds.width + 10.0 {
-                    let histogram_y = scaling.macd_to_y(macd.histogram);
-                    let bar_height = (zero_y - histogram_y).abs();
-                    let bar_y = if macd.histogram >= 0.0 {
-                        zero_y - bar_height
-                    } else {
-                        zero_y
-                    };
-                    
-                    // Comparer avec la valeur précédente de l'histogramme
-                    let is_histogram_decreasing = if i > 0 {
-                        if let Some(prev_macd) = visible_macd_values.get(i - 1).and_then(|opt| opt.as_ref()) {
-                            macd.histogram.abs() < prev_macd.histogram.abs()
-                        } else {
-                            false
-                        }
-                    } else {
-                        false
-                    };
-                    
-                    let bar_color = if macd.histogram >= 0.0 {
-                        if is_histogram_decreasing {
-                            Color::from_rgba(0.3, 1.0, 0.3, 0.4)
-                        } else {
-                            Color::from_rgba(0.0, 0.8, 0.0, 0.6)
-                        }
-                    } else {
-                        if is_histogram_decreasing {
-                            Color::from_rgba(1.0, 0.3, 0.3, 0.4)
-                        } else {
-                            Color::from_rgba(0.8, 0.0, 0.0, 0.6)
-                        }
-                    };
-                    
-                    // Calculer la largeur des barres
-                    let candle_period = calculate_candle_period(visible_candles_slice);
-                    let bar_width = calculate_bar_width(
-                        candle_period,
-                        max_time - min_time,
-                        bounds.width,
-                    );
-                    
-                    let bar = Path::rectangle(
-                        Point::new(x - bar_width / 2.0, bar_y),
-                        iced::Size::new(bar_width.max(1.0), bar_height.max(1.0)),
-                    );
-                    frame.fill(&bar, bar_color);
-                }
-            }
-        }
+        let mut frame = Frame::new(renderer, bounds.size());
 
         // Rendre le crosshair synchronisé avec le graphique principal
         let mouse_position_in_chart = cursor.position_in(bounds);
@@ -274,11 +151,40 @@ impl<'a> Program<crate::app::messages::Message> for MACDProgram<'a> {
             bounds.height,
             mouse_position_in_chart.map(|p| p.y),
             &|y| scaling.y_to_macd(y),
-            Some(crosshair_style),
+            Some(crosshair_style.clone()),
             mouse_position_in_chart.map(|p| p.x),
         );
 
-        vec![frame.into_geometry()]
+        // Valeurs MACD au timestamp survolé (crosshair synchronisé)
+        if let (Some(index), Some(all_candles)) = (self.chart_state.hover_candle_index(), self.chart_state.all_candles()) {
+            if let (Some(Some(macd)), Some(candle)) = (all_macd_slice.get(index), all_candles.get(index)) {
+                let x = macd_time_scale.time_to_x(candle.timestamp);
+                if x >= 0.0 && x <= bounds.width {
+                    let format_value = |value: f64| if value.abs() >= 1.0 {
+                        format!("{:.2}", value)
+                    } else {
+                        format!("{:.4}", value)
+                    };
+                    let label = format!(
+                        "MACD {}  Sig {}  Hist {}",
+                        format_value(macd.macd_line),
+                        format_value(macd.signal_line),
+                        format_value(macd.histogram),
+                    );
+                    render_hover_value(
+                        &mut frame,
+                        &crosshair_style,
+                        x,
+                        Some(scaling.macd_to_y(macd.macd_line)),
+                        &label,
+                        Color::from_rgb(0.0, 0.8, 1.0),
+                        bounds.width,
+                    );
+                }
+            }
+        }
+
+        vec![curves, frame.into_geometry()]
     }
 
     fn update(
@@ -328,6 +234,18 @@ impl<'a> Program<crate::app::messages::Message> for MACDProgram<'a> {
                         ));
                     }
                 }
+                // Partager le timestamp survolé avec le graphique principal et les autres panneaux
+                if let Some(position) = cursor.position_in(bounds) {
+                    let (min_time, max_time) = self.chart_state.viewport.time_scale().time_range();
+                    let time_scale = crate::finance_chart::scale::TimeScale::new(min_time, max_time, bounds.width);
+                    return Some(iced::widget::canvas::Action::publish(
+                        crate::app::messages::Message::Chart(
+                            crate::finance_chart::messages::ChartMessage::IndicatorHovered {
+                                timestamp: time_scale.x_to_time(position.x),
+                            }
+                        )
+                    ));
+                }
                 // Sinon, demander un redraw pour mettre à jour le crosshair
                 return Some(iced::widget::canvas::Action::request_redraw());
             }
@@ -347,6 +265,184 @@ impl<'a> Program<crate::app::messages::Message> for MACDProgram<'a> {
     }
 }
 
+/// Couleur de fond du graphique MACD
+const MACD_BACKGROUND: Color = Color::from_rgb(0.08, 0.10, 0.08);
+
+/// Dessine le fond, les niveaux de référence, les lignes MACD/signal et l'histogramme
+fn draw_macd_curves(
+    frame: &mut Frame,
+    visible_macd_values: &[Option<MacdValue>],
+    visible_candles_slice: &[Candle],
+    macd_time_scale: &TimeScale,
+    scaling: &MacdScaling,
+    time_span: i64,
+) {
+    let bounds = frame.size();
+    let (width, height) = (bounds.width, bounds.height);
+
+    // Fond sombre
+    let background = Path::rectangle(Point::ORIGIN, bounds);
+    frame.fill(&background, MACD_BACKGROUND);
+
+    let zero_y = scaling.zero_y();
+    
+    // Calculer les niveaux MACD pour dessiner les lignes de référence
+    let macd_step = scaling.calculate_step();
+    let first_macd = scaling.first_level();
+    
+    // Dessiner les lignes de référence horizontales
+    let mut macd_value = first_macd;
+    while macd_value <= scaling.symmetric_max {
+        let y = scaling.macd_to_y(macd_value);
+        
+        if y >= 0.0 && y <= height {
+            let ref_line = Path::new(|builder| {
+                builder.move_to(Point::new(0.0, y));
+                builder.line_to(Point::new(width, y));
+            });
+            
+            let line_color = if macd_value == 0.0 {
+                Color::from_rgba(0.5, 0.5, 0.5, 0.5)
+            } else {
+                Color::from_rgba(0.3, 0.3, 0.3, 0.3)
+            };
+            
+            frame.stroke(
+                &ref_line,
+                Stroke::default()
+                    .with_color(line_color)
+                    .with_width(1.0),
+            );
+        }
+        
+        macd_value += macd_step;
+    }
+
+    // Dessiner la ligne MACD
+    let macd_path = Path::new(|builder| {
+        let mut first_point = true;
+        
+        for (i, macd_opt) in visible_macd_values.iter().enumerate() {
+            if i >= visible_candles_slice.len() {
+                break;
+            }
+            
+            if let Some(macd) = macd_opt {
+                let x = macd_time_scale.time_to_x(visible_candles_slice[i].timestamp);
+                let y = scaling.macd_to_y(macd.macd_line);
+                
+                if x >= -10.0 && x <= width + 10.0 {
+                    if first_point {
+                        builder.move_to(Point::new(x, y));
+                        first_point = false;
+                    } else {
+                        builder.line_to(Point::new(x, y));
+                    }
+                }
+            }
+        }
+    });
+
+    frame.stroke(
+        &macd_path,
+        Stroke::default()
+            .with_color(Color::from_rgb(0.0, 0.8, 1.0)) // Cyan
+            .with_width(1.5),
+    );
+
+    // Dessiner la ligne de signal
+    let signal_path = Path::new(|builder| {
+        let mut first_point = true;
+        
+        for (i, macd_opt) in visible_macd_values.iter().enumerate() {
+            if i >= visible_candles_slice.len() {
+                break;
+            }
+            
+            if let Some(macd) = macd_opt {
+                let x = macd_time_scale.time_to_x(visible_candles_slice[i].timestamp);
+                let y = scaling.macd_to_y(macd.signal_line);
+                
+                if x >= -10.0 && x <= width + 10.0 {
+                    if first_point {
+                        builder.move_to(Point::new(x, y));
+                        first_point = false;
+                    } else {
+                        builder.line_to(Point::new(x, y));
+                    }
+                }
+            }
+        }
+    });
+
+    frame.stroke(
+        &signal_path,
+        Stroke::default()
+            .with_color(Color::from_rgb(1.0, 0.5, 0.0)) // Orange
+            .with_width(1.5),
+    );
+
+    // Dessiner l'histogramme
+    for (i, macd_opt) in visible_macd_values.iter().enumerate() {
+        if i >= visible_candles_slice.len() {
+            break;
+        }
+        
+        if let Some(macd) = macd_opt {
+            let x = macd_time_scale.time_to_x(visible_candles_slice[i].timestamp);
+            
+            if x >= -10.0 && x <= width + 10.0 {
+                let histogram_y = scaling.macd_to_y(macd.histogram);
+                let bar_height = (zero_y - histogram_y).abs();
+                let bar_y = if macd.histogram >= 0.0 {
+                    zero_y - bar_height
+                } else {
+                    zero_y
+                };
+                
+                // Comparer avec la valeur précédente de l'histogramme
+                let is_histogram_decreasing = if i > 0 {
+                    if let Some(prev_macd) = visible_macd_values.get(i - 1).and_then(|opt| opt.as_ref()) {
+                        macd.histogram.abs() < prev_macd.histogram.abs()
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                };
+                
+                let bar_color = if macd.histogram >= 0.0 {
+                    if is_histogram_decreasing {
+                        Color::from_rgba(0.3, 1.0, 0.3, 0.4)
+                    } else {
+                        Color::from_rgba(0.0, 0.8, 0.0, 0.6)
+                    }
+                } else {
+                    if is_histogram_decreasing {
+                        Color::from_rgba(1.0, 0.3, 0.3, 0.4)
+                    } else {
+                        Color::from_rgba(0.8, 0.0, 0.0, 0.6)
+                    }
+                };
+                
+                // Calculer la largeur des barres
+                let candle_period = calculate_candle_period(visible_candles_slice);
+                let bar_width = calculate_bar_width(
+                    candle_period,
+                    time_span,
+                    width,
+                );
+                
+                let bar = Path::rectangle(
+                    Point::new(x - bar_width / 2.0, bar_y),
+                    iced::Size::new(bar_width.max(1.0), bar_height.max(1.0)),
+                );
+                frame.fill(&bar, bar_color);
+            }
+        }
+    }
+}
+
 /// Crée un widget canvas pour le MACD
 pub fn macd_chart<'a>(chart_state: &'a ChartState) -> Element<'a, crate::app::messages::Message> {
     Canvas::new(MACDProgram::new(chart_state))
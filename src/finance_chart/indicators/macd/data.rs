@@ -3,6 +3,8 @@
 //! Ce module contient la logique partagée pour calculer le MACD avec toutes les bougies
 //! et extraire les valeurs correspondant aux bougies visibles.
 
+use std::sync::Arc;
+
 use crate::finance_chart::state::ChartState;
 use crate::finance_chart::core::Candle;
 use super::calc::{calculate_macd, MacdValue, MACD_FAST_PERIOD, MACD_SLOW_PERIOD, MACD_SIGNAL_PERIOD};
@@ -81,6 +83,17 @@ pub fn calculate_all_macd_values(chart_state: &ChartState) -> Option<Vec<Option<
     }
 }
 
+/// Valeurs MACD de toute la série affichée
+///
+/// Réutilise le cache du `ChartState` (maintenu par `compute_and_store_macd`) et ne
+/// recalcule tout qu'en son absence.
+pub fn macd_values(chart_state: &ChartState) -> Option<Arc<Vec<Option<MacdValue>>>> {
+    match &chart_state.macd_cache {
+        Some(snapshot) => Some(snapshot.values.clone()),
+        None => calculate_all_macd_values(chart_state).map(Arc::new),
+    }
+}
+
 /// Calcule la plage de valeurs MACD (min, max) pour les valeurs visibles
 ///
 /// # Arguments
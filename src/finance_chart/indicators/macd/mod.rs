@@ -10,8 +10,9 @@ pub mod scaling;
 pub mod snapshot;
 
 // Ré-exports pour faciliter l'accès
-pub use calc::MacdValue;
+pub use calc::{MacdValue, MacdState};
 pub use chart::macd_chart;
 pub use axis::macd_y_axis;
 pub use data::calculate_all_macd_values;
+pub use snapshot::MacdSnapshot;
 
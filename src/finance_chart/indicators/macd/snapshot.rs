@@ -1,10 +1,62 @@
-//! Snapshot léger pour le rendu de l'axe MACD
+//! Snapshots des valeurs MACD pour le rendu
 //!
-//! Ce module fournit une structure légère contenant uniquement les données
+//! Ce module fournit les valeurs MACD versionnées de la série affichée (stockées
+//! dans le ChartState) et une structure légère contenant uniquement les données
 //! nécessaires au rendu de l'axe MACD, évitant de cloner le ChartState complet.
 
+use std::ops::Range;
+use std::sync::Arc;
+
 use super::calc::MacdValue;
 
+/// Valeurs MACD de toute la série affichée, versionnées
+///
+/// La version change à chaque modification des valeurs. `dirty_range` indique quels
+/// index ont changé par rapport à la version précédente, ce qui permet au rendu de
+/// garder sa géométrie quand seules des bougies hors de la vue ont été recalculées.
+#[derive(Debug, Clone)]
+pub struct MacdSnapshot {
+    /// Valeurs MACD (une par bougie)
+    pub values: Arc<Vec<Option<MacdValue>>>,
+    /// Version des valeurs
+    pub version: u64,
+    /// Index modifiés depuis la version précédente (None = valeurs entièrement recalculées)
+    pub dirty_range: Option<Range<usize>>,
+}
+
+impl MacdSnapshot {
+    /// Crée un snapshot à partir de valeurs entièrement recalculées
+    pub fn new(values: Vec<Option<MacdValue>>, version: u64) -> Self {
+        Self {
+            values: Arc::new(values),
+            version,
+            dirty_range: None,
+        }
+    }
+
+    /// Passe à la version `version` après modification des valeurs à partir de l'index `start`
+    ///
+    /// Toute la fin de série (`start..len`) est considérée comme modifiée.
+    pub fn mark_dirty_from(&mut self, start: usize, version: u64) {
+        self.dirty_range = Some(start..self.values.len());
+        self.version = version;
+    }
+
+    /// Indique si les valeurs des index `range` sont identiques à celles de la version `previous`
+    ///
+    /// Seule la version immédiatement précédente peut être comparée : au-delà, les
+    /// modifications intermédiaires ne sont plus connues.
+    pub fn unchanged_since(&self, previous: u64, range: &Range<usize>) -> bool {
+        if self.version == previous {
+            return true;
+        }
+        self.version == previous.wrapping_add(1)
+            && self.dirty_range
+                .as_ref()
+                .is_some_and(|dirty| dirty.start >= range.end || dirty.end <= range.start)
+    }
+}
+
 /// Snapshot léger pour le rendu de l'axe MACD
 /// 
 /// Contient uniquement les données pré-calculées nécessaires au dessin,
@@ -41,3 +93,22 @@ impl MacdAxisSnapshot {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unchanged_since_previous_version() {
+        let mut snapshot = MacdSnapshot::new(vec![None; 100], 3);
+        assert!(snapshot.unchanged_since(3, &(0..100)));
+
+        // Dernière bougie recalculée : seule une vue qui l'inclut doit être redessinée
+        snapshot.mark_dirty_from(99, 4);
+        assert!(snapshot.unchanged_since(3, &(20..99)));
+        assert!(!snapshot.unchanged_since(3, &(50..100)));
+        // Version trop ancienne ou recalcul complet : modifications inconnues
+        assert!(!snapshot.unchanged_since(2, &(20..50)));
+        assert!(!MacdSnapshot::new(vec![None; 100], 4).unchanged_since(3, &(20..50)));
+    }
+}
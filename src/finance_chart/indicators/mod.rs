@@ -7,6 +7,16 @@
 //! - `volume` : Graphique de volume
 //! - `bollinger` : Bandes de Bollinger
 //! - `moving_average` : Simple Moving Average (SMA)
+//! - `atr` : Average True Range
+//! - `obv` : On-Balance Volume
+//! - `vwap` : Volume Weighted Average Price
+//! - `psar` : Parabolic SAR
+//! - `donchian` : Canaux de Donchian
+//! - `keltner` : Canaux de Keltner
+//! - `cci` : Commodity Channel Index
+//! - `williams_r` : Williams %R
+//! - `volume_profile` : Volume par niveau de prix
+//! - `pivots` : Points pivots (classiques et Fibonacci)
 
 pub mod ema;
 pub mod rsi;
@@ -14,4 +24,19 @@ pub mod macd;
 pub mod volume;
 pub mod bollinger;
 pub mod moving_average;
+pub mod atr;
+pub mod obv;
+pub mod vwap;
+pub mod psar;
+pub mod donchian;
+pub mod keltner;
+pub mod cci;
+pub mod williams_r;
+pub mod volume_profile;
+pub mod pivots;
+
+use crate::finance_chart::core::Candle;
+
+/// Valeurs d'un indicateur pour les bougies visibles, ces bougies et l'index de la première
+pub type VisibleSlice<'a, T> = (&'a [T], &'a [Candle], usize);
 
@@ -1,9 +1,12 @@
-//! Calculs de la moyenne mobile (Simple Moving Average - SMA)
+//! Calculs des moyennes mobiles (SMA, EMA, WMA)
 //!
-//! La moyenne mobile est calculée en faisant la moyenne arithmétique
-//! des prix de clôture sur une période donnée.
+//! La moyenne mobile simple est calculée en faisant la moyenne arithmétique
+//! des prix de clôture sur une période donnée. L'EMA lisse exponentiellement
+//! les clôtures et la WMA pondère linéairement la fenêtre (poids 1 à `period`).
 
 use crate::finance_chart::core::Candle;
+use crate::finance_chart::indicators::ema::Ema;
+use crate::app::state::MaType;
 
 /// Période par défaut pour la moyenne mobile
 pub const MA_PERIOD: usize = 20;
@@ -76,6 +79,63 @@ pub fn calculate_moving_average(
     result
 }
 
+/// Calcule la moyenne mobile exponentielle (EMA) des clôtures
+///
+/// Les `period - 1` premières valeurs sont `None` (période d'amorçage de l'EMA,
+/// initialisée avec une SMA).
+pub fn calculate_exponential_moving_average(
+    candles: &[Candle],
+    period: usize,
+) -> Vec<Option<f64>> {
+    if period == 0 || candles.len() < period {
+        return vec![None; candles.len()];
+    }
+
+    let mut ema = Ema::new(period);
+    candles
+        .iter()
+        .enumerate()
+        .map(|(i, candle)| {
+            let value = ema.feed(candle.close);
+            (i + 1 >= period).then_some(value)
+        })
+        .collect()
+}
+
+/// Calcule la moyenne mobile pondérée (WMA) des clôtures
+///
+/// La clôture la plus récente de la fenêtre a le poids `period`,
+/// la plus ancienne le poids 1.
+pub fn calculate_weighted_moving_average(
+    candles: &[Candle],
+    period: usize,
+) -> Vec<Option<f64>> {
+    if period == 0 || candles.len() < period {
+        return vec![None; candles.len()];
+    }
+
+    let weight_sum = (period * (period + 1)) as f64 / 2.0;
+    let mut result = vec![None; period - 1];
+    result.extend(candles.windows(period).map(|window| {
+        let weighted: f64 = window
+            .iter()
+            .enumerate()
+            .map(|(j, candle)| (j + 1) as f64 * candle.close)
+            .sum();
+        Some(weighted / weight_sum)
+    }));
+    result
+}
+
+/// Calcule une moyenne mobile du type demandé
+pub fn calculate_ma(candles: &[Candle], period: usize, ma_type: MaType) -> Vec<Option<f64>> {
+    match ma_type {
+        MaType::Sma => calculate_moving_average(candles, period),
+        MaType::Ema => calculate_exponential_moving_average(candles, period),
+        MaType::Wma => calculate_weighted_moving_average(candles, period),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,5 +226,33 @@ mod tests {
             assert!((ma - expected).abs() < 0.01);
         }
     }
-}
 
+    #[test]
+    fn test_weighted_moving_average() {
+        let candles: Vec<Candle> = [1.0, 2.0, 3.0, 4.0]
+            .iter()
+            .enumerate()
+            .map(|(i, &p)| Candle::new(i as i64 * 60, p, p, p, p, 1000.0))
+            .collect();
+
+        let result = calculate_weighted_moving_average(&candles, 3);
+        assert_eq!(result[1], None);
+        // (1×1 + 2×2 + 3×3) / 6
+        assert!((result[2].unwrap() - 14.0 / 6.0).abs() < 1e-9);
+        // (2×1 + 3×2 + 4×3) / 6
+        assert!((result[3].unwrap() - 20.0 / 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_calculate_ma_dispatch_constant_prices() {
+        let candles: Vec<Candle> = (0..30)
+            .map(|i| Candle::new(1000 + i * 60, 100.0, 100.0, 100.0, 100.0, 1000.0))
+            .collect();
+
+        for ma_type in [MaType::Sma, MaType::Ema, MaType::Wma] {
+            let result = calculate_ma(&candles, 10, ma_type);
+            assert_eq!(result[8], None);
+            assert!((result[29].unwrap() - 100.0).abs() < 1e-9);
+        }
+    }
+}
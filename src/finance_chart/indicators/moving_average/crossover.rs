@@ -0,0 +1,78 @@
+//! Croisements entre deux moyennes mobiles (golden cross / death cross)
+//!
+//! Un croisement est détecté sur la bougie où l'écart entre la moyenne rapide et la
+//! moyenne lente change de signe. Les bougies où les deux moyennes sont égales ne
+//! produisent pas de croisement : le signe précédent est conservé jusqu'à ce que
+//! l'écart redevienne non nul.
+
+use std::cmp::Ordering;
+
+/// Sens d'un croisement de moyennes mobiles
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossDirection {
+    /// La moyenne rapide passe au-dessus de la lente (haussier)
+    Golden,
+    /// La moyenne rapide passe en dessous de la lente (baissier)
+    Death,
+}
+
+/// Détecte les croisements entre une moyenne rapide et une moyenne lente
+///
+/// # Arguments
+/// * `fast` - Valeurs de la moyenne rapide (une par bougie)
+/// * `slow` - Valeurs de la moyenne lente (une par bougie, mêmes index)
+///
+/// # Retourne
+/// L'index de chaque bougie de croisement et son sens. Aucun croisement n'est
+/// signalé dans la zone de chauffe (valeurs `None`), ni à sa sortie.
+pub fn find_crossovers(fast: &[Option<f64>], slow: &[Option<f64>]) -> Vec<(usize, CrossDirection)> {
+    let mut crossings = Vec::new();
+    // Position de la moyenne rapide par rapport à la lente lors du dernier écart non nul
+    let mut last_side: Option<Ordering> = None;
+
+    for (index, (fast, slow)) in fast.iter().zip(slow).enumerate() {
+        let (Some(fast), Some(slow)) = (fast, slow) else {
+            last_side = None;
+            continue;
+        };
+        let side = match fast.partial_cmp(slow) {
+            Some(Ordering::Equal) | None => continue,
+            Some(side) => side,
+        };
+        if last_side.is_some_and(|last| last != side) {
+            let direction = if side == Ordering::Greater {
+                CrossDirection::Golden
+            } else {
+                CrossDirection::Death
+            };
+            crossings.push((index, direction));
+        }
+        last_side = Some(side);
+    }
+
+    crossings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crossovers_skip_warmup_and_equal_values() {
+        let fast = [None, Some(1.0), Some(3.0), Some(2.0), Some(2.0), Some(1.0), Some(3.0)];
+        let slow = [None, None, Some(2.0), Some(2.0), Some(2.0), Some(2.0), Some(2.0)];
+
+        // Pas de croisement en sortie de chauffe (index 2), ni sur les égalités (3, 4)
+        assert_eq!(
+            find_crossovers(&fast, &slow),
+            vec![(5, CrossDirection::Death), (6, CrossDirection::Golden)]
+        );
+    }
+
+    #[test]
+    fn test_touch_without_crossing_is_ignored() {
+        let fast = [Some(3.0), Some(2.0), Some(3.0)];
+        let slow = [Some(2.0), Some(2.0), Some(2.0)];
+        assert!(find_crossovers(&fast, &slow).is_empty());
+    }
+}
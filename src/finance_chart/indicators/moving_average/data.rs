@@ -5,7 +5,8 @@
 
 use crate::finance_chart::state::ChartState;
 use crate::finance_chart::core::Candle;
-use super::calc::{calculate_moving_average, MA_PERIOD};
+use crate::app::state::MaType;
+use std::sync::Arc;
 
 /// Calcule la moyenne mobile pour toutes les bougies et retourne les valeurs correspondant aux bougies visibles
 ///
@@ -53,35 +54,24 @@ pub fn calculate_ma_data<'a>(
     Some((slice, visible_candles_slice, visible_start_idx))
 }
 
-/// Calcule toutes les valeurs de la moyenne mobile pour toutes les bougies
+/// Calcule toutes les valeurs d'une moyenne mobile pour toutes les bougies
+///
+/// Le résultat est mis en cache dans le `ChartState` par (série, type, période) :
+/// plusieurs moyennes mobiles peuvent être affichées sans recalcul à chaque frame.
 ///
 /// # Arguments
 /// * `chart_state` - L'état du graphique contenant les bougies
-/// * `period` - Période pour le calcul (optionnel, utilise MA_PERIOD par défaut)
+/// * `period` - Période de la moyenne mobile
+/// * `ma_type` - Type de moyenne mobile (SMA, EMA, WMA)
 ///
 /// # Retourne
 /// Toutes les valeurs MA calculées, ou `None` si le calcul n'est pas possible
 pub fn calculate_all_ma_values(
     chart_state: &ChartState,
-    period: Option<usize>,
-) -> Option<Vec<Option<f64>>> {
-    let all_candles = chart_state.all_candles()?;
-    
-    if all_candles.is_empty() {
-        return None;
-    }
-
-    let period = period.unwrap_or(MA_PERIOD);
-
-    let all_ma_values = calculate_moving_average(
-        all_candles,
-        period,
-    );
-    
-    if all_ma_values.is_empty() {
-        None
-    } else {
-        Some(all_ma_values)
-    }
+    period: usize,
+    ma_type: MaType,
+) -> Option<Arc<Vec<Option<f64>>>> {
+    chart_state
+        .moving_average_values(ma_type, period)
+        .filter(|values| !values.is_empty())
 }
-
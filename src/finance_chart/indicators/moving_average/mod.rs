@@ -1,13 +1,16 @@
-//! Module de la moyenne mobile (Simple Moving Average - SMA)
+//! Module des moyennes mobiles (SMA, EMA, WMA)
 //!
-//! La moyenne mobile est un indicateur technique qui affiche
-//! la moyenne arithmétique des prix de clôture sur une période donnée.
+//! La moyenne mobile est un indicateur technique qui lisse les prix de clôture
+//! sur une période donnée. Plusieurs moyennes peuvent être affichées simultanément.
+//! Le sous-module `crossover` détecte les croisements entre deux moyennes.
 
 pub mod calc;
 pub mod data;
+pub mod crossover;
 
 pub use data::{
     calculate_ma_data,
     calculate_all_ma_values,
 };
+pub use crossover::{find_crossovers, CrossDirection};
 
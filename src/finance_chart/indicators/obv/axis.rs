@@ -0,0 +1,90 @@
+//! Canvas pour l'axe Y de l'OBV
+//!
+//! Affiche les valeurs de l'OBV sur l'axe vertical à droite du graphique OBV.
+//! Les totaux cumulés pouvant être très grands, les labels sont abrégés (k/M/B).
+
+use iced::widget::canvas::{Canvas, Frame, Geometry, Program, Text, Path};
+use iced::{Element, Length, Point, Rectangle};
+use iced::mouse::Cursor;
+use iced::Pixels;
+
+use crate::finance_chart::axis::{Y_AXIS_WIDTH, AxisStyle};
+use crate::finance_chart::render::calculate_nice_step;
+use crate::finance_chart::state::ChartState;
+use crate::finance_chart::settings::format_abbreviated;
+use super::data::{calculate_all_obv_values, calculate_obv_data, calculate_obv_range, obv_to_y};
+
+/// Program pour l'axe Y de l'OBV
+pub struct OBVAxisProgram {
+    /// Plage (min, max) des valeurs visibles, `None` si aucune donnée
+    range: Option<(f64, f64)>,
+}
+
+impl OBVAxisProgram {
+    pub fn new(range: Option<(f64, f64)>) -> Self {
+        Self { range }
+    }
+}
+
+impl<Message> Program<Message> for OBVAxisProgram {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let style = AxisStyle::default();
+
+        // Fond
+        let background = Path::rectangle(Point::ORIGIN, bounds.size());
+        frame.fill(&background, style.background_color);
+
+        let Some(range) = self.range else {
+            return vec![frame.into_geometry()];
+        };
+
+        let step = calculate_nice_step(range.1 - range.0);
+        if step <= 0.0 || !step.is_finite() {
+            return vec![frame.into_geometry()];
+        }
+
+        // Dessiner les labels
+        let mut value = (range.0 / step).ceil() * step;
+        while value <= range.1 {
+            let y = obv_to_y(value, range, bounds.height);
+
+            if y >= 0.0 && y <= bounds.height {
+                let text = Text {
+                    content: format_abbreviated(value),
+                    position: Point::new(5.0, y - 6.0),
+                    color: style.text_color,
+                    size: Pixels(style.text_size),
+                    ..Text::default()
+                };
+                frame.fill_text(text);
+            }
+
+            value += step;
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+/// Crée un widget canvas pour l'axe Y de l'OBV
+pub fn obv_y_axis<'a>(chart_state: &'a ChartState, height: f32) -> Element<'a, crate::app::messages::Message> {
+    let range = calculate_all_obv_values(chart_state).and_then(|all_obv_values| {
+        calculate_obv_data(chart_state, &all_obv_values)
+            .and_then(|(visible, _, _)| calculate_obv_range(visible))
+    });
+
+    Canvas::new(OBVAxisProgram::new(range))
+        .width(Length::Fixed(Y_AXIS_WIDTH))
+        .height(Length::Fixed(height))
+        .into()
+}
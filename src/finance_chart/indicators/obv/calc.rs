@@ -0,0 +1,57 @@
+//! Calculs de l'OBV (On-Balance Volume)
+//!
+//! L'OBV cumule le volume signé : le volume est ajouté quand la clôture monte,
+//! retranché quand elle baisse, et ignoré quand elle est inchangée.
+
+use crate::finance_chart::core::Candle;
+
+/// Calcule l'OBV (On-Balance Volume) pour une série de bougies
+///
+/// # Arguments
+/// * `candles` - Slice de bougies triées par timestamp croissant
+///
+/// # Retourne
+/// Un vecteur contenant le total cumulé pour chaque bougie.
+/// La première bougie sert de référence et vaut 0.
+pub fn calculate_obv(candles: &[Candle]) -> Vec<f64> {
+    let mut obv_values = Vec::with_capacity(candles.len());
+    let mut obv = 0.0;
+
+    for (i, candle) in candles.iter().enumerate() {
+        if i > 0 {
+            let prev_close = candles[i - 1].close;
+            if candle.close > prev_close {
+                obv += candle.volume;
+            } else if candle.close < prev_close {
+                obv -= candle.volume;
+            }
+        }
+        obv_values.push(obv);
+    }
+
+    obv_values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_obv_accumulates_signed_volume() {
+        let candles = vec![
+            Candle::new(1000, 100.0, 101.0, 99.0, 100.0, 500.0),
+            Candle::new(2000, 100.0, 102.0, 99.0, 101.0, 1000.0), // hausse : +1000
+            Candle::new(3000, 101.0, 102.0, 99.0, 100.0, 400.0),  // baisse : -400
+            Candle::new(4000, 100.0, 101.0, 99.0, 100.0, 900.0),  // inchangé
+            Candle::new(5000, 100.0, 103.0, 99.0, 102.0, 250.0),  // hausse : +250
+        ];
+
+        let obv = calculate_obv(&candles);
+        assert_eq!(obv, vec![0.0, 1000.0, 600.0, 600.0, 850.0]);
+    }
+
+    #[test]
+    fn test_obv_empty() {
+        assert!(calculate_obv(&[]).is_empty());
+    }
+}
@@ -0,0 +1,198 @@
+//! Widget Canvas pour afficher l'OBV (On-Balance Volume)
+//!
+//! Affiche l'OBV dans un graphique séparé sous le graphique principal,
+//! avec une échelle ajustée à la plage visible.
+
+use iced::widget::canvas::{Canvas, Frame, Geometry, Program, Path, Stroke, Text};
+use iced::{Color, Element, Length, Point, Rectangle};
+use iced::mouse::Cursor;
+
+use crate::finance_chart::state::ChartState;
+use crate::finance_chart::render::render_obv_crosshair;
+use crate::finance_chart::render::crosshair::CrosshairStyle;
+use crate::finance_chart::settings::format_abbreviated;
+use super::data::{calculate_all_obv_values, calculate_obv_data, calculate_obv_range, obv_to_y, y_to_obv};
+
+/// Couleur de la ligne OBV
+const OBV_LINE_COLOR: Color = Color::from_rgb(0.6, 0.5, 1.0);
+
+/// Program Iced pour le rendu de l'OBV
+pub struct OBVProgram<'a> {
+    chart_state: &'a ChartState,
+}
+
+impl<'a> OBVProgram<'a> {
+    pub fn new(chart_state: &'a ChartState) -> Self {
+        Self { chart_state }
+    }
+}
+
+impl<'a> Program<crate::app::messages::Message> for OBVProgram<'a> {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        // Fond sombre
+        let background = Path::rectangle(Point::ORIGIN, bounds.size());
+        frame.fill(&background, Color::from_rgb(0.08, 0.08, 0.11));
+
+        // Calculer toutes les valeurs OBV (sur toutes les bougies, le total est cumulé)
+        let all_obv_values = match calculate_all_obv_values(self.chart_state) {
+            Some(values) => values,
+            None => return vec![frame.into_geometry()],
+        };
+
+        let (visible_obv_values, visible_candles_slice, _visible_start_idx) =
+            match calculate_obv_data(self.chart_state, &all_obv_values) {
+                Some(data) => data,
+                None => return vec![frame.into_geometry()],
+            };
+
+        // Échelle ajustée aux valeurs visibles
+        let range = match calculate_obv_range(visible_obv_values) {
+            Some(range) => range,
+            None => return vec![frame.into_geometry()],
+        };
+
+        let viewport = &self.chart_state.viewport;
+
+        // Créer un TimeScale temporaire pour l'OBV chart qui utilise bounds.width
+        let (min_time, max_time) = viewport.time_scale().time_range();
+        use crate::finance_chart::scale::TimeScale;
+        let obv_time_scale = TimeScale::new(min_time, max_time, bounds.width);
+
+        let height = bounds.height;
+
+        // Dessiner la ligne de l'OBV
+        let obv_path = Path::new(|builder| {
+            let mut first_point = true;
+
+            for (obv, candle) in visible_obv_values.iter().zip(visible_candles_slice.iter()) {
+                let x = obv_time_scale.time_to_x(candle.timestamp);
+                let y = obv_to_y(*obv, range, height);
+
+                if x >= -10.0 && x <= bounds.width + 10.0 {
+                    if first_point {
+                        builder.move_to(Point::new(x, y));
+                        first_point = false;
+                    } else {
+                        builder.line_to(Point::new(x, y));
+                    }
+                }
+            }
+        });
+
+        let stroke = Stroke::default()
+            .with_color(OBV_LINE_COLOR)
+            .with_width(2.0);
+        frame.stroke(&obv_path, stroke);
+
+        // Rendre le crosshair synchronisé avec le graphique principal
+        let mouse_position_in_chart = cursor.position_in(bounds);
+        let crosshair_style = CrosshairStyle {
+            line_color: Color::from_rgba(0.6, 0.6, 0.6, 0.8),
+            ..Default::default()
+        };
+        render_obv_crosshair(
+            &mut frame,
+            bounds.width,
+            bounds.height,
+            mouse_position_in_chart.map(|p| p.y),
+            &|y| format_abbreviated(y_to_obv(y, range, height)),
+            Some(crosshair_style),
+        );
+
+        // Dessiner le label OBV dans la zone du chart (à droite)
+        if let Some(current_obv) = all_obv_values.last() {
+            let label = format!("OBV: {}", format_abbreviated(*current_obv));
+            let label_width = 6.5 * label.len() as f32 + 8.0;
+            let text_bg = Path::rectangle(
+                Point::new(bounds.width - label_width, 0.0),
+                iced::Size::new(label_width, 18.0),
+            );
+            frame.fill(&text_bg, Color::from_rgba(0.0, 0.0, 0.0, 0.45));
+            frame.fill_text(Text {
+                content: label,
+                position: Point::new(bounds.width - label_width + 4.0, 6.0),
+                color: OBV_LINE_COLOR,
+                size: iced::Pixels(11.0),
+                ..Text::default()
+            });
+        }
+
+        vec![frame.into_geometry()]
+    }
+
+    fn update(
+        &self,
+        _state: &mut Self::State,
+        event: &iced::Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Option<iced::widget::canvas::Action<crate::app::messages::Message>> {
+        match event {
+            // Gestion du pan (drag) avec la position absolue, comme le graphique principal
+            iced::Event::Mouse(iced::mouse::Event::ButtonPressed(iced::mouse::Button::Left)) if cursor.position_in(bounds).is_some() => {
+                if let Some(absolute_position) = cursor.position() {
+                    return Some(iced::widget::canvas::Action::publish(
+                        crate::app::messages::Message::Chart(
+                            crate::finance_chart::messages::ChartMessage::StartPan {
+                                position: absolute_position,
+                                time: None,
+                            }
+                        )
+                    ));
+                }
+            }
+            iced::Event::Mouse(iced::mouse::Event::ButtonReleased(iced::mouse::Button::Left)) => {
+                return Some(iced::widget::canvas::Action::publish(
+                    crate::app::messages::Message::Chart(
+                        crate::finance_chart::messages::ChartMessage::EndPan
+                    )
+                ));
+            }
+            iced::Event::Mouse(iced::mouse::Event::CursorMoved { position: _ }) => {
+                if self.chart_state.interaction.is_panning {
+                    if let Some(absolute_position) = cursor.position() {
+                        return Some(iced::widget::canvas::Action::publish(
+                            crate::app::messages::Message::Chart(
+                                crate::finance_chart::messages::ChartMessage::UpdatePanHorizontal {
+                                    position: absolute_position
+                                }
+                            )
+                        ));
+                    }
+                }
+                // Sinon, demander un redraw pour mettre à jour le crosshair
+                return Some(iced::widget::canvas::Action::request_redraw());
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Self::State,
+        _bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> iced::mouse::Interaction {
+        iced::mouse::Interaction::Crosshair
+    }
+}
+
+/// Crée un widget canvas pour l'OBV
+pub fn obv_chart<'a>(chart_state: &'a ChartState) -> Element<'a, crate::app::messages::Message> {
+    Canvas::new(OBVProgram::new(chart_state))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+}
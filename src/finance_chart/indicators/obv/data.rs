@@ -0,0 +1,136 @@
+//! Fonctions helper pour le calcul et l'extraction des données OBV
+//!
+//! Ce module contient la logique partagée pour calculer l'OBV avec toutes les bougies
+//! et extraire les valeurs correspondant aux bougies visibles.
+
+use crate::finance_chart::state::ChartState;
+use crate::finance_chart::core::Candle;
+use super::calc::calculate_obv;
+
+/// Marge appliquée autour de la plage visible pour que la courbe ne touche pas les bords
+const OBV_RANGE_PADDING: f64 = 0.1;
+
+/// Extrait les valeurs OBV correspondant aux bougies visibles
+///
+/// # Arguments
+/// * `chart_state` - L'état du graphique contenant les bougies
+/// * `all_obv_values` - Toutes les valeurs OBV pré-calculées
+///
+/// # Retourne
+/// Un tuple contenant :
+/// - Les valeurs OBV correspondant aux bougies visibles
+/// - Les bougies visibles
+/// - L'index de début des bougies visibles
+pub fn calculate_obv_data<'a>(
+    chart_state: &'a ChartState,
+    all_obv_values: &'a [f64],
+) -> Option<(&'a [f64], &'a [Candle], usize)> {
+    if all_obv_values.is_empty() {
+        return None;
+    }
+
+    // Récupérer les bougies visibles pour déterminer quelle partie de l'OBV afficher
+    let visible_candles = chart_state.visible_candles();
+    if visible_candles.is_empty() {
+        return None;
+    }
+    let (_, visible_candles_slice) = &visible_candles[0];
+
+    // Récupérer toutes les bougies pour trouver l'index de début
+    let all_candles = chart_state.all_candles()?;
+
+    // Trouver l'index de début des bougies visibles dans toutes les bougies
+    let visible_start_idx = if let Some(first_visible) = visible_candles_slice.first() {
+        all_candles
+            .iter()
+            .position(|c| c.timestamp == first_visible.timestamp)
+            .unwrap_or(0)
+    } else {
+        0
+    };
+
+    // Limiter la tranche pour éviter un out-of-bounds si les vecteurs diffèrent
+    let end = (visible_start_idx + visible_candles_slice.len()).min(all_obv_values.len());
+    let start = visible_start_idx.min(end);
+    let slice = &all_obv_values[start..end];
+
+    Some((slice, visible_candles_slice, visible_start_idx))
+}
+
+/// Calcule toutes les valeurs OBV pour toutes les bougies
+///
+/// # Arguments
+/// * `chart_state` - L'état du graphique contenant les bougies
+///
+/// # Retourne
+/// Toutes les valeurs OBV calculées, ou `None` si le calcul n'est pas possible
+pub fn calculate_all_obv_values(chart_state: &ChartState) -> Option<Vec<f64>> {
+    let all_candles = chart_state.all_candles()?;
+
+    if all_candles.is_empty() {
+        return None;
+    }
+
+    Some(calculate_obv(all_candles))
+}
+
+/// Calcule la plage (min, max) des valeurs OBV visibles, avec une marge
+///
+/// # Retourne
+/// `Some((min, max))` si des valeurs existent, `None` sinon
+pub fn calculate_obv_range(visible_obv_values: &[f64]) -> Option<(f64, f64)> {
+    if visible_obv_values.is_empty() {
+        return None;
+    }
+
+    let (min, max) = visible_obv_values
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &obv| {
+            (min.min(obv), max.max(obv))
+        });
+
+    // Éviter une plage nulle quand l'OBV est constant
+    let span = (max - min).max(max.abs().max(min.abs()) * 0.01).max(1.0);
+    let padding = span * OBV_RANGE_PADDING;
+    Some((min - padding, max + padding))
+}
+
+/// Convertit une valeur OBV en coordonnée Y pour une plage et une hauteur données
+pub fn obv_to_y(value: f64, range: (f64, f64), height: f32) -> f32 {
+    let (min, max) = range;
+    let normalized = (value - min) / (max - min);
+    height * (1.0 - normalized as f32)
+}
+
+/// Convertit une coordonnée Y en valeur OBV pour une plage et une hauteur données
+pub fn y_to_obv(y: f32, range: (f64, f64), height: f32) -> f64 {
+    let (min, max) = range;
+    let normalized = 1.0 - (y / height) as f64;
+    min + normalized * (max - min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_obv_range_handles_negative_values() {
+        let (min, max) = calculate_obv_range(&[-1000.0, 0.0, 1000.0]).unwrap();
+        assert!((min + 1200.0).abs() < 1e-9);
+        assert!((max - 1200.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_obv_range_constant() {
+        let (min, max) = calculate_obv_range(&[0.0, 0.0]).unwrap();
+        assert!(min < 0.0 && max > 0.0);
+    }
+
+    #[test]
+    fn test_obv_y_roundtrip() {
+        let range = (-500.0, 1500.0);
+        let y = obv_to_y(500.0, range, 200.0);
+        assert!((y - 100.0).abs() < 1e-4);
+        assert!((y_to_obv(y, range, 200.0) - 500.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,12 @@
+//! Module OBV (On-Balance Volume)
+//!
+//! Contient les calculs, le rendu graphique et l'axe Y de l'OBV.
+
+pub mod calc;
+pub mod chart;
+pub mod axis;
+pub mod data;
+
+// Ré-exports pour faciliter l'accès
+pub use chart::obv_chart;
+pub use axis::obv_y_axis;
@@ -0,0 +1,157 @@
+//! Calculs des points pivots
+//!
+//! Classique : P = (H + L + C) / 3, R1 = 2P − L, S1 = 2P − H, R2 = P + (H − L),
+//! S2 = P − (H − L), R3 = H + 2(P − L), S3 = L − 2(H − P).
+//! Fibonacci : R/S = P ± 0.382, 0.618 et 1.0 × (H − L).
+//!
+//! H, L et C sont le plus haut, le plus bas et la clôture du jour (UTC) précédent,
+//! même sur un graphique intraday : les bougies sont regroupées par jour calendaire.
+
+use crate::finance_chart::core::Candle;
+use crate::finance_chart::indicators::vwap::calc::{utc_day, SECONDS_PER_DAY};
+use crate::app::state::PivotMethod;
+
+/// Niveaux pivots d'une période
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PivotLevels {
+    pub pivot: f64,
+    pub r1: f64,
+    pub r2: f64,
+    pub r3: f64,
+    pub s1: f64,
+    pub s2: f64,
+    pub s3: f64,
+}
+
+impl PivotLevels {
+    /// Calcule les niveaux à partir du plus haut, du plus bas et de la clôture de la période précédente
+    pub fn compute(high: f64, low: f64, close: f64, method: PivotMethod) -> Self {
+        let pivot = (high + low + close) / 3.0;
+        let range = high - low;
+
+        match method {
+            PivotMethod::Classic => Self {
+                pivot,
+                r1: 2.0 * pivot - low,
+                r2: pivot + range,
+                r3: high + 2.0 * (pivot - low),
+                s1: 2.0 * pivot - high,
+                s2: pivot - range,
+                s3: low - 2.0 * (high - pivot),
+            },
+            PivotMethod::Fibonacci => Self {
+                pivot,
+                r1: pivot + 0.382 * range,
+                r2: pivot + 0.618 * range,
+                r3: pivot + range,
+                s1: pivot - 0.382 * range,
+                s2: pivot - 0.618 * range,
+                s3: pivot - range,
+            },
+        }
+    }
+
+    /// Liste des niveaux avec leur libellé, de la résistance la plus haute au support le plus bas
+    pub fn labeled(&self) -> [(&'static str, f64); 7] {
+        [
+            ("R3", self.r3),
+            ("R2", self.r2),
+            ("R1", self.r1),
+            ("P", self.pivot),
+            ("S1", self.s1),
+            ("S2", self.s2),
+            ("S3", self.s3),
+        ]
+    }
+}
+
+/// Pivots applicables à un jour donné
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DailyPivots {
+    /// Début du jour (timestamp UTC, inclus)
+    pub day_start: i64,
+    /// Fin du jour (timestamp UTC, exclu)
+    pub day_end: i64,
+    pub levels: PivotLevels,
+}
+
+/// Calcule les pivots de chaque jour présent dans les bougies
+///
+/// Les niveaux d'un jour proviennent du jour précédent disponible dans les données
+/// (le premier jour n'a donc pas de pivots, et un week-end sans données est sauté).
+///
+/// # Arguments
+/// * `candles` - Bougies triées par timestamp croissant (toute granularité)
+/// * `method` - Formule utilisée (classique ou Fibonacci)
+pub fn calculate_daily_pivots(candles: &[Candle], method: PivotMethod) -> Vec<DailyPivots> {
+    // Regrouper les bougies par jour : (jour, plus haut, plus bas, dernière clôture)
+    let mut days: Vec<(i64, f64, f64, f64)> = Vec::new();
+    for candle in candles {
+        let day = utc_day(candle.timestamp);
+        match days.last_mut() {
+            Some((last_day, high, low, close)) if *last_day == day => {
+                *high = high.max(candle.high);
+                *low = low.min(candle.low);
+                *close = candle.close;
+            }
+            _ => days.push((day, candle.high, candle.low, candle.close)),
+        }
+    }
+
+    days.windows(2)
+        .map(|pair| {
+            let (_, high, low, close) = pair[0];
+            let day = pair[1].0;
+            DailyPivots {
+                day_start: day * SECONDS_PER_DAY,
+                day_end: (day + 1) * SECONDS_PER_DAY,
+                levels: PivotLevels::compute(high, low, close, method),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 3600;
+
+    #[test]
+    fn test_classic_levels() {
+        let levels = PivotLevels::compute(110.0, 90.0, 100.0, PivotMethod::Classic);
+        assert!((levels.pivot - 100.0).abs() < 1e-9);
+        assert!((levels.r1 - 110.0).abs() < 1e-9);
+        assert!((levels.s1 - 90.0).abs() < 1e-9);
+        assert!((levels.r2 - 120.0).abs() < 1e-9);
+        assert!((levels.s2 - 80.0).abs() < 1e-9);
+        assert!((levels.r3 - 130.0).abs() < 1e-9);
+        assert!((levels.s3 - 70.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fibonacci_levels() {
+        let levels = PivotLevels::compute(110.0, 90.0, 100.0, PivotMethod::Fibonacci);
+        assert!((levels.r1 - 107.64).abs() < 1e-9);
+        assert!((levels.s2 - 87.64).abs() < 1e-9);
+        assert!((levels.r3 - 120.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_intraday_candles_grouped_by_previous_day() {
+        let candles = vec![
+            // Jour 0 : H = 12, L = 8, C = 10
+            Candle::new(HOUR, 9.0, 12.0, 9.0, 11.0, 1.0),
+            Candle::new(5 * HOUR, 11.0, 11.5, 8.0, 10.0, 1.0),
+            // Jour 1
+            Candle::new(SECONDS_PER_DAY + HOUR, 10.0, 15.0, 10.0, 14.0, 1.0),
+            Candle::new(SECONDS_PER_DAY + 2 * HOUR, 14.0, 14.0, 13.0, 13.0, 1.0),
+        ];
+
+        let pivots = calculate_daily_pivots(&candles, PivotMethod::Classic);
+        assert_eq!(pivots.len(), 1);
+        assert_eq!(pivots[0].day_start, SECONDS_PER_DAY);
+        assert_eq!(pivots[0].day_end, 2 * SECONDS_PER_DAY);
+        assert!((pivots[0].levels.pivot - 10.0).abs() < 1e-9);
+    }
+}
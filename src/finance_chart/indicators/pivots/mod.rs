@@ -0,0 +1,8 @@
+//! Module Points Pivots (classiques et Fibonacci)
+//!
+//! Calcule les niveaux pivot, R1–R3 et S1–S3 de chaque jour à partir
+//! de l'OHLC du jour précédent.
+
+pub mod calc;
+
+pub use calc::{calculate_daily_pivots, DailyPivots};
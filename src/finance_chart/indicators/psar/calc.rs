@@ -0,0 +1,158 @@
+//! Calculs du Parabolic SAR
+//!
+//! SAR(n+1) = SAR(n) + AF × (EP - SAR(n)), où EP est le point extrême de la tendance
+//! en cours et AF le facteur d'accélération, incrémenté de `step` à chaque nouvel
+//! extrême et plafonné à `max_step`. À chaque inversion, le SAR repart de l'EP
+//! précédent et l'AF est réinitialisé à `step`.
+
+use crate::finance_chart::core::Candle;
+
+/// Paramètres par défaut pour le Parabolic SAR
+pub const PSAR_STEP: f64 = 0.02;
+pub const PSAR_MAX_STEP: f64 = 0.2;
+
+/// Valeur du Parabolic SAR pour une bougie
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PsarValue {
+    pub sar: f64,            // Prix du point SAR
+    pub is_uptrend: bool,    // true : point sous la bougie, false : au-dessus
+    pub acceleration: f64,   // Facteur d'accélération utilisé pour le point suivant
+}
+
+/// Calcule le Parabolic SAR pour une série de bougies
+///
+/// # Arguments
+/// * `candles` - Slice de bougies triées par timestamp croissant
+/// * `step` - Incrément du facteur d'accélération (défaut: 0.02)
+/// * `max_step` - Valeur maximale du facteur d'accélération (défaut: 0.2)
+///
+/// # Retourne
+/// Un vecteur de valeurs SAR correspondant à chaque bougie.
+/// La première valeur est `None` car la tendance initiale nécessite deux bougies.
+pub fn calculate_psar(candles: &[Candle], step: f64, max_step: f64) -> Vec<Option<PsarValue>> {
+    if candles.len() < 2 {
+        return vec![None; candles.len()];
+    }
+
+    let mut psar_values = Vec::with_capacity(candles.len());
+    psar_values.push(None);
+
+    // Tendance initiale déduite des deux premières clôtures
+    let mut is_uptrend = candles[1].close >= candles[0].close;
+    let mut sar = if is_uptrend { candles[0].low } else { candles[0].high };
+    let mut extreme_point = if is_uptrend {
+        candles[0].high.max(candles[1].high)
+    } else {
+        candles[0].low.min(candles[1].low)
+    };
+    let mut acceleration = step;
+    psar_values.push(Some(PsarValue { sar, is_uptrend, acceleration }));
+
+    for i in 2..candles.len() {
+        let candle = &candles[i];
+        let mut next_sar = sar + acceleration * (extreme_point - sar);
+
+        if is_uptrend {
+            // Le SAR ne peut pas dépasser les plus bas des deux bougies précédentes
+            next_sar = next_sar.min(candles[i - 1].low).min(candles[i - 2].low);
+
+            if candle.low < next_sar {
+                // Inversion : le SAR repart de l'extrême de la tendance précédente
+                is_uptrend = false;
+                next_sar = extreme_point;
+                extreme_point = candle.low;
+                acceleration = step;
+            } else if candle.high > extreme_point {
+                extreme_point = candle.high;
+                acceleration = (acceleration + step).min(max_step);
+            }
+        } else {
+            // Le SAR ne peut pas être sous les plus hauts des deux bougies précédentes
+            next_sar = next_sar.max(candles[i - 1].high).max(candles[i - 2].high);
+
+            if candle.high > next_sar {
+                // Inversion : le SAR repart de l'extrême de la tendance précédente
+                is_uptrend = true;
+                next_sar = extreme_point;
+                extreme_point = candle.high;
+                acceleration = step;
+            } else if candle.low < extreme_point {
+                extreme_point = candle.low;
+                acceleration = (acceleration + step).min(max_step);
+            }
+        }
+
+        sar = next_sar;
+        psar_values.push(Some(PsarValue { sar, is_uptrend, acceleration }));
+    }
+
+    psar_values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Série haussière régulière : chaque bougie fait un nouveau plus haut
+    fn rising_candles(count: i64) -> Vec<Candle> {
+        (0..count)
+            .map(|i| {
+                let base = 100.0 + i as f64;
+                Candle::new(i * 60, base, base + 1.0, base - 1.0, base + 0.5, 1000.0)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_psar_uptrend_below_lows() {
+        let candles = rising_candles(10);
+        let psar = calculate_psar(&candles, PSAR_STEP, PSAR_MAX_STEP);
+
+        assert_eq!(psar.len(), candles.len());
+        assert!(psar[0].is_none());
+        for (value, candle) in psar.iter().zip(candles.iter()).skip(1) {
+            let value = value.unwrap();
+            assert!(value.is_uptrend);
+            assert!(value.sar <= candle.low);
+        }
+    }
+
+    #[test]
+    fn test_psar_acceleration_capped() {
+        let candles = rising_candles(30);
+        let psar = calculate_psar(&candles, 0.05, 0.2);
+        let last = psar.last().unwrap().unwrap();
+        assert!((last.acceleration - 0.2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_psar_acceleration_resets_on_flip() {
+        let mut candles = rising_candles(8);
+        let before_flip = calculate_psar(&candles, PSAR_STEP, PSAR_MAX_STEP);
+        let previous = before_flip.last().unwrap().unwrap();
+        assert!(previous.acceleration > PSAR_STEP);
+
+        // Effondrement sous le SAR : inversion vers une tendance baissière
+        candles.push(Candle::new(8 * 60, 100.0, 100.0, 90.0, 91.0, 1000.0));
+        // Nouveau plus bas : l'AF repart de step et ne s'incrémente qu'une fois
+        candles.push(Candle::new(9 * 60, 91.0, 92.0, 85.0, 86.0, 1000.0));
+
+        let psar = calculate_psar(&candles, PSAR_STEP, PSAR_MAX_STEP);
+        let flip = psar[8].unwrap();
+        assert!(!flip.is_uptrend);
+        assert_eq!(flip.acceleration, PSAR_STEP);
+        // Le SAR repart du plus haut de la tendance haussière
+        assert_eq!(flip.sar, candles[7].high);
+        assert!(flip.sar >= candles[8].high);
+
+        let after = psar[9].unwrap();
+        assert!(!after.is_uptrend);
+        assert!((after.acceleration - 2.0 * PSAR_STEP).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_psar_not_enough_data() {
+        let candles = rising_candles(1);
+        assert_eq!(calculate_psar(&candles, PSAR_STEP, PSAR_MAX_STEP), vec![None]);
+    }
+}
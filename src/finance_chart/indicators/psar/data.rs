@@ -0,0 +1,87 @@
+//! Fonctions helper pour le calcul et l'extraction des données du Parabolic SAR
+//!
+//! Ce module contient la logique partagée pour calculer le Parabolic SAR avec toutes les bougies
+//! et extraire les valeurs correspondant aux bougies visibles.
+
+use crate::finance_chart::state::ChartState;
+use crate::finance_chart::indicators::VisibleSlice;
+use super::calc::{calculate_psar, PsarValue, PSAR_STEP, PSAR_MAX_STEP};
+
+/// Extrait les valeurs du Parabolic SAR correspondant aux bougies visibles
+///
+/// # Arguments
+/// * `chart_state` - L'état du graphique contenant les bougies
+/// * `all_psar_values` - Toutes les valeurs SAR pré-calculées
+///
+/// # Retourne
+/// Un tuple contenant :
+/// - Les valeurs SAR correspondant aux bougies visibles
+/// - Les bougies visibles
+/// - L'index de début des bougies visibles
+pub fn calculate_psar_data<'a>(
+    chart_state: &'a ChartState,
+    all_psar_values: &'a [Option<PsarValue>],
+) -> Option<VisibleSlice<'a, Option<PsarValue>>> {
+    if all_psar_values.is_empty() {
+        return None;
+    }
+
+    // Récupérer les bougies visibles pour déterminer quelle partie du SAR afficher
+    let visible_candles = chart_state.visible_candles();
+    if visible_candles.is_empty() {
+        return None;
+    }
+    let (_, visible_candles_slice) = &visible_candles[0];
+
+    // Récupérer toutes les bougies pour trouver l'index de début
+    let all_candles = chart_state.all_candles()?;
+
+    // Trouver l'index de début des bougies visibles dans toutes les bougies
+    let visible_start_idx = if let Some(first_visible) = visible_candles_slice.first() {
+        all_candles
+            .iter()
+            .position(|c| c.timestamp == first_visible.timestamp)
+            .unwrap_or(0)
+    } else {
+        0
+    };
+
+    // Limiter la tranche pour éviter un out-of-bounds si les vecteurs diffèrent
+    let end = (visible_start_idx + visible_candles_slice.len()).min(all_psar_values.len());
+    let start = visible_start_idx.min(end);
+    let slice = &all_psar_values[start..end];
+
+    Some((slice, visible_candles_slice, visible_start_idx))
+}
+
+/// Calcule toutes les valeurs du Parabolic SAR pour toutes les bougies
+///
+/// # Arguments
+/// * `chart_state` - L'état du graphique contenant les bougies
+/// * `step` - Incrément du facteur d'accélération (optionnel, utilise PSAR_STEP par défaut)
+/// * `max_step` - Facteur d'accélération maximal (optionnel, utilise PSAR_MAX_STEP par défaut)
+///
+/// # Retourne
+/// Toutes les valeurs SAR calculées, ou `None` si le calcul n'est pas possible
+pub fn calculate_all_psar_values(
+    chart_state: &ChartState,
+    step: Option<f64>,
+    max_step: Option<f64>,
+) -> Option<Vec<Option<PsarValue>>> {
+    let all_candles = chart_state.all_candles()?;
+
+    if all_candles.is_empty() {
+        return None;
+    }
+
+    let step = step.unwrap_or(PSAR_STEP);
+    let max_step = max_step.unwrap_or(PSAR_MAX_STEP).max(step);
+
+    let all_psar_values = calculate_psar(all_candles, step, max_step);
+
+    if all_psar_values.is_empty() {
+        None
+    } else {
+        Some(all_psar_values)
+    }
+}
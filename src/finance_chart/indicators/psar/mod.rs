@@ -0,0 +1,14 @@
+//! Module du Parabolic SAR (Stop And Reverse)
+//!
+//! Le Parabolic SAR place un point sous les bougies en tendance haussière
+//! et au-dessus en tendance baissière ; il se rapproche du prix à mesure
+//! que la tendance se prolonge, puis s'inverse quand le prix le traverse.
+
+pub mod calc;
+pub mod data;
+
+pub use calc::PsarValue;
+pub use data::{
+    calculate_psar_data,
+    calculate_all_psar_values,
+};
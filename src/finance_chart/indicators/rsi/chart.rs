@@ -7,7 +7,7 @@ use iced::{Color, Element, Length, Point, Rectangle};
 use iced::mouse::Cursor;
 
 use crate::finance_chart::state::ChartState;
-use crate::finance_chart::render::render_rsi_crosshair;
+use crate::finance_chart::render::{render_rsi_crosshair, render_hover_value};
 use crate::finance_chart::render::crosshair::CrosshairStyle;
 use crate::app::state::IndicatorParams;
 use super::calc::{RSI_OVERBOUGHT, RSI_OVERSOLD};
@@ -162,10 +162,29 @@ impl<'a> Program<crate::app::messages::Message> for RSIProgram<'a> {
             bounds.width,
             bounds.height,
             mouse_position_in_chart.map(|p| p.y),
-            Some(crosshair_style),
+            Some(crosshair_style.clone()),
             mouse_position_in_chart.map(|p| p.x),
         );
 
+        // Valeur du RSI au timestamp survolé (crosshair synchronisé)
+        if let (Some(index), Some(all_candles)) = (self.chart_state.hover_candle_index(), self.chart_state.all_candles()) {
+            if let (Some(Some(rsi)), Some(candle)) = (all_rsi_values.get(index), all_candles.get(index)) {
+                let x = rsi_time_scale.time_to_x(candle.timestamp);
+                if x >= 0.0 && x <= bounds.width {
+                    let y = height * (1.0 - (*rsi / 100.0).clamp(0.0, 1.0) as f32);
+                    render_hover_value(
+                        &mut frame,
+                        &crosshair_style,
+                        x,
+                        Some(y),
+                        &format!("RSI {:.1}", rsi),
+                        Color::from_rgb(0.0, 0.8, 1.0),
+                        bounds.width,
+                    );
+                }
+            }
+        }
+
         // Dessiner le label RSI dans la zone du chart (à droite), afin qu'il ne soit pas tronqué
         if let Some(current_rsi) = get_last_rsi_value(self.chart_state, Some(&all_rsi_values), Some(self.params)) {
             let label = format!("RSI: {:.1}", current_rsi);
@@ -232,6 +251,18 @@ impl<'a> Program<crate::app::messages::Message> for RSIProgram<'a> {
                         ));
                     }
                 }
+                // Partager le timestamp survolé avec le graphique principal et les autres panneaux
+                if let Some(position) = cursor.position_in(bounds) {
+                    let (min_time, max_time) = self.chart_state.viewport.time_scale().time_range();
+                    let time_scale = crate::finance_chart::scale::TimeScale::new(min_time, max_time, bounds.width);
+                    return Some(iced::widget::canvas::Action::publish(
+                        crate::app::messages::Message::Chart(
+                            crate::finance_chart::messages::ChartMessage::IndicatorHovered {
+                                timestamp: time_scale.x_to_time(position.x),
+                            }
+                        )
+                    ));
+                }
                 // Sinon, demander un redraw pour mettre à jour le crosshair
                 return Some(iced::widget::canvas::Action::request_redraw());
             }
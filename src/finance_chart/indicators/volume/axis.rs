@@ -9,6 +9,7 @@ use iced::mouse::Cursor;
 use crate::finance_chart::scale::VolumeScale;
 use crate::finance_chart::render::calculate_nice_step;
 use crate::finance_chart::axis::Y_AXIS_WIDTH;
+use crate::finance_chart::settings::format_abbreviated;
 
 /// Style pour l'axe des volumes
 struct AxisStyle {
@@ -73,7 +74,10 @@ impl<Message> Program<Message> for VolumeAxisProgram {
 
             // Ne dessiner que si visible
             if y >= 0.0 && y <= bounds.height {
-                let label = if volume_step >= 1.0 {
+                // Abréger les grands volumes pour qu'ils tiennent dans l'axe
+                let label = if max_volume >= 1_000.0 {
+                    format_abbreviated(volume)
+                } else if volume_step >= 1.0 {
                     format!("{:.0}", volume)
                 } else if volume_step >= 0.1 {
                     format!("{:.1}", volume)
@@ -0,0 +1,71 @@
+//! Calculs associés au panneau de volume
+//!
+//! Direction des barres (haussière/baissière) et moyenne mobile du volume.
+
+use crate::finance_chart::core::Candle;
+
+/// Détermine si la barre de volume d'une bougie doit être colorée en haussier
+///
+/// La direction est donnée par la clôture par rapport à l'ouverture. Pour une bougie
+/// sans corps (clôture = ouverture), on compare à la clôture précédente si elle existe ;
+/// la première bougie visible n'a pas de précédente et reste alors haussière.
+pub fn is_bullish_volume(candle: &Candle, previous: Option<&Candle>) -> bool {
+    if candle.close != candle.open {
+        return candle.close > candle.open;
+    }
+    previous.is_none_or(|prev| candle.close >= prev.close)
+}
+
+/// Calcule la moyenne mobile simple du volume
+///
+/// Les volumes invalides (NaN ou négatifs) comptent pour zéro.
+/// Les `period - 1` premières valeurs sont `None`.
+pub fn calculate_volume_ma(candles: &[Candle], period: usize) -> Vec<Option<f64>> {
+    if period == 0 || candles.len() < period {
+        return vec![None; candles.len()];
+    }
+
+    let volumes: Vec<f64> = candles
+        .iter()
+        .map(|c| if c.volume.is_nan() || c.volume < 0.0 { 0.0 } else { c.volume })
+        .collect();
+
+    let mut result = vec![None; period - 1];
+    let mut sum: f64 = volumes[..period].iter().sum();
+    result.push(Some(sum / period as f64));
+    for i in period..volumes.len() {
+        sum += volumes[i] - volumes[i - period];
+        result.push(Some(sum / period as f64));
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_volume_direction_with_doji() {
+        let up = Candle::new(0, 10.0, 12.0, 9.0, 11.0, 100.0);
+        let doji_above = Candle::new(60, 12.0, 12.5, 11.5, 12.0, 100.0);
+        let doji_below = Candle::new(120, 10.0, 10.5, 9.5, 10.0, 100.0);
+
+        assert!(is_bullish_volume(&up, None));
+        assert!(is_bullish_volume(&doji_above, Some(&up)));
+        assert!(!is_bullish_volume(&doji_below, Some(&up)));
+        // Première bougie visible : pas de précédente
+        assert!(is_bullish_volume(&doji_below, None));
+    }
+
+    #[test]
+    fn test_volume_ma() {
+        let candles: Vec<Candle> = [10.0, 20.0, 30.0, 40.0]
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| Candle::new(i as i64 * 60, 1.0, 1.0, 1.0, 1.0, v))
+            .collect();
+
+        let ma = calculate_volume_ma(&candles, 2);
+        assert_eq!(ma, vec![None, Some(15.0), Some(25.0), Some(35.0)]);
+    }
+}
@@ -1,27 +1,50 @@
 //! Widget Canvas pour afficher les volumes échangés
 //!
 //! Affiche des barres de volume pour chaque bougie, synchronisé avec le graphique principal.
+//! Les barres prennent les couleurs haussière/baissière du style et une moyenne
+//! du volume peut être superposée.
 
-use iced::widget::canvas::{Canvas, Frame, Geometry, Program, Path};
+use iced::widget::canvas::{Canvas, Frame, Geometry, Program, Path, Stroke};
 use iced::{Color, Element, Length, Point, Rectangle, Size};
 use iced::mouse::Cursor;
 
 use crate::finance_chart::state::ChartState;
 use crate::finance_chart::scale::VolumeScale;
-use crate::finance_chart::render::{calculate_bar_width, calculate_candle_period, render_volume_crosshair};
+use crate::finance_chart::render::{calculate_bar_width, calculate_candle_period, render_volume_crosshair, render_hover_value};
 use crate::finance_chart::render::crosshair::CrosshairStyle;
+use crate::finance_chart::settings::ChartStyle;
+use super::calc::{is_bullish_volume, calculate_volume_ma};
+use crate::finance_chart::settings::format_abbreviated;
+
+/// Opacité appliquée aux couleurs du style pour les barres de volume
+const VOLUME_BAR_ALPHA: f32 = 0.7;
+
+/// Couleur de la ligne de moyenne du volume
+const VOLUME_MA_COLOR: Color = Color::from_rgba(1.0, 0.85, 0.3, 0.9);
 
 /// Program Iced pour le rendu du volume
 pub struct VolumeProgram<'a> {
     chart_state: &'a ChartState,
     volume_scale: VolumeScale,
+    bullish_color: Color,
+    bearish_color: Color,
+    /// Période de la moyenne du volume (`None` = pas de moyenne)
+    ma_period: Option<usize>,
 }
 
 impl<'a> VolumeProgram<'a> {
-    pub fn new(chart_state: &'a ChartState, volume_scale: VolumeScale) -> Self {
+    pub fn new(
+        chart_state: &'a ChartState,
+        volume_scale: VolumeScale,
+        chart_style: &ChartStyle,
+        ma_period: Option<usize>,
+    ) -> Self {
         Self {
             chart_state,
             volume_scale,
+            bullish_color: Color { a: VOLUME_BAR_ALPHA, ..chart_style.bullish_color.to_iced() },
+            bearish_color: Color { a: VOLUME_BAR_ALPHA, ..chart_style.bearish_color.to_iced() },
+            ma_period,
         }
     }
 }
@@ -64,7 +87,7 @@ impl<'a> Program<crate::app::messages::Message> for VolumeProgram<'a> {
         let bar_width = calculate_bar_width(candle_period, max_time - min_time, bounds.width);
 
         // Dessiner les barres de volume
-        for candle in candles.iter() {
+        for (i, candle) in candles.iter().enumerate() {
             if candle.volume.is_nan() || candle.volume < 0.0 {
                 continue;
             }
@@ -78,10 +101,12 @@ impl<'a> Program<crate::app::messages::Message> for VolumeProgram<'a> {
                 let bar_height = (y_bottom - y_top).max(0.0);
 
                 if bar_height >= 0.0 {
-                    let bar_color = if candle.is_bullish() {
-                        Color::from_rgba(0.0, 0.6, 0.0, 0.7) // Vert
+                    // La première bougie visible n'a pas de précédente
+                    let previous = i.checked_sub(1).map(|p| &candles[p]);
+                    let bar_color = if is_bullish_volume(candle, previous) {
+                        self.bullish_color
                     } else {
-                        Color::from_rgba(0.8, 0.0, 0.0, 0.7) // Rouge
+                        self.bearish_color
                     };
 
                     let final_height = bar_height.max(1.0);
@@ -96,6 +121,32 @@ impl<'a> Program<crate::app::messages::Message> for VolumeProgram<'a> {
             }
         }
 
+        // Dessiner la moyenne du volume (calculée sur toutes les bougies)
+        if let (Some(period), Some(all_candles)) = (self.ma_period, self.chart_state.all_candles()) {
+            let all_ma_values = calculate_volume_ma(all_candles, period);
+            let visible_start_idx = candles
+                .first()
+                .and_then(|first| all_candles.iter().position(|c| c.timestamp == first.timestamp))
+                .unwrap_or(0);
+
+            let ma_path = Path::new(|builder| {
+                let mut first_point = true;
+                for (candle, ma_opt) in candles.iter().zip(all_ma_values.iter().skip(visible_start_idx)) {
+                    if let Some(ma) = ma_opt {
+                        let x = volume_time_scale.time_to_x(candle.timestamp);
+                        let y = self.volume_scale.volume_to_y(*ma);
+                        if first_point {
+                            builder.move_to(Point::new(x, y));
+                            first_point = false;
+                        } else {
+                            builder.line_to(Point::new(x, y));
+                        }
+                    }
+                }
+            });
+            frame.stroke(&ma_path, Stroke::default().with_color(VOLUME_MA_COLOR).with_width(1.5));
+        }
+
         // Rendre le crosshair synchronisé avec le graphique principal
         let mouse_position_in_chart = cursor.position_in(bounds);
         let main_mouse_position = self.chart_state.interaction.mouse_position;
@@ -113,10 +164,28 @@ impl<'a> Program<crate::app::messages::Message> for VolumeProgram<'a> {
             bounds.width,
             bounds.height,
             mouse_position_in_chart.map(|p| p.y),
-            Some(crosshair_style),
+            Some(crosshair_style.clone()),
             mouse_position_in_chart.map(|p| p.x),
         );
 
+        // Volume au timestamp survolé (crosshair synchronisé)
+        if let (Some(index), Some(all_candles)) = (self.chart_state.hover_candle_index(), self.chart_state.all_candles()) {
+            if let Some(candle) = all_candles.get(index).filter(|c| c.volume.is_finite()) {
+                let x = volume_time_scale.time_to_x(candle.timestamp);
+                if x >= 0.0 && x <= bounds.width {
+                    render_hover_value(
+                        &mut frame,
+                        &crosshair_style,
+                        x,
+                        Some(self.volume_scale.volume_to_y(candle.volume)),
+                        &format!("Vol {}", format_abbreviated(candle.volume)),
+                        VOLUME_MA_COLOR,
+                        bounds.width,
+                    );
+                }
+            }
+        }
+
         vec![frame.into_geometry()]
     }
 
@@ -167,6 +236,18 @@ impl<'a> Program<crate::app::messages::Message> for VolumeProgram<'a> {
                         ));
                     }
                 }
+                // Partager le timestamp survolé avec le graphique principal et les autres panneaux
+                if let Some(position) = cursor.position_in(bounds) {
+                    let (min_time, max_time) = self.chart_state.viewport.time_scale().time_range();
+                    let time_scale = crate::finance_chart::scale::TimeScale::new(min_time, max_time, bounds.width);
+                    return Some(iced::widget::canvas::Action::publish(
+                        crate::app::messages::Message::Chart(
+                            crate::finance_chart::messages::ChartMessage::IndicatorHovered {
+                                timestamp: time_scale.x_to_time(position.x),
+                            }
+                        )
+                    ));
+                }
                 // Sinon, demander un redraw pour mettre à jour le crosshair
                 return Some(iced::widget::canvas::Action::request_redraw());
             }
@@ -187,11 +268,16 @@ impl<'a> Program<crate::app::messages::Message> for VolumeProgram<'a> {
 }
 
 /// Crée un élément canvas pour afficher les volumes
+///
+/// Les barres utilisent les couleurs haussière/baissière de `chart_style`.
+/// `ma_period` active la ligne de moyenne du volume sur N périodes.
 pub fn volume_chart<'a>(
     chart_state: &'a ChartState,
     volume_scale: VolumeScale,
+    chart_style: &ChartStyle,
+    ma_period: Option<usize>,
 ) -> Element<'a, crate::app::messages::Message> {
-    Canvas::new(VolumeProgram::new(chart_state, volume_scale))
+    Canvas::new(VolumeProgram::new(chart_state, volume_scale, chart_style, ma_period))
         .width(Length::Fill)
         .height(Length::Fill)
         .into()
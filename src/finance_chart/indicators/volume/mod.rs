@@ -1,7 +1,8 @@
 //! Module Volume
 //!
-//! Contient le rendu graphique et l'axe Y du volume.
+//! Contient le rendu graphique, l'axe Y et la moyenne du volume.
 
+pub mod calc;
 pub mod chart;
 pub mod axis;
 
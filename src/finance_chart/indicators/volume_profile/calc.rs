@@ -0,0 +1,117 @@
+//! Calculs du Volume Profile
+//!
+//! Le volume de chaque bougie est réparti uniformément sur sa plage [low, high],
+//! puis accumulé dans des tranches de prix de hauteur égale.
+
+use crate::finance_chart::core::Candle;
+
+/// Nombre de tranches de prix par défaut
+pub const VOLUME_PROFILE_BINS: usize = 24;
+
+/// Calcule le profil de volume sur une plage de prix
+///
+/// # Arguments
+/// * `candles` - Bougies à prendre en compte (en général les bougies visibles)
+/// * `bins` - Nombre de tranches de prix
+/// * `price_range` - Plage (min, max) découpée en tranches
+///
+/// # Retourne
+/// Un vecteur de `(prix au centre de la tranche, volume)` du plus bas au plus haut.
+/// Seule la part du volume située dans la plage est comptée. Vide si les paramètres
+/// sont invalides.
+pub fn compute_profile(candles: &[Candle], bins: usize, price_range: (f64, f64)) -> Vec<(f64, f64)> {
+    let (min_price, max_price) = price_range;
+    if bins == 0 || !min_price.is_finite() || !max_price.is_finite() || max_price <= min_price {
+        return Vec::new();
+    }
+
+    let bin_size = (max_price - min_price) / bins as f64;
+    let mut volumes = vec![0.0; bins];
+    let bin_index = |price: f64| (((price - min_price) / bin_size) as usize).min(bins - 1);
+
+    for candle in candles {
+        if candle.volume.is_nan() || candle.volume <= 0.0 {
+            continue;
+        }
+
+        let span = candle.high - candle.low;
+        if span <= f64::EPSILON {
+            // Bougie sans amplitude : tout le volume au niveau de la clôture
+            if candle.close >= min_price && candle.close <= max_price {
+                volumes[bin_index(candle.close)] += candle.volume;
+            }
+            continue;
+        }
+
+        let low = candle.low.max(min_price);
+        let high = candle.high.min(max_price);
+        if high <= low {
+            continue;
+        }
+
+        // Répartir le volume au prorata du recouvrement avec chaque tranche
+        let (first, last) = (bin_index(low), bin_index(high));
+        for (i, bin_volume) in volumes.iter_mut().enumerate().take(last + 1).skip(first) {
+            let bin_low = min_price + i as f64 * bin_size;
+            let bin_high = bin_low + bin_size;
+            let overlap = high.min(bin_high) - low.max(bin_low);
+            if overlap > 0.0 {
+                *bin_volume += candle.volume * overlap / span;
+            }
+        }
+    }
+
+    volumes
+        .into_iter()
+        .enumerate()
+        .map(|(i, volume)| (min_price + (i as f64 + 0.5) * bin_size, volume))
+        .collect()
+}
+
+/// Retourne l'index de la tranche de volume maximal (point of control)
+pub fn point_of_control(profile: &[(f64, f64)]) -> Option<usize> {
+    profile
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, volume))| *volume > 0.0)
+        .max_by(|a, b| a.1 .1.total_cmp(&b.1 .1))
+        .map(|(i, _)| i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_distributes_volume_over_range() {
+        // Bougie couvrant 100 → 110 avec 100 de volume, 10 tranches de 1
+        let candles = vec![Candle::new(0, 100.0, 110.0, 100.0, 105.0, 100.0)];
+        let profile = compute_profile(&candles, 10, (100.0, 110.0));
+
+        assert_eq!(profile.len(), 10);
+        assert!((profile[0].0 - 100.5).abs() < 1e-9);
+        assert!(profile.iter().all(|(_, v)| (v - 10.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_profile_clips_outside_range_and_finds_poc() {
+        let candles = vec![
+            // Moitié hors plage : seuls 50 sont comptés
+            Candle::new(0, 95.0, 105.0, 95.0, 100.0, 100.0),
+            // Bougie sans amplitude à 108
+            Candle::new(60, 108.0, 108.0, 108.0, 108.0, 80.0),
+        ];
+        let profile = compute_profile(&candles, 2, (100.0, 110.0));
+
+        assert!((profile[0].1 - 50.0).abs() < 1e-9);
+        assert!((profile[1].1 - 80.0).abs() < 1e-9);
+        assert_eq!(point_of_control(&profile), Some(1));
+    }
+
+    #[test]
+    fn test_profile_invalid_inputs() {
+        assert!(compute_profile(&[], 0, (0.0, 1.0)).is_empty());
+        assert!(compute_profile(&[], 10, (5.0, 5.0)).is_empty());
+        assert_eq!(point_of_control(&[(1.0, 0.0)]), None);
+    }
+}
@@ -0,0 +1,8 @@
+//! Module Volume Profile (volume par niveau de prix)
+//!
+//! Répartit le volume des bougies visibles dans des tranches de prix
+//! pour afficher un histogramme horizontal sur le graphique principal.
+
+pub mod calc;
+
+pub use calc::{compute_profile, point_of_control, VOLUME_PROFILE_BINS};
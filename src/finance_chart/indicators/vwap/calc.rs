@@ -0,0 +1,123 @@
+//! Calculs du VWAP (Volume Weighted Average Price)
+//!
+//! VWAP = Σ(prix typique × volume) / Σ(volume), avec prix typique = (high + low + close) / 3.
+
+use crate::finance_chart::core::Candle;
+use crate::finance_chart::render::calculate_candle_period;
+
+/// Nombre de secondes dans un jour
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Retourne l'index du jour UTC d'un timestamp (en secondes)
+pub fn utc_day(timestamp: i64) -> i64 {
+    timestamp.div_euclid(SECONDS_PER_DAY)
+}
+
+/// Indique si le VWAP doit être réinitialisé chaque jour pour cette série
+///
+/// Seules les séries intraday (période < 1 jour) sont réinitialisées ;
+/// au-delà, chaque bougie serait sa propre session et le VWAP n'aurait pas de sens.
+pub fn resets_daily(candles: &[Candle]) -> bool {
+    candles.len() >= 2 && calculate_candle_period(candles) < SECONDS_PER_DAY
+}
+
+/// Calcule le VWAP pour une série de bougies
+///
+/// # Arguments
+/// * `candles` - Slice de bougies triées par timestamp croissant
+/// * `reset_daily` - Réinitialise les cumuls au début de chaque jour UTC
+///
+/// # Retourne
+/// Un vecteur de valeurs VWAP correspondant à chaque bougie.
+/// La valeur est `None` tant qu'aucun volume n'a été échangé depuis la dernière réinitialisation.
+pub fn calculate_vwap(candles: &[Candle], reset_daily: bool) -> Vec<Option<f64>> {
+    let mut vwap_values = Vec::with_capacity(candles.len());
+    let mut cumulative_pv = 0.0;
+    let mut cumulative_volume = 0.0;
+    let mut current_day = None;
+
+    for candle in candles {
+        if reset_daily {
+            let day = utc_day(candle.timestamp);
+            if current_day != Some(day) {
+                current_day = Some(day);
+                cumulative_pv = 0.0;
+                cumulative_volume = 0.0;
+            }
+        }
+
+        let typical_price = (candle.high + candle.low + candle.close) / 3.0;
+        cumulative_pv += typical_price * candle.volume;
+        cumulative_volume += candle.volume;
+
+        if cumulative_volume > 0.0 {
+            vwap_values.push(Some(cumulative_pv / cumulative_volume));
+        } else {
+            vwap_values.push(None);
+        }
+    }
+
+    vwap_values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 3600;
+
+    #[test]
+    fn test_vwap_weights_by_volume() {
+        let candles = vec![
+            Candle::new(0, 10.0, 10.0, 10.0, 10.0, 1.0),
+            Candle::new(HOUR, 20.0, 20.0, 20.0, 20.0, 3.0),
+        ];
+
+        let vwap = calculate_vwap(&candles, true);
+        assert_eq!(vwap[0], Some(10.0));
+        // (10 * 1 + 20 * 3) / 4
+        assert_eq!(vwap[1], Some(17.5));
+    }
+
+    #[test]
+    fn test_vwap_resets_at_utc_midnight() {
+        let candles = vec![
+            Candle::new(SECONDS_PER_DAY - 2 * HOUR, 10.0, 10.0, 10.0, 10.0, 1.0),
+            Candle::new(SECONDS_PER_DAY - HOUR, 20.0, 20.0, 20.0, 20.0, 1.0),
+            Candle::new(SECONDS_PER_DAY, 30.0, 30.0, 30.0, 30.0, 1.0),
+        ];
+
+        let vwap = calculate_vwap(&candles, true);
+        assert_eq!(vwap[1], Some(15.0));
+        // Nouvelle journée : le VWAP repart du prix de la première bougie
+        assert_eq!(vwap[2], Some(30.0));
+
+        let cumulative = calculate_vwap(&candles, false);
+        assert_eq!(cumulative[2], Some(20.0));
+    }
+
+    #[test]
+    fn test_vwap_zero_volume() {
+        let candles = vec![Candle::new(0, 10.0, 10.0, 10.0, 10.0, 0.0)];
+        assert_eq!(calculate_vwap(&candles, true), vec![None]);
+    }
+
+    #[test]
+    fn test_resets_daily_depends_on_period() {
+        let hourly: Vec<Candle> = (0..3)
+            .map(|i| Candle::new(i * HOUR, 1.0, 1.0, 1.0, 1.0, 1.0))
+            .collect();
+        let daily: Vec<Candle> = (0..3)
+            .map(|i| Candle::new(i * SECONDS_PER_DAY, 1.0, 1.0, 1.0, 1.0, 1.0))
+            .collect();
+
+        assert!(resets_daily(&hourly));
+        assert!(!resets_daily(&daily));
+    }
+
+    #[test]
+    fn test_utc_day_negative_timestamp() {
+        assert_eq!(utc_day(-1), -1);
+        assert_eq!(utc_day(0), 0);
+    }
+}
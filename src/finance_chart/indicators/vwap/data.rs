@@ -0,0 +1,81 @@
+//! Fonctions helper pour le calcul et l'extraction des données du VWAP
+//!
+//! Ce module contient la logique partagée pour calculer le VWAP avec toutes les bougies
+//! et extraire les valeurs correspondant aux bougies visibles.
+
+use crate::finance_chart::state::ChartState;
+use crate::finance_chart::indicators::VisibleSlice;
+use super::calc::{calculate_vwap, resets_daily};
+
+/// Extrait les valeurs VWAP correspondant aux bougies visibles
+///
+/// # Arguments
+/// * `chart_state` - L'état du graphique contenant les bougies
+/// * `all_vwap_values` - Toutes les valeurs VWAP pré-calculées
+///
+/// # Retourne
+/// Un tuple contenant :
+/// - Les valeurs VWAP correspondant aux bougies visibles
+/// - Les bougies visibles
+/// - L'index de début des bougies visibles
+pub fn calculate_vwap_data<'a>(
+    chart_state: &'a ChartState,
+    all_vwap_values: &'a [Option<f64>],
+) -> Option<VisibleSlice<'a, Option<f64>>> {
+    if all_vwap_values.is_empty() {
+        return None;
+    }
+
+    // Récupérer les bougies visibles pour déterminer quelle partie du VWAP afficher
+    let visible_candles = chart_state.visible_candles();
+    if visible_candles.is_empty() {
+        return None;
+    }
+    let (_, visible_candles_slice) = &visible_candles[0];
+
+    // Récupérer toutes les bougies pour trouver l'index de début
+    let all_candles = chart_state.all_candles()?;
+
+    // Trouver l'index de début des bougies visibles dans toutes les bougies
+    let visible_start_idx = if let Some(first_visible) = visible_candles_slice.first() {
+        all_candles
+            .iter()
+            .position(|c| c.timestamp == first_visible.timestamp)
+            .unwrap_or(0)
+    } else {
+        0
+    };
+
+    // Limiter la tranche pour éviter un out-of-bounds si les vecteurs diffèrent
+    let end = (visible_start_idx + visible_candles_slice.len()).min(all_vwap_values.len());
+    let start = visible_start_idx.min(end);
+    let slice = &all_vwap_values[start..end];
+
+    Some((slice, visible_candles_slice, visible_start_idx))
+}
+
+/// Calcule toutes les valeurs du VWAP pour toutes les bougies
+///
+/// Le VWAP est réinitialisé chaque jour UTC pour les séries intraday,
+/// et cumulé sur toute la série pour les unités de temps journalières et plus.
+///
+/// # Arguments
+/// * `chart_state` - L'état du graphique contenant les bougies
+///
+/// # Retourne
+/// Toutes les valeurs VWAP calculées, ou `None` si le calcul n'est pas possible
+pub fn calculate_all_vwap_values(chart_state: &ChartState) -> Option<Vec<Option<f64>>> {
+    let all_candles = chart_state.all_candles()?;
+
+    if all_candles.is_empty() {
+        return None;
+    }
+
+    let all_vwap_values = calculate_vwap(all_candles, resets_daily(all_candles));
+
+    if all_vwap_values.is_empty() {
+        None
+    } else {
+        Some(all_vwap_values)
+    }
+}
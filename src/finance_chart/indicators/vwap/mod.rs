@@ -0,0 +1,13 @@
+//! Module VWAP (Volume Weighted Average Price)
+//!
+//! Le VWAP est le prix moyen pondéré par le volume. Sur les séries intraday,
+//! il repart de zéro au début de chaque jour UTC ; sur les séries journalières
+//! et plus longues, il est cumulé sur toute la série.
+
+pub mod calc;
+pub mod data;
+
+pub use data::{
+    calculate_vwap_data,
+    calculate_all_vwap_values,
+};
@@ -0,0 +1,61 @@
+//! Canvas pour l'axe Y du Williams %R
+//!
+//! Affiche les valeurs du Williams %R (-100 à 0) sur l'axe vertical à droite du graphique.
+
+use iced::widget::canvas::{Canvas, Frame, Geometry, Program, Text, Path};
+use iced::{Element, Length, Point, Rectangle};
+use iced::mouse::Cursor;
+use iced::Pixels;
+
+use crate::finance_chart::axis::{Y_AXIS_WIDTH, AxisStyle};
+use crate::finance_chart::state::ChartState;
+use super::data::williams_r_to_y;
+
+/// Program pour l'axe Y du Williams %R
+pub struct WilliamsRAxisProgram;
+
+impl<Message> Program<Message> for WilliamsRAxisProgram {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let style = AxisStyle::default();
+
+        // Fond
+        let background = Path::rectangle(Point::ORIGIN, bounds.size());
+        frame.fill(&background, style.background_color);
+
+        // Le Williams %R varie de -100 à 0, un label tous les 20
+        for value in (-100..=0).step_by(20) {
+            let y = williams_r_to_y(value as f64, bounds.height);
+
+            if y >= 0.0 && y <= bounds.height {
+                let text = Text {
+                    content: format!("{}", value),
+                    position: Point::new(5.0, y - 6.0),
+                    color: style.text_color,
+                    size: Pixels(style.text_size),
+                    ..Text::default()
+                };
+                frame.fill_text(text);
+            }
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+/// Crée un widget canvas pour l'axe Y du Williams %R
+pub fn williams_r_y_axis<'a>(_chart_state: &'a ChartState, height: f32) -> Element<'a, crate::app::messages::Message> {
+    Canvas::new(WilliamsRAxisProgram)
+        .width(Length::Fixed(Y_AXIS_WIDTH))
+        .height(Length::Fixed(height))
+        .into()
+}
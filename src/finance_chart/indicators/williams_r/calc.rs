@@ -0,0 +1,72 @@
+//! Calculs du Williams %R
+//!
+//! %R = (plus haut de la période - clôture) / (plus haut - plus bas) × -100.
+//! L'indicateur varie de -100 (clôture au plus bas) à 0 (clôture au plus haut).
+
+use crate::finance_chart::core::Candle;
+
+/// Période par défaut pour le Williams %R
+pub const WILLIAMS_R_PERIOD: usize = 14;
+
+/// Niveaux de référence du Williams %R
+pub const WILLIAMS_R_OVERBOUGHT: f64 = -20.0;
+pub const WILLIAMS_R_OVERSOLD: f64 = -80.0;
+
+/// Calcule le Williams %R pour une série de bougies
+///
+/// # Arguments
+/// * `candles` - Slice de bougies triées par timestamp croissant
+/// * `period` - Période de la fenêtre glissante (défaut: 14)
+///
+/// # Retourne
+/// Un vecteur de valeurs %R correspondant à chaque bougie.
+/// Les `period - 1` premières valeurs sont `None` car il n'y a pas assez de données.
+/// Si le plus haut et le plus bas sont égaux, la valeur est -50 (milieu de la plage).
+pub fn calculate_williams_r(candles: &[Candle], period: usize) -> Vec<Option<f64>> {
+    if period == 0 || candles.len() < period {
+        return vec![None; candles.len()];
+    }
+
+    let mut values = vec![None; period - 1];
+    values.extend(candles.windows(period).map(|window| {
+        let highest = window.iter().map(|c| c.high).fold(f64::NEG_INFINITY, f64::max);
+        let lowest = window.iter().map(|c| c.low).fold(f64::INFINITY, f64::min);
+        let close = window[period - 1].close;
+        let range = highest - lowest;
+
+        Some(if range > f64::EPSILON {
+            (highest - close) / range * -100.0
+        } else {
+            -50.0
+        })
+    }));
+
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_williams_r_bounds() {
+        let candles = vec![
+            Candle::new(0, 10.0, 12.0, 8.0, 10.0, 1.0),
+            Candle::new(60, 10.0, 14.0, 9.0, 14.0, 1.0),   // Clôture au plus haut
+            Candle::new(120, 14.0, 13.0, 8.0, 8.0, 1.0),   // Clôture au plus bas
+        ];
+        let values = calculate_williams_r(&candles, 2);
+
+        assert_eq!(values[0], None);
+        assert_eq!(values[1], Some(0.0));
+        assert_eq!(values[2], Some(-100.0));
+    }
+
+    #[test]
+    fn test_williams_r_flat_range() {
+        let candles: Vec<Candle> = (0..3)
+            .map(|i| Candle::new(i * 60, 5.0, 5.0, 5.0, 5.0, 1.0))
+            .collect();
+        assert_eq!(calculate_williams_r(&candles, 2)[2], Some(-50.0));
+    }
+}
@@ -0,0 +1,223 @@
+//! Widget Canvas pour afficher le Williams %R
+//!
+//! Affiche le Williams %R dans un graphique séparé sous le graphique principal,
+//! sur une échelle fixe de -100 à 0 avec les zones de sur-achat (-20) et de sur-vente (-80).
+
+use iced::widget::canvas::{Canvas, Frame, Geometry, Program, Path, Stroke, Text};
+use iced::{Color, Element, Length, Point, Rectangle};
+use iced::mouse::Cursor;
+
+use crate::finance_chart::state::ChartState;
+use crate::finance_chart::render::render_williams_r_crosshair;
+use crate::finance_chart::render::crosshair::CrosshairStyle;
+use super::calc::{WILLIAMS_R_OVERBOUGHT, WILLIAMS_R_OVERSOLD};
+use super::data::{calculate_all_williams_r_values, calculate_williams_r_data, williams_r_to_y, y_to_williams_r};
+
+/// Couleur de la ligne Williams %R
+const WILLIAMS_R_LINE_COLOR: Color = Color::from_rgb(0.3, 0.9, 0.6);
+
+/// Program Iced pour le rendu du Williams %R
+pub struct WilliamsRProgram<'a> {
+    chart_state: &'a ChartState,
+    period: usize,
+}
+
+impl<'a> WilliamsRProgram<'a> {
+    pub fn new(chart_state: &'a ChartState, period: usize) -> Self {
+        Self { chart_state, period }
+    }
+}
+
+impl<'a> Program<crate::app::messages::Message> for WilliamsRProgram<'a> {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        // Fond sombre
+        let background = Path::rectangle(Point::ORIGIN, bounds.size());
+        frame.fill(&background, Color::from_rgb(0.07, 0.10, 0.09));
+
+        // Calculer toutes les valeurs Williams %R (sur toutes les bougies, pas seulement visibles)
+        let all_williams_r_values = match calculate_all_williams_r_values(self.chart_state, Some(self.period)) {
+            Some(values) => values,
+            None => return vec![frame.into_geometry()],
+        };
+
+        let (visible_williams_r_values, visible_candles_slice, _visible_start_idx) =
+            match calculate_williams_r_data(self.chart_state, &all_williams_r_values) {
+                Some(data) => data,
+                None => return vec![frame.into_geometry()],
+            };
+
+        if visible_williams_r_values.is_empty() || visible_candles_slice.is_empty() {
+            return vec![frame.into_geometry()];
+        }
+
+        let viewport = &self.chart_state.viewport;
+
+        // Créer un TimeScale temporaire pour le Williams %R chart qui utilise bounds.width
+        let (min_time, max_time) = viewport.time_scale().time_range();
+        use crate::finance_chart::scale::TimeScale;
+        let wr_time_scale = TimeScale::new(min_time, max_time, bounds.width);
+
+        let height = bounds.height;
+
+        // Zone de sur-achat (-20 à 0) et de sur-vente (-100 à -80)
+        let overbought_y = williams_r_to_y(WILLIAMS_R_OVERBOUGHT, height);
+        let oversold_y = williams_r_to_y(WILLIAMS_R_OVERSOLD, height);
+        frame.fill(
+            &Path::rectangle(Point::ORIGIN, iced::Size::new(bounds.width, overbought_y)),
+            Color::from_rgba(1.0, 0.0, 0.0, 0.2),
+        );
+        frame.fill(
+            &Path::rectangle(Point::new(0.0, oversold_y), iced::Size::new(bounds.width, height - oversold_y)),
+            Color::from_rgba(0.0, 1.0, 0.0, 0.2),
+        );
+
+        // Dessiner les lignes de référence à -80, -50 et -20
+        for level in [WILLIAMS_R_OVERSOLD, -50.0, WILLIAMS_R_OVERBOUGHT] {
+            let y = williams_r_to_y(level, height);
+            let ref_path = Path::line(Point::new(0.0, y), Point::new(bounds.width, y));
+            frame.stroke(
+                &ref_path,
+                Stroke::default()
+                    .with_color(Color::from_rgba(0.5, 0.5, 0.5, 0.5))
+                    .with_width(1.0),
+            );
+        }
+
+        // Dessiner la ligne du Williams %R
+        let wr_path = Path::new(|builder| {
+            let mut first_point = true;
+
+            for (wr_opt, candle) in visible_williams_r_values.iter().zip(visible_candles_slice.iter()) {
+                if let Some(wr) = wr_opt {
+                    let x = wr_time_scale.time_to_x(candle.timestamp);
+                    let y = williams_r_to_y(*wr, height);
+
+                    if x >= -10.0 && x <= bounds.width + 10.0 {
+                        if first_point {
+                            builder.move_to(Point::new(x, y));
+                            first_point = false;
+                        } else {
+                            builder.line_to(Point::new(x, y));
+                        }
+                    }
+                }
+            }
+        });
+
+        let stroke = Stroke::default()
+            .with_color(WILLIAMS_R_LINE_COLOR)
+            .with_width(2.0);
+        frame.stroke(&wr_path, stroke);
+
+        // Rendre le crosshair synchronisé avec le graphique principal
+        let mouse_position_in_chart = cursor.position_in(bounds);
+        let crosshair_style = CrosshairStyle {
+            line_color: Color::from_rgba(0.6, 0.6, 0.6, 0.8),
+            ..Default::default()
+        };
+        render_williams_r_crosshair(
+            &mut frame,
+            bounds.width,
+            bounds.height,
+            mouse_position_in_chart.map(|p| p.y),
+            &|y| y_to_williams_r(y, height),
+            Some(crosshair_style),
+        );
+
+        // Dessiner le label Williams %R dans la zone du chart (à droite)
+        if let Some(current_wr) = all_williams_r_values.iter().rev().find_map(|opt| *opt) {
+            let label = format!("%R({}): {:.1}", self.period, current_wr);
+            let label_width = 6.5 * label.len() as f32 + 8.0;
+            let text_bg = Path::rectangle(
+                Point::new(bounds.width - label_width, 0.0),
+                iced::Size::new(label_width, 18.0),
+            );
+            frame.fill(&text_bg, Color::from_rgba(0.0, 0.0, 0.0, 0.45));
+            frame.fill_text(Text {
+                content: label,
+                position: Point::new(bounds.width - label_width + 4.0, 6.0),
+                color: WILLIAMS_R_LINE_COLOR,
+                size: iced::Pixels(11.0),
+                ..Text::default()
+            });
+        }
+
+        vec![frame.into_geometry()]
+    }
+
+    fn update(
+        &self,
+        _state: &mut Self::State,
+        event: &iced::Event,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Option<iced::widget::canvas::Action<crate::app::messages::Message>> {
+        match event {
+            // Gestion du pan (drag) avec la position absolue, comme le graphique principal
+            iced::Event::Mouse(iced::mouse::Event::ButtonPressed(iced::mouse::Button::Left)) if cursor.position_in(bounds).is_some() => {
+                if let Some(absolute_position) = cursor.position() {
+                    return Some(iced::widget::canvas::Action::publish(
+                        crate::app::messages::Message::Chart(
+                            crate::finance_chart::messages::ChartMessage::StartPan {
+                                position: absolute_position,
+                                time: None,
+                            }
+                        )
+                    ));
+                }
+            }
+            iced::Event::Mouse(iced::mouse::Event::ButtonReleased(iced::mouse::Button::Left)) => {
+                return Some(iced::widget::canvas::Action::publish(
+                    crate::app::messages::Message::Chart(
+                        crate::finance_chart::messages::ChartMessage::EndPan
+                    )
+                ));
+            }
+            iced::Event::Mouse(iced::mouse::Event::CursorMoved { position: _ }) => {
+                if self.chart_state.interaction.is_panning {
+                    if let Some(absolute_position) = cursor.position() {
+                        return Some(iced::widget::canvas::Action::publish(
+                            crate::app::messages::Message::Chart(
+                                crate::finance_chart::messages::ChartMessage::UpdatePanHorizontal {
+                                    position: absolute_position
+                                }
+                            )
+                        ));
+                    }
+                }
+                // Sinon, demander un redraw pour mettre à jour le crosshair
+                return Some(iced::widget::canvas::Action::request_redraw());
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Self::State,
+        _bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> iced::mouse::Interaction {
+        iced::mouse::Interaction::Crosshair
+    }
+}
+
+/// Crée un widget canvas pour le Williams %R
+pub fn williams_r_chart<'a>(chart_state: &'a ChartState, period: usize) -> Element<'a, crate::app::messages::Message> {
+    Canvas::new(WilliamsRProgram::new(chart_state, period))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+}
@@ -0,0 +1,107 @@
+//! Fonctions helper pour le calcul et l'extraction des données Williams %R
+//!
+//! Ce module contient la logique partagée pour calculer le Williams %R avec toutes les bougies
+//! et extraire les valeurs correspondant aux bougies visibles.
+
+use crate::finance_chart::state::ChartState;
+use crate::finance_chart::indicators::VisibleSlice;
+use super::calc::{calculate_williams_r, WILLIAMS_R_PERIOD};
+
+/// Extrait les valeurs Williams %R correspondant aux bougies visibles
+///
+/// # Arguments
+/// * `chart_state` - L'état du graphique contenant les bougies
+/// * `all_williams_r_values` - Toutes les valeurs Williams %R pré-calculées
+///
+/// # Retourne
+/// Un tuple contenant :
+/// - Les valeurs Williams %R correspondant aux bougies visibles
+/// - Les bougies visibles
+/// - L'index de début des bougies visibles
+pub fn calculate_williams_r_data<'a>(
+    chart_state: &'a ChartState,
+    all_williams_r_values: &'a [Option<f64>],
+) -> Option<VisibleSlice<'a, Option<f64>>> {
+    if all_williams_r_values.is_empty() {
+        return None;
+    }
+
+    // Récupérer les bougies visibles pour déterminer quelle partie du Williams %R afficher
+    let visible_candles = chart_state.visible_candles();
+    if visible_candles.is_empty() {
+        return None;
+    }
+    let (_, visible_candles_slice) = &visible_candles[0];
+
+    // Récupérer toutes les bougies pour trouver l'index de début
+    let all_candles = chart_state.all_candles()?;
+
+    // Trouver l'index de début des bougies visibles dans toutes les bougies
+    let visible_start_idx = if let Some(first_visible) = visible_candles_slice.first() {
+        all_candles
+            .iter()
+            .position(|c| c.timestamp == first_visible.timestamp)
+            .unwrap_or(0)
+    } else {
+        0
+    };
+
+    // Limiter la tranche pour éviter un out-of-bounds si les vecteurs diffèrent
+    let end = (visible_start_idx + visible_candles_slice.len()).min(all_williams_r_values.len());
+    let start = visible_start_idx.min(end);
+    let slice = &all_williams_r_values[start..end];
+
+    Some((slice, visible_candles_slice, visible_start_idx))
+}
+
+/// Calcule toutes les valeurs Williams %R pour toutes les bougies
+///
+/// # Arguments
+/// * `chart_state` - L'état du graphique contenant les bougies
+/// * `period` - Période du Williams %R (défaut: `WILLIAMS_R_PERIOD`)
+///
+/// # Retourne
+/// Toutes les valeurs Williams %R calculées, ou `None` si le calcul n'est pas possible
+pub fn calculate_all_williams_r_values(
+    chart_state: &ChartState,
+    period: Option<usize>,
+) -> Option<Vec<Option<f64>>> {
+    let all_candles = chart_state.all_candles()?;
+
+    if all_candles.is_empty() {
+        return None;
+    }
+
+    let period = period.unwrap_or(WILLIAMS_R_PERIOD);
+    let all_williams_r_values = calculate_williams_r(all_candles, period);
+
+    if all_williams_r_values.is_empty() {
+        None
+    } else {
+        Some(all_williams_r_values)
+    }
+}
+
+/// Convertit une valeur %R (-100 à 0) en coordonnée Y
+pub fn williams_r_to_y(value: f64, height: f32) -> f32 {
+    let normalized = ((value + 100.0) / 100.0).clamp(0.0, 1.0);
+    height * (1.0 - normalized as f32)
+}
+
+/// Convertit une coordonnée Y en valeur %R (-100 à 0)
+pub fn y_to_williams_r(y: f32, height: f32) -> f64 {
+    let normalized = 1.0 - (y / height) as f64;
+    normalized * 100.0 - 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_williams_r_y_mapping() {
+        assert!((williams_r_to_y(0.0, 100.0) - 0.0).abs() < 1e-4);
+        assert!((williams_r_to_y(-100.0, 100.0) - 100.0).abs() < 1e-4);
+        assert!((y_to_williams_r(20.0, 100.0) + 20.0).abs() < 1e-6);
+    }
+}
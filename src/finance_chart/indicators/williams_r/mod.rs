@@ -0,0 +1,12 @@
+//! Module Williams %R
+//!
+//! Contient les calculs, le rendu graphique et l'axe Y du Williams %R.
+
+pub mod calc;
+pub mod chart;
+pub mod axis;
+pub mod data;
+
+// Ré-exports pour faciliter l'accès
+pub use chart::williams_r_chart;
+pub use axis::williams_r_y_axis;
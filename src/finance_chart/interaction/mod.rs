@@ -1,6 +1,8 @@
 pub mod events;
 pub mod rectangle_editing;
+pub mod snapping;
 
 pub use events::InteractionState;
 pub use rectangle_editing::{hit_test_rectangles, apply_edit_update, cursor_for_edit_mode};
+pub use snapping::{snap_to_candle, snap_rectangle_edit, nearest_candle_index};
 
@@ -38,6 +38,10 @@ pub fn hit_test_rectangles(
 
     // Parcourir les rectangles en ordre inverse (le dernier dessiné est au-dessus)
     for (index, rect) in rectangles.iter().enumerate().rev() {
+        // Les rectangles verrouillés ou masqués ne sont pas éditables
+        if rect.locked || rect.hidden {
+            continue;
+        }
         let x1 = time_scale.time_to_x(rect.start_time);
         let x2 = time_scale.time_to_x(rect.end_time);
         let y1 = price_scale.price_to_y(rect.start_price);
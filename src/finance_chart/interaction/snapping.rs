@@ -0,0 +1,163 @@
+//! Aimantation des dessins sur les bougies (Ctrl maintenu)
+
+use crate::finance_chart::core::Candle;
+use crate::finance_chart::tools::{DrawnRectangle, EditMode};
+use crate::finance_chart::viewport::Viewport;
+
+/// Distance maximale (en pixels) pour aimanter un prix sur une valeur OHLC
+const PRICE_SNAP_DISTANCE: f32 = 30.0;
+
+/// Retourne l'index de la bougie la plus proche d'un timestamp
+///
+/// Les bougies doivent être triées par timestamp croissant.
+pub fn nearest_candle_index(time: i64, candles: &[Candle]) -> Option<usize> {
+    if candles.is_empty() {
+        return None;
+    }
+    let idx = candles.partition_point(|c| c.timestamp < time);
+    if idx == 0 {
+        return Some(0);
+    }
+    if idx >= candles.len() {
+        return Some(candles.len() - 1);
+    }
+    let before = time - candles[idx - 1].timestamp;
+    let after = candles[idx].timestamp - time;
+    Some(if before <= after { idx - 1 } else { idx })
+}
+
+/// Aimante un point (temps, prix) sur la bougie la plus proche
+///
+/// Le temps est aligné sur le timestamp de la bougie la plus proche ; le prix
+/// est remplacé par la valeur OHLC de cette bougie la plus proche à l'écran,
+/// si elle est à moins de `PRICE_SNAP_DISTANCE` pixels.
+pub fn snap_to_candle(time: i64, price: f64, candles: &[Candle], viewport: &Viewport) -> (i64, f64) {
+    let Some(index) = nearest_candle_index(time, candles) else {
+        return (time, price);
+    };
+    let candle = &candles[index];
+
+    let price_scale = viewport.price_scale();
+    let y = price_scale.price_to_y(price);
+    let nearest_price = [candle.open, candle.high, candle.low, candle.close]
+        .into_iter()
+        .map(|value| (value, (price_scale.price_to_y(value) - y).abs()))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .filter(|(_, distance)| *distance <= PRICE_SNAP_DISTANCE)
+        .map(|(value, _)| value)
+        .unwrap_or(price);
+
+    (candle.timestamp, nearest_price)
+}
+
+/// Aimante les bords d'un rectangle modifiés par une édition
+///
+/// Seuls les bords déplacés par `mode` sont aimantés ; en mode déplacement,
+/// le rectangle entier est décalé pour que son coin de départ tombe sur une bougie.
+pub fn snap_rectangle_edit(
+    rect: &mut DrawnRectangle,
+    mode: EditMode,
+    cursor_time: i64,
+    cursor_price: f64,
+    candles: &[Candle],
+    viewport: &Viewport,
+) {
+    let snap = |time: i64, price: f64| snap_to_candle(time, price, candles, viewport);
+
+    match mode {
+        EditMode::Move => {
+            let (time, price) = snap(rect.start_time, rect.start_price);
+            let delta_time = time - rect.start_time;
+            let delta_price = price - rect.start_price;
+            rect.start_time += delta_time;
+            rect.end_time += delta_time;
+            rect.start_price += delta_price;
+            rect.end_price += delta_price;
+        }
+        EditMode::ResizeTopLeft => {
+            (rect.start_time, rect.start_price) = snap(rect.start_time, rect.start_price);
+        }
+        EditMode::ResizeTopRight => {
+            (rect.end_time, rect.start_price) = snap(rect.end_time, rect.start_price);
+        }
+        EditMode::ResizeBottomLeft => {
+            (rect.start_time, rect.end_price) = snap(rect.start_time, rect.end_price);
+        }
+        EditMode::ResizeBottomRight => {
+            (rect.end_time, rect.end_price) = snap(rect.end_time, rect.end_price);
+        }
+        EditMode::ResizeTop => {
+            rect.start_price = snap(cursor_time, rect.start_price).1;
+        }
+        EditMode::ResizeBottom => {
+            rect.end_price = snap(cursor_time, rect.end_price).1;
+        }
+        EditMode::ResizeLeft => {
+            rect.start_time = snap(rect.start_time, cursor_price).0;
+        }
+        EditMode::ResizeRight => {
+            rect.end_time = snap(rect.end_time, cursor_price).0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candles() -> Vec<Candle> {
+        vec![
+            Candle::new(100, 50.0, 60.0, 40.0, 55.0, 1.0),
+            Candle::new(200, 55.0, 70.0, 50.0, 65.0, 1.0),
+            Candle::new(300, 65.0, 80.0, 60.0, 75.0, 1.0),
+        ]
+    }
+
+    /// Viewport de 1000x100 px couvrant temps 0..1000 et prix 0..100 (1 px par unité)
+    fn viewport() -> Viewport {
+        Viewport::new(1000.0, 100.0)
+    }
+
+    #[test]
+    fn test_nearest_candle_index() {
+        let candles = candles();
+        assert_eq!(nearest_candle_index(0, &candles), Some(0));
+        assert_eq!(nearest_candle_index(140, &candles), Some(0));
+        assert_eq!(nearest_candle_index(160, &candles), Some(1));
+        assert_eq!(nearest_candle_index(999, &candles), Some(2));
+        assert_eq!(nearest_candle_index(10, &[]), None);
+    }
+
+    #[test]
+    fn test_snap_to_candle_picks_nearest_ohlc() {
+        let (time, price) = snap_to_candle(210, 68.0, &candles(), &viewport());
+        assert_eq!(time, 200);
+        assert_eq!(price, 70.0);
+    }
+
+    #[test]
+    fn test_snap_to_candle_keeps_far_price() {
+        // Trop loin de toute valeur OHLC : seul le temps est aimanté
+        let (time, price) = snap_to_candle(290, 10.0, &candles(), &viewport());
+        assert_eq!(time, 300);
+        assert_eq!(price, 10.0);
+    }
+
+    #[test]
+    fn test_snap_rectangle_edit_only_moved_edge() {
+        let mut rect = DrawnRectangle::new(110, 59.0, 280, 20.0);
+        snap_rectangle_edit(&mut rect, EditMode::ResizeTopLeft, 110, 59.0, &candles(), &viewport());
+        assert_eq!((rect.start_time, rect.start_price), (100, 60.0));
+        // Le coin opposé n'est pas modifié
+        assert_eq!((rect.end_time, rect.end_price), (280, 20.0));
+    }
+
+    #[test]
+    fn test_snap_rectangle_move_keeps_size() {
+        let mut rect = DrawnRectangle::new(190, 71.0, 290, 61.0);
+        snap_rectangle_edit(&mut rect, EditMode::Move, 0, 0.0, &candles(), &viewport());
+        assert_eq!((rect.start_time, rect.start_price), (200, 70.0));
+        assert_eq!((rect.end_time - rect.start_time), 100);
+        assert!((rect.start_price - rect.end_price - 10.0).abs() < 1e-9);
+    }
+}
@@ -3,7 +3,8 @@
 //! Architecture Elm : les widgets émettent des messages,
 //! l'application centrale gère les mutations d'état.
 
-use iced::Point;
+use iced::{Color, Point};
+use super::core::SeriesId;
 use super::tools::EditMode;
 
 /// Messages émis par le canvas principal du graphique
@@ -18,46 +19,126 @@ pub enum ChartMessage {
     UpdatePanHorizontal { position: Point },
     /// Terminer le pan
     EndPan,
-    /// Zoom horizontal (molette)
-    ZoomHorizontal { factor: f64 },
+    /// Zoom horizontal (molette) ; `cursor` : position du curseur dans le graphique
+    ZoomHorizontal { factor: f64, cursor: Option<Point> },
     /// Zoom vertical (ALT + molette)
-    ZoomVertical { factor: f64 },
+    ZoomVertical { factor: f64, cursor: Option<Point> },
     /// Zoom les deux axes (CTRL + molette)
-    ZoomBoth { factor: f64 },
+    ZoomBoth { factor: f64, cursor: Option<Point> },
+    /// Ajuster la vue pour afficher toutes les bougies de la série (touche F)
+    FitToData,
+    /// Réinitialiser la vue sur la série active (double-clic)
+    ResetView,
+    /// Démarrer une sélection de zoom (Shift + glisser)
+    StartZoomSelection { screen_x: f32, screen_y: f32, time: i64, price: f64 },
+    /// Zoomer sur la zone sélectionnée : (temps, prix) des deux coins opposés
+    ZoomToSelection { start: (i64, f64), end: (i64, f64) },
+
+    // === Affichage ===
+    /// Activer/désactiver le mode aimant du crosshair (touche M)
+    ToggleCrosshairMagnet,
+    /// Raccourci clavier d'outil de dessin (relayé au panel d'outils)
+    SelectTool { tool: Option<super::tools::Tool> },
+    /// Changer l'intervalle affiché (agrégation de la série active, None = natif)
+    SetDisplayInterval { interval: Option<String> },
+    /// Passer à un autre intervalle de l'actif courant (série téléchargée séparément)
+    SwitchInterval(String),
 
     // === Dessin ===
     /// Démarrer le dessin d'un rectangle
     StartDrawingRectangle { screen_x: f32, screen_y: f32, time: i64, price: f64 },
     /// Mettre à jour l'aperçu du dessin
     UpdateDrawing { screen_x: f32, screen_y: f32 },
-    /// Terminer le dessin d'un rectangle
-    FinishDrawingRectangle { end_time: i64, end_price: f64 },
+    /// Terminer le dessin d'un rectangle (`snap` : aimanter sur les bougies, Ctrl maintenu)
+    FinishDrawingRectangle { end_time: i64, end_price: f64, snap: bool },
     /// Démarrer le dessin d'une ligne horizontale
     StartDrawingHLine { screen_y: f32, price: f64 },
-    /// Terminer le dessin d'une ligne horizontale
-    FinishDrawingHLine,
+    /// Terminer le dessin d'une ligne horizontale (`snap` : prix arrondi, Ctrl maintenu)
+    FinishDrawingHLine { snap: bool },
+    /// Démarrer le dessin d'une ligne verticale
+    StartDrawingVLine { screen_x: f32, time: i64 },
+    /// Terminer le dessin d'une ligne verticale
+    FinishDrawingVLine,
+    /// Démarrer une mesure (règle prix/temps, non persistée)
+    StartMeasure { screen_x: f32, screen_y: f32, time: i64, price: f64 },
+    /// Ouvrir la saisie d'une annotation texte (nouvelle si `index` est None)
+    StartEditingText { position: Point, time: i64, price: f64, index: Option<usize> },
+    /// Mettre à jour le texte en cours de saisie
+    UpdateTextDraft { content: String },
+    /// Valider la saisie de l'annotation texte
+    CommitText { content: String },
+    /// Abandonner la saisie de l'annotation texte
+    CancelText,
+    /// Démarrer le dessin d'une demi-droite
+    StartDrawingRay { screen_x: f32, screen_y: f32, time: i64, price: f64 },
+    /// Terminer le dessin d'une demi-droite
+    FinishDrawingRay { end_time: i64, end_price: f64 },
     /// Annuler le dessin en cours
     CancelDrawing,
 
     // === Édition de rectangles ===
     /// Sélectionner et commencer l'édition d'un rectangle
     StartRectangleEdit { index: usize, mode: EditMode, time: i64, price: f64 },
-    /// Mettre à jour l'édition du rectangle
-    UpdateRectangleEdit { time: i64, price: f64 },
+    /// Mettre à jour l'édition du rectangle (`snap` : aimanter sur les bougies, Ctrl maintenu)
+    UpdateRectangleEdit { time: i64, price: f64, snap: bool },
     /// Terminer l'édition du rectangle
     FinishRectangleEdit,
     /// Désélectionner le rectangle
     DeselectRectangle,
+    /// Changer la couleur de fond d'un rectangle (l'opacité est conservée)
+    SetRectangleColor { index: usize, color: Color },
+    /// Changer l'opacité du fond d'un rectangle
+    SetRectangleOpacity { index: usize, opacity: f32 },
+    /// Ouvrir la saisie du texte d'un rectangle
+    StartEditingRectangleLabel { index: usize },
+    /// Mettre à jour le texte en cours de saisie
+    UpdateRectangleLabelDraft { content: String },
+    /// Valider le texte du rectangle (vide = pas de texte)
+    CommitRectangleLabel,
+    /// Abandonner la saisie du texte
+    CancelRectangleLabelEdit,
 
     // === Édition de lignes horizontales ===
     /// Sélectionner et commencer l'édition d'une ligne
     StartHLineEdit { index: usize, price: f64 },
-    /// Mettre à jour l'édition de la ligne
-    UpdateHLineEdit { price: f64 },
+    /// Mettre à jour l'édition de la ligne (`snap` : prix arrondi, Ctrl maintenu)
+    UpdateHLineEdit { price: f64, snap: bool },
     /// Terminer l'édition de la ligne
     FinishHLineEdit,
     /// Désélectionner la ligne
     DeselectHLine,
+    /// Ouvrir la saisie du prix d'une ligne (clic sur son étiquette)
+    StartEditingHLinePrice { index: usize, position: Point },
+    /// Mettre à jour le prix en cours de saisie
+    UpdateHLinePriceDraft { content: String },
+    /// Fixer le prix exact d'une ligne
+    SetHLinePrice { index: usize, price: f64 },
+    /// Abandonner la saisie du prix
+    CancelHLinePriceEdit,
+    /// Changer la couleur d'une ligne
+    SetHLineColor { index: usize, color: Color },
+
+    // === Édition de lignes verticales ===
+    /// Sélectionner et commencer l'édition d'une ligne verticale
+    StartVLineEdit { index: usize, time: i64 },
+    /// Mettre à jour l'édition de la ligne verticale
+    UpdateVLineEdit { time: i64 },
+    /// Terminer l'édition de la ligne verticale
+    FinishVLineEdit,
+    /// Désélectionner la ligne verticale
+    DeselectVLine,
+
+    // === Sélection d'annotations texte ===
+    /// Sélectionner une annotation texte
+    SelectText { index: usize },
+    /// Désélectionner l'annotation texte
+    DeselectText,
+
+    // === Sélection de lignes de tendance ===
+    /// Sélectionner une ligne de tendance
+    SelectTrendLine { index: usize },
+    /// Désélectionner la ligne de tendance
+    DeselectTrendLine,
 
     // === Suppression ===
     /// Supprimer l'élément sélectionné
@@ -78,7 +159,28 @@ pub enum ChartMessage {
     // === Position souris ===
     /// Mise à jour de la position de la souris
     MouseMoved { position: Point },
+    /// Survol d'un panneau d'indicateur (synchronise le crosshair des autres panneaux)
+    IndicatorHovered { timestamp: i64 },
     
+    // === Clic sur une bougie ===
+    /// Clic gauche sans déplacement sur une bougie, sans outil actif
+    ///
+    /// L'appui a démarré un pan : ce message le termine, à traiter comme `EndPan`
+    /// en plus de la réaction propre à l'application.
+    #[allow(dead_code)] // Détail de la bougie pour les applications qui embarquent le graphique
+    CandleClicked {
+        series_id: SeriesId,
+        timestamp: i64,
+        open: f64,
+        high: f64,
+        low: f64,
+        close: f64,
+    },
+    /// Sélectionne la bougie au timestamp donné (surlignée, détail dans le bandeau)
+    SelectCandle { timestamp: i64 },
+    /// Retire la sélection de bougie
+    DeselectCandle,
+
     // === Clic droit ===
     /// Clic droit sur le graphique (pour menu contextuel)
     RightClick { position: Point },
@@ -87,6 +189,10 @@ pub enum ChartMessage {
     /// Mise à jour de la taille du viewport (et des bounds pour convertir positions absolues en relatives)
     Resize { width: f32, height: f32, x: f32, y: f32 },
     
+    // === Renko ===
+    /// Définir la taille des briques Renko (valeur <= 0 = taille automatique)
+    SetRenkoBrickSize(f64),
+    
     // === Backtest ===
     /// Sélectionner une date de départ pour le backtest (clic sur le graphique)
     ///
@@ -101,15 +207,25 @@ pub enum ChartMessage {
 /// Messages émis par l'axe Y
 #[derive(Debug, Clone)]
 pub enum YAxisMessage {
-    /// Zoom vertical par drag
-    ZoomVertical { factor: f64 },
+    /// Étirer/compresser la plage de prix par drag vertical (delta en pixels)
+    DragScale { delta: f32 },
+    /// Basculer entre échelle linéaire et logarithmique (clic droit)
+    ToggleLogScale,
+    /// Inverser le sens de l'échelle de prix (clic sur l'indicateur en bas de l'axe)
+    ToggleInverted,
+    /// Verrouiller/déverrouiller l'ajustement automatique des prix (clic sur le cadenas)
+    ToggleAutoScale,
+    /// Créer une alerte de prix (drag depuis l'axe relâché sur le graphique)
+    CreateAlert { price: f64 },
+    /// Supprimer une alerte de prix (clic sur son étiquette)
+    RemoveAlert { id: u64 },
 }
 
 /// Messages émis par l'axe X
 #[derive(Debug, Clone)]
 pub enum XAxisMessage {
-    /// Zoom horizontal par drag
-    ZoomHorizontal { factor: f64 },
+    /// Étirer/compresser la plage temporelle par drag horizontal (delta en pixels)
+    DragScale { delta: f32 },
 }
 
 /// Messages émis par le panel d'outils
@@ -119,6 +235,8 @@ pub enum ToolsPanelMessage {
     ToggleTool { tool: super::tools::Tool },
     /// Ouvrir/fermer l'onglet d'indicateurs
     ToggleIndicatorsPanel,
+    /// Sélectionner un outil (None = aucun), sans bascule : raccourcis clavier
+    SelectTool { tool: Option<super::tools::Tool> },
 }
 
 /// Messages émis par le panel de séries
@@ -126,5 +244,11 @@ pub enum ToolsPanelMessage {
 pub enum SeriesPanelMessage {
     /// Sélectionner une série par son nom (depuis le select box)
     SelectSeriesByName { series_name: String },
+    /// Afficher/masquer une série en superposition (sans changer la série principale)
+    ToggleSeriesVisibility { series_id: super::core::SeriesId },
+    /// Suspendre/reprendre les mises à jour temps réel d'une série
+    ToggleRealtime { series_id: super::core::SeriesId },
+    /// Tracer une série superposée sur l'axe de prix gauche ou droit
+    SetSeriesAxis { series_id: super::core::SeriesId, axis: super::core::PriceAxis },
 }
 
@@ -12,6 +12,7 @@
 //! - Providers : providers de données (Binance, etc.)
 //! - Realtime : mise à jour en temps réel
 //! - Indicators : indicateurs techniques (RSI, MACD, Volume, EMA)
+//! - Alerts : alertes de prix
 
 pub mod core;
 pub mod scale;
@@ -24,6 +25,8 @@ pub mod data_loader;
 pub mod series_select;
 pub mod settings;
 pub mod messages;
+pub mod export;
+pub mod alerts;
 
 // Modules réorganisés en dossiers
 pub mod axis;
@@ -33,21 +36,22 @@ pub mod realtime;
 pub mod indicators;
 
 // Ré-exports principaux
-pub use state::ChartState;
-pub use widget::{chart, chart_with_trading, chart_with_trades_and_trading};
+#[allow(unused_imports)] // ChartStateBuilder : pour les applications qui embarquent le graphique
+pub use state::{ChartState, ChartStateBuilder};
+pub use widget::{chart, chart_with_trading, chart_with_trades_and_trading, ChartOptions};
 pub use data_loader::{load_from_json, load_all_from_directory, is_directory_empty, save_to_json};
-pub use series_select::series_select_box;
+pub use series_select::{series_select_box, display_interval_select, interval_toolbar};
 pub use settings::{ChartStyle, SettingsState};
 pub use messages::{ChartMessage, YAxisMessage, XAxisMessage, ToolsPanelMessage, SeriesPanelMessage};
 
 // Ré-exports depuis axis/
-pub use axis::{x_axis, y_axis, X_AXIS_HEIGHT};
+pub use axis::{x_axis, y_axis, y_axis_left, X_AXIS_HEIGHT};
 
 // Ré-exports depuis tools/
 pub use tools::{tools_panel, ToolsState, TOOLS_PANEL_WIDTH};
 
 // Ré-exports depuis providers/
-pub use providers::{BinanceProvider, ProviderConfigManager, ProviderType};
+pub use providers::{BinanceProvider, BinanceWsProvider, SharedDataProvider, create_data_provider, WsConnectionState, WsEvent, ProviderConfigManager, ProviderType};
 
 // Ré-exports depuis realtime/
 pub use realtime::UpdateResult;
@@ -59,3 +63,11 @@ pub use indicators::macd::macd_chart;
 pub use indicators::macd::macd_y_axis;
 pub use indicators::volume::volume_chart;
 pub use indicators::volume::volume_y_axis;
+pub use indicators::atr::atr_chart;
+pub use indicators::atr::atr_y_axis;
+pub use indicators::obv::obv_chart;
+pub use indicators::obv::obv_y_axis;
+pub use indicators::cci::cci_chart;
+pub use indicators::cci::cci_y_axis;
+pub use indicators::williams_r::williams_r_chart;
+pub use indicators::williams_r::williams_r_y_axis;
@@ -4,6 +4,9 @@
 
 use crate::finance_chart::core::{Candle, SeriesId};
 use crate::finance_chart::realtime::{RealtimeDataProvider, ProviderError};
+use super::rate_limit::RateLimiter;
+use futures::future::BoxFuture;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use serde::{Deserialize, Serialize};
 use hmac::{Hmac, Mac};
@@ -57,6 +60,15 @@ const BINANCE_API_BASE: &str = "https://api.binance.com/api/v3";
 /// Timeout par défaut pour les requêtes HTTP (en secondes)
 const DEFAULT_TIMEOUT_SECS: u64 = 10;
 
+/// Poids de requête autorisé par minute (limite Binance par IP, avec marge)
+const REQUEST_WEIGHT_PER_MINUTE: f64 = 1200.0;
+
+/// Poids d'une requête klines
+const KLINES_WEIGHT: f64 = 2.0;
+
+/// Attente par défaut après une réponse 429 sans en-tête `Retry-After` (en secondes)
+const DEFAULT_RETRY_AFTER_SECS: u64 = 1;
+
 /// Provider Binance pour récupérer les données depuis l'API Binance
 #[derive(Clone)]
 pub struct BinanceProvider {
@@ -69,6 +81,8 @@ pub struct BinanceProvider {
     pub(crate) api_token: Option<String>,
     /// Clé secrète API pour la signature HMAC
     pub(crate) api_secret: Option<String>,
+    /// Limiteur de débit partagé par les clones du provider
+    pub(crate) rate_limiter: Arc<RateLimiter>,
 }
 
 impl BinanceProvider {
@@ -121,8 +135,17 @@ impl BinanceProvider {
             base_url: BINANCE_API_BASE.to_string(),
             api_token,
             api_secret,
+            rate_limiter: Arc::new(RateLimiter::new(
+                REQUEST_WEIGHT_PER_MINUTE,
+                REQUEST_WEIGHT_PER_MINUTE / 60.0,
+            )),
         }
     }
+
+    /// Indique si les requêtes sont actuellement ralenties (limite de débit atteinte)
+    pub fn is_rate_limited(&self) -> bool {
+        self.rate_limiter.is_throttled()
+    }
     
     /// Génère une signature HMAC SHA256 pour une requête Binance
     pub fn generate_signature(&self, query_string: &str) -> Result<String, ProviderError> {
@@ -359,6 +382,10 @@ impl BinanceProvider {
     }
 
     /// Récupère les klines depuis l'API Binance
+    ///
+    /// Chaque requête passe par le limiteur de débit. Une réponse 429 suspend les
+    /// requêtes pendant la durée `Retry-After`, puis la requête est retentée avec
+    /// un backoff exponentiel (`retry_with_backoff`).
     async fn fetch_klines(
         &self,
         symbol: &str,
@@ -385,15 +412,57 @@ impl BinanceProvider {
             url.push_str(&params.join("&"));
         }
 
+        use crate::app::error_handling::{retry_with_backoff, RetryConfig, RetryResult};
+
+        // Conserver l'erreur d'origine : retry_with_backoff la convertit en AppError
+        let last_error: Mutex<Option<ProviderError>> = Mutex::new(None);
+        let (url, last_error_ref) = (url.as_str(), &last_error);
+        let result = retry_with_backoff(
+            || async move {
+                let result = self.fetch_klines_once(url).await;
+                if let (Err(e), Ok(mut last)) = (&result, last_error_ref.lock()) {
+                    *last = Some(e.clone());
+                }
+                result
+            },
+            RetryConfig::for_api_calls(),
+            &format!("klines {} {}", symbol, interval),
+        )
+        .await;
+
+        match result {
+            RetryResult::Success { value, .. } => Ok(value),
+            RetryResult::Failed { error, .. } => Err(last_error
+                .into_inner()
+                .ok()
+                .flatten()
+                .unwrap_or(ProviderError::Unknown(error.technical_message))),
+        }
+    }
+
+    /// Effectue une requête klines unique (sans retry)
+    async fn fetch_klines_once(&self, url: &str) -> Result<Vec<Candle>, ProviderError> {
+        self.rate_limiter.acquire(KLINES_WEIGHT).await;
+
         let response = self
             .client
-            .get(&url)
+            .get(url)
             .send()
             .await
             .map_err(ProviderError::from)?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
+            if status == 429 || status == 418 {
+                let retry_after = response
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.trim().parse::<u64>().ok())
+                    .unwrap_or(DEFAULT_RETRY_AFTER_SECS);
+                eprintln!("⏳ Limite de requêtes Binance atteinte, pause de {} s", retry_after);
+                self.rate_limiter.block_for(Duration::from_secs(retry_after));
+            }
             let error_text = response
                 .text()
                 .await
@@ -825,6 +894,64 @@ impl RealtimeDataProvider for BinanceProvider {
         })
         .map_err(|e| e.to_string())
     }
+
+    fn get_latest_candle_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+    ) -> BoxFuture<'a, Result<Option<Candle>, ProviderError>> {
+        Box::pin(BinanceProvider::get_latest_candle_async(self, series_id))
+    }
+
+    fn fetch_new_candles_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+        since_timestamp: i64,
+    ) -> BoxFuture<'a, Result<Vec<Candle>, ProviderError>> {
+        Box::pin(BinanceProvider::fetch_new_candles_async(self, series_id, since_timestamp))
+    }
+
+    fn fetch_all_candles_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+    ) -> BoxFuture<'a, Result<Vec<Candle>, ProviderError>> {
+        Box::pin(BinanceProvider::fetch_all_candles_async(self, series_id))
+    }
+
+    fn fetch_full_history_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+    ) -> BoxFuture<'a, Result<Vec<Candle>, ProviderError>> {
+        Box::pin(BinanceProvider::fetch_full_history_async(self, series_id))
+    }
+
+    fn fetch_all_candles_in_range_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+        start_timestamp: i64,
+        end_timestamp: i64,
+    ) -> BoxFuture<'a, Result<Vec<Candle>, ProviderError>> {
+        Box::pin(BinanceProvider::fetch_all_candles_in_range_async(self, series_id, start_timestamp, end_timestamp))
+    }
+
+    fn fetch_candles_backwards_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+        start_timestamp: i64,
+        end_timestamp: i64,
+    ) -> BoxFuture<'a, Result<Vec<Candle>, ProviderError>> {
+        Box::pin(BinanceProvider::fetch_candles_backwards_async(self, series_id, start_timestamp, end_timestamp))
+    }
+
+    fn check_oldest_available_timestamp_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+    ) -> BoxFuture<'a, Result<Option<i64>, ProviderError>> {
+        Box::pin(BinanceProvider::check_oldest_available_timestamp_async(self, series_id))
+    }
+
+    fn is_rate_limited(&self) -> bool {
+        BinanceProvider::is_rate_limited(self)
+    }
 }
 
 #[cfg(test)]
@@ -878,5 +1005,48 @@ mod tests {
         assert_eq!(candle.low, 49900.0);
         assert_eq!(candle.close, 50050.0);
     }
-}
 
+    /// Sert les réponses HTTP données, une par connexion, et compte les requêtes reçues
+    fn serve_responses(responses: Vec<String>) -> (String, Arc<std::sync::atomic::AtomicUsize>) {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let requests = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        std::thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut buffer = [0u8; 4096];
+                let _ = stream.read(&mut buffer);
+                counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        (format!("http://{}", address), requests)
+    }
+
+    #[test]
+    fn test_fetch_klines_retries_after_429() {
+        let body = r#"[[1609459200000,"50000.0","50100.0","49900.0","50050.0","100.5",1609462800000,"0",0,"0","0","0"]]"#;
+        let (base_url, requests) = serve_responses(vec![
+            "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 0\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string(),
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            ),
+        ]);
+
+        let mut provider = BinanceProvider::new();
+        provider.base_url = base_url;
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let candles = runtime
+            .block_on(provider.fetch_klines("BTCUSDT", "1h", None, None, Some(1)))
+            .unwrap();
+
+        assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert_eq!(candles.len(), 1);
+        assert_eq!(candles[0].close, 50050.0);
+    }
+}
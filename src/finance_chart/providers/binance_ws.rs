@@ -0,0 +1,377 @@
+//! Provider Binance temps réel via le flux WebSocket des klines
+//!
+//! Contrairement à `BinanceProvider` (polling REST), ce provider s'abonne au
+//! flux combiné `<symbol>@kline_<interval>` et pousse chaque mise à jour de
+//! bougie (en cours ou clôturée) dans l'application via une `Subscription` Iced.
+//! La connexion est rétablie automatiquement avec un backoff exponentiel.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures::{SinkExt, StreamExt};
+use iced::Subscription;
+use serde::Deserialize;
+
+use crate::finance_chart::core::{Candle, SeriesId};
+use crate::finance_chart::realtime::{ProviderError, RealtimeDataProvider};
+
+/// URL de base du flux WebSocket combiné de Binance
+const BINANCE_WS_BASE: &str = "wss://stream.binance.com:9443/stream?streams=";
+
+/// Délai initial avant reconnexion
+const INITIAL_BACKOFF_SECS: u64 = 1;
+
+/// Délai maximum entre deux tentatives de reconnexion
+const MAX_BACKOFF_SECS: u64 = 60;
+
+/// État de la connexion WebSocket
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WsConnectionState {
+    /// Première connexion en cours
+    Connecting,
+    /// Socket ouvert et flux reçu
+    Connected,
+    /// Connexion perdue, nouvelle tentative prévue
+    Reconnecting { attempt: u32 },
+}
+
+impl WsConnectionState {
+    /// Indique si le socket est actuellement ouvert
+    pub fn is_live(&self) -> bool {
+        matches!(self, WsConnectionState::Connected)
+    }
+}
+
+/// Événement émis par la subscription WebSocket
+#[derive(Debug, Clone)]
+pub enum WsEvent {
+    /// Changement d'état de la connexion
+    Status(WsConnectionState),
+    /// Mise à jour d'une bougie (en cours ou clôturée)
+    Kline {
+        series_id: SeriesId,
+        candle: Candle,
+    },
+}
+
+/// Action à effectuer pour une bougie reçue par le WebSocket
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KlineAction {
+    /// Bougie déjà connue et identique (ou plus ancienne) : rien à faire
+    Skip,
+    /// Mettre à jour / ajouter la bougie dans la série
+    Apply,
+}
+
+/// Compare une bougie reçue par le WebSocket à la dernière bougie connue
+///
+/// Évite de ré-appliquer la bougie en cours quand elle est identique à celle
+/// déjà récupérée par REST, et ignore les messages en retard.
+pub fn dedup_kline(candle: &Candle, last: Option<&Candle>) -> KlineAction {
+    match last {
+        Some(last) if candle.timestamp < last.timestamp => KlineAction::Skip,
+        Some(last) if candle == last => KlineAction::Skip,
+        _ => KlineAction::Apply,
+    }
+}
+
+/// Délai avant la tentative de reconnexion `attempt` (1s, 2s, 4s… plafonné à 60s)
+pub fn backoff_delay(attempt: u32) -> Duration {
+    let secs = INITIAL_BACKOFF_SECS
+        .saturating_mul(1u64 << attempt.min(16))
+        .min(MAX_BACKOFF_SECS);
+    Duration::from_secs(secs)
+}
+
+/// Flux kline souscrit : série de l'application et couple symbole/intervalle Binance
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct KlineStream {
+    series_id: SeriesId,
+    symbol: String,
+    interval: String,
+}
+
+/// Message du flux combiné Binance (`{"stream": ..., "data": {...}}`)
+#[derive(Debug, Deserialize)]
+struct WsCombinedMessage {
+    data: WsKlineEvent,
+}
+
+/// Événement kline Binance
+#[derive(Debug, Deserialize)]
+struct WsKlineEvent {
+    #[serde(rename = "k")]
+    kline: WsKline,
+}
+
+/// Contenu d'une kline WebSocket (prix transmis sous forme de chaînes)
+#[derive(Debug, Deserialize)]
+struct WsKline {
+    #[serde(rename = "t")]
+    open_time: i64,
+    #[serde(rename = "s")]
+    symbol: String,
+    #[serde(rename = "i")]
+    interval: String,
+    #[serde(rename = "o")]
+    open: String,
+    #[serde(rename = "h")]
+    high: String,
+    #[serde(rename = "l")]
+    low: String,
+    #[serde(rename = "c")]
+    close: String,
+    #[serde(rename = "v")]
+    volume: String,
+}
+
+/// Parse un message texte du flux combiné en (symbole, intervalle, bougie)
+fn parse_kline_message(text: &str) -> Result<(String, String, Candle), ProviderError> {
+    let message: WsCombinedMessage = serde_json::from_str(text)?;
+    let k = message.data.kline;
+
+    let parse_price = |value: &str, field: &str| -> Result<f64, ProviderError> {
+        value
+            .parse::<f64>()
+            .map_err(|e| ProviderError::Parse(format!("Erreur parsing {}: {}", field, e)))
+    };
+
+    let candle = Candle::new(
+        k.open_time / 1000,
+        parse_price(&k.open, "open")?,
+        parse_price(&k.high, "high")?,
+        parse_price(&k.low, "low")?,
+        parse_price(&k.close, "close")?,
+        parse_price(&k.volume, "volume")?,
+    );
+
+    Ok((k.symbol.to_uppercase(), k.interval, candle))
+}
+
+/// Provider temps réel Binance basé sur le WebSocket
+///
+/// Conserve la dernière bougie reçue pour chaque série afin de pouvoir
+/// répondre au trait `RealtimeDataProvider` sans requête réseau.
+#[derive(Debug, Clone, Default)]
+pub struct BinanceWsProvider {
+    streams: Vec<KlineStream>,
+    latest: Arc<Mutex<HashMap<SeriesId, Candle>>>,
+}
+
+impl BinanceWsProvider {
+    /// Crée un provider abonné aux séries données (format `SYMBOL_INTERVAL`)
+    ///
+    /// Les séries dont l'identifiant n'est pas au format Binance sont ignorées.
+    pub fn new(series_ids: impl IntoIterator<Item = SeriesId>) -> Self {
+        let mut streams: Vec<KlineStream> = series_ids
+            .into_iter()
+            .filter_map(|series_id| {
+                let (symbol, interval) = series_id.name.split_once('_')?;
+                if symbol.is_empty() || interval.is_empty() {
+                    return None;
+                }
+                Some(KlineStream {
+                    symbol: symbol.to_uppercase(),
+                    interval: interval.to_string(),
+                    series_id,
+                })
+            })
+            .collect();
+        streams.sort_by(|a, b| a.series_id.name.cmp(&b.series_id.name));
+        streams.dedup();
+
+        Self {
+            streams,
+            latest: Arc::default(),
+        }
+    }
+
+    /// Crée un provider abonné à d'autres séries, partageant le cache des dernières bougies
+    pub fn subscribed_to(&self, series_ids: impl IntoIterator<Item = SeriesId>) -> Self {
+        Self {
+            latest: Arc::clone(&self.latest),
+            ..Self::new(series_ids)
+        }
+    }
+
+    /// Indique si au moins une série est souscrite
+    pub fn has_streams(&self) -> bool {
+        !self.streams.is_empty()
+    }
+
+    /// Construit l'URL du flux combiné pour toutes les séries souscrites
+    pub fn stream_url(&self) -> String {
+        stream_url(&self.streams)
+    }
+
+    /// Enregistre la dernière bougie reçue pour une série
+    pub fn record(&self, series_id: &SeriesId, candle: Candle) {
+        if let Ok(mut latest) = self.latest.lock() {
+            latest.insert(series_id.clone(), candle);
+        }
+    }
+
+    /// Subscription Iced émettant les événements du WebSocket
+    ///
+    /// La subscription est identifiée par la liste des flux : elle n'est
+    /// recréée (reconnexion) que si les séries souscrites changent.
+    pub fn subscription(&self) -> Subscription<WsEvent> {
+        Subscription::run_with(self.streams.clone(), kline_stream)
+    }
+}
+
+impl RealtimeDataProvider for BinanceWsProvider {
+    fn fetch_latest_candle(&self, series_id: &SeriesId) -> Result<Option<Candle>, String> {
+        let latest = self.latest.lock().map_err(|e| e.to_string())?;
+        Ok(latest.get(series_id).copied())
+    }
+
+    fn fetch_new_candles(&self, series_id: &SeriesId, since_timestamp: i64) -> Result<Vec<Candle>, String> {
+        // Le WebSocket ne garde pas d'historique : seule la dernière bougie est disponible
+        Ok(self
+            .fetch_latest_candle(series_id)?
+            .filter(|candle| candle.timestamp >= since_timestamp)
+            .into_iter()
+            .collect())
+    }
+}
+
+/// Construit l'URL du flux combiné (`btcusdt@kline_1m/ethusdt@kline_1h`)
+fn stream_url(streams: &[KlineStream]) -> String {
+    let names: Vec<String> = streams
+        .iter()
+        .map(|s| format!("{}@kline_{}", s.symbol.to_lowercase(), s.interval))
+        .collect();
+    format!("{}{}", BINANCE_WS_BASE, names.join("/"))
+}
+
+/// Boucle de connexion WebSocket avec reconnexion (backoff exponentiel)
+// `Subscription::run_with` impose la signature `fn(&D)`
+#[allow(clippy::ptr_arg)]
+fn kline_stream(streams: &Vec<KlineStream>) -> impl futures::Stream<Item = WsEvent> {
+    let streams = streams.clone();
+
+    iced::stream::channel(100, move |mut output: futures::channel::mpsc::Sender<WsEvent>| async move {
+        let url = stream_url(&streams);
+        let lookup: HashMap<(String, String), SeriesId> = streams
+            .iter()
+            .map(|s| ((s.symbol.clone(), s.interval.clone()), s.series_id.clone()))
+            .collect();
+        let mut attempt: u32 = 0;
+
+        let _ = output.send(WsEvent::Status(WsConnectionState::Connecting)).await;
+
+        loop {
+            match tokio_tungstenite::connect_async(url.as_str()).await {
+                Ok((mut socket, _)) => {
+                    println!("🔌 WebSocket Binance connecté ({} flux)", streams.len());
+                    attempt = 0;
+                    let _ = output.send(WsEvent::Status(WsConnectionState::Connected)).await;
+
+                    while let Some(message) = socket.next().await {
+                        let text = match message {
+                            Ok(tokio_tungstenite::tungstenite::Message::Text(text)) => text,
+                            Ok(tokio_tungstenite::tungstenite::Message::Close(_)) => break,
+                            Ok(_) => continue,
+                            Err(e) => {
+                                eprintln!("⚠️ WebSocket Binance: {}", e);
+                                break;
+                            }
+                        };
+
+                        match parse_kline_message(&text) {
+                            Ok((symbol, interval, candle)) => {
+                                if let Some(series_id) = lookup.get(&(symbol, interval)) {
+                                    let event = WsEvent::Kline {
+                                        series_id: series_id.clone(),
+                                        candle,
+                                    };
+                                    if output.send(event).await.is_err() {
+                                        // Subscription abandonnée par Iced
+                                        return;
+                                    }
+                                }
+                            }
+                            Err(e) => eprintln!("⚠️ Message WebSocket ignoré: {}", e),
+                        }
+                    }
+                }
+                Err(e) => {
+                    eprintln!("❌ Connexion WebSocket Binance impossible: {}", e);
+                }
+            }
+
+            attempt = attempt.saturating_add(1);
+            let _ = output.send(WsEvent::Status(WsConnectionState::Reconnecting { attempt })).await;
+            tokio::time::sleep(backoff_delay(attempt - 1)).await;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_kline_message() {
+        let text = r#"{"stream":"btcusdt@kline_1m","data":{"e":"kline","E":1609459260000,"s":"BTCUSDT",
+            "k":{"t":1609459200000,"T":1609459259999,"s":"BTCUSDT","i":"1m","o":"50000.0","c":"50050.0",
+            "h":"50100.0","l":"49900.0","v":"100.5","x":false}}}"#;
+
+        let (symbol, interval, candle) = parse_kline_message(text).unwrap();
+        assert_eq!(symbol, "BTCUSDT");
+        assert_eq!(interval, "1m");
+        assert_eq!(candle.timestamp, 1609459200);
+        assert_eq!(candle.high, 50100.0);
+        assert_eq!(candle.close, 50050.0);
+    }
+
+    #[test]
+    fn test_parse_kline_message_invalid() {
+        assert!(parse_kline_message("{\"result\":null,\"id\":1}").is_err());
+    }
+
+    #[test]
+    fn test_stream_url() {
+        let provider = BinanceWsProvider::new([
+            SeriesId::new("ETHUSDT_1h"),
+            SeriesId::new("BTCUSDT_1m"),
+            SeriesId::new("invalid"),
+        ]);
+        assert_eq!(
+            provider.stream_url(),
+            "wss://stream.binance.com:9443/stream?streams=btcusdt@kline_1m/ethusdt@kline_1h"
+        );
+    }
+
+    #[test]
+    fn test_backoff_delay() {
+        assert_eq!(backoff_delay(0), Duration::from_secs(1));
+        assert_eq!(backoff_delay(3), Duration::from_secs(8));
+        assert_eq!(backoff_delay(10), Duration::from_secs(MAX_BACKOFF_SECS));
+        assert_eq!(backoff_delay(u32::MAX), Duration::from_secs(MAX_BACKOFF_SECS));
+    }
+
+    #[test]
+    fn test_dedup_kline() {
+        let last = Candle::new(120, 1.0, 2.0, 0.5, 1.5, 10.0);
+        assert_eq!(dedup_kline(&last, Some(&last)), KlineAction::Skip);
+        assert_eq!(dedup_kline(&Candle::new(60, 1.0, 2.0, 0.5, 1.5, 10.0), Some(&last)), KlineAction::Skip);
+        assert_eq!(dedup_kline(&Candle::new(120, 1.0, 2.5, 0.5, 2.4, 12.0), Some(&last)), KlineAction::Apply);
+        assert_eq!(dedup_kline(&Candle::new(180, 1.5, 1.6, 1.4, 1.5, 1.0), Some(&last)), KlineAction::Apply);
+        assert_eq!(dedup_kline(&last, None), KlineAction::Apply);
+    }
+
+    #[test]
+    fn test_fetch_latest_from_cache() {
+        let provider = BinanceWsProvider::new([SeriesId::new("BTCUSDT_1m")]);
+        let series_id = SeriesId::new("BTCUSDT_1m");
+        assert_eq!(provider.fetch_latest_candle(&series_id).unwrap(), None);
+
+        let candle = Candle::new(60, 1.0, 2.0, 0.5, 1.5, 10.0);
+        provider.record(&series_id, candle);
+        assert_eq!(provider.fetch_latest_candle(&series_id).unwrap(), Some(candle));
+        assert!(provider.fetch_new_candles(&series_id, 120).unwrap().is_empty());
+    }
+}
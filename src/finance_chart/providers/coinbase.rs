@@ -0,0 +1,412 @@
+//! Provider Coinbase Exchange pour récupérer les bougies via l'API REST publique
+//!
+//! L'endpoint `/products/{id}/candles` retourne au maximum 300 bougies par
+//! requête, au format `[time, low, high, open, close, volume]` et triées du
+//! plus récent au plus ancien.
+
+use crate::finance_chart::core::{Candle, SeriesId};
+use crate::finance_chart::realtime::{RealtimeDataProvider, ProviderError};
+use super::config::ProviderType;
+use futures::future::BoxFuture;
+use std::time::Duration;
+
+/// URL de base de l'API Coinbase Exchange
+const COINBASE_API_BASE: &str = "https://api.exchange.coinbase.com";
+
+/// Timeout par défaut pour les requêtes HTTP (en secondes)
+const DEFAULT_TIMEOUT_SECS: u64 = 10;
+
+/// Nombre maximum de bougies retournées par requête
+const MAX_CANDLES_PER_REQUEST: i64 = 300;
+
+/// Provider Coinbase pour récupérer les données depuis l'API Coinbase Exchange
+#[derive(Clone)]
+pub struct CoinbaseProvider {
+    /// Client HTTP pour les requêtes
+    client: reqwest::Client,
+    /// URL de base de l'API
+    base_url: String,
+}
+
+/// Convertit un intervalle (ex: "1h") en granularité Coinbase (en secondes)
+///
+/// Coinbase ne supporte que 1m, 5m, 15m, 1h, 6h et 1d.
+fn interval_to_granularity(interval: &str) -> Result<i64, ProviderError> {
+    match interval {
+        "1m" => Ok(60),
+        "5m" => Ok(300),
+        "15m" => Ok(900),
+        "1h" => Ok(3600),
+        "6h" => Ok(21_600),
+        "1d" => Ok(86_400),
+        _ => Err(ProviderError::Validation(format!(
+            "Intervalle {} non supporté par Coinbase (1m, 5m, 15m, 1h, 6h, 1d)",
+            interval
+        ))),
+    }
+}
+
+/// Convertit une bougie Coinbase `[time, low, high, open, close, volume]` en Candle
+fn parse_candle_array(arr: &[serde_json::Value]) -> Result<Candle, ProviderError> {
+    if arr.len() < 6 {
+        return Err(ProviderError::Parse(format!(
+            "Tableau de bougie incomplet: {} éléments (attendu: 6)",
+            arr.len()
+        )));
+    }
+
+    let number = |idx: usize, field: &str| -> Result<f64, ProviderError> {
+        arr[idx]
+            .as_f64()
+            .ok_or_else(|| ProviderError::Parse(format!("{} invalide", field)))
+    };
+
+    let time = arr[0]
+        .as_i64()
+        .ok_or_else(|| ProviderError::Parse("time invalide".to_string()))?;
+
+    Ok(Candle::new(
+        time,
+        number(3, "open")?,
+        number(2, "high")?,
+        number(1, "low")?,
+        number(4, "close")?,
+        number(5, "volume")?,
+    ))
+}
+
+/// Formate un timestamp Unix en ISO 8601 pour les paramètres `start`/`end`
+fn to_iso8601(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|dt| dt.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+        .unwrap_or_default()
+}
+
+impl CoinbaseProvider {
+    /// Crée un nouveau provider Coinbase avec les paramètres par défaut
+    pub fn new() -> Self {
+        Self::with_timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
+    }
+
+    /// Crée un nouveau provider avec un timeout personnalisé
+    pub fn with_timeout(timeout: Duration) -> Self {
+        // Coinbase rejette les requêtes sans User-Agent
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .user_agent(concat!("CandleChart/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .unwrap_or_else(|e| {
+                eprintln!("⚠️ Erreur création client HTTP: {}. Utilisation d'un client basique.", e);
+                reqwest::Client::new()
+            });
+
+        Self {
+            client,
+            base_url: COINBASE_API_BASE.to_string(),
+        }
+    }
+
+    /// Extrait l'identifiant de produit Coinbase et la granularité depuis un SeriesId
+    fn parse_series_id(&self, series_id: &SeriesId) -> Result<(String, i64), ProviderError> {
+        let (symbol, interval) = series_id.name.split_once('_').ok_or_else(|| {
+            ProviderError::InvalidSeriesId(format!(
+                "Format de SeriesId invalide: {}. Attendu: SYMBOL_INTERVAL (ex: BTCUSDT_1h)",
+                series_id.name
+            ))
+        })?;
+
+        let product_id = ProviderType::Coinbase.normalize_symbol(symbol);
+        let granularity = interval_to_granularity(interval)?;
+        Ok((product_id, granularity))
+    }
+
+    /// Récupère au plus 300 bougies depuis l'API, triées par timestamp croissant
+    async fn fetch_candles(
+        &self,
+        product_id: &str,
+        granularity: i64,
+        start: Option<i64>,
+        end: Option<i64>,
+    ) -> Result<Vec<Candle>, ProviderError> {
+        let mut url = format!(
+            "{}/products/{}/candles?granularity={}",
+            self.base_url, product_id, granularity
+        );
+        if let (Some(start), Some(end)) = (start, end) {
+            url.push_str(&format!("&start={}&end={}", to_iso8601(start), to_iso8601(end)));
+        }
+
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(ProviderError::from)?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Erreur inconnue".to_string());
+            return Err(ProviderError::Api {
+                status: Some(status),
+                message: error_text,
+            });
+        }
+
+        let json: Vec<Vec<serde_json::Value>> = response
+            .json()
+            .await
+            .map_err(ProviderError::from)?;
+
+        let mut candles = Vec::with_capacity(json.len());
+        for candle_arr in json {
+            match parse_candle_array(&candle_arr) {
+                Ok(candle) => candles.push(candle),
+                Err(e) => {
+                    eprintln!("⚠️ Erreur parsing bougie Coinbase: {}", e);
+                }
+            }
+        }
+
+        // Coinbase retourne les bougies de la plus récente à la plus ancienne
+        candles.sort_by_key(|c| c.timestamp);
+        Ok(candles)
+    }
+
+    /// Récupère la dernière bougie de manière asynchrone
+    pub async fn get_latest_candle_async(&self, series_id: &SeriesId) -> Result<Option<Candle>, ProviderError> {
+        let (product_id, granularity) = self.parse_series_id(series_id)?;
+        let candles = self.fetch_candles(&product_id, granularity, None, None).await?;
+        Ok(candles.into_iter().last())
+    }
+
+    /// Récupère les nouvelles bougies depuis un timestamp de manière asynchrone
+    pub async fn fetch_new_candles_async(&self, series_id: &SeriesId, since_timestamp: i64) -> Result<Vec<Candle>, ProviderError> {
+        let now = chrono::Utc::now().timestamp();
+        self.fetch_all_candles_in_range_async(series_id, since_timestamp, now).await
+    }
+
+    /// Récupère les bougies les plus récentes avant `end_timestamp` (au plus 300)
+    pub async fn fetch_candles_backwards_async(
+        &self,
+        series_id: &SeriesId,
+        _start_timestamp: i64,  // Non utilisé ici - filtrage fait côté appelant
+        end_timestamp: i64,
+    ) -> Result<Vec<Candle>, ProviderError> {
+        let (product_id, granularity) = self.parse_series_id(series_id)?;
+        let start = end_timestamp - granularity * MAX_CANDLES_PER_REQUEST;
+        self.fetch_candles(&product_id, granularity, Some(start), Some(end_timestamp)).await
+    }
+
+    /// Récupère tout l'historique disponible en remontant le temps par fenêtres de 300 bougies
+    pub async fn fetch_full_history_async(&self, series_id: &SeriesId) -> Result<Vec<Candle>, ProviderError> {
+        let (product_id, granularity) = self.parse_series_id(series_id)?;
+        let window = granularity * MAX_CANDLES_PER_REQUEST;
+
+        let mut all_candles = Vec::new();
+        let mut end = chrono::Utc::now().timestamp();
+
+        println!("📥 Téléchargement de l'historique complet pour {}...", series_id.name);
+
+        loop {
+            let candles = self.fetch_candles(&product_id, granularity, Some(end - window), Some(end)).await?;
+            let Some(oldest) = candles.first().map(|c| c.timestamp) else {
+                break;
+            };
+
+            all_candles.splice(0..0, candles);
+            end = oldest - granularity;
+
+            println!("  📊 {} bougies téléchargées...", all_candles.len());
+
+            // Petite pause pour respecter la limite de requêtes publiques
+            tokio::time::sleep(tokio::time::Duration::from_millis(150)).await;
+        }
+
+        println!("✅ Historique complet téléchargé: {} bougies", all_candles.len());
+        Ok(all_candles)
+    }
+
+    /// Récupère TOUTES les bougies dans une plage temporelle avec pagination
+    /// (fenêtres successives de 300 bougies maximum)
+    pub async fn fetch_all_candles_in_range_async(
+        &self,
+        series_id: &SeriesId,
+        start_timestamp: i64,
+        end_timestamp: i64,
+    ) -> Result<Vec<Candle>, ProviderError> {
+        let (product_id, granularity) = self.parse_series_id(series_id)?;
+        let window = granularity * MAX_CANDLES_PER_REQUEST;
+
+        let mut all_candles: Vec<Candle> = Vec::new();
+        let mut current_start = start_timestamp;
+
+        println!("📥 Téléchargement des données de {} à {} pour {}...", start_timestamp, end_timestamp, series_id.name);
+
+        while current_start < end_timestamp {
+            let window_end = (current_start + window).min(end_timestamp);
+            let candles = self.fetch_candles(&product_id, granularity, Some(current_start), Some(window_end)).await?;
+
+            // Les bornes Coinbase sont inclusives : éviter les doublons entre deux fenêtres
+            let last_known = all_candles.last().map(|c| c.timestamp);
+            all_candles.extend(candles.into_iter().filter(|c| Some(c.timestamp) > last_known));
+
+            current_start = window_end + 1;
+
+            if current_start < end_timestamp {
+                println!("  📊 {} bougies téléchargées...", all_candles.len());
+                // Petite pause pour respecter la limite de requêtes publiques
+                tokio::time::sleep(tokio::time::Duration::from_millis(150)).await;
+            }
+        }
+
+        println!("✅ Total: {} bougies téléchargées", all_candles.len());
+        Ok(all_candles)
+    }
+
+    /// Teste la connexion à l'API Coinbase
+    pub async fn test_connection(&self) -> Result<(), ProviderError> {
+        let url = format!("{}/time", self.base_url);
+        let response = self.client.get(&url).send().await.map_err(ProviderError::from)?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(ProviderError::Api {
+                status: Some(response.status().as_u16()),
+                message: "Échec du test de connexion Coinbase".to_string(),
+            })
+        }
+    }
+
+    /// Exécute une future async
+    fn run_async<F, T>(&self, future: F) -> Result<T, ProviderError>
+    where
+        F: std::future::Future<Output = Result<T, ProviderError>>,
+    {
+        tokio::runtime::Runtime::new()
+            .map_err(|e| ProviderError::Unknown(format!("Erreur création runtime: {}", e)))?
+            .block_on(future)
+    }
+}
+
+impl Default for CoinbaseProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for CoinbaseProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CoinbaseProvider")
+            .field("base_url", &self.base_url)
+            .finish()
+    }
+}
+
+impl RealtimeDataProvider for CoinbaseProvider {
+    fn fetch_latest_candle(&self, series_id: &SeriesId) -> Result<Option<Candle>, String> {
+        self.run_async(CoinbaseProvider::get_latest_candle_async(self, series_id))
+            .map_err(|e| e.to_string())
+    }
+
+    fn fetch_new_candles(&self, series_id: &SeriesId, since_timestamp: i64) -> Result<Vec<Candle>, String> {
+        self.run_async(CoinbaseProvider::fetch_new_candles_async(self, series_id, since_timestamp))
+            .map_err(|e| e.to_string())
+    }
+
+    fn get_latest_candle_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+    ) -> BoxFuture<'a, Result<Option<Candle>, ProviderError>> {
+        Box::pin(CoinbaseProvider::get_latest_candle_async(self, series_id))
+    }
+
+    fn fetch_new_candles_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+        since_timestamp: i64,
+    ) -> BoxFuture<'a, Result<Vec<Candle>, ProviderError>> {
+        Box::pin(CoinbaseProvider::fetch_new_candles_async(self, series_id, since_timestamp))
+    }
+
+    fn fetch_full_history_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+    ) -> BoxFuture<'a, Result<Vec<Candle>, ProviderError>> {
+        Box::pin(CoinbaseProvider::fetch_full_history_async(self, series_id))
+    }
+
+    fn fetch_all_candles_in_range_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+        start_timestamp: i64,
+        end_timestamp: i64,
+    ) -> BoxFuture<'a, Result<Vec<Candle>, ProviderError>> {
+        Box::pin(CoinbaseProvider::fetch_all_candles_in_range_async(self, series_id, start_timestamp, end_timestamp))
+    }
+
+    fn fetch_candles_backwards_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+        start_timestamp: i64,
+        end_timestamp: i64,
+    ) -> BoxFuture<'a, Result<Vec<Candle>, ProviderError>> {
+        Box::pin(CoinbaseProvider::fetch_candles_backwards_async(self, series_id, start_timestamp, end_timestamp))
+    }
+
+    fn check_oldest_available_timestamp_async<'a>(
+        &'a self,
+        _series_id: &'a SeriesId,
+    ) -> BoxFuture<'a, Result<Option<i64>, ProviderError>> {
+        // Coinbase n'expose pas la date de la première bougie disponible
+        Box::pin(async { Ok(None) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_series_id() {
+        let provider = CoinbaseProvider::new();
+
+        let (product_id, granularity) = provider.parse_series_id(&SeriesId::new("BTCUSD_1h")).unwrap();
+        assert_eq!(product_id, "BTC-USD");
+        assert_eq!(granularity, 3600);
+
+        assert!(provider.parse_series_id(&SeriesId::new("BTCUSD_4h")).is_err());
+        assert!(provider.parse_series_id(&SeriesId::new("invalid")).is_err());
+    }
+
+    #[test]
+    fn test_normalize_symbol() {
+        assert_eq!(ProviderType::Coinbase.normalize_symbol("BTCUSDT"), "BTC-USDT");
+        assert_eq!(ProviderType::Coinbase.normalize_symbol("ethusd"), "ETH-USD");
+        assert_eq!(ProviderType::Coinbase.normalize_symbol("BTC-EUR"), "BTC-EUR");
+        assert_eq!(ProviderType::Coinbase.normalize_symbol("SOL/USDC"), "SOL-USDC");
+        assert_eq!(ProviderType::Binance.normalize_symbol("btc-usdt"), "BTCUSDT");
+    }
+
+    #[test]
+    fn test_parse_candle_array() {
+        let candle_json = serde_json::json!([1609459200i64, 49900.0, 50100.0, 50000.0, 50050.0, 100.5]);
+        let arr: Vec<serde_json::Value> = serde_json::from_value(candle_json).unwrap();
+        let candle = parse_candle_array(&arr).unwrap();
+
+        assert_eq!(candle.timestamp, 1609459200);
+        assert_eq!(candle.open, 50000.0);
+        assert_eq!(candle.high, 50100.0);
+        assert_eq!(candle.low, 49900.0);
+        assert_eq!(candle.close, 50050.0);
+        assert_eq!(candle.volume, 100.5);
+    }
+
+    #[test]
+    fn test_to_iso8601() {
+        assert_eq!(to_iso8601(1609459200), "2021-01-01T00:00:00Z");
+    }
+}
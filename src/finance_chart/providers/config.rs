@@ -9,21 +9,31 @@ use std::collections::HashMap;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ProviderType {
     Binance,
+    Coinbase,
+    Kraken,
     // Ajouter d'autres providers ici à l'avenir
-    // Coinbase,
-    // Kraken,
 }
 
+/// Devises de cotation reconnues pour découper un symbole collé (ex: "BTCUSDT")
+///
+/// Les plus longues d'abord pour que "USDT" soit testé avant "USD".
+const QUOTE_CURRENCIES: &[&str] = &["USDT", "USDC", "EUR", "GBP", "USD", "BTC", "ETH"];
+
+/// Codes d'actifs propres à Kraken (code usuel → code Kraken)
+const KRAKEN_ASSET_CODES: &[(&str, &str)] = &[("BTC", "XBT"), ("DOGE", "XDG")];
+
 impl ProviderType {
     /// Retourne tous les providers disponibles
     pub fn all() -> Vec<ProviderType> {
-        vec![ProviderType::Binance]
+        vec![ProviderType::Binance, ProviderType::Coinbase, ProviderType::Kraken]
     }
 
     /// Retourne le nom d'affichage du provider
     pub fn display_name(&self) -> &'static str {
         match self {
             ProviderType::Binance => "Binance",
+            ProviderType::Coinbase => "Coinbase",
+            ProviderType::Kraken => "Kraken",
         }
     }
 
@@ -31,6 +41,41 @@ impl ProviderType {
     pub fn description(&self) -> &'static str {
         match self {
             ProviderType::Binance => "API publique Binance pour les données de marché en temps réel",
+            ProviderType::Coinbase => "API publique Coinbase Exchange pour les données de marché",
+            ProviderType::Kraken => "API publique Kraken pour les données OHLC",
+        }
+    }
+
+    /// Convertit un symbole vers le format attendu par le provider
+    ///
+    /// Binance utilise des symboles collés (`BTCUSDT`), Coinbase des paires
+    /// séparées par un tiret (`BTC-USD`) et Kraken ses propres codes d'actifs
+    /// (`XBTUSD`). Tous ces formats sont acceptés en entrée.
+    pub fn normalize_symbol(&self, symbol: &str) -> String {
+        let symbol = symbol.to_uppercase();
+        match self {
+            ProviderType::Binance => symbol.replace(['-', '/'], ""),
+            ProviderType::Coinbase => {
+                if symbol.contains('-') {
+                    return symbol;
+                }
+                if let Some((base, quote)) = symbol.split_once('/') {
+                    return format!("{}-{}", base, quote);
+                }
+                QUOTE_CURRENCIES
+                    .iter()
+                    .find(|quote| symbol.len() > quote.len() && symbol.ends_with(*quote))
+                    .map(|quote| format!("{}-{}", &symbol[..symbol.len() - quote.len()], quote))
+                    .unwrap_or(symbol)
+            }
+            ProviderType::Kraken => {
+                let symbol = symbol.replace(['-', '/'], "");
+                KRAKEN_ASSET_CODES
+                    .iter()
+                    .find(|(usual, _)| symbol.starts_with(usual))
+                    .map(|(usual, kraken)| format!("{}{}", kraken, &symbol[usual.len()..]))
+                    .unwrap_or(symbol)
+            }
         }
     }
 }
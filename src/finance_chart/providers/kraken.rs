@@ -0,0 +1,396 @@
+//! Provider Kraken pour récupérer les bougies OHLC via l'API REST publique
+//!
+//! L'endpoint `/0/public/OHLC` attend un intervalle en minutes et retourne au
+//! plus 720 bougies, accompagnées d'un curseur `last` à repasser dans `since`
+//! pour obtenir la page suivante.
+
+use crate::finance_chart::core::{Candle, SeriesId};
+use crate::finance_chart::realtime::{RealtimeDataProvider, ProviderError};
+use super::config::ProviderType;
+use futures::future::BoxFuture;
+use std::time::Duration;
+
+/// URL de base de l'API publique Kraken
+const KRAKEN_API_BASE: &str = "https://api.kraken.com/0/public";
+
+/// Timeout par défaut pour les requêtes HTTP (en secondes)
+const DEFAULT_TIMEOUT_SECS: u64 = 10;
+
+/// Correspondance intervalle → minutes (seuls intervalles acceptés par Kraken)
+const INTERVAL_MINUTES: &[(&str, u32)] = &[
+    ("1m", 1),
+    ("5m", 5),
+    ("15m", 15),
+    ("30m", 30),
+    ("1h", 60),
+    ("4h", 240),
+    ("1d", 1440),
+    ("1w", 10080),
+    ("15d", 21600),
+];
+
+/// Provider Kraken pour récupérer les données depuis l'API publique Kraken
+#[derive(Clone)]
+pub struct KrakenProvider {
+    /// Client HTTP pour les requêtes
+    client: reqwest::Client,
+    /// URL de base de l'API
+    base_url: String,
+}
+
+/// Page de bougies retournée par Kraken
+#[derive(Debug)]
+struct OhlcPage {
+    candles: Vec<Candle>,
+    /// Curseur à passer dans `since` pour la page suivante
+    last: Option<i64>,
+}
+
+/// Convertit un intervalle (ex: "1h") en minutes Kraken
+fn interval_to_minutes(interval: &str) -> Result<u32, ProviderError> {
+    INTERVAL_MINUTES
+        .iter()
+        .find(|(name, _)| *name == interval)
+        .map(|(_, minutes)| *minutes)
+        .ok_or_else(|| ProviderError::Validation(format!(
+            "Intervalle {} non supporté par Kraken",
+            interval
+        )))
+}
+
+/// Convertit une ligne OHLC Kraken `[time, open, high, low, close, vwap, volume, count]` en Candle
+fn parse_ohlc_array(arr: &[serde_json::Value]) -> Result<Candle, ProviderError> {
+    if arr.len() < 7 {
+        return Err(ProviderError::Parse(format!(
+            "Tableau OHLC incomplet: {} éléments (attendu: 8)",
+            arr.len()
+        )));
+    }
+
+    let parse_price = |idx: usize, field: &str| -> Result<f64, ProviderError> {
+        arr[idx]
+            .as_str()
+            .ok_or_else(|| ProviderError::Parse(format!("{} invalide (string)", field)))?
+            .parse::<f64>()
+            .map_err(|e| ProviderError::Parse(format!("Erreur parsing {}: {}", field, e)))
+    };
+
+    let time = arr[0]
+        .as_i64()
+        .ok_or_else(|| ProviderError::Parse("time invalide".to_string()))?;
+
+    Ok(Candle::new(
+        time,
+        parse_price(1, "open")?,
+        parse_price(2, "high")?,
+        parse_price(3, "low")?,
+        parse_price(4, "close")?,
+        parse_price(6, "volume")?,
+    ))
+}
+
+/// Parse la réponse JSON complète de `/OHLC`
+///
+/// Le résultat est indexé par le nom canonique de la paire (ex: `XXBTZUSD`),
+/// qui peut différer du nom demandé : on prend la première entrée tableau.
+fn parse_ohlc_response(json: &serde_json::Value) -> Result<OhlcPage, ProviderError> {
+    if let Some(errors) = json["error"].as_array() {
+        if !errors.is_empty() {
+            let message = errors
+                .iter()
+                .filter_map(|e| e.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(ProviderError::Api { status: None, message });
+        }
+    }
+
+    let result = json["result"]
+        .as_object()
+        .ok_or_else(|| ProviderError::Parse("Champ result manquant".to_string()))?;
+
+    let last = result.get("last").and_then(|v| v.as_i64());
+    let rows = result
+        .iter()
+        .filter(|(key, _)| key.as_str() != "last")
+        .find_map(|(_, value)| value.as_array())
+        .ok_or_else(|| ProviderError::Parse("Aucune donnée OHLC dans la réponse".to_string()))?;
+
+    let mut candles = Vec::with_capacity(rows.len());
+    for row in rows {
+        let Some(arr) = row.as_array() else { continue };
+        match parse_ohlc_array(arr) {
+            Ok(candle) => candles.push(candle),
+            Err(e) => {
+                eprintln!("⚠️ Erreur parsing bougie Kraken: {}", e);
+            }
+        }
+    }
+
+    Ok(OhlcPage { candles, last })
+}
+
+impl KrakenProvider {
+    /// Crée un nouveau provider Kraken avec les paramètres par défaut
+    pub fn new() -> Self {
+        Self::with_timeout(Duration::from_secs(DEFAULT_TIMEOUT_SECS))
+    }
+
+    /// Crée un nouveau provider avec un timeout personnalisé
+    pub fn with_timeout(timeout: Duration) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .unwrap_or_else(|e| {
+                eprintln!("⚠️ Erreur création client HTTP: {}. Utilisation d'un client basique.", e);
+                reqwest::Client::new()
+            });
+
+        Self {
+            client,
+            base_url: KRAKEN_API_BASE.to_string(),
+        }
+    }
+
+    /// Extrait la paire Kraken et l'intervalle en minutes depuis un SeriesId
+    fn parse_series_id(&self, series_id: &SeriesId) -> Result<(String, u32), ProviderError> {
+        let (symbol, interval) = series_id.name.split_once('_').ok_or_else(|| {
+            ProviderError::InvalidSeriesId(format!(
+                "Format de SeriesId invalide: {}. Attendu: SYMBOL_INTERVAL (ex: BTCUSD_1h)",
+                series_id.name
+            ))
+        })?;
+
+        let pair = ProviderType::Kraken.normalize_symbol(symbol);
+        let minutes = interval_to_minutes(interval)?;
+        Ok((pair, minutes))
+    }
+
+    /// Récupère une page OHLC (au plus 720 bougies) depuis le curseur `since`
+    async fn fetch_ohlc(&self, pair: &str, minutes: u32, since: Option<i64>) -> Result<OhlcPage, ProviderError> {
+        let mut url = format!("{}/OHLC?pair={}&interval={}", self.base_url, pair, minutes);
+        if let Some(since) = since {
+            url.push_str(&format!("&since={}", since));
+        }
+
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(ProviderError::from)?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Erreur inconnue".to_string());
+            return Err(ProviderError::Api {
+                status: Some(status),
+                message: error_text,
+            });
+        }
+
+        let json: serde_json::Value = response.json().await.map_err(ProviderError::from)?;
+        parse_ohlc_response(&json)
+    }
+
+    /// Récupère la dernière bougie de manière asynchrone
+    pub async fn get_latest_candle_async(&self, series_id: &SeriesId) -> Result<Option<Candle>, ProviderError> {
+        let (pair, minutes) = self.parse_series_id(series_id)?;
+        let page = self.fetch_ohlc(&pair, minutes, None).await?;
+        Ok(page.candles.into_iter().last())
+    }
+
+    /// Récupère les nouvelles bougies depuis un timestamp de manière asynchrone
+    pub async fn fetch_new_candles_async(&self, series_id: &SeriesId, since_timestamp: i64) -> Result<Vec<Candle>, ProviderError> {
+        self.fetch_all_candles_in_range_async(series_id, since_timestamp, i64::MAX).await
+    }
+
+    /// Récupère TOUTES les bougies dans une plage temporelle en suivant le curseur `last`
+    ///
+    /// Kraken ne conserve que les 720 dernières bougies par intervalle : une
+    /// plage plus ancienne retourne simplement moins de données.
+    pub async fn fetch_all_candles_in_range_async(
+        &self,
+        series_id: &SeriesId,
+        start_timestamp: i64,
+        end_timestamp: i64,
+    ) -> Result<Vec<Candle>, ProviderError> {
+        let (pair, minutes) = self.parse_series_id(series_id)?;
+
+        let mut all_candles: Vec<Candle> = Vec::new();
+        // `since` est exclusif côté Kraken
+        let mut cursor = start_timestamp.saturating_sub(1);
+
+        println!("📥 Téléchargement des données Kraken depuis {} pour {}...", start_timestamp, series_id.name);
+
+        loop {
+            let page = self.fetch_ohlc(&pair, minutes, Some(cursor)).await?;
+
+            let last_known = all_candles.last().map(|c| c.timestamp);
+            all_candles.extend(
+                page.candles
+                    .into_iter()
+                    .filter(|c| c.timestamp >= start_timestamp && c.timestamp <= end_timestamp)
+                    .filter(|c| Some(c.timestamp) > last_known),
+            );
+
+            // Arrêter si le curseur n'avance plus ou si la fin de la plage est atteinte
+            match page.last {
+                Some(next) if next > cursor && next < end_timestamp => cursor = next,
+                _ => break,
+            }
+
+            println!("  📊 {} bougies téléchargées...", all_candles.len());
+
+            // Petite pause pour respecter la limite de requêtes publiques
+            tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+        }
+
+        println!("✅ Total: {} bougies téléchargées", all_candles.len());
+        Ok(all_candles)
+    }
+
+    /// Retourne le timestamp de la bougie la plus ancienne disponible
+    pub async fn check_oldest_available_timestamp_async(&self, series_id: &SeriesId) -> Result<Option<i64>, ProviderError> {
+        let (pair, minutes) = self.parse_series_id(series_id)?;
+        let page = self.fetch_ohlc(&pair, minutes, None).await?;
+        Ok(page.candles.first().map(|c| c.timestamp))
+    }
+
+    /// Teste la connexion à l'API Kraken
+    pub async fn test_connection(&self) -> Result<(), ProviderError> {
+        let url = format!("{}/Time", self.base_url);
+        let response = self.client.get(&url).send().await.map_err(ProviderError::from)?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(ProviderError::Api {
+                status: Some(response.status().as_u16()),
+                message: "Échec du test de connexion Kraken".to_string(),
+            })
+        }
+    }
+
+    /// Exécute une future async
+    fn run_async<F, T>(&self, future: F) -> Result<T, ProviderError>
+    where
+        F: std::future::Future<Output = Result<T, ProviderError>>,
+    {
+        tokio::runtime::Runtime::new()
+            .map_err(|e| ProviderError::Unknown(format!("Erreur création runtime: {}", e)))?
+            .block_on(future)
+    }
+}
+
+impl Default for KrakenProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for KrakenProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KrakenProvider")
+            .field("base_url", &self.base_url)
+            .finish()
+    }
+}
+
+impl RealtimeDataProvider for KrakenProvider {
+    fn fetch_latest_candle(&self, series_id: &SeriesId) -> Result<Option<Candle>, String> {
+        self.run_async(KrakenProvider::get_latest_candle_async(self, series_id))
+            .map_err(|e| e.to_string())
+    }
+
+    fn fetch_new_candles(&self, series_id: &SeriesId, since_timestamp: i64) -> Result<Vec<Candle>, String> {
+        self.run_async(KrakenProvider::fetch_new_candles_async(self, series_id, since_timestamp))
+            .map_err(|e| e.to_string())
+    }
+
+    fn get_latest_candle_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+    ) -> BoxFuture<'a, Result<Option<Candle>, ProviderError>> {
+        Box::pin(KrakenProvider::get_latest_candle_async(self, series_id))
+    }
+
+    fn fetch_new_candles_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+        since_timestamp: i64,
+    ) -> BoxFuture<'a, Result<Vec<Candle>, ProviderError>> {
+        Box::pin(KrakenProvider::fetch_new_candles_async(self, series_id, since_timestamp))
+    }
+
+    fn fetch_all_candles_in_range_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+        start_timestamp: i64,
+        end_timestamp: i64,
+    ) -> BoxFuture<'a, Result<Vec<Candle>, ProviderError>> {
+        Box::pin(KrakenProvider::fetch_all_candles_in_range_async(self, series_id, start_timestamp, end_timestamp))
+    }
+
+    fn check_oldest_available_timestamp_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+    ) -> BoxFuture<'a, Result<Option<i64>, ProviderError>> {
+        Box::pin(KrakenProvider::check_oldest_available_timestamp_async(self, series_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval_to_minutes() {
+        assert_eq!(interval_to_minutes("1m").unwrap(), 1);
+        assert_eq!(interval_to_minutes("1h").unwrap(), 60);
+        assert_eq!(interval_to_minutes("1d").unwrap(), 1440);
+        assert!(interval_to_minutes("3m").is_err());
+    }
+
+    #[test]
+    fn test_parse_series_id_translates_pair() {
+        let provider = KrakenProvider::new();
+        let (pair, minutes) = provider.parse_series_id(&SeriesId::new("BTCUSD_4h")).unwrap();
+        assert_eq!(pair, "XBTUSD");
+        assert_eq!(minutes, 240);
+
+        let (pair, _) = provider.parse_series_id(&SeriesId::new("ETH-EUR_1h")).unwrap();
+        assert_eq!(pair, "ETHEUR");
+    }
+
+    #[test]
+    fn test_parse_ohlc_response() {
+        let json = serde_json::json!({
+            "error": [],
+            "result": {
+                "XXBTZUSD": [
+                    [1688669400, "30000.0", "30100.0", "29900.0", "30050.0", "30010.0", "12.5", 42],
+                    [1688673000, "30050.0", "30200.0", "30000.0", "30150.0", "30100.0", "8.0", 30]
+                ],
+                "last": 1688669400
+            }
+        });
+
+        let page = parse_ohlc_response(&json).unwrap();
+        assert_eq!(page.last, Some(1688669400));
+        assert_eq!(page.candles.len(), 2);
+        assert_eq!(page.candles[0].timestamp, 1688669400);
+        assert_eq!(page.candles[0].low, 29900.0);
+        assert_eq!(page.candles[0].volume, 12.5);
+        assert_eq!(page.candles[1].close, 30150.0);
+    }
+
+    #[test]
+    fn test_parse_ohlc_response_api_error() {
+        let json = serde_json::json!({ "error": ["EQuery:Unknown asset pair"] });
+        assert!(matches!(parse_ohlc_response(&json), Err(ProviderError::Api { .. })));
+    }
+}
@@ -1,12 +1,39 @@
 //! Module des providers de données en temps réel
 //!
-//! Contient les implémentations des différents providers (Binance, etc.)
+//! Contient les implémentations des différents providers (Binance, Coinbase, Kraken, etc.)
 //! et la gestion de leur configuration.
 
 pub mod binance;
+pub mod binance_ws;
+pub mod coinbase;
+pub mod kraken;
 pub mod config;
+pub mod rate_limit;
+
+use std::sync::Arc;
+
+use crate::finance_chart::realtime::RealtimeDataProvider;
 
 // Ré-exports
 pub use binance::BinanceProvider;
+pub use binance_ws::{BinanceWsProvider, WsConnectionState, WsEvent};
+pub use coinbase::CoinbaseProvider;
+pub use kraken::KrakenProvider;
 pub use config::{ProviderConfigManager, ProviderType};
 
+/// Provider de données partagé entre les tâches asynchrones
+pub type SharedDataProvider = Arc<dyn RealtimeDataProvider + Send + Sync>;
+
+/// Crée le provider de données correspondant au provider actif de la configuration
+pub fn create_data_provider(config: &ProviderConfigManager) -> SharedDataProvider {
+    let active = config.active_config();
+    let api_token = active.and_then(|c| c.api_token.clone());
+    let api_secret = active.and_then(|c| c.api_secret.clone());
+
+    match config.active_provider {
+        ProviderType::Binance => Arc::new(BinanceProvider::with_token_and_secret(api_token, api_secret)),
+        ProviderType::Coinbase => Arc::new(CoinbaseProvider::new()),
+        ProviderType::Kraken => Arc::new(KrakenProvider::new()),
+    }
+}
+
@@ -0,0 +1,138 @@
+//! Limitation du débit des requêtes HTTP (token bucket)
+//!
+//! Chaque requête consomme un nombre de jetons égal à son poids ; le seau se
+//! remplit en continu jusqu'à sa capacité. Un blocage explicite (réponse 429
+//! avec `Retry-After`) suspend toutes les requêtes jusqu'à son échéance.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// État interne du seau
+#[derive(Debug)]
+struct BucketState {
+    /// Jetons disponibles
+    tokens: f64,
+    /// Dernier remplissage
+    last_refill: Instant,
+    /// Blocage imposé par le serveur (Retry-After)
+    blocked_until: Option<Instant>,
+    /// Fin de l'attente en cours faute de jetons (pour l'affichage)
+    waiting_until: Option<Instant>,
+}
+
+/// Limiteur de débit partagé entre les requêtes d'un provider
+#[derive(Debug)]
+pub struct RateLimiter {
+    /// Nombre maximal de jetons (rafale autorisée)
+    capacity: f64,
+    /// Jetons regagnés par seconde
+    refill_per_second: f64,
+    state: Mutex<BucketState>,
+}
+
+impl RateLimiter {
+    /// Crée un limiteur plein de `capacity` jetons, rechargé de `refill_per_second` jetons/s
+    pub fn new(capacity: f64, refill_per_second: f64) -> Self {
+        Self {
+            capacity,
+            refill_per_second,
+            state: Mutex::new(BucketState {
+                tokens: capacity,
+                last_refill: Instant::now(),
+                blocked_until: None,
+                waiting_until: None,
+            }),
+        }
+    }
+
+    /// Consomme `weight` jetons si possible, sinon retourne le temps d'attente nécessaire
+    fn try_acquire(&self, weight: f64, now: Instant) -> Duration {
+        let Ok(mut state) = self.state.lock() else {
+            return Duration::ZERO;
+        };
+
+        if let Some(until) = state.blocked_until {
+            if until > now {
+                return until - now;
+            }
+            state.blocked_until = None;
+        }
+
+        let elapsed = now.saturating_duration_since(state.last_refill).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        state.last_refill = now;
+
+        // Une requête plus lourde que la capacité passe dès que le seau est plein
+        let weight = weight.min(self.capacity);
+        if state.tokens >= weight {
+            state.tokens -= weight;
+            state.waiting_until = None;
+            return Duration::ZERO;
+        }
+        let wait = Duration::from_secs_f64((weight - state.tokens) / self.refill_per_second);
+        state.waiting_until = Some(now + wait);
+        wait
+    }
+
+    /// Attend que `weight` jetons soient disponibles puis les consomme
+    pub async fn acquire(&self, weight: f64) {
+        loop {
+            let wait = self.try_acquire(weight, Instant::now());
+            if wait.is_zero() {
+                return;
+            }
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Suspend toutes les requêtes pendant `duration` (ex: `Retry-After` d'une réponse 429)
+    pub fn block_for(&self, duration: Duration) {
+        if let Ok(mut state) = self.state.lock() {
+            let now = Instant::now();
+            let until = now + duration;
+            state.blocked_until = Some(state.blocked_until.map_or(until, |current| current.max(until)));
+            state.tokens = 0.0;
+            state.last_refill = now;
+        }
+    }
+
+    /// Indique si des requêtes sont actuellement retenues (blocage serveur ou seau vide)
+    pub fn is_throttled(&self) -> bool {
+        let now = Instant::now();
+        self.state
+            .lock()
+            .map(|state| {
+                state.blocked_until.is_some_and(|until| until > now)
+                    || state.waiting_until.is_some_and(|until| until > now)
+            })
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_allows_burst_then_waits() {
+        let limiter = RateLimiter::new(10.0, 5.0);
+        let now = Instant::now();
+
+        assert!(limiter.try_acquire(6.0, now).is_zero());
+        // 4 jetons restants : il en manque 2, soit 0.4 s à 5 jetons/s
+        let wait = limiter.try_acquire(6.0, now);
+        assert!((wait.as_secs_f64() - 0.4).abs() < 1e-6);
+
+        assert!(limiter.try_acquire(6.0, now + Duration::from_millis(400)).is_zero());
+    }
+
+    #[test]
+    fn test_block_for_suspends_requests() {
+        let limiter = RateLimiter::new(10.0, 5.0);
+        limiter.block_for(Duration::from_secs(2));
+
+        assert!(limiter.is_throttled());
+        assert!(!limiter.try_acquire(1.0, Instant::now()).is_zero());
+        assert!(limiter.try_acquire(1.0, Instant::now() + Duration::from_secs(3)).is_zero());
+    }
+}
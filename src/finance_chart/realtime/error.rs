@@ -44,6 +44,13 @@ impl fmt::Display for ProviderError {
 
 impl std::error::Error for ProviderError {}
 
+impl ProviderError {
+    /// Indique si le serveur a refusé la requête pour dépassement de limite (429, ou 418 chez Binance)
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self, ProviderError::Api { status: Some(429 | 418), .. })
+    }
+}
+
 impl From<reqwest::Error> for ProviderError {
     fn from(err: reqwest::Error) -> Self {
         if err.is_timeout() {
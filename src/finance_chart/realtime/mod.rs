@@ -5,6 +5,8 @@
 
 pub mod error;
 
+use futures::future::BoxFuture;
+
 use crate::finance_chart::core::{Candle, SeriesId};
 pub use error::ProviderError;
 
@@ -28,6 +30,11 @@ pub enum UpdateResult {
 ///
 /// Ce trait permet d'abstraire la source de données (API REST, WebSocket, etc.)
 /// et de l'intégrer avec le système de mise à jour du graphique.
+///
+/// Les méthodes `*_async` retournent des futures boxées pour pouvoir être
+/// appelées via `Arc<dyn RealtimeDataProvider + Send + Sync>`. Leur
+/// implémentation par défaut s'appuie sur les méthodes synchrones ; les
+/// providers REST les surchargent avec de vraies requêtes asynchrones.
 #[allow(dead_code)]
 pub trait RealtimeDataProvider {
     /// Récupère la dernière bougie pour une série donnée
@@ -40,5 +47,82 @@ pub trait RealtimeDataProvider {
     fn fetch_all_candles(&self, series_id: &SeriesId) -> Result<Vec<Candle>, String> {
         self.fetch_new_candles(series_id, 0)
     }
+
+    /// Récupère la dernière bougie de manière asynchrone
+    fn get_latest_candle_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+    ) -> BoxFuture<'a, Result<Option<Candle>, ProviderError>> {
+        let result = self.fetch_latest_candle(series_id).map_err(ProviderError::from);
+        Box::pin(async move { result })
+    }
+
+    /// Récupère les nouvelles bougies depuis un timestamp de manière asynchrone
+    fn fetch_new_candles_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+        since_timestamp: i64,
+    ) -> BoxFuture<'a, Result<Vec<Candle>, ProviderError>> {
+        let result = self.fetch_new_candles(series_id, since_timestamp).map_err(ProviderError::from);
+        Box::pin(async move { result })
+    }
+
+    /// Récupère toutes les bougies de manière asynchrone
+    fn fetch_all_candles_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+    ) -> BoxFuture<'a, Result<Vec<Candle>, ProviderError>> {
+        self.fetch_new_candles_async(series_id, 0)
+    }
+
+    /// Récupère tout l'historique disponible (avec pagination si le provider la supporte)
+    fn fetch_full_history_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+    ) -> BoxFuture<'a, Result<Vec<Candle>, ProviderError>> {
+        self.fetch_all_candles_async(series_id)
+    }
+
+    /// Récupère toutes les bougies comprises entre deux timestamps
+    fn fetch_all_candles_in_range_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+        start_timestamp: i64,
+        end_timestamp: i64,
+    ) -> BoxFuture<'a, Result<Vec<Candle>, ProviderError>> {
+        let future = self.fetch_new_candles_async(series_id, start_timestamp);
+        Box::pin(async move {
+            Ok(future
+                .await?
+                .into_iter()
+                .filter(|candle| candle.timestamp <= end_timestamp)
+                .collect())
+        })
+    }
+
+    /// Récupère les bougies les plus récentes avant `end_timestamp`
+    /// (le filtrage par `start_timestamp` reste à la charge de l'appelant)
+    fn fetch_candles_backwards_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+        start_timestamp: i64,
+        end_timestamp: i64,
+    ) -> BoxFuture<'a, Result<Vec<Candle>, ProviderError>> {
+        self.fetch_all_candles_in_range_async(series_id, start_timestamp, end_timestamp)
+    }
+
+    /// Retourne le timestamp de la bougie la plus ancienne disponible
+    fn check_oldest_available_timestamp_async<'a>(
+        &'a self,
+        series_id: &'a SeriesId,
+    ) -> BoxFuture<'a, Result<Option<i64>, ProviderError>> {
+        let future = self.fetch_all_candles_async(series_id);
+        Box::pin(async move { Ok(future.await?.first().map(|c| c.timestamp)) })
+    }
+
+    /// Indique si les requêtes sont actuellement ralenties par une limite de débit
+    fn is_rate_limited(&self) -> bool {
+        false
+    }
 }
 
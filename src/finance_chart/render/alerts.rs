@@ -0,0 +1,76 @@
+//! Rendu des alertes de prix (ligne de déclenchement pointillée avec une cloche)
+
+use iced::widget::canvas::{path::Arc, Frame, Path};
+use iced::{Color, Point, Radians};
+
+use crate::finance_chart::alerts::Alert;
+use crate::finance_chart::viewport::Viewport;
+use super::horizontal_line::draw_level_segment;
+
+/// Style pour les alertes
+pub struct AlertStyle {
+    pub pending_color: Color,    // Couleur d'une alerte en attente
+    pub triggered_color: Color,  // Couleur d'une alerte déjà déclenchée
+    pub line_width: f32,         // Épaisseur de la ligne
+}
+
+impl Default for AlertStyle {
+    fn default() -> Self {
+        Self {
+            pending_color: Color::from_rgb(1.0, 0.75, 0.1),
+            triggered_color: Color::from_rgba(0.6, 0.6, 0.6, 0.5),
+            line_width: 1.0,
+        }
+    }
+}
+
+/// Taille de la cloche en pixels
+const BELL_SIZE: f32 = 10.0;
+
+/// Dessine une petite cloche centrée horizontalement en `center_x`, posée sur la ligne `y`
+fn draw_bell(frame: &mut Frame, center_x: f32, y: f32, color: Color) {
+    let half = BELL_SIZE / 2.0;
+    let base_y = y - 3.0;
+
+    // Corps : dôme + flancs, fermé par la base
+    let body = Path::new(|builder| {
+        builder.move_to(Point::new(center_x - half, base_y));
+        builder.line_to(Point::new(center_x - half * 0.7, base_y - half));
+        builder.arc(Arc {
+            center: Point::new(center_x, base_y - half),
+            radius: half * 0.7,
+            start_angle: Radians(std::f32::consts::PI),
+            end_angle: Radians(2.0 * std::f32::consts::PI),
+        });
+        builder.line_to(Point::new(center_x + half, base_y));
+        builder.close();
+    });
+    frame.fill(&body, color);
+
+    // Battant sous la base
+    frame.fill(&Path::circle(Point::new(center_x, base_y + 1.5), 1.5), color);
+}
+
+/// Rend les alertes de prix sur le graphique principal
+///
+/// Les alertes déjà déclenchées restent visibles, atténuées, jusqu'à leur suppression.
+pub fn render_alerts(
+    frame: &mut Frame,
+    viewport: &Viewport,
+    alerts: &[Alert],
+    style: Option<AlertStyle>,
+) {
+    let style = style.unwrap_or_default();
+    let width = viewport.width();
+
+    for alert in alerts {
+        let y = viewport.price_scale().price_to_y(alert.price);
+        if y < -BELL_SIZE || y > viewport.height() + BELL_SIZE {
+            continue;
+        }
+        let color = if alert.triggered { style.triggered_color } else { style.pending_color };
+
+        draw_level_segment(frame, y, 0.0, width, color, style.line_width, true);
+        draw_bell(frame, width - BELL_SIZE - 4.0, y, color);
+    }
+}
@@ -0,0 +1,89 @@
+//! Rendu du graphique en aire (remplissage sous la ligne des clôtures)
+
+use iced::widget::canvas::{gradient, Frame, Gradient, Path};
+use iced::{Color, Point, Size};
+
+use crate::finance_chart::viewport::Viewport;
+use crate::finance_chart::core::Candle;
+use super::line::close_points;
+
+/// Opacité du dégradé en haut de l'aire
+const AREA_TOP_ALPHA: f32 = 0.45;
+/// Opacité du dégradé en bas de l'aire
+const AREA_BOTTOM_ALPHA: f32 = 0.02;
+
+/// Rend l'aire comprise entre la ligne des clôtures et le bas du viewport
+///
+/// Le remplissage utilise un dégradé vertical dérivé de `color`. Les points hors
+/// écran sont ramenés sur les bords par interpolation pour éviter les pics
+/// lorsque des bougies sont coupées à gauche ou à droite.
+pub fn render_area_series(
+    frame: &mut Frame,
+    candles: &[Candle],
+    viewport: &Viewport,
+    color: Color,
+) {
+    if candles.is_empty() {
+        return;
+    }
+
+    let width = viewport.width();
+    let bottom = viewport.height();
+    let points = clip_to_viewport(close_points(candles, viewport, None, false), width);
+
+    let top = match points.iter().map(|p| p.y).reduce(f32::min) {
+        Some(top) => top,
+        None => return,
+    };
+
+    let fill = Gradient::Linear(
+        gradient::Linear::new(Point::new(0.0, top), Point::new(0.0, bottom))
+            .add_stop(0.0, Color { a: AREA_TOP_ALPHA, ..color })
+            .add_stop(1.0, Color { a: AREA_BOTTOM_ALPHA, ..color }),
+    );
+
+    // Une seule bougie visible : remplissage plat sous son prix de clôture
+    if points.len() < 2 {
+        let y = points[0].y.clamp(0.0, bottom);
+        let flat = Path::rectangle(Point::new(0.0, y), Size::new(width, bottom - y));
+        frame.fill(&flat, fill);
+        return;
+    }
+
+    let area_path = Path::new(|builder| {
+        let first = points[0];
+        let last = points[points.len() - 1];
+        builder.move_to(Point::new(first.x, bottom));
+        for point in &points {
+            builder.line_to(*point);
+        }
+        builder.line_to(Point::new(last.x, bottom));
+        builder.close();
+    });
+
+    frame.fill(&area_path, fill);
+}
+
+/// Ramène le premier et le dernier point sur les bords du viewport
+fn clip_to_viewport(mut points: Vec<Point>, width: f32) -> Vec<Point> {
+    if points.len() >= 2 {
+        if points[0].x < 0.0 {
+            points[0] = interpolate_at_x(points[0], points[1], 0.0);
+        }
+        let n = points.len();
+        if points[n - 1].x > width {
+            points[n - 1] = interpolate_at_x(points[n - 2], points[n - 1], width);
+        }
+    }
+    points
+}
+
+/// Point du segment [a, b] situé à l'abscisse `x`
+fn interpolate_at_x(a: Point, b: Point, x: f32) -> Point {
+    let dx = b.x - a.x;
+    if dx.abs() < f32::EPSILON {
+        return Point::new(x, a.y);
+    }
+    let t = ((x - a.x) / dx).clamp(0.0, 1.0);
+    Point::new(x, a.y + (b.y - a.y) * t)
+}
@@ -71,6 +71,26 @@ pub fn calculate_bar_width(candle_period: i64, time_range: i64, viewport_width:
         .clamp(MIN_WIDTH, MAX_WIDTH)
 }
 
+/// Calcule la largeur du corps des bougies
+///
+/// `width_ratio` est la part de l'espace disponible par bougie (0.0–1.0) ;
+/// `None` conserve le dimensionnement adaptatif de `calculate_bar_width`.
+/// Le corps garde au moins 1px pour ne pas disparaître en fort dézoom.
+pub fn calculate_candle_width(
+    candle_period: i64,
+    time_range: i64,
+    viewport_width: f32,
+    width_ratio: Option<f32>,
+) -> f32 {
+    let Some(ratio) = width_ratio else {
+        return calculate_bar_width(candle_period, time_range, viewport_width);
+    };
+    let pixels_per_second = viewport_width as f64 / time_range as f64;
+    let spacing = (candle_period as f64 * pixels_per_second) as f32;
+
+    (spacing * ratio.clamp(0.0, 1.0)).max(MIN_WIDTH)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,6 +117,22 @@ mod tests {
         assert_eq!(calculate_candle_period(&candles), 900);
     }
 
+    #[test]
+    fn test_candle_width_scales_with_visible_count() {
+        // 1 heure de période, 800px : 50 bougies visibles puis 200
+        let wide = calculate_candle_width(3600, 50 * 3600, 800.0, Some(0.5));
+        let narrow = calculate_candle_width(3600, 200 * 3600, 800.0, Some(0.5));
+        assert!((wide - 8.0).abs() < 1e-3);
+        assert!((narrow - 2.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_candle_width_minimum_one_pixel() {
+        // 10 000 bougies sur 800px : espace sous le pixel
+        let width = calculate_candle_width(3600, 10_000 * 3600, 800.0, Some(0.8));
+        assert_eq!(width, MIN_WIDTH);
+    }
+
     #[test]
     fn test_calculate_bar_width() {
         // 1 heure de période, 24h de range, 800px de largeur
@@ -3,7 +3,8 @@ use iced::{Color, Point, Size};
 
 use super::super::core::Candle;
 use super::super::viewport::Viewport;
-use super::bar_sizing::{calculate_bar_width, calculate_candle_period};
+use super::super::settings::CandleBodyStyle;
+use super::bar_sizing::{calculate_candle_width, calculate_candle_period};
 
 /// Couleurs par défaut pour les bougies
 pub struct CandleColors {
@@ -22,15 +23,73 @@ impl Default for CandleColors {
     }
 }
 
+/// Dimensions et style du corps des bougies
+#[derive(Debug, Clone, Copy)]
+pub struct CandleSizing {
+    /// Part de l'espace disponible occupée par le corps (None = adaptatif)
+    pub width_ratio: Option<f32>,
+    /// Épaisseur des mèches en pixels
+    pub wick_width: f32,
+    /// Corps pleins ou creux pour les bougies haussières
+    pub body_style: CandleBodyStyle,
+}
+
+impl Default for CandleSizing {
+    fn default() -> Self {
+        Self {
+            width_ratio: None,
+            wick_width: 1.0,
+            body_style: CandleBodyStyle::Solid,
+        }
+    }
+}
+
+/// Opacité minimale du corps d'une bougie en mode heatmap de volume
+const VOLUME_HEAT_MIN_ALPHA: f32 = 0.25;
+
+/// Intensité (0..1) d'un volume dans la plage (min, max) du volume visible
+fn volume_heat_intensity(volume: f64, (min, max): (f64, f64)) -> f32 {
+    let span = max - min;
+    if span <= f64::EPSILON {
+        return 1.0;
+    }
+    ((volume - min) / span).clamp(0.0, 1.0) as f32
+}
+
+/// Couleur du contour de la bougie sélectionnée
+const SELECTION_COLOR: Color = Color::from_rgb(1.0, 0.85, 0.2);
+/// Écart en pixels entre la bougie sélectionnée et son contour
+const SELECTION_PADDING: f32 = 3.0;
+
+/// Encadre la bougie sélectionnée, de la mèche haute à la mèche basse
+fn render_selection_outline(frame: &mut Frame, candle: &Candle, viewport: &Viewport, candle_width: f32) {
+    let x = viewport.time_scale().time_to_x(candle.timestamp);
+    let high_y = viewport.price_scale().price_to_y(candle.high);
+    let low_y = viewport.price_scale().price_to_y(candle.low);
+    let top = high_y.min(low_y) - SELECTION_PADDING;
+    let bottom = high_y.max(low_y) + SELECTION_PADDING;
+    let half_width = candle_width / 2.0 + SELECTION_PADDING;
+
+    let outline = Path::rectangle(
+        Point::new(x - half_width, top),
+        Size::new(half_width * 2.0, bottom - top),
+    );
+    frame.stroke(&outline, canvas::Stroke::default().with_color(SELECTION_COLOR).with_width(1.5));
+}
+
 /// Rend une bougie sur le frame
+#[allow(clippy::too_many_arguments)]
 fn render_single_candle(
     frame: &mut Frame,
     candle: &Candle,
     viewport: &Viewport,
     candle_width: f32,
+    sizing: CandleSizing,
     colors: &CandleColors,
     opacity: f32,
+    volume_heat: Option<(f64, f64)>,
 ) {
+    let wick_width = sizing.wick_width;
     let price_scale = viewport.price_scale();
     let time_scale = viewport.time_scale();
 
@@ -41,10 +100,18 @@ fn render_single_candle(
     let low_y = price_scale.price_to_y(candle.low);
 
     // Couleur selon si la bougie est haussière ou baissière, avec opacité
+    // (atténuée selon le volume relatif en mode heatmap)
+    let body_opacity = match volume_heat {
+        Some(range) => {
+            let intensity = volume_heat_intensity(candle.volume, range);
+            opacity * (VOLUME_HEAT_MIN_ALPHA + (1.0 - VOLUME_HEAT_MIN_ALPHA) * intensity)
+        }
+        None => opacity,
+    };
     let body_color = if candle.is_bullish() {
-        Color::from_rgba(colors.bullish.r, colors.bullish.g, colors.bullish.b, opacity)
+        Color::from_rgba(colors.bullish.r, colors.bullish.g, colors.bullish.b, body_opacity)
     } else {
-        Color::from_rgba(colors.bearish.r, colors.bearish.g, colors.bearish.b, opacity)
+        Color::from_rgba(colors.bearish.r, colors.bearish.g, colors.bearish.b, body_opacity)
     };
     
     let wick_color = Color::from_rgba(colors.wick.r, colors.wick.g, colors.wick.b, opacity);
@@ -54,7 +121,7 @@ fn render_single_candle(
         builder.move_to(Point::new(x, high_y));
         builder.line_to(Point::new(x, low_y));
     });
-    frame.stroke(&wick_path, canvas::Stroke::default().with_color(wick_color).with_width(1.0));
+    frame.stroke(&wick_path, canvas::Stroke::default().with_color(wick_color).with_width(wick_width));
 
     // Dessiner le body
     let body_top = open_y.min(close_y);
@@ -65,7 +132,15 @@ fn render_single_candle(
         Point::new(x - candle_width / 2.0, body_top),
         Size::new(candle_width, body_height),
     );
-    frame.fill(&body_path, body_color);
+    // Style creux : contour seul pour les haussières (corps trop fin = plein)
+    let hollow = sizing.body_style == CandleBodyStyle::Hollow
+        && candle.is_bullish()
+        && candle_width > 2.0;
+    if hollow {
+        frame.stroke(&body_path, canvas::Stroke::default().with_color(body_color).with_width(1.0));
+    } else {
+        frame.fill(&body_path, body_color);
+    }
 }
 
 /// Rend toutes les bougies visibles sur le frame
@@ -74,13 +149,20 @@ fn render_single_candle(
 /// * `cutoff_timestamp` - Si Some, les bougies avant ce timestamp sont pleines (opacité 1.0),
 ///   celles après sont semi-transparentes (opacité 0.5). Si None, toutes les bougies sont pleines.
 /// * `hide_after_cutoff` - Si true et cutoff_timestamp est Some, les bougies après le timestamp sont cachées
+/// * `volume_heat` - Si Some((min, max)), le corps des bougies est d'autant plus opaque que
+///   leur volume est élevé dans cette plage (volume visible)
+/// * `selected` - Timestamp de la bougie sélectionnée, encadrée d'un contour
+#[allow(clippy::too_many_arguments)]
 pub fn render_candlesticks(
     frame: &mut Frame,
     candles: &[Candle],
     viewport: &Viewport,
     colors: Option<CandleColors>,
+    sizing: CandleSizing,
     cutoff_timestamp: Option<i64>,
     hide_after_cutoff: bool,
+    volume_heat: Option<(f64, f64)>,
+    selected: Option<i64>,
 ) {
     if candles.is_empty() {
         return;
@@ -91,7 +173,12 @@ pub fn render_candlesticks(
     // Calculer la largeur des bougies via le module bar_sizing
     let candle_period = calculate_candle_period(candles);
     let (min_time, max_time) = viewport.time_scale().time_range();
-    let candle_width = calculate_bar_width(candle_period, max_time - min_time, viewport.width());
+    let candle_width = calculate_candle_width(candle_period, max_time - min_time, viewport.width(), sizing.width_ratio);
+    // La mèche ne dépasse pas le corps
+    let sizing = CandleSizing {
+        wick_width: sizing.wick_width.clamp(1.0, candle_width.max(1.0)),
+        ..sizing
+    };
 
     // Dessiner uniquement les bougies visibles
     // Pour les séries avec peu de bougies, dessiner toutes les bougies même si elles sont légèrement en dehors
@@ -121,8 +208,24 @@ pub fn render_candlesticks(
                 1.0 // Pleine opacité par défaut
             };
             
-            render_single_candle(frame, candle, viewport, candle_width, &colors, opacity);
+            render_single_candle(frame, candle, viewport, candle_width, sizing, &colors, opacity, volume_heat);
+            if selected == Some(candle.timestamp) {
+                render_selection_outline(frame, candle, viewport, candle_width);
+            }
         }
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_volume_heat_intensity() {
+        assert_eq!(volume_heat_intensity(50.0, (0.0, 100.0)), 0.5);
+        assert_eq!(volume_heat_intensity(150.0, (0.0, 100.0)), 1.0);
+        assert_eq!(volume_heat_intensity(-10.0, (0.0, 100.0)), 0.0);
+        // Plage vide : toutes les bougies pleinement colorées
+        assert_eq!(volume_heat_intensity(42.0, (42.0, 42.0)), 1.0);
+    }
+}
@@ -1,10 +1,20 @@
 //! Rendu du crosshair (réticule) avec affichage prix/date
 
+use chrono::{TimeZone, Utc};
 use iced::widget::canvas::{Frame, Path, Stroke, Text};
 use iced::{Color, Point, Size};
 
+use crate::finance_chart::core::Candle;
 use crate::finance_chart::viewport::Viewport;
-use crate::finance_chart::render::utils::format_price_compact;
+use crate::finance_chart::render::draw::dashed_line;
+use crate::finance_chart::render::tooltip::find_candle_at_position;
+use crate::finance_chart::settings::{format_abbreviated, ChartStyle};
+
+/// Distance verticale maximale (pixels) pour que le mode aimant accroche un point OHLC
+const MAGNET_THRESHOLD_PX: f32 = 30.0;
+
+/// Largeur approximative d'un caractère des étiquettes de survol (pixels)
+const HOVER_CHAR_WIDTH: f32 = 6.5;
 
 /// Style du crosshair
 #[derive(Clone)]
@@ -14,6 +24,10 @@ pub struct CrosshairStyle {
     pub label_bg_color: Color,
     pub label_text_color: Color,
     pub label_text_size: f32,
+    /// Longueurs (tiret, espace) des lignes, `None` pour un trait continu
+    pub dash: Option<(f32, f32)>,
+    /// Affiche les étiquettes prix/date sur les axes
+    pub show_labels: bool,
 }
 
 impl Default for CrosshairStyle {
@@ -24,17 +38,68 @@ impl Default for CrosshairStyle {
             label_bg_color: Color::from_rgba(0.2, 0.2, 0.25, 0.95),
             label_text_color: Color::WHITE,
             label_text_size: 11.0,
+            dash: None,
+            show_labels: true,
+        }
+    }
+}
+
+impl CrosshairStyle {
+    /// Style des lignes du crosshair selon les réglages du graphique
+    ///
+    /// Les couleurs des étiquettes restent celles par défaut (fond sombre).
+    pub fn from_chart_style(chart_style: &ChartStyle) -> Self {
+        Self {
+            line_color: chart_style.crosshair_color.to_iced(),
+            line_width: chart_style.crosshair.width,
+            dash: chart_style.crosshair.dash.pattern(),
+            show_labels: chart_style.crosshair.show_labels,
+            ..Self::default()
+        }
+    }
+
+    /// Trace une ligne du crosshair avec l'épaisseur et le motif du style
+    pub fn stroke_line(&self, frame: &mut Frame, from: Point, to: Point) {
+        let stroke = Stroke::default()
+            .with_color(self.line_color)
+            .with_width(self.line_width);
+        match self.dash {
+            Some((dash, gap)) => dashed_line(frame, from, to, dash, gap, stroke),
+            None => frame.stroke(&Path::line(from, to), stroke),
         }
     }
 }
 
+/// Trouve le point OHLC le plus proche du curseur sur la bougie survolée (mode aimant)
+///
+/// Retourne la position écran du point (centre de la bougie, niveau du prix)
+/// et le prix exact, ou `None` si aucun prix n'est à moins de `MAGNET_THRESHOLD_PX`.
+pub fn find_nearest_ohlc(pos: Point, candles: &[Candle], viewport: &Viewport) -> Option<(Point, f64)> {
+    let candle = find_candle_at_position(pos.x, candles, viewport)?;
+    let price_scale = viewport.price_scale();
+
+    [candle.open, candle.high, candle.low, candle.close]
+        .into_iter()
+        .map(|price| (price, (price_scale.price_to_y(price) - pos.y).abs()))
+        .filter(|(_, distance)| *distance <= MAGNET_THRESHOLD_PX)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(price, _)| {
+            let x = viewport.time_scale().time_to_x(candle.timestamp);
+            (Point::new(x, price_scale.price_to_y(price)), price)
+        })
+}
+
 /// Dessine le crosshair à la position de la souris
-/// Affiche uniquement la ligne horizontale et les labels
-/// (La ligne verticale est gérée par le composant overlay)
+/// Affiche uniquement la ligne horizontale
+/// (La ligne verticale est gérée par le composant overlay, les labels prix/temps par les axes)
+///
+/// `snapped` (mode aimant) remplace la position libre par le point OHLC
+/// accroché : la ligne passe par ce point.
 pub fn render_crosshair(
     frame: &mut Frame,
     viewport: &Viewport,
     mouse_position: Point,
+    snapped: Option<(Point, f64)>,
     style: Option<CrosshairStyle>,
 ) {
     let style = style.unwrap_or_default();
@@ -47,61 +112,82 @@ pub fn render_crosshair(
         return;
     }
 
+    let y = snapped.map_or(mouse_position.y, |(point, _)| point.y);
+
     // === Ligne horizontale (prix) ===
-    let horizontal_line = Path::new(|builder| {
-        builder.move_to(Point::new(0.0, mouse_position.y));
-        builder.line_to(Point::new(width, mouse_position.y));
-    });
-    let stroke = Stroke::default()
-        .with_color(style.line_color)
-        .with_width(style.line_width);
-    frame.stroke(&horizontal_line, stroke);
+    style.stroke_line(frame, Point::new(0.0, y), Point::new(width, y));
 
-    // === Label du prix (sur le bord droit) ===
-    let price = viewport.price_scale().y_to_price(mouse_position.y);
-    let price_label = format_price_compact(price);
-    draw_price_label(frame, &style, mouse_position.y, width, &price_label);
+    // Point accroché (mode aimant)
+    if let Some((point, _)) = snapped {
+        frame.fill(&Path::circle(point, 3.0), style.line_color);
+    }
+}
 
-    // Note: Le label du temps est géré par le composant overlay
+/// Formate le temps du crosshair selon l'intervalle affiché
+///
+/// Date seule pour les intervalles d'un jour ou plus, date et heure sinon.
+pub fn format_crosshair_time(timestamp: i64, interval_seconds: i64) -> String {
+    let Some(datetime) = Utc.timestamp_opt(timestamp, 0).single() else {
+        return String::new();
+    };
+    if interval_seconds >= 86400 {
+        datetime.format("%d/%m/%y").to_string()
+    } else {
+        datetime.format("%d/%m %H:%M").to_string()
+    }
 }
 
-/// Dessine le label du prix sur le bord droit
-fn draw_price_label(frame: &mut Frame, style: &CrosshairStyle, y: f32, width: f32, label: &str) {
-    let padding_x = 4.0;
+/// Dessine l'étiquette du prix du crosshair sur l'axe Y (toute la largeur de l'axe)
+pub fn draw_axis_price_label(frame: &mut Frame, style: &CrosshairStyle, y: f32, axis_width: f32, label: &str) {
     let padding_y = 2.0;
-    let label_width = 60.0;
     let label_height = style.label_text_size + padding_y * 2.0;
-    
-    let label_x = width - label_width - 2.0;
     let label_y = y - label_height / 2.0;
 
-    // Fond du label
-    let bg_rect = Path::rectangle(
-        Point::new(label_x, label_y),
-        Size::new(label_width, label_height),
+    frame.fill(
+        &Path::rectangle(Point::new(0.0, label_y), Size::new(axis_width, label_height)),
+        style.label_bg_color,
     );
-    frame.fill(&bg_rect, style.label_bg_color);
+    frame.fill_text(Text {
+        content: label.to_string(),
+        position: Point::new(3.0, label_y + padding_y),
+        color: style.label_text_color,
+        size: iced::Pixels(style.label_text_size),
+        ..Text::default()
+    });
+}
 
-    // Texte
-    let text = Text {
+/// Dessine l'étiquette du temps du crosshair sur l'axe X, centrée sur `x`
+///
+/// L'étiquette est décalée pour rester entièrement dans l'axe près des bords.
+pub fn draw_axis_time_label(frame: &mut Frame, style: &CrosshairStyle, x: f32, axis_width: f32, label: &str) {
+    let padding_x = 4.0;
+    let padding_y = 2.0;
+    let label_width = label.chars().count() as f32 * HOVER_CHAR_WIDTH + padding_x * 2.0;
+    let label_height = style.label_text_size + padding_y * 2.0;
+    let label_x = (x - label_width / 2.0).clamp(0.0, (axis_width - label_width).max(0.0));
+
+    frame.fill(
+        &Path::rectangle(Point::new(label_x, 2.0), Size::new(label_width, label_height)),
+        style.label_bg_color,
+    );
+    frame.fill_text(Text {
         content: label.to_string(),
-        position: Point::new(label_x + padding_x, label_y + padding_y),
+        position: Point::new(label_x + padding_x, 2.0 + padding_y),
         color: style.label_text_color,
         size: iced::Pixels(style.label_text_size),
         ..Text::default()
-    };
-    frame.fill_text(text);
+    });
 }
 
-/// Dessine le label du temps sur le bord bas
-pub fn draw_time_label(frame: &mut Frame, style: &CrosshairStyle, x: f32, height: f32, label: &str) {
+/// Dessine le label du prix sur le bord droit
+fn draw_price_label(frame: &mut Frame, style: &CrosshairStyle, y: f32, width: f32, label: &str) {
     let padding_x = 4.0;
     let padding_y = 2.0;
-    let label_width = 50.0;
+    let label_width = 60.0;
     let label_height = style.label_text_size + padding_y * 2.0;
     
-    let label_x = x - label_width / 2.0;
-    let label_y = height - label_height - 2.0;
+    let label_x = width - label_width - 2.0;
+    let label_y = y - label_height / 2.0;
 
     // Fond du label
     let bg_rect = Path::rectangle(
@@ -152,7 +238,7 @@ pub fn render_volume_crosshair(
             
             // Label du volume (sur le bord droit)
             let volume = volume_scale.y_to_volume(y);
-            let volume_label = format!("{:.0}", volume);
+            let volume_label = format_abbreviated(volume);
             draw_price_label(frame, &style, y, chart_bounds_width, &volume_label);
         }
     }
@@ -237,3 +323,237 @@ pub fn render_macd_crosshair(
     }
 }
 
+
+/// Dessine le crosshair pour le graphique ATR
+/// Affiche uniquement la ligne horizontale et le label de l'ATR à la position Y de la souris
+/// (La ligne verticale est gérée par le composant overlay)
+pub fn render_atr_crosshair(
+    frame: &mut Frame,
+    chart_bounds_width: f32,
+    chart_bounds_height: f32,
+    mouse_y_in_chart: Option<f32>,
+    y_to_atr: &dyn Fn(f32) -> f64,
+    style: Option<CrosshairStyle>,
+) {
+    if let Some(y) = mouse_y_in_chart {
+        if y >= 0.0 && y <= chart_bounds_height {
+            let style = style.unwrap_or_default();
+
+            // Ligne horizontale
+            let horizontal_line = Path::new(|builder| {
+                builder.move_to(Point::new(0.0, y));
+                builder.line_to(Point::new(chart_bounds_width, y));
+            });
+            let stroke = Stroke::default()
+                .with_color(style.line_color)
+                .with_width(style.line_width);
+            frame.stroke(&horizontal_line, stroke);
+
+            // Label de l'ATR (sur le bord droit)
+            let atr_value = y_to_atr(y);
+            let atr_label = if atr_value.abs() >= 1.0 {
+                format!("{:.2}", atr_value)
+            } else {
+                format!("{:.4}", atr_value)
+            };
+            draw_price_label(frame, &style, y, chart_bounds_width, &atr_label);
+        }
+    }
+}
+
+/// Dessine le crosshair pour le graphique OBV
+/// Affiche uniquement la ligne horizontale et le label de l'OBV à la position Y de la souris
+/// (La ligne verticale est gérée par le composant overlay)
+pub fn render_obv_crosshair(
+    frame: &mut Frame,
+    chart_bounds_width: f32,
+    chart_bounds_height: f32,
+    mouse_y_in_chart: Option<f32>,
+    y_to_label: &dyn Fn(f32) -> String,
+    style: Option<CrosshairStyle>,
+) {
+    if let Some(y) = mouse_y_in_chart {
+        if y >= 0.0 && y <= chart_bounds_height {
+            let style = style.unwrap_or_default();
+
+            // Ligne horizontale
+            let horizontal_line = Path::new(|builder| {
+                builder.move_to(Point::new(0.0, y));
+                builder.line_to(Point::new(chart_bounds_width, y));
+            });
+            let stroke = Stroke::default()
+                .with_color(style.line_color)
+                .with_width(style.line_width);
+            frame.stroke(&horizontal_line, stroke);
+
+            // Label de l'OBV (sur le bord droit, déjà abrégé)
+            draw_price_label(frame, &style, y, chart_bounds_width, &y_to_label(y));
+        }
+    }
+}
+
+/// Dessine le crosshair pour le graphique CCI
+/// Affiche uniquement la ligne horizontale et le label du CCI à la position Y de la souris
+/// (La ligne verticale est gérée par le composant overlay)
+pub fn render_cci_crosshair(
+    frame: &mut Frame,
+    chart_bounds_width: f32,
+    chart_bounds_height: f32,
+    mouse_y_in_chart: Option<f32>,
+    y_to_cci: &dyn Fn(f32) -> f64,
+    style: Option<CrosshairStyle>,
+) {
+    if let Some(y) = mouse_y_in_chart {
+        if y >= 0.0 && y <= chart_bounds_height {
+            let style = style.unwrap_or_default();
+
+            // Ligne horizontale
+            let horizontal_line = Path::new(|builder| {
+                builder.move_to(Point::new(0.0, y));
+                builder.line_to(Point::new(chart_bounds_width, y));
+            });
+            let stroke = Stroke::default()
+                .with_color(style.line_color)
+                .with_width(style.line_width);
+            frame.stroke(&horizontal_line, stroke);
+
+            // Label du CCI (sur le bord droit)
+            let cci_label = format!("{:.1}", y_to_cci(y));
+            draw_price_label(frame, &style, y, chart_bounds_width, &cci_label);
+        }
+    }
+}
+
+/// Dessine le crosshair pour le graphique Williams %R
+/// Affiche uniquement la ligne horizontale et le label du Williams %R à la position Y de la souris
+/// (La ligne verticale est gérée par le composant overlay)
+pub fn render_williams_r_crosshair(
+    frame: &mut Frame,
+    chart_bounds_width: f32,
+    chart_bounds_height: f32,
+    mouse_y_in_chart: Option<f32>,
+    y_to_williams_r: &dyn Fn(f32) -> f64,
+    style: Option<CrosshairStyle>,
+) {
+    if let Some(y) = mouse_y_in_chart {
+        if y >= 0.0 && y <= chart_bounds_height {
+            let style = style.unwrap_or_default();
+
+            // Ligne horizontale
+            let horizontal_line = Path::new(|builder| {
+                builder.move_to(Point::new(0.0, y));
+                builder.line_to(Point::new(chart_bounds_width, y));
+            });
+            let stroke = Stroke::default()
+                .with_color(style.line_color)
+                .with_width(style.line_width);
+            frame.stroke(&horizontal_line, stroke);
+
+            // Label du Williams %R (sur le bord droit)
+            let williams_r_label = format!("{:.1}", y_to_williams_r(y));
+            draw_price_label(frame, &style, y, chart_bounds_width, &williams_r_label);
+        }
+    }
+}
+
+/// Position horizontale de l'étiquette de survol d'un panneau d'indicateur
+///
+/// L'étiquette est placée à droite de la ligne verticale, ou à gauche si elle
+/// déborderait du panneau.
+fn hover_label_x(x: f32, label_width: f32, chart_width: f32) -> f32 {
+    let gap = 6.0;
+    if x + gap + label_width <= chart_width {
+        x + gap
+    } else {
+        (x - gap - label_width).max(0.0)
+    }
+}
+
+/// Dessine la valeur d'un indicateur au timestamp survolé (crosshair synchronisé)
+///
+/// `x` est la position de la bougie survolée dans le panneau et `y` celle de la
+/// valeur sur la courbe (point marqué si présent). La ligne verticale elle-même
+/// est dessinée par le composant overlay.
+pub fn render_hover_value(
+    frame: &mut Frame,
+    style: &CrosshairStyle,
+    x: f32,
+    y: Option<f32>,
+    label: &str,
+    color: Color,
+    chart_bounds_width: f32,
+) {
+    if let Some(y) = y {
+        frame.fill(&Path::circle(Point::new(x, y), 3.0), color);
+    }
+
+    let padding_x = 4.0;
+    let padding_y = 2.0;
+    let label_width = label.chars().count() as f32 * HOVER_CHAR_WIDTH + padding_x * 2.0;
+    let label_height = style.label_text_size + padding_y * 2.0;
+    let label_x = hover_label_x(x, label_width, chart_bounds_width);
+
+    let bg_rect = Path::rectangle(Point::new(label_x, 2.0), Size::new(label_width, label_height));
+    frame.fill(&bg_rect, style.label_bg_color);
+    frame.fill_text(Text {
+        content: label.to_string(),
+        position: Point::new(label_x + padding_x, 2.0 + padding_y),
+        color,
+        size: iced::Pixels(style.label_text_size),
+        ..Text::default()
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_crosshair_time_depends_on_interval() {
+        // 2024-01-15 13:45:00 UTC
+        let ts = 1_705_326_300;
+        assert_eq!(format_crosshair_time(ts, 3600), "15/01 13:45");
+        assert_eq!(format_crosshair_time(ts, 86400), "15/01/24");
+    }
+
+    #[test]
+    fn test_hover_label_flips_near_right_edge() {
+        assert_eq!(hover_label_x(100.0, 50.0, 500.0), 106.0);
+        assert_eq!(hover_label_x(480.0, 50.0, 500.0), 424.0);
+        // Panneau trop étroit : l'étiquette reste dans les bornes
+        assert_eq!(hover_label_x(10.0, 50.0, 40.0), 0.0);
+    }
+
+    fn viewport() -> Viewport {
+        // 1 pixel par seconde, 1 pixel par unité de prix (0 en bas, 200 en haut)
+        let mut viewport = Viewport::new(1000.0, 200.0);
+        viewport.zoom_to_rect((0, 1000), (0.0, 200.0));
+        viewport
+    }
+
+    #[test]
+    fn test_find_nearest_ohlc_snaps_to_closest_price() {
+        let viewport = viewport();
+        let candles = [
+            Candle::new(100, 100.0, 150.0, 50.0, 120.0, 1.0),
+            Candle::new(200, 120.0, 130.0, 110.0, 115.0, 1.0),
+        ];
+        // Curseur à 145 sur la première bougie : accroche le plus haut (150)
+        let y = viewport.price_scale().price_to_y(145.0);
+        let (point, price) = find_nearest_ohlc(Point::new(105.0, y), &candles, &viewport).unwrap();
+        assert_eq!(price, 150.0);
+        assert!((point.x - viewport.time_scale().time_to_x(100)).abs() < 1e-3);
+        assert!((point.y - viewport.price_scale().price_to_y(150.0)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_find_nearest_ohlc_respects_threshold() {
+        let viewport = viewport();
+        let candles = [Candle::new(100, 100.0, 101.0, 99.0, 100.5, 1.0)];
+        // Trop loin de tous les prix de la bougie
+        let y = viewport.price_scale().price_to_y(180.0);
+        assert!(find_nearest_ohlc(Point::new(100.0, y), &candles, &viewport).is_none());
+        // Aucune bougie sous le curseur
+        assert!(find_nearest_ohlc(Point::new(900.0, 100.0), &[], &viewport).is_none());
+    }
+}
@@ -0,0 +1,35 @@
+//! Rendu des séparateurs de journée (lignes verticales discrètes sur les graphiques intraday)
+
+use iced::{Color, Point};
+use iced::widget::canvas::{Frame, LineDash, Path, Stroke};
+
+use super::super::viewport::Viewport;
+
+/// Couleur des séparateurs, volontairement plus discrète que la grille
+const SEPARATOR_COLOR: Color = Color::from_rgba(0.6, 0.6, 0.65, 0.22);
+
+/// Trace une ligne verticale pointillée au début de chaque journée visible
+///
+/// `boundaries` contient les timestamps (triés) des bougies ouvrant une journée.
+pub fn render_day_separators(frame: &mut Frame, viewport: &Viewport, boundaries: &[i64]) {
+    let time_scale = viewport.time_scale();
+    let (min_time, max_time) = time_scale.time_range();
+    let height = viewport.height();
+
+    // Les timestamps étant triés, on ne parcourt que la plage visible
+    let start = boundaries.partition_point(|&t| t < min_time);
+    let end = boundaries.partition_point(|&t| t <= max_time).max(start);
+
+    let stroke = Stroke {
+        line_dash: LineDash {
+            segments: &[4.0, 4.0],
+            offset: 0,
+        },
+        ..Stroke::default().with_color(SEPARATOR_COLOR).with_width(1.0)
+    };
+    for &timestamp in &boundaries[start..end] {
+        let x = time_scale.time_to_x(timestamp).round() + 0.5;
+        let line = Path::line(Point::new(x, 0.0), Point::new(x, height));
+        frame.stroke(&line, stroke);
+    }
+}
@@ -0,0 +1,105 @@
+//! Rendu des canaux de Donchian sur le graphique principal
+
+use iced::widget::canvas::{Frame, Path, Stroke};
+use iced::{Color, Point, Rectangle, Size};
+
+use crate::finance_chart::viewport::Viewport;
+use crate::finance_chart::core::Candle;
+use crate::finance_chart::indicators::donchian::DonchianValue;
+
+/// Style pour les canaux de Donchian
+pub struct DonchianStyle {
+    pub band_color: Color,    // Couleur des bandes supérieure et inférieure
+    pub middle_color: Color,  // Couleur de la ligne médiane
+    pub fill_color: Color,    // Couleur de remplissage entre les bandes
+    pub line_width: f32,      // Épaisseur des lignes
+}
+
+impl Default for DonchianStyle {
+    fn default() -> Self {
+        Self {
+            band_color: Color::from_rgba(0.2, 0.8, 0.6, 0.8),      // Vert d'eau pour les bandes
+            middle_color: Color::from_rgba(0.2, 0.8, 0.6, 0.5),    // Médiane plus discrète
+            fill_color: Color::from_rgba(0.2, 0.8, 0.6, 0.1),      // Remplissage très transparent
+            line_width: 1.5,
+        }
+    }
+}
+
+/// Construit une polyligne à partir d'une suite de points
+fn polyline(points: &[Point]) -> Path {
+    Path::new(|builder| {
+        if let Some(first) = points.first() {
+            builder.move_to(*first);
+        }
+        for point in points.iter().skip(1) {
+            builder.line_to(*point);
+        }
+    })
+}
+
+/// Rend les canaux de Donchian sur le graphique principal
+///
+/// Tous les points des bougies visibles sont conservés (y compris ceux juste
+/// hors de l'écran) et le dessin est découpé à la zone du graphique, afin que
+/// le remplissage et les lignes s'arrêtent net aux bords du viewport.
+///
+/// # Arguments
+/// * `frame` - Frame de rendu Iced
+/// * `viewport` - Viewport pour les conversions de coordonnées
+/// * `candles` - Bougies visibles sur le graphique
+/// * `donchian_values` - Valeurs Donchian pré-calculées correspondant aux bougies visibles
+/// * `style` - Style optionnel pour personnaliser les couleurs
+pub fn render_donchian(
+    frame: &mut Frame,
+    viewport: &Viewport,
+    candles: &[Candle],
+    donchian_values: &[Option<DonchianValue>],
+    style: Option<DonchianStyle>,
+) {
+    let style = style.unwrap_or_default();
+
+    let mut upper_points = Vec::new();
+    let mut middle_points = Vec::new();
+    let mut lower_points = Vec::new();
+
+    for (candle, value) in candles.iter().zip(donchian_values.iter()) {
+        if let Some(value) = value {
+            let x = viewport.time_scale().time_to_x(candle.timestamp);
+            upper_points.push(Point::new(x, viewport.price_scale().price_to_y(value.upper)));
+            middle_points.push(Point::new(x, viewport.price_scale().price_to_y(value.middle)));
+            lower_points.push(Point::new(x, viewport.price_scale().price_to_y(value.lower)));
+        }
+    }
+
+    if upper_points.len() < 2 {
+        return;
+    }
+
+    let clip = Rectangle::new(Point::ORIGIN, Size::new(viewport.width(), viewport.height()));
+    frame.with_clip(clip, |frame| {
+        // Remplissage : bande supérieure de gauche à droite, puis inférieure de droite à gauche
+        let fill_path = Path::new(|builder| {
+            builder.move_to(upper_points[0]);
+            for point in &upper_points[1..] {
+                builder.line_to(*point);
+            }
+            for point in lower_points.iter().rev() {
+                builder.line_to(*point);
+            }
+            builder.close();
+        });
+        frame.fill(&fill_path, style.fill_color);
+
+        let band_stroke = Stroke::default()
+            .with_color(style.band_color)
+            .with_width(style.line_width);
+        frame.stroke(&polyline(&upper_points), band_stroke);
+        frame.stroke(&polyline(&lower_points), band_stroke);
+
+        let middle_stroke = Stroke::default()
+            .with_color(style.middle_color)
+            .with_width(style.line_width);
+        frame.stroke(&polyline(&middle_points), middle_stroke);
+    });
+}
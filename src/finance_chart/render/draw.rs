@@ -0,0 +1,163 @@
+//! Primitives de dessin partagées par les différents rendus
+//!
+//! Le canvas Iced ne trace que des traits pleins : les lignes en tirets sont
+//! découpées ici en sous-chemins le long de la géométrie.
+
+use iced::widget::canvas::{Frame, Path, Stroke};
+use iced::{Point, Size};
+
+/// Découpe la polyligne `points` en tirets de longueur `dash_len` séparés de `gap_len`
+///
+/// Le motif se poursuit d'un segment à l'autre : un tiret qui chevauche un
+/// sommet reste une seule polyligne. Le dernier tiret est tronqué à
+/// l'extrémité. Un motif invalide (tiret ou espace nul) donne la polyligne entière.
+fn dash_polylines(points: &[Point], dash_len: f32, gap_len: f32) -> Vec<Vec<Point>> {
+    if points.len() < 2 {
+        return Vec::new();
+    }
+    if dash_len <= 0.0 || gap_len <= 0.0 {
+        return vec![points.to_vec()];
+    }
+
+    let period = dash_len + gap_len;
+    let mut dashes: Vec<Vec<Point>> = Vec::new();
+    // Position courante dans le motif (tiret puis espace)
+    let mut phase = 0.0;
+    // Un tiret commencé sur le segment précédent se prolonge sur le suivant
+    let mut in_dash = false;
+
+    for pair in points.windows(2) {
+        let (from, to) = (pair[0], pair[1]);
+        let length = from.distance(to);
+        if length <= 0.0 {
+            continue;
+        }
+        let at = |t: f32| Point::new(from.x + (to.x - from.x) * t / length, from.y + (to.y - from.y) * t / length);
+
+        let mut t = 0.0;
+        while t < length {
+            if phase < dash_len {
+                let end = (t + dash_len - phase).min(length);
+                match dashes.last_mut() {
+                    Some(dash) if in_dash => dash.push(at(end)),
+                    _ => dashes.push(vec![at(t), at(end)]),
+                }
+                in_dash = true;
+                phase += end - t;
+                t = end;
+            } else {
+                in_dash = false;
+                let end = (t + period - phase).min(length);
+                phase += end - t;
+                t = end;
+            }
+            if phase >= period {
+                phase -= period;
+            }
+        }
+    }
+    dashes
+}
+
+/// Restreint le segment `from`-`to` au rectangle (0, 0)-`size` (algorithme de Liang-Barsky)
+///
+/// Retourne `None` si le segment est entièrement hors du rectangle.
+pub fn clip_segment(from: Point, to: Point, size: Size) -> Option<(Point, Point)> {
+    let (dx, dy) = (to.x - from.x, to.y - from.y);
+    let (mut t0, mut t1) = (0.0_f32, 1.0_f32);
+    // Chaque bord : (p, q) avec p * t <= q pour rester du bon côté
+    let edges = [(-dx, from.x), (dx, size.width - from.x), (-dy, from.y), (dy, size.height - from.y)];
+    for (p, q) in edges {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else {
+            let t = q / p;
+            if p < 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+        }
+    }
+    if t0 > t1 {
+        return None;
+    }
+    let at = |t: f32| Point::new(from.x + dx * t, from.y + dy * t);
+    Some((at(t0), at(t1)))
+}
+
+/// Dessine une polyligne en tirets, le motif étant continu aux sommets
+///
+/// Tous les tirets sont regroupés dans un seul chemin pour un unique appel à `stroke`.
+pub fn dashed_path(frame: &mut Frame, points: &[Point], dash_len: f32, gap_len: f32, stroke: Stroke) {
+    let dashes = dash_polylines(points, dash_len, gap_len);
+    if dashes.is_empty() {
+        return;
+    }
+    let path = Path::new(|builder| {
+        for dash in &dashes {
+            builder.move_to(dash[0]);
+            for point in &dash[1..] {
+                builder.line_to(*point);
+            }
+        }
+    });
+    frame.stroke(&path, stroke);
+}
+
+/// Dessine une ligne en tirets de `from` à `to`
+pub fn dashed_line(frame: &mut Frame, from: Point, to: Point, dash_len: f32, gap_len: f32, stroke: Stroke) {
+    dashed_path(frame, &[from, to], dash_len, gap_len, stroke);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dashes_of_horizontal_line() {
+        // 100 px avec un motif 6/4 : 10 tirets complets
+        let dashes = dash_polylines(&[Point::new(0.0, 5.0), Point::new(100.0, 5.0)], 6.0, 4.0);
+        assert_eq!(dashes.len(), 10);
+        assert_eq!(dashes[1], vec![Point::new(10.0, 5.0), Point::new(16.0, 5.0)]);
+
+        // 95 px : le dernier tiret est tronqué à l'extrémité
+        let dashes = dash_polylines(&[Point::new(0.0, 5.0), Point::new(95.0, 5.0)], 6.0, 4.0);
+        assert_eq!(dashes.len(), 10);
+        assert_eq!(dashes[9], vec![Point::new(90.0, 5.0), Point::new(95.0, 5.0)]);
+
+        // Motif invalide : une seule ligne continue
+        assert_eq!(dash_polylines(&[Point::ORIGIN, Point::new(10.0, 0.0)], 0.0, 4.0).len(), 1);
+    }
+
+    #[test]
+    fn test_dash_pattern_continues_across_vertices() {
+        // Motif 8/4 sur un L de 5 px puis 10 px : le premier tiret passe le coin
+        let points = [Point::ORIGIN, Point::new(5.0, 0.0), Point::new(5.0, 10.0)];
+        let dashes = dash_polylines(&points, 8.0, 4.0);
+        assert_eq!(dashes, vec![
+            vec![Point::ORIGIN, Point::new(5.0, 0.0), Point::new(5.0, 3.0)],
+            vec![Point::new(5.0, 7.0), Point::new(5.0, 10.0)],
+        ]);
+
+        // Moins de deux points : rien à dessiner
+        assert!(dash_polylines(&[Point::ORIGIN], 8.0, 4.0).is_empty());
+    }
+
+    #[test]
+    fn test_clip_segment() {
+        let size = Size::new(100.0, 50.0);
+        // Entièrement visible : inchangé
+        let inside = (Point::new(10.0, 10.0), Point::new(90.0, 40.0));
+        assert_eq!(clip_segment(inside.0, inside.1, size), Some(inside));
+
+        // Entrée hors écran à gauche : coupée au bord
+        let clipped = clip_segment(Point::new(-100.0, 0.0), Point::new(100.0, 50.0), size);
+        assert_eq!(clipped, Some((Point::new(0.0, 25.0), Point::new(100.0, 50.0))));
+
+        // Entièrement au-dessus de la zone
+        assert_eq!(clip_segment(Point::new(0.0, -10.0), Point::new(100.0, -5.0), size), None);
+    }
+}
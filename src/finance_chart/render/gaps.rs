@@ -0,0 +1,100 @@
+//! Rendu des trous de données (zones hachurées là où l'historique est incomplet)
+
+use iced::{Color, Point, Size};
+use iced::widget::canvas::{Frame, Path, Stroke};
+
+use super::super::viewport::Viewport;
+
+/// Couleur de fond des zones sans données
+const GAP_FILL_COLOR: Color = Color::from_rgba(0.5, 0.5, 0.55, 0.06);
+/// Couleur des hachures
+const GAP_HATCH_COLOR: Color = Color::from_rgba(0.6, 0.6, 0.65, 0.18);
+/// Espacement horizontal entre deux hachures (pixels)
+const HATCH_SPACING: f32 = 10.0;
+
+/// Calcule les segments de hachures diagonales (45°) contenus dans `[x_start, x_end] × [0, height]`
+///
+/// Les hachures sont alignées sur une grille fixe en X pour ne pas « glisser »
+/// d'une zone à l'autre.
+pub fn hatch_segments(x_start: f32, x_end: f32, height: f32, spacing: f32) -> Vec<(Point, Point)> {
+    if x_end <= x_start || height <= 0.0 || spacing <= 0.0 {
+        return Vec::new();
+    }
+
+    // Chaque hachure part du bas en x = k et monte vers la droite jusqu'à x = k + height
+    let first = ((x_start - height) / spacing).floor() as i64;
+    let last = (x_end / spacing).ceil() as i64;
+
+    let mut segments = Vec::new();
+    for step in first..=last {
+        let k = step as f32 * spacing;
+        let from_x = k.max(x_start);
+        let to_x = (k + height).min(x_end);
+        if to_x <= from_x {
+            continue;
+        }
+        segments.push((
+            Point::new(from_x, height - (from_x - k)),
+            Point::new(to_x, height - (to_x - k)),
+        ));
+    }
+    segments
+}
+
+/// Grise et hachure les plages de temps sans données sur toute la hauteur
+pub fn render_gaps(frame: &mut Frame, viewport: &Viewport, gaps: &[(i64, i64)]) {
+    let time_scale = viewport.time_scale();
+    let (min_time, max_time) = time_scale.time_range();
+    let height = viewport.height();
+
+    for &(start, end) in gaps {
+        if end <= min_time || start >= max_time {
+            continue;
+        }
+        // Tronquer aux bords visibles pour limiter le nombre de hachures
+        let x_start = time_scale.time_to_x(start.max(min_time));
+        let x_end = time_scale.time_to_x(end.min(max_time));
+        if x_end - x_start < 1.0 {
+            continue;
+        }
+
+        frame.fill_rectangle(
+            Point::new(x_start, 0.0),
+            Size::new(x_end - x_start, height),
+            GAP_FILL_COLOR,
+        );
+
+        let hatches = Path::new(|builder| {
+            for (from, to) in hatch_segments(x_start, x_end, height, HATCH_SPACING) {
+                builder.move_to(from);
+                builder.line_to(to);
+            }
+        });
+        frame.stroke(&hatches, Stroke::default().with_color(GAP_HATCH_COLOR).with_width(1.0));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hatches_stay_inside_zone() {
+        let segments = hatch_segments(15.0, 55.0, 30.0, 10.0);
+        assert!(!segments.is_empty());
+        for (from, to) in segments {
+            for p in [from, to] {
+                assert!(p.x >= 15.0 && p.x <= 55.0);
+                assert!(p.y >= 0.0 && p.y <= 30.0);
+            }
+            // Diagonale à 45° montant vers la droite
+            assert!(((to.x - from.x) - (from.y - to.y)).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_empty_zone_has_no_hatches() {
+        assert!(hatch_segments(20.0, 20.0, 30.0, 10.0).is_empty());
+        assert!(hatch_segments(20.0, 40.0, 0.0, 10.0).is_empty());
+    }
+}
@@ -1,13 +1,27 @@
-use iced::widget::canvas::{self, Frame, Path};
+use iced::widget::canvas::{self, Frame, LineDash, Path};
 use iced::{Color, Point};
 use chrono::{DateTime, Utc, TimeZone};
 
 use super::super::viewport::Viewport;
+use super::super::scale::PriceScale;
 
-/// Couleurs par défaut pour la grille
+/// Nombre de subdivisions d'un intervalle principal par les lignes secondaires
+const MINOR_DIVISIONS: usize = 5;
+/// Espacement minimal (pixels) entre deux lignes secondaires pour qu'elles soient dessinées
+const MIN_MINOR_SPACING_PX: f32 = 4.0;
+/// Opacité des lignes secondaires relativement aux lignes principales
+const MINOR_ALPHA_FACTOR: f32 = 0.4;
+
+/// Style de la grille
 pub struct GridStyle {
     pub line_color: Color,
     pub line_width: f32,
+    /// Lignes pointillées au lieu de pleines
+    pub dashed: bool,
+    /// Lignes secondaires entre les lignes principales
+    pub minor_lines: bool,
+    /// Facteur de densité (> 1 = grille plus fine, < 1 = plus grossière)
+    pub density: f32,
 }
 
 impl Default for GridStyle {
@@ -15,6 +29,9 @@ impl Default for GridStyle {
         Self {
             line_color: Color::from_rgba(0.5, 0.5, 0.5, 0.3),
             line_width: 1.0,
+            dashed: false,
+            minor_lines: false,
+            density: 1.0,
         }
     }
 }
@@ -45,6 +62,102 @@ pub fn calculate_nice_step(range: f64) -> f64 {
     nice_step * magnitude
 }
 
+/// Calcule des niveaux logarithmiques (1, 2, 5 par décade) dans la plage donnée
+///
+/// Si la plage couvre moins d'une décade, les niveaux 1-2-5 sont trop espacés :
+/// on retombe alors sur un pas linéaire "rond".
+pub fn calculate_log_levels(min_price: f64, max_price: f64) -> Vec<f64> {
+    if min_price <= 0.0 || max_price <= min_price || !max_price.is_finite() {
+        return Vec::new();
+    }
+
+    let first_decade = min_price.log10().floor() as i32;
+    let last_decade = max_price.log10().ceil() as i32;
+    let mut levels = Vec::new();
+
+    for decade in first_decade..=last_decade {
+        let base = 10_f64.powi(decade);
+        for mult in [1.0, 2.0, 5.0] {
+            let level = base * mult;
+            if level >= min_price && level <= max_price {
+                levels.push(level);
+            }
+        }
+    }
+
+    if levels.len() < 3 {
+        return linear_levels(min_price, max_price);
+    }
+    levels
+}
+
+/// Niveaux linéaires "ronds" dans la plage donnée
+fn linear_levels(min_price: f64, max_price: f64) -> Vec<f64> {
+    levels_with_step(min_price, max_price, calculate_nice_step(max_price - min_price))
+}
+
+/// Multiples de `step` compris dans la plage donnée
+fn levels_with_step(min_price: f64, max_price: f64, step: f64) -> Vec<f64> {
+    let mut levels = Vec::new();
+    let mut price = (min_price / step).ceil() * step;
+    while price <= max_price {
+        levels.push(price);
+        price += step;
+    }
+    levels
+}
+
+/// Niveaux de prix de la grille selon le type d'échelle (linéaire ou logarithmique)
+pub fn price_grid_levels(price_scale: &PriceScale) -> Vec<f64> {
+    price_grid_levels_with_density(price_scale, 1.0)
+}
+
+/// Niveaux de prix de la grille avec un facteur de densité
+///
+/// La densité divise la plage passée à `calculate_nice_step` ; elle est sans effet
+/// en échelle logarithmique (niveaux 1-2-5 fixes par décade).
+fn price_grid_levels_with_density(price_scale: &PriceScale, density: f32) -> Vec<f64> {
+    let (min_price, max_price) = price_scale.price_range();
+    if price_scale.is_log() {
+        calculate_log_levels(min_price, max_price)
+    } else {
+        let step = calculate_nice_step((max_price - min_price) / density.max(0.1) as f64);
+        levels_with_step(min_price, max_price, step)
+    }
+}
+
+/// Niveaux secondaires : chaque intervalle entre deux niveaux principaux est divisé en 5
+///
+/// Les intervalles sont prolongés d'un pas avant le premier et après le dernier niveau
+/// pour couvrir les bords. Un intervalle dont les subdivisions feraient moins de
+/// `MIN_MINOR_SPACING_PX` pixels (selon `to_pixel`) est ignoré.
+fn minor_levels(majors: &[f64], to_pixel: impl Fn(f64) -> f32) -> Vec<f64> {
+    if majors.len() < 2 {
+        return Vec::new();
+    }
+    let first_gap = majors[1] - majors[0];
+    let last_gap = majors[majors.len() - 1] - majors[majors.len() - 2];
+
+    let mut bounds = Vec::with_capacity(majors.len() + 2);
+    bounds.push(majors[0] - first_gap);
+    bounds.extend_from_slice(majors);
+    bounds.push(majors[majors.len() - 1] + last_gap);
+
+    let mut minors = Vec::new();
+    for pair in bounds.windows(2) {
+        let (start, end) = (pair[0], pair[1]);
+        let spacing = (to_pixel(end) - to_pixel(start)).abs() / MINOR_DIVISIONS as f32;
+        // NaN possible pour un niveau négatif en échelle log
+        if spacing.is_nan() || spacing < MIN_MINOR_SPACING_PX {
+            continue;
+        }
+        for k in 1..MINOR_DIVISIONS {
+            minors.push(start + (end - start) * k as f64 / MINOR_DIVISIONS as f64);
+        }
+    }
+    minors
+}
+
 /// Calcule un pas temporel "rond" approprié (en secondes)
 pub fn calculate_nice_time_step(range_seconds: i64) -> i64 {
     if range_seconds <= 0 {
@@ -104,62 +217,131 @@ pub fn format_time(timestamp: i64, step: i64) -> String {
     }
 }
 
+/// Trace des lignes horizontales (`horizontal`) ou verticales aux positions données
+///
+/// Les positions doivent être monotones : une ligne à moins d'un pixel de la
+/// précédente n'est pas redessinée.
+fn stroke_lines(
+    frame: &mut Frame,
+    viewport: &Viewport,
+    positions: impl Iterator<Item = f32>,
+    horizontal: bool,
+    stroke: canvas::Stroke,
+) {
+    let limit = if horizontal { viewport.height() } else { viewport.width() };
+    let mut last_drawn: Option<f32> = None;
+    for pos in positions {
+        // Ne dessiner que si visible
+        if !(0.0..=limit).contains(&pos) {
+            continue;
+        }
+        if last_drawn.is_some_and(|last| (pos - last).abs() < 1.0) {
+            continue;
+        }
+        last_drawn = Some(pos);
+        let line = Path::new(|builder| {
+            if horizontal {
+                builder.move_to(Point::new(0.0, pos));
+                builder.line_to(Point::new(viewport.width(), pos));
+            } else {
+                builder.move_to(Point::new(pos, 0.0));
+                builder.line_to(Point::new(pos, viewport.height()));
+            }
+        });
+        frame.stroke(&line, stroke);
+    }
+}
+
 /// Rend une grille sur le frame (lignes uniquement, sans labels)
 pub fn render_grid(frame: &mut Frame, viewport: &Viewport, style: Option<GridStyle>) {
     let style = style.unwrap_or_default();
-    
-    // === Lignes horizontales (niveaux de prix ronds) ===
-    let (min_price, max_price) = viewport.price_scale().price_range();
-    let price_range = max_price - min_price;
-    let price_step = calculate_nice_step(price_range);
-    
-    // Trouver le premier niveau rond >= min_price
-    let first_price = (min_price / price_step).ceil() * price_step;
-    
-    let mut price = first_price;
-    while price <= max_price {
-        let y = viewport.price_scale().price_to_y(price);
-        
-        // Ne dessiner que si visible
-        if y >= 0.0 && y <= viewport.height() {
-            let line = Path::new(|builder| {
-                builder.move_to(Point::new(0.0, y));
-                builder.line_to(Point::new(viewport.width(), y));
-            });
-            let stroke = canvas::Stroke::default()
-                .with_color(style.line_color)
-                .with_width(style.line_width);
-            frame.stroke(&line, stroke);
-        }
-        
-        price += price_step;
+    let line_dash = if style.dashed {
+        LineDash { segments: &[4.0, 4.0], offset: 0 }
+    } else {
+        LineDash::default()
+    };
+    let major_stroke = canvas::Stroke {
+        line_dash,
+        ..canvas::Stroke::default()
+            .with_color(style.line_color)
+            .with_width(style.line_width)
+    };
+    let minor_color = Color {
+        a: style.line_color.a * MINOR_ALPHA_FACTOR,
+        ..style.line_color
+    };
+    let minor_stroke = canvas::Stroke {
+        line_dash,
+        ..canvas::Stroke::default()
+            .with_color(minor_color)
+            .with_width(style.line_width)
+    };
+    let price_scale = viewport.price_scale();
+    let time_scale = viewport.time_scale();
+
+    // === Lignes horizontales (niveaux de prix ronds, espacés en log si besoin) ===
+    let price_levels = price_grid_levels_with_density(price_scale, style.density);
+    if style.minor_lines {
+        let minors = minor_levels(&price_levels, |price| price_scale.price_to_y(price));
+        stroke_lines(frame, viewport, minors.into_iter().map(|p| price_scale.price_to_y(p)), true, minor_stroke);
     }
+    stroke_lines(frame, viewport, price_levels.iter().map(|&p| price_scale.price_to_y(p)), true, major_stroke);
 
     // === Lignes verticales (timestamps ronds) ===
-    let (min_time, max_time) = viewport.time_scale().time_range();
-    let time_range = max_time - min_time;
+    let (min_time, max_time) = time_scale.time_range();
+    let time_range = ((max_time - min_time) as f64 / style.density.max(0.1) as f64) as i64;
     let time_step = calculate_nice_time_step(time_range);
-    
+
     // Trouver le premier timestamp rond >= min_time
     let first_time = ((min_time / time_step) + 1) * time_step;
-    
-    let mut time = first_time;
-    while time <= max_time {
-        let x = viewport.time_scale().time_to_x(time);
-        
-        // Ne dessiner que si visible
-        if x >= 0.0 && x <= viewport.width() {
-            let line = Path::new(|builder| {
-                builder.move_to(Point::new(x, 0.0));
-                builder.line_to(Point::new(x, viewport.height()));
-            });
-            let stroke = canvas::Stroke::default()
-                .with_color(style.line_color)
-                .with_width(style.line_width);
-            frame.stroke(&line, stroke);
-        }
-        
-        time += time_step;
+    let times: Vec<f64> = (0..)
+        .map(|i| first_time + i * time_step)
+        .take_while(|&time| time <= max_time)
+        .map(|time| time as f64)
+        .collect();
+
+    if style.minor_lines {
+        let minors = minor_levels(&times, |time| time_scale.time_to_x(time as i64));
+        stroke_lines(frame, viewport, minors.into_iter().map(|t| time_scale.time_to_x(t as i64)), false, minor_stroke);
     }
+    stroke_lines(frame, viewport, times.iter().map(|&t| time_scale.time_to_x(t as i64)), false, major_stroke);
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_levels_per_decade() {
+        let levels = calculate_log_levels(1.0, 1000.0);
+        assert_eq!(levels, vec![1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0]);
+    }
+
+    #[test]
+    fn test_log_levels_narrow_range_falls_back_to_linear() {
+        let levels = calculate_log_levels(100.0, 150.0);
+        assert!(levels.len() >= 3);
+        assert!(levels.windows(2).all(|w| w[1] > w[0]));
+    }
+
+    #[test]
+    fn test_log_levels_invalid_range() {
+        assert!(calculate_log_levels(0.0, 100.0).is_empty());
+        assert!(calculate_log_levels(10.0, 5.0).is_empty());
+    }
+
+    #[test]
+    fn test_minor_levels_subdivide_by_five() {
+        let minors = minor_levels(&[10.0, 20.0], |v| v as f32 * 10.0);
+        // 4 subdivisions avant, entre et après les deux niveaux principaux
+        assert_eq!(minors.len(), 12);
+        assert_eq!(&minors[4..8], &[12.0, 14.0, 16.0, 18.0]);
+    }
+
+    #[test]
+    fn test_minor_levels_skip_sub_pixel_spacing() {
+        // 10 unités = 10 px, soit 2 px par subdivision : trop serré
+        assert!(minor_levels(&[10.0, 20.0, 30.0], |v| v as f32).is_empty());
+        assert!(minor_levels(&[10.0], |v| v as f32 * 100.0).is_empty());
+    }
+}
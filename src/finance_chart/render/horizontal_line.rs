@@ -3,9 +3,17 @@
 use iced::widget::canvas::{Frame, Path, Stroke, Text};
 use iced::{Color, Point, Size};
 
+use crate::finance_chart::render::draw::dashed_line;
 use crate::finance_chart::tools::DrawnHorizontalLine;
 use crate::finance_chart::viewport::Viewport;
-use crate::finance_chart::render::utils::format_price_badge;
+use crate::finance_chart::settings::{format_price, ChartStyle};
+
+/// Largeur de l'étiquette de prix d'une ligne
+const LABEL_WIDTH: f32 = 60.0;
+/// Hauteur de l'étiquette de prix d'une ligne
+const LABEL_HEIGHT: f32 = 16.0;
+/// Marge entre l'étiquette et le bord droit du graphique
+const LABEL_MARGIN: f32 = 5.0;
 
 /// Dessine une ligne horizontale
 pub fn draw_horizontal_line(
@@ -13,6 +21,7 @@ pub fn draw_horizontal_line(
     viewport: &Viewport,
     line: &DrawnHorizontalLine,
     is_selected: bool,
+    chart_style: &ChartStyle,
 ) {
     let price_scale = viewport.price_scale();
     let y = price_scale.price_to_y(line.price);
@@ -24,86 +33,59 @@ pub fn draw_horizontal_line(
 
     let width = viewport.width();
 
-    if line.dashed {
-        // Ligne pointillée
-        draw_dashed_line(frame, y, width, line.color, line.width, is_selected);
+    let line_color = if is_selected {
+        Color::WHITE
     } else {
-        // Ligne pleine
-        let path = Path::new(|builder| {
-            builder.move_to(Point::new(0.0, y));
-            builder.line_to(Point::new(width, y));
-        });
-        
-        let line_color = if is_selected {
-            Color::WHITE
-        } else {
-            line.color
-        };
-        
-        let stroke = Stroke::default()
-            .with_color(line_color)
-            .with_width(if is_selected { line.width + 1.0 } else { line.width });
-        frame.stroke(&path, stroke);
-    }
+        line.color
+    };
+    let line_width = if is_selected { line.width + 1.0 } else { line.width };
+    draw_level_segment(frame, y, 0.0, width, line_color, line_width, line.dashed);
 
-    // Badge de prix si sélectionné
-    if is_selected {
-        draw_price_badge(frame, y, width, line.price, line.color);
-    }
+    // Étiquette de prix sur le bord droit (cliquable pour saisir un prix exact)
+    draw_price_badge(frame, y, width, &format_price(line.price, chart_style), line.color);
+}
+
+/// Coin haut-gauche de l'étiquette de prix d'une ligne à la hauteur `y`
+fn label_origin(y: f32, width: f32) -> Point {
+    Point::new(width - LABEL_WIDTH - LABEL_MARGIN, y - LABEL_HEIGHT / 2.0)
 }
 
-/// Dessine une ligne pointillée
-fn draw_dashed_line(
+/// Dessine un segment horizontal entre `x_start` et `x_end`, plein ou pointillé
+///
+/// Partagé par les lignes horizontales dessinées et les niveaux d'indicateurs (pivots).
+pub fn draw_level_segment(
     frame: &mut Frame,
     y: f32,
-    width: f32,
+    x_start: f32,
+    x_end: f32,
     color: Color,
     line_width: f32,
-    is_selected: bool,
+    dashed: bool,
 ) {
-    let dash_length = 8.0;
-    let gap_length = 4.0;
-    
-    let line_color = if is_selected {
-        Color::WHITE
-    } else {
-        color
-    };
-    let stroke_width = if is_selected { line_width + 1.0 } else { line_width };
+    let stroke = Stroke::default()
+        .with_color(color)
+        .with_width(line_width);
 
-    let mut x = 0.0;
-    while x < width {
-        let end_x = (x + dash_length).min(width);
-        let dash = Path::new(|builder| {
-            builder.move_to(Point::new(x, y));
-            builder.line_to(Point::new(end_x, y));
-        });
-        let stroke = Stroke::default()
-            .with_color(line_color)
-            .with_width(stroke_width);
-        frame.stroke(&dash, stroke);
-        x += dash_length + gap_length;
+    let (from, to) = (Point::new(x_start, y), Point::new(x_end, y));
+    if dashed {
+        dashed_line(frame, from, to, 8.0, 4.0, stroke);
+    } else {
+        frame.stroke(&Path::line(from, to), stroke);
     }
 }
 
 /// Dessine un badge de prix sur le bord droit
-fn draw_price_badge(frame: &mut Frame, y: f32, width: f32, price: f64, color: Color) {
-    let badge_width = 60.0;
-    let badge_height = 16.0;
-    let badge_x = width - badge_width - 5.0;
-    let badge_y = y - badge_height / 2.0;
+fn draw_price_badge(frame: &mut Frame, y: f32, width: f32, price_label: &str, color: Color) {
+    let origin = label_origin(y, width);
+    let (badge_x, badge_y) = (origin.x, origin.y);
 
     // Fond du badge
-    let bg_rect = Path::rectangle(
-        Point::new(badge_x, badge_y),
-        Size::new(badge_width, badge_height),
-    );
+    let bg_rect = Path::rectangle(origin, Size::new(LABEL_WIDTH, LABEL_HEIGHT));
     frame.fill(&bg_rect, color);
 
     // Texte du prix
-    let price_str = format_price_badge(price);
     let text = Text {
-        content: price_str,
+        content: price_label.to_string(),
         position: Point::new(badge_x + 4.0, badge_y + 2.0),
         color: Color::BLACK,
         size: iced::Pixels(11.0),
@@ -133,6 +115,27 @@ pub fn draw_hline_preview(frame: &mut Frame, y: f32, width: f32) {
     }
 }
 
+/// Hit-test pour les étiquettes de prix des lignes horizontales
+///
+/// Retourne l'index de la ligne et le coin haut-gauche de son étiquette.
+pub fn hit_test_hline_label(
+    position: Point,
+    lines: &[DrawnHorizontalLine],
+    viewport: &Viewport,
+) -> Option<(usize, Point)> {
+    let price_scale = viewport.price_scale();
+
+    lines.iter().enumerate().rev().find_map(|(index, line)| {
+        if line.locked || line.hidden {
+            return None;
+        }
+        let origin = label_origin(price_scale.price_to_y(line.price), viewport.width());
+        let inside = position.x >= origin.x && position.x <= origin.x + LABEL_WIDTH
+            && position.y >= origin.y && position.y <= origin.y + LABEL_HEIGHT;
+        inside.then_some((index, origin))
+    })
+}
+
 /// Hit-test pour les lignes horizontales
 pub fn hit_test_hline(
     mouse_y: f32,
@@ -143,6 +146,9 @@ pub fn hit_test_hline(
     let price_scale = viewport.price_scale();
 
     for (index, line) in lines.iter().enumerate().rev() {
+        if line.locked || line.hidden {
+            continue;
+        }
         let y = price_scale.price_to_y(line.price);
         if (mouse_y - y).abs() <= HIT_TOLERANCE {
             return Some(index);
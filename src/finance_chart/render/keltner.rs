@@ -0,0 +1,116 @@
+//! Rendu des canaux de Keltner sur le graphique principal
+
+use iced::widget::canvas::{Frame, Path, Stroke};
+use iced::{Color, Point, Rectangle, Size};
+
+use crate::finance_chart::viewport::Viewport;
+use crate::finance_chart::core::Candle;
+use crate::finance_chart::indicators::keltner::KeltnerValue;
+
+/// Style pour les canaux de Keltner
+pub struct KeltnerStyle {
+    pub middle_color: Color,  // Couleur de l'EMA médiane
+    pub band_color: Color,    // Couleur des bandes
+    pub fill_color: Color,    // Couleur de remplissage entre les bandes
+    pub line_width: f32,      // Épaisseur des lignes
+}
+
+impl Default for KeltnerStyle {
+    fn default() -> Self {
+        Self {
+            middle_color: Color::from_rgba(1.0, 0.6, 0.2, 0.8),    // Orange pour l'EMA
+            band_color: Color::from_rgba(0.8, 0.4, 0.9, 0.8),      // Violet pour les bandes
+            fill_color: Color::from_rgba(0.8, 0.4, 0.9, 0.1),      // Violet très transparent
+            line_width: 1.5,
+        }
+    }
+}
+
+/// Points d'un segment continu de valeurs Keltner
+#[derive(Default)]
+struct Segment {
+    middle: Vec<Point>,
+    upper: Vec<Point>,
+    lower: Vec<Point>,
+}
+
+/// Construit une polyligne à partir d'une suite de points
+fn polyline(points: &[Point]) -> Path {
+    Path::new(|builder| {
+        if let Some(first) = points.first() {
+            builder.move_to(*first);
+        }
+        for point in points.iter().skip(1) {
+            builder.line_to(*point);
+        }
+    })
+}
+
+/// Rend les canaux de Keltner sur le graphique principal
+///
+/// Les bougies sans valeur (période de chauffe de l'EMA et de l'ATR) coupent
+/// le tracé au lieu d'être reliées à zéro : chaque suite continue de valeurs
+/// est dessinée comme un segment indépendant.
+///
+/// # Arguments
+/// * `frame` - Frame de rendu Iced
+/// * `viewport` - Viewport pour les conversions de coordonnées
+/// * `candles` - Bougies visibles sur le graphique
+/// * `keltner_values` - Valeurs Keltner pré-calculées correspondant aux bougies visibles
+/// * `style` - Style optionnel pour personnaliser les couleurs
+pub fn render_keltner(
+    frame: &mut Frame,
+    viewport: &Viewport,
+    candles: &[Candle],
+    keltner_values: &[Option<KeltnerValue>],
+    style: Option<KeltnerStyle>,
+) {
+    let style = style.unwrap_or_default();
+
+    let mut segments = vec![Segment::default()];
+    for (candle, value) in candles.iter().zip(keltner_values.iter()) {
+        match value {
+            Some(value) => {
+                let x = viewport.time_scale().time_to_x(candle.timestamp);
+                let segment = segments.last_mut().expect("au moins un segment");
+                segment.middle.push(Point::new(x, viewport.price_scale().price_to_y(value.middle)));
+                segment.upper.push(Point::new(x, viewport.price_scale().price_to_y(value.upper)));
+                segment.lower.push(Point::new(x, viewport.price_scale().price_to_y(value.lower)));
+            }
+            None => {
+                if segments.last().is_some_and(|s| !s.middle.is_empty()) {
+                    segments.push(Segment::default());
+                }
+            }
+        }
+    }
+
+    let middle_stroke = Stroke::default()
+        .with_color(style.middle_color)
+        .with_width(style.line_width);
+    let band_stroke = Stroke::default()
+        .with_color(style.band_color)
+        .with_width(style.line_width);
+
+    let clip = Rectangle::new(Point::ORIGIN, Size::new(viewport.width(), viewport.height()));
+    frame.with_clip(clip, |frame| {
+        for segment in segments.iter().filter(|s| s.middle.len() > 1) {
+            // Remplissage entre la bande supérieure et la bande inférieure
+            let fill_path = Path::new(|builder| {
+                builder.move_to(segment.upper[0]);
+                for point in &segment.upper[1..] {
+                    builder.line_to(*point);
+                }
+                for point in segment.lower.iter().rev() {
+                    builder.line_to(*point);
+                }
+                builder.close();
+            });
+            frame.fill(&fill_path, style.fill_color);
+
+            frame.stroke(&polyline(&segment.upper), band_stroke);
+            frame.stroke(&polyline(&segment.lower), band_stroke);
+            frame.stroke(&polyline(&segment.middle), middle_stroke);
+        }
+    });
+}
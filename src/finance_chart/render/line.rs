@@ -0,0 +1,111 @@
+//! Rendu du graphique en ligne (prix de clôture)
+
+use iced::widget::canvas::{Frame, Path, Stroke};
+use iced::{Color, Point};
+
+use crate::finance_chart::viewport::Viewport;
+use crate::finance_chart::core::Candle;
+
+/// Style pour le graphique en ligne
+pub struct LineStyle {
+    pub color: Color,        // Couleur de la ligne
+    pub line_width: f32,     // Épaisseur de la ligne
+}
+
+impl Default for LineStyle {
+    fn default() -> Self {
+        Self {
+            color: Color::from_rgb(0.0, 0.8, 0.0),
+            line_width: 1.5,
+        }
+    }
+}
+
+/// Rend une série sous forme de ligne reliant les prix de clôture
+///
+/// # Arguments
+/// * `frame` - Frame de rendu Iced
+/// * `candles` - Bougies de la série (triées par timestamp)
+/// * `viewport` - Viewport pour les conversions de coordonnées
+/// * `style` - Style optionnel pour personnaliser la couleur et l'épaisseur
+/// * `cutoff_timestamp` - Si Some et `hide_after_cutoff` est true, la ligne s'arrête à ce timestamp
+pub fn render_line_series(
+    frame: &mut Frame,
+    candles: &[Candle],
+    viewport: &Viewport,
+    style: Option<LineStyle>,
+    cutoff_timestamp: Option<i64>,
+    hide_after_cutoff: bool,
+) {
+    if candles.is_empty() {
+        return;
+    }
+
+    let style = style.unwrap_or_default();
+    let points = close_points(candles, viewport, cutoff_timestamp, hide_after_cutoff);
+
+    if points.len() < 2 {
+        return;
+    }
+
+    let line_path = Path::new(|builder| {
+        builder.move_to(points[0]);
+        for point in &points[1..] {
+            builder.line_to(*point);
+        }
+    });
+
+    let stroke = Stroke::default()
+        .with_color(style.color)
+        .with_width(style.line_width);
+    frame.stroke(&line_path, stroke);
+}
+
+/// Convertit les clôtures en points écran
+///
+/// Garde un point de part et d'autre de la zone visible pour que la ligne
+/// touche les bords au lieu de s'arrêter sur la première bougie visible.
+pub(crate) fn close_points(
+    candles: &[Candle],
+    viewport: &Viewport,
+    cutoff_timestamp: Option<i64>,
+    hide_after_cutoff: bool,
+) -> Vec<Point> {
+    let width = viewport.width();
+    let time_scale = viewport.time_scale();
+    let price_scale = viewport.price_scale();
+
+    let mut points = Vec::with_capacity(candles.len());
+    let mut previous: Option<Point> = None;
+
+    for candle in candles {
+        if let Some(cutoff) = cutoff_timestamp {
+            if hide_after_cutoff && candle.timestamp > cutoff {
+                break;
+            }
+        }
+
+        let point = Point::new(
+            time_scale.time_to_x(candle.timestamp),
+            price_scale.price_to_y(candle.close),
+        );
+
+        if point.x < 0.0 {
+            // Encore à gauche de la zone visible : retenir le dernier point
+            previous = Some(point);
+            continue;
+        }
+
+        if let Some(prev) = previous.take() {
+            points.push(prev);
+        }
+        points.push(point);
+
+        if point.x > width {
+            // Premier point à droite de la zone visible : inutile d'aller plus loin
+            break;
+        }
+    }
+
+    points
+}
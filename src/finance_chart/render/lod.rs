@@ -0,0 +1,89 @@
+//! Niveau de détail (LOD) pour les très grandes séries
+//!
+//! Quand il y a plus de bougies visibles que de colonnes de pixels, les bougies
+//! d'une même colonne sont fusionnées avant le dessin : le rendu reste identique
+//! à l'œil mais le nombre de formes dessinées est borné par la largeur du viewport.
+
+use super::super::core::Candle;
+use super::super::viewport::Viewport;
+
+/// Indique si la série doit passer par l'agrégation LOD
+pub fn needs_lod(candles: &[Candle], viewport: &Viewport) -> bool {
+    candles.len() > viewport.width().max(1.0) as usize
+}
+
+/// Fusionne les bougies par colonne de pixels
+///
+/// Chaque paquet garde l'ouverture de la première bougie, la clôture de la dernière,
+/// le plus haut et le plus bas du paquet et la somme des volumes. Le timestamp est
+/// celui de la première bougie du paquet.
+pub fn aggregate_for_width(candles: &[Candle], viewport: &Viewport) -> Vec<Candle> {
+    let time_scale = viewport.time_scale();
+    let mut aggregated: Vec<Candle> = Vec::with_capacity(viewport.width().max(1.0) as usize + 1);
+    let mut current_column: Option<i64> = None;
+
+    for candle in candles {
+        let column = time_scale.time_to_x(candle.timestamp).floor() as i64;
+        match aggregated.last_mut() {
+            Some(bucket) if current_column == Some(column) => {
+                bucket.high = bucket.high.max(candle.high);
+                bucket.low = bucket.low.min(candle.low);
+                bucket.close = candle.close;
+                bucket.volume += candle.volume;
+            }
+            _ => {
+                aggregated.push(*candle);
+                current_column = Some(column);
+            }
+        }
+    }
+    aggregated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aggregation_preserves_ohlc() {
+        // 1000 bougies d'une minute sur 100px : 10 bougies par colonne
+        let candles: Vec<Candle> = (0..1000)
+            .map(|i| {
+                let base = 100.0 + (i % 37) as f64;
+                Candle::new(i * 60, base, base + 2.0 + (i % 5) as f64, base - 1.0 - (i % 3) as f64, base + 0.5, 1.0)
+            })
+            .collect();
+        let mut viewport = Viewport::new(100.0, 400.0);
+        viewport.zoom_to_rect((0, 1000 * 60), (0.0, 200.0));
+
+        assert!(needs_lod(&candles, &viewport));
+        let aggregated = aggregate_for_width(&candles, &viewport);
+        assert!(aggregated.len() <= 101);
+
+        // Chaque paquet correspond à une plage contiguë de bougies sources
+        let mut index = 0;
+        for (n, bucket) in aggregated.iter().enumerate() {
+            let end = aggregated
+                .get(n + 1)
+                .map(|next| candles.iter().position(|c| c.timestamp == next.timestamp).unwrap())
+                .unwrap_or(candles.len());
+            let group = &candles[index..end];
+            assert_eq!(bucket.timestamp, group[0].timestamp);
+            assert_eq!(bucket.open, group[0].open);
+            assert_eq!(bucket.close, group[group.len() - 1].close);
+            assert_eq!(bucket.high, group.iter().map(|c| c.high).fold(f64::MIN, f64::max));
+            assert_eq!(bucket.low, group.iter().map(|c| c.low).fold(f64::MAX, f64::min));
+            assert_eq!(bucket.volume, group.len() as f64);
+            index = end;
+        }
+        assert_eq!(index, candles.len());
+    }
+
+    #[test]
+    fn test_no_lod_when_candles_fit() {
+        let candles: Vec<Candle> = (0..50).map(|i| Candle::new(i * 60, 1.0, 2.0, 0.5, 1.5, 1.0)).collect();
+        let mut viewport = Viewport::new(800.0, 400.0);
+        viewport.zoom_to_rect((0, 50 * 60), (0.0, 10.0));
+        assert!(!needs_lod(&candles, &viewport));
+    }
+}
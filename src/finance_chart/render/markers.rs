@@ -0,0 +1,146 @@
+//! Rendu des marqueurs d'étude sur le graphique principal
+//!
+//! Triangles dessinés sous ou au-dessus d'une bougie pour signaler un événement
+//! calculé (croisements de moyennes mobiles, aperçu des signaux d'une
+//! stratégie…). Contrairement aux marqueurs de trades, ils ne dépendent
+//! d'aucun ordre réel.
+
+use iced::widget::canvas::{Frame, Path, Stroke};
+use iced::{Color, Point};
+
+use crate::app::data::TradeType;
+use crate::finance_chart::core::Candle;
+use crate::finance_chart::indicators::moving_average::CrossDirection;
+use crate::finance_chart::viewport::Viewport;
+
+/// Style des marqueurs de croisement
+pub struct CrossoverMarkerStyle {
+    pub golden_color: Color,  // Croisement haussier (triangle vers le haut sous la bougie)
+    pub death_color: Color,   // Croisement baissier (triangle vers le bas au-dessus)
+    pub size: f32,            // Demi-largeur du triangle
+    pub offset: f32,          // Écart entre la mèche et la pointe du triangle
+}
+
+impl Default for CrossoverMarkerStyle {
+    fn default() -> Self {
+        Self {
+            golden_color: Color::from_rgb(0.95, 0.75, 0.1),
+            death_color: Color::from_rgb(0.6, 0.3, 0.9),
+            size: 6.0,
+            offset: 4.0,
+        }
+    }
+}
+
+/// Dessine un triangle dont la pointe est en `tip`
+///
+/// Pointe vers le haut si `pointing_up` (le triangle s'étend alors sous la pointe).
+pub fn draw_triangle_marker(frame: &mut Frame, tip: Point, size: f32, pointing_up: bool, color: Color) {
+    let base_y = if pointing_up { tip.y + size * 1.5 } else { tip.y - size * 1.5 };
+    let path = Path::new(|builder| {
+        builder.move_to(tip);
+        builder.line_to(Point::new(tip.x - size, base_y));
+        builder.line_to(Point::new(tip.x + size, base_y));
+        builder.close();
+    });
+    frame.fill(&path, color);
+    frame.stroke(&path, Stroke::default().with_color(Color::from_rgb(0.1, 0.1, 0.1)).with_width(1.0));
+}
+
+/// Rend les marqueurs de croisement de moyennes mobiles
+///
+/// # Arguments
+/// * `frame` - Frame de rendu Iced
+/// * `viewport` - Viewport pour les conversions de coordonnées
+/// * `candles` - Toutes les bougies de la série (les index des croisements s'y réfèrent)
+/// * `crossings` - Croisements détectés (index de bougie, sens)
+/// * `style` - Style optionnel pour personnaliser les couleurs
+pub fn render_crossover_markers(
+    frame: &mut Frame,
+    viewport: &Viewport,
+    candles: &[Candle],
+    crossings: &[(usize, CrossDirection)],
+    style: Option<CrossoverMarkerStyle>,
+) {
+    let style = style.unwrap_or_default();
+    let (min_time, max_time) = viewport.time_scale().time_range();
+
+    for &(index, direction) in crossings {
+        let Some(candle) = candles.get(index) else {
+            continue;
+        };
+        if candle.timestamp < min_time || candle.timestamp > max_time {
+            continue;
+        }
+        let x = viewport.time_scale().time_to_x(candle.timestamp);
+        match direction {
+            CrossDirection::Golden => {
+                let y = viewport.price_scale().price_to_y(candle.low) + style.offset;
+                draw_triangle_marker(frame, Point::new(x, y), style.size, true, style.golden_color);
+            }
+            CrossDirection::Death => {
+                let y = viewport.price_scale().price_to_y(candle.high) - style.offset;
+                draw_triangle_marker(frame, Point::new(x, y), style.size, false, style.death_color);
+            }
+        }
+    }
+}
+
+/// Style des flèches d'aperçu des signaux d'une stratégie
+pub struct SignalMarkerStyle {
+    pub buy_color: Color,   // Signal d'achat (flèche vers le haut sous la bougie)
+    pub sell_color: Color,  // Signal de vente (flèche vers le bas au-dessus)
+    pub size: f32,          // Demi-largeur du triangle
+    pub offset: f32,        // Écart entre la mèche et la pointe du triangle
+}
+
+impl Default for SignalMarkerStyle {
+    fn default() -> Self {
+        Self {
+            buy_color: Color::from_rgb(0.2, 0.85, 0.6),
+            sell_color: Color::from_rgb(0.95, 0.35, 0.45),
+            size: 5.0,
+            offset: 6.0,
+        }
+    }
+}
+
+/// Rend les signaux qu'une stratégie aurait émis sur l'historique
+///
+/// # Arguments
+/// * `frame` - Frame de rendu Iced
+/// * `viewport` - Viewport pour les conversions de coordonnées
+/// * `candles` - Bougies de la série, triées par timestamp
+/// * `signals` - Timestamp de la bougie et sens de chaque signal
+/// * `style` - Style optionnel pour personnaliser les couleurs
+pub fn render_signal_markers(
+    frame: &mut Frame,
+    viewport: &Viewport,
+    candles: &[Candle],
+    signals: &[(i64, TradeType)],
+    style: Option<SignalMarkerStyle>,
+) {
+    let style = style.unwrap_or_default();
+    let (min_time, max_time) = viewport.time_scale().time_range();
+
+    for &(timestamp, side) in signals {
+        if timestamp < min_time || timestamp > max_time {
+            continue;
+        }
+        let Ok(index) = candles.binary_search_by_key(&timestamp, |c| c.timestamp) else {
+            continue;
+        };
+        let candle = &candles[index];
+        let x = viewport.time_scale().time_to_x(timestamp);
+        match side {
+            TradeType::Buy => {
+                let y = viewport.price_scale().price_to_y(candle.low) + style.offset;
+                draw_triangle_marker(frame, Point::new(x, y), style.size, true, style.buy_color);
+            }
+            TradeType::Sell => {
+                let y = viewport.price_scale().price_to_y(candle.high) - style.offset;
+                draw_triangle_marker(frame, Point::new(x, y), style.size, false, style.sell_color);
+            }
+        }
+    }
+}
@@ -0,0 +1,174 @@
+//! Rendu de l'outil de mesure (règle prix/temps)
+//!
+//! La mesure est transitoire : elle n'est dessinée que pendant le glisser.
+
+use iced::widget::canvas::{Frame, Path, Stroke, Text};
+use iced::{Color, Point, Size};
+
+use crate::finance_chart::viewport::Viewport;
+use super::utils::format_price_badge;
+
+/// Résultat d'une mesure entre deux points (temps, prix)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeasureInfo {
+    pub price_delta: f64,
+    /// Variation en pourcentage par rapport au prix de départ
+    pub percent: f64,
+    /// Nombre de barres (signé) entre les deux points
+    pub bars: i64,
+    /// Durée écoulée en secondes (signée)
+    pub elapsed: i64,
+}
+
+impl MeasureInfo {
+    pub fn compute(
+        start_time: i64,
+        start_price: f64,
+        end_time: i64,
+        end_price: f64,
+        candle_period: i64,
+    ) -> Self {
+        let price_delta = end_price - start_price;
+        let percent = if start_price != 0.0 {
+            price_delta / start_price * 100.0
+        } else {
+            0.0
+        };
+        let elapsed = end_time - start_time;
+        let bars = if candle_period > 0 {
+            (elapsed as f64 / candle_period as f64).round() as i64
+        } else {
+            0
+        };
+        Self { price_delta, percent, bars, elapsed }
+    }
+}
+
+/// Formate une durée en secondes (ex: "2j 3h", "45m")
+pub fn format_duration(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let s = seconds.unsigned_abs();
+    let days = s / 86_400;
+    let hours = (s % 86_400) / 3600;
+    let minutes = (s % 3600) / 60;
+
+    let body = if days > 0 {
+        if hours > 0 { format!("{}j {}h", days, hours) } else { format!("{}j", days) }
+    } else if hours > 0 {
+        if minutes > 0 { format!("{}h {}m", hours, minutes) } else { format!("{}h", hours) }
+    } else if minutes > 0 {
+        format!("{}m", minutes)
+    } else {
+        format!("{}s", s)
+    };
+    format!("{}{}", sign, body)
+}
+
+/// Dessine la boîte de mesure entre deux points écran
+pub fn draw_measure(
+    frame: &mut Frame,
+    viewport: &Viewport,
+    start: Point,
+    current: Point,
+    candle_period: i64,
+) {
+    let time_scale = viewport.time_scale();
+    let price_scale = viewport.price_scale();
+    let info = MeasureInfo::compute(
+        time_scale.x_to_time(start.x),
+        price_scale.y_to_price(start.y),
+        time_scale.x_to_time(current.x),
+        price_scale.y_to_price(current.y),
+        candle_period,
+    );
+
+    let base = if info.price_delta >= 0.0 {
+        Color::from_rgb(0.2, 0.8, 0.4)
+    } else {
+        Color::from_rgb(0.9, 0.3, 0.3)
+    };
+
+    // Boîte
+    let min_x = start.x.min(current.x);
+    let min_y = start.y.min(current.y);
+    let size = Size::new((current.x - start.x).abs(), (current.y - start.y).abs());
+    let rect = Path::rectangle(Point::new(min_x, min_y), size);
+    frame.fill(&rect, Color { a: 0.15, ..base });
+    frame.stroke(&rect, Stroke::default().with_color(Color { a: 0.8, ..base }).with_width(1.0));
+
+    // Diagonale de mesure
+    frame.stroke(
+        &Path::line(start, current),
+        Stroke::default().with_color(base).with_width(1.0),
+    );
+
+    // Étiquette sous (ou au-dessus de) la boîte selon le sens
+    let lines = [
+        format!("{}{} ({:+.2}%)",
+            if info.price_delta >= 0.0 { "+" } else { "-" },
+            format_price_badge(info.price_delta.abs()),
+            info.percent),
+        format!("{} barres, {}", info.bars, format_duration(info.elapsed)),
+    ];
+    let label_width = 170.0;
+    let label_height = 36.0;
+    let label_x = (min_x + size.width / 2.0 - label_width / 2.0)
+        .clamp(0.0, (viewport.width() - label_width).max(0.0));
+    let label_y = if current.y >= start.y {
+        (min_y + size.height + 6.0).min(viewport.height() - label_height)
+    } else {
+        (min_y - label_height - 6.0).max(0.0)
+    };
+
+    frame.fill(
+        &Path::rectangle(Point::new(label_x, label_y), Size::new(label_width, label_height)),
+        Color { a: 0.9, ..base },
+    );
+    for (i, line) in lines.into_iter().enumerate() {
+        frame.fill_text(Text {
+            content: line,
+            position: Point::new(label_x + 6.0, label_y + 4.0 + i as f32 * 15.0),
+            color: Color::WHITE,
+            size: iced::Pixels(11.0),
+            ..Text::default()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure_info_up() {
+        let info = MeasureInfo::compute(0, 100.0, 7200, 110.0, 3600);
+        assert!((info.price_delta - 10.0).abs() < 1e-9);
+        assert!((info.percent - 10.0).abs() < 1e-9);
+        assert_eq!(info.bars, 2);
+        assert_eq!(info.elapsed, 7200);
+    }
+
+    #[test]
+    fn test_measure_info_down_and_backwards() {
+        let info = MeasureInfo::compute(7200, 200.0, 0, 150.0, 3600);
+        assert!((info.percent + 25.0).abs() < 1e-9);
+        assert_eq!(info.bars, -2);
+    }
+
+    #[test]
+    fn test_measure_info_zero_price() {
+        let info = MeasureInfo::compute(0, 0.0, 60, 5.0, 0);
+        assert_eq!(info.percent, 0.0);
+        assert_eq!(info.bars, 0);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(45), "45s");
+        assert_eq!(format_duration(900), "15m");
+        assert_eq!(format_duration(3600), "1h");
+        assert_eq!(format_duration(5400), "1h 30m");
+        assert_eq!(format_duration(86_400 * 2 + 3600 * 3), "2j 3h");
+        assert_eq!(format_duration(-7200), "-2h");
+    }
+}
@@ -1,26 +1,67 @@
+pub mod alerts;
+pub mod area;
 pub mod bar_sizing;
 pub mod bollinger;
 pub mod candlestick;
 pub mod crosshair;
 pub mod current_price;
+pub mod day_separators;
+pub mod draw;
+pub mod gaps;
 pub mod grid;
 pub mod horizontal_line;
+pub mod line;
+pub mod lod;
+pub mod markers;
+pub mod measure;
 pub mod moving_average;
+pub mod vwap;
+pub mod psar;
+pub mod donchian;
+pub mod keltner;
+pub mod volume_profile;
+pub mod pivots;
+pub mod ohlc_bar;
 pub mod order_lines;
 pub mod rectangles;
+pub mod renko;
+pub mod sessions;
 pub mod tooltip;
+pub mod text_annotation;
 pub mod trade_markers;
+pub mod trend_line;
+pub mod vertical_line;
 pub mod utils;
 
+pub use alerts::render_alerts;
+pub use area::render_area_series;
 pub use bar_sizing::{calculate_bar_width, calculate_candle_period};
 pub use bollinger::{render_bollinger_bands, BollingerStyle};
-pub use moving_average::{render_moving_average, MovingAverageStyle};
-pub use candlestick::render_candlesticks;
-pub use crosshair::{render_crosshair, render_volume_crosshair, render_rsi_crosshair, render_macd_crosshair};
+pub use measure::draw_measure;
+pub use moving_average::{render_moving_averages, MovingAverageStyle};
+pub use vwap::{render_vwap, VwapStyle};
+pub use psar::{render_psar, PsarStyle};
+pub use donchian::{render_donchian, DonchianStyle};
+pub use keltner::{render_keltner, KeltnerStyle};
+pub use volume_profile::render_volume_profile;
+pub use pivots::render_pivots;
+pub use candlestick::{render_candlesticks, CandleSizing};
+pub use crosshair::{render_crosshair, find_nearest_ohlc, render_volume_crosshair, render_rsi_crosshair, render_macd_crosshair, render_atr_crosshair, render_obv_crosshair, render_cci_crosshair, render_williams_r_crosshair, render_hover_value};
 pub use current_price::render_current_price_line;
-pub use grid::{render_grid, calculate_nice_step, calculate_nice_time_step, format_time};
-pub use horizontal_line::{draw_horizontal_line, draw_hline_preview, hit_test_hline};
+pub use day_separators::render_day_separators;
+pub use gaps::render_gaps;
+pub use grid::{render_grid, calculate_nice_step, calculate_nice_time_step, format_time, price_grid_levels};
+pub use horizontal_line::{draw_horizontal_line, draw_hline_preview, hit_test_hline, hit_test_hline_label};
+pub use line::{render_line_series, LineStyle};
+pub use lod::{aggregate_for_width, needs_lod};
+pub use markers::{render_crossover_markers, render_signal_markers};
+pub use ohlc_bar::render_ohlc_bars;
 pub use order_lines::{draw_pending_order_lines, draw_tp_sl_lines, draw_preview_limit_order_lines, draw_preview_tp_sl_lines};
-pub use rectangles::{draw_rectangle, draw_preview_rectangle};
+pub use rectangles::{draw_rectangle, draw_preview_rectangle, draw_zoom_selection};
+pub use renko::{render_renko, visible_renko_range};
+pub use sessions::render_sessions;
 pub use tooltip::{render_tooltip, find_candle_at_position};
-pub use trade_markers::render_trade_markers;
+pub use text_annotation::{draw_text_annotation, hit_test_text};
+pub use trade_markers::{render_trade_markers, render_pnl_connectors, visible_trade_clusters, find_cluster_at, render_cluster_tooltip};
+pub use vertical_line::{draw_vertical_line, draw_vline_preview, hit_test_vline};
+pub use trend_line::{draw_trend_line, draw_ray_preview, hit_test_trend_line};
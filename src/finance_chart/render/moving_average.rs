@@ -82,3 +82,21 @@ pub fn render_moving_average(
     frame.stroke(&ma_path, ma_stroke);
 }
 
+
+/// Rend plusieurs moyennes mobiles sur le graphique principal
+///
+/// # Arguments
+/// * `frame` - Frame de rendu Iced
+/// * `viewport` - Viewport pour les conversions de coordonnées
+/// * `candles` - Bougies visibles sur le graphique
+/// * `lines` - Valeurs visibles de chaque moyenne mobile avec leur style
+pub fn render_moving_averages<'a>(
+    frame: &mut Frame,
+    viewport: &Viewport,
+    candles: &[Candle],
+    lines: impl IntoIterator<Item = (&'a [Option<f64>], MovingAverageStyle)>,
+) {
+    for (ma_values, style) in lines {
+        render_moving_average(frame, viewport, candles, ma_values, Some(style));
+    }
+}
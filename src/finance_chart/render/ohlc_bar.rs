@@ -0,0 +1,68 @@
+//! Rendu des barres OHLC (ligne verticale + ticks d'ouverture et de clôture)
+
+use iced::widget::canvas::{self, Frame, Path};
+use iced::{Color, Point};
+
+use super::super::core::Candle;
+use super::super::viewport::Viewport;
+use super::bar_sizing::{calculate_bar_width, calculate_candle_period};
+use super::candlestick::CandleColors;
+
+/// Rend les barres OHLC visibles
+///
+/// Chaque barre est une ligne verticale du bas au haut, avec un tick à gauche
+/// pour l'ouverture et un tick à droite pour la clôture. La longueur des ticks
+/// suit l'espacement des bougies pour éviter les chevauchements en zoom arrière.
+pub fn render_ohlc_bars(
+    frame: &mut Frame,
+    candles: &[Candle],
+    viewport: &Viewport,
+    colors: Option<CandleColors>,
+) {
+    if candles.is_empty() {
+        return;
+    }
+
+    let colors = colors.unwrap_or_default();
+    let price_scale = viewport.price_scale();
+    let time_scale = viewport.time_scale();
+
+    // Même dimensionnement que les bougies : le tick occupe la moitié de la largeur d'une barre
+    let candle_period = calculate_candle_period(candles);
+    let (min_time, max_time) = time_scale.time_range();
+    let bar_width = calculate_bar_width(candle_period, max_time - min_time, viewport.width());
+    let tick_length = (bar_width / 2.0).max(1.0);
+    let line_width = if bar_width >= 6.0 { 1.5 } else { 1.0 };
+
+    for candle in candles {
+        let x = time_scale.time_to_x(candle.timestamp);
+        if x < -bar_width || x > viewport.width() + bar_width {
+            continue;
+        }
+
+        let color: Color = if candle.is_bullish() {
+            colors.bullish
+        } else {
+            colors.bearish
+        };
+
+        let open_y = price_scale.price_to_y(candle.open);
+        let close_y = price_scale.price_to_y(candle.close);
+        let high_y = price_scale.price_to_y(candle.high);
+        let low_y = price_scale.price_to_y(candle.low);
+
+        let bar = Path::new(|builder| {
+            builder.move_to(Point::new(x, high_y));
+            builder.line_to(Point::new(x, low_y));
+            builder.move_to(Point::new(x - tick_length, open_y));
+            builder.line_to(Point::new(x, open_y));
+            builder.move_to(Point::new(x, close_y));
+            builder.line_to(Point::new(x + tick_length, close_y));
+        });
+
+        frame.stroke(
+            &bar,
+            canvas::Stroke::default().with_color(color).with_width(line_width),
+        );
+    }
+}
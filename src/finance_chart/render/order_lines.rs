@@ -3,7 +3,9 @@
 use iced::widget::canvas::{Frame, Path, Stroke, Text};
 use iced::{Color, Point, Size};
 
+use crate::finance_chart::render::draw::dashed_line;
 use crate::finance_chart::viewport::Viewport;
+use crate::finance_chart::settings::{format_price, ChartStyle};
 use crate::app::data::{PendingOrder, Position, TradeType};
 
 /// Dessine les lignes des ordres limit en attente
@@ -12,6 +14,7 @@ pub fn draw_pending_order_lines(
     viewport: &Viewport,
     pending_orders: &[PendingOrder],
     current_symbol: &str,
+    chart_style: &ChartStyle,
 ) {
     for order in pending_orders {
         if order.symbol != current_symbol {
@@ -34,10 +37,16 @@ pub fn draw_pending_order_lines(
         };
         
         // Dessiner une ligne pointillée pour les ordres limit
-        draw_dashed_line(frame, y, width, color, 1.5);
+        draw_order_line(frame, y, width, color, 1.5);
         
-        // Dessiner un label avec le prix limite
-        draw_order_label(frame, y, width, order.limit_price, &format!("Limit {}", if order.trade_type == TradeType::Buy { "BUY" } else { "SELL" }), color);
+        // Dessiner un label avec le prix limite (les ordres OCO indiquent leur rôle)
+        let side = if order.trade_type == TradeType::Buy { "BUY" } else { "SELL" };
+        let label = match (order.oco_group, order.is_stop) {
+            (Some(_), false) => format!("OCO TP {}", side),
+            (Some(_), true) => format!("OCO Stop {}", side),
+            (None, _) => format!("Limit {}", side),
+        };
+        draw_order_label(frame, y, width, &format_price(order.limit_price, chart_style), &label, color);
     }
 }
 
@@ -47,6 +56,7 @@ pub fn draw_tp_sl_lines(
     viewport: &Viewport,
     positions: &[Position],
     current_symbol: &str,
+    chart_style: &ChartStyle,
 ) {
     for position in positions {
         if position.symbol != current_symbol {
@@ -60,8 +70,8 @@ pub fn draw_tp_sl_lines(
             if y >= -10.0 && y <= viewport.height() + 10.0 {
                 let width = viewport.width();
                 let color = Color::from_rgba(0.0, 0.7, 0.0, 0.6); // Vert pour TP
-                draw_dashed_line(frame, y, width, color, 1.0);
-                draw_order_label(frame, y, width, tp, "TP", color);
+                draw_order_line(frame, y, width, color, 1.0);
+                draw_order_label(frame, y, width, &format_price(tp, chart_style), "TP", color);
             }
         }
         
@@ -71,38 +81,47 @@ pub fn draw_tp_sl_lines(
             
             if y >= -10.0 && y <= viewport.height() + 10.0 {
                 let width = viewport.width();
-                let color = Color::from_rgba(0.7, 0.0, 0.0, 0.6); // Rouge pour SL
-                draw_dashed_line(frame, y, width, color, 1.0);
-                draw_order_label(frame, y, width, sl, "SL", color);
+                // Orange pour un stop suiveur, rouge pour un SL fixe
+                let (color, label) = if position.trailing_offset.is_some() {
+                    (Color::from_rgba(0.9, 0.5, 0.0, 0.7), "Trailing SL")
+                } else {
+                    (Color::from_rgba(0.7, 0.0, 0.0, 0.6), "SL")
+                };
+                draw_order_line(frame, y, width, color, 1.0);
+                draw_order_label(frame, y, width, &format_price(sl, chart_style), label, color);
             }
         }
     }
 }
 
-/// Dessine une ligne pointillée
-fn draw_dashed_line(
-    frame: &mut Frame,
-    y: f32,
-    width: f32,
-    color: Color,
-    line_width: f32,
-) {
-    let dash_length = 8.0;
-    let gap_length = 4.0;
-    
-    let mut x = 0.0;
-    while x < width {
-        let end_x = (x + dash_length).min(width);
-        let dash = Path::new(|builder| {
-            builder.move_to(Point::new(x, y));
-            builder.line_to(Point::new(end_x, y));
-        });
-        let stroke = Stroke::default()
-            .with_color(color)
-            .with_width(line_width);
-        frame.stroke(&dash, stroke);
-        x += dash_length + gap_length;
-    }
+/// Longueurs (tiret, espace) des lignes d'ordres, pour les distinguer des lignes de prix pleines
+const ORDER_DASH: (f32, f32) = (8.0, 4.0);
+
+/// Longueurs (tiret, espace) des prévisualisations, plus longues que celles des ordres réels
+const PREVIEW_DASH: (f32, f32) = (12.0, 6.0);
+
+/// Dessine une ligne d'ordre horizontale en tirets sur toute la largeur
+fn draw_order_line(frame: &mut Frame, y: f32, width: f32, color: Color, line_width: f32) {
+    dashed_line(
+        frame,
+        Point::new(0.0, y),
+        Point::new(width, y),
+        ORDER_DASH.0,
+        ORDER_DASH.1,
+        Stroke::default().with_color(color).with_width(line_width),
+    );
+}
+
+/// Dessine une ligne de prévisualisation horizontale en tirets longs
+fn draw_preview_line(frame: &mut Frame, y: f32, width: f32, color: Color) {
+    dashed_line(
+        frame,
+        Point::new(0.0, y),
+        Point::new(width, y),
+        PREVIEW_DASH.0,
+        PREVIEW_DASH.1,
+        Stroke::default().with_color(color).with_width(1.5),
+    );
 }
 
 /// Dessine un label pour un ordre/TP/SL
@@ -110,7 +129,7 @@ fn draw_order_label(
     frame: &mut Frame,
     y: f32,
     width: f32,
-    price: f64,
+    price_label: &str,
     label: &str,
     color: Color,
 ) {
@@ -127,8 +146,7 @@ fn draw_order_label(
     frame.fill(&bg_rect, color);
     
     // Texte du label et prix
-    let price_str = format!("{:.2}", price);
-    let label_text = format!("{} {}", label, price_str);
+    let label_text = format!("{} {}", label, price_label);
     let text = Text {
         content: label_text,
         position: Point::new(badge_x + 4.0, badge_y + 2.0),
@@ -145,6 +163,7 @@ pub fn draw_preview_limit_order_lines(
     frame: &mut Frame,
     viewport: &Viewport,
     limit_price: f64,
+    chart_style: &ChartStyle,
 ) {
     let y = viewport.price_scale().price_to_y(limit_price);
     
@@ -158,24 +177,8 @@ pub fn draw_preview_limit_order_lines(
     // Couleur pour la prévisualisation (jaune/orange pour indiquer que c'est une prévisualisation)
     let preview_color = Color::from_rgba(1.0, 0.8, 0.0, 0.6); // Jaune/orange semi-transparent
     
-    // Dessiner une ligne pointillée pour la prévisualisation (style différent des ordres réels)
-    // Utiliser des tirets plus longs pour distinguer visuellement
-    let dash_length = 12.0;
-    let gap_length = 6.0;
-    
-    let mut x = 0.0;
-    while x < width {
-        let end_x = (x + dash_length).min(width);
-        let dash = Path::new(|builder| {
-            builder.move_to(Point::new(x, y));
-            builder.line_to(Point::new(end_x, y));
-        });
-        let stroke = Stroke::default()
-            .with_color(preview_color)
-            .with_width(1.5);
-        frame.stroke(&dash, stroke);
-        x += dash_length + gap_length;
-    }
+    // Dessiner une ligne pointillée pour la prévisualisation (tirets plus longs que les ordres réels)
+    draw_preview_line(frame, y, width, preview_color);
     
     // Dessiner un label avec "Preview" pour indiquer que c'est une prévisualisation
     let badge_width = 90.0;
@@ -191,7 +194,7 @@ pub fn draw_preview_limit_order_lines(
     frame.fill(&bg_rect, preview_color);
     
     // Texte du label
-    let price_str = format!("{:.2}", limit_price);
+    let price_str = format_price(limit_price, chart_style);
     let label_text = format!("Preview {}", price_str);
     let text = Text {
         content: label_text,
@@ -210,6 +213,7 @@ pub fn draw_preview_tp_sl_lines(
     viewport: &Viewport,
     take_profit: Option<f64>,
     stop_loss: Option<f64>,
+    chart_style: &ChartStyle,
 ) {
     let width = viewport.width();
     
@@ -222,22 +226,7 @@ pub fn draw_preview_tp_sl_lines(
             let tp_color = Color::from_rgba(0.0, 0.7, 0.0, 0.5); // Vert semi-transparent pour prévisualisation
             
             // Dessiner une ligne pointillée pour la prévisualisation
-            let dash_length = 12.0;
-            let gap_length = 6.0;
-            
-            let mut x = 0.0;
-            while x < width {
-                let end_x = (x + dash_length).min(width);
-                let dash = Path::new(|builder| {
-                    builder.move_to(Point::new(x, y));
-                    builder.line_to(Point::new(end_x, y));
-                });
-                let stroke = Stroke::default()
-                    .with_color(tp_color)
-                    .with_width(1.5);
-                frame.stroke(&dash, stroke);
-                x += dash_length + gap_length;
-            }
+            draw_preview_line(frame, y, width, tp_color);
             
             // Dessiner un label avec "Preview TP"
             let badge_width = 85.0;
@@ -251,7 +240,7 @@ pub fn draw_preview_tp_sl_lines(
             );
             frame.fill(&bg_rect, tp_color);
             
-            let price_str = format!("{:.2}", tp);
+            let price_str = format_price(tp, chart_style);
             let label_text = format!("Preview TP {}", price_str);
             let text = Text {
                 content: label_text,
@@ -273,22 +262,7 @@ pub fn draw_preview_tp_sl_lines(
             let sl_color = Color::from_rgba(0.7, 0.0, 0.0, 0.5); // Rouge semi-transparent pour prévisualisation
             
             // Dessiner une ligne pointillée pour la prévisualisation
-            let dash_length = 12.0;
-            let gap_length = 6.0;
-            
-            let mut x = 0.0;
-            while x < width {
-                let end_x = (x + dash_length).min(width);
-                let dash = Path::new(|builder| {
-                    builder.move_to(Point::new(x, y));
-                    builder.line_to(Point::new(end_x, y));
-                });
-                let stroke = Stroke::default()
-                    .with_color(sl_color)
-                    .with_width(1.5);
-                frame.stroke(&dash, stroke);
-                x += dash_length + gap_length;
-            }
+            draw_preview_line(frame, y, width, sl_color);
             
             // Dessiner un label avec "Preview SL"
             let badge_width = 85.0;
@@ -302,7 +276,7 @@ pub fn draw_preview_tp_sl_lines(
             );
             frame.fill(&bg_rect, sl_color);
             
-            let price_str = format!("{:.2}", sl);
+            let price_str = format_price(sl, chart_style);
             let label_text = format!("Preview SL {}", price_str);
             let text = Text {
                 content: label_text,
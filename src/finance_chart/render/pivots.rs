@@ -0,0 +1,81 @@
+//! Rendu des points pivots sur le graphique principal
+//!
+//! Chaque jour affiche ses niveaux (P, R1–R3, S1–S3) sur sa propre plage horaire,
+//! avec un libellé au début du segment.
+
+use iced::widget::canvas::{Frame, Text};
+use iced::{Color, Point};
+
+use crate::finance_chart::viewport::Viewport;
+use crate::finance_chart::indicators::pivots::DailyPivots;
+use super::horizontal_line::draw_level_segment;
+
+/// Style pour les points pivots
+pub struct PivotStyle {
+    pub pivot_color: Color,       // Couleur du pivot central
+    pub resistance_color: Color,  // Couleur des résistances R1–R3
+    pub support_color: Color,     // Couleur des supports S1–S3
+    pub line_width: f32,          // Épaisseur des lignes
+}
+
+impl Default for PivotStyle {
+    fn default() -> Self {
+        Self {
+            pivot_color: Color::from_rgba(0.9, 0.9, 0.9, 0.8),
+            resistance_color: Color::from_rgba(0.9, 0.3, 0.3, 0.7),
+            support_color: Color::from_rgba(0.3, 0.8, 0.4, 0.7),
+            line_width: 1.0,
+        }
+    }
+}
+
+/// Rend les points pivots sur le graphique principal
+///
+/// # Arguments
+/// * `frame` - Frame de rendu Iced
+/// * `viewport` - Viewport pour les conversions de coordonnées
+/// * `pivots` - Pivots de chaque jour (seuls les jours visibles sont dessinés)
+/// * `style` - Style optionnel pour personnaliser les couleurs
+pub fn render_pivots(
+    frame: &mut Frame,
+    viewport: &Viewport,
+    pivots: &[DailyPivots],
+    style: Option<PivotStyle>,
+) {
+    let style = style.unwrap_or_default();
+    let width = viewport.width();
+    let height = viewport.height();
+
+    for day in pivots {
+        let x_start = viewport.time_scale().time_to_x(day.day_start).max(0.0);
+        let x_end = viewport.time_scale().time_to_x(day.day_end).min(width);
+        if x_end <= x_start {
+            continue;
+        }
+
+        for (label, price) in day.levels.labeled() {
+            let y = viewport.price_scale().price_to_y(price);
+            if y < 0.0 || y > height {
+                continue;
+            }
+
+            let (color, dashed) = match label {
+                "P" => (style.pivot_color, false),
+                l if l.starts_with('R') => (style.resistance_color, true),
+                _ => (style.support_color, true),
+            };
+            draw_level_segment(frame, y, x_start, x_end, color, style.line_width, dashed);
+
+            // Libellé uniquement si le segment est assez large pour le lire
+            if x_end - x_start > 30.0 {
+                frame.fill_text(Text {
+                    content: label.to_string(),
+                    position: Point::new(x_start + 3.0, y - 13.0),
+                    color,
+                    size: iced::Pixels(10.0),
+                    ..Text::default()
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Rendu du Parabolic SAR sur le graphique principal
+
+use iced::widget::canvas::{Frame, Path};
+use iced::{Color, Point};
+
+use crate::finance_chart::viewport::Viewport;
+use crate::finance_chart::core::Candle;
+use crate::finance_chart::indicators::psar::PsarValue;
+
+/// Style pour le Parabolic SAR
+pub struct PsarStyle {
+    pub uptrend_color: Color,    // Couleur des points sous les bougies (tendance haussière)
+    pub downtrend_color: Color,  // Couleur des points au-dessus des bougies (tendance baissière)
+    pub dot_radius: f32,         // Rayon des points
+}
+
+impl Default for PsarStyle {
+    fn default() -> Self {
+        Self {
+            uptrend_color: Color::from_rgba(0.2, 0.8, 0.4, 0.9),
+            downtrend_color: Color::from_rgba(0.9, 0.3, 0.3, 0.9),
+            dot_radius: 2.0,
+        }
+    }
+}
+
+/// Rend le Parabolic SAR sur le graphique principal
+///
+/// # Arguments
+/// * `frame` - Frame de rendu Iced
+/// * `viewport` - Viewport pour les conversions de coordonnées
+/// * `candles` - Bougies visibles sur le graphique
+/// * `psar_values` - Valeurs SAR pré-calculées correspondant aux bougies visibles
+/// * `style` - Style optionnel pour personnaliser les couleurs et la taille des points
+pub fn render_psar(
+    frame: &mut Frame,
+    viewport: &Viewport,
+    candles: &[Candle],
+    psar_values: &[Option<PsarValue>],
+    style: Option<PsarStyle>,
+) {
+    if candles.is_empty() || psar_values.is_empty() {
+        return;
+    }
+
+    let style = style.unwrap_or_default();
+
+    for (candle, psar_opt) in candles.iter().zip(psar_values.iter()) {
+        let Some(psar) = psar_opt else {
+            continue;
+        };
+
+        let x = viewport.time_scale().time_to_x(candle.timestamp);
+
+        // Ne dessiner que les points visibles
+        if x < -10.0 || x > viewport.width() + 10.0 {
+            continue;
+        }
+
+        let y = viewport.price_scale().price_to_y(psar.sar);
+        let color = if psar.is_uptrend {
+            style.uptrend_color
+        } else {
+            style.downtrend_color
+        };
+
+        let dot = Path::circle(Point::new(x, y), style.dot_radius);
+        frame.fill(&dot, color);
+    }
+}
@@ -1,11 +1,14 @@
 //! Rendu des rectangles dessinés sur le graphique
 
-use iced::widget::canvas::{self, Frame, Path};
+use iced::widget::canvas::{self, Frame, LineDash, Path, Text};
 use iced::{Color, Point, Size};
 
 use crate::finance_chart::tools::{DrawnRectangle, HANDLE_SIZE};
 use crate::finance_chart::viewport::Viewport;
 
+/// Taille du texte affiché dans les rectangles
+const LABEL_TEXT_SIZE: f32 = 12.0;
+
 /// Dessine un rectangle avec sa bordure et ses poignées si sélectionné
 pub fn draw_rectangle(
     frame: &mut Frame,
@@ -40,17 +43,18 @@ pub fn draw_rectangle(
         Point::new(min_x, min_y),
         Size::new(width, height),
     );
-    frame.fill(&rect_path, rect.color);
+    let fill_color = rect.fill_color();
+    frame.fill(&rect_path, fill_color);
 
     // Dessiner la bordure (plus opaque si sélectionné)
     let border_color = if is_selected {
         Color::from_rgba(1.0, 1.0, 1.0, 1.0) // Blanc si sélectionné
     } else {
         Color::from_rgba(
-            rect.color.r,
-            rect.color.g,
-            rect.color.b,
-            (rect.color.a * 2.5).min(1.0),
+            fill_color.r,
+            fill_color.g,
+            fill_color.b,
+            (fill_color.a * 2.5).clamp(0.5, 1.0),
         )
     };
     let stroke = canvas::Stroke::default()
@@ -58,6 +62,24 @@ pub fn draw_rectangle(
         .with_width(if is_selected { 2.0 } else { 1.5 });
     frame.stroke(&rect_path, stroke);
 
+    // Texte centré, recalculé à chaque rendu pour suivre le redimensionnement
+    if let Some(label) = rect.label.as_deref().filter(|label| !label.is_empty()) {
+        // Largeur estimée : ~0.6 × taille de police par caractère
+        let text_width = label.chars().count() as f32 * LABEL_TEXT_SIZE * 0.6;
+        if text_width <= width && LABEL_TEXT_SIZE <= height {
+            frame.fill_text(Text {
+                content: label.to_string(),
+                position: Point::new(
+                    min_x + (width - text_width) / 2.0,
+                    min_y + (height - LABEL_TEXT_SIZE * 1.2) / 2.0,
+                ),
+                color: Color { a: 1.0, ..fill_color },
+                size: iced::Pixels(LABEL_TEXT_SIZE),
+                ..Text::default()
+            });
+        }
+    }
+
     // Dessiner les poignées de redimensionnement si sélectionné
     if is_selected {
         draw_handles(frame, min_x, min_y, max_x, max_y);
@@ -126,3 +148,36 @@ fn draw_handles(frame: &mut Frame, min_x: f32, min_y: f32, max_x: f32, max_y: f3
     }
 }
 
+/// Dessine le rectangle de sélection du zoom (Shift + glisser)
+pub fn draw_zoom_selection(
+    frame: &mut Frame,
+    start_x: f32,
+    start_y: f32,
+    current_x: f32,
+    current_y: f32,
+) {
+    let min_x = start_x.min(current_x);
+    let min_y = start_y.min(current_y);
+    let width = (current_x - start_x).abs();
+    let height = (current_y - start_y).abs();
+
+    if width > 1.0 && height > 1.0 {
+        let selection = Path::rectangle(
+            Point::new(min_x, min_y),
+            Size::new(width, height),
+        );
+        frame.fill(&selection, Color::from_rgba(0.8, 0.8, 0.8, 0.08));
+
+        // Bordure pointillée pour la distinguer d'un rectangle dessiné
+        let stroke = canvas::Stroke {
+            line_dash: LineDash {
+                segments: &[4.0, 4.0],
+                offset: 0,
+            },
+            ..canvas::Stroke::default()
+                .with_color(Color::from_rgba(0.9, 0.9, 0.9, 0.8))
+                .with_width(1.0)
+        };
+        frame.stroke(&selection, stroke);
+    }
+}
@@ -0,0 +1,65 @@
+//! Rendu des briques Renko
+//!
+//! Les briques sont réparties régulièrement sur la largeur du graphique :
+//! l'axe du temps ne sert qu'à choisir les briques visibles (celles formées
+//! dans la plage temporelle du viewport).
+
+use std::ops::Range;
+
+use iced::widget::canvas::{Frame, Path};
+use iced::{Point, Size};
+
+use crate::finance_chart::core::{RenkoBrick, RenkoDirection};
+use crate::finance_chart::viewport::Viewport;
+use super::candlestick::CandleColors;
+
+/// Espacement entre deux briques (en pixels)
+const BRICK_GAP: f32 = 1.0;
+
+/// Retourne la plage d'indices des briques formées dans la plage temporelle visible
+pub fn visible_renko_range(bricks: &[RenkoBrick], viewport: &Viewport) -> Range<usize> {
+    let (min_time, max_time) = viewport.time_scale().time_range();
+    let start = bricks.partition_point(|b| b.timestamp < min_time);
+    let end = bricks.partition_point(|b| b.timestamp <= max_time);
+    start..end.max(start)
+}
+
+/// Largeur allouée à chaque brique pour une plage visible donnée
+pub fn renko_brick_width(visible: &Range<usize>, viewport: &Viewport) -> f32 {
+    let count = visible.len().max(1);
+    viewport.width() / count as f32
+}
+
+/// Rend les briques Renko visibles
+pub fn render_renko(
+    frame: &mut Frame,
+    bricks: &[RenkoBrick],
+    viewport: &Viewport,
+    colors: Option<CandleColors>,
+) {
+    let visible = visible_renko_range(bricks, viewport);
+    if visible.is_empty() {
+        return;
+    }
+
+    let colors = colors.unwrap_or_default();
+    let price_scale = viewport.price_scale();
+    let slot_width = renko_brick_width(&visible, viewport);
+    let gap = if slot_width > BRICK_GAP * 3.0 { BRICK_GAP } else { 0.0 };
+
+    for (slot, brick) in bricks[visible].iter().enumerate() {
+        let x = slot as f32 * slot_width;
+        let top = price_scale.price_to_y(brick.price_high);
+        let bottom = price_scale.price_to_y(brick.price_low);
+        let color = match brick.direction {
+            RenkoDirection::Up => colors.bullish,
+            RenkoDirection::Down => colors.bearish,
+        };
+
+        let rect = Path::rectangle(
+            Point::new(x + gap, top.min(bottom)),
+            Size::new((slot_width - gap * 2.0).max(1.0), (bottom - top).abs().max(1.0)),
+        );
+        frame.fill(&rect, color);
+    }
+}
@@ -0,0 +1,135 @@
+//! Rendu des sessions de trading (bandes verticales translucides en fond)
+
+use iced::{Point, Size};
+use iced::widget::canvas::Frame;
+
+use super::super::settings::SessionConfig;
+use super::super::viewport::Viewport;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Au-delà de ce nombre de jours visibles, les bandes ne sont plus lisibles
+const MAX_VISIBLE_DAYS: i64 = 400;
+
+/// Convertit une heure HHMM en secondes depuis minuit (None si invalide)
+fn hhmm_to_seconds(hhmm: u16) -> Option<i64> {
+    let (hours, minutes) = (hhmm / 100, hhmm % 100);
+    if hours > 24 || minutes >= 60 || (hours == 24 && minutes > 0) {
+        return None;
+    }
+    Some(hours as i64 * 3600 + minutes as i64 * 60)
+}
+
+/// Calcule les bandes (début, fin, index de session) visibles dans `time_range`
+///
+/// Les bandes sont répétées pour chaque jour de la plage et tronquées aux bords.
+pub fn session_bands(time_range: (i64, i64), config: &SessionConfig) -> Vec<(i64, i64, usize)> {
+    let (min_time, max_time) = time_range;
+    if max_time <= min_time {
+        return Vec::new();
+    }
+
+    let offset = config.utc_offset_minutes as i64 * 60;
+    // Commencer la veille pour inclure les sessions qui débordent sur minuit
+    let first_day = (min_time + offset).div_euclid(SECONDS_PER_DAY) - 1;
+    let last_day = (max_time + offset).div_euclid(SECONDS_PER_DAY);
+    if last_day - first_day > MAX_VISIBLE_DAYS {
+        return Vec::new();
+    }
+
+    let mut bands = Vec::new();
+    for (index, &(start_hhmm, end_hhmm, _)) in config.sessions.iter().enumerate() {
+        let (Some(start), Some(mut end)) = (hhmm_to_seconds(start_hhmm), hhmm_to_seconds(end_hhmm)) else {
+            continue;
+        };
+        if start == end {
+            continue;
+        }
+        if end < start {
+            end += SECONDS_PER_DAY;
+        }
+
+        for day in first_day..=last_day {
+            let day_start = day * SECONDS_PER_DAY - offset;
+            let band_start = (day_start + start).max(min_time);
+            let band_end = (day_start + end).min(max_time);
+            if band_end > band_start {
+                bands.push((band_start, band_end, index));
+            }
+        }
+    }
+    bands
+}
+
+/// Dessine les sessions de trading en bandes verticales sur toute la hauteur
+pub fn render_sessions(frame: &mut Frame, viewport: &Viewport, session_config: &SessionConfig) {
+    if !session_config.enabled {
+        return;
+    }
+
+    let time_scale = viewport.time_scale();
+    for (start, end, index) in session_bands(time_scale.time_range(), session_config) {
+        let x_start = time_scale.time_to_x(start);
+        let x_end = time_scale.time_to_x(end);
+        let color = session_config.sessions[index].2.to_iced();
+        frame.fill_rectangle(
+            Point::new(x_start, 0.0),
+            Size::new((x_end - x_start).max(1.0), viewport.height()),
+            color,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::finance_chart::settings::SerializableColor;
+
+    fn config(sessions: Vec<(u16, u16)>, utc_offset_minutes: i32) -> SessionConfig {
+        SessionConfig {
+            enabled: true,
+            utc_offset_minutes,
+            sessions: sessions
+                .into_iter()
+                .map(|(start, end)| (start, end, SerializableColor::from_rgba(1.0, 1.0, 1.0, 0.1)))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_bands_tile_across_days() {
+        // Deux jours complets à partir du 2023-11-14 00:00 UTC
+        let day = 1_699_920_000;
+        let bands = session_bands((day, day + 2 * SECONDS_PER_DAY), &config(vec![(800, 1700)], 0));
+        assert_eq!(bands, vec![
+            (day + 8 * 3600, day + 17 * 3600, 0),
+            (day + SECONDS_PER_DAY + 8 * 3600, day + SECONDS_PER_DAY + 17 * 3600, 0),
+        ]);
+    }
+
+    #[test]
+    fn test_bands_clip_to_viewport() {
+        let day = 1_699_920_000;
+        let bands = session_bands((day + 10 * 3600, day + 12 * 3600), &config(vec![(800, 1700)], 0));
+        assert_eq!(bands, vec![(day + 10 * 3600, day + 12 * 3600, 0)]);
+    }
+
+    #[test]
+    fn test_overnight_session_and_offset() {
+        let day = 1_699_920_000;
+        // 22:00 → 06:00 : la session de la veille déborde sur le début de la plage
+        let bands = session_bands((day, day + 12 * 3600), &config(vec![(2200, 600)], 0));
+        assert_eq!(bands, vec![(day, day + 6 * 3600, 0)]);
+
+        // UTC+2 : 08:00 locale = 06:00 UTC
+        let bands = session_bands((day, day + 12 * 3600), &config(vec![(800, 900)], 120));
+        assert_eq!(bands, vec![(day + 6 * 3600, day + 7 * 3600, 0)]);
+    }
+
+    #[test]
+    fn test_invalid_sessions_are_skipped() {
+        let day = 1_699_920_000;
+        let bands = session_bands((day, day + SECONDS_PER_DAY), &config(vec![(2500, 900), (800, 800), (870, 900)], 0));
+        assert!(bands.is_empty());
+    }
+}
@@ -0,0 +1,74 @@
+//! Rendu des annotations texte ancrées au graphique
+
+use iced::widget::canvas::{Frame, Path, Stroke, Text};
+use iced::{Color, Point, Size};
+
+use crate::finance_chart::tools::DrawnText;
+use crate::finance_chart::viewport::Viewport;
+
+/// Marge autour du texte pour la sélection et le hit-test
+const TEXT_PADDING: f32 = 3.0;
+
+/// Calcule la boîte englobante approximative d'une annotation (coin haut-gauche, taille)
+fn text_bounds(viewport: &Viewport, text: &DrawnText) -> (Point, Size) {
+    let x = viewport.time_scale().time_to_x(text.time);
+    let y = viewport.price_scale().price_to_y(text.price);
+    // Largeur estimée : ~0.6 × taille de police par caractère
+    let width = text.content.chars().count() as f32 * text.size * 0.6;
+    let height = text.size * 1.2;
+    (
+        Point::new(x - TEXT_PADDING, y - TEXT_PADDING),
+        Size::new(width + 2.0 * TEXT_PADDING, height + 2.0 * TEXT_PADDING),
+    )
+}
+
+/// Dessine une annotation texte à sa position (temps, prix)
+pub fn draw_text_annotation(
+    frame: &mut Frame,
+    viewport: &Viewport,
+    text: &DrawnText,
+    is_selected: bool,
+) {
+    let (origin, size) = text_bounds(viewport, text);
+
+    // Ne pas dessiner si hors de vue
+    if origin.x + size.width < 0.0 || origin.x > viewport.width()
+        || origin.y + size.height < 0.0 || origin.y > viewport.height() {
+        return;
+    }
+
+    if is_selected {
+        let stroke = Stroke::default()
+            .with_color(Color::WHITE)
+            .with_width(1.0);
+        frame.stroke(&Path::rectangle(origin, size), stroke);
+    }
+
+    frame.fill_text(Text {
+        content: text.content.clone(),
+        position: Point::new(origin.x + TEXT_PADDING, origin.y + TEXT_PADDING),
+        color: text.color,
+        size: iced::Pixels(text.size),
+        ..Text::default()
+    });
+}
+
+/// Hit-test pour les annotations texte
+pub fn hit_test_text(
+    position: Point,
+    texts: &[DrawnText],
+    viewport: &Viewport,
+) -> Option<usize> {
+    for (index, text) in texts.iter().enumerate().rev() {
+        if text.locked || text.hidden {
+            continue;
+        }
+        let (origin, size) = text_bounds(viewport, text);
+        if position.x >= origin.x && position.x <= origin.x + size.width
+            && position.y >= origin.y && position.y <= origin.y + size.height {
+            return Some(index);
+        }
+    }
+
+    None
+}
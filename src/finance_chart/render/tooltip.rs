@@ -6,6 +6,7 @@ use chrono::{DateTime, Utc, TimeZone};
 
 use crate::finance_chart::core::Candle;
 use crate::finance_chart::viewport::Viewport;
+use crate::finance_chart::settings::{format_abbreviated, format_price, ChartStyle, TooltipConfig, TooltipField};
 
 /// Style du tooltip
 pub struct TooltipStyle {
@@ -61,18 +62,29 @@ pub fn find_candle_at_position<'a>(
 }
 
 /// Dessine le tooltip OHLC
+///
+/// Les lignes suivent `config` (champs et ordre). `previous` est la bougie qui
+/// précède `candle`, nécessaire à la variation par rapport à la clôture précédente.
+/// Les prix suivent la précision de `chart_style`.
+#[allow(clippy::too_many_arguments)]
 pub fn render_tooltip(
     frame: &mut Frame,
     candle: &Candle,
+    previous: Option<&Candle>,
+    config: &TooltipConfig,
+    chart_style: &ChartStyle,
     mouse_position: Point,
     viewport: &Viewport,
     style: Option<TooltipStyle>,
 ) {
+    if config.fields.is_empty() {
+        return;
+    }
     let style = style.unwrap_or_default();
     
     let line_height = style.text_size + 4.0;
     let tooltip_width = 140.0;
-    let tooltip_height = line_height * 6.0 + style.padding * 2.0;
+    let tooltip_height = line_height * config.fields.len() as f32 + style.padding * 2.0;
 
     // Positionner le tooltip (éviter de sortir du viewport)
     let mut tooltip_x = mouse_position.x + 15.0;
@@ -105,50 +117,54 @@ pub fn render_tooltip(
     let mut y = tooltip_y + style.padding;
     let x = tooltip_x + style.padding;
 
-    // Date/Heure
-    let datetime: DateTime<Utc> = Utc.timestamp_opt(candle.timestamp, 0)
-        .single()
-        .unwrap_or_else(|| Utc.timestamp_opt(0, 0).unwrap());
-    let date_str = datetime.format("%d/%m/%Y %H:%M").to_string();
-    draw_text_line(frame, &date_str, x, y, style.text_color, style.text_size);
-    y += line_height;
-
-    // Open
-    let open_str = format!("O: {}", format_price(candle.open));
-    draw_text_line(frame, &open_str, x, y, style.text_color, style.text_size);
-    y += line_height;
-
-    // High
-    let high_str = format!("H: {}", format_price(candle.high));
-    draw_text_line(frame, &high_str, x, y, style.text_color, style.text_size);
-    y += line_height;
-
-    // Low
-    let low_str = format!("L: {}", format_price(candle.low));
-    draw_text_line(frame, &low_str, x, y, style.text_color, style.text_size);
-    y += line_height;
-
-    // Close
-    let close_str = format!("C: {}", format_price(candle.close));
-    draw_text_line(frame, &close_str, x, y, style.text_color, style.text_size);
-    y += line_height;
-
-    // Change %
-    let change_pct = ((candle.close - candle.open) / candle.open) * 100.0;
-    let change_str = format!("{:+.2}%", change_pct);
-    let change_color = if candle.is_bullish() {
-        style.bullish_color
-    } else {
-        style.bearish_color
-    };
-    draw_text_line(frame, &change_str, x, y, change_color, style.text_size);
+    for field in &config.fields {
+        let (content, color) = tooltip_line(*field, candle, previous, chart_style, &style);
+        draw_text_line(frame, &content, x, y, color, style.text_size);
+        y += line_height;
+    }
 }
 
-use super::utils::format_price_detailed;
+/// Texte et couleur d'une ligne du tooltip
+fn tooltip_line(
+    field: TooltipField,
+    candle: &Candle,
+    previous: Option<&Candle>,
+    chart_style: &ChartStyle,
+    style: &TooltipStyle,
+) -> (String, Color) {
+    let trend_color = |positive: bool| if positive { style.bullish_color } else { style.bearish_color };
+    match field {
+        TooltipField::Timestamp => {
+            let datetime: DateTime<Utc> = Utc.timestamp_opt(candle.timestamp, 0)
+                .single()
+                .unwrap_or_else(|| Utc.timestamp_opt(0, 0).unwrap());
+            (datetime.format("%d/%m/%Y %H:%M").to_string(), style.text_color)
+        }
+        TooltipField::Open => (format!("O: {}", format_price(candle.open, chart_style)), style.text_color),
+        TooltipField::High => (format!("H: {}", format_price(candle.high, chart_style)), style.text_color),
+        TooltipField::Low => (format!("L: {}", format_price(candle.low, chart_style)), style.text_color),
+        TooltipField::Close => (format!("C: {}", format_price(candle.close, chart_style)), style.text_color),
+        TooltipField::Volume => (format!("V: {}", format_abbreviated(candle.volume)), style.text_color),
+        TooltipField::ChangePercent => {
+            let change_pct = ((candle.close - candle.open) / candle.open) * 100.0;
+            (format!("{:+.2}%", change_pct), trend_color(candle.is_bullish()))
+        }
+        TooltipField::ChangeAbs => {
+            let change = candle.close - candle.open;
+            let sign = if change < 0.0 { "-" } else { "+" };
+            (format!("{}{}", sign, format_price(change.abs(), chart_style)), trend_color(candle.is_bullish()))
+        }
+        TooltipField::PreviousChangePercent => match previous_change_percent(candle, previous) {
+            Some(change_pct) => (format!("Préc.: {:+.2}%", change_pct), trend_color(change_pct >= 0.0)),
+            None => ("Préc.: —".to_string(), style.text_color),
+        },
+    }
+}
 
-/// Formate un prix pour l'affichage
-fn format_price(price: f64) -> String {
-    format_price_detailed(price)
+/// Variation en % de la clôture par rapport à la clôture précédente
+fn previous_change_percent(candle: &Candle, previous: Option<&Candle>) -> Option<f64> {
+    let previous = previous.filter(|p| p.close != 0.0)?;
+    Some((candle.close - previous.close) / previous.close * 100.0)
 }
 
 /// Dessine une ligne de texte
@@ -163,3 +179,16 @@ fn draw_text_line(frame: &mut Frame, content: &str, x: f32, y: f32, color: Color
     frame.fill_text(text);
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_previous_change_percent() {
+        let previous = Candle::new(0, 90.0, 101.0, 89.0, 100.0, 1.0);
+        let candle = Candle::new(60, 100.0, 112.0, 99.0, 110.0, 1.0);
+        let change = previous_change_percent(&candle, Some(&previous)).unwrap();
+        assert!((change - 10.0).abs() < 1e-9);
+        assert!(previous_change_percent(&candle, None).is_none());
+    }
+}
@@ -1,9 +1,124 @@
 //! Rendu des marqueurs de trades sur le graphique
+//!
+//! Les trades d'un même créneau de bougies sont regroupés en un badge (nombre
+//! de trades, couleur du sens net) ; le survol d'un badge liste ses trades.
+//! Les positions clôturées peuvent être reliées de l'entrée à la sortie, avec
+//! leur P&L au milieu du segment.
 
-use iced::widget::canvas::{Frame, Path, Stroke};
-use iced::{Color, Point};
+use std::collections::BTreeMap;
+
+use iced::widget::canvas::{Frame, Path, Stroke, Text};
+use iced::{Color, Point, Size};
+use chrono::{DateTime, TimeZone, Utc};
+use crate::finance_chart::scale::TimeScale;
+use crate::finance_chart::settings::{format_price, ChartStyle};
 use crate::finance_chart::viewport::Viewport;
-use crate::app::data::{Trade, TradeType};
+use crate::app::data::{ClosedTrade, Trade, TradeType};
+use super::draw::clip_segment;
+use super::tooltip::TooltipStyle;
+
+/// Espacement minimal en pixels entre deux badges : en dessous, les créneaux couvrent plusieurs bougies
+const MIN_MARKER_SPACING_PX: f32 = 18.0;
+
+/// Rayon du badge d'un groupe de trades
+const CLUSTER_RADIUS: f32 = 9.0;
+
+/// Nombre maximal de trades listés dans le tooltip d'un groupe
+const CLUSTER_TOOLTIP_MAX_LINES: usize = 12;
+
+/// Trades d'un même créneau de bougies, dessinés comme un seul marqueur
+#[derive(Debug, Clone)]
+pub struct TradeCluster<'a> {
+    /// Timestamp où placer le marqueur (bougie centrale du créneau)
+    pub timestamp: i64,
+    /// Trades du créneau, dans l'ordre chronologique
+    pub trades: Vec<&'a Trade>,
+}
+
+impl TradeCluster<'_> {
+    /// Quantité nette du groupe (achats - ventes)
+    pub fn net_quantity(&self) -> f64 {
+        self.trades.iter()
+            .map(|trade| match trade.trade_type {
+                TradeType::Buy => trade.quantity,
+                TradeType::Sell => -trade.quantity,
+            })
+            .sum()
+    }
+
+    /// Sens net du groupe (None si achats et ventes s'équilibrent)
+    pub fn net_direction(&self) -> Option<TradeType> {
+        let net = self.net_quantity();
+        if net > f64::EPSILON {
+            Some(TradeType::Buy)
+        } else if net < -f64::EPSILON {
+            Some(TradeType::Sell)
+        } else {
+            None
+        }
+    }
+
+    /// Prix moyen pondéré par les quantités (position verticale du badge)
+    pub fn average_price(&self) -> f64 {
+        let quantity: f64 = self.trades.iter().map(|trade| trade.quantity).sum();
+        if quantity <= 0.0 {
+            return self.trades.iter().map(|trade| trade.price).sum::<f64>() / self.trades.len().max(1) as f64;
+        }
+        self.trades.iter().map(|trade| trade.price * trade.quantity).sum::<f64>() / quantity
+    }
+
+    /// Position du marqueur à l'écran
+    fn position(&self, viewport: &Viewport) -> Point {
+        Point::new(
+            viewport.time_scale().time_to_x(self.timestamp),
+            viewport.price_scale().price_to_y(self.average_price()),
+        )
+    }
+}
+
+/// Durée d'un créneau de regroupement
+///
+/// Une bougie tant que les bougies sont espacées d'au moins `MIN_MARKER_SPACING_PX`,
+/// sinon le nombre de bougies nécessaire pour atteindre cet espacement.
+fn cluster_slot(time_scale: &TimeScale, candle_period: i64) -> i64 {
+    let candle_period = candle_period.max(1);
+    let spacing = time_scale.duration_to_width(candle_period);
+    if spacing <= 0.0 {
+        return candle_period;
+    }
+    let candles_per_slot = (MIN_MARKER_SPACING_PX / spacing).ceil().max(1.0) as i64;
+    candle_period * candles_per_slot
+}
+
+/// Regroupe par créneau de `slot` secondes les trades de `current_symbol` visibles dans `time_range`
+///
+/// Les créneaux sont alignés sur des multiples de `slot` : avec un créneau d'une
+/// bougie, chaque groupe correspond à la bougie dont le trade est issu.
+fn cluster_trades<'a>(
+    trades: &'a [Trade],
+    current_symbol: &str,
+    (min_time, max_time): (i64, i64),
+    slot: i64,
+    candle_period: i64,
+) -> Vec<TradeCluster<'a>> {
+    let slot = slot.max(1);
+    let candle_period = candle_period.clamp(1, slot);
+    let mut slots: BTreeMap<i64, Vec<&'a Trade>> = BTreeMap::new();
+    for trade in trades {
+        if trade.symbol == current_symbol && trade.timestamp >= min_time && trade.timestamp <= max_time {
+            slots.entry(trade.timestamp.div_euclid(slot)).or_default().push(trade);
+        }
+    }
+
+    // Bougie centrale du créneau
+    let center_offset = (slot / candle_period / 2) * candle_period;
+    slots.into_iter()
+        .map(|(index, mut trades)| {
+            trades.sort_by_key(|trade| trade.timestamp);
+            TradeCluster { timestamp: index * slot + center_offset, trades }
+        })
+        .collect()
+}
 
 /// Dessine un marqueur de trade sur le graphique
 pub fn draw_trade_marker(
@@ -65,26 +180,242 @@ pub fn draw_trade_marker(
     frame.stroke(&marker_path, stroke);
 }
 
+/// Groupes des trades de `current_symbol` visibles dans le viewport
+///
+/// `candle_period` est l'intervalle entre bougies de la série affichée.
+pub fn visible_trade_clusters<'a>(
+    viewport: &Viewport,
+    trades: &'a [Trade],
+    current_symbol: &str,
+    candle_period: i64,
+) -> Vec<TradeCluster<'a>> {
+    let time_scale = viewport.time_scale();
+    let slot = cluster_slot(time_scale, candle_period);
+    cluster_trades(trades, current_symbol, time_scale.time_range(), slot, candle_period)
+}
+
+/// Dessine le badge d'un groupe de trades : nombre de trades sur la couleur du sens net
+fn draw_cluster_badge(frame: &mut Frame, viewport: &Viewport, cluster: &TradeCluster) {
+    let center = cluster.position(viewport);
+    let bounds = frame.size();
+    if center.x < -20.0 || center.x > bounds.width + 20.0 || center.y < -20.0 || center.y > bounds.height + 20.0 {
+        return;
+    }
+
+    let color = match cluster.net_direction() {
+        Some(TradeType::Buy) => Color::from_rgb(0.0, 0.8, 0.0),
+        Some(TradeType::Sell) => Color::from_rgb(0.8, 0.0, 0.0),
+        None => Color::from_rgb(0.5, 0.5, 0.5),
+    };
+    let badge = Path::circle(center, CLUSTER_RADIUS);
+    frame.fill(&badge, color);
+    frame.stroke(&badge, Stroke::default().with_color(Color::from_rgb(0.1, 0.1, 0.1)).with_width(1.0));
+
+    frame.fill_text(Text {
+        content: cluster.trades.len().to_string(),
+        position: center,
+        color: Color::WHITE,
+        size: iced::Pixels(11.0),
+        align_x: iced::alignment::Horizontal::Center.into(),
+        align_y: iced::alignment::Vertical::Center,
+        ..Text::default()
+    });
+}
+
 /// Dessine tous les marqueurs de trades visibles
+///
+/// Les trades d'un même créneau (une bougie, ou plusieurs quand le zoom est
+/// large) sont regroupés en un badge ; un trade seul garde son triangle.
 pub fn render_trade_markers(
     frame: &mut Frame,
     viewport: &Viewport,
     trades: &[Trade],
     current_symbol: &str,
+    candle_period: i64,
 ) {
-    // Filtrer les trades pour le symbole actuel et qui sont dans le viewport
-    let (min_time, max_time) = viewport.time_scale().time_range();
-    
-    for trade in trades {
-        // Filtrer par symbole
-        if trade.symbol != current_symbol {
-            continue;
+    for cluster in visible_trade_clusters(viewport, trades, current_symbol, candle_period) {
+        match cluster.trades.as_slice() {
+            [trade] => draw_trade_marker(frame, viewport, trade),
+            _ => draw_cluster_badge(frame, viewport, &cluster),
         }
-        
-        // Vérifier si le trade est dans la plage temporelle visible
-        if trade.timestamp >= min_time && trade.timestamp <= max_time {
-            draw_trade_marker(frame, viewport, trade);
+    }
+}
+
+/// Relie l'entrée et la sortie de chaque position clôturée, coloré selon le P&L
+///
+/// Le segment est coupé aux bords du graphique quand l'entrée ou la sortie est
+/// hors écran ; l'étiquette du P&L est placée au milieu de la partie visible.
+pub fn render_pnl_connectors(
+    frame: &mut Frame,
+    viewport: &Viewport,
+    closed_trades: &[ClosedTrade],
+    chart_style: &ChartStyle,
+) {
+    let time_scale = viewport.time_scale();
+    let price_scale = viewport.price_scale();
+    for closed in closed_trades {
+        let entry = Point::new(time_scale.time_to_x(closed.entry_timestamp), price_scale.price_to_y(closed.entry_price));
+        let exit = Point::new(time_scale.time_to_x(closed.exit_timestamp), price_scale.price_to_y(closed.exit_price));
+        let Some((from, to)) = clip_segment(entry, exit, frame.size()) else {
+            continue;
+        };
+
+        let color = if closed.pnl >= 0.0 {
+            chart_style.bullish_color.to_iced()
+        } else {
+            chart_style.bearish_color.to_iced()
+        };
+        let connector = Path::line(from, to);
+        frame.stroke(&connector, Stroke::default().with_color(Color { a: 0.8, ..color }).with_width(1.5));
+
+        let label = format!("{:+.2}", closed.pnl);
+        let text_size = 10.0;
+        let label_width = label.chars().count() as f32 * text_size * 0.6 + 6.0;
+        let middle = Point::new((from.x + to.x) / 2.0, (from.y + to.y) / 2.0);
+        let background = Path::rectangle(
+            Point::new(middle.x - label_width / 2.0, middle.y - text_size / 2.0 - 3.0),
+            Size::new(label_width, text_size + 6.0),
+        );
+        frame.fill(&background, Color { a: 0.85, ..color });
+        frame.fill_text(Text {
+            content: label,
+            position: Point::new(middle.x - label_width / 2.0 + 3.0, middle.y - text_size / 2.0 - 1.0),
+            color: Color::WHITE,
+            size: iced::Pixels(text_size),
+            ..Text::default()
+        });
+    }
+}
+
+/// Groupe de plusieurs trades dont le badge est sous `position`
+pub fn find_cluster_at<'c, 'a>(
+    position: Point,
+    clusters: &'c [TradeCluster<'a>],
+    viewport: &Viewport,
+) -> Option<&'c TradeCluster<'a>> {
+    clusters.iter()
+        .filter(|cluster| cluster.trades.len() > 1)
+        .find(|cluster| cluster.position(viewport).distance(position) <= CLUSTER_RADIUS + 2.0)
+}
+
+/// Ligne du tooltip d'un groupe : heure, sens, quantité et prix d'un trade
+fn cluster_trade_line(trade: &Trade, chart_style: &ChartStyle) -> String {
+    let time = Utc.timestamp_opt(trade.timestamp, 0)
+        .single()
+        .map(|datetime: DateTime<Utc>| datetime.format("%d/%m %H:%M").to_string())
+        .unwrap_or_default();
+    let side = match trade.trade_type {
+        TradeType::Buy => "Achat",
+        TradeType::Sell => "Vente",
+    };
+    format!("{} {} {} @ {}", time, side, trade.quantity, format_price(trade.price, chart_style))
+}
+
+/// Dessine la liste des trades d'un groupe survolé
+pub fn render_cluster_tooltip(
+    frame: &mut Frame,
+    cluster: &TradeCluster,
+    mouse_position: Point,
+    viewport: &Viewport,
+    chart_style: &ChartStyle,
+) {
+    let style = TooltipStyle::default();
+    let mut lines: Vec<(String, Color)> = vec![(
+        format!("{} trades · net {:+}", cluster.trades.len(), cluster.net_quantity()),
+        style.text_color,
+    )];
+    for trade in cluster.trades.iter().take(CLUSTER_TOOLTIP_MAX_LINES) {
+        let color = match trade.trade_type {
+            TradeType::Buy => style.bullish_color,
+            TradeType::Sell => style.bearish_color,
+        };
+        lines.push((cluster_trade_line(trade, chart_style), color));
+    }
+    if cluster.trades.len() > CLUSTER_TOOLTIP_MAX_LINES {
+        lines.push((format!("… {} de plus", cluster.trades.len() - CLUSTER_TOOLTIP_MAX_LINES), style.text_color));
+    }
+
+    // Largeur estimée d'après le nombre de caractères (pas de mesure de texte sur le canvas)
+    let line_height = style.text_size + 4.0;
+    let longest = lines.iter().map(|(line, _)| line.chars().count()).max().unwrap_or(0);
+    let tooltip_width = longest as f32 * style.text_size * 0.6 + style.padding * 2.0;
+    let tooltip_height = line_height * lines.len() as f32 + style.padding * 2.0;
+
+    let mut tooltip_x = mouse_position.x + 15.0;
+    if tooltip_x + tooltip_width > viewport.width() {
+        tooltip_x = mouse_position.x - tooltip_width - 15.0;
+    }
+    let tooltip_y = (mouse_position.y - tooltip_height / 2.0)
+        .min(viewport.height() - tooltip_height)
+        .max(0.0);
+
+    let background = Path::rectangle(Point::new(tooltip_x, tooltip_y), Size::new(tooltip_width, tooltip_height));
+    frame.fill(&background, style.bg_color);
+    frame.stroke(&background, Stroke::default().with_color(style.border_color).with_width(1.0));
+
+    let mut y = tooltip_y + style.padding;
+    for (content, color) in lines {
+        frame.fill_text(Text {
+            content,
+            position: Point::new(tooltip_x + style.padding, y),
+            color,
+            size: iced::Pixels(style.text_size),
+            ..Text::default()
+        });
+        y += line_height;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(id: u64, trade_type: TradeType, quantity: f64, timestamp: i64) -> Trade {
+        Trade {
+            id,
+            symbol: "BTCUSDT".to_string(),
+            trade_type,
+            quantity,
+            price: 100.0 + id as f64,
+            total_amount: quantity * 100.0,
+            realized_pnl: 0.0,
+            timestamp,
+            strategy_id: None,
+            strategy_name: None,
         }
     }
+
+    #[test]
+    fn test_trades_grouped_by_candle() {
+        let trades = vec![
+            trade(1, TradeType::Buy, 2.0, 3600 + 60),
+            trade(2, TradeType::Sell, 0.5, 3600 + 1800),
+            trade(3, TradeType::Buy, 1.0, 7200 + 10),
+            Trade { symbol: "ETHUSDT".to_string(), ..trade(4, TradeType::Sell, 1.0, 3600) },
+        ];
+        let clusters = cluster_trades(&trades, "BTCUSDT", (0, 10_000), 3600, 3600);
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].timestamp, 3600);
+        assert_eq!(clusters[0].trades.len(), 2);
+        assert_eq!(clusters[0].net_quantity(), 1.5);
+        assert_eq!(clusters[0].net_direction(), Some(TradeType::Buy));
+        assert_eq!(clusters[1].trades.len(), 1);
+
+        // Créneau de 4 bougies : un seul groupe, placé sur une bougie centrale
+        let clusters = cluster_trades(&trades, "BTCUSDT", (0, 10_000), 4 * 3600, 3600);
+        assert_eq!(clusters.len(), 1);
+        assert_eq!(clusters[0].timestamp, 2 * 3600);
+    }
+
+    #[test]
+    fn test_cluster_slot_widens_when_zoomed_out() {
+        // 100 bougies d'une heure sur 800 px : 8 px par bougie, 3 bougies par créneau
+        let time_scale = TimeScale::new(0, 100 * 3600, 800.0);
+        assert_eq!(cluster_slot(&time_scale, 3600), 3 * 3600);
+
+        // 10 bougies sur 800 px : une bougie par créneau
+        let time_scale = TimeScale::new(0, 10 * 3600, 800.0);
+        assert_eq!(cluster_slot(&time_scale, 3600), 3600);
+    }
 }
 
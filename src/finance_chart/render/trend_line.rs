@@ -0,0 +1,186 @@
+//! Rendu des lignes de tendance et des demi-droites (rays)
+
+use iced::widget::canvas::{Frame, Path, Stroke};
+use iced::{Color, Point};
+
+use crate::finance_chart::tools::DrawnTrendLine;
+use crate::finance_chart::viewport::Viewport;
+
+/// Tolérance de hit-test en pixels
+const HIT_TOLERANCE: f32 = 5.0;
+
+/// Prolonge le second point d'une demi-droite jusqu'au temps `edge_time`
+///
+/// L'extrapolation se fait en espace données (temps/prix) pour que la
+/// demi-droite reste ancrée aux prix lors du pan et du zoom.
+/// Si la ligne est verticale ou si `edge_time` est en arrière du second
+/// point, le second point est retourné tel quel.
+fn extend_to_time(
+    start_time: i64,
+    start_price: f64,
+    end_time: i64,
+    end_price: f64,
+    edge_time: i64,
+) -> (i64, f64) {
+    let dt = (end_time - start_time) as f64;
+    if dt == 0.0 {
+        return (end_time, end_price);
+    }
+    let forward = (edge_time - end_time).signum() == (end_time - start_time).signum();
+    if !forward {
+        return (end_time, end_price);
+    }
+    let slope = (end_price - start_price) / dt;
+    let price = start_price + slope * (edge_time - start_time) as f64;
+    (edge_time, price)
+}
+
+/// Calcule les extrémités à dessiner (en temps/prix) d'une ligne de tendance
+///
+/// Pour une demi-droite, le second point est prolongé jusqu'au bord du
+/// viewport situé dans la direction de la ligne (bord droit en général).
+fn trend_line_endpoints(viewport: &Viewport, line: &DrawnTrendLine) -> ((i64, f64), (i64, f64)) {
+    let start = (line.start_time, line.start_price);
+    if !line.ray {
+        return (start, (line.end_time, line.end_price));
+    }
+
+    let time_scale = viewport.time_scale();
+    let edge_time = if line.end_time >= line.start_time {
+        time_scale.x_to_time(viewport.width())
+    } else {
+        time_scale.x_to_time(0.0)
+    };
+    let end = extend_to_time(line.start_time, line.start_price, line.end_time, line.end_price, edge_time);
+    (start, end)
+}
+
+/// Convertit les extrémités d'une ligne en coordonnées écran
+fn screen_segment(viewport: &Viewport, line: &DrawnTrendLine) -> (Point, Point) {
+    let time_scale = viewport.time_scale();
+    let price_scale = viewport.price_scale();
+    let ((t1, p1), (t2, p2)) = trend_line_endpoints(viewport, line);
+    (
+        Point::new(time_scale.time_to_x(t1), price_scale.price_to_y(p1)),
+        Point::new(time_scale.time_to_x(t2), price_scale.price_to_y(p2)),
+    )
+}
+
+/// Distance d'un point à un segment
+fn distance_to_segment(p: Point, a: Point, b: Point) -> f32 {
+    let dx = b.x - a.x;
+    let dy = b.y - a.y;
+    let len_sq = dx * dx + dy * dy;
+    if len_sq == 0.0 {
+        return ((p.x - a.x).powi(2) + (p.y - a.y).powi(2)).sqrt();
+    }
+    let t = (((p.x - a.x) * dx + (p.y - a.y) * dy) / len_sq).clamp(0.0, 1.0);
+    let proj_x = a.x + t * dx;
+    let proj_y = a.y + t * dy;
+    ((p.x - proj_x).powi(2) + (p.y - proj_y).powi(2)).sqrt()
+}
+
+/// Dessine une ligne de tendance (ou une demi-droite si `ray`)
+pub fn draw_trend_line(
+    frame: &mut Frame,
+    viewport: &Viewport,
+    line: &DrawnTrendLine,
+    is_selected: bool,
+) {
+    let (a, b) = screen_segment(viewport, line);
+
+    // Ne pas dessiner si entièrement hors de vue
+    if a.x.max(b.x) < 0.0 || a.x.min(b.x) > viewport.width() {
+        return;
+    }
+
+    let path = Path::line(a, b);
+    let color = if is_selected { Color::WHITE } else { line.color };
+    let stroke = Stroke::default()
+        .with_color(color)
+        .with_width(if is_selected { line.width + 1.0 } else { line.width });
+    frame.stroke(&path, stroke);
+
+    // Point d'ancrage visible quand la ligne est sélectionnée
+    if is_selected {
+        frame.fill(&Path::circle(a, 3.5), line.color);
+    }
+}
+
+/// Dessine l'aperçu d'une demi-droite en cours de création
+pub fn draw_ray_preview(frame: &mut Frame, viewport: &Viewport, start: Point, current: Point) {
+    let time_scale = viewport.time_scale();
+    let price_scale = viewport.price_scale();
+    let preview = DrawnTrendLine::new(
+        time_scale.x_to_time(start.x),
+        price_scale.y_to_price(start.y),
+        time_scale.x_to_time(current.x),
+        price_scale.y_to_price(current.y),
+        true,
+    );
+    let (a, b) = screen_segment(viewport, &preview);
+
+    let stroke = Stroke::default()
+        .with_color(Color::from_rgba(0.4, 0.8, 1.0, 0.6))
+        .with_width(1.5);
+    frame.stroke(&Path::line(a, b), stroke);
+    frame.fill(&Path::circle(start, 3.0), Color::from_rgba(0.4, 0.8, 1.0, 0.8));
+}
+
+/// Hit-test pour les lignes de tendance (segment prolongé inclus pour les rays)
+pub fn hit_test_trend_line(
+    mouse: Point,
+    lines: &[DrawnTrendLine],
+    viewport: &Viewport,
+) -> Option<usize> {
+    for (index, line) in lines.iter().enumerate().rev() {
+        if line.locked || line.hidden {
+            continue;
+        }
+        let (a, b) = screen_segment(viewport, line);
+        if distance_to_segment(mouse, a, b) <= HIT_TOLERANCE {
+            return Some(index);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extend_to_time_follows_slope() {
+        // Pente de +1 par seconde
+        let (t, p) = extend_to_time(0, 100.0, 10, 110.0, 50);
+        assert_eq!(t, 50);
+        assert!((p - 150.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_extend_to_time_leftward_ray() {
+        let (t, p) = extend_to_time(100, 50.0, 90, 40.0, 0);
+        assert_eq!(t, 0);
+        assert!((p + 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_extend_to_time_edge_behind_end() {
+        // Le bord est avant le second point : pas de prolongement
+        assert_eq!(extend_to_time(0, 100.0, 10, 110.0, 5), (10, 110.0));
+        // Ligne verticale
+        assert_eq!(extend_to_time(10, 100.0, 10, 110.0, 50), (10, 110.0));
+    }
+
+    #[test]
+    fn test_distance_to_segment() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(10.0, 0.0);
+        assert!((distance_to_segment(Point::new(5.0, 3.0), a, b) - 3.0).abs() < 1e-6);
+        // Au-delà de l'extrémité : distance à l'extrémité
+        assert!((distance_to_segment(Point::new(13.0, 4.0), a, b) - 5.0).abs() < 1e-6);
+        // Segment dégénéré
+        assert!((distance_to_segment(Point::new(3.0, 4.0), a, a) - 5.0).abs() < 1e-6);
+    }
+}
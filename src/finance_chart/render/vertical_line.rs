@@ -0,0 +1,101 @@
+//! Rendu des lignes verticales (marqueurs temporels)
+
+use iced::widget::canvas::{Frame, Path, Stroke, Text};
+use iced::{Color, Point, Size};
+
+use crate::finance_chart::tools::DrawnVerticalLine;
+use crate::finance_chart::viewport::Viewport;
+
+/// Tolérance de hit-test en pixels
+const HIT_TOLERANCE: f32 = 5.0;
+
+/// Dessine une ligne verticale sur toute la hauteur du graphique
+pub fn draw_vertical_line(
+    frame: &mut Frame,
+    viewport: &Viewport,
+    line: &DrawnVerticalLine,
+    is_selected: bool,
+) {
+    let x = viewport.time_scale().time_to_x(line.time);
+
+    // Ne pas dessiner si hors de vue
+    if x < -10.0 || x > viewport.width() + 10.0 {
+        return;
+    }
+
+    let height = viewport.height();
+    let path = Path::line(Point::new(x, 0.0), Point::new(x, height));
+    let line_color = if is_selected { Color::WHITE } else { line.color };
+    let stroke = Stroke::default()
+        .with_color(line_color)
+        .with_width(if is_selected { line.width + 1.0 } else { line.width });
+    frame.stroke(&path, stroke);
+
+    // Étiquette optionnelle en haut de la ligne
+    if let Some(label) = line.label.as_deref().filter(|l| !l.is_empty()) {
+        draw_label(frame, x, label, line.color);
+    }
+}
+
+/// Dessine l'étiquette d'une ligne verticale
+fn draw_label(frame: &mut Frame, x: f32, label: &str, color: Color) {
+    let font_size = 11.0;
+    let badge_width = label.chars().count() as f32 * font_size * 0.6 + 8.0;
+    let badge_height = 16.0;
+    let badge_x = x + 3.0;
+    let badge_y = 4.0;
+
+    let bg_rect = Path::rectangle(
+        Point::new(badge_x, badge_y),
+        Size::new(badge_width, badge_height),
+    );
+    frame.fill(&bg_rect, color);
+
+    let text = Text {
+        content: label.to_string(),
+        position: Point::new(badge_x + 4.0, badge_y + 2.0),
+        color: Color::BLACK,
+        size: iced::Pixels(font_size),
+        ..Text::default()
+    };
+    frame.fill_text(text);
+}
+
+/// Dessine l'aperçu d'une ligne verticale en cours de création
+pub fn draw_vline_preview(frame: &mut Frame, x: f32, height: f32) {
+    let dash_length = 8.0;
+    let gap_length = 4.0;
+    let color = Color::from_rgba(0.7, 0.5, 1.0, 0.6);
+
+    let mut y = 0.0;
+    while y < height {
+        let end_y = (y + dash_length).min(height);
+        let dash = Path::line(Point::new(x, y), Point::new(x, end_y));
+        let stroke = Stroke::default()
+            .with_color(color)
+            .with_width(1.5);
+        frame.stroke(&dash, stroke);
+        y += dash_length + gap_length;
+    }
+}
+
+/// Hit-test pour les lignes verticales (position x en pixels)
+pub fn hit_test_vline(
+    mouse_x: f32,
+    lines: &[DrawnVerticalLine],
+    viewport: &Viewport,
+) -> Option<usize> {
+    let time_scale = viewport.time_scale();
+
+    for (index, line) in lines.iter().enumerate().rev() {
+        if line.locked || line.hidden {
+            continue;
+        }
+        let x = time_scale.time_to_x(line.time);
+        if (mouse_x - x).abs() <= HIT_TOLERANCE {
+            return Some(index);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,79 @@
+//! Rendu du Volume Profile sur le graphique principal
+//!
+//! Histogramme horizontal ancré sur le bord droit du graphique,
+//! avec la tranche de volume maximal (point of control) mise en évidence.
+
+use iced::widget::canvas::{Frame, Path};
+use iced::{Color, Point, Size};
+
+use crate::finance_chart::viewport::Viewport;
+use crate::finance_chart::indicators::volume_profile::point_of_control;
+
+/// Style pour le Volume Profile
+pub struct VolumeProfileStyle {
+    pub bar_color: Color,       // Couleur des barres
+    pub poc_color: Color,       // Couleur de la tranche de volume maximal
+    pub max_width_ratio: f32,   // Largeur maximale des barres (fraction de la largeur du graphique)
+}
+
+impl Default for VolumeProfileStyle {
+    fn default() -> Self {
+        Self {
+            bar_color: Color::from_rgba(0.5, 0.6, 0.8, 0.25),  // Bleu gris translucide
+            poc_color: Color::from_rgba(1.0, 0.7, 0.2, 0.45),  // Orange pour le POC
+            max_width_ratio: 0.25,
+        }
+    }
+}
+
+/// Rend le Volume Profile sur le graphique principal
+///
+/// # Arguments
+/// * `frame` - Frame de rendu Iced
+/// * `viewport` - Viewport pour les conversions de coordonnées
+/// * `profile` - Tranches `(prix au centre, volume)` triées par prix croissant et régulièrement espacées
+/// * `style` - Style optionnel
+pub fn render_volume_profile(
+    frame: &mut Frame,
+    viewport: &Viewport,
+    profile: &[(f64, f64)],
+    style: Option<VolumeProfileStyle>,
+) {
+    let max_volume = profile.iter().map(|(_, v)| *v).fold(0.0, f64::max);
+    if max_volume <= 0.0 {
+        return;
+    }
+
+    let style = style.unwrap_or_default();
+    let poc = point_of_control(profile);
+    let width = viewport.width();
+    let max_bar_width = width * style.max_width_ratio;
+
+    // Demi-hauteur d'une tranche en prix (une seule tranche couvre toute la hauteur)
+    let half_step = match profile {
+        [first, second, ..] => (second.0 - first.0) / 2.0,
+        _ => {
+            let (min, max) = viewport.price_scale().price_range();
+            (max - min) / 2.0
+        }
+    };
+
+    for (i, (price, volume)) in profile.iter().enumerate() {
+        if *volume <= 0.0 {
+            continue;
+        }
+
+        let y_top = viewport.price_scale().price_to_y(price + half_step);
+        let y_bottom = viewport.price_scale().price_to_y(price - half_step);
+        // 1px d'écart entre les barres pour les distinguer
+        let bar_height = ((y_bottom - y_top).abs() - 1.0).max(1.0);
+        let bar_width = (volume / max_volume) as f32 * max_bar_width;
+
+        let color = if Some(i) == poc { style.poc_color } else { style.bar_color };
+        let bar = Path::rectangle(
+            Point::new(width - bar_width, y_top.min(y_bottom)),
+            Size::new(bar_width, bar_height),
+        );
+        frame.fill(&bar, color);
+    }
+}
@@ -0,0 +1,92 @@
+//! Rendu du VWAP sur le graphique principal
+
+use iced::widget::canvas::{Frame, Path, Stroke};
+use iced::{Color, Point};
+
+use crate::finance_chart::viewport::Viewport;
+use crate::finance_chart::core::Candle;
+use crate::finance_chart::indicators::vwap::calc::{resets_daily, utc_day};
+
+/// Style pour le VWAP
+pub struct VwapStyle {
+    pub color: Color,        // Couleur de la ligne
+    pub line_width: f32,     // Épaisseur de la ligne
+}
+
+impl Default for VwapStyle {
+    fn default() -> Self {
+        Self {
+            color: Color::from_rgba(0.9, 0.3, 0.9, 0.9),  // Magenta pour le VWAP
+            line_width: 2.0,
+        }
+    }
+}
+
+/// Rend le VWAP sur le graphique principal
+///
+/// Sur les séries intraday, la ligne est interrompue à chaque changement de jour UTC
+/// pour que la réinitialisation apparaisse en dents de scie plutôt qu'en saut vertical.
+///
+/// # Arguments
+/// * `frame` - Frame de rendu Iced
+/// * `viewport` - Viewport pour les conversions de coordonnées
+/// * `candles` - Bougies visibles sur le graphique
+/// * `vwap_values` - Valeurs VWAP pré-calculées correspondant aux bougies visibles
+/// * `style` - Style optionnel pour personnaliser la couleur et l'épaisseur
+pub fn render_vwap(
+    frame: &mut Frame,
+    viewport: &Viewport,
+    candles: &[Candle],
+    vwap_values: &[Option<f64>],
+    style: Option<VwapStyle>,
+) {
+    if candles.is_empty() || vwap_values.is_empty() {
+        return;
+    }
+
+    // S'assurer que les deux slices ont la même longueur
+    let min_len = candles.len().min(vwap_values.len());
+    let candles = &candles[..min_len];
+    let vwap_values = &vwap_values[..min_len];
+
+    let style = style.unwrap_or_default();
+    let split_by_day = resets_daily(candles);
+
+    // Regrouper les points par session (un segment par jour UTC en intraday)
+    let mut segments: Vec<Vec<Point>> = Vec::new();
+    let mut current_day = None;
+
+    for (candle, vwap_opt) in candles.iter().zip(vwap_values.iter()) {
+        let day = utc_day(candle.timestamp);
+        if segments.is_empty() || (split_by_day && current_day != Some(day)) {
+            segments.push(Vec::new());
+            current_day = Some(day);
+        }
+
+        if let Some(vwap) = vwap_opt {
+            let x = viewport.time_scale().time_to_x(candle.timestamp);
+
+            // Ne garder que les points visibles
+            if x >= -10.0 && x <= viewport.width() + 10.0 {
+                let y = viewport.price_scale().price_to_y(*vwap);
+                if let Some(segment) = segments.last_mut() {
+                    segment.push(Point::new(x, y));
+                }
+            }
+        }
+    }
+
+    let stroke = Stroke::default()
+        .with_color(style.color)
+        .with_width(style.line_width);
+
+    for points in segments.iter().filter(|points| points.len() >= 2) {
+        let path = Path::new(|builder| {
+            builder.move_to(points[0]);
+            for point in &points[1..] {
+                builder.line_to(*point);
+            }
+        });
+        frame.stroke(&path, stroke);
+    }
+}
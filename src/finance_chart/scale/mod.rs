@@ -2,7 +2,7 @@ pub mod price;
 pub mod time;
 pub mod volume;
 
-pub use price::PriceScale;
+pub use price::{PriceScale, round_to_nice};
 pub use time::TimeScale;
 pub use volume::VolumeScale;
 
@@ -1,4 +1,22 @@
-/// Échelle linéaire pour convertir les prix en coordonnées Y
+use crate::finance_chart::render::calculate_nice_step;
+
+/// Prix minimum utilisé en échelle logarithmique (log10 non défini pour les prix <= 0)
+pub const LOG_MIN_PRICE: f64 = 1e-12;
+
+/// Arrondit `price` au niveau "rond" le plus proche pour une plage de prix visible `range`
+///
+/// Le pas est celui de la grille (`calculate_nice_step`) : le niveau tombe sur
+/// les étiquettes de l'axe (ex: multiple de 100 pour une plage de 500).
+pub fn round_to_nice(price: f64, range: f64) -> f64 {
+    let step = calculate_nice_step(range);
+    let rounded = (price / step).round() * step;
+    // Supprimer les erreurs d'arrondi binaire (ex: 0.30000000000000004)
+    let decimals = (-step.log10().floor()).max(0.0) as i32;
+    let factor = 10_f64.powi(decimals);
+    (rounded * factor).round() / factor
+}
+
+/// Échelle de prix (linéaire ou logarithmique) pour convertir les prix en coordonnées Y
 /// 
 /// Gère la conversion entre valeurs de prix et coordonnées écran,
 /// avec support pour l'autoscaling et les marges.
@@ -12,6 +30,10 @@ pub struct PriceScale {
     height: f32,
     /// Marge verticale en pourcentage (0.0 = pas de marge)
     margin_ratio: f32,
+    /// Échelle logarithmique (mapping en log10)
+    log: bool,
+    /// Échelle inversée (prix élevés en bas de l'écran)
+    inverted: bool,
 }
 
 impl PriceScale {
@@ -22,9 +44,79 @@ impl PriceScale {
             max_price,
             height,
             margin_ratio: 0.1, // 10% de marge par défaut
+            log: false,
+            inverted: false,
+        }
+    }
+
+    /// Active ou désactive l'échelle logarithmique
+    pub fn set_log(&mut self, log: bool) {
+        self.log = log;
+    }
+
+    /// Indique si l'échelle est logarithmique
+    pub fn is_log(&self) -> bool {
+        self.log
+    }
+
+    /// Inverse ou rétablit le sens de l'axe (prix élevés en bas si `inverted`)
+    pub fn set_inverted(&mut self, inverted: bool) {
+        self.inverted = inverted;
+    }
+
+    /// Indique si l'échelle est inversée
+    pub fn is_inverted(&self) -> bool {
+        self.inverted
+    }
+
+    /// Position verticale normalisée (0 = haut, 1 = bas) d'une position normalisée sur l'axe
+    ///
+    /// L'opération est sa propre inverse : elle sert aux deux sens de conversion.
+    fn flip(&self, normalized: f64) -> f64 {
+        if self.inverted {
+            normalized
+        } else {
+            1.0 - normalized
         }
     }
 
+    /// Convertit un prix dans l'espace de l'axe (log10 si échelle logarithmique)
+    pub fn to_axis(&self, price: f64) -> f64 {
+        if self.log {
+            price.max(LOG_MIN_PRICE).log10()
+        } else {
+            price
+        }
+    }
+
+    /// Convertit une valeur de l'espace de l'axe en prix
+    pub fn price_from_axis(&self, value: f64) -> f64 {
+        if self.log {
+            10_f64.powf(value)
+        } else {
+            value
+        }
+    }
+
+    /// Plage effective (avec marges) dans l'espace de l'axe
+    fn effective_axis_range(&self) -> (f64, f64) {
+        let axis_min = self.to_axis(self.min_price);
+        let axis_max = self.to_axis(self.max_price);
+        let margin = (axis_max - axis_min) * self.margin_ratio as f64;
+        (axis_min - margin, axis_max + margin)
+    }
+
+    /// Change la marge verticale (part de la plage ajoutée en haut et en bas)
+    pub fn set_margin_ratio(&mut self, margin_ratio: f32) {
+        self.margin_ratio = margin_ratio.max(0.0);
+    }
+
+    /// Marge verticale (part de la plage ajoutée en haut et en bas)
+    #[allow(dead_code)] // API publique pour utilisation future
+    pub fn margin_ratio(&self) -> f32 {
+        self.margin_ratio
+    }
+
     /// Met à jour la hauteur disponible
     pub fn set_height(&mut self, height: f32) {
         self.height = height;
@@ -42,39 +134,34 @@ impl PriceScale {
     }
 
     /// Convertit un prix en coordonnée Y (0 = haut de l'écran)
+    ///
+    /// Le prix max est en haut, ou en bas si l'échelle est inversée.
     pub fn price_to_y(&self, price: f64) -> f32 {
-        let range = self.max_price - self.min_price;
-        if range == 0.0 {
-            return self.height / 2.0;
-        }
-
         // Appliquer les marges
-        let margin = range * self.margin_ratio as f64;
-        let effective_min = self.min_price - margin;
-        let effective_max = self.max_price + margin;
+        let (effective_min, effective_max) = self.effective_axis_range();
         let effective_range = effective_max - effective_min;
+        if effective_range == 0.0 {
+            return self.height / 2.0;
+        }
 
-        // Inverser Y (0 = haut, height = bas)
-        let normalized = (price - effective_min) / effective_range;
-        self.height * (1.0 - normalized as f32)
+        // Y croît vers le bas : le prix max est en haut sauf en échelle inversée
+        let normalized = (self.to_axis(price) - effective_min) / effective_range;
+        self.height * self.flip(normalized) as f32
     }
 
     /// Convertit une coordonnée Y en prix
     pub fn y_to_price(&self, y: f32) -> f64 {
-        let range = self.max_price - self.min_price;
         if self.height == 0.0 {
             return self.min_price;
         }
 
         // Appliquer les marges (inverse de price_to_y)
-        let margin = range * self.margin_ratio as f64;
-        let effective_min = self.min_price - margin;
-        let effective_max = self.max_price + margin;
+        let (effective_min, effective_max) = self.effective_axis_range();
         let effective_range = effective_max - effective_min;
 
-        // Inverser Y (0 = haut = prix max, height = bas = prix min)
-        let normalized = 1.0 - (y as f64 / self.height as f64);
-        effective_min + (normalized * effective_range)
+        // Inverse de price_to_y (0 = haut = prix max, sauf en échelle inversée)
+        let normalized = self.flip(y as f64 / self.height as f64);
+        self.price_from_axis(effective_min + (normalized * effective_range))
     }
 }
 
@@ -82,6 +169,17 @@ impl PriceScale {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_round_to_nice_depends_on_range() {
+        // Plage de 500 : pas de 100
+        assert_eq!(round_to_nice(42_163.7, 500.0), 42_200.0);
+        // Plage de 50 : pas de 10
+        assert_eq!(round_to_nice(42_163.7, 50.0), 42_160.0);
+        // Petits prix : pas de 0.01, sans résidu binaire
+        assert_eq!(round_to_nice(1.08437, 0.05), 1.08);
+        assert_eq!(round_to_nice(0.2999, 0.05), 0.3);
+    }
+
     #[test]
     fn test_price_scale() {
         let scale = PriceScale::new(100.0, 200.0, 100.0);
@@ -98,5 +196,57 @@ mod tests {
         let y_mid = scale.price_to_y(150.0);
         assert!((y_mid - 50.0).abs() < 5.0);
     }
+
+    #[test]
+    fn test_log_price_scale() {
+        let mut scale = PriceScale::new(10.0, 1000.0, 100.0);
+        scale.set_log(true);
+
+        // En log, 100 est au milieu de [10, 1000]
+        let y_mid = scale.price_to_y(100.0);
+        assert!((y_mid - 50.0).abs() < 0.01);
+
+        // Les prix doublés sont séparés par la même distance
+        let d1 = scale.price_to_y(20.0) - scale.price_to_y(40.0);
+        let d2 = scale.price_to_y(200.0) - scale.price_to_y(400.0);
+        assert!((d1 - d2).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_inverted_price_scale() {
+        let mut scale = PriceScale::new(100.0, 200.0, 100.0);
+        scale.set_inverted(true);
+
+        // Prix max en bas, prix min en haut
+        assert!(scale.price_to_y(200.0) > 90.0);
+        assert!(scale.price_to_y(100.0) < 10.0);
+        assert!((scale.y_to_price(0.0) - 100.0).abs() < 15.0);
+    }
+
+    #[test]
+    fn test_roundtrip_in_both_orientations() {
+        for log in [false, true] {
+            for inverted in [false, true] {
+                let mut scale = PriceScale::new(10.0, 1000.0, 400.0);
+                scale.set_log(log);
+                scale.set_inverted(inverted);
+                for price in [10.0, 42.5, 500.0, 1000.0] {
+                    let back = scale.y_to_price(scale.price_to_y(price));
+                    assert!((back - price).abs() / price < 1e-4, "log={log} inverted={inverted} price={price}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_log_roundtrip() {
+        let mut scale = PriceScale::new(0.5, 50_000.0, 400.0);
+        scale.set_log(true);
+        for price in [0.5, 3.0, 1234.5, 50_000.0] {
+            let y = scale.price_to_y(price);
+            let back = scale.y_to_price(y);
+            assert!((back - price).abs() / price < 1e-4);
+        }
+    }
 }
 
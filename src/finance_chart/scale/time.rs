@@ -59,6 +59,15 @@ impl TimeScale {
         let normalized = x as f64 / self.width as f64;
         self.min_time + (normalized * range as f64) as i64
     }
+
+    /// Largeur en pixels d'une durée (ex: espacement entre deux bougies)
+    pub fn duration_to_width(&self, duration: i64) -> f32 {
+        let range = self.max_time - self.min_time;
+        if range <= 0 {
+            return 0.0;
+        }
+        (duration as f64 / range as f64) as f32 * self.width
+    }
 }
 
 #[cfg(test)]
@@ -77,6 +86,8 @@ mod tests {
         
         let x_mid = scale.time_to_x(1500);
         assert_eq!(x_mid, 50.0);
+
+        assert_eq!(scale.duration_to_width(250), 25.0);
     }
 }
 
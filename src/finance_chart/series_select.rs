@@ -1,11 +1,12 @@
 //! Select box pour choisir les séries à afficher
 
-use iced::widget::{container, pick_list, row, text, Space};
+use iced::widget::{button, checkbox, container, pick_list, row, text, Row, Space};
 use iced::{Color, Element, Length};
 use std::collections::HashSet;
 
-use super::core::SeriesManager;
-use super::messages::SeriesPanelMessage;
+use super::core::{PriceAxis, SeriesManager};
+use super::messages::{ChartMessage, SeriesPanelMessage};
+use super::state::ChartState;
 use crate::app::utils::utils::interval_to_seconds;
 
 /// Liste des unités de temps valides (triées du plus petit au plus grand)
@@ -111,12 +112,77 @@ pub fn series_select_box<'a>(
     .placeholder("Sélectionner une série...")
     .text_size(13.0);
 
-    // Container avec le label et le pick_list
+    // Cases à cocher pour superposer les autres séries du même intervalle
+    let primary = series_manager.active_series().next();
+    let mut overlay_candidates: Vec<_> = series_manager
+        .all_series()
+        .filter(|series| primary.is_some_and(|p| p.interval == series.interval))
+        .collect();
+    overlay_candidates.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+
+    let mut overlays = Row::new().spacing(10).align_y(iced::Alignment::Center);
+    if overlay_candidates.len() > 1 {
+        for series in overlay_candidates {
+            let series_id = series.id.clone();
+            // La couleur personnalisée de la série sert de repère dans la légende
+            let label_color = series.color.unwrap_or(Color::from_rgb(0.8, 0.8, 0.8));
+            let mut entry = row![
+                checkbox(series_manager.is_series_visible(&series.id))
+                    .on_toggle(move |_| SeriesPanelMessage::ToggleSeriesVisibility {
+                        series_id: series_id.clone(),
+                    }),
+                text(series.symbol.clone()).size(12).color(label_color)
+            ]
+            .spacing(4)
+            .align_y(iced::Alignment::Center);
+
+            // Série superposée affichée : choix de l'axe de prix (G = gauche, D = droite)
+            let is_primary = primary.is_some_and(|p| p.id == series.id);
+            if !is_primary && series_manager.is_series_visible(&series.id) {
+                let (label, axis) = match series.axis {
+                    PriceAxis::Left => ("G", PriceAxis::Right),
+                    PriceAxis::Right => ("D", PriceAxis::Left),
+                };
+                entry = entry.push(
+                    button(text(label).size(11))
+                        .on_press(SeriesPanelMessage::SetSeriesAxis { series_id: series.id.clone(), axis })
+                        .padding([1, 5])
+                        .style(if series.axis == PriceAxis::Left { button::primary } else { button::secondary })
+                );
+            }
+            overlays = overlays.push(entry);
+        }
+    }
+
+    // Boutons pause/reprise du temps réel pour chaque série affichée
+    let show_symbols = series_manager.active_series().count() > 1;
+    let mut realtime_toggles = Row::new().spacing(4).align_y(iced::Alignment::Center);
+    for series in series_manager.active_series() {
+        let series_id = series.id.clone();
+        let icon = if series.realtime_enabled { "⏸" } else { "▶" };
+        let label = if show_symbols {
+            format!("{} {}", icon, series.symbol)
+        } else {
+            icon.to_string()
+        };
+        realtime_toggles = realtime_toggles.push(
+            button(text(label).size(12))
+                .on_press(SeriesPanelMessage::ToggleRealtime { series_id })
+                .padding([3, 8])
+                .style(if series.realtime_enabled { button::secondary } else { button::primary })
+        );
+    }
+
+    // Container avec le label, le pick_list, les séries superposables et le temps réel
     container(
         row![
             label,
             Space::new().width(Length::Fixed(8.0)),
-            pick_list_widget
+            pick_list_widget,
+            Space::new().width(Length::Fixed(8.0)),
+            overlays,
+            Space::new().width(Length::Fixed(8.0)),
+            realtime_toggles
         ]
         .spacing(5)
         .align_y(iced::Alignment::Center)
@@ -125,3 +191,90 @@ pub fn series_select_box<'a>(
     .into()
 }
 
+
+/// Crée la barre d'intervalles de l'actif courant
+///
+/// Un bouton par intervalle déjà chargé pour le symbole de la série active,
+/// pour passer de l'un à l'autre sans repasser par la liste des séries.
+/// Rien n'est affiché tant qu'un seul intervalle est disponible.
+pub fn interval_toolbar<'a>(
+    series_manager: &'a SeriesManager,
+    selected_asset_symbol: Option<&'a String>,
+) -> Element<'a, ChartMessage> {
+    let active = series_manager.active_series().next();
+    let Some(symbol) = selected_asset_symbol
+        .map(String::as_str)
+        .or_else(|| active.map(|series| series.symbol.as_str()))
+    else {
+        return Space::new().width(Length::Shrink).into();
+    };
+    let intervals = series_manager.intervals_for_symbol(symbol);
+    if intervals.len() < 2 {
+        return Space::new().width(Length::Shrink).into();
+    }
+
+    let mut buttons = Row::new().spacing(2).align_y(iced::Alignment::Center);
+    for (interval, series_id) in intervals {
+        let is_active = active.is_some_and(|series| series.id == *series_id);
+        buttons = buttons.push(
+            button(text(interval.to_string()).size(12))
+                .on_press(ChartMessage::SwitchInterval(interval.to_string()))
+                .padding([3, 8])
+                .style(if is_active { button::primary } else { button::secondary })
+        );
+    }
+
+    container(buttons)
+        .padding([5, 10])
+        .into()
+}
+
+/// Crée un select box pour l'intervalle d'affichage (agrégation de la série active)
+///
+/// Seuls les intervalles supérieurs ou égaux à celui de la série active sont
+/// proposés ; choisir l'intervalle natif désactive l'agrégation.
+pub fn display_interval_select(chart_state: &ChartState) -> Element<'_, ChartMessage> {
+    let native = chart_state.series_manager
+        .active_series()
+        .next()
+        .map(|series| series.interval.clone());
+    let native_seconds = native.as_deref().map(interval_to_seconds).unwrap_or(0);
+
+    let intervals: Vec<String> = VALID_TIME_UNITS
+        .iter()
+        .filter(|interval| interval_to_seconds(interval) >= native_seconds)
+        .map(|interval| interval.to_string())
+        .collect();
+
+    let selected = chart_state
+        .display_interval()
+        .map(str::to_string)
+        .or_else(|| native.clone());
+
+    let label = text("Affichage:")
+        .size(14)
+        .color(Color::from_rgb(0.8, 0.8, 0.8));
+
+    let pick_list_widget = pick_list(
+        intervals,
+        selected,
+        move |interval: String| {
+            let interval = (Some(&interval) != native.as_ref()).then_some(interval);
+            ChartMessage::SetDisplayInterval { interval }
+        },
+    )
+    .width(Length::Fixed(80.0))
+    .text_size(13.0);
+
+    container(
+        row![
+            label,
+            Space::new().width(Length::Fixed(8.0)),
+            pick_list_widget
+        ]
+        .spacing(5)
+        .align_y(iced::Alignment::Center)
+    )
+    .padding([5, 10])
+    .into()
+}
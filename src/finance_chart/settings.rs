@@ -1,6 +1,6 @@
 //! Module de configuration et settings du graphique
 
-use iced::Color;
+use iced::{Color, Point};
 use serde::{Deserialize, Serialize};
 
 /// Style personnalisable du graphique
@@ -25,14 +25,260 @@ pub struct ChartStyle {
     /// Active le défilement automatique vers les dernières données
     #[serde(default = "default_auto_scroll")]
     pub auto_scroll_enabled: bool,
+    /// Mode de rendu des séries (bougies, ligne, aire)
+    #[serde(default)]
+    pub render_mode: ChartRenderMode,
+    /// Bandes de fond des sessions de trading
+    #[serde(default)]
+    pub sessions: SessionConfig,
+    /// Largeur du corps des bougies en part de l'espace disponible (None = adaptative)
+    #[serde(default)]
+    pub candle_width_ratio: Option<f32>,
+    /// Épaisseur des mèches en pixels
+    #[serde(default = "default_wick_width")]
+    pub wick_width: f32,
+    /// Style du corps des bougies (plein ou creux pour les haussières)
+    #[serde(default)]
+    pub candle_body_style: CandleBodyStyle,
+    /// Apparence de la grille (densité, pointillés, lignes secondaires)
+    #[serde(default)]
+    pub grid: GridConfig,
+    /// Apparence du crosshair (épaisseur, tirets, étiquettes sur les axes)
+    #[serde(default)]
+    pub crosshair: CrosshairConfig,
+    /// Nombre de décimales des prix affichés (None = selon l'ordre de grandeur du prix)
+    #[serde(default)]
+    pub price_decimals: Option<usize>,
+    /// Espace vide laissé à droite de la dernière bougie, en largeurs de bougie
+    #[serde(default)]
+    pub right_padding_candles: f32,
+}
+
+/// Configuration de l'apparence de la grille
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GridConfig {
+    /// Lignes pointillées au lieu de pleines
+    pub dashed: bool,
+    /// Lignes secondaires (chaque intervalle principal divisé en 5)
+    pub minor_lines: bool,
+    /// Facteur de densité (> 1 = grille plus fine, < 1 = plus grossière)
+    pub density: f32,
+}
+
+impl Default for GridConfig {
+    fn default() -> Self {
+        Self {
+            dashed: false,
+            minor_lines: false,
+            density: 1.0,
+        }
+    }
+}
+
+impl GridConfig {
+    /// Densités proposées dans l'UI avec leur libellé
+    pub const DENSITIES: [(f32, &'static str); 3] = [(0.5, "Large"), (1.0, "Normale"), (2.0, "Fine")];
+}
+
+/// Motif de trait des lignes du crosshair
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CrosshairDash {
+    /// Trait continu (par défaut)
+    #[default]
+    Solid,
+    /// Tirets
+    Dashed,
+    /// Pointillés
+    Dotted,
+}
+
+impl CrosshairDash {
+    /// Tous les motifs disponibles (pour les sélecteurs de l'UI)
+    pub const ALL: [CrosshairDash; 3] = [CrosshairDash::Solid, CrosshairDash::Dashed, CrosshairDash::Dotted];
+
+    /// Libellé affiché dans l'UI
+    pub fn label(&self) -> &'static str {
+        match self {
+            CrosshairDash::Solid => "Continu",
+            CrosshairDash::Dashed => "Tirets",
+            CrosshairDash::Dotted => "Points",
+        }
+    }
+
+    /// Longueurs (tiret, espace) en pixels, `None` pour un trait continu
+    pub fn pattern(&self) -> Option<(f32, f32)> {
+        match self {
+            CrosshairDash::Solid => None,
+            CrosshairDash::Dashed => Some((6.0, 4.0)),
+            CrosshairDash::Dotted => Some((2.0, 3.0)),
+        }
+    }
+}
+
+/// Configuration de l'apparence du crosshair
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CrosshairConfig {
+    /// Épaisseur des lignes en pixels
+    pub width: f32,
+    /// Motif de trait des lignes
+    pub dash: CrosshairDash,
+    /// Affiche les étiquettes prix/date sur les axes
+    pub show_labels: bool,
+}
+
+impl Default for CrosshairConfig {
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            dash: CrosshairDash::Solid,
+            show_labels: true,
+        }
+    }
+}
+
+impl CrosshairConfig {
+    /// Épaisseurs proposées dans l'UI
+    pub const WIDTHS: [f32; 3] = [1.0, 1.5, 2.0];
+}
+
+/// Session de trading : (début HHMM, fin HHMM, couleur), en heure locale
+///
+/// Une session dont la fin est antérieure au début se termine le lendemain
+/// (ex: `(2200, 600, ..)`).
+pub type TradingSession = (u16, u16, SerializableColor);
+
+/// Configuration de l'affichage des sessions de trading
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionConfig {
+    /// Affiche les bandes de sessions sur le graphique
+    pub enabled: bool,
+    /// Décalage du fuseau horaire des sessions par rapport à UTC, en minutes
+    pub utc_offset_minutes: i32,
+    /// Sessions à afficher
+    pub sessions: Vec<TradingSession>,
+}
+
+impl Default for SessionConfig {
+    /// Sessions Forex Asie / Europe / US (heures UTC), désactivées par défaut
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            utc_offset_minutes: 0,
+            sessions: vec![
+                (0, 900, SerializableColor::from_rgba(1.0, 0.8, 0.0, 0.05)),
+                (700, 1600, SerializableColor::from_rgba(0.2, 0.6, 1.0, 0.05)),
+                (1300, 2200, SerializableColor::from_rgba(0.8, 0.2, 0.4, 0.05)),
+            ],
+        }
+    }
+}
+
+/// Mode de rendu des séries sur le graphique principal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ChartRenderMode {
+    /// Bougies japonaises (par défaut)
+    #[default]
+    Candlestick,
+    /// Ligne reliant les prix de clôture
+    Line,
+    /// Aire remplie sous la ligne des clôtures
+    Area,
+    /// Barres OHLC classiques
+    OhlcBar,
+    /// Briques Renko (indépendantes du temps)
+    Renko,
+}
+
+impl ChartRenderMode {
+    /// Tous les modes disponibles (pour les sélecteurs de l'UI)
+    pub const ALL: [ChartRenderMode; 5] = [
+        ChartRenderMode::Candlestick,
+        ChartRenderMode::OhlcBar,
+        ChartRenderMode::Line,
+        ChartRenderMode::Area,
+        ChartRenderMode::Renko,
+    ];
+
+    /// Libellé affiché dans l'UI
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChartRenderMode::Candlestick => "Bougies",
+            ChartRenderMode::Line => "Ligne",
+            ChartRenderMode::Area => "Aire",
+            ChartRenderMode::OhlcBar => "Barres OHLC",
+            ChartRenderMode::Renko => "Renko",
+        }
+    }
+}
+
+/// Style du corps des bougies japonaises
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CandleBodyStyle {
+    /// Corps pleins pour toutes les bougies (par défaut)
+    #[default]
+    Solid,
+    /// Corps creux (contour seul) pour les bougies haussières, pleins pour les baissières
+    Hollow,
+}
+
+impl CandleBodyStyle {
+    /// Tous les styles disponibles (pour les sélecteurs de l'UI)
+    pub const ALL: [CandleBodyStyle; 2] = [CandleBodyStyle::Solid, CandleBodyStyle::Hollow];
+
+    /// Libellé affiché dans l'UI
+    pub fn label(&self) -> &'static str {
+        match self {
+            CandleBodyStyle::Solid => "Pleines",
+            CandleBodyStyle::Hollow => "Creuses",
+        }
+    }
 }
 
 fn default_auto_scroll() -> bool {
     true
 }
 
+fn default_wick_width() -> f32 {
+    1.0
+}
+
+/// Thèmes de couleurs prédéfinis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemePreset {
+    Dark,
+    Light,
+}
+
+impl ThemePreset {
+    /// Tous les thèmes disponibles (pour les sélecteurs de l'UI)
+    pub const ALL: [ThemePreset; 2] = [ThemePreset::Dark, ThemePreset::Light];
+
+    /// Libellé affiché dans l'UI
+    pub fn label(&self) -> &'static str {
+        match self {
+            ThemePreset::Dark => "Sombre",
+            ThemePreset::Light => "Clair",
+        }
+    }
+
+    /// Style complet correspondant au thème
+    pub fn style(&self) -> ChartStyle {
+        match self {
+            ThemePreset::Dark => ChartStyle::dark_preset(),
+            ThemePreset::Light => ChartStyle::light_preset(),
+        }
+    }
+}
+
 impl Default for ChartStyle {
     fn default() -> Self {
+        Self::dark_preset()
+    }
+}
+
+impl ChartStyle {
+    /// Thème sombre (thème par défaut)
+    pub fn dark_preset() -> Self {
         Self {
             background_color: SerializableColor::from_rgb(0.06, 0.06, 0.08),
             bullish_color: SerializableColor::from_rgb(0.0, 0.8, 0.0),
@@ -43,11 +289,45 @@ impl Default for ChartStyle {
             crosshair_color: SerializableColor::from_rgba(0.6, 0.6, 0.6, 0.8),
             text_color: SerializableColor::from_rgba(0.8, 0.8, 0.8, 1.0),
             auto_scroll_enabled: true,
+            render_mode: ChartRenderMode::Candlestick,
+            sessions: SessionConfig::default(),
+            candle_width_ratio: None,
+            wick_width: default_wick_width(),
+            candle_body_style: CandleBodyStyle::Solid,
+            grid: GridConfig::default(),
+            crosshair: CrosshairConfig::default(),
+            price_decimals: None,
+            right_padding_candles: 0.0,
+        }
+    }
+
+    /// Thème clair : fond blanc, texte et grille sombres
+    pub fn light_preset() -> Self {
+        Self {
+            background_color: SerializableColor::from_rgb(0.98, 0.98, 0.97),
+            bullish_color: SerializableColor::from_rgb(0.1, 0.6, 0.3),
+            bearish_color: SerializableColor::from_rgb(0.85, 0.2, 0.2),
+            wick_color: SerializableColor::from_rgb(0.35, 0.35, 0.38),
+            grid_color: SerializableColor::from_rgba(0.2, 0.2, 0.25, 0.15),
+            current_price_color: SerializableColor::from_rgba(0.1, 0.4, 0.85, 0.9),
+            crosshair_color: SerializableColor::from_rgba(0.3, 0.3, 0.35, 0.8),
+            text_color: SerializableColor::from_rgb(0.15, 0.15, 0.18),
+            ..Self::dark_preset()
+        }
+    }
+
+    /// Remplace toutes les couleurs éditables par celles du thème
+    ///
+    /// Passe par `color_fields()` pour que les retouches individuelles restent
+    /// appliquées sur les mêmes champs. Les autres réglages (mode de rendu,
+    /// sessions, dimensions des bougies) sont conservés.
+    pub fn apply_preset(&mut self, preset: ThemePreset) {
+        let preset_style = preset.style();
+        for field in color_fields() {
+            (field.set)(self, (field.get)(&preset_style));
         }
     }
-}
 
-impl ChartStyle {
     /// Sauvegarde les settings dans un fichier
     pub fn save_to_file(&self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
         let json = serde_json::to_string_pretty(self)?;
@@ -63,8 +343,67 @@ impl ChartStyle {
     }
 }
 
+/// Nombre maximal de décimales pour l'affichage des prix
+pub const MAX_PRICE_DECIMALS: usize = 8;
+
+/// Précisions proposées dans l'UI (en plus du mode automatique)
+pub const PRICE_DECIMALS_CHOICES: [usize; 5] = [0, 2, 4, 6, 8];
+
+/// Espaces à droite de la dernière bougie proposés dans l'UI (en bougies)
+pub const RIGHT_PADDING_CHOICES: [f32; 4] = [0.0, 5.0, 10.0, 20.0];
+
+/// Nombre de décimales adapté à l'ordre de grandeur d'un prix
+///
+/// Vise environ cinq chiffres significatifs, avec au moins deux décimales :
+/// 50000 → 2, 1.5 → 4, 0.05 → 6, 0.00001234 → 8.
+pub fn auto_price_decimals(price: f64) -> usize {
+    let magnitude = price.abs();
+    if magnitude == 0.0 || !magnitude.is_finite() {
+        return 2;
+    }
+    let digits = magnitude.log10().floor() as i32;
+    (4 - digits).clamp(2, MAX_PRICE_DECIMALS as i32) as usize
+}
+
+/// Formate un prix selon la précision configurée dans le style
+///
+/// Point d'entrée unique pour les prix affichés (axe Y, tooltip, étiquettes).
+pub fn format_price(value: f64, style: &ChartStyle) -> String {
+    let decimals = style.price_decimals
+        .unwrap_or_else(|| auto_price_decimals(value))
+        .min(MAX_PRICE_DECIMALS);
+    format!("{:.*}", decimals, value)
+}
+
+/// Suffixes des grands nombres abrégés (milliers, millions, milliards, billions)
+const ABBREVIATION_SUFFIXES: [&str; 4] = ["k", "M", "B", "T"];
+
+/// Formate un grand nombre avec un suffixe k/M/B/T au-delà de mille (ex: `1.2M`, `3.4B`)
+///
+/// Utilisé pour les volumes et les indicateurs cumulés : les valeurs négatives
+/// conservent leur signe (OBV), les valeurs inférieures à 1 gardent deux décimales.
+/// Un arrondi qui atteindrait 1000 passe au suffixe suivant (999 999 → `1.0M`).
+pub fn format_abbreviated(value: f64) -> String {
+    let abs = value.abs();
+    if abs > 0.0 && abs < 1.0 {
+        return format!("{:.2}", value);
+    }
+    if abs.round() < 1000.0 {
+        return format!("{:.0}", value);
+    }
+
+    let mut scaled = abs / 1000.0;
+    let mut index = 0;
+    while (scaled * 10.0).round() >= 10_000.0 && index + 1 < ABBREVIATION_SUFFIXES.len() {
+        scaled /= 1000.0;
+        index += 1;
+    }
+    let sign = if value < 0.0 { "-" } else { "" };
+    format!("{}{:.1}{}", sign, scaled, ABBREVIATION_SUFFIXES[index])
+}
+
 /// Couleur sérialisable (wrapper autour de iced::Color)
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct SerializableColor {
     pub r: f32,
     pub g: f32,
@@ -103,14 +442,188 @@ impl From<Color> for SerializableColor {
 }
 
 
+/// Champ affichable dans le tooltip OHLC
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TooltipField {
+    Timestamp,
+    Open,
+    High,
+    Low,
+    Close,
+    Volume,
+    /// Variation clôture / ouverture de la bougie, en %
+    ChangePercent,
+    /// Variation clôture / ouverture de la bougie, en valeur
+    ChangeAbs,
+    /// Variation par rapport à la clôture de la bougie précédente, en %
+    PreviousChangePercent,
+}
+
+impl TooltipField {
+    pub const ALL: [TooltipField; 9] = [
+        TooltipField::Timestamp,
+        TooltipField::Open,
+        TooltipField::High,
+        TooltipField::Low,
+        TooltipField::Close,
+        TooltipField::Volume,
+        TooltipField::ChangePercent,
+        TooltipField::ChangeAbs,
+        TooltipField::PreviousChangePercent,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TooltipField::Timestamp => "Date / heure",
+            TooltipField::Open => "Ouverture",
+            TooltipField::High => "Plus haut",
+            TooltipField::Low => "Plus bas",
+            TooltipField::Close => "Clôture",
+            TooltipField::Volume => "Volume",
+            TooltipField::ChangePercent => "Variation (%)",
+            TooltipField::ChangeAbs => "Variation",
+            TooltipField::PreviousChangePercent => "Variation vs bougie précédente (%)",
+        }
+    }
+}
+
+/// Contenu du tooltip : champs affichés, dans l'ordre d'affichage
+#[derive(Debug, Clone, PartialEq)]
+pub struct TooltipConfig {
+    pub fields: Vec<TooltipField>,
+}
+
+impl Default for TooltipConfig {
+    /// Date, OHLC puis variation en % (disposition historique)
+    fn default() -> Self {
+        Self {
+            fields: vec![
+                TooltipField::Timestamp,
+                TooltipField::Open,
+                TooltipField::High,
+                TooltipField::Low,
+                TooltipField::Close,
+                TooltipField::ChangePercent,
+            ],
+        }
+    }
+}
+
+impl TooltipConfig {
+    pub fn is_enabled(&self, field: TooltipField) -> bool {
+        self.fields.contains(&field)
+    }
+
+    /// Affiche ou masque un champ (un champ activé est ajouté en dernier)
+    pub fn toggle(&mut self, field: TooltipField) {
+        if let Some(index) = self.fields.iter().position(|&f| f == field) {
+            self.fields.remove(index);
+        } else {
+            self.fields.push(field);
+        }
+    }
+
+    /// Déplace un champ affiché d'un rang vers le haut (`up`) ou vers le bas
+    pub fn move_field(&mut self, field: TooltipField, up: bool) {
+        let Some(index) = self.fields.iter().position(|&f| f == field) else {
+            return;
+        };
+        let target = if up {
+            index.checked_sub(1)
+        } else {
+            Some(index + 1).filter(|&i| i < self.fields.len())
+        };
+        if let Some(target) = target {
+            self.fields.swap(index, target);
+        }
+    }
+}
+
+/// Point fixe du zoom à la molette
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ZoomAnchor {
+    /// Zoom autour du curseur (par défaut)
+    #[default]
+    Cursor,
+    /// Zoom autour du centre du graphique
+    Center,
+    /// Zoom en gardant le bord droit (dernières bougies) fixe
+    RightEdge,
+}
+
+impl ZoomAnchor {
+    /// Tous les ancrages disponibles (pour les sélecteurs de l'UI)
+    pub const ALL: [ZoomAnchor; 3] = [ZoomAnchor::Cursor, ZoomAnchor::Center, ZoomAnchor::RightEdge];
+
+    /// Libellé affiché dans l'UI
+    pub fn label(&self) -> &'static str {
+        match self {
+            ZoomAnchor::Cursor => "Curseur",
+            ZoomAnchor::Center => "Centre",
+            ZoomAnchor::RightEdge => "Bord droit",
+        }
+    }
+
+    /// Point écran autour duquel zoomer dans un graphique de `width` x `height`
+    ///
+    /// Sans position du curseur, le zoom se fait autour du centre. Le bord
+    /// droit garde le centre vertical pour le zoom des prix.
+    pub fn resolve(&self, cursor: Option<Point>, width: f32, height: f32) -> Point {
+        let center = Point::new(width / 2.0, height / 2.0);
+        match self {
+            ZoomAnchor::Cursor => cursor.unwrap_or(center),
+            ZoomAnchor::Center => center,
+            ZoomAnchor::RightEdge => Point::new(width, height / 2.0),
+        }
+    }
+}
+
 /// État du dialog settings
 #[derive(Debug, Clone, Default)]
 pub struct SettingsState {
     /// Le dialog est-il ouvert
     pub is_open: bool,
+    /// Affiche les bougies en Heikin-Ashi
+    pub heikin_ashi_enabled: bool,
+    /// Le crosshair s'accroche au prix OHLC le plus proche (touche M)
+    pub crosshair_magnet: bool,
+    /// Hachure les zones où l'historique de la série est incomplet
+    pub show_gaps: bool,
+    /// Atténue le corps des bougies selon leur volume relatif à la fenêtre visible
+    pub volume_heatmap: bool,
+    /// Trace un séparateur vertical au début de chaque journée (graphiques intraday)
+    pub show_day_separators: bool,
+    /// Aligne les bords de la vue sur des bougies entières à la fin d'un pan
+    pub snap_pan_to_candle: bool,
+    /// Point fixe du zoom à la molette
+    pub zoom_anchor: ZoomAnchor,
+    /// Affiche le bandeau d'informations (bougies visibles, intervalle, période)
+    pub show_hud: bool,
+    /// Relie l'entrée et la sortie des positions clôturées, avec leur P&L
+    pub show_trade_pnl: bool,
+    /// Champs affichés dans le tooltip OHLC
+    pub tooltip: TooltipConfig,
+    /// Nombre maximal de requêtes simultanées vers le provider (complétion des gaps)
+    pub max_concurrent_requests: usize,
+    /// Nombre maximal de bougies gardées en mémoire par série (None = illimité)
+    ///
+    /// Au-delà, les bougies les plus anciennes sont retirées de la mémoire ; elles
+    /// restent dans le fichier de la série.
+    pub max_candles_per_series: Option<usize>,
 }
 
 impl SettingsState {
+    /// Valeur par défaut du nombre de requêtes simultanées
+    pub const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 4;
+    /// Valeurs proposées dans l'UI
+    pub const MAX_CONCURRENT_REQUESTS_CHOICES: [usize; 4] = [1, 2, 4, 8];
+    /// Limites d'historique proposées dans l'UI
+    pub const MAX_CANDLES_CHOICES: [Option<usize>; 5] = [None, Some(10_000), Some(50_000), Some(200_000), Some(1_000_000)];
+
+    /// Nombre de requêtes simultanées effectif (au moins 1)
+    pub fn concurrency_limit(&self) -> usize {
+        self.max_concurrent_requests.max(1)
+    }
 }
 
 /// Définition d'un champ de couleur éditable
@@ -202,3 +715,98 @@ pub fn preset_colors() -> Vec<SerializableColor> {
     ]
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_price_auto_precision() {
+        let style = ChartStyle::default();
+        assert_eq!(format_price(50000.0, &style), "50000.00");
+        assert_eq!(format_price(1.5, &style), "1.5000");
+        assert_eq!(format_price(0.05, &style), "0.050000");
+        assert_eq!(format_price(0.00001234, &style), "0.00001234");
+        assert_eq!(format_price(0.0, &style), "0.00");
+    }
+
+    #[test]
+    fn test_format_abbreviated_suffixes() {
+        assert_eq!(format_abbreviated(0.0), "0");
+        assert_eq!(format_abbreviated(0.25), "0.25");
+        assert_eq!(format_abbreviated(950.0), "950");
+        assert_eq!(format_abbreviated(1_500.0), "1.5k");
+        assert_eq!(format_abbreviated(2_340_000.0), "2.3M");
+        assert_eq!(format_abbreviated(7_000_000_000.0), "7.0B");
+        assert_eq!(format_abbreviated(4_200_000_000_000.0), "4.2T");
+    }
+
+    #[test]
+    fn test_format_abbreviated_boundaries() {
+        assert_eq!(format_abbreviated(999.0), "999");
+        assert_eq!(format_abbreviated(999.6), "1.0k");
+        assert_eq!(format_abbreviated(1_000.0), "1.0k");
+        assert_eq!(format_abbreviated(999_999.0), "1.0M");
+        assert_eq!(format_abbreviated(1_000_000.0), "1.0M");
+        assert_eq!(format_abbreviated(999_999_999_999.0), "1.0T");
+    }
+
+    #[test]
+    fn test_format_abbreviated_negative() {
+        assert_eq!(format_abbreviated(-12_500.0), "-12.5k");
+        assert_eq!(format_abbreviated(-999.0), "-999");
+        assert_eq!(format_abbreviated(-0.5), "-0.50");
+    }
+
+    #[test]
+    fn test_format_price_fixed_decimals() {
+        let style = ChartStyle { price_decimals: Some(3), ..ChartStyle::default() };
+        assert_eq!(format_price(50000.0, &style), "50000.000");
+        assert_eq!(format_price(0.00001234, &style), "0.000");
+
+        let style = ChartStyle { price_decimals: Some(20), ..ChartStyle::default() };
+        assert_eq!(format_price(1.0, &style), "1.00000000");
+    }
+
+    #[test]
+    fn test_tooltip_config_toggle_and_move() {
+        let mut config = TooltipConfig::default();
+        assert!(!config.is_enabled(TooltipField::Volume));
+
+        config.toggle(TooltipField::Volume);
+        assert_eq!(config.fields.last(), Some(&TooltipField::Volume));
+
+        config.move_field(TooltipField::Volume, true);
+        assert_eq!(config.fields[config.fields.len() - 2], TooltipField::Volume);
+        // Déjà en tête : aucun effet
+        config.move_field(TooltipField::Timestamp, true);
+        assert_eq!(config.fields[0], TooltipField::Timestamp);
+
+        config.toggle(TooltipField::Open);
+        assert!(!config.is_enabled(TooltipField::Open));
+    }
+
+    #[test]
+    fn test_apply_preset_swaps_all_color_fields() {
+        let mut style = ChartStyle { render_mode: ChartRenderMode::Line, ..Default::default() };
+        style.apply_preset(ThemePreset::Light);
+
+        let light = ChartStyle::light_preset();
+        for field in color_fields() {
+            assert_eq!((field.get)(&style), (field.get)(&light), "{}", field.label);
+        }
+        // Les réglages hors couleurs sont conservés
+        assert_eq!(style.render_mode, ChartRenderMode::Line);
+    }
+
+    #[test]
+    fn test_color_tweak_after_preset_persists() {
+        let mut style = ChartStyle::default();
+        style.apply_preset(ThemePreset::Light);
+
+        let field = &color_fields()[1];
+        let tweak = SerializableColor::from_rgb(0.2, 0.6, 1.0);
+        (field.set)(&mut style, tweak);
+        assert_eq!((field.get)(&style), tweak);
+        assert_eq!(style.background_color, ChartStyle::light_preset().background_color);
+    }
+}
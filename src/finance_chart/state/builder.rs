@@ -0,0 +1,138 @@
+//! Construction configurable de `ChartState` pour les applications qui embarquent le graphique
+
+use crate::finance_chart::settings::{ChartRenderMode, ChartStyle};
+
+use super::chart_state::{ChartState, DEFAULT_VISIBLE_CANDLES};
+
+/// Configuration initiale d'un graphique, à appliquer avant l'ajout des séries
+///
+/// `build()` donne le `ChartState` dont la vue se cale sur les dernières
+/// bougies à chaque ajout de série ; `chart_style()` donne le `ChartStyle` à
+/// passer aux widgets (mode de rendu, défilement automatique).
+#[derive(Debug, Clone)]
+pub struct ChartStateBuilder {
+    width: f32,
+    height: f32,
+    visible_candles: usize,
+    price_margin: Option<f32>,
+    style: ChartStyle,
+}
+
+impl ChartStateBuilder {
+    /// Configuration par défaut (150 bougies visibles, marge de prix de 10%)
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            width,
+            height,
+            visible_candles: DEFAULT_VISIBLE_CANDLES,
+            price_margin: None,
+            style: ChartStyle::default(),
+        }
+    }
+
+    /// Nombre de bougies affichées à l'ouverture d'une série (au moins 1)
+    pub fn visible_candles(mut self, count: usize) -> Self {
+        self.visible_candles = count.max(1);
+        self
+    }
+
+    /// Marge verticale de l'échelle de prix (0.1 = 10% de la plage en haut et en bas)
+    pub fn price_margin(mut self, margin_ratio: f32) -> Self {
+        self.price_margin = Some(margin_ratio);
+        self
+    }
+
+    /// Espace vide laissé à droite de la dernière bougie, en largeurs de bougie
+    #[allow(dead_code)] // API publique pour utilisation future
+    pub fn right_padding(mut self, candles: f32) -> Self {
+        self.style.right_padding_candles = candles.max(0.0);
+        self
+    }
+
+    /// Style de départ, complété par `render_mode`, `auto_scroll` et `right_padding`
+    pub fn style(mut self, style: ChartStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Mode de rendu initial des séries
+    #[allow(dead_code)] // API publique pour utilisation future
+    pub fn render_mode(mut self, render_mode: ChartRenderMode) -> Self {
+        self.style.render_mode = render_mode;
+        self
+    }
+
+    /// Défilement automatique vers les nouvelles bougies
+    #[allow(dead_code)] // API publique pour utilisation future
+    pub fn auto_scroll(mut self, enabled: bool) -> Self {
+        self.style.auto_scroll_enabled = enabled;
+        self
+    }
+
+    /// Style du graphique correspondant à la configuration
+    pub fn chart_style(&self) -> ChartStyle {
+        self.style.clone()
+    }
+
+    /// Construit l'état du graphique (sans série)
+    pub fn build(self) -> ChartState {
+        let mut state = ChartState::new(self.width, self.height);
+        state.initial_visible_candles = self.visible_candles;
+        state.right_padding_candles = self.style.right_padding_candles;
+        if let Some(margin_ratio) = self.price_margin {
+            state.viewport.set_price_margin(margin_ratio);
+        }
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::finance_chart::core::{Candle, SeriesData, SeriesId, TimeSeries};
+
+    fn series(count: usize) -> SeriesData {
+        let mut data = TimeSeries::new();
+        for i in 0..count {
+            data.push(Candle::new(i as i64 * 3600, 100.0, 101.0, 99.0, 100.5, 10.0)).unwrap();
+        }
+        SeriesData::new(SeriesId::new("TEST_1h"), "TEST".to_string(), "1h".to_string(), data)
+    }
+
+    #[test]
+    fn test_builder_sets_initial_view() {
+        let builder = ChartState::builder(800.0, 600.0)
+            .visible_candles(60)
+            .price_margin(0.02)
+            .render_mode(ChartRenderMode::Line)
+            .auto_scroll(false);
+        let style = builder.chart_style();
+        assert_eq!(style.render_mode, ChartRenderMode::Line);
+        assert!(!style.auto_scroll_enabled);
+
+        let mut state = builder.build();
+        state.add_series(series(500));
+        assert_eq!(state.viewport.time_scale().time_range(), (439 * 3600, 499 * 3600));
+        assert_eq!(state.viewport.price_scale().margin_ratio(), 0.02);
+
+        // Le recentrage sur les dernières bougies garde le même nombre de bougies
+        state.pan_horizontal(-400.0);
+        state.scroll_to_latest();
+        assert_eq!(state.viewport.time_scale().time_range(), (439 * 3600, 499 * 3600));
+    }
+
+    #[test]
+    fn test_builder_right_padding_leaves_future_space() {
+        let builder = ChartState::builder(800.0, 600.0).visible_candles(60).right_padding(10.0);
+        assert_eq!(builder.chart_style().right_padding_candles, 10.0);
+
+        let mut state = builder.build();
+        state.add_series(series(500));
+        // Les 60 dernières bougies restent visibles, suivies de 10 bougies d'espace vide
+        assert_eq!(state.viewport.time_scale().time_range(), (439 * 3600, 509 * 3600));
+
+        // Sans espace à droite, la vue se recale sur la dernière bougie
+        state.set_right_padding_candles(0.0);
+        assert_eq!(state.viewport.time_scale().time_range(), (439 * 3600, 499 * 3600));
+    }
+}
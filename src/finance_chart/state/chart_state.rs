@@ -1,12 +1,26 @@
-use super::super::core::{SeriesManager, SeriesData, Candle, SeriesId};
+use super::super::core::{SeriesManager, SeriesData, Candle, SeriesId, RenkoBrick, PriceAxis};
 use super::super::interaction::InteractionState;
 use super::super::viewport::Viewport;
 use super::super::realtime::{UpdateResult, RealtimeDataProvider};
-use super::super::indicators::macd::MacdValue;
+use super::super::indicators::macd::{MacdValue, MacdState, MacdSnapshot};
+use super::super::indicators::macd::calc::{MACD_FAST_PERIOD, MACD_SLOW_PERIOD, MACD_SIGNAL_PERIOD};
+use super::super::alerts::AlertsState;
+use crate::app::state::MaType;
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// Nombre de bougies visibles par défaut à l'initialisation
-const DEFAULT_VISIBLE_CANDLES: usize = 150;
+pub(crate) const DEFAULT_VISIBLE_CANDLES: usize = 150;
+
+/// Valeurs de moyenne mobile en cache, par (série, type, période)
+type MaCache = HashMap<(SeriesId, MaType, usize), Arc<Vec<Option<f64>>>>;
+
+/// Trous de données en cache par série, avec le nombre de bougies au moment du calcul
+type GapsCache = HashMap<SeriesId, (usize, Arc<Vec<(i64, i64)>>)>;
+
+/// Débuts de journée en cache par série, avec le nombre de bougies et le décalage UTC du calcul
+type DayBoundariesCache = HashMap<SeriesId, (usize, i32, Arc<Vec<i64>>)>;
 
 /// État complet du graphique
 /// 
@@ -19,8 +33,46 @@ pub struct ChartState {
     pub viewport: Viewport,
     /// État des interactions
     pub interaction: InteractionState,
-    /// Cache optionnel des valeurs MACD pré-calculées pour la série active
-    pub macd_cache: Option<Arc<Vec<Option<MacdValue>>>>,
+    /// Cache optionnel des valeurs MACD pré-calculées (et versionnées) pour la série active
+    pub macd_cache: Option<MacdSnapshot>,
+    /// Dernière version attribuée à `macd_cache` (jamais réinitialisée)
+    macd_version: u64,
+    /// EMA courantes du MACD, pour mettre à jour `macd_cache` bougie par bougie
+    macd_state: Option<MacdState>,
+    /// Cache des bougies Heikin-Ashi par série (calculé à la demande pendant le rendu)
+    heikin_ashi_cache: RefCell<HashMap<SeriesId, Arc<Vec<Candle>>>>,
+    /// Cache des moyennes mobiles par (série, type, période)
+    ma_cache: RefCell<MaCache>,
+    /// Cache des trous de données par série, avec le nombre de bougies au moment du calcul
+    gaps_cache: RefCell<GapsCache>,
+    /// Cache des débuts de journée par série, avec le nombre de bougies et le décalage UTC du calcul
+    day_boundaries_cache: RefCell<DayBoundariesCache>,
+    /// Taille des briques Renko (None = taille automatique basée sur l'ATR)
+    pub renko_brick_size: Option<f64>,
+    /// Intervalle d'affichage (None = intervalle natif de la série active)
+    display_interval: Option<String>,
+    /// Série active agrégée vers `display_interval`, avec l'ID de la série source
+    display_series: Option<(SeriesId, SeriesData)>,
+    /// Alertes de prix posées sur la série principale
+    pub alerts: AlertsState,
+    /// Timestamp survolé, partagé entre le graphique principal et les panneaux d'indicateurs
+    pub hover_timestamp: Option<i64>,
+    /// Position du crosshair (temps, prix) tant que le curseur survole le graphique principal,
+    /// affichée en étiquettes sur les axes
+    pub crosshair: Option<(i64, f64)>,
+    /// Timestamp de la bougie sélectionnée par un clic, surlignée et détaillée
+    /// dans le bandeau de lecture jusqu'à sa désélection
+    pub selected_candle: Option<i64>,
+    /// Mode replay : index (dans la série affichée) de la dernière bougie révélée.
+    /// Les bougies suivantes sont masquées pour le rendu, les indicateurs et les outils.
+    pub replay_cursor: Option<usize>,
+    /// Mode comparaison : chaque série active est ramenée à 0% au bord gauche de la vue
+    pub comparison_mode: bool,
+    /// Nombre de bougies affichées lors d'un recentrage sur les données récentes
+    pub(crate) initial_visible_candles: usize,
+    /// Espace vide laissé après la dernière bougie lors d'un recentrage, en bougies
+    /// (`ChartStyle::right_padding_candles`)
+    pub(crate) right_padding_candles: f32,
 }
 
 impl ChartState {
@@ -33,47 +85,463 @@ impl ChartState {
             viewport,
             interaction: InteractionState::default(),
             macd_cache: None,
+            macd_version: 0,
+            macd_state: None,
+            heikin_ashi_cache: RefCell::new(HashMap::new()),
+            ma_cache: RefCell::new(HashMap::new()),
+            gaps_cache: RefCell::new(HashMap::new()),
+            day_boundaries_cache: RefCell::new(HashMap::new()),
+            renko_brick_size: None,
+            display_interval: None,
+            display_series: None,
+            alerts: AlertsState::default(),
+            hover_timestamp: None,
+            crosshair: None,
+            selected_candle: None,
+            replay_cursor: None,
+            comparison_mode: false,
+            initial_visible_candles: DEFAULT_VISIBLE_CANDLES,
+            right_padding_candles: 0.0,
         }
     }
 
+    /// Configure un état de graphique avant l'ajout des séries
+    ///
+    /// Voir `ChartStateBuilder` pour les options (bougies visibles, marge des prix...).
+    pub fn builder(width: f32, height: f32) -> super::ChartStateBuilder {
+        super::ChartStateBuilder::new(width, height)
+    }
+
     /// Calcule et stocke le cache MACD pour la série active.
     ///
+    /// Le cache déjà à jour (maintenu incrémentalement par `update_candle` et
+    /// `merge_candles`) est réutilisé ; le calcul complet n'a lieu qu'après une invalidation.
     /// Retourne un `Arc` vers le vecteur pré-calculé si le calcul a réussi.
     pub fn compute_and_store_macd(&mut self) -> Option<Arc<Vec<Option<MacdValue>>>> {
+        if let Some(cache) = &self.macd_cache {
+            return Some(cache.values.clone());
+        }
         // Utilise la fonction utilitaire du module macd
         match crate::finance_chart::indicators::macd::calculate_all_macd_values(self) {
             Some(values) => {
-                let arc: Arc<Vec<Option<MacdValue>>> = Arc::new(values);
-                self.macd_cache = Some(arc.clone());
+                self.macd_version = self.macd_version.wrapping_add(1);
+                let snapshot = MacdSnapshot::new(values, self.macd_version);
+                let arc = snapshot.values.clone();
+                self.macd_cache = Some(snapshot);
+                self.macd_state = self.all_candles().map(|candles| {
+                    MacdState::from_candles(candles, MACD_FAST_PERIOD, MACD_SLOW_PERIOD, MACD_SIGNAL_PERIOD)
+                });
                 Some(arc)
             }
             None => None,
         }
     }
 
+    /// Invalide le cache MACD et son état incrémental
+    fn invalidate_macd(&mut self) {
+        self.macd_cache = None;
+        self.macd_state = None;
+    }
+
+    /// Répercute sur le cache MACD les bougies modifiées à partir de `earliest_change`
+    ///
+    /// Seuls le remplacement de la dernière bougie et l'ajout en fin de série sont
+    /// incrémentaux. Retourne `false` si un recalcul complet est nécessaire.
+    fn sync_macd(&mut self, series_id: &SeriesId, earliest_change: i64) -> bool {
+        // Le MACD porte sur la série affichée : pas d'incrémental sur une série agrégée
+        // ni sur une série tronquée par le replay
+        if self.display_series.is_some() || self.replay_cursor.is_some() {
+            return false;
+        }
+        let is_primary = self.series_manager
+            .active_series()
+            .next()
+            .is_some_and(|series| &series.id == series_id);
+        if !is_primary {
+            return false;
+        }
+        let Some(candles) = Self::displayed(&self.series_manager, &self.display_series)
+            .map(|series| series.data.all_candles())
+        else {
+            return false;
+        };
+        let (Some(state), Some(cache)) = (self.macd_state.as_mut(), self.macd_cache.as_mut()) else {
+            return false;
+        };
+        // En dessous de ce seuil, le calcul complet ne produit que des None
+        let min_len = MACD_SLOW_PERIOD + MACD_SIGNAL_PERIOD;
+        let known = state.len();
+        let Some(last_timestamp) = state.last_timestamp() else {
+            return false;
+        };
+        if known < min_len
+            || cache.values.len() != known
+            || candles.len() < known
+            || earliest_change < last_timestamp
+            || candles[known - 1].timestamp != last_timestamp
+        {
+            return false;
+        }
+
+        let values = Arc::make_mut(&mut cache.values);
+        let dirty_start = if earliest_change == last_timestamp {
+            values[known - 1] = state.replace_last(&candles[known - 1]);
+            known - 1
+        } else {
+            known
+        };
+        for candle in &candles[known..] {
+            values.push(state.push(candle));
+        }
+        // Seule la fin de série a changé : le rendu peut garder les portions intactes
+        self.macd_version = self.macd_version.wrapping_add(1);
+        cache.mark_dirty_from(dirty_start, self.macd_version);
+        true
+    }
+
+    /// Ajoute au cache MACD la bougie que le replay vient de révéler
+    ///
+    /// Retourne `false` si le cache ne couvre pas exactement les bougies
+    /// révélées jusqu'à la précédente (un recalcul complet est alors nécessaire).
+    fn push_replay_macd(&mut self) -> bool {
+        let Some(candles) = self.all_candles() else {
+            return false;
+        };
+        let Some((candle, previous)) = candles.split_last() else {
+            return false;
+        };
+        let candle = *candle;
+        let previous_timestamp = previous.last().map(|c| c.timestamp);
+        let revealed = previous.len();
+        let (Some(state), Some(cache)) = (self.macd_state.as_mut(), self.macd_cache.as_mut()) else {
+            return false;
+        };
+        // En dessous de ce seuil, le calcul complet ne produit que des None
+        if revealed < MACD_SLOW_PERIOD + MACD_SIGNAL_PERIOD
+            || state.len() != revealed
+            || cache.values.len() != revealed
+            || state.last_timestamp() != previous_timestamp
+        {
+            return false;
+        }
+
+        Arc::make_mut(&mut cache.values).push(state.push(&candle));
+        self.macd_version = self.macd_version.wrapping_add(1);
+        cache.mark_dirty_from(revealed, self.macd_version);
+        true
+    }
+
     /// Ajoute une série au graphique
     pub fn add_series(&mut self, series: SeriesData) {
         self.series_manager.add_series(series);
         // Mettre à jour le viewport avec la plage globale après ajout
         self.update_viewport_from_series();
         // Invalider le cache MACD lorsque les données changent
-        self.macd_cache = None;
+        self.invalidate_macd();
+        self.heikin_ashi_cache.borrow_mut().clear();
+        self.ma_cache.borrow_mut().clear();
+        self.gaps_cache.borrow_mut().clear();
+        self.day_boundaries_cache.borrow_mut().clear();
+    }
+
+    /// Intervalle d'affichage courant (None = intervalle natif)
+    pub fn display_interval(&self) -> Option<&str> {
+        self.display_interval.as_deref()
+    }
+
+    /// Change l'intervalle affiché sans modifier la série stockée
+    ///
+    /// La série active est agrégée vers `interval` (voir `core::resample`).
+    /// `None` revient à l'intervalle natif.
+    pub fn set_display_interval(&mut self, interval: Option<String>) -> Result<(), String> {
+        let previous = std::mem::replace(&mut self.display_interval, interval);
+        if let Err(e) = self.try_refresh_display_series() {
+            self.display_interval = previous;
+            self.refresh_display_series();
+            return Err(e);
+        }
+        // Les index du replay ne correspondent plus à la série agrégée
+        self.replay_cursor = None;
+        self.invalidate_macd();
+        self.heikin_ashi_cache.borrow_mut().clear();
+        self.ma_cache.borrow_mut().clear();
+        self.gaps_cache.borrow_mut().clear();
+        self.day_boundaries_cache.borrow_mut().clear();
+        self.focus_displayed_series();
+        Ok(())
+    }
+
+    /// Recalcule la série agrégée (à appeler quand la série active change)
+    pub fn refresh_display_series(&mut self) {
+        if let Err(e) = self.try_refresh_display_series() {
+            eprintln!("⚠️ Agrégation impossible: {}", e);
+            self.display_series = None;
+        }
+    }
+
+    fn try_refresh_display_series(&mut self) -> Result<(), String> {
+        self.display_series = None;
+        let Some(interval) = self.display_interval.as_deref() else {
+            return Ok(());
+        };
+        let Some(source) = self.series_manager.active_series().next() else {
+            return Ok(());
+        };
+        if source.interval != interval {
+            let resampled = crate::finance_chart::core::resample(source, interval)?;
+            self.display_series = Some((source.id.clone(), resampled));
+        }
+        Ok(())
+    }
+
+    /// Met à jour la série agrégée après une modification de `series_id` à partir de `earliest_change`
+    ///
+    /// Seuls les derniers paquets sont recalculés quand la modification porte sur la
+    /// fin de la série (cas du temps réel) ; sinon la série est ré-agrégée entièrement.
+    fn refresh_display_tail(&mut self, series_id: &SeriesId, earliest_change: i64) {
+        let Some((source_id, resampled)) = self.display_series.as_mut() else {
+            return;
+        };
+        if source_id != series_id {
+            return;
+        }
+        let Some(source) = self.series_manager.get_series(series_id) else {
+            return;
+        };
+        if !matches!(crate::finance_chart::core::resample::resample_tail(source, resampled, earliest_change), Ok(true)) {
+            self.refresh_display_series();
+        }
+    }
+
+    /// Série réellement affichée : la série agrégée si elle correspond à la série active
+    pub fn displayed_series(&self) -> Option<&SeriesData> {
+        Self::displayed(&self.series_manager, &self.display_series)
+    }
+
+    fn displayed<'a>(
+        series_manager: &'a SeriesManager,
+        display_series: &'a Option<(SeriesId, SeriesData)>,
+    ) -> Option<&'a SeriesData> {
+        let active = series_manager.active_series().next()?;
+        match display_series {
+            Some((source_id, resampled)) if *source_id == active.id => Some(resampled),
+            _ => Some(active),
+        }
+    }
+
+    /// Retourne les bougies Heikin-Ashi de toute la série
+    ///
+    /// La transformation part de la première bougie chargée pour rester stable
+    /// pendant le pan. Le résultat est mis en cache jusqu'à la prochaine modification
+    /// de la série.
+    pub fn heikin_ashi_candles(&self, series_id: &SeriesId) -> Option<Arc<Vec<Candle>>> {
+        let series = self.displayed_series()
+            .filter(|s| s.id == *series_id)
+            .or_else(|| self.series_manager.get_series(series_id))?;
+        let candles = series.data.all_candles();
+
+        if let Some(cached) = self.heikin_ashi_cache.borrow().get(series_id) {
+            // Garde-fou si la série a été modifiée sans passer par ChartState
+            if cached.len() == candles.len()
+                && cached.last().map(|c| c.timestamp) == candles.last().map(|c| c.timestamp)
+            {
+                return Some(cached.clone());
+            }
+        }
+
+        let transformed = Arc::new(crate::finance_chart::core::to_heikin_ashi(candles));
+        self.heikin_ashi_cache
+            .borrow_mut()
+            .insert(series_id.clone(), transformed.clone());
+        Some(transformed)
+    }
+
+    /// Taille de brique Renko effective pour la série active
+    pub fn effective_renko_brick_size(&self) -> Option<f64> {
+        use crate::finance_chart::core::renko::{auto_brick_size, RENKO_ATR_PERIOD};
+        self.renko_brick_size
+            .or_else(|| auto_brick_size(self.all_candles()?, RENKO_ATR_PERIOD))
+    }
+
+    /// Construit les briques Renko de la série active (depuis la première bougie chargée)
+    pub fn renko_bricks(&self) -> Vec<RenkoBrick> {
+        match (self.all_candles(), self.effective_renko_brick_size()) {
+            (Some(candles), Some(size)) => crate::finance_chart::core::build_renko(candles, size),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Valeurs d'une moyenne mobile pour la série affichée
+    ///
+    /// Le résultat est mis en cache par (série, type, période) jusqu'à la prochaine
+    /// modification de la série.
+    pub fn moving_average_values(&self, ma_type: MaType, period: usize) -> Option<Arc<Vec<Option<f64>>>> {
+        let series = self.displayed_series()?;
+        let candles = series.data.all_candles();
+        if candles.is_empty() {
+            return None;
+        }
+
+        let key = (series.id.clone(), ma_type, period);
+        if let Some(cached) = self.ma_cache.borrow().get(&key) {
+            // Garde-fou si la série a été modifiée sans passer par ChartState
+            if cached.len() == candles.len() {
+                return Some(cached.clone());
+            }
+        }
+
+        let values = Arc::new(crate::finance_chart::indicators::moving_average::calc::calculate_ma(
+            candles, period, ma_type,
+        ));
+        self.ma_cache.borrow_mut().insert(key, values.clone());
+        Some(values)
+    }
+
+    /// Trous de données de la série affichée, en plages de temps à griser
+    ///
+    /// Les trous sont détectés via `TimeSeries::detect_gaps` avec l'intervalle de la
+    /// série, puis élargis d'une demi-période de chaque côté pour couvrir l'espace
+    /// vide entre les deux bougies qui les bordent. Le résultat est mis en cache
+    /// jusqu'à la prochaine modification de la série.
+    pub fn data_gaps(&self) -> Option<Arc<Vec<(i64, i64)>>> {
+        let series = self.displayed_series()?;
+        let interval = crate::finance_chart::core::resample::parse_interval(&series.interval)?.approx_seconds();
+        let candle_count = series.data.len();
+
+        if let Some((cached_count, cached)) = self.gaps_cache.borrow().get(&series.id) {
+            // Garde-fou si la série a été modifiée sans passer par ChartState
+            if *cached_count == candle_count {
+                return Some(cached.clone());
+            }
+        }
+
+        let half_period = interval / 2;
+        let gaps: Arc<Vec<(i64, i64)>> = Arc::new(
+            series.data
+                .detect_gaps(interval)
+                .into_iter()
+                .map(|(start, end)| (start - half_period, end + half_period))
+                .collect(),
+        );
+        self.gaps_cache
+            .borrow_mut()
+            .insert(series.id.clone(), (candle_count, gaps.clone()));
+        Some(gaps)
+    }
+
+    /// Timestamps des bougies qui ouvrent une nouvelle journée dans la série affichée
+    ///
+    /// Retourne `None` si l'intervalle n'est pas intraday (séparateurs sans objet).
+    /// Les indices viennent de `TimeSeries::day_boundaries` et sont mis en cache
+    /// jusqu'à la prochaine modification de la série ou changement de fuseau.
+    pub fn day_boundaries(&self, utc_offset_minutes: i32) -> Option<Arc<Vec<i64>>> {
+        let series = self.displayed_series()?;
+        let interval = crate::finance_chart::core::resample::parse_interval(&series.interval)?.approx_seconds();
+        if interval >= 86_400 {
+            return None;
+        }
+        let candle_count = series.data.len();
+
+        if let Some((cached_count, cached_offset, cached)) = self.day_boundaries_cache.borrow().get(&series.id) {
+            if *cached_count == candle_count && *cached_offset == utc_offset_minutes {
+                return Some(cached.clone());
+            }
+        }
+
+        let candles = series.data.all_candles();
+        let boundaries: Arc<Vec<i64>> = Arc::new(
+            series.data
+                .day_boundaries(utc_offset_minutes)
+                .into_iter()
+                .map(|i| candles[i].timestamp)
+                .collect(),
+        );
+        self.day_boundaries_cache
+            .borrow_mut()
+            .insert(series.id.clone(), (candle_count, utc_offset_minutes, boundaries.clone()));
+        Some(boundaries)
+    }
+
+    /// Invalide les caches dérivés (Heikin-Ashi, moyennes mobiles, trous, journées) d'une série
+    fn invalidate_series_caches(&self, series_id: &SeriesId) {
+        self.heikin_ashi_cache.borrow_mut().remove(series_id);
+        // La série agrégée affichée a son propre identifiant
+        let display_id = self.display_series
+            .as_ref()
+            .filter(|(source_id, _)| source_id == series_id)
+            .map(|(_, resampled)| resampled.id.clone());
+        self.ma_cache
+            .borrow_mut()
+            .retain(|(id, _, _), _| id != series_id && Some(id) != display_id.as_ref());
+        self.gaps_cache
+            .borrow_mut()
+            .retain(|id, _| id != series_id && Some(id) != display_id.as_ref());
+        self.day_boundaries_cache
+            .borrow_mut()
+            .retain(|id, _| id != series_id && Some(id) != display_id.as_ref());
+    }
+
+    /// Affiche ou masque une série superposée
+    ///
+    /// Si la série principale change, la série agrégée et le viewport sont recalculés.
+    pub fn toggle_series_visibility(&mut self, series_id: &SeriesId) -> bool {
+        let primary_before = self.series_manager.active_series().next().map(|s| s.id.clone());
+        if !self.series_manager.toggle_series_visibility(series_id) {
+            return false;
+        }
+        let primary_after = self.series_manager.active_series().next().map(|s| s.id.clone());
+        if primary_before != primary_after {
+            self.invalidate_macd();
+            self.update_viewport_from_series();
+        }
+        true
     }
 
     /// Met à jour le viewport en fonction des séries actives
     /// Réinitialise le zoom pour afficher correctement la série active
     pub fn update_viewport_from_series(&mut self) {
+        // La série active a pu changer : le replay porte sur l'ancienne série
+        self.replay_cursor = None;
+        // Recalculer la série agrégée éventuelle
+        self.refresh_display_series();
+        self.invalidate_macd();
+        self.focus_displayed_series();
+    }
+
+    /// Recentre le viewport sur les dernières bougies de la série affichée
+    fn focus_displayed_series(&mut self) {
         // Puisqu'on n'affiche qu'une seule série à la fois, utiliser focus_on_recent
         // qui réinitialise correctement le zoom horizontal et vertical
-        if let Some(active_series) = self.series_manager.active_series().next() {
+        if let Some(active_series) = Self::displayed(&self.series_manager, &self.display_series) {
             // Utiliser focus_on_recent qui calcule automatiquement :
             // - La plage temporelle pour les N dernières bougies
             // - La plage de prix pour les bougies visibles
             // Cela réinitialise complètement le zoom
-            self.viewport.focus_on_recent(&active_series.data, DEFAULT_VISIBLE_CANDLES);
+            self.viewport.focus_on_recent(&active_series.data, self.initial_visible_candles, self.right_padding_candles);
         }
     }
 
+    /// Change l'espace vide à droite de la dernière bougie et recentre la vue
+    pub fn set_right_padding_candles(&mut self, candles: f32) {
+        self.right_padding_candles = candles.max(0.0);
+        self.focus_displayed_series();
+    }
+
+    /// Ajuste le viewport pour afficher toutes les bougies de la série affichée
+    ///
+    /// Retourne `false` (viewport inchangé) si la série est vide ou absente.
+    pub fn fit_to_data(&mut self) -> bool {
+        match Self::displayed(&self.series_manager, &self.display_series) {
+            Some(series) => self.viewport.fit_to_data(series.data.all_candles()),
+            None => false,
+        }
+    }
+
+    /// Zoome sur une zone (plage de temps, plage de prix) sélectionnée à la souris
+    pub fn zoom_to_rect(&mut self, time_range: (i64, i64), price_range: (f64, f64)) -> bool {
+        self.viewport.zoom_to_rect(time_range, price_range)
+    }
+
     /// Met à jour la taille du viewport
     pub fn resize(&mut self, width: f32, height: f32) {
         self.viewport.set_size(width, height);
@@ -83,28 +551,247 @@ impl ChartState {
     pub fn visible_candles(&self) -> Vec<(super::super::core::SeriesId, &[super::super::core::Candle])> {
         let (min_time, max_time) = self.viewport.time_scale().time_range();
         
-        // Collecter les bougies visibles
-        self.series_manager.visible_candles(min_time..max_time)
+        // Série agrégée : elle remplace la série principale, les séries superposées restent natives
+        let mut visible = if let Some((source_id, resampled)) = &self.display_series {
+            self.series_manager
+                .active_series()
+                .map(|series| {
+                    let shown = if series.id == *source_id { resampled } else { series };
+                    (shown.id.clone(), shown.data.visible_candles(min_time..max_time))
+                })
+                .collect()
+        } else {
+            // Collecter les bougies visibles
+            self.series_manager.visible_candles(min_time..max_time)
+        };
+
+        // Replay : masquer les bougies postérieures à la dernière bougie révélée
+        if let Some(end_time) = self.replay_end_time() {
+            for (_, candles) in visible.iter_mut() {
+                *candles = &candles[..candles.partition_point(|c| c.timestamp <= end_time)];
+            }
+        }
+        visible
+    }
+
+    /// Axe de prix sur lequel une série affichée est tracée
+    ///
+    /// La série principale (éventuellement agrégée) reste toujours sur l'axe de droite,
+    /// qui porte le zoom, le verrouillage et les outils de dessin.
+    pub fn series_axis(&self, series_id: &SeriesId) -> PriceAxis {
+        let is_primary = self.series_manager.active_series().next().is_some_and(|series| series.id == *series_id)
+            || self.displayed_series().is_some_and(|series| series.id == *series_id);
+        if is_primary {
+            return PriceAxis::Right;
+        }
+        self.series_manager
+            .get_series(series_id)
+            .map(|series| series.axis)
+            .unwrap_or_default()
+    }
+
+    /// Viewport de l'axe de gauche
+    ///
+    /// Même plage temporelle que le viewport principal, plage de prix ajustée aux
+    /// bougies visibles des séries assignées à gauche (avec 5% de marge).
+    /// `None` si aucune série visible n'est assignée à l'axe de gauche.
+    pub fn left_axis_viewport(&self) -> Option<Viewport> {
+        // En mode comparaison, toutes les séries partagent l'échelle en pourcentage
+        if self.comparison_mode {
+            return None;
+        }
+        let (min_price, max_price) = self.visible_candles()
+            .into_iter()
+            .filter(|(series_id, _)| self.series_axis(series_id) == PriceAxis::Left)
+            .flat_map(|(_, candles)| candles.iter())
+            .fold(None, |acc, candle| {
+                Some(match acc {
+                    None => (candle.low, candle.high),
+                    Some((min, max)) => (f64::min(min, candle.low), f64::max(max, candle.high)),
+                })
+            })?;
+        let margin = if max_price > min_price {
+            (max_price - min_price) * 0.05
+        } else {
+            (max_price.abs() * 0.01).max(0.01)
+        };
+        Some(self.viewport.with_price_range(min_price - margin, max_price + margin))
+    }
+
+    /// Bougies visibles de chaque série active, en variation (%) depuis la clôture
+    /// de sa première bougie visible
+    ///
+    /// Toutes les séries partent de 0% au bord gauche de la vue, ce qui permet de
+    /// comparer des symboles de prix très différents sur une même échelle.
+    /// Les séries dont la clôture de référence est nulle sont ignorées.
+    pub fn comparison_candles(&self) -> Vec<(SeriesId, Vec<Candle>)> {
+        self.visible_candles()
+            .into_iter()
+            .filter_map(|(series_id, candles)| {
+                let base = candles.first()?.close;
+                if base == 0.0 {
+                    return None;
+                }
+                let to_percent = |price: f64| (price / base - 1.0) * 100.0;
+                let rebased = candles
+                    .iter()
+                    .map(|candle| Candle::new(
+                        candle.timestamp,
+                        to_percent(candle.open),
+                        to_percent(candle.high),
+                        to_percent(candle.low),
+                        to_percent(candle.close),
+                        candle.volume,
+                    ))
+                    .collect();
+                Some((series_id, rebased))
+            })
+            .collect()
+    }
+
+    /// Viewport du mode comparaison : échelle linéaire en pourcentage ajustée
+    /// aux séries rebasées (avec 5% de marge)
+    pub fn comparison_viewport(&self, series: &[(SeriesId, Vec<Candle>)]) -> Option<Viewport> {
+        let (min_percent, max_percent) = series
+            .iter()
+            .flat_map(|(_, candles)| candles.iter())
+            .fold(None, |acc, candle| {
+                Some(match acc {
+                    None => (candle.low, candle.high),
+                    Some((min, max)) => (f64::min(min, candle.low), f64::max(max, candle.high)),
+                })
+            })?;
+        let margin = ((max_percent - min_percent) * 0.05).max(0.1);
+        let mut viewport = self.viewport.with_price_range(min_percent - margin, max_percent + margin);
+        // Les variations peuvent être négatives : pas d'échelle logarithmique
+        viewport.set_log_scale(false);
+        Some(viewport)
+    }
+
+    /// Active ou désactive le mode comparaison
+    pub fn toggle_comparison_mode(&mut self) {
+        self.comparison_mode = !self.comparison_mode;
     }
 
     /// Retourne toutes les bougies de la première série active
     /// Utile pour calculer des indicateurs qui nécessitent l'historique complet
+    ///
+    /// En mode replay, seules les bougies révélées sont retournées.
     pub fn all_candles(&self) -> Option<&[super::super::core::Candle]> {
-        self.series_manager.active_series()
-            .next()
-            .map(|series| series.data.all_candles())
+        let candles = self.displayed_series()?.data.all_candles();
+        match self.replay_cursor {
+            Some(cursor) if !candles.is_empty() => Some(&candles[..=cursor.min(candles.len() - 1)]),
+            _ => Some(candles),
+        }
+    }
+
+    /// Dernière bougie révélée en mode replay (`None` hors replay)
+    pub fn replay_candle(&self) -> Option<&Candle> {
+        self.replay_cursor?;
+        self.all_candles()?.last()
+    }
+
+    /// Timestamp de la dernière bougie révélée en mode replay
+    fn replay_end_time(&self) -> Option<i64> {
+        self.replay_candle().map(|candle| candle.timestamp)
+    }
+
+    /// Démarre le replay à la bougie la plus proche de `time`
+    ///
+    /// Retourne `false` si la série affichée est vide.
+    pub fn start_replay(&mut self, time: i64) -> bool {
+        let Some(index) = self.displayed_series()
+            .and_then(|series| super::super::interaction::nearest_candle_index(time, series.data.all_candles()))
+        else {
+            return false;
+        };
+        self.replay_cursor = Some(index);
+        self.invalidate_macd();
+        true
+    }
+
+    /// Révèle la bougie suivante et fait suivre la vue
+    ///
+    /// Retourne `false` (curseur inchangé) si toutes les bougies sont déjà révélées.
+    pub fn step_replay(&mut self) -> bool {
+        let Some(cursor) = self.replay_cursor else {
+            return false;
+        };
+        let total = self.displayed_series().map_or(0, |series| series.data.len());
+        if cursor + 1 >= total {
+            return false;
+        }
+        self.replay_cursor = Some(cursor + 1);
+        // Mise à jour O(1) du MACD, sinon recalcul complet au prochain accès
+        if !self.push_replay_macd() {
+            self.invalidate_macd();
+        }
+
+        // Garder la bougie révélée dans la vue (marge de 20% à droite)
+        if let Some(timestamp) = self.replay_end_time() {
+            let x = self.viewport.time_scale().time_to_x(timestamp);
+            let width = self.viewport.width();
+            if x > width * 0.9 {
+                self.viewport.pan_horizontal(x - width * 0.8);
+            }
+        }
+        true
+    }
+
+    /// Quitte le mode replay et révèle toutes les bougies
+    pub fn stop_replay(&mut self) {
+        self.replay_cursor = None;
+        self.invalidate_macd();
+    }
+
+    /// Met à jour la position du crosshair depuis la position de la souris sur le graphique
+    ///
+    /// Hors du graphique, le crosshair est effacé. En mode aimant (`magnet`), le prix
+    /// est celui du point OHLC accroché, comme la ligne dessinée par le graphique.
+    pub fn update_crosshair(&mut self, position: iced::Point, magnet: bool) {
+        let inside = position.x >= 0.0 && position.x <= self.viewport.width()
+            && position.y >= 0.0 && position.y <= self.viewport.height();
+        if !inside {
+            self.crosshair = None;
+            return;
+        }
+        let time = self.viewport.time_scale().x_to_time(position.x);
+        let snapped_price = if magnet {
+            self.visible_candles()
+                .iter()
+                .find_map(|(_, candles)| crate::finance_chart::render::find_nearest_ohlc(position, candles, &self.viewport))
+                .map(|(_, price)| price)
+        } else {
+            None
+        };
+        let price = snapped_price.unwrap_or_else(|| self.viewport.price_scale().y_to_price(position.y));
+        self.crosshair = Some((time, price));
+    }
+
+    /// Index (dans `all_candles`) de la bougie la plus proche du timestamp survolé
+    pub fn hover_candle_index(&self) -> Option<usize> {
+        let time = self.hover_timestamp?;
+        super::super::interaction::nearest_candle_index(time, self.all_candles()?)
+    }
+
+    /// Intervalle de la série affichée (intervalle d'agrégation s'il y en a un)
+    pub fn displayed_interval(&self) -> Option<&str> {
+        self.displayed_series()
+            .map(|series| series.interval.as_str())
     }
 
     /// Retourne la dernière bougie de la première série active (pour la ligne de prix courant)
+    ///
+    /// En mode replay, il s'agit de la dernière bougie révélée.
     pub fn last_candle(&self) -> Option<&super::super::core::Candle> {
-        self.series_manager.active_series()
-            .next()
-            .and_then(|series| series.data.last_candle())
+        self.all_candles()
+            .and_then(|candles| candles.last())
     }
 
     /// Effectue un pan horizontal (déplacement temporel)
     pub fn pan_horizontal(&mut self, delta_x: f32) {
         self.viewport.pan_horizontal(delta_x);
+        self.clamp_to_history();
     }
 
     /// Effectue un pan vertical (déplacement prix)
@@ -112,19 +799,90 @@ impl ChartState {
         self.viewport.pan_vertical(delta_y);
     }
 
-    /// Effectue un zoom horizontal (axe X / temps)
-    pub fn zoom(&mut self, factor: f64) {
-        self.viewport.zoom(factor);
+    /// Effectue un zoom horizontal (axe X / temps) autour du point écran `anchor`
+    pub fn zoom(&mut self, factor: f64, anchor: iced::Point) {
+        self.viewport.zoom(factor, anchor);
+        self.clamp_to_history();
     }
 
-    /// Effectue un zoom vertical (axe Y / prix) - ALT + molette
-    pub fn zoom_vertical(&mut self, factor: f64) {
-        self.viewport.zoom_vertical(factor);
+    /// Étire ou compresse la plage temporelle, bord droit fixe (drag sur l'axe X)
+    pub fn drag_scale_time(&mut self, delta_x: f32) {
+        self.viewport.drag_scale_time(delta_x);
+        self.clamp_to_history();
     }
 
-    /// Effectue un zoom sur les deux axes - CTRL + molette
-    pub fn zoom_both(&mut self, factor: f64) {
-        self.viewport.zoom_both(factor);
+    /// Bascule l'échelle de prix entre linéaire et logarithmique
+    pub fn toggle_log_scale(&mut self) {
+        let log = !self.viewport.price_scale().is_log();
+        self.viewport.set_log_scale(log);
+    }
+
+    /// Inverse le sens de l'échelle de prix (prix élevés en bas)
+    pub fn toggle_inverted_scale(&mut self) {
+        let inverted = !self.viewport.price_scale().is_inverted();
+        self.viewport.set_inverted_scale(inverted);
+    }
+
+    /// Verrouille ou déverrouille l'ajustement automatique de l'axe des prix
+    pub fn toggle_auto_scale(&mut self) {
+        let auto_scale = !self.viewport.auto_scale();
+        self.viewport.set_auto_scale(auto_scale);
+    }
+
+    /// Effectue un zoom vertical (axe Y / prix) autour du point écran `anchor` - ALT + molette
+    pub fn zoom_vertical(&mut self, factor: f64, anchor: iced::Point) {
+        self.viewport.zoom_vertical(factor, anchor);
+    }
+
+    /// Étire ou compresse la plage de prix autour de son centre (drag sur l'axe Y)
+    pub fn drag_scale_price(&mut self, delta_y: f32) {
+        self.viewport.drag_scale_price(delta_y);
+    }
+
+    /// Effectue un zoom sur les deux axes autour du point écran `anchor` - CTRL + molette
+    pub fn zoom_both(&mut self, factor: f64, anchor: iced::Point) {
+        self.viewport.zoom_both(factor, anchor);
+        self.clamp_to_history();
+    }
+
+    /// Empêche la vue de remonter avant les bougies conservées en mémoire
+    ///
+    /// Sans effet si l'historique de la série principale n'a pas été tronqué.
+    fn clamp_to_history(&mut self) {
+        let first_kept = self.series_manager
+            .active_series()
+            .next()
+            .filter(|series| series.history_trimmed)
+            .and_then(|series| series.data.min_timestamp());
+        if let Some(first_kept) = first_kept {
+            self.viewport.clamp_min_time(first_kept);
+        }
+    }
+
+    /// Limite chaque série à `max_candles` bougies en supprimant les plus anciennes
+    ///
+    /// Les caches d'indicateurs des séries tronquées sont invalidés, la position du
+    /// replay est conservée (par timestamp) et la vue est ramenée dans l'historique restant.
+    ///
+    /// # Retourne
+    /// `true` si au moins une série a été tronquée
+    pub fn apply_history_cap(&mut self, max_candles: usize) -> bool {
+        let replay_time = self.replay_end_time();
+        let trimmed = self.series_manager.trim_all_to(max_candles);
+        if trimmed.is_empty() {
+            return false;
+        }
+        for series_id in &trimmed {
+            self.invalidate_series_caches(series_id);
+        }
+        self.refresh_display_series();
+        self.invalidate_macd();
+        if let Some(time) = replay_time {
+            self.replay_cursor = self.displayed_series()
+                .and_then(|series| super::super::interaction::nearest_candle_index(time, series.data.all_candles()));
+        }
+        self.clamp_to_history();
+        true
     }
 
     /// Démarre un pan (drag)
@@ -144,6 +902,11 @@ impl ChartState {
             // Le delta vertical n'est pas inversé (tirer vers le haut = monter)
             self.pan_horizontal(-delta_x);
             
+            // Axe des prix verrouillé : le pan reste purement horizontal
+            if !self.viewport.auto_scale() {
+                return;
+            }
+            
             // Ne déplacer verticalement que si le mouvement vertical est significatif
             // Cela évite de déplacer l'axe Y quand on veut seulement panner horizontalement
             // Seuil : le mouvement vertical doit être au moins 25% du mouvement horizontal
@@ -177,8 +940,21 @@ impl ChartState {
     }
 
     /// Termine le pan
-    pub fn end_pan(&mut self) {
+    ///
+    /// Avec `snap_to_candles`, les bords de la vue sont alignés sur des limites de
+    /// bougies de la série affichée (uniquement si un pan était en cours).
+    pub fn end_pan(&mut self, snap_to_candles: bool) {
+        let was_panning = self.interaction.is_panning;
         self.interaction.end_pan();
+        if !(snap_to_candles && was_panning) {
+            return;
+        }
+        let interval = self.displayed_interval()
+            .and_then(crate::finance_chart::core::resample::parse_interval)
+            .map(|span| span.approx_seconds());
+        if let Some(interval) = interval {
+            self.viewport.snap_to_candles(interval);
+        }
     }
 
     // ============================================================================
@@ -198,14 +974,18 @@ impl ChartState {
     /// Le résultat de la mise à jour
     pub fn update_candle(&mut self, series_id: &SeriesId, candle: Candle) -> UpdateResult {
         match self.series_manager.update_series_candle(series_id, candle) {
-            Some(Ok(true)) => {
-                // Invalider le cache MACD car les données ont changé
-                self.macd_cache = None;
-                UpdateResult::CandleUpdated
-            }
-            Some(Ok(false)) => {
-                self.macd_cache = None;
-                UpdateResult::NewCandle
+            Some(Ok(updated)) => {
+                self.invalidate_series_caches(series_id);
+                self.refresh_display_tail(series_id, candle.timestamp);
+                // Mise à jour O(1) du MACD, sinon recalcul complet au prochain accès
+                if !self.sync_macd(series_id, candle.timestamp) {
+                    self.invalidate_macd();
+                }
+                if updated {
+                    UpdateResult::CandleUpdated
+                } else {
+                    UpdateResult::NewCandle
+                }
             }
             Some(Err(e)) => UpdateResult::Error(format!("Bougie invalide: {}", e)),
             None => UpdateResult::Error(format!("Série {} introuvable", series_id.name)),
@@ -224,10 +1004,18 @@ impl ChartState {
     /// # Retourne
     /// Le résultat de la fusion avec le nombre de nouvelles bougies ajoutées
     pub fn merge_candles(&mut self, series_id: &SeriesId, candles: Vec<Candle>) -> UpdateResult {
+        let earliest_change = candles.iter().map(|c| c.timestamp).min();
         match self.series_manager.merge_series_candles(series_id, candles) {
             Some(added) => {
-                // Invalider le cache MACD car les données ont été modifiées
-                self.macd_cache = None;
+                // Invalider les caches car les données ont été modifiées
+                self.invalidate_series_caches(series_id);
+                if let Some(ts) = earliest_change {
+                    self.refresh_display_tail(series_id, ts);
+                }
+                let synced = earliest_change.is_some_and(|ts| self.sync_macd(series_id, ts));
+                if !synced {
+                    self.invalidate_macd();
+                }
                 UpdateResult::MultipleCandlesAdded(added)
             }
             None => UpdateResult::Error(format!("Série {} introuvable", series_id.name)),
@@ -333,17 +1121,157 @@ impl ChartState {
     ///
     /// Utile après une mise à jour en temps réel pour suivre les nouvelles bougies.
     pub fn auto_scroll_to_latest(&mut self) {
+        // Si on est déjà proche de la fin, ajuster pour montrer les nouvelles données
+        let latest = self.series_manager.active_series().next().and_then(|active_series| {
+            let padding = Viewport::right_padding_duration(&active_series.data, self.right_padding_candles);
+            active_series.data.max_timestamp().map(|max_time| (max_time, padding))
+        });
+        if let Some((max_time, padding)) = latest {
+            let (current_min, current_max) = self.viewport.time_scale().time_range();
+            // Si on est dans les 10% de la fin (hors espace vide à droite), ajuster pour suivre
+            let range = current_max - current_min;
+            if max_time > current_max - padding - (range / 10) {
+                self.scroll_to_latest();
+            }
+        }
+    }
+
+    /// Ramène la vue sur les dernières bougies, quelle que soit la position actuelle
+    pub fn scroll_to_latest(&mut self) {
         if let Some(active_series) = self.series_manager.active_series().next() {
-            // Si on est déjà proche de la fin, ajuster pour montrer les nouvelles données
-            if let Some(max_time) = active_series.data.max_timestamp() {
-                let (current_min, current_max) = self.viewport.time_scale().time_range();
-                // Si on est dans les 10% de la fin, ajuster pour suivre
-                let range = current_max - current_min;
-                if max_time > current_max - (range / 10) {
-                    // Ajuster le viewport pour montrer les dernières données
-                    self.viewport.focus_on_recent(&active_series.data, DEFAULT_VISIBLE_CANDLES);
+            self.viewport.focus_on_recent(&active_series.data, self.initial_visible_candles, self.right_padding_candles);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::finance_chart::core::TimeSeries;
+
+    fn state_with_candles(count: usize) -> ChartState {
+        let mut data = TimeSeries::new();
+        for i in 0..count {
+            data.push(Candle::new(i as i64 * 3600, 100.0, 101.0, 99.0, 100.5, 10.0)).unwrap();
+        }
+        let mut state = ChartState::new(800.0, 600.0);
+        state.add_series(SeriesData::new(SeriesId::new("TEST_1h"), "TEST".to_string(), "1h".to_string(), data));
+        state
+    }
+
+    #[test]
+    fn test_replay_hides_future_candles() {
+        let mut state = state_with_candles(20);
+        assert!(state.start_replay(10 * 3600));
+        assert_eq!(state.all_candles().map(|c| c.len()), Some(11));
+        assert_eq!(state.last_candle().map(|c| c.timestamp), Some(10 * 3600));
+        assert!(state.visible_candles().iter().all(|(_, candles)| candles.iter().all(|c| c.timestamp <= 10 * 3600)));
+
+        assert!(state.step_replay());
+        assert_eq!(state.replay_candle().map(|c| c.timestamp), Some(11 * 3600));
+
+        state.stop_replay();
+        assert!(state.replay_candle().is_none());
+        assert_eq!(state.all_candles().map(|c| c.len()), Some(20));
+    }
+
+    #[test]
+    fn test_history_cap_keeps_view_in_memory() {
+        let mut state = state_with_candles(20);
+        assert!(state.start_replay(15 * 3600));
+        assert!(state.apply_history_cap(10));
+        assert_eq!(state.all_candles().map(|c| c.len()), Some(6));
+        assert_eq!(state.replay_candle().map(|c| c.timestamp), Some(15 * 3600));
+
+        // Impossible de remonter avant la première bougie conservée
+        state.pan_horizontal(-1.0e6);
+        assert!(state.viewport.time_scale().time_range().0 >= 10 * 3600);
+        assert!(!state.apply_history_cap(10));
+    }
+
+    #[test]
+    fn test_left_axis_has_its_own_price_range() {
+        let mut state = state_with_candles(20);
+        let mut data = TimeSeries::new();
+        for i in 0..20 {
+            data.push(Candle::new(i as i64 * 3600, 10_000.0, 10_100.0, 9_900.0, 10_050.0, 1.0)).unwrap();
+        }
+        let other = SeriesId::new("OTHER_1h");
+        state.add_series(SeriesData::new(other.clone(), "OTHER".to_string(), "1h".to_string(), data));
+        state.toggle_series_visibility(&other);
+        assert!(state.left_axis_viewport().is_none());
+
+        state.series_manager.set_series_axis(&other, PriceAxis::Left);
+        let left = state.left_axis_viewport().expect("axe gauche");
+        let (min, max) = left.price_scale().price_range();
+        assert!(min < 9_900.0 && max > 10_100.0 && min > 9_000.0);
+        assert_eq!(left.time_scale().time_range(), state.viewport.time_scale().time_range());
+
+        // La série principale reste sur l'axe de droite
+        let primary = SeriesId::new("TEST_1h");
+        state.series_manager.set_series_axis(&primary, PriceAxis::Left);
+        assert_eq!(state.series_axis(&primary), PriceAxis::Right);
+    }
+
+    #[test]
+    fn test_comparison_rebases_to_first_visible_close() {
+        let mut data = TimeSeries::new();
+        data.push(Candle::new(0, 50.0, 55.0, 45.0, 50.0, 1.0)).unwrap();
+        data.push(Candle::new(3600, 50.0, 80.0, 50.0, 75.0, 1.0)).unwrap();
+        let mut state = ChartState::new(800.0, 600.0);
+        state.add_series(SeriesData::new(SeriesId::new("TEST_1h"), "TEST".to_string(), "1h".to_string(), data));
+
+        let series = state.comparison_candles();
+        let candles = &series[0].1;
+        assert_eq!(candles[0].close, 0.0);
+        assert!((candles[1].close - 50.0).abs() < 1e-9);
+        assert!((candles[1].high - 60.0).abs() < 1e-9);
+
+        let viewport = state.comparison_viewport(&series).unwrap();
+        let (min, max) = viewport.price_scale().price_range();
+        assert!(min < -10.0 && max > 60.0);
+        assert!(!viewport.price_scale().is_log());
+    }
+
+    #[test]
+    fn test_replay_step_updates_macd_incrementally() {
+        let mut data = TimeSeries::new();
+        for i in 0..60 {
+            let close = 100.0 + (i as f64 * 0.7).sin() * 5.0;
+            data.push(Candle::new(i * 3600, close, close + 1.0, close - 1.0, close, 10.0)).unwrap();
+        }
+        let mut state = ChartState::new(800.0, 600.0);
+        state.add_series(SeriesData::new(SeriesId::new("TEST_1h"), "TEST".to_string(), "1h".to_string(), data));
+
+        assert!(state.start_replay(40 * 3600));
+        state.compute_and_store_macd().unwrap();
+        let version = state.macd_cache.as_ref().unwrap().version;
+        assert!(state.step_replay());
+
+        // Le cache est prolongé sans recalcul complet et reste identique à celui-ci
+        let cache = state.macd_cache.as_ref().unwrap();
+        assert!(cache.version > version);
+        let incremental = cache.values.clone();
+        let full = crate::finance_chart::indicators::macd::calculate_all_macd_values(&state).unwrap();
+        assert_eq!(incremental.len(), 42);
+        assert_eq!(full.len(), 42);
+        for (a, b) in incremental.iter().zip(&full) {
+            match (a, b) {
+                (Some(a), Some(b)) => {
+                    assert!((a.macd_line - b.macd_line).abs() < 1e-9);
+                    assert!((a.signal_line - b.signal_line).abs() < 1e-9);
                 }
+                (a, b) => assert_eq!(a.is_none(), b.is_none()),
             }
         }
     }
+
+    #[test]
+    fn test_replay_step_stops_at_last_candle() {
+        let mut state = state_with_candles(3);
+        assert!(state.start_replay(i64::MAX));
+        assert_eq!(state.replay_cursor, Some(2));
+        assert!(!state.step_replay());
+        assert_eq!(state.replay_cursor, Some(2));
+    }
 }
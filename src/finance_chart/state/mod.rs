@@ -1,6 +1,10 @@
+pub mod builder;
 pub mod chart_state;
+pub mod readout;
 
+pub use builder::ChartStateBuilder;
 pub use chart_state::ChartState;
+pub use readout::{ReadoutIndicators, ReadoutEntry};
 
 
 
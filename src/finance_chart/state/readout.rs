@@ -0,0 +1,220 @@
+//! Lecture des valeurs d'indicateurs à une bougie donnée
+//!
+//! Regroupe en un seul endroit les valeurs de tous les indicateurs activés pour
+//! la bougie survolée (bandeau de lecture au-dessus du graphique).
+
+use super::ChartState;
+use crate::app::state::IndicatorParams;
+use crate::finance_chart::indicators::bollinger::calc::calculate_bollinger_bands;
+use crate::finance_chart::indicators::macd::calculate_all_macd_values;
+use crate::finance_chart::indicators::rsi::calc::calculate_rsi;
+use crate::finance_chart::interaction::nearest_candle_index;
+use crate::finance_chart::settings::{format_abbreviated, format_price, ChartStyle};
+
+/// Indicateurs à inclure dans la lecture
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReadoutIndicators {
+    pub volume: bool,
+    pub rsi: bool,
+    pub macd: bool,
+    pub bollinger: bool,
+    pub moving_averages: bool,
+}
+
+/// Valeur d'un indicateur à afficher dans le bandeau
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadoutEntry {
+    /// Nom court de l'indicateur (ex: "RSI 14")
+    pub label: String,
+    /// Valeur(s) formatée(s)
+    pub value: String,
+}
+
+impl ChartState {
+    /// Valeurs des indicateurs activés pour la bougie la plus proche de `time`
+    ///
+    /// Le MACD et les moyennes mobiles proviennent de leurs caches ; le RSI et les
+    /// bandes de Bollinger sont recalculés sur la série affichée. Les indicateurs
+    /// sans valeur à cette bougie (période de chauffe) sont omis. Les prix suivent
+    /// la précision de `chart_style`.
+    pub fn indicator_readout_at(
+        &self,
+        time: i64,
+        indicators: ReadoutIndicators,
+        params: &IndicatorParams,
+        chart_style: &ChartStyle,
+    ) -> Vec<ReadoutEntry> {
+        let mut entries = Vec::new();
+        let Some(candles) = self.all_candles() else {
+            return entries;
+        };
+        let Some(index) = nearest_candle_index(time, candles) else {
+            return entries;
+        };
+
+        if indicators.moving_averages {
+            for config in &params.moving_averages {
+                let value = self.moving_average_values(config.ma_type, config.period)
+                    .and_then(|values| values.get(index).copied().flatten());
+                if let Some(value) = value {
+                    entries.push(ReadoutEntry {
+                        label: format!("{} {}", config.ma_type.as_str(), config.period),
+                        value: format_price(value, chart_style),
+                    });
+                }
+            }
+        }
+
+        if indicators.bollinger {
+            let bands = calculate_bollinger_bands(candles, params.bollinger_period, params.bollinger_std_dev);
+            if let Some(band) = bands.get(index).cloned().flatten() {
+                entries.push(ReadoutEntry {
+                    label: format!("BB {}", params.bollinger_period),
+                    value: format!(
+                        "{} / {} / {}",
+                        format_price(band.upper, chart_style),
+                        format_price(band.middle, chart_style),
+                        format_price(band.lower, chart_style),
+                    ),
+                });
+            }
+        }
+
+        if indicators.volume {
+            entries.push(ReadoutEntry {
+                label: "Vol".to_string(),
+                value: format_abbreviated(candles[index].volume),
+            });
+        }
+
+        if indicators.rsi {
+            let rsi = calculate_rsi(candles, params.rsi_period, params.rsi_method);
+            if let Some(value) = rsi.get(index).copied().flatten() {
+                entries.push(ReadoutEntry {
+                    label: format!("RSI {}", params.rsi_period),
+                    value: format!("{:.2}", value),
+                });
+            }
+        }
+
+        if indicators.macd {
+            let macd = match &self.macd_cache {
+                Some(cache) => cache.values.get(index).cloned().flatten(),
+                None => calculate_all_macd_values(self).and_then(|values| values.get(index).cloned().flatten()),
+            };
+            if let Some(value) = macd {
+                entries.push(ReadoutEntry {
+                    label: "MACD".to_string(),
+                    value: format!("{:.2} / {:.2} / {:.2}", value.macd_line, value.signal_line, value.histogram),
+                });
+            }
+        }
+
+        entries
+    }
+
+    /// Détail de la bougie sélectionnée (OHLC, volume, variation)
+    ///
+    /// La variation est calculée par rapport à la clôture de la bougie
+    /// précédente. Vide sans sélection ou si la bougie n'est plus dans la série
+    /// affichée (changement de série, replay).
+    pub fn selected_candle_readout(&self, chart_style: &ChartStyle) -> Vec<ReadoutEntry> {
+        let Some(timestamp) = self.selected_candle else {
+            return Vec::new();
+        };
+        let Some(candles) = self.all_candles() else {
+            return Vec::new();
+        };
+        let Ok(index) = candles.binary_search_by_key(&timestamp, |candle| candle.timestamp) else {
+            return Vec::new();
+        };
+        let candle = &candles[index];
+
+        let mut entries: Vec<ReadoutEntry> = [
+            ("O", candle.open),
+            ("H", candle.high),
+            ("L", candle.low),
+            ("C", candle.close),
+        ]
+        .into_iter()
+        .map(|(label, price)| ReadoutEntry { label: label.to_string(), value: format_price(price, chart_style) })
+        .collect();
+        entries.push(ReadoutEntry {
+            label: "Vol".to_string(),
+            value: format_abbreviated(candle.volume),
+        });
+
+        let previous_close = index.checked_sub(1).map(|i| candles[i].close).filter(|close| *close != 0.0);
+        if let Some(previous_close) = previous_close {
+            entries.push(ReadoutEntry {
+                label: "Var".to_string(),
+                value: format!("{:+.2}%", (candle.close - previous_close) / previous_close * 100.0),
+            });
+        }
+
+        entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::finance_chart::core::{Candle, SeriesData, SeriesId, TimeSeries};
+
+    fn state_with_candles(count: usize) -> ChartState {
+        let mut data = TimeSeries::new();
+        for i in 0..count {
+            let price = 100.0 + (i % 7) as f64;
+            data.push(Candle::new(i as i64 * 3600, price, price + 1.0, price - 1.0, price + 0.5, 10.0 + i as f64))
+                .unwrap();
+        }
+        let mut state = ChartState::new(800.0, 600.0);
+        state.add_series(SeriesData::new(SeriesId::new("TEST_1h"), "TEST".to_string(), "1h".to_string(), data));
+        state
+    }
+
+    #[test]
+    fn test_readout_only_includes_enabled_indicators() {
+        let state = state_with_candles(60);
+        let params = IndicatorParams::new();
+        let indicators = ReadoutIndicators { volume: true, rsi: true, ..Default::default() };
+
+        let entries = state.indicator_readout_at(50 * 3600, indicators, &params, &ChartStyle::default());
+        let labels: Vec<&str> = entries.iter().map(|entry| entry.label.as_str()).collect();
+        let rsi_label = format!("RSI {}", params.rsi_period);
+        assert_eq!(labels, vec!["Vol", rsi_label.as_str()]);
+        assert_eq!(entries[0].value, "60");
+    }
+
+    #[test]
+    fn test_readout_skips_warmup_values() {
+        let state = state_with_candles(60);
+        let params = IndicatorParams::new();
+        let indicators = ReadoutIndicators { rsi: true, macd: true, ..Default::default() };
+
+        // Première bougie : ni RSI ni MACD ne sont encore définis
+        assert!(state.indicator_readout_at(0, indicators, &params, &ChartStyle::default()).is_empty());
+        assert!(state.indicator_readout_at(i64::MIN, ReadoutIndicators::default(), &params, &ChartStyle::default()).is_empty());
+    }
+
+    #[test]
+    fn test_selected_candle_readout() {
+        let mut state = state_with_candles(10);
+        let style = ChartStyle::default();
+        assert!(state.selected_candle_readout(&style).is_empty());
+
+        // Bougie 1 : clôture 101.5 contre 100.5 pour la précédente
+        state.selected_candle = Some(3600);
+        let entries = state.selected_candle_readout(&style);
+        let labels: Vec<&str> = entries.iter().map(|entry| entry.label.as_str()).collect();
+        assert_eq!(labels, vec!["O", "H", "L", "C", "Vol", "Var"]);
+        assert_eq!(entries[4].value, "11");
+        assert_eq!(entries[5].value, "+1.00%");
+
+        // Première bougie : pas de variation ; timestamp absent : rien
+        state.selected_candle = Some(0);
+        assert_eq!(state.selected_candle_readout(&style).len(), 5);
+        state.selected_candle = Some(1800);
+        assert!(state.selected_candle_readout(&style).is_empty());
+    }
+}
@@ -1,6 +1,6 @@
 //! Module des outils de dessin
 //!
-//! Contient les types et états pour les outils de dessin (rectangles, lignes)
+//! Contient les types et états pour les outils de dessin (rectangles, lignes horizontales et verticales, demi-droites, textes)
 //! ainsi que le panel d'outils.
 
 pub mod state;
@@ -8,7 +8,8 @@ pub mod panel;
 
 // Ré-exports
 pub use state::{
-    Tool, ToolsState, DrawnRectangle, DrawnHorizontalLine,
+    Tool, ToolsState, DrawingKind, DrawnRectangle, DrawnHorizontalLine, DrawnVerticalLine, DrawnTrendLine, DrawnText,
+    TextEditState, HLinePriceEditState, RectangleLabelEditState, DrawingRenameState,
     EditMode, EditState, Action, HANDLE_SIZE,
 };
 pub use panel::{tools_panel, TOOLS_PANEL_WIDTH};
@@ -59,7 +59,7 @@ impl<'a> Program<ToolsPanelMessage> for ToolsPanelProgram<'a> {
             .with_width(1.0);
         frame.stroke(&border, stroke);
 
-        let tools = [Tool::Rectangle, Tool::HorizontalLine];
+        let tools = [Tool::Rectangle, Tool::HorizontalLine, Tool::VerticalLine, Tool::Ray, Tool::Text, Tool::Measure];
         
         let button_size = 32.0;
         let padding = (bounds.width - button_size) / 2.0;
@@ -122,6 +122,67 @@ impl<'a> Program<ToolsPanelMessage> for ToolsPanelProgram<'a> {
                         .with_width(1.5);
                     frame.stroke(&line, icon_stroke);
                 }
+                Tool::VerticalLine => {
+                    let half_height = 11.0;
+
+                    let line = Path::new(|builder| {
+                        builder.move_to(Point::new(cx, cy - half_height));
+                        builder.line_to(Point::new(cx, cy + half_height));
+                    });
+                    let icon_stroke = canvas::Stroke::default()
+                        .with_color(icon_color)
+                        .with_width(1.5);
+                    frame.stroke(&line, icon_stroke);
+                    frame.fill(&Path::rectangle(Point::new(cx, cy - half_height), Size::new(7.0, 5.0)), icon_color);
+                }
+                Tool::Text => {
+                    let half = 7.0;
+
+                    let glyph = Path::new(|builder| {
+                        builder.move_to(Point::new(cx - half, cy - half));
+                        builder.line_to(Point::new(cx + half, cy - half));
+                        builder.move_to(Point::new(cx, cy - half));
+                        builder.line_to(Point::new(cx, cy + half));
+                    });
+                    let icon_stroke = canvas::Stroke::default()
+                        .with_color(icon_color)
+                        .with_width(2.0);
+                    frame.stroke(&glyph, icon_stroke);
+                }
+                Tool::Measure => {
+                    let half = 10.0;
+
+                    // Règle en diagonale avec graduations
+                    let ruler = Path::new(|builder| {
+                        builder.move_to(Point::new(cx - half, cy + half));
+                        builder.line_to(Point::new(cx + half, cy - half));
+                        for i in 1..4 {
+                            let t = i as f32 / 4.0;
+                            let x = cx - half + 2.0 * half * t;
+                            let y = cy + half - 2.0 * half * t;
+                            builder.move_to(Point::new(x, y));
+                            builder.line_to(Point::new(x - 3.0, y - 3.0));
+                        }
+                    });
+                    let icon_stroke = canvas::Stroke::default()
+                        .with_color(icon_color)
+                        .with_width(1.5);
+                    frame.stroke(&ruler, icon_stroke);
+                }
+                Tool::Ray => {
+                    let half_width = 11.0;
+                    let anchor = Point::new(cx - half_width + 2.0, cy + 6.0);
+
+                    let line = Path::new(|builder| {
+                        builder.move_to(anchor);
+                        builder.line_to(Point::new(cx + half_width, cy - 6.0));
+                    });
+                    let icon_stroke = canvas::Stroke::default()
+                        .with_color(icon_color)
+                        .with_width(1.5);
+                    frame.stroke(&line, icon_stroke);
+                    frame.fill(&Path::circle(anchor, 2.5), icon_color);
+                }
             }
         }
 
@@ -181,7 +242,7 @@ impl<'a> Program<ToolsPanelMessage> for ToolsPanelProgram<'a> {
         let padding = (bounds.width - button_size) / 2.0;
         let start_y = 15.0;
         let spacing = 8.0;
-        let tools_count = 2;
+        let tools_count = 6;
         let bottom_margin = 15.0;
         let indicators_y = bounds.height - bottom_margin - button_size;
         let indicators_button_index = tools_count;
@@ -213,7 +274,7 @@ impl<'a> Program<ToolsPanelMessage> for ToolsPanelProgram<'a> {
             }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 if let Some(pos) = cursor.position_in(bounds) {
-                    let tools = [Tool::Rectangle, Tool::HorizontalLine];
+                    let tools = [Tool::Rectangle, Tool::HorizontalLine, Tool::VerticalLine, Tool::Ray, Tool::Text, Tool::Measure];
                     
                     for (i, tool) in tools.iter().enumerate() {
                         let y = start_y + (i as f32) * (button_size + spacing);
@@ -13,6 +13,63 @@ pub const MAX_HISTORY_SIZE: usize = 50;
 pub enum Tool {
     Rectangle,
     HorizontalLine,
+    /// Demi-droite ancrée sur un point et prolongée jusqu'au bord droit
+    Ray,
+    /// Ligne verticale marquant un instant (événement)
+    VerticalLine,
+    /// Annotation texte ancrée à un point (temps, prix)
+    Text,
+    /// Règle de mesure transitoire (écart de prix et de temps)
+    Measure,
+}
+
+impl Tool {
+    /// Outil associé à un raccourci clavier (insensible à la casse)
+    ///
+    /// `r` rectangle, `h` ligne horizontale, `t` ligne de tendance, `v` ligne verticale,
+    /// `a` annotation texte. La mesure n'a pas de raccourci (`m` bascule l'aimant).
+    pub fn from_shortcut(key: &str) -> Option<Self> {
+        match key.to_ascii_lowercase().as_str() {
+            "r" => Some(Tool::Rectangle),
+            "h" => Some(Tool::HorizontalLine),
+            "t" => Some(Tool::Ray),
+            "v" => Some(Tool::VerticalLine),
+            "a" => Some(Tool::Text),
+            _ => None,
+        }
+    }
+}
+
+/// Type d'un dessin, pour le désigner avec son index (liste des dessins)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawingKind {
+    Rectangle,
+    HorizontalLine,
+    VerticalLine,
+    Text,
+    TrendLine,
+}
+
+impl DrawingKind {
+    /// Tous les types, dans l'ordre de la liste des dessins
+    pub const ALL: [DrawingKind; 5] = [
+        DrawingKind::Rectangle,
+        DrawingKind::HorizontalLine,
+        DrawingKind::VerticalLine,
+        DrawingKind::TrendLine,
+        DrawingKind::Text,
+    ];
+
+    /// Nom affiché dans la liste des dessins
+    pub fn label(&self) -> &'static str {
+        match self {
+            DrawingKind::Rectangle => "Rectangle",
+            DrawingKind::HorizontalLine => "Ligne horizontale",
+            DrawingKind::VerticalLine => "Ligne verticale",
+            DrawingKind::Text => "Texte",
+            DrawingKind::TrendLine => "Ligne de tendance",
+        }
+    }
 }
 
 /// Mode d'édition d'un rectangle
@@ -30,17 +87,35 @@ pub enum EditMode {
 }
 
 /// Rectangle dessiné sur le graphique
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DrawnRectangle {
     pub start_time: i64,
     pub start_price: f64,
     pub end_time: i64,
     pub end_price: f64,
+    /// Couleur de remplissage (l'alpha est l'opacité du fond)
     #[serde(with = "color_serde")]
     pub color: Color,
+    /// Texte optionnel centré dans le rectangle
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Nom affiché dans la liste des dessins (renommage par l'utilisateur)
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Verrouillé : ignoré par les hit-tests (ni sélection ni édition)
+    #[serde(default)]
+    pub locked: bool,
+    /// Masqué : ni dessiné ni sélectionnable
+    #[serde(default)]
+    pub hidden: bool,
 }
 
 impl DrawnRectangle {
+    /// Opacité maximale du fond, pour que les bougies restent visibles à travers la zone
+    pub const MAX_FILL_OPACITY: f32 = 0.6;
+    /// Opacités proposées dans l'UI
+    pub const FILL_OPACITIES: [f32; 4] = [0.1, 0.2, 0.3, 0.5];
+
     pub fn new(start_time: i64, start_price: f64, end_time: i64, end_price: f64) -> Self {
         Self {
             start_time,
@@ -48,18 +123,36 @@ impl DrawnRectangle {
             end_time,
             end_price,
             color: Color::from_rgba(0.2, 0.6, 1.0, 0.3),
+            label: None,
+            name: None,
+            locked: false,
+            hidden: false,
         }
     }
+
+    /// Couleur de remplissage effective (opacité bornée à `MAX_FILL_OPACITY`)
+    pub fn fill_color(&self) -> Color {
+        Color { a: self.color.a.clamp(0.0, Self::MAX_FILL_OPACITY), ..self.color }
+    }
 }
 
 /// Ligne horizontale dessinée sur le graphique
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DrawnHorizontalLine {
     pub price: f64,
     #[serde(with = "color_serde")]
     pub color: Color,
     pub width: f32,
     pub dashed: bool,
+    /// Nom affiché dans la liste des dessins (voir `DrawnRectangle::name`)
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Verrouillé (voir `DrawnRectangle::locked`)
+    #[serde(default)]
+    pub locked: bool,
+    /// Masqué (voir `DrawnRectangle::hidden`)
+    #[serde(default)]
+    pub hidden: bool,
 }
 
 impl DrawnHorizontalLine {
@@ -69,6 +162,122 @@ impl DrawnHorizontalLine {
             color: Color::from_rgba(1.0, 0.8, 0.0, 0.8),
             width: 1.5,
             dashed: true,
+            name: None,
+            locked: false,
+            hidden: false,
+        }
+    }
+}
+
+/// Ligne verticale dessinée sur le graphique (marqueur temporel)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DrawnVerticalLine {
+    pub time: i64,
+    #[serde(with = "color_serde")]
+    pub color: Color,
+    pub width: f32,
+    /// Texte optionnel affiché en haut de la ligne
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Nom affiché dans la liste des dessins (voir `DrawnRectangle::name`)
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Verrouillé (voir `DrawnRectangle::locked`)
+    #[serde(default)]
+    pub locked: bool,
+    /// Masqué (voir `DrawnRectangle::hidden`)
+    #[serde(default)]
+    pub hidden: bool,
+}
+
+impl DrawnVerticalLine {
+    pub fn new(time: i64) -> Self {
+        Self {
+            time,
+            color: Color::from_rgba(0.7, 0.5, 1.0, 0.8),
+            width: 1.5,
+            label: None,
+            name: None,
+            locked: false,
+            hidden: false,
+        }
+    }
+}
+
+/// Annotation texte ancrée à un point du graphique
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DrawnText {
+    pub time: i64,
+    pub price: f64,
+    pub content: String,
+    pub size: f32,
+    #[serde(with = "color_serde")]
+    pub color: Color,
+    /// Nom affiché dans la liste des dessins (voir `DrawnRectangle::name`)
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Verrouillé (voir `DrawnRectangle::locked`)
+    #[serde(default)]
+    pub locked: bool,
+    /// Masqué (voir `DrawnRectangle::hidden`)
+    #[serde(default)]
+    pub hidden: bool,
+}
+
+impl DrawnText {
+    pub fn new(time: i64, price: f64, content: String) -> Self {
+        Self {
+            time,
+            price,
+            content,
+            size: 13.0,
+            color: Color::from_rgba(0.9, 0.9, 0.9, 1.0),
+            name: None,
+            locked: false,
+            hidden: false,
+        }
+    }
+}
+
+/// Ligne de tendance dessinée sur le graphique (deux points en temps/prix)
+///
+/// Si `ray` est vrai, la ligne part du premier point et se prolonge au-delà
+/// du second jusqu'au bord du graphique, en gardant la pente en temps/prix.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DrawnTrendLine {
+    pub start_time: i64,
+    pub start_price: f64,
+    pub end_time: i64,
+    pub end_price: f64,
+    #[serde(with = "color_serde")]
+    pub color: Color,
+    pub width: f32,
+    #[serde(default)]
+    pub ray: bool,
+    /// Nom affiché dans la liste des dessins (voir `DrawnRectangle::name`)
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Verrouillé (voir `DrawnRectangle::locked`)
+    #[serde(default)]
+    pub locked: bool,
+    /// Masqué (voir `DrawnRectangle::hidden`)
+    #[serde(default)]
+    pub hidden: bool,
+}
+
+impl DrawnTrendLine {
+    pub fn new(start_time: i64, start_price: f64, end_time: i64, end_price: f64, ray: bool) -> Self {
+        Self {
+            start_time,
+            start_price,
+            end_time,
+            end_price,
+            color: Color::from_rgba(0.4, 0.8, 1.0, 0.9),
+            width: 1.5,
+            ray,
+            name: None,
+            locked: false,
+            hidden: false,
         }
     }
 }
@@ -116,6 +325,15 @@ pub enum Action {
     CreateHLine { line: DrawnHorizontalLine },
     DeleteHLine { index: usize, line: DrawnHorizontalLine },
     ModifyHLine { index: usize, old_line: DrawnHorizontalLine, new_line: DrawnHorizontalLine },
+    CreateVLine { line: DrawnVerticalLine },
+    DeleteVLine { index: usize, line: DrawnVerticalLine },
+    ModifyVLine { index: usize, old_line: DrawnVerticalLine, new_line: DrawnVerticalLine },
+    CreateText { text: DrawnText },
+    DeleteText { index: usize, text: DrawnText },
+    ModifyText { index: usize, old_text: DrawnText, new_text: DrawnText },
+    CreateTrendLine { line: DrawnTrendLine },
+    DeleteTrendLine { index: usize, line: DrawnTrendLine },
+    ModifyTrendLine { index: usize, old_line: DrawnTrendLine, new_line: DrawnTrendLine },
 }
 
 /// Gestionnaire d'historique pour undo/redo
@@ -138,6 +356,9 @@ impl History {
         &mut self,
         rectangles: &mut Vec<DrawnRectangle>,
         hlines: &mut Vec<DrawnHorizontalLine>,
+        vlines: &mut Vec<DrawnVerticalLine>,
+        texts: &mut Vec<DrawnText>,
+        trend_lines: &mut Vec<DrawnTrendLine>,
     ) -> bool {
         if let Some(action) = self.undo_stack.pop() {
             match &action {
@@ -161,6 +382,36 @@ impl History {
                         hlines[*index] = old_line.clone();
                     }
                 }
+                Action::CreateVLine { .. } => { vlines.pop(); }
+                Action::DeleteVLine { index, line } => {
+                    let idx = (*index).min(vlines.len());
+                    vlines.insert(idx, line.clone());
+                }
+                Action::ModifyVLine { index, old_line, .. } => {
+                    if *index < vlines.len() {
+                        vlines[*index] = old_line.clone();
+                    }
+                }
+                Action::CreateText { .. } => { texts.pop(); }
+                Action::DeleteText { index, text } => {
+                    let idx = (*index).min(texts.len());
+                    texts.insert(idx, text.clone());
+                }
+                Action::ModifyText { index, old_text, .. } => {
+                    if *index < texts.len() {
+                        texts[*index] = old_text.clone();
+                    }
+                }
+                Action::CreateTrendLine { .. } => { trend_lines.pop(); }
+                Action::DeleteTrendLine { index, line } => {
+                    let idx = (*index).min(trend_lines.len());
+                    trend_lines.insert(idx, line.clone());
+                }
+                Action::ModifyTrendLine { index, old_line, .. } => {
+                    if *index < trend_lines.len() {
+                        trend_lines[*index] = old_line.clone();
+                    }
+                }
             }
             self.redo_stack.push(action);
             true
@@ -173,6 +424,9 @@ impl History {
         &mut self,
         rectangles: &mut Vec<DrawnRectangle>,
         hlines: &mut Vec<DrawnHorizontalLine>,
+        vlines: &mut Vec<DrawnVerticalLine>,
+        texts: &mut Vec<DrawnText>,
+        trend_lines: &mut Vec<DrawnTrendLine>,
     ) -> bool {
         if let Some(action) = self.redo_stack.pop() {
             match &action {
@@ -200,6 +454,39 @@ impl History {
                         hlines[*index] = new_line.clone();
                     }
                 }
+                Action::CreateVLine { line } => { vlines.push(line.clone()); }
+                Action::DeleteVLine { index, .. } => {
+                    if *index < vlines.len() {
+                        vlines.remove(*index);
+                    }
+                }
+                Action::ModifyVLine { index, new_line, .. } => {
+                    if *index < vlines.len() {
+                        vlines[*index] = new_line.clone();
+                    }
+                }
+                Action::CreateText { text } => { texts.push(text.clone()); }
+                Action::DeleteText { index, .. } => {
+                    if *index < texts.len() {
+                        texts.remove(*index);
+                    }
+                }
+                Action::ModifyText { index, new_text, .. } => {
+                    if *index < texts.len() {
+                        texts[*index] = new_text.clone();
+                    }
+                }
+                Action::CreateTrendLine { line } => { trend_lines.push(line.clone()); }
+                Action::DeleteTrendLine { index, .. } => {
+                    if *index < trend_lines.len() {
+                        trend_lines.remove(*index);
+                    }
+                }
+                Action::ModifyTrendLine { index, new_line, .. } => {
+                    if *index < trend_lines.len() {
+                        trend_lines[*index] = new_line.clone();
+                    }
+                }
             }
             self.undo_stack.push(action);
             true
@@ -273,6 +560,85 @@ impl HLineEditState {
     }
 }
 
+/// État d'édition d'une ligne verticale
+#[derive(Debug, Clone, Default)]
+pub struct VLineEditState {
+    pub selected_index: Option<usize>,
+    pub is_editing: bool,
+    pub start_time: Option<i64>,
+    pub original_line: Option<DrawnVerticalLine>,
+}
+
+impl VLineEditState {
+    pub fn start(&mut self, index: usize, time: i64, line: DrawnVerticalLine) {
+        self.selected_index = Some(index);
+        self.is_editing = true;
+        self.start_time = Some(time);
+        self.original_line = Some(line);
+    }
+
+    pub fn finish(&mut self) {
+        self.is_editing = false;
+        self.start_time = None;
+        self.original_line = None;
+    }
+
+    pub fn deselect(&mut self) {
+        self.selected_index = None;
+        self.finish();
+    }
+}
+
+/// Saisie en cours d'une annotation texte
+#[derive(Debug, Clone, Default)]
+pub struct TextEditState {
+    /// Index de l'annotation modifiée (None pour une nouvelle annotation)
+    pub index: Option<usize>,
+    pub time: i64,
+    pub price: f64,
+    /// Position globale du champ de saisie
+    pub position: (f32, f32),
+    pub content: String,
+}
+
+/// Saisie en cours du texte d'un rectangle
+#[derive(Debug, Clone, Default)]
+pub struct RectangleLabelEditState {
+    /// Index du rectangle modifié
+    pub index: usize,
+    pub content: String,
+}
+
+/// Renommage en cours d'un dessin depuis la liste des dessins
+#[derive(Debug, Clone)]
+pub struct DrawingRenameState {
+    pub kind: DrawingKind,
+    pub index: usize,
+    pub content: String,
+}
+
+/// Saisie en cours du prix d'une ligne horizontale (clic sur son étiquette)
+#[derive(Debug, Clone, Default)]
+pub struct HLinePriceEditState {
+    /// Index de la ligne modifiée
+    pub index: usize,
+    /// Position globale du champ de saisie
+    pub position: (f32, f32),
+    pub content: String,
+}
+
+impl HLinePriceEditState {
+    /// Prix saisi, s'il est valide (virgule décimale acceptée)
+    pub fn parsed_price(&self) -> Option<f64> {
+        self.content
+            .trim()
+            .replace(',', ".")
+            .parse::<f64>()
+            .ok()
+            .filter(|price| price.is_finite() && *price > 0.0)
+    }
+}
+
 /// État de dessin en cours
 #[derive(Debug, Clone, Default)]
 pub struct DrawingState {
@@ -281,6 +647,8 @@ pub struct DrawingState {
     pub start_time: Option<i64>,
     pub start_price: Option<f64>,
     pub current_screen_point: Option<(f32, f32)>,
+    /// Sélection de zoom en cours (Shift + glisser) : rien n'est créé au relâchement
+    pub zoom_selection: bool,
 }
 
 impl DrawingState {
@@ -292,6 +660,12 @@ impl DrawingState {
         self.current_screen_point = Some((screen_x, screen_y));
     }
 
+    /// Démarre une sélection de zoom (rectangle transitoire)
+    pub fn start_zoom_selection(&mut self, screen_x: f32, screen_y: f32, time: i64, price: f64) {
+        self.start(screen_x, screen_y, time, price);
+        self.zoom_selection = true;
+    }
+
     pub fn update(&mut self, screen_x: f32, screen_y: f32) {
         self.current_screen_point = Some((screen_x, screen_y));
     }
@@ -320,6 +694,32 @@ impl DrawingState {
         None
     }
 
+    pub fn finish_vline(&mut self) -> Option<DrawnVerticalLine> {
+        if self.is_drawing {
+            if let Some(time) = self.start_time {
+                let line = DrawnVerticalLine::new(time);
+                self.reset();
+                return Some(line);
+            }
+        }
+        self.reset();
+        None
+    }
+
+    pub fn finish_trend_line(&mut self, end_time: i64, end_price: f64, ray: bool) -> Option<DrawnTrendLine> {
+        if self.is_drawing {
+            if let (Some(start_time), Some(start_price)) = (self.start_time, self.start_price) {
+                // Un clic sans déplacement ne définit aucune direction
+                let line = (start_time != end_time || start_price != end_price)
+                    .then(|| DrawnTrendLine::new(start_time, start_price, end_time, end_price, ray));
+                self.reset();
+                return line;
+            }
+        }
+        self.reset();
+        None
+    }
+
     pub fn cancel(&mut self) {
         self.reset();
     }
@@ -330,6 +730,7 @@ impl DrawingState {
         self.start_time = None;
         self.start_price = None;
         self.current_screen_point = None;
+        self.zoom_selection = false;
     }
 }
 
@@ -339,38 +740,281 @@ pub struct ToolsState {
     pub selected_tool: Option<Tool>,
     pub rectangles: Vec<DrawnRectangle>,
     pub horizontal_lines: Vec<DrawnHorizontalLine>,
+    pub vertical_lines: Vec<DrawnVerticalLine>,
+    pub texts: Vec<DrawnText>,
+    /// Index de l'annotation texte sélectionnée
+    pub selected_text: Option<usize>,
+    /// Saisie de texte en cours (champ affiché par-dessus le graphique)
+    pub text_editing: Option<TextEditState>,
+    /// Saisie du prix d'une ligne horizontale en cours
+    pub hline_price_editing: Option<HLinePriceEditState>,
+    /// Saisie du texte d'un rectangle en cours
+    pub rectangle_label_editing: Option<RectangleLabelEditState>,
+    /// Renommage d'un dessin en cours (liste des dessins)
+    pub drawing_rename: Option<DrawingRenameState>,
+    pub trend_lines: Vec<DrawnTrendLine>,
+    /// Index de la ligne de tendance sélectionnée
+    pub selected_trend_line: Option<usize>,
     pub drawing: DrawingState,
     pub editing: EditState,
     pub hline_editing: HLineEditState,
+    pub vline_editing: VLineEditState,
     pub history: History,
 }
 
+/// Version du format de sérialisation des dessins
+pub const DRAWINGS_SCHEMA_VERSION: u32 = 1;
+
+/// Les fichiers antérieurs au champ `version` utilisent le format 1
+fn legacy_schema_version() -> u32 {
+    1
+}
+
 /// Structure pour la sérialisation des dessins
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DrawingsData {
+    /// Version du format (voir `DRAWINGS_SCHEMA_VERSION`)
+    #[serde(default = "legacy_schema_version")]
+    pub version: u32,
     pub rectangles: Vec<DrawnRectangle>,
     pub horizontal_lines: Vec<DrawnHorizontalLine>,
+    #[serde(default)]
+    pub vertical_lines: Vec<DrawnVerticalLine>,
+    #[serde(default)]
+    pub texts: Vec<DrawnText>,
+    #[serde(default)]
+    pub trend_lines: Vec<DrawnTrendLine>,
 }
 
 impl ToolsState {
-    pub fn export_drawings(&self) -> Result<String, serde_json::Error> {
+    /// Drapeaux (verrouillé, masqué) d'un dessin
+    fn drawing_flags_mut(&mut self, kind: DrawingKind, index: usize) -> Option<(&mut bool, &mut bool)> {
+        match kind {
+            DrawingKind::Rectangle => self.rectangles.get_mut(index).map(|d| (&mut d.locked, &mut d.hidden)),
+            DrawingKind::HorizontalLine => self.horizontal_lines.get_mut(index).map(|d| (&mut d.locked, &mut d.hidden)),
+            DrawingKind::VerticalLine => self.vertical_lines.get_mut(index).map(|d| (&mut d.locked, &mut d.hidden)),
+            DrawingKind::Text => self.texts.get_mut(index).map(|d| (&mut d.locked, &mut d.hidden)),
+            DrawingKind::TrendLine => self.trend_lines.get_mut(index).map(|d| (&mut d.locked, &mut d.hidden)),
+        }
+    }
+
+    /// Désélectionne les dessins d'un type (un dessin verrouillé, masqué ou supprimé
+    /// ne doit plus être la cible des touches Suppr ou Échap)
+    fn deselect_kind(&mut self, kind: DrawingKind) {
+        match kind {
+            DrawingKind::Rectangle => self.editing.deselect(),
+            DrawingKind::HorizontalLine => self.hline_editing.deselect(),
+            DrawingKind::VerticalLine => self.vline_editing.deselect(),
+            DrawingKind::Text => self.selected_text = None,
+            DrawingKind::TrendLine => self.selected_trend_line = None,
+        }
+    }
+
+    /// Dessin actuellement sélectionné sur le graphique, tous types confondus
+    pub fn selected_drawing(&self) -> Option<(DrawingKind, usize)> {
+        self.editing.selected_index.map(|index| (DrawingKind::Rectangle, index))
+            .or(self.hline_editing.selected_index.map(|index| (DrawingKind::HorizontalLine, index)))
+            .or(self.vline_editing.selected_index.map(|index| (DrawingKind::VerticalLine, index)))
+            .or(self.selected_text.map(|index| (DrawingKind::Text, index)))
+            .or(self.selected_trend_line.map(|index| (DrawingKind::TrendLine, index)))
+    }
+
+    /// Sélectionne un dessin comme un clic sur le graphique (un seul dessin sélectionné)
+    ///
+    /// # Retourne
+    /// `false` si le dessin n'existe pas ou ne peut pas être sélectionné (verrouillé ou masqué)
+    pub fn select_drawing(&mut self, kind: DrawingKind, index: usize) -> bool {
+        match self.drawing_flags_mut(kind, index) {
+            Some((locked, hidden)) if !*locked && !*hidden => {}
+            _ => return false,
+        }
+        for other in DrawingKind::ALL {
+            self.deselect_kind(other);
+        }
+        match kind {
+            DrawingKind::Rectangle => self.editing.selected_index = Some(index),
+            DrawingKind::HorizontalLine => self.hline_editing.selected_index = Some(index),
+            DrawingKind::VerticalLine => self.vline_editing.selected_index = Some(index),
+            DrawingKind::Text => self.selected_text = Some(index),
+            DrawingKind::TrendLine => self.selected_trend_line = Some(index),
+        }
+        true
+    }
+
+    /// Nom d'un dessin (`None` si le dessin n'existe pas)
+    pub fn drawing_name(&self, kind: DrawingKind, index: usize) -> Option<Option<&str>> {
+        match kind {
+            DrawingKind::Rectangle => self.rectangles.get(index).map(|d| d.name.as_deref()),
+            DrawingKind::HorizontalLine => self.horizontal_lines.get(index).map(|d| d.name.as_deref()),
+            DrawingKind::VerticalLine => self.vertical_lines.get(index).map(|d| d.name.as_deref()),
+            DrawingKind::Text => self.texts.get(index).map(|d| d.name.as_deref()),
+            DrawingKind::TrendLine => self.trend_lines.get(index).map(|d| d.name.as_deref()),
+        }
+    }
+
+    /// Applique une modification au nom et à la couleur d'un dessin, avec historique
+    fn modify_drawing(&mut self, kind: DrawingKind, index: usize, change: impl FnOnce(&mut Option<String>, &mut Color)) -> bool {
+        let action = match kind {
+            DrawingKind::Rectangle => {
+                let Some(rect) = self.rectangles.get_mut(index) else { return false };
+                let old_rect = rect.clone();
+                change(&mut rect.name, &mut rect.color);
+                (*rect != old_rect).then(|| Action::ModifyRectangle { index, old_rect, new_rect: rect.clone() })
+            }
+            DrawingKind::HorizontalLine => {
+                let Some(line) = self.horizontal_lines.get_mut(index) else { return false };
+                let old_line = line.clone();
+                change(&mut line.name, &mut line.color);
+                (*line != old_line).then(|| Action::ModifyHLine { index, old_line, new_line: line.clone() })
+            }
+            DrawingKind::VerticalLine => {
+                let Some(line) = self.vertical_lines.get_mut(index) else { return false };
+                let old_line = line.clone();
+                change(&mut line.name, &mut line.color);
+                (*line != old_line).then(|| Action::ModifyVLine { index, old_line, new_line: line.clone() })
+            }
+            DrawingKind::Text => {
+                let Some(text) = self.texts.get_mut(index) else { return false };
+                let old_text = text.clone();
+                change(&mut text.name, &mut text.color);
+                (*text != old_text).then(|| Action::ModifyText { index, old_text, new_text: text.clone() })
+            }
+            DrawingKind::TrendLine => {
+                let Some(line) = self.trend_lines.get_mut(index) else { return false };
+                let old_line = line.clone();
+                change(&mut line.name, &mut line.color);
+                (*line != old_line).then(|| Action::ModifyTrendLine { index, old_line, new_line: line.clone() })
+            }
+        };
+        if let Some(action) = action {
+            self.history.record(action);
+        }
+        true
+    }
+
+    /// Renomme un dessin (un nom vide efface le nom)
+    ///
+    /// # Retourne
+    /// `false` si le dessin n'existe pas
+    pub fn rename_drawing(&mut self, kind: DrawingKind, index: usize, name: &str) -> bool {
+        let name = Some(name.trim().to_string()).filter(|name| !name.is_empty());
+        self.modify_drawing(kind, index, |current, _| *current = name)
+    }
+
+    /// Change la couleur d'un dessin en conservant son opacité
+    ///
+    /// # Retourne
+    /// `false` si le dessin n'existe pas
+    pub fn set_drawing_color(&mut self, kind: DrawingKind, index: usize, color: Color) -> bool {
+        self.modify_drawing(kind, index, |_, current| *current = Color { a: current.a, ..color })
+    }
+
+    /// Verrouille ou déverrouille un dessin
+    ///
+    /// # Retourne
+    /// Le nouvel état, ou `None` si le dessin n'existe pas
+    pub fn toggle_drawing_locked(&mut self, kind: DrawingKind, index: usize) -> Option<bool> {
+        let (locked, _) = self.drawing_flags_mut(kind, index)?;
+        *locked = !*locked;
+        let locked = *locked;
+        if locked {
+            self.deselect_kind(kind);
+        }
+        Some(locked)
+    }
+
+    /// Masque ou réaffiche un dessin
+    ///
+    /// # Retourne
+    /// Le nouvel état, ou `None` si le dessin n'existe pas
+    pub fn toggle_drawing_hidden(&mut self, kind: DrawingKind, index: usize) -> Option<bool> {
+        let (_, hidden) = self.drawing_flags_mut(kind, index)?;
+        *hidden = !*hidden;
+        let hidden = *hidden;
+        if hidden {
+            self.deselect_kind(kind);
+        }
+        Some(hidden)
+    }
+
+    /// Supprime un dessin (enregistré dans l'historique, annulable)
+    ///
+    /// # Retourne
+    /// `false` si le dessin n'existe pas
+    pub fn delete_drawing(&mut self, kind: DrawingKind, index: usize) -> bool {
+        let action = match kind {
+            DrawingKind::Rectangle if index < self.rectangles.len() => {
+                Action::DeleteRectangle { index, rect: self.rectangles.remove(index) }
+            }
+            DrawingKind::HorizontalLine if index < self.horizontal_lines.len() => {
+                Action::DeleteHLine { index, line: self.horizontal_lines.remove(index) }
+            }
+            DrawingKind::VerticalLine if index < self.vertical_lines.len() => {
+                Action::DeleteVLine { index, line: self.vertical_lines.remove(index) }
+            }
+            DrawingKind::Text if index < self.texts.len() => {
+                Action::DeleteText { index, text: self.texts.remove(index) }
+            }
+            DrawingKind::TrendLine if index < self.trend_lines.len() => {
+                Action::DeleteTrendLine { index, line: self.trend_lines.remove(index) }
+            }
+            _ => return false,
+        };
+        self.history.record(action);
+        // Les index ont changé : la sélection et le renommage de ce type ne sont plus valables
+        self.deselect_kind(kind);
+        if self.drawing_rename.as_ref().is_some_and(|rename| rename.kind == kind) {
+            self.drawing_rename = None;
+        }
+        true
+    }
+
+    /// Exporte tous les dessins en JSON (format versionné)
+    ///
+    /// Les ancres (temps, prix) sont restituées à l'identique par `import_drawings`.
+    pub fn export_drawings(&self) -> String {
         let data = DrawingsData {
+            version: DRAWINGS_SCHEMA_VERSION,
             rectangles: self.rectangles.clone(),
             horizontal_lines: self.horizontal_lines.clone(),
+            vertical_lines: self.vertical_lines.clone(),
+            texts: self.texts.clone(),
+            trend_lines: self.trend_lines.clone(),
         };
         serde_json::to_string_pretty(&data)
+            .expect("la sérialisation des dessins ne peut pas échouer")
     }
 
-    pub fn import_drawings(&mut self, json: &str) -> Result<(), serde_json::Error> {
-        let data: DrawingsData = serde_json::from_str(json)?;
+    /// Remplace les dessins par ceux du JSON fourni
+    ///
+    /// Refuse les fichiers produits par une version plus récente du format.
+    /// En cas d'erreur, les dessins actuels ne sont pas modifiés.
+    pub fn import_drawings(&mut self, json: &str) -> Result<(), String> {
+        let data: DrawingsData = serde_json::from_str(json)
+            .map_err(|e| format!("JSON de dessins invalide: {}", e))?;
+        if data.version > DRAWINGS_SCHEMA_VERSION {
+            return Err(format!(
+                "Version de dessins non supportée: {} (maximum {})",
+                data.version, DRAWINGS_SCHEMA_VERSION
+            ));
+        }
         self.rectangles = data.rectangles;
+        self.rectangle_label_editing = None;
         self.horizontal_lines = data.horizontal_lines;
+        self.hline_price_editing = None;
+        self.vertical_lines = data.vertical_lines;
+        self.texts = data.texts;
+        self.selected_text = None;
+        self.text_editing = None;
+        self.trend_lines = data.trend_lines;
+        self.vline_editing = VLineEditState::default();
+        self.selected_trend_line = None;
         self.history = History::default();
         Ok(())
     }
 
     pub fn save_to_file(&self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let json = self.export_drawings()?;
+        let json = self.export_drawings();
         std::fs::write(path, json)?;
         Ok(())
     }
@@ -382,3 +1026,126 @@ impl ToolsState {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drawing_flags_roundtrip_and_delete() {
+        let mut tools = ToolsState::default();
+        tools.horizontal_lines.push(DrawnHorizontalLine::new(100.0));
+        tools.horizontal_lines.push(DrawnHorizontalLine::new(200.0));
+        tools.hline_editing.selected_index = Some(1);
+
+        assert_eq!(tools.toggle_drawing_locked(DrawingKind::HorizontalLine, 1), Some(true));
+        assert_eq!(tools.hline_editing.selected_index, None);
+        assert_eq!(tools.toggle_drawing_hidden(DrawingKind::HorizontalLine, 0), Some(true));
+        assert_eq!(tools.toggle_drawing_hidden(DrawingKind::Text, 0), None);
+
+        // Les drapeaux sont conservés par l'export
+        let json = tools.export_drawings();
+        let mut restored = ToolsState::default();
+        restored.import_drawings(&json).unwrap();
+        assert!(restored.horizontal_lines[0].hidden && !restored.horizontal_lines[0].locked);
+        assert!(restored.horizontal_lines[1].locked);
+
+        assert!(tools.delete_drawing(DrawingKind::HorizontalLine, 0));
+        assert_eq!(tools.horizontal_lines.len(), 1);
+        assert!(!tools.delete_drawing(DrawingKind::HorizontalLine, 5));
+    }
+
+    #[test]
+    fn test_select_rename_and_recolor_drawing() {
+        let mut tools = ToolsState::default();
+        tools.rectangles.push(DrawnRectangle::new(0, 100.0, 60, 110.0));
+        tools.trend_lines.push(DrawnTrendLine::new(0, 100.0, 60, 120.0, false));
+        tools.editing.selected_index = Some(0);
+
+        // La sélection depuis la liste remplace celle du graphique
+        assert!(tools.select_drawing(DrawingKind::TrendLine, 0));
+        assert_eq!(tools.selected_drawing(), Some((DrawingKind::TrendLine, 0)));
+        assert_eq!(tools.editing.selected_index, None);
+        tools.toggle_drawing_locked(DrawingKind::Rectangle, 0);
+        assert!(!tools.select_drawing(DrawingKind::Rectangle, 0));
+
+        assert!(tools.rename_drawing(DrawingKind::TrendLine, 0, "  Support  "));
+        assert_eq!(tools.drawing_name(DrawingKind::TrendLine, 0), Some(Some("Support")));
+        assert!(tools.set_drawing_color(DrawingKind::Rectangle, 0, Color::from_rgb(1.0, 0.0, 0.0)));
+        // L'opacité du fond est conservée
+        assert_eq!(tools.rectangles[0].color, Color::from_rgba(1.0, 0.0, 0.0, 0.3));
+
+        // Les deux modifications sont annulables
+        tools.history.undo(&mut tools.rectangles, &mut tools.horizontal_lines, &mut tools.vertical_lines, &mut tools.texts, &mut tools.trend_lines);
+        tools.history.undo(&mut tools.rectangles, &mut tools.horizontal_lines, &mut tools.vertical_lines, &mut tools.texts, &mut tools.trend_lines);
+        assert_eq!(tools.drawing_name(DrawingKind::TrendLine, 0), Some(None));
+        assert_eq!(tools.rectangles[0].color, Color::from_rgba(0.2, 0.6, 1.0, 0.3));
+        assert!(!tools.rename_drawing(DrawingKind::Text, 0, "x"));
+    }
+
+    #[test]
+    fn test_tool_shortcuts() {
+        assert_eq!(Tool::from_shortcut("r"), Some(Tool::Rectangle));
+        assert_eq!(Tool::from_shortcut("H"), Some(Tool::HorizontalLine));
+        assert_eq!(Tool::from_shortcut("t"), Some(Tool::Ray));
+        // Touches réservées à d'autres raccourcis (aimant, ajustement de la vue)
+        assert_eq!(Tool::from_shortcut("m"), None);
+        assert_eq!(Tool::from_shortcut("f"), None);
+    }
+
+    #[test]
+    fn test_drawings_export_import_roundtrip() {
+        let mut tools = ToolsState::default();
+        tools.rectangles.push(DrawnRectangle::new(1_700_000_000, 42_123.456_789_012_3, 1_700_003_600, 0.1 + 0.2));
+        tools.horizontal_lines.push(DrawnHorizontalLine::new(37_000.000_000_000_01));
+        let rectangles = tools.rectangles.clone();
+        let horizontal_lines = tools.horizontal_lines.clone();
+
+        let json = tools.export_drawings();
+        tools.rectangles.clear();
+        tools.horizontal_lines.clear();
+        tools.import_drawings(&json).unwrap();
+
+        assert_eq!(tools.rectangles, rectangles);
+        assert_eq!(tools.horizontal_lines, horizontal_lines);
+    }
+
+    #[test]
+    fn test_rectangle_fill_opacity_is_clamped() {
+        let mut rect = DrawnRectangle::new(0, 1.0, 10, 2.0);
+        assert_eq!(rect.fill_color().a, 0.3);
+        // Un fichier de dessins peut contenir une opacité plus forte : les bougies restent visibles
+        rect.color.a = 1.0;
+        assert_eq!(rect.fill_color().a, DrawnRectangle::MAX_FILL_OPACITY);
+
+        let json = serde_json::to_string(&DrawnRectangle { label: Some("Offre".to_string()), ..rect.clone() }).unwrap();
+        let restored: DrawnRectangle = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.label.as_deref(), Some("Offre"));
+    }
+
+    #[test]
+    fn test_hline_price_draft_parsing() {
+        let mut edit = HLinePriceEditState { index: 0, position: (0.0, 0.0), content: " 42150,5 ".to_string() };
+        assert_eq!(edit.parsed_price(), Some(42150.5));
+        edit.content = "abc".to_string();
+        assert_eq!(edit.parsed_price(), None);
+        edit.content = "-3".to_string();
+        assert_eq!(edit.parsed_price(), None);
+    }
+
+    #[test]
+    fn test_import_drawings_versions() {
+        let mut tools = ToolsState::default();
+        // Ancien format sans champ version
+        tools.import_drawings(r#"{"rectangles": [], "horizontal_lines": []}"#).unwrap();
+
+        // Format plus récent : refusé sans modifier les dessins
+        tools.horizontal_lines.push(DrawnHorizontalLine::new(1.0));
+        let future = format!(
+            r#"{{"version": {}, "rectangles": [], "horizontal_lines": []}}"#,
+            DRAWINGS_SCHEMA_VERSION + 1
+        );
+        assert!(tools.import_drawings(&future).is_err());
+        assert_eq!(tools.horizontal_lines.len(), 1);
+        assert!(tools.import_drawings("pas du json").is_err());
+    }
+}
@@ -1,3 +1,5 @@
+use iced::Point;
+
 use super::super::scale::{PriceScale, TimeScale};
 use super::super::core::TimeSeries;
 
@@ -9,6 +11,9 @@ const MAX_TIME_RANGE: i64 = 10 * 365 * 24 * 3600; // Maximum 10 ans visible (pou
 const MIN_PRICE_RANGE: f64 = 0.01;     // Minimum 0.01 de plage de prix
 const MAX_PRICE_RANGE: f64 = 1_000_000.0; // Maximum 1M de plage de prix
 
+/// Sensibilité du drag sur les axes : glisser sur toute la hauteur/largeur multiplie la plage par e^2
+const DRAG_SCALE_SENSITIVITY: f64 = 2.0;
+
 /// Viewport gère la vue visible du graphique
 /// 
 /// Combine les échelles de prix et de temps pour définir
@@ -21,6 +26,8 @@ pub struct Viewport {
     width: f32,
     /// Hauteur totale du viewport en pixels
     height: f32,
+    /// Ajustement automatique de la plage de prix (false = plage verrouillée)
+    auto_scale: bool,
 }
 
 impl Viewport {
@@ -31,6 +38,7 @@ impl Viewport {
             time_scale: TimeScale::new(0, 1000, width),
             width,
             height,
+            auto_scale: true,
         }
     }
 
@@ -39,7 +47,8 @@ impl Viewport {
     /// # Arguments
     /// * `data` - La série temporelle
     /// * `visible_candles` - Nombre de bougies à afficher initialement
-    pub fn focus_on_recent(&mut self, data: &TimeSeries, visible_candles: usize) {
+    /// * `right_padding_candles` - Espace vide laissé après la dernière bougie, en bougies
+    pub fn focus_on_recent(&mut self, data: &TimeSeries, visible_candles: usize, right_padding_candles: f32) {
         let max_time = match data.max_timestamp() {
             Some(t) => t,
             None => return,
@@ -61,7 +70,7 @@ impl Viewport {
             if let Some((min_price, max_price)) = data.price_range() {
                 // Ajouter une marge de 10% pour la visibilité
                 let price_margin = (max_price - min_price) * 0.1;
-                self.price_scale.set_price_range(
+                self.set_auto_price_range(
                     min_price - price_margin,
                     max_price + price_margin
                 );
@@ -90,13 +99,13 @@ impl Viewport {
             
             self.time_scale.set_time_range(
                 min_time.saturating_sub(time_padding),
-                max_time + time_padding
+                max_time + time_padding + Self::right_padding_duration(data, right_padding_candles)
             );
             
             if let Some((min_price, max_price)) = data.price_range() {
                 // Ajouter une petite marge pour la visibilité
                 let price_margin = (max_price - min_price) * 0.05;
-                self.price_scale.set_price_range(
+                self.set_auto_price_range(
                     min_price - price_margin,
                     max_price + price_margin
                 );
@@ -115,7 +124,9 @@ impl Viewport {
         let visible_time_range = candle_interval * actual_visible_candles as i64;
         let start_time = (max_time - visible_time_range).max(min_time); // S'assurer qu'on ne dépasse pas min_time
 
-        self.time_scale.set_time_range(start_time, max_time);
+        // L'espace vide à droite s'ajoute à la plage sans réduire le nombre de bougies visibles
+        let padded_max_time = max_time + Self::right_padding_duration(data, right_padding_candles);
+        self.time_scale.set_time_range(start_time, padded_max_time);
 
         // Calculer la plage de prix uniquement pour les bougies visibles
         // Utiliser la méthode avec cache si disponible
@@ -132,12 +143,99 @@ impl Viewport {
         
         // Ajouter une petite marge pour la visibilité
         let price_margin = (price_range.1 - price_range.0) * 0.05;
-        self.price_scale.set_price_range(
+        self.set_auto_price_range(
             price_range.0 - price_margin,
             price_range.1 + price_margin
         );
     }
 
+    /// Durée équivalente à `padding_candles` bougies, d'après l'intervalle moyen de la série
+    ///
+    /// Retourne 0 pour une série de moins de deux bougies ou un padding nul.
+    pub fn right_padding_duration(data: &TimeSeries, padding_candles: f32) -> i64 {
+        let (Some(min_time), Some(max_time)) = (data.min_timestamp(), data.max_timestamp()) else {
+            return 0;
+        };
+        if data.len() < 2 || padding_candles <= 0.0 {
+            return 0;
+        }
+        let candle_interval = (max_time - min_time) as f64 / (data.len() - 1) as f64;
+        (candle_interval * padding_candles as f64).round() as i64
+    }
+
+    /// Ajuste les deux échelles pour que toutes les bougies soient visibles
+    ///
+    /// Contrairement à `focus_on_recent`, aucune limite sur le nombre de bougies :
+    /// la plage temporelle et la plage de prix couvrent l'ensemble des données,
+    /// avec une petite marge. Retourne `false` (viewport inchangé) si `candles` est vide.
+    pub fn fit_to_data(&mut self, candles: &[super::super::core::Candle]) -> bool {
+        let Some((min_price, max_price)) = Self::price_range_for_candles(candles) else {
+            return false;
+        };
+        let (min_time, max_time) = candles.iter().fold((i64::MAX, i64::MIN), |(min, max), candle| {
+            (min.min(candle.timestamp), max.max(candle.timestamp))
+        });
+
+        // Marge temporelle : 2% de chaque côté, au moins une demi-bougie
+        let total_time_range = max_time - min_time;
+        let time_padding = if candles.len() > 1 && total_time_range > 0 {
+            let candle_interval = total_time_range / (candles.len() as i64 - 1);
+            (total_time_range / 50).max(candle_interval / 2)
+        } else {
+            // Une seule bougie : 1 jour de chaque côté, comme focus_on_recent
+            86400
+        };
+        self.time_scale.set_time_range(
+            min_time.saturating_sub(time_padding),
+            max_time.saturating_add(time_padding)
+        );
+
+        // Marge de prix : 5% de la plage (ou 1% du prix si la plage est nulle)
+        let price_margin = if max_price > min_price {
+            (max_price - min_price) * 0.05
+        } else {
+            (max_price.abs() * 0.01).max(MIN_PRICE_RANGE)
+        };
+        self.set_auto_price_range(
+            min_price - price_margin,
+            max_price + price_margin
+        );
+        true
+    }
+
+    /// Ajuste les deux échelles exactement sur la zone sélectionnée
+    ///
+    /// Les bornes peuvent être données dans n'importe quel ordre. Une sélection
+    /// trop petite (simple clic) est ignorée et retourne `false`.
+    pub fn zoom_to_rect(&mut self, time_range: (i64, i64), price_range: (f64, f64)) -> bool {
+        let (min_time, max_time) = (time_range.0.min(time_range.1), time_range.0.max(time_range.1));
+        let (min_price, max_price) = (price_range.0.min(price_range.1), price_range.0.max(price_range.1));
+
+        if max_time - min_time < MIN_TIME_RANGE || max_price - min_price < MIN_PRICE_RANGE {
+            return false;
+        }
+
+        self.time_scale.set_time_range(min_time, max_time);
+        self.price_scale.set_price_range(min_price, max_price);
+        true
+    }
+
+    /// Copie du viewport avec une autre plage de prix (même plage temporelle, même taille)
+    ///
+    /// Sert aux séries tracées sur l'axe de gauche, qui ont leur propre échelle de prix.
+    pub fn with_price_range(&self, min_price: f64, max_price: f64) -> Self {
+        let mut viewport = self.clone();
+        viewport.price_scale.set_price_range(min_price, max_price);
+        viewport
+    }
+
+    /// Applique une plage de prix calculée automatiquement, sauf si l'échelle est verrouillée
+    fn set_auto_price_range(&mut self, min_price: f64, max_price: f64) {
+        if self.auto_scale {
+            self.price_scale.set_price_range(min_price, max_price);
+        }
+    }
+
     /// Calcule la plage de prix pour un slice de bougies
     fn price_range_for_candles(candles: &[super::super::core::Candle]) -> Option<(f64, f64)> {
         candles.iter().fold(None, |acc, candle| {
@@ -156,6 +254,34 @@ impl Viewport {
         self.time_scale.set_width(width);
     }
 
+    /// Active ou désactive l'échelle de prix logarithmique
+    pub fn set_log_scale(&mut self, log: bool) {
+        self.price_scale.set_log(log);
+    }
+
+    /// Inverse ou rétablit le sens de l'échelle de prix (prix élevés en bas)
+    pub fn set_inverted_scale(&mut self, inverted: bool) {
+        self.price_scale.set_inverted(inverted);
+    }
+
+    /// Change la marge verticale de l'échelle de prix (0.1 = 10% en haut et en bas)
+    pub fn set_price_margin(&mut self, margin_ratio: f32) {
+        self.price_scale.set_margin_ratio(margin_ratio);
+    }
+
+    /// Active ou verrouille l'ajustement automatique de la plage de prix
+    ///
+    /// Verrouillée, la plage de prix n'est plus recalculée lors des recentrages
+    /// (`focus_on_recent`, `fit_to_data`) ; le zoom et le drag sur l'axe Y restent possibles.
+    pub fn set_auto_scale(&mut self, auto_scale: bool) {
+        self.auto_scale = auto_scale;
+    }
+
+    /// Indique si la plage de prix s'ajuste automatiquement
+    pub fn auto_scale(&self) -> bool {
+        self.auto_scale
+    }
+
     /// Retourne une référence à l'échelle de prix
     pub fn price_scale(&self) -> &PriceScale {
         &self.price_scale
@@ -176,31 +302,75 @@ impl Viewport {
         self.height
     }
 
-    /// Zoom progressif centré au milieu du graphique (axe X uniquement)
+    /// Zoom progressif horizontal (axe X uniquement) autour du point écran `anchor`
     /// 
-    /// `factor` > 1.0 = zoom out (plage plus grande), < 1.0 = zoom in (plage plus petite)
-    pub fn zoom(&mut self, factor: f64) {
-        self.zoom_horizontal(factor);
+    /// `factor` > 1.0 = zoom out (plage plus grande), < 1.0 = zoom in (plage plus petite).
+    /// Le temps sous `anchor.x` reste à la même position à l'écran.
+    pub fn zoom(&mut self, factor: f64, anchor: Point) {
+        let relative_x = if self.width > 0.0 {
+            (anchor.x / self.width).clamp(0.0, 1.0) as f64
+        } else {
+            0.5
+        };
+        self.zoom_horizontal_at(factor, relative_x);
     }
 
-    /// Zoom horizontal (axe X / temps)
-    fn zoom_horizontal(&mut self, factor: f64) {
+    /// Zoom horizontal autour d'un point d'ancrage
+    ///
+    /// `anchor` est la position relative du point fixe dans la plage
+    /// (0.0 = bord gauche, 0.5 = centre, 1.0 = bord droit).
+    fn zoom_horizontal_at(&mut self, factor: f64, anchor: f64) {
         let (min_time, max_time) = self.time_scale.time_range();
         let time_range = max_time - min_time;
-        let center_time = min_time + time_range / 2;
+        let anchor_time = min_time + (time_range as f64 * anchor) as i64;
         
         let new_time_range = (time_range as f64 * factor) as i64;
         let new_time_range = new_time_range.clamp(MIN_TIME_RANGE, MAX_TIME_RANGE);
         
-        let new_min = center_time - new_time_range / 2;
-        let new_max = center_time + new_time_range / 2;
+        let new_min = anchor_time - (new_time_range as f64 * anchor) as i64;
+        let new_max = new_min + new_time_range;
         self.time_scale.set_time_range(new_min, new_max);
     }
 
-    /// Zoom vertical (axe Y / prix) - ALT + molette
+    /// Étire ou compresse la plage temporelle en gardant le bord droit fixe (drag sur l'axe X)
+    ///
+    /// `delta_x` < 0 (vers la gauche) élargit la plage (plus de bougies visibles),
+    /// > 0 la resserre. La dernière bougie reste en place.
+    pub fn drag_scale_time(&mut self, delta_x: f32) {
+        if self.width <= 0.0 || !delta_x.is_finite() || delta_x == 0.0 {
+            return;
+        }
+        let factor = (-delta_x as f64 / self.width as f64 * DRAG_SCALE_SENSITIVITY).exp();
+        self.zoom_horizontal_at(factor, 1.0);
+    }
+
+    /// Position relative du prix sous `y` dans la plage de l'axe (0.0 = bas, 1.0 = haut)
+    ///
+    /// Calculée dans l'espace de l'axe (log10 en échelle logarithmique) ; peut
+    /// sortir de [0, 1] dans les marges. 0.5 si la plage est invalide.
+    fn anchor_fraction_y(&self, y: f32) -> f64 {
+        let (min_price, max_price) = self.price_scale.price_range();
+        let axis_min = self.price_scale.to_axis(min_price);
+        let axis_range = self.price_scale.to_axis(max_price) - axis_min;
+        if self.height <= 0.0 || axis_range <= 0.0 || !axis_range.is_finite() {
+            return 0.5;
+        }
+        let anchor_price = self.price_scale.y_to_price(y.clamp(0.0, self.height));
+        let fraction = (self.price_scale.to_axis(anchor_price) - axis_min) / axis_range;
+        if fraction.is_finite() { fraction } else { 0.5 }
+    }
+
+    /// Zoom vertical (axe Y / prix) autour du point écran `anchor` - ALT + molette
     /// 
-    /// `factor` > 1.0 = zoom out, < 1.0 = zoom in
-    pub fn zoom_vertical(&mut self, factor: f64) {
+    /// `factor` > 1.0 = zoom out, < 1.0 = zoom in. Le prix sous `anchor.y` reste
+    /// à la même position à l'écran.
+    pub fn zoom_vertical(&mut self, factor: f64, anchor: Point) {
+        let fraction = self.anchor_fraction_y(anchor.y);
+        if self.price_scale.is_log() {
+            self.zoom_vertical_log(factor, fraction);
+            return;
+        }
+
         let (min_price, max_price) = self.price_scale.price_range();
         let price_range = max_price - min_price;
         
@@ -209,8 +379,8 @@ impl Viewport {
             return;
         }
         
-        // Calculer le centre de la plage actuelle
-        let center_price = min_price + price_range / 2.0;
+        // Prix d'ancrage, qui garde sa position relative dans la plage
+        let anchor_price = min_price + price_range * fraction;
         
         // Calculer la nouvelle plage en multipliant par le facteur
         let new_price_range = price_range * factor;
@@ -235,9 +405,9 @@ impl Viewport {
             return;
         }
         
-        // Calculer les nouvelles limites centrées sur le centre actuel
-        let new_min = center_price - clamped_range / 2.0;
-        let new_max = center_price + clamped_range / 2.0;
+        // Calculer les nouvelles limites autour du prix d'ancrage
+        let new_min = anchor_price - clamped_range * fraction;
+        let new_max = new_min + clamped_range;
         
         // Vérifications finales
         if !new_min.is_finite() || !new_max.is_finite() || new_min >= new_max {
@@ -247,12 +417,65 @@ impl Viewport {
         self.price_scale.set_price_range(new_min, new_max);
     }
 
-    /// Zoom sur les deux axes (X et Y) - CTRL + molette
+    /// Zoom vertical en échelle logarithmique (ancré dans l'espace log10)
+    fn zoom_vertical_log(&mut self, factor: f64, fraction: f64) {
+        let (min_price, max_price) = self.price_scale.price_range();
+        let axis_min = self.price_scale.to_axis(min_price);
+        let axis_max = self.price_scale.to_axis(max_price);
+        let axis_range = axis_max - axis_min;
+        if axis_range <= 0.0 || !axis_range.is_finite() || factor <= 0.0 {
+            return;
+        }
+
+        let anchor = axis_min + axis_range * fraction;
+        let new_range = axis_range * factor;
+        let new_min = self.price_scale.price_from_axis(anchor - new_range * fraction);
+        let new_max = self.price_scale.price_from_axis(anchor + new_range * (1.0 - fraction));
+        if new_min.is_finite() && new_max.is_finite() && new_min < new_max {
+            self.price_scale.set_price_range(new_min, new_max);
+        }
+    }
+
+    /// Étire ou compresse la plage de prix autour de son centre (drag sur l'axe Y)
+    ///
+    /// `delta_y` > 0 (vers le bas) élargit la plage, < 0 la resserre. Le facteur est
+    /// proportionnel au déplacement relatif à la hauteur du viewport, et la plage
+    /// résultante est bornée entre `MIN_PRICE_RANGE` et `MAX_PRICE_RANGE`.
+    pub fn drag_scale_price(&mut self, delta_y: f32) {
+        if self.height <= 0.0 || !delta_y.is_finite() || delta_y == 0.0 {
+            return;
+        }
+        let factor = (delta_y as f64 / self.height as f64 * DRAG_SCALE_SENSITIVITY).exp();
+
+        // Étirement dans l'espace de l'axe (log10 en échelle logarithmique)
+        let (min_price, max_price) = self.price_scale.price_range();
+        let axis_min = self.price_scale.to_axis(min_price);
+        let axis_max = self.price_scale.to_axis(max_price);
+        let center = axis_min + (axis_max - axis_min) / 2.0;
+        let half_range = (axis_max - axis_min) * factor / 2.0;
+        let new_min = self.price_scale.price_from_axis(center - half_range);
+        let new_max = self.price_scale.price_from_axis(center + half_range);
+        if !new_min.is_finite() || !new_max.is_finite() || new_min >= new_max {
+            return;
+        }
+
+        // Ne jamais écraser l'échelle à zéro ni l'étendre à l'infini
+        let range = new_max - new_min;
+        if !(MIN_PRICE_RANGE..=MAX_PRICE_RANGE).contains(&range) {
+            let mid = new_min + range / 2.0;
+            let half = range.clamp(MIN_PRICE_RANGE, MAX_PRICE_RANGE) / 2.0;
+            self.price_scale.set_price_range(mid - half, mid + half);
+        } else {
+            self.price_scale.set_price_range(new_min, new_max);
+        }
+    }
+
+    /// Zoom sur les deux axes (X et Y) autour du point écran `anchor` - CTRL + molette
     /// 
     /// `factor` > 1.0 = zoom out, < 1.0 = zoom in
-    pub fn zoom_both(&mut self, factor: f64) {
-        self.zoom_horizontal(factor);
-        self.zoom_vertical(factor);
+    pub fn zoom_both(&mut self, factor: f64, anchor: Point) {
+        self.zoom(factor, anchor);
+        self.zoom_vertical(factor, anchor);
     }
 
     /// Pan horizontal basé sur un delta en pixels
@@ -264,13 +487,97 @@ impl Viewport {
         self.time_scale.set_time_range(min_time + delta_seconds, max_time + delta_seconds);
     }
 
+    /// Décale la plage temporelle vers la droite si elle commence avant `min_time`
+    ///
+    /// La durée affichée est conservée.
+    pub fn clamp_min_time(&mut self, min_time: i64) {
+        let (start, end) = self.time_scale.time_range();
+        if start < min_time {
+            self.time_scale.set_time_range(min_time, end + (min_time - start));
+        }
+    }
+
+    /// Aligne les bords de la plage temporelle sur des limites de bougies
+    ///
+    /// Les bougies étant centrées sur leur timestamp, les limites se trouvent à
+    /// `k * interval + interval / 2`. Chaque bord est arrondi à la limite la plus
+    /// proche et la plage couvre toujours au moins une bougie.
+    pub fn snap_to_candles(&mut self, interval_seconds: i64) {
+        if interval_seconds <= 0 {
+            return;
+        }
+        // La limite la plus proche de `time` est le milieu de la période qui le contient
+        let half = interval_seconds / 2;
+        let snap = |time: i64| time.div_euclid(interval_seconds) * interval_seconds + half;
+
+        let (min_time, max_time) = self.time_scale.time_range();
+        let new_min = snap(min_time);
+        let new_max = snap(max_time).max(new_min + interval_seconds);
+        self.time_scale.set_time_range(new_min, new_max);
+    }
+
     /// Pan vertical basé sur un delta en pixels
     pub fn pan_vertical(&mut self, delta_y: f32) {
+        // Le déplacement se fait dans l'espace de l'axe (log10 en échelle logarithmique)
         let (min_price, max_price) = self.price_scale.price_range();
-        let price_range = max_price - min_price;
-        let price_per_pixel = price_range / self.height as f64;
-        let delta_price = delta_y as f64 * price_per_pixel;
-        self.price_scale.set_price_range(min_price + delta_price, max_price + delta_price);
+        let axis_min = self.price_scale.to_axis(min_price);
+        let axis_max = self.price_scale.to_axis(max_price);
+        let value_per_pixel = (axis_max - axis_min) / self.height as f64;
+        // Échelle inversée : les prix augmentent vers le bas, le contenu suit toujours le curseur
+        let direction = if self.price_scale.is_inverted() { -1.0 } else { 1.0 };
+        let delta = delta_y as f64 * value_per_pixel * direction;
+        self.price_scale.set_price_range(
+            self.price_scale.price_from_axis(axis_min + delta),
+            self.price_scale.price_from_axis(axis_max + delta),
+        );
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snap_to_candles_aligns_edges() {
+        let mut viewport = Viewport::new(800.0, 600.0);
+        viewport.time_scale.set_time_range(1_010, 4_250);
+        viewport.snap_to_candles(60);
+
+        let (min_time, max_time) = viewport.time_scale().time_range();
+        // Bougies centrées sur leur timestamp : limites à k * 60 + 30
+        assert_eq!((min_time, max_time), (990, 4_230));
+
+        // Le zoom reste possible après l'alignement
+        viewport.zoom(0.5, Point::new(400.0, 300.0));
+        let (zoomed_min, zoomed_max) = viewport.time_scale().time_range();
+        assert!(zoomed_max - zoomed_min < max_time - min_time);
+    }
+
+    #[test]
+    fn test_zoom_keeps_anchor_under_cursor() {
+        let mut viewport = Viewport::new(1000.0, 500.0);
+        viewport.zoom_to_rect((0, 10_000), (100.0, 200.0));
+        let cursor = Point::new(250.0, 100.0);
+        let time_before = viewport.time_scale().x_to_time(cursor.x);
+        let price_before = viewport.price_scale().y_to_price(cursor.y);
+
+        viewport.zoom_both(0.5, cursor);
+
+        let (min_time, max_time) = viewport.time_scale().time_range();
+        assert_eq!(max_time - min_time, 5_000);
+        assert!((viewport.time_scale().x_to_time(cursor.x) - time_before).abs() <= 1);
+        assert!((viewport.price_scale().y_to_price(cursor.y) - price_before).abs() < 1e-6);
+
+        // Ancrage sur le bord droit : la fin de la plage ne bouge pas
+        viewport.zoom(2.0, Point::new(1000.0, 250.0));
+        assert_eq!(viewport.time_scale().time_range().1, max_time);
+    }
+
+    #[test]
+    fn test_snap_to_candles_keeps_one_candle() {
+        let mut viewport = Viewport::new(800.0, 600.0);
+        viewport.time_scale.set_time_range(3_605, 3_610);
+        viewport.snap_to_candles(3_600);
+        assert_eq!(viewport.time_scale().time_range(), (5_400, 9_000));
+    }
+}
@@ -3,30 +3,52 @@
 //! Architecture Elm : émet des messages pour les mutations d'état,
 //! reçoit des références immuables pour le rendu.
 
-use iced::widget::canvas::{Canvas, Frame, Geometry, Program, Action as CanvasAction, Path, Text};
+use iced::widget::canvas::{Cache, Canvas, Frame, Geometry, Program, Action as CanvasAction, Path, Text};
 use iced::{Element, Event, Length, Point, Rectangle, Size, Color};
 use iced::{keyboard, mouse};
+use std::cell::Cell;
+use std::time::{Duration, Instant};
 
 use super::render::{
-    render_candlesticks, render_current_price_line, render_grid,
-    render_crosshair, render_tooltip, find_candle_at_position,
-    draw_rectangle, draw_preview_rectangle,
-    draw_horizontal_line, draw_hline_preview, hit_test_hline,
-    render_trade_markers,
+    render_candlesticks, CandleSizing, render_current_price_line, render_grid, render_sessions, render_gaps, render_day_separators,
+    calculate_nice_time_step, format_time,
+    render_crosshair, find_nearest_ohlc, render_tooltip, find_candle_at_position,
+    draw_rectangle, draw_preview_rectangle, draw_zoom_selection,
+    draw_horizontal_line, draw_hline_preview, hit_test_hline, hit_test_hline_label,
+    draw_vertical_line, draw_vline_preview, hit_test_vline,
+    draw_trend_line, draw_ray_preview, hit_test_trend_line,
+    draw_text_annotation, hit_test_text,
+    draw_measure, calculate_candle_period,
+    render_trade_markers, render_pnl_connectors, visible_trade_clusters, find_cluster_at, render_cluster_tooltip,
     draw_pending_order_lines, draw_tp_sl_lines, draw_preview_limit_order_lines, draw_preview_tp_sl_lines,
     render_bollinger_bands, BollingerStyle,
-    render_moving_average, MovingAverageStyle,
+    render_moving_averages, MovingAverageStyle,
+    render_vwap, VwapStyle,
+    render_psar, PsarStyle, render_donchian, DonchianStyle, render_keltner, KeltnerStyle,
+    render_volume_profile, render_pivots, render_alerts, render_crossover_markers, render_signal_markers,
+    render_line_series, LineStyle, render_area_series, aggregate_for_width, needs_lod,
+    render_renko, render_ohlc_bars,
     grid::GridStyle, current_price::CurrentPriceStyle,
     crosshair::CrosshairStyle, tooltip::TooltipStyle,
 };
 use super::interaction::{hit_test_rectangles, cursor_for_edit_mode};
+use super::core::{Candle, PriceAxis};
 use super::state::ChartState;
 use super::tools::{Tool, ToolsState};
-use super::settings::{SettingsState, ChartStyle};
+use super::settings::{SettingsState, ChartStyle, ChartRenderMode, format_price};
 use super::messages::ChartMessage;
 
+/// Délai maximal entre deux clics pour un double-clic
+const DOUBLE_CLICK_DELAY: Duration = Duration::from_millis(400);
+
+/// Distance maximale (pixels) entre deux clics pour un double-clic
+const DOUBLE_CLICK_DISTANCE_PX: f32 = 5.0;
+
+/// Déplacement maximal (pixels) entre l'appui et le relâchement pour un clic sur une bougie
+const CLICK_DRAG_TOLERANCE_PX: f32 = 3.0;
+
 /// État local du widget (UI uniquement, pas de données business)
-#[derive(Debug, Clone, Default)]
+#[derive(Default)]
 pub struct WidgetState {
     /// ALT est maintenu
     pub alt_pressed: bool,
@@ -34,6 +56,84 @@ pub struct WidgetState {
     pub ctrl_pressed: bool,
     /// SHIFT est maintenu (pour afficher le tooltip)
     pub shift_pressed: bool,
+    /// Instant et position du dernier clic gauche (détection du double-clic)
+    last_click: Option<(Instant, Point)>,
+    /// Position de l'appui gauche sans outil actif (détection d'un clic sur une bougie)
+    press_position: Option<Point>,
+    /// Géométrie des couches statiques (fond, séries, indicateurs, dessins)
+    static_cache: Cache,
+    /// Clé de la géométrie en cache (None = jamais dessinée)
+    static_key: Cell<Option<StaticLayerKey>>,
+}
+
+/// Ce dont dépendent les couches statiques : version des données de l'app, série affichée et viewport
+///
+/// Tant que la clé est inchangée, seuls le crosshair et le tooltip sont redessinés.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct StaticLayerKey {
+    render_version: u64,
+    /// Nombre de bougies et dernière bougie de la série affichée (mises à jour temps réel)
+    candle_count: usize,
+    last_candle: Option<Candle>,
+    time_range: (i64, i64),
+    price_range: (f64, f64),
+    size: (f32, f32),
+    log_scale: bool,
+    inverted_scale: bool,
+}
+
+impl StaticLayerKey {
+    fn new(program: &ChartProgram, size: Size) -> Self {
+        let viewport = &program.chart_state.viewport;
+        let series = program.chart_state.displayed_series();
+        Self {
+            render_version: program.options.render_version,
+            candle_count: series.map_or(0, |s| s.data.len()),
+            last_candle: series.and_then(|s| s.data.last_candle()).copied(),
+            time_range: viewport.time_scale().time_range(),
+            price_range: viewport.price_scale().price_range(),
+            size: (size.width, size.height),
+            log_scale: viewport.price_scale().is_log(),
+            inverted_scale: viewport.price_scale().is_inverted(),
+        }
+    }
+}
+
+/// Options d'affichage passées aux fonctions `chart*`
+///
+/// Regroupe les overlays activés et le contexte de rendu ; `ChartOptions::default()`
+/// affiche le graphique seul, sans overlay.
+#[derive(Clone, Copy, Default)]
+pub struct ChartOptions<'a> {
+    /// Indique si un panneau a le focus (désactive les interactions du chart)
+    pub panel_focused: bool,
+    /// Indique si les bandes de Bollinger sont activées
+    pub bollinger_enabled: bool,
+    /// Indique si la moyenne mobile est activée
+    pub ma_enabled: bool,
+    /// Indique si le VWAP est activé
+    pub vwap_enabled: bool,
+    /// Indique si le Parabolic SAR est activé
+    pub psar_enabled: bool,
+    /// Indique si les canaux de Donchian sont activés
+    pub donchian_enabled: bool,
+    /// Indique si les canaux de Keltner sont activés
+    pub keltner_enabled: bool,
+    /// Indique si le Volume Profile est activé
+    pub volume_profile_enabled: bool,
+    /// Indique si les points pivots sont activés
+    pub pivots_enabled: bool,
+    /// Indique si les marqueurs de croisement de moyennes mobiles sont activés
+    pub crossover_markers_enabled: bool,
+    /// Paramètres des indicateurs
+    pub indicator_params: Option<&'a crate::app::state::IndicatorParams>,
+    /// État du backtest (optionnel)
+    pub backtest_state: Option<&'a crate::app::state::backtest::BacktestState>,
+    /// Version des données de l'app (`ChartApp::render_version`), invalide le cache de rendu
+    ///
+    /// Les changements de la série affichée et du viewport sont détectés sans elle ;
+    /// l'appelant doit l'incrémenter quand les overlays, les dessins ou le style changent.
+    pub render_version: u64,
 }
 
 /// Program Iced pour le rendu du graphique
@@ -43,22 +143,14 @@ pub struct ChartProgram<'a> {
     tools_state: &'a ToolsState,
     settings_state: &'a SettingsState,
     chart_style: &'a ChartStyle,
-    /// Indique si un panneau a le focus (désactive les interactions du chart)
-    panel_focused: bool,
     /// Trades à afficher (optionnel, pour le mode paper trading)
     trades: Option<&'a [crate::app::data::Trade]>,
     /// Symbole actuel pour filtrer les trades
     current_symbol: Option<&'a str>,
     /// État de trading pour afficher les ordres limit et TP/SL
     trading_state: Option<&'a crate::app::state::TradingState>,
-    /// Indique si les bandes de Bollinger sont activées
-    bollinger_enabled: bool,
-    /// Indique si la moyenne mobile est activée
-    ma_enabled: bool,
-    /// Paramètres des indicateurs
-    indicator_params: Option<&'a crate::app::state::IndicatorParams>,
-    /// État du backtest (optionnel)
-    backtest_state: Option<&'a crate::app::state::backtest::BacktestState>,
+    /// Overlays, paramètres des indicateurs et contexte de rendu
+    options: ChartOptions<'a>,
 }
 
 impl<'a> ChartProgram<'a> {
@@ -74,14 +166,10 @@ impl<'a> ChartProgram<'a> {
             tools_state, 
             settings_state, 
             chart_style, 
-            panel_focused,
             trades: None,
             current_symbol: None,
             trading_state: None,
-            bollinger_enabled: false,
-            ma_enabled: false,
-            indicator_params: None,
-            backtest_state: None,
+            options: ChartOptions { panel_focused, ..ChartOptions::default() },
         }
     }
     
@@ -99,14 +187,10 @@ impl<'a> ChartProgram<'a> {
             tools_state, 
             settings_state, 
             chart_style, 
-            panel_focused,
             trades: None,
             current_symbol: Some(current_symbol),
             trading_state: Some(trading_state),
-            bollinger_enabled: false,
-            ma_enabled: false,
-            indicator_params: None,
-            backtest_state: None,
+            options: ChartOptions { panel_focused, ..ChartOptions::default() },
         }
     }
     
@@ -125,17 +209,21 @@ impl<'a> ChartProgram<'a> {
             tools_state, 
             settings_state, 
             chart_style, 
-            panel_focused,
             trades: Some(trades),
             current_symbol: Some(current_symbol),
             trading_state: Some(trading_state),
-            bollinger_enabled: false,
-            ma_enabled: false,
-            indicator_params: None,
-            backtest_state: None,
+            options: ChartOptions { panel_focused, ..ChartOptions::default() },
         }
     }
 
+    /// Intervalle entre bougies de la série affichée (1h par défaut)
+    fn candle_period(&self) -> i64 {
+        self.chart_state.visible_candles()
+            .first()
+            .map(|(_, candles)| calculate_candle_period(candles))
+            .unwrap_or(3600)
+    }
+
     /// Génère des couleurs différentes pour chaque série
     fn get_series_colors(&self, series_idx: usize, series_id: &super::core::SeriesId) -> super::render::candlestick::CandleColors {
         use iced::Color;
@@ -243,8 +331,8 @@ impl<'a> ChartProgram<'a> {
             1.0, // Opacité complète pour le fond
         );
         
-        // Formater le prix avec 2 décimales
-        let price_label = format!("{:.2}", current_price);
+        // Formater le prix selon la précision configurée
+        let price_label = format_price(current_price, self.chart_style);
         
         let padding_x = 4.0;
         let padding_y = 2.0;
@@ -273,26 +361,112 @@ impl<'a> ChartProgram<'a> {
         frame.fill_text(text);
     }
 
-    /// Dessine tous les éléments dessinés (rectangles et lignes horizontales)
+    /// Dessine le bandeau d'informations en haut à gauche
+    ///
+    /// Nombre de bougies visibles, intervalle affiché et période visible, formatée
+    /// comme les labels de l'axe X. Recalculé à chaque frame pour suivre pan et zoom.
+    fn draw_hud(&self, frame: &mut Frame) {
+        let viewport = &self.chart_state.viewport;
+        let visible_count: usize = self.chart_state.visible_candles()
+            .first()
+            .map(|(_, candles)| candles.len())
+            .unwrap_or(0);
+
+        let mut parts = vec![format!("{} bougies", visible_count)];
+        if let Some(interval) = self.chart_state.displayed_interval() {
+            parts.push(interval.to_string());
+        }
+        // En Renko, l'axe X n'est pas linéaire en temps
+        if self.chart_style.render_mode != ChartRenderMode::Renko {
+            let (min_time, max_time) = viewport.time_scale().time_range();
+            let time_step = calculate_nice_time_step(max_time - min_time);
+            parts.push(format!(
+                "{} → {}",
+                format_time(min_time, time_step),
+                format_time(max_time, time_step)
+            ));
+        }
+        let label = parts.join("  ·  ");
+
+        let text_size = 11.0;
+        let padding_x = 6.0;
+        let padding_y = 3.0;
+        // Estimation de la largeur du texte (pas de mesure disponible dans le canvas)
+        let label_width = label.chars().count() as f32 * text_size * 0.6 + padding_x * 2.0;
+        let label_height = text_size + padding_y * 2.0;
+        let origin = Point::new(6.0, 6.0);
+
+        let bg_rect = Path::rectangle(origin, Size::new(label_width, label_height));
+        frame.fill(&bg_rect, Color::from_rgba(0.0, 0.0, 0.0, 0.45));
+
+        frame.fill_text(Text {
+            content: label,
+            position: Point::new(origin.x + padding_x, origin.y + padding_y),
+            color: self.chart_style.text_color.to_iced(),
+            size: iced::Pixels(text_size),
+            ..Text::default()
+        });
+    }
+
+    /// Dessine tous les éléments dessinés (rectangles, lignes, demi-droites et textes)
     fn draw_all_drawings(&self, frame: &mut Frame) {
         let viewport = &self.chart_state.viewport;
 
+        // Les dessins masqués (liste des dessins) ne sont pas rendus
         // Dessiner les lignes horizontales
         let selected_hline = self.tools_state.hline_editing.selected_index;
-        for (index, line) in self.tools_state.horizontal_lines.iter().enumerate() {
+        for (index, line) in self.tools_state.horizontal_lines.iter().enumerate().filter(|(_, line)| !line.hidden) {
             let is_selected = selected_hline == Some(index);
-            draw_horizontal_line(frame, viewport, line, is_selected);
+            draw_horizontal_line(frame, viewport, line, is_selected, self.chart_style);
+        }
+
+        // Dessiner les lignes verticales
+        let selected_vline = self.tools_state.vline_editing.selected_index;
+        for (index, line) in self.tools_state.vertical_lines.iter().enumerate().filter(|(_, line)| !line.hidden) {
+            let is_selected = selected_vline == Some(index);
+            draw_vertical_line(frame, viewport, line, is_selected);
+        }
+
+        // Dessiner les lignes de tendance et demi-droites
+        let selected_trend_line = self.tools_state.selected_trend_line;
+        for (index, line) in self.tools_state.trend_lines.iter().enumerate().filter(|(_, line)| !line.hidden) {
+            let is_selected = selected_trend_line == Some(index);
+            draw_trend_line(frame, viewport, line, is_selected);
         }
 
         // Dessiner les rectangles
         let selected_rect = self.tools_state.editing.selected_index;
-        for (index, rect) in self.tools_state.rectangles.iter().enumerate() {
+        for (index, rect) in self.tools_state.rectangles.iter().enumerate().filter(|(_, rect)| !rect.hidden) {
             let is_selected = selected_rect == Some(index);
             draw_rectangle(frame, viewport, rect, is_selected);
         }
 
+        // Dessiner les annotations texte
+        let selected_text = self.tools_state.selected_text;
+        for (index, text) in self.tools_state.texts.iter().enumerate().filter(|(_, text)| !text.hidden) {
+            let is_selected = selected_text == Some(index);
+            draw_text_annotation(frame, viewport, text, is_selected);
+        }
+
+        // Dessiner la sélection de zoom en cours (prioritaire sur l'outil actif)
+        if self.tools_state.drawing.is_drawing && self.tools_state.drawing.zoom_selection {
+            if let (Some((start_x, start_y)), Some((current_x, current_y))) = (
+                self.tools_state.drawing.start_screen_point,
+                self.tools_state.drawing.current_screen_point,
+            ) {
+                draw_zoom_selection(frame, start_x, start_y, current_x, current_y);
+            }
+        }
+
+        // Pendant une sélection de zoom, aucun aperçu d'outil n'est affiché
+        let preview_tool = if self.tools_state.drawing.zoom_selection {
+            None
+        } else {
+            self.tools_state.selected_tool
+        };
+
         // Dessiner l'aperçu du rectangle en cours de dessin
-        if self.tools_state.drawing.is_drawing && self.tools_state.selected_tool == Some(Tool::Rectangle) {
+        if self.tools_state.drawing.is_drawing && preview_tool == Some(Tool::Rectangle) {
             if let (Some((start_x, start_y)), Some((current_x, current_y))) = (
                 self.tools_state.drawing.start_screen_point,
                 self.tools_state.drawing.current_screen_point,
@@ -302,25 +476,52 @@ impl<'a> ChartProgram<'a> {
         }
 
         // Dessiner l'aperçu de la ligne horizontale en cours
-        if self.tools_state.drawing.is_drawing && self.tools_state.selected_tool == Some(Tool::HorizontalLine) {
+        if self.tools_state.drawing.is_drawing && preview_tool == Some(Tool::HorizontalLine) {
             if let Some((_, y)) = self.tools_state.drawing.current_screen_point {
                 draw_hline_preview(frame, y, viewport.width());
             }
         }
+
+        // Dessiner l'aperçu de la ligne verticale en cours
+        if self.tools_state.drawing.is_drawing && preview_tool == Some(Tool::VerticalLine) {
+            if let Some((x, _)) = self.tools_state.drawing.current_screen_point {
+                draw_vline_preview(frame, x, viewport.height());
+            }
+        }
+
+        // Dessiner la mesure en cours (transitoire)
+        if self.tools_state.drawing.is_drawing && preview_tool == Some(Tool::Measure) {
+            if let (Some((start_x, start_y)), Some((current_x, current_y))) = (
+                self.tools_state.drawing.start_screen_point,
+                self.tools_state.drawing.current_screen_point,
+            ) {
+                draw_measure(frame, viewport, Point::new(start_x, start_y), Point::new(current_x, current_y), self.candle_period());
+            }
+        }
+
+        // Dessiner l'aperçu de la demi-droite en cours
+        if self.tools_state.drawing.is_drawing && preview_tool == Some(Tool::Ray) {
+            if let (Some((start_x, start_y)), Some((current_x, current_y))) = (
+                self.tools_state.drawing.start_screen_point,
+                self.tools_state.drawing.current_screen_point,
+            ) {
+                draw_ray_preview(frame, viewport, Point::new(start_x, start_y), Point::new(current_x, current_y));
+            }
+        }
         
         // Dessiner les lignes des ordres limit et TP/SL si on a le trading_state
         if let (Some(trading_state), Some(current_symbol)) = (self.trading_state, self.current_symbol) {
             // Dessiner les ordres limit en attente
-            draw_pending_order_lines(frame, viewport, &trading_state.trade_history.pending_orders, current_symbol);
+            draw_pending_order_lines(frame, viewport, &trading_state.trade_history.pending_orders, current_symbol, self.chart_style);
             
             // Dessiner les lignes TP/SL des positions ouvertes
-            draw_tp_sl_lines(frame, viewport, &trading_state.trade_history.open_positions, current_symbol);
+            draw_tp_sl_lines(frame, viewport, &trading_state.trade_history.open_positions, current_symbol, self.chart_style);
             
             // Dessiner les lignes de prévisualisation si on est en mode Limit et qu'un prix limite est saisi
             use crate::app::data::OrderType;
             if trading_state.order_type == OrderType::Limit {
                 if let Some(limit_price) = trading_state.parse_limit_price() {
-                    draw_preview_limit_order_lines(frame, viewport, limit_price);
+                    draw_preview_limit_order_lines(frame, viewport, limit_price, self.chart_style);
                 }
             }
             
@@ -346,198 +547,571 @@ impl<'a> ChartProgram<'a> {
                 } else {
                     trading_state.parse_stop_loss()
                 };
-                draw_preview_tp_sl_lines(frame, viewport, preview_tp, preview_sl);
+                draw_preview_tp_sl_lines(frame, viewport, preview_tp, preview_sl, self.chart_style);
             }
         }
     }
 }
 
-impl<'a> Program<ChartMessage> for ChartProgram<'a> {
-    type State = WidgetState;
+/// Dessine le graphique complet (fond, séries, indicateurs, dessins, crosshair) dans `frame`
+///
+/// Séquence de rendu de `ChartProgram::draw`, extraite pour pouvoir dessiner
+/// hors d'une fenêtre Iced (export PNG).
+pub fn draw_chart(
+    frame: &mut Frame,
+    program: &ChartProgram,
+    widget_state: &WidgetState,
+    size: Size,
+    mouse_position: Option<Point>,
+) {
+    draw_static_layers(frame, program, size);
+    draw_dynamic_layers(frame, program, widget_state, mouse_position);
+}
 
-    fn draw(
-        &self,
-        widget_state: &Self::State,
-        renderer: &iced::Renderer,
-        _theme: &iced::Theme,
-        bounds: Rectangle,
-        cursor: iced::mouse::Cursor,
-    ) -> Vec<Geometry> {
-        let mut frame = Frame::new(renderer, bounds.size());
+/// Dessine les couches qui ne dépendent pas de la souris (mises en cache par le widget)
+fn draw_static_layers(frame: &mut Frame, program: &ChartProgram, size: Size) {
+    // Les couleurs sont maintenant gérées par série dans get_series_colors()
+    let grid_style = GridStyle {
+        line_color: program.chart_style.grid_color.to_iced(),
+        line_width: 1.0,
+        dashed: program.chart_style.grid.dashed,
+        minor_lines: program.chart_style.grid.minor_lines,
+        density: program.chart_style.grid.density,
+    };
+    let price_style = CurrentPriceStyle {
+        line_color: program.chart_style.current_price_color.to_iced(),
+        ..Default::default()
+    };
 
-        // Mettre à jour la position de la souris (lecture seule)
-        let mouse_position = cursor.position_in(bounds);
+    // Rendu du graphique de base
+    // Fond avec la couleur personnalisée
+    let bg_color = program.chart_style.background_color.to_iced();
+    frame.fill_rectangle(iced::Point::ORIGIN, size, bg_color);
+    
+    // Sessions de trading (sans objet en Renko, indépendant du temps)
+    if program.chart_style.render_mode != ChartRenderMode::Renko {
+        render_sessions(frame, &program.chart_state.viewport, &program.chart_style.sessions);
 
-        // Les couleurs sont maintenant gérées par série dans get_series_colors()
-        let grid_style = GridStyle {
-            line_color: self.chart_style.grid_color.to_iced(),
-            line_width: 1.0,
-        };
-        let price_style = CurrentPriceStyle {
-            line_color: self.chart_style.current_price_color.to_iced(),
-            ..Default::default()
-        };
+        // Zones sans données (trous de l'historique)
+        if program.settings_state.show_gaps {
+            if let Some(gaps) = program.chart_state.data_gaps() {
+                render_gaps(frame, &program.chart_state.viewport, &gaps);
+            }
+        }
 
-        // Rendu du graphique de base
-        // Fond avec la couleur personnalisée
-        let bg_color = self.chart_style.background_color.to_iced();
-        frame.fill_rectangle(iced::Point::ORIGIN, bounds.size(), bg_color);
-        
-        render_grid(&mut frame, &self.chart_state.viewport, Some(grid_style));
-        
-        // Rendre toutes les séries actives avec des couleurs différentes
-        // Pour les séries avec peu de bougies, passer toutes les bougies au renderer
-        let visible_series = self.chart_state.visible_candles();
-        
-        // Déterminer le timestamp de coupure et si on doit cacher les bougies après
-        // Seulement si le backtest est activé
-        let (cutoff_timestamp, hide_after_cutoff) = if let Some(backtest_state) = self.backtest_state {
-            // Vérifier que le backtest est activé avant d'appliquer l'opacité
-            if !backtest_state.enabled {
-                (None, false)
-            } else if let Some(current_ts) = backtest_state.current_candle_timestamp() {
-                // Si le player est en play, cacher les bougies après la barre
-                let hide = backtest_state.is_playing;
-                (Some(current_ts), hide)
-            } else if let Some(start_ts) = backtest_state.start_timestamp {
-                // Si pas de timestamp actuel mais un timestamp de départ, l'utiliser
-                (Some(start_ts), false)
-            } else {
-                (None, false)
+        // Séparateurs de journée (intraday uniquement)
+        if program.settings_state.show_day_separators {
+            let utc_offset = program.chart_style.sessions.utc_offset_minutes;
+            if let Some(boundaries) = program.chart_state.day_boundaries(utc_offset) {
+                render_day_separators(frame, &program.chart_state.viewport, &boundaries);
+            }
+        }
+    }
+    
+    // Mode comparaison : séries rebasées en pourcentage, tracées en lignes sur une échelle commune.
+    // Indicateurs, alertes et dessins sont exprimés en prix : ils ne sont pas affichés.
+    if program.chart_state.comparison_mode {
+        let comparison_series = program.chart_state.comparison_candles();
+        if let Some(viewport) = program.chart_state.comparison_viewport(&comparison_series) {
+            render_grid(frame, &viewport, Some(grid_style));
+            for (series_idx, (series_id, candles)) in comparison_series.iter().enumerate() {
+                let line_style = LineStyle {
+                    color: program.get_series_colors(series_idx, series_id).bullish,
+                    ..Default::default()
+                };
+                render_line_series(frame, candles, &viewport, Some(line_style), None, false);
             }
+        }
+        if program.settings_state.show_hud {
+            program.draw_hud(frame);
+        }
+        return;
+    }
+    
+    render_grid(frame, &program.chart_state.viewport, Some(grid_style));
+    
+    // Rendre toutes les séries actives avec des couleurs différentes
+    // Pour les séries avec peu de bougies, passer toutes les bougies au renderer
+    let visible_series = program.chart_state.visible_candles();
+    // Échelle de prix propre aux séries assignées à l'axe de gauche
+    let left_viewport = program.chart_state.left_axis_viewport();
+    
+    // Déterminer le timestamp de coupure et si on doit cacher les bougies après
+    // Seulement si le backtest est activé
+    let (cutoff_timestamp, hide_after_cutoff) = if let Some(backtest_state) = program.options.backtest_state {
+        // Vérifier que le backtest est activé avant d'appliquer l'opacité
+        if !backtest_state.enabled {
+            (None, false)
+        } else if let Some(current_ts) = backtest_state.current_candle_timestamp() {
+            // Si le player est en play, cacher les bougies après la barre
+            let hide = backtest_state.is_playing;
+            (Some(current_ts), hide)
+        } else if let Some(start_ts) = backtest_state.start_timestamp {
+            // Si pas de timestamp actuel mais un timestamp de départ, l'utiliser
+            (Some(start_ts), false)
         } else {
             (None, false)
-        };
-        
-        for (series_idx, (series_id, candles)) in visible_series.iter().enumerate() {
-            // Si on a très peu de bougies visibles mais que la série en a plus,
-            // c'est probablement un problème de filtrage - utiliser toutes les bougies
-            let candles_to_render = if candles.len() <= 5 {
-                if let Some(series) = self.chart_state.series_manager.get_series(series_id) {
-                    if series.data.len() > candles.len() * 2 {
-                        // Le filtrage a retiré trop de bougies, utiliser toutes les bougies
-                        series.data.all_candles()
-                    } else {
-                        *candles
-                    }
+        }
+    } else {
+        (None, false)
+    };
+    
+    for (series_idx, (series_id, candles)) in visible_series.iter().enumerate() {
+        // Si on a très peu de bougies visibles mais que la série en a plus,
+        // c'est probablement un problème de filtrage - utiliser toutes les bougies
+        // (sauf en replay, où les bougies futures doivent rester masquées)
+        let candles_to_render = if candles.len() <= 5 && program.chart_state.replay_cursor.is_none() {
+            if let Some(series) = program.chart_state.series_manager.get_series(series_id) {
+                if series.data.len() > candles.len() * 2 {
+                    // Le filtrage a retiré trop de bougies, utiliser toutes les bougies
+                    series.data.all_candles()
                 } else {
                     *candles
                 }
             } else {
                 *candles
-            };
-            
-            // Générer des couleurs différentes pour chaque série
-            let series_colors = self.get_series_colors(series_idx, series_id);
-            render_candlesticks(
-                &mut frame, 
-                candles_to_render, 
-                &self.chart_state.viewport, 
-                Some(series_colors),
-                cutoff_timestamp,
-                hide_after_cutoff,
-            );
-        }
+            }
+        } else {
+            *candles
+        };
         
-        // Afficher la ligne de prix courant de la première série active
-        if let Some(last_candle) = self.chart_state.last_candle() {
-            render_current_price_line(&mut frame, &self.chart_state.viewport, last_candle.close, Some(price_style));
-            // Afficher le label du prix actuel sur le bord droit (avant la zone Y)
-            self.draw_current_price_label(&mut frame, last_candle);
-        }
-
-        // Rendu des bandes de Bollinger (si activées)
-        if self.bollinger_enabled {
-            // Calculer toutes les valeurs Bollinger sur toutes les bougies
-            use crate::finance_chart::indicators::bollinger::calculate_all_bollinger_values;
-            use crate::finance_chart::indicators::bollinger::calculate_bollinger_data;
-            
-            let period = self.indicator_params.map(|p| p.bollinger_period);
-            let std_dev = self.indicator_params.map(|p| p.bollinger_std_dev);
-            if let Some(all_bollinger_values) = calculate_all_bollinger_values(self.chart_state, period, std_dev) {
-                // Extraire les valeurs correspondant aux bougies visibles
-                if let Some((visible_bollinger_values, visible_candles, _)) = 
-                    calculate_bollinger_data(self.chart_state, &all_bollinger_values) 
-                {
-                    let bollinger_style = BollingerStyle::default();
-                    render_bollinger_bands(
-                        &mut frame, 
-                        &self.chart_state.viewport, 
-                        visible_candles, 
-                        visible_bollinger_values,
-                        Some(bollinger_style)
+        // Transformer en Heikin-Ashi si activé (calculé sur toute la série puis découpé)
+        let heikin_ashi = if program.settings_state.heikin_ashi_enabled {
+            program.chart_state.heikin_ashi_candles(series_id)
+        } else {
+            None
+        };
+        let candles_to_render: &[crate::finance_chart::core::Candle] = match (&heikin_ashi, candles_to_render.first()) {
+            (Some(ha), Some(first)) => {
+                let start = ha.partition_point(|c| c.timestamp < first.timestamp);
+                let end = (start + candles_to_render.len()).min(ha.len());
+                &ha[start..end]
+            }
+            _ => candles_to_render,
+        };
+        
+        // Chaque série est tracée avec l'échelle de prix de son axe
+        let viewport = match (program.chart_state.series_axis(series_id), &left_viewport) {
+            (PriceAxis::Left, Some(left_viewport)) => left_viewport,
+            _ => &program.chart_state.viewport,
+        };
+        
+        // LOD : plus de bougies que de colonnes de pixels, fusionner par colonne avant le dessin
+        let lod_candles;
+        let candles_to_render: &[crate::finance_chart::core::Candle] = if needs_lod(candles_to_render, viewport) {
+            lod_candles = aggregate_for_width(candles_to_render, viewport);
+            &lod_candles
+        } else {
+            candles_to_render
+        };
+        
+        // Générer des couleurs différentes pour chaque série
+        let series_colors = program.get_series_colors(series_idx, series_id);
+        match program.chart_style.render_mode {
+            ChartRenderMode::Candlestick => {
+                // Heatmap de volume : plage relative à la fenêtre visible
+                let volume_heat = if program.settings_state.volume_heatmap {
+                    let (min_time, max_time) = program.chart_state.viewport.time_scale().time_range();
+                    program.chart_state.displayed_series()
+                        .filter(|series| series.id == *series_id)
+                        .or_else(|| program.chart_state.series_manager.get_series(series_id))
+                        .and_then(|series| series.data.volume_range_for_time_range(min_time..max_time))
+                } else {
+                    None
+                };
+                render_candlesticks(
+                    frame, 
+                    candles_to_render, 
+                    viewport, 
+                    Some(series_colors),
+                    CandleSizing {
+                        width_ratio: program.chart_style.candle_width_ratio,
+                        wick_width: program.chart_style.wick_width,
+                        body_style: program.chart_style.candle_body_style,
+                    },
+                    cutoff_timestamp,
+                    hide_after_cutoff,
+                    volume_heat,
+                    // La sélection porte sur la série affichée (première série)
+                    program.chart_state.selected_candle.filter(|_| series_idx == 0),
+                );
+            }
+            ChartRenderMode::OhlcBar => {
+                render_ohlc_bars(
+                    frame,
+                    candles_before_cutoff(candles_to_render, cutoff_timestamp, hide_after_cutoff),
+                    viewport,
+                    Some(series_colors),
+                );
+            }
+            ChartRenderMode::Line | ChartRenderMode::Area => {
+                if program.chart_style.render_mode == ChartRenderMode::Area {
+                    render_area_series(
+                        frame,
+                        candles_before_cutoff(candles_to_render, cutoff_timestamp, hide_after_cutoff),
+                        viewport,
+                        series_colors.bullish,
                     );
                 }
+                // La ligne reprend la couleur haussière de la série
+                let line_style = LineStyle {
+                    color: series_colors.bullish,
+                    ..Default::default()
+                };
+                render_line_series(
+                    frame,
+                    candles_to_render,
+                    viewport,
+                    Some(line_style),
+                    cutoff_timestamp,
+                    hide_after_cutoff,
+                );
+            }
+            ChartRenderMode::Renko => {
+                // Les briques sont construites pour la série active uniquement
+                if series_idx == 0 {
+                    let bricks = program.chart_state.renko_bricks();
+                    render_renko(frame, &bricks, viewport, Some(series_colors));
+                }
             }
         }
+    }
+    
+    // Afficher la ligne de prix courant de la première série active
+    if let Some(last_candle) = program.chart_state.last_candle() {
+        render_current_price_line(frame, &program.chart_state.viewport, last_candle.close, Some(price_style));
+        // Afficher le label du prix actuel sur le bord droit (avant la zone Y)
+        program.draw_current_price_label(frame, last_candle);
+    }
 
-        // Rendu de la moyenne mobile (si activée)
-        if self.ma_enabled {
-            // Calculer toutes les valeurs MA sur toutes les bougies
-            use crate::finance_chart::indicators::moving_average::calculate_all_ma_values;
-            use crate::finance_chart::indicators::moving_average::calculate_ma_data;
-            
-            let period = self.indicator_params.map(|p| p.ma_period);
-            if let Some(all_ma_values) = calculate_all_ma_values(self.chart_state, period) {
-                // Extraire les valeurs correspondant aux bougies visibles
-                if let Some((visible_ma_values, visible_candles, _)) = 
-                    calculate_ma_data(self.chart_state, &all_ma_values) 
-                {
-                    let ma_style = MovingAverageStyle::default();
-                    render_moving_average(
-                        &mut frame, 
-                        &self.chart_state.viewport, 
-                        visible_candles, 
-                        visible_ma_values,
-                        Some(ma_style)
-                    );
-                }
+    // Rendu des bandes de Bollinger (si activées)
+    if program.options.bollinger_enabled {
+        // Calculer toutes les valeurs Bollinger sur toutes les bougies
+        use crate::finance_chart::indicators::bollinger::calculate_all_bollinger_values;
+        use crate::finance_chart::indicators::bollinger::calculate_bollinger_data;
+        
+        let period = program.options.indicator_params.map(|p| p.bollinger_period);
+        let std_dev = program.options.indicator_params.map(|p| p.bollinger_std_dev);
+        if let Some(all_bollinger_values) = calculate_all_bollinger_values(program.chart_state, period, std_dev) {
+            // Extraire les valeurs correspondant aux bougies visibles
+            if let Some((visible_bollinger_values, visible_candles, _)) = 
+                calculate_bollinger_data(program.chart_state, &all_bollinger_values) 
+            {
+                let bollinger_style = BollingerStyle::default();
+                render_bollinger_bands(
+                    frame, 
+                    &program.chart_state.viewport, 
+                    visible_candles, 
+                    visible_bollinger_values,
+                    Some(bollinger_style)
+                );
             }
         }
+    }
 
-        // Rendu des dessins (rectangles et lignes)
-        self.draw_all_drawings(&mut frame);
+    // Rendu des moyennes mobiles (si activées)
+    if program.options.ma_enabled {
+        use crate::finance_chart::indicators::moving_average::calculate_all_ma_values;
+        use crate::finance_chart::indicators::moving_average::calculate_ma_data;
+        use crate::app::state::MaConfig;
+        
+        let default_configs = [MaConfig::preset(0)];
+        let configs = program.options.indicator_params
+            .map(|p| p.moving_averages.as_slice())
+            .unwrap_or(&default_configs);
+        
+        // Calculer (ou récupérer du cache) toutes les valeurs de chaque moyenne mobile
+        let all_ma_values: Vec<_> = configs
+            .iter()
+            .filter_map(|config| {
+                calculate_all_ma_values(program.chart_state, config.period, config.ma_type)
+                    .map(|values| (values, config.color.to_iced()))
+            })
+            .collect();
         
-        // Rendu des marqueurs de trades (si disponibles)
-        if let (Some(trades), Some(symbol)) = (self.trades, self.current_symbol) {
-            render_trade_markers(&mut frame, &self.chart_state.viewport, trades, symbol);
+        // Extraire les valeurs correspondant aux bougies visibles
+        let mut visible_candles = None;
+        let mut lines = Vec::with_capacity(all_ma_values.len());
+        for (values, color) in &all_ma_values {
+            if let Some((visible_ma_values, candles, _)) =
+                calculate_ma_data(program.chart_state, values)
+            {
+                visible_candles = Some(candles);
+                lines.push((visible_ma_values, MovingAverageStyle { color: *color, ..MovingAverageStyle::default() }));
+            }
+        }
+        
+        if let Some(candles) = visible_candles {
+            render_moving_averages(frame, &program.chart_state.viewport, candles, lines);
         }
+    }
 
-        // Rendu du crosshair (seulement si le dialog n'est pas ouvert)
-        if !self.settings_state.is_open {
-            if let Some(pos) = mouse_position {
-                let crosshair_style = CrosshairStyle {
-                    line_color: self.chart_style.crosshair_color.to_iced(),
-                    label_text_color: self.chart_style.text_color.to_iced(),
-                    ..Default::default()
-                };
-                render_crosshair(&mut frame, &self.chart_state.viewport, pos, Some(crosshair_style));
-
-                // Rendu du tooltip OHLC (si SHIFT maintenu)
-                if widget_state.shift_pressed {
-                    let visible_series = self.chart_state.visible_candles();
-                    // Chercher dans toutes les séries actives
-                    for (_, candles) in visible_series.iter() {
-                        if let Some(candle) = find_candle_at_position(pos.x, candles, &self.chart_state.viewport) {
-                            // Utiliser les couleurs du style pour le tooltip
-                            let tooltip_style = TooltipStyle {
-                                bg_color: Color::from_rgba(0.1, 0.1, 0.12, 0.95), // Fond sombre
-                                border_color: Color::from_rgba(0.3, 0.3, 0.35, 1.0), // Bordure
-                                text_color: self.chart_style.text_color.to_iced(),
-                                bullish_color: self.chart_style.bullish_color.to_iced(),
-                                bearish_color: self.chart_style.bearish_color.to_iced(),
-                                text_size: 11.0,
-                                padding: 8.0,
-                            };
-                            render_tooltip(&mut frame, candle, pos, &self.chart_state.viewport, Some(tooltip_style));
-                            break; // Afficher seulement le premier trouvé
-                        }
+    // Rendu du VWAP (si activé)
+    if program.options.vwap_enabled {
+        use crate::finance_chart::indicators::vwap::calculate_all_vwap_values;
+        use crate::finance_chart::indicators::vwap::calculate_vwap_data;
+
+        if let Some(all_vwap_values) = calculate_all_vwap_values(program.chart_state) {
+            // Extraire les valeurs correspondant aux bougies visibles
+            if let Some((visible_vwap_values, visible_candles, _)) =
+                calculate_vwap_data(program.chart_state, &all_vwap_values)
+            {
+                render_vwap(
+                    frame,
+                    &program.chart_state.viewport,
+                    visible_candles,
+                    visible_vwap_values,
+                    Some(VwapStyle::default())
+                );
+            }
+        }
+    }
+
+    // Rendu du Parabolic SAR (si activé)
+    if program.options.psar_enabled {
+        use crate::finance_chart::indicators::psar::calculate_all_psar_values;
+        use crate::finance_chart::indicators::psar::calculate_psar_data;
+
+        let step = program.options.indicator_params.map(|p| p.psar_step);
+        let max_step = program.options.indicator_params.map(|p| p.psar_max_step);
+        if let Some(all_psar_values) = calculate_all_psar_values(program.chart_state, step, max_step) {
+            // Extraire les valeurs correspondant aux bougies visibles
+            if let Some((visible_psar_values, visible_candles, _)) =
+                calculate_psar_data(program.chart_state, &all_psar_values)
+            {
+                render_psar(
+                    frame,
+                    &program.chart_state.viewport,
+                    visible_candles,
+                    visible_psar_values,
+                    Some(PsarStyle::default())
+                );
+            }
+        }
+    }
+
+    // Rendu des canaux de Donchian (si activés)
+    if program.options.donchian_enabled {
+        use crate::finance_chart::indicators::donchian::calculate_all_donchian_values;
+        use crate::finance_chart::indicators::donchian::calculate_donchian_data;
+
+        let period = program.options.indicator_params.map(|p| p.donchian_period);
+        if let Some(all_donchian_values) = calculate_all_donchian_values(program.chart_state, period) {
+            // Extraire les valeurs correspondant aux bougies visibles
+            if let Some((visible_donchian_values, visible_candles, _)) =
+                calculate_donchian_data(program.chart_state, &all_donchian_values)
+            {
+                render_donchian(
+                    frame,
+                    &program.chart_state.viewport,
+                    visible_candles,
+                    visible_donchian_values,
+                    Some(DonchianStyle::default())
+                );
+            }
+        }
+    }
+
+    // Rendu des canaux de Keltner (si activés)
+    if program.options.keltner_enabled {
+        use crate::finance_chart::indicators::keltner::calculate_all_keltner_values;
+        use crate::finance_chart::indicators::keltner::calculate_keltner_data;
+
+        let ema_period = program.options.indicator_params.map(|p| p.keltner_ema_period);
+        let atr_period = program.options.indicator_params.map(|p| p.keltner_atr_period);
+        let multiplier = program.options.indicator_params.map(|p| p.keltner_multiplier);
+        if let Some(all_keltner_values) = calculate_all_keltner_values(program.chart_state, ema_period, atr_period, multiplier) {
+            // Extraire les valeurs correspondant aux bougies visibles
+            if let Some((visible_keltner_values, visible_candles, _)) =
+                calculate_keltner_data(program.chart_state, &all_keltner_values)
+            {
+                render_keltner(
+                    frame,
+                    &program.chart_state.viewport,
+                    visible_candles,
+                    visible_keltner_values,
+                    Some(KeltnerStyle::default())
+                );
+            }
+        }
+    }
+
+    // Rendu du Volume Profile (si activé), recalculé sur les bougies et la plage de prix visibles
+    if program.options.volume_profile_enabled {
+        use crate::finance_chart::indicators::volume_profile::{compute_profile, VOLUME_PROFILE_BINS};
+
+        let visible_candles = program.chart_state.visible_candles();
+        if let Some((_, candles)) = visible_candles.first() {
+            let price_range = program.chart_state.viewport.price_scale().price_range();
+            let profile = compute_profile(candles, VOLUME_PROFILE_BINS, price_range);
+            render_volume_profile(frame, &program.chart_state.viewport, &profile, None);
+        }
+    }
+
+    // Rendu des points pivots (si activés), calculés par jour sur toutes les bougies
+    if program.options.pivots_enabled {
+        use crate::finance_chart::indicators::pivots::calculate_daily_pivots;
+
+        if let Some(all_candles) = program.chart_state.all_candles() {
+            let method = program.options.indicator_params.map(|p| p.pivot_method).unwrap_or_default();
+            let pivots = calculate_daily_pivots(all_candles, method);
+            render_pivots(frame, &program.chart_state.viewport, &pivots, None);
+        }
+    }
+
+    // Rendu des marqueurs de croisement de moyennes mobiles (si activés)
+    if program.options.crossover_markers_enabled {
+        use crate::finance_chart::indicators::moving_average::find_crossovers;
+
+        let default_params = crate::app::state::IndicatorParams::default();
+        let params = program.options.indicator_params.unwrap_or(&default_params);
+        if let (Some(fast), Some(slow), Some(all_candles)) = (
+            program.chart_state.moving_average_values(params.crossover_ma_type, params.crossover_fast_period),
+            program.chart_state.moving_average_values(params.crossover_ma_type, params.crossover_slow_period),
+            program.chart_state.all_candles(),
+        ) {
+            let crossings = find_crossovers(&fast, &slow);
+            render_crossover_markers(frame, &program.chart_state.viewport, all_candles, &crossings, None);
+        }
+    }
+
+    // Aperçu des signaux d'une stratégie, s'il porte sur la série affichée
+    if let Some(preview) = program.options.backtest_state.and_then(|b| b.signal_preview.as_ref()) {
+        let active_id = program.chart_state.series_manager.active_series().next().map(|s| &s.id);
+        if active_id == Some(&preview.series_id) {
+            if let Some(all_candles) = program.chart_state.all_candles() {
+                render_signal_markers(frame, &program.chart_state.viewport, all_candles, &preview.signals, None);
+            }
+        }
+    }
+
+    // Rendu des alertes de prix
+    render_alerts(frame, &program.chart_state.viewport, &program.chart_state.alerts.alerts, None);
+
+    // Rendu des dessins (rectangles et lignes)
+    program.draw_all_drawings(frame);
+    
+    // Liaisons entrée/sortie des positions clôturées, sous les marqueurs
+    if let (true, Some(trading_state), Some(symbol)) =
+        (program.settings_state.show_trade_pnl, program.trading_state, program.current_symbol)
+    {
+        let closed_trades = trading_state.trade_history.closed_trades(symbol);
+        render_pnl_connectors(frame, &program.chart_state.viewport, &closed_trades, program.chart_style);
+    }
+
+    // Rendu des marqueurs de trades (si disponibles)
+    if let (Some(trades), Some(symbol)) = (program.trades, program.current_symbol) {
+        render_trade_markers(frame, &program.chart_state.viewport, trades, symbol, program.candle_period());
+    }
+
+    // Bandeau d'informations sur la fenêtre visible
+    if program.settings_state.show_hud {
+        program.draw_hud(frame);
+    }
+}
+
+/// Dessine les couches qui suivent la souris (crosshair, tooltip), redessinées à chaque frame
+fn draw_dynamic_layers(
+    frame: &mut Frame,
+    program: &ChartProgram,
+    widget_state: &WidgetState,
+    mouse_position: Option<Point>,
+) {
+    // Rendu du crosshair (seulement si le dialog n'est pas ouvert)
+    if !program.settings_state.is_open {
+        if let Some(pos) = mouse_position {
+            let crosshair_style = CrosshairStyle {
+                label_text_color: program.chart_style.text_color.to_iced(),
+                ..CrosshairStyle::from_chart_style(program.chart_style)
+            };
+            // Mode aimant : accrocher le prix OHLC le plus proche (sans objet en Renko)
+            let snapped = if program.settings_state.crosshair_magnet
+                && program.chart_style.render_mode != ChartRenderMode::Renko
+            {
+                program.chart_state.visible_candles()
+                    .iter()
+                    .find_map(|(_, candles)| find_nearest_ohlc(pos, candles, &program.chart_state.viewport))
+            } else {
+                None
+            };
+            render_crosshair(frame, &program.chart_state.viewport, pos, snapped, Some(crosshair_style));
+
+            // Rendu du tooltip OHLC (si SHIFT maintenu)
+            if widget_state.shift_pressed {
+                let visible_series = program.chart_state.visible_candles();
+                // Chercher dans toutes les séries actives
+                for (_, candles) in visible_series.iter() {
+                    if let Some(candle) = find_candle_at_position(pos.x, candles, &program.chart_state.viewport) {
+                        // Utiliser les couleurs du style pour le tooltip
+                        let tooltip_style = TooltipStyle {
+                            bg_color: Color::from_rgba(0.1, 0.1, 0.12, 0.95), // Fond sombre
+                            border_color: Color::from_rgba(0.3, 0.3, 0.35, 1.0), // Bordure
+                            text_color: program.chart_style.text_color.to_iced(),
+                            bullish_color: program.chart_style.bullish_color.to_iced(),
+                            bearish_color: program.chart_style.bearish_color.to_iced(),
+                            text_size: 11.0,
+                            padding: 8.0,
+                        };
+                        // Bougie précédente : cherchée dans la série complète pour que la
+                        // première bougie visible ait aussi sa variation
+                        let source = program.chart_state.all_candles()
+                            .filter(|all| all.binary_search_by_key(&candle.timestamp, |c| c.timestamp).is_ok())
+                            .unwrap_or(&candles[..]);
+                        let previous = source
+                            .partition_point(|c| c.timestamp < candle.timestamp)
+                            .checked_sub(1)
+                            .map(|i| &source[i]);
+                        render_tooltip(
+                            frame,
+                            candle,
+                            previous,
+                            &program.settings_state.tooltip,
+                            program.chart_style,
+                            pos,
+                            &program.chart_state.viewport,
+                            Some(tooltip_style),
+                        );
+                        break; // Afficher seulement le premier trouvé
                     }
                 }
+            } else if let (Some(trades), Some(symbol)) = (program.trades, program.current_symbol) {
+                // Survol d'un groupe de trades : liste des trades regroupés
+                let viewport = &program.chart_state.viewport;
+                let clusters = visible_trade_clusters(viewport, trades, symbol, program.candle_period());
+                if let Some(cluster) = find_cluster_at(pos, &clusters, viewport) {
+                    render_cluster_tooltip(frame, cluster, pos, viewport, program.chart_style);
+                }
             }
         }
+    }
+}
+
+impl<'a> Program<ChartMessage> for ChartProgram<'a> {
+    type State = WidgetState;
+
+    fn draw(
+        &self,
+        widget_state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: Rectangle,
+        cursor: iced::mouse::Cursor,
+    ) -> Vec<Geometry> {
+        // Couches statiques : reconstruites seulement si les données ou le viewport ont changé
+        let key = StaticLayerKey::new(self, bounds.size());
+        if widget_state.static_key.get() != Some(key) {
+            widget_state.static_cache.clear();
+            widget_state.static_key.set(Some(key));
+        }
+        let static_layers = widget_state.static_cache.draw(renderer, bounds.size(), |frame| {
+            draw_static_layers(frame, self, bounds.size());
+        });
 
-        vec![frame.into_geometry()]
+        // Couches dynamiques (position de la souris en lecture seule)
+        let mut frame = Frame::new(renderer, bounds.size());
+        let mouse_position = cursor.position_in(bounds);
+        draw_dynamic_layers(&mut frame, self, widget_state, mouse_position);
+        vec![static_layers, frame.into_geometry()]
     }
 
     fn update(
@@ -580,17 +1154,26 @@ impl<'a> Program<ChartMessage> for ChartProgram<'a> {
             // === Gestion de la souris ===
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 if let Some(position) = cursor.position_in(bounds) {
+                    widget_state.press_position = None;
+                    // Double-clic : réinitialiser la vue, sans action d'outil sur le second clic
+                    if self.is_double_click(widget_state, position) {
+                        return Some(CanvasAction::publish(ChartMessage::ResetView));
+                    }
+                    if self.tools_state.selected_tool.is_none() {
+                        widget_state.press_position = Some(position);
+                    }
                     // Stocker les bounds du graphique principal pour convertir les positions absolues
                     // des indicateurs en positions relatives
                     if let Some(absolute_position) = cursor.position() {
                         // Pour le pan, utiliser la position absolue pour cohérence avec les indicateurs
-                        return self.handle_mouse_press(position, absolute_position);
+                        return self.handle_mouse_press(position, absolute_position, widget_state.shift_pressed);
                     }
-                    return self.handle_mouse_press(position, position);
+                    return self.handle_mouse_press(position, position, widget_state.shift_pressed);
                 }
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
-                return self.handle_mouse_release(cursor.position_in(bounds));
+                let press_position = widget_state.press_position.take();
+                return self.handle_mouse_release(cursor.position_in(bounds), widget_state.ctrl_pressed, press_position);
             }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
                 if cursor.position_in(bounds).is_some() {
@@ -607,10 +1190,10 @@ impl<'a> Program<ChartMessage> for ChartProgram<'a> {
                 // Utiliser la position absolue pour cohérence avec les indicateurs
                 let absolute_position = cursor.position()
                     .unwrap_or_else(|| Point::new(position.x + bounds.x, position.y + bounds.y));
-                return self.handle_mouse_move(position, absolute_position, bounds);
+                return self.handle_mouse_move(position, absolute_position, bounds, widget_state.ctrl_pressed);
             }
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                return self.handle_scroll(widget_state, *delta);
+                return self.handle_scroll(widget_state, *delta, cursor.position_in(bounds));
             }
             _ => {}
         }
@@ -638,6 +1221,9 @@ impl<'a> Program<ChartMessage> for ChartProgram<'a> {
         if self.tools_state.hline_editing.is_editing {
             return mouse::Interaction::ResizingVertically;
         }
+        if self.tools_state.vline_editing.is_editing {
+            return mouse::Interaction::ResizingHorizontally;
+        }
         
         // Survol d'un rectangle ou ligne
         // Test rectangles
@@ -655,6 +1241,21 @@ impl<'a> Program<ChartMessage> for ChartProgram<'a> {
             return mouse::Interaction::ResizingVertically;
         }
         
+        // Test lignes verticales
+        if hit_test_vline(position.x, &self.tools_state.vertical_lines, &self.chart_state.viewport).is_some() {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+        
+        // Test annotations texte
+        if hit_test_text(position, &self.tools_state.texts, &self.chart_state.viewport).is_some() {
+            return mouse::Interaction::Pointer;
+        }
+        
+        // Test lignes de tendance
+        if hit_test_trend_line(position, &self.tools_state.trend_lines, &self.chart_state.viewport).is_some() {
+            return mouse::Interaction::Pointer;
+        }
+        
         // Curseur croix uniquement sur le canvas du graphique
         mouse::Interaction::Crosshair
     }
@@ -670,6 +1271,32 @@ impl<'a> ChartProgram<'a> {
         widget_state: &mut WidgetState,
         key: keyboard::Key,
     ) -> Option<CanvasAction<ChartMessage>> {
+        // Saisie de texte en cours : les touches vont au champ de saisie
+        if self.tools_state.text_editing.is_some() {
+            return match key {
+                keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                    Some(CanvasAction::publish(ChartMessage::CancelText))
+                }
+                _ => None,
+            };
+        }
+        if self.tools_state.hline_price_editing.is_some() {
+            return match key {
+                keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                    Some(CanvasAction::publish(ChartMessage::CancelHLinePriceEdit))
+                }
+                _ => None,
+            };
+        }
+        if self.tools_state.rectangle_label_editing.is_some() {
+            return match key {
+                keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                    Some(CanvasAction::publish(ChartMessage::CancelRectangleLabelEdit))
+                }
+                _ => None,
+            };
+        }
+        
         match key {
             keyboard::Key::Named(keyboard::key::Named::Alt) => {
                 widget_state.alt_pressed = true;
@@ -695,10 +1322,22 @@ impl<'a> ChartProgram<'a> {
                     Some(CanvasAction::publish(ChartMessage::FinishRectangleEdit))
                 } else if self.tools_state.hline_editing.is_editing {
                     Some(CanvasAction::publish(ChartMessage::FinishHLineEdit))
+                } else if self.tools_state.vline_editing.is_editing {
+                    Some(CanvasAction::publish(ChartMessage::FinishVLineEdit))
                 } else if self.tools_state.editing.selected_index.is_some() {
                     Some(CanvasAction::publish(ChartMessage::DeselectRectangle))
                 } else if self.tools_state.hline_editing.selected_index.is_some() {
                     Some(CanvasAction::publish(ChartMessage::DeselectHLine))
+                } else if self.tools_state.vline_editing.selected_index.is_some() {
+                    Some(CanvasAction::publish(ChartMessage::DeselectVLine))
+                } else if self.tools_state.selected_text.is_some() {
+                    Some(CanvasAction::publish(ChartMessage::DeselectText))
+                } else if self.tools_state.selected_trend_line.is_some() {
+                    Some(CanvasAction::publish(ChartMessage::DeselectTrendLine))
+                } else if self.tools_state.selected_tool.is_some() {
+                    Some(CanvasAction::publish(ChartMessage::SelectTool { tool: None }))
+                } else if self.chart_state.selected_candle.is_some() {
+                    Some(CanvasAction::publish(ChartMessage::DeselectCandle))
                 } else {
                     Some(CanvasAction::request_redraw())
                 }
@@ -715,6 +1354,34 @@ impl<'a> ChartProgram<'a> {
             keyboard::Key::Character(c) if c.as_str() == "o" && widget_state.ctrl_pressed => {
                 Some(CanvasAction::publish(ChartMessage::LoadDrawings))
             }
+            // Mode aimant du crosshair
+            keyboard::Key::Character(c)
+                if c.as_str().eq_ignore_ascii_case("m") && !widget_state.ctrl_pressed =>
+            {
+                Some(CanvasAction::publish(ChartMessage::ToggleCrosshairMagnet))
+            }
+            // Raccourcis des outils de dessin (ignorés quand un panneau a le focus)
+            keyboard::Key::Character(c)
+                if !widget_state.ctrl_pressed && !self.options.panel_focused && Tool::from_shortcut(c.as_str()).is_some() =>
+            {
+                let tool = Tool::from_shortcut(c.as_str());
+                Some(CanvasAction::publish(ChartMessage::SelectTool { tool }))
+            }
+            // Ajuster la vue à toutes les données
+            keyboard::Key::Character(c)
+                if c.as_str().eq_ignore_ascii_case("f") && !widget_state.ctrl_pressed =>
+            {
+                Some(CanvasAction::publish(ChartMessage::FitToData))
+            }
+            // Ajustement de la taille des briques Renko : [ réduit, ] agrandit
+            keyboard::Key::Character(c)
+                if (c.as_str() == "[" || c.as_str() == "]")
+                    && self.chart_style.render_mode == ChartRenderMode::Renko =>
+            {
+                let factor = if c.as_str() == "]" { 1.1 } else { 0.9 };
+                self.chart_state.effective_renko_brick_size()
+                    .map(|size| CanvasAction::publish(ChartMessage::SetRenkoBrickSize(size * factor)))
+            }
             _ => None
         }
     }
@@ -741,16 +1408,59 @@ impl<'a> ChartProgram<'a> {
         }
     }
 
-    fn handle_mouse_press(&self, position: Point, absolute_position: Point) -> Option<CanvasAction<ChartMessage>> {
+    /// Enregistre un clic et indique s'il complète un double-clic
+    ///
+    /// Un second clic sur une annotation texte reste un clic simple (il ouvre l'édition).
+    fn is_double_click(&self, widget_state: &mut WidgetState, position: Point) -> bool {
+        let now = Instant::now();
+        let is_double = widget_state.last_click.is_some_and(|(instant, last)| {
+            now.duration_since(instant) <= DOUBLE_CLICK_DELAY
+                && last.distance(position) <= DOUBLE_CLICK_DISTANCE_PX
+        }) && !self.options.panel_focused
+            && hit_test_text(position, &self.tools_state.texts, &self.chart_state.viewport).is_none();
+        // Après un double-clic, le clic suivant en démarre un nouveau
+        widget_state.last_click = if is_double { None } else { Some((now, position)) };
+        is_double
+    }
+
+    fn handle_mouse_press(&self, position: Point, absolute_position: Point, zoom_select: bool) -> Option<CanvasAction<ChartMessage>> {
         // Ignorer les événements si un panneau a le focus
-        if self.panel_focused {
+        if self.options.panel_focused {
             return None;
         }
         
+        // Clic en dehors du champ de saisie : valider le texte en cours
+        if let Some(edit) = &self.tools_state.text_editing {
+            return Some(CanvasAction::publish(ChartMessage::CommitText {
+                content: edit.content.clone(),
+            }));
+        }
+        // Idem pour le prix d'une ligne horizontale (abandonné s'il est invalide)
+        if let Some(edit) = &self.tools_state.hline_price_editing {
+            return Some(CanvasAction::publish(match edit.parsed_price() {
+                Some(price) => ChartMessage::SetHLinePrice { index: edit.index, price },
+                None => ChartMessage::CancelHLinePriceEdit,
+            }));
+        }
+        // Idem pour le texte d'un rectangle
+        if self.tools_state.rectangle_label_editing.is_some() {
+            return Some(CanvasAction::publish(ChartMessage::CommitRectangleLabel));
+        }
+        
         let viewport = &self.chart_state.viewport;
         let time = viewport.time_scale().x_to_time(position.x);
         let price = viewport.price_scale().y_to_price(position.y);
 
+        // Shift + glisser : sélection de zoom (prioritaire sur les dessins et l'outil actif)
+        if zoom_select {
+            return Some(CanvasAction::publish(ChartMessage::StartZoomSelection {
+                screen_x: position.x,
+                screen_y: position.y,
+                time,
+                price,
+            }));
+        }
+
         // Clic sur un rectangle existant
         if let Some(result) = hit_test_rectangles(
             position,
@@ -766,6 +1476,16 @@ impl<'a> ChartProgram<'a> {
             }));
         }
         
+        // Clic sur l'étiquette de prix d'une ligne horizontale : saisir un prix exact
+        if let Some((index, label)) = hit_test_hline_label(position, &self.tools_state.horizontal_lines, &self.chart_state.viewport) {
+            let offset_x = absolute_position.x - position.x;
+            let offset_y = absolute_position.y - position.y;
+            return Some(CanvasAction::publish(ChartMessage::StartEditingHLinePrice {
+                index,
+                position: Point::new(label.x + offset_x, label.y + offset_y),
+            }));
+        }
+        
         // Clic sur une ligne horizontale existante
         if let Some(index) = hit_test_hline(position.y, &self.tools_state.horizontal_lines, &self.chart_state.viewport) {
             return Some(CanvasAction::publish(ChartMessage::StartHLineEdit {
@@ -774,6 +1494,32 @@ impl<'a> ChartProgram<'a> {
             }));
         }
         
+        // Clic sur une ligne verticale existante
+        if let Some(index) = hit_test_vline(position.x, &self.tools_state.vertical_lines, &self.chart_state.viewport) {
+            return Some(CanvasAction::publish(ChartMessage::StartVLineEdit {
+                index,
+                time,
+            }));
+        }
+        
+        // Clic sur une annotation texte (un second clic ouvre l'édition)
+        if let Some(index) = hit_test_text(position, &self.tools_state.texts, &self.chart_state.viewport) {
+            if self.tools_state.selected_text == Some(index) {
+                return Some(CanvasAction::publish(ChartMessage::StartEditingText {
+                    position: absolute_position,
+                    time,
+                    price,
+                    index: Some(index),
+                }));
+            }
+            return Some(CanvasAction::publish(ChartMessage::SelectText { index }));
+        }
+        
+        // Clic sur une ligne de tendance existante (segment prolongé inclus)
+        if let Some(index) = hit_test_trend_line(position, &self.tools_state.trend_lines, &self.chart_state.viewport) {
+            return Some(CanvasAction::publish(ChartMessage::SelectTrendLine { index }));
+        }
+        
         // Outil actif - priorité sur le pan
         match self.tools_state.selected_tool {
             Some(Tool::Rectangle) => {
@@ -790,6 +1536,36 @@ impl<'a> ChartProgram<'a> {
                     price,
                 }));
             }
+            Some(Tool::VerticalLine) => {
+                Some(CanvasAction::publish(ChartMessage::StartDrawingVLine {
+                    screen_x: position.x,
+                    time,
+                }))
+            }
+            Some(Tool::Measure) => {
+                Some(CanvasAction::publish(ChartMessage::StartMeasure {
+                    screen_x: position.x,
+                    screen_y: position.y,
+                    time,
+                    price,
+                }))
+            }
+            Some(Tool::Text) => {
+                Some(CanvasAction::publish(ChartMessage::StartEditingText {
+                    position: absolute_position,
+                    time,
+                    price,
+                    index: None,
+                }))
+            }
+            Some(Tool::Ray) => {
+                Some(CanvasAction::publish(ChartMessage::StartDrawingRay {
+                    screen_x: position.x,
+                    screen_y: position.y,
+                    time,
+                    price,
+                }))
+            }
             None => {
                 // Pas d'outil actif - émettre aussi SelectBacktestDate (le handler vérifiera si la section est active)
                 // et démarrer le pan
@@ -803,7 +1579,7 @@ impl<'a> ChartProgram<'a> {
         }
     }
 
-    fn handle_mouse_release(&self, cursor_position: Option<Point>) -> Option<CanvasAction<ChartMessage>> {
+    fn handle_mouse_release(&self, cursor_position: Option<Point>, snap: bool, press_position: Option<Point>) -> Option<CanvasAction<ChartMessage>> {
         // Fin d'édition rectangle
         if self.tools_state.editing.is_editing {
             return Some(CanvasAction::publish(ChartMessage::FinishRectangleEdit));
@@ -814,6 +1590,11 @@ impl<'a> ChartProgram<'a> {
             return Some(CanvasAction::publish(ChartMessage::FinishHLineEdit));
         }
         
+        // Fin d'édition ligne verticale
+        if self.tools_state.vline_editing.is_editing {
+            return Some(CanvasAction::publish(ChartMessage::FinishVLineEdit));
+        }
+        
         // Fin de dessin
         if self.tools_state.drawing.is_drawing {
             if let Some(position) = cursor_position {
@@ -821,30 +1602,85 @@ impl<'a> ChartProgram<'a> {
                 let end_time = viewport.time_scale().x_to_time(position.x);
                 let end_price = viewport.price_scale().y_to_price(position.y);
                 
+                // Sélection de zoom : aucun dessin n'est créé
+                if self.tools_state.drawing.zoom_selection {
+                    let drawing = &self.tools_state.drawing;
+                    if let (Some(start_time), Some(start_price)) = (drawing.start_time, drawing.start_price) {
+                        return Some(CanvasAction::publish(ChartMessage::ZoomToSelection {
+                            start: (start_time, start_price),
+                            end: (end_time, end_price),
+                        }));
+                    }
+                    return Some(CanvasAction::publish(ChartMessage::CancelDrawing));
+                }
+                
                 match self.tools_state.selected_tool {
                     Some(Tool::Rectangle) => {
                         return Some(CanvasAction::publish(ChartMessage::FinishDrawingRectangle {
                             end_time,
                             end_price,
+                            snap,
                         }));
                     }
                     Some(Tool::HorizontalLine) => {
-                        return Some(CanvasAction::publish(ChartMessage::FinishDrawingHLine));
+                        return Some(CanvasAction::publish(ChartMessage::FinishDrawingHLine { snap }));
                     }
-                    None => {}
+                    Some(Tool::VerticalLine) => {
+                        return Some(CanvasAction::publish(ChartMessage::FinishDrawingVLine));
+                    }
+                    Some(Tool::Ray) => {
+                        return Some(CanvasAction::publish(ChartMessage::FinishDrawingRay {
+                            end_time,
+                            end_price,
+                        }));
+                    }
+                    Some(Tool::Measure) => {
+                        // La mesure n'est pas conservée au relâchement
+                        return Some(CanvasAction::publish(ChartMessage::CancelDrawing));
+                    }
+                    Some(Tool::Text) | None => {}
                 }
             } else {
                 return Some(CanvasAction::publish(ChartMessage::CancelDrawing));
             }
         }
         
+        // Clic sans déplacement (le pan démarré par l'appui n'a rien bougé) : bougie cliquée
+        if let (Some(press), Some(position)) = (press_position, cursor_position) {
+            if self.chart_state.interaction.is_panning && press.distance(position) <= CLICK_DRAG_TOLERANCE_PX {
+                if let Some(message) = self.candle_clicked(position) {
+                    return Some(CanvasAction::publish(message));
+                }
+            }
+        }
+
         // Fin du pan
         Some(CanvasAction::publish(ChartMessage::EndPan))
     }
 
-    fn handle_mouse_move(&self, position: Point, absolute_position: Point, _bounds: Rectangle) -> Option<CanvasAction<ChartMessage>> {
+    /// Message de clic sur la bougie de la série principale sous `position`
+    ///
+    /// Sans objet en Renko, où l'axe X n'est pas linéaire en temps.
+    fn candle_clicked(&self, position: Point) -> Option<ChartMessage> {
+        if self.chart_style.render_mode == ChartRenderMode::Renko {
+            return None;
+        }
+        let visible = self.chart_state.visible_candles();
+        let (series_id, candles) = visible.first()?;
+        let candle = find_candle_at_position(position.x, candles, &self.chart_state.viewport)?;
+        Some(ChartMessage::CandleClicked {
+            series_id: series_id.clone(),
+            timestamp: candle.timestamp,
+            open: candle.open,
+            high: candle.high,
+            low: candle.low,
+            close: candle.close,
+        })
+    }
+
+    fn handle_mouse_move(&self, position: Point, absolute_position: Point, _bounds: Rectangle, snap: bool) -> Option<CanvasAction<ChartMessage>> {
         // Ignorer les événements si un panneau a le focus
-        if self.panel_focused {
+        if self.options.panel_focused {
             return None;
         }
         
@@ -855,7 +1691,8 @@ impl<'a> ChartProgram<'a> {
         // Vérifier si on est en train de faire quelque chose qui bloque le pan
         let is_busy = self.tools_state.drawing.is_drawing 
             || self.tools_state.editing.is_editing 
-            || self.tools_state.hline_editing.is_editing;
+            || self.tools_state.hline_editing.is_editing
+            || self.tools_state.vline_editing.is_editing;
         
         // PRIORITÉ 1 : Pan (si actif et pas occupé par autre chose)
         if self.chart_state.interaction.is_panning && !is_busy {
@@ -865,12 +1702,17 @@ impl<'a> ChartProgram<'a> {
         
         // PRIORITÉ 2 : Édition rectangle (si active)
         if self.tools_state.editing.is_editing {
-            return Some(CanvasAction::publish(ChartMessage::UpdateRectangleEdit { time, price }));
+            return Some(CanvasAction::publish(ChartMessage::UpdateRectangleEdit { time, price, snap }));
         }
         
         // PRIORITÉ 3 : Édition ligne horizontale (si active)
         if self.tools_state.hline_editing.is_editing {
-            return Some(CanvasAction::publish(ChartMessage::UpdateHLineEdit { price }));
+            return Some(CanvasAction::publish(ChartMessage::UpdateHLineEdit { price, snap }));
+        }
+        
+        // PRIORITÉ 3 bis : Édition ligne verticale (si active)
+        if self.tools_state.vline_editing.is_editing {
+            return Some(CanvasAction::publish(ChartMessage::UpdateVLineEdit { time }));
         }
         
         // PRIORITÉ 4 : Dessin en cours
@@ -885,9 +1727,9 @@ impl<'a> ChartProgram<'a> {
         Some(CanvasAction::publish(ChartMessage::MouseMoved { position }))
     }
 
-    fn handle_scroll(&self, widget_state: &WidgetState, delta: mouse::ScrollDelta) -> Option<CanvasAction<ChartMessage>> {
+    fn handle_scroll(&self, widget_state: &WidgetState, delta: mouse::ScrollDelta, cursor: Option<Point>) -> Option<CanvasAction<ChartMessage>> {
         // Ignorer les événements si un panneau a le focus
-        if self.panel_focused {
+        if self.options.panel_focused {
             return None;
         }
         
@@ -917,12 +1759,27 @@ impl<'a> ChartProgram<'a> {
                 mouse::ScrollDelta::Lines { y, .. } => if y > 0.0 { 0.9 } else { 1.1 },
                 mouse::ScrollDelta::Pixels { y, .. } => if y > 0.0 { 0.95 } else { 1.05 },
             };
-            Some(CanvasAction::publish(ChartMessage::ZoomBoth { factor: zoom_factor }))
+            Some(CanvasAction::publish(ChartMessage::ZoomBoth { factor: zoom_factor, cursor }))
         } else if widget_state.alt_pressed {
-            Some(CanvasAction::publish(ChartMessage::ZoomVertical { factor: zoom_factor_vertical }))
+            Some(CanvasAction::publish(ChartMessage::ZoomVertical { factor: zoom_factor_vertical, cursor }))
         } else {
-            Some(CanvasAction::publish(ChartMessage::ZoomHorizontal { factor: zoom_factor_horizontal }))
+            Some(CanvasAction::publish(ChartMessage::ZoomHorizontal { factor: zoom_factor_horizontal, cursor }))
+        }
+    }
+}
+
+/// Tronque les bougies après la barre de backtest si elles doivent être cachées
+fn candles_before_cutoff(
+    candles: &[crate::finance_chart::core::Candle],
+    cutoff_timestamp: Option<i64>,
+    hide_after_cutoff: bool,
+) -> &[crate::finance_chart::core::Candle] {
+    match cutoff_timestamp {
+        Some(cutoff) if hide_after_cutoff => {
+            let end = candles.partition_point(|c| c.timestamp <= cutoff);
+            &candles[..end]
         }
+        _ => candles,
     }
 }
 
@@ -937,18 +1794,10 @@ pub fn chart<'a>(
     tools_state: &'a ToolsState,
     settings_state: &'a SettingsState,
     chart_style: &'a ChartStyle,
-    panel_focused: bool,
-    bollinger_enabled: bool,
-    ma_enabled: bool,
-    // Paramètres des indicateurs
-    indicator_params: Option<&'a crate::app::state::IndicatorParams>,
-    backtest_state: Option<&'a crate::app::state::backtest::BacktestState>,
+    options: &ChartOptions<'a>,
 ) -> Element<'a, ChartMessage> {
-    let mut program = ChartProgram::new(chart_state, tools_state, settings_state, chart_style, panel_focused);
-    program.bollinger_enabled = bollinger_enabled;
-    program.ma_enabled = ma_enabled;
-    program.indicator_params = indicator_params;
-    program.backtest_state = backtest_state;
+    let mut program = ChartProgram::new(chart_state, tools_state, settings_state, chart_style, options.panel_focused);
+    program.options = *options;
     Canvas::new(program)
         .width(Length::Fill)
         .height(Length::Fill)
@@ -961,20 +1810,12 @@ pub fn chart_with_trading<'a>(
     tools_state: &'a ToolsState,
     settings_state: &'a SettingsState,
     chart_style: &'a ChartStyle,
-    panel_focused: bool,
     trading_state: &'a crate::app::state::TradingState,
     current_symbol: &'a str,
-    bollinger_enabled: bool,
-    ma_enabled: bool,
-    // Paramètres des indicateurs
-    indicator_params: Option<&'a crate::app::state::IndicatorParams>,
-    backtest_state: Option<&'a crate::app::state::backtest::BacktestState>,
+    options: &ChartOptions<'a>,
 ) -> Element<'a, ChartMessage> {
-    let mut program = ChartProgram::with_trading_state(chart_state, tools_state, settings_state, chart_style, panel_focused, trading_state, current_symbol);
-    program.bollinger_enabled = bollinger_enabled;
-    program.ma_enabled = ma_enabled;
-    program.indicator_params = indicator_params;
-    program.backtest_state = backtest_state;
+    let mut program = ChartProgram::with_trading_state(chart_state, tools_state, settings_state, chart_style, options.panel_focused, trading_state, current_symbol);
+    program.options = *options;
     Canvas::new(program)
         .width(Length::Fill)
         .height(Length::Fill)
@@ -982,26 +1823,19 @@ pub fn chart_with_trading<'a>(
 }
 
 /// Crée un graphique avec les trades et les ordres limit/TP/SL
+#[allow(clippy::too_many_arguments)]
 pub fn chart_with_trades_and_trading<'a>(
     chart_state: &'a ChartState,
     tools_state: &'a ToolsState,
     settings_state: &'a SettingsState,
     chart_style: &'a ChartStyle,
-    panel_focused: bool,
     trades: &'a [crate::app::data::Trade],
     current_symbol: &'a str,
     trading_state: &'a crate::app::state::TradingState,
-    bollinger_enabled: bool,
-    ma_enabled: bool,
-    // Paramètres des indicateurs
-    indicator_params: Option<&'a crate::app::state::IndicatorParams>,
-    backtest_state: Option<&'a crate::app::state::backtest::BacktestState>,
+    options: &ChartOptions<'a>,
 ) -> Element<'a, ChartMessage> {
-    let mut program = ChartProgram::with_trades_and_trading(chart_state, tools_state, settings_state, chart_style, panel_focused, trades, current_symbol, trading_state);
-    program.bollinger_enabled = bollinger_enabled;
-    program.ma_enabled = ma_enabled;
-    program.indicator_params = indicator_params;
-    program.backtest_state = backtest_state;
+    let mut program = ChartProgram::with_trades_and_trading(chart_state, tools_state, settings_state, chart_style, options.panel_focused, trades, current_symbol, trading_state);
+    program.options = *options;
     Canvas::new(program)
         .width(Length::Fill)
         .height(Length::Fill)